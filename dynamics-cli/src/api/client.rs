@@ -1,12 +1,13 @@
 use super::constants::{self, headers, methods};
+use super::models::WhoAmI;
 use super::operations::{BatchRequestBuilder, BatchResponseParser, Operation, OperationResult};
-use super::query::{Query, QueryResponse, QueryResult};
+use super::query::{FetchError, Query, QueryResponse, QueryResult};
 use super::resilience::{
     ApiLogger, BypassConfig, ConcurrencyLimiter, MetricsCollector, OperationContext,
-    OperationMetrics, RateLimiter, ResilienceConfig, RetryConfig, RetryPolicy,
+    OperationMetrics, RateLimiter, ResilienceConfig, RetryConfig, RetryPolicy, TimeoutConfig,
 };
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
 /// Build HTTP headers for bypassing Dynamics 365 custom business logic
@@ -45,6 +46,50 @@ fn build_bypass_headers(config: &BypassConfig) -> Vec<(&'static str, String)> {
     bypass_headers
 }
 
+/// Build the per-operation headers for a data request: bypass headers plus impersonation
+fn build_operation_headers(resilience: &ResilienceConfig) -> Vec<(&'static str, String)> {
+    let mut operation_headers = build_bypass_headers(&resilience.bypass);
+    if let Some(user_id) = &resilience.impersonate_user_id {
+        operation_headers.push((headers::CALLER_ID, user_id.clone()));
+    }
+    operation_headers
+}
+
+/// Build headers for requests that write record data (create, update, upsert)
+///
+/// Adds the duplicate detection switch on top of the operation headers, since
+/// duplicate detection rules only run when records are written.
+fn build_write_headers(resilience: &ResilienceConfig) -> Vec<(&'static str, String)> {
+    let mut write_headers = build_operation_headers(resilience);
+    if resilience.bypass.suppress_duplicate_detection {
+        write_headers.push((headers::SUPPRESS_DUPLICATE_DETECTION, "true".to_string()));
+    }
+    write_headers
+}
+
+/// Build the URL and JSON body for invoking an action
+///
+/// Action parameters are sent as a JSON object; `Value::Null` means no parameters.
+fn action_request(
+    base_url: &str,
+    name: &str,
+    bound_entity: Option<(&str, &str)>,
+    params: &Value,
+) -> anyhow::Result<(String, Value)> {
+    if name.trim().is_empty() {
+        anyhow::bail!("Action name must not be empty");
+    }
+    let body = match params {
+        Value::Null => Value::Object(serde_json::Map::new()),
+        Value::Object(_) => params.clone(),
+        other => anyhow::bail!("Action parameters must be a JSON object, got: {}", other),
+    };
+    Ok((
+        constants::action_endpoint(base_url, name.trim(), bound_entity),
+        body,
+    ))
+}
+
 /// Modern Dynamics 365 Web API client with connection pooling
 #[derive(Clone)]
 pub struct DynamicsClient {
@@ -56,6 +101,7 @@ pub struct DynamicsClient {
     concurrency_limiter: ConcurrencyLimiter, // Global concurrency limiter for this client instance
     api_logger: ApiLogger,     // Structured logger for operations
     metrics_collector: MetricsCollector, // Performance metrics collector
+    timeouts: TimeoutConfig,   // Timeouts for requests made without a ResilienceConfig
 }
 
 /// Entity metadata from EntityDefinitions (EntitySetName, IsIntersect, PrimaryNameAttribute, etc.)
@@ -104,6 +150,16 @@ impl DynamicsClient {
         Ok(permit)
     }
 
+    /// Like `apply_rate_limiting`, but also honours any rate-limit override for the entity
+    async fn apply_entity_rate_limiting(
+        &self,
+        entity: &str,
+    ) -> anyhow::Result<tokio::sync::OwnedSemaphorePermit> {
+        let permit = self.concurrency_limiter.acquire().await;
+        self.rate_limiter.acquire_for_entity(entity).await;
+        Ok(permit)
+    }
+
     /// Get rate limiter statistics for monitoring
     pub fn rate_limiter_stats(&self) -> crate::api::resilience::RateLimiterStats {
         self.rate_limiter.stats()
@@ -143,6 +199,7 @@ impl DynamicsClient {
             concurrency_limiter: ConcurrencyLimiter::new(default_config.concurrency.clone()),
             api_logger: ApiLogger::new(default_config.monitoring.clone()),
             metrics_collector: MetricsCollector::new(default_config.monitoring),
+            timeouts: default_config.timeout,
         }
     }
 
@@ -162,6 +219,7 @@ impl DynamicsClient {
             concurrency_limiter: ConcurrencyLimiter::new(default_config.concurrency.clone()),
             api_logger: ApiLogger::new(default_config.monitoring.clone()),
             metrics_collector: MetricsCollector::new(default_config.monitoring),
+            timeouts: default_config.timeout,
         }
     }
 
@@ -190,9 +248,29 @@ impl DynamicsClient {
             concurrency_limiter: ConcurrencyLimiter::new(default_config.concurrency.clone()),
             api_logger: ApiLogger::new(default_config.monitoring.clone()),
             metrics_collector: MetricsCollector::new(default_config.monitoring),
+            timeouts: default_config.timeout,
         }
     }
 
+    /// Share a concurrency limiter with other clients (e.g. an environment's partition)
+    pub fn with_concurrency_limiter(mut self, limiter: ConcurrencyLimiter) -> Self {
+        self.concurrency_limiter = limiter;
+        self
+    }
+
+    /// Share a rate limiter with other clients (e.g. an environment's partition)
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = limiter;
+        self
+    }
+
+    /// Set the timeouts used for queries and metadata requests.
+    /// Operations take their timeouts from the `ResilienceConfig` they're executed with.
+    pub fn with_timeouts(mut self, timeouts: TimeoutConfig) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
     /// Execute a single operation
     pub async fn execute(
         &self,
@@ -209,8 +287,14 @@ impl DynamicsClient {
                     "CreateWithRefs operation can only be executed within a batch changeset. Use execute_batch() instead."
                 ))
             }
-            Operation::Update { entity, id, data } => {
-                self.update_record(entity, id, data, resilience).await
+            Operation::Update {
+                entity,
+                id,
+                data,
+                if_match,
+            } => {
+                self.update_record(entity, id, data, if_match.as_deref(), resilience)
+                    .await
             }
             Operation::Delete { entity, id } => self.delete_record(entity, id, resilience).await,
             Operation::Upsert {
@@ -272,7 +356,15 @@ impl DynamicsClient {
             Operation::DeleteAttribute { entity, attribute } => {
                 self.delete_attribute(entity, attribute, resilience).await
             }
+            Operation::CreateGlobalOptionSet {
+                option_set_data,
+                solution_name,
+            } => {
+                self.create_global_option_set(option_set_data, solution_name.as_deref(), resilience)
+                    .await
+            }
             Operation::PublishAllXml => self.publish_all_xml(resilience).await,
+            Operation::PublishXml { entities } => self.publish_xml(entities, resilience).await,
         }
     }
 
@@ -291,10 +383,29 @@ impl DynamicsClient {
             return Ok(vec![result]);
         }
 
-        self.execute_batch_request(operations, resilience).await
+        self.execute_batch_request(operations, resilience, false)
+            .await
+    }
+
+    /// Execute multiple operations as independent requests in one batch.
+    /// A failed operation doesn't stop or roll back the others; each gets its own result.
+    pub async fn execute_batch_continue_on_error(
+        &self,
+        operations: &[Operation],
+        resilience: &ResilienceConfig,
+    ) -> anyhow::Result<Vec<OperationResult>> {
+        if operations.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.execute_batch_request(operations, resilience, true)
+            .await
     }
 
     /// Execute an OData query
+    ///
+    /// HTTP failures come back as an error `QueryResult`; use
+    /// `QueryResult::fetch_error` to tell auth, throttling and not-found apart.
     pub async fn execute_query(&self, query: &Query) -> anyhow::Result<QueryResult> {
         let url = constants::entity_endpoint(&self.base_url, &query.entity);
 
@@ -318,6 +429,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.read)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header("Prefer", &prefer)
@@ -336,7 +448,7 @@ impl DynamicsClient {
         entity_name: &str,
         fetchxml: &str,
     ) -> anyhow::Result<Value> {
-        let _permit = self.apply_rate_limiting().await?;
+        let _permit = self.apply_entity_rate_limiting(entity_name).await?;
 
         let encoded_fetchxml = urlencoding::encode(fetchxml);
 
@@ -355,6 +467,7 @@ impl DynamicsClient {
                         encoded_fetchxml
                     ))
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.read)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header("OData-MaxVersion", headers::ODATA_VERSION)
@@ -413,6 +526,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.read)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header("OData-MaxVersion", headers::ODATA_VERSION)
@@ -453,6 +567,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(next_link)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.read)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header("Prefer", &prefer)
@@ -510,6 +625,11 @@ impl DynamicsClient {
         if !["GET", "POST", "PATCH", "DELETE"].contains(&method_upper.as_str()) {
             return Err(anyhow::anyhow!("Unsupported HTTP method: {}", method));
         }
+        let timeout = if method_upper == "GET" {
+            self.timeouts.read
+        } else {
+            self.timeouts.write
+        };
 
         // Execute request with retry policy
         let response = self
@@ -525,6 +645,7 @@ impl DynamicsClient {
 
                 request = request
                     .bearer_auth(&self.access_token)
+                    .timeout(timeout)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header("OData-MaxVersion", headers::ODATA_VERSION);
@@ -567,6 +688,59 @@ impl DynamicsClient {
         }
     }
 
+    /// Invoke a Dataverse action (built-in or custom) and return its parsed response
+    ///
+    /// # Arguments
+    /// * `name` - Action name (e.g., "PublishAllXml", "new_RecalculateTotals")
+    /// * `bound_entity` - `(entity set, record id)` for bound actions, `None` for unbound ones
+    /// * `params` - JSON object with the action parameters (`Value::Null` for none)
+    ///
+    /// # Returns
+    /// The action's JSON response, or an empty object for actions without a return value
+    pub async fn call_action(
+        &self,
+        name: &str,
+        bound_entity: Option<(&str, &str)>,
+        params: &Value,
+    ) -> anyhow::Result<Value> {
+        let (url, body) = action_request(&self.base_url, name, bound_entity, params)?;
+        let correlation_id = uuid::Uuid::new_v4().to_string();
+
+        let _permit = match bound_entity {
+            Some((entity_set, _)) => self.apply_entity_rate_limiting(entity_set).await?,
+            None => self.apply_rate_limiting().await?,
+        };
+
+        let response = self
+            .retry_policy
+            .execute(|| async {
+                self.http_client
+                    .post(&url)
+                    .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.write)
+                    .header("Accept", headers::CONTENT_TYPE_JSON)
+                    .header("Content-Type", headers::CONTENT_TYPE_JSON)
+                    .header("OData-Version", headers::ODATA_VERSION)
+                    .header("OData-MaxVersion", headers::ODATA_VERSION)
+                    .header(headers::X_CORRELATION_ID, &correlation_id)
+                    .json(&body)
+                    .send()
+                    .await
+            })
+            .await?;
+
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            anyhow::bail!("Action '{}' failed with status {}: {}", name, status, text);
+        }
+        if text.trim().is_empty() {
+            return Ok(serde_json::json!({}));
+        }
+        serde_json::from_str(&text)
+            .map_err(|e| anyhow::anyhow!("Failed to parse response of action '{}': {}", name, e))
+    }
+
     /// Create a new record
     async fn create_record(
         &self,
@@ -582,7 +756,7 @@ impl DynamicsClient {
         let mut context = logger.start_operation("create", entity, &correlation_id);
 
         // Apply rate limiting before making the request
-        let _permit = self.apply_rate_limiting().await?;
+        let _permit = self.apply_entity_rate_limiting(entity).await?;
 
         // Log request details
         let mut request_headers = HashMap::new();
@@ -606,13 +780,14 @@ impl DynamicsClient {
 
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
         let request_start = std::time::Instant::now();
-        let bypass_headers = build_bypass_headers(&resilience.bypass);
+        let bypass_headers = build_write_headers(resilience);
         let response = retry_policy
             .execute(|| async {
                 let mut request = self
                     .http_client
                     .post(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.write)
                     .header("Content-Type", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header("Prefer", headers::PREFER_RETURN_REPRESENTATION)
@@ -670,25 +845,27 @@ impl DynamicsClient {
         entity: &str,
         id: &str,
         data: &Value,
+        if_match: Option<&str>,
         resilience: &ResilienceConfig,
     ) -> anyhow::Result<OperationResult> {
         let url = constants::entity_record_endpoint(&self.base_url, entity, id);
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
         // Apply rate limiting before making the request
-        let _permit = self.apply_rate_limiting().await?;
+        let _permit = self.apply_entity_rate_limiting(entity).await?;
 
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
-        let bypass_headers = build_bypass_headers(&resilience.bypass);
+        let bypass_headers = build_write_headers(resilience);
         let response = retry_policy
             .execute(|| async {
                 let mut request = self
                     .http_client
                     .patch(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.write)
                     .header("Content-Type", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
-                    .header("If-Match", headers::IF_MATCH_ANY)
+                    .header("If-Match", if_match.unwrap_or(headers::IF_MATCH_ANY))
                     .header("Prefer", headers::PREFER_RETURN_REPRESENTATION)
                     .header(headers::X_CORRELATION_ID, &correlation_id);
 
@@ -706,6 +883,7 @@ impl DynamicsClient {
                 entity: entity.to_string(),
                 id: id.to_string(),
                 data: data.clone(),
+                if_match: if_match.map(|s| s.to_string()),
             },
             response,
         )
@@ -723,16 +901,17 @@ impl DynamicsClient {
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
         // Apply rate limiting before making the request
-        let _permit = self.apply_rate_limiting().await?;
+        let _permit = self.apply_entity_rate_limiting(entity).await?;
 
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
-        let bypass_headers = build_bypass_headers(&resilience.bypass);
+        let bypass_headers = build_operation_headers(resilience);
         let response = retry_policy
             .execute(|| async {
                 let mut request = self
                     .http_client
                     .delete(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.write)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header(headers::X_CORRELATION_ID, &correlation_id);
 
@@ -768,16 +947,17 @@ impl DynamicsClient {
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
         // Apply rate limiting before making the request
-        let _permit = self.apply_rate_limiting().await?;
+        let _permit = self.apply_entity_rate_limiting(entity).await?;
 
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
-        let bypass_headers = build_bypass_headers(&resilience.bypass);
+        let bypass_headers = build_write_headers(resilience);
         let response = retry_policy
             .execute(|| async {
                 let mut request = self
                     .http_client
                     .patch(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.write)
                     .header("Content-Type", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header("Prefer", headers::PREFER_RETURN_REPRESENTATION)
@@ -821,20 +1001,21 @@ impl DynamicsClient {
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
         // Apply rate limiting before making the request
-        let _permit = self.apply_rate_limiting().await?;
+        let _permit = self.apply_entity_rate_limiting(entity).await?;
 
         let body = serde_json::json!({
             "@odata.id": target_ref
         });
 
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
-        let bypass_headers = build_bypass_headers(&resilience.bypass);
+        let bypass_headers = build_operation_headers(resilience);
         let response = retry_policy
             .execute(|| async {
                 let mut request = self
                     .http_client
                     .post(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.write)
                     .header("Content-Type", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header(headers::X_CORRELATION_ID, &correlation_id);
@@ -877,16 +1058,17 @@ impl DynamicsClient {
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
         // Apply rate limiting before making the request
-        let _permit = self.apply_rate_limiting().await?;
+        let _permit = self.apply_entity_rate_limiting(entity).await?;
 
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
-        let bypass_headers = build_bypass_headers(&resilience.bypass);
+        let bypass_headers = build_operation_headers(resilience);
         let response = retry_policy
             .execute(|| async {
                 let mut request = self
                     .http_client
                     .delete(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.write)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header(headers::X_CORRELATION_ID, &correlation_id);
 
@@ -934,6 +1116,7 @@ impl DynamicsClient {
                     .http_client
                     .post(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.schema)
                     .header("Content-Type", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header(headers::X_CORRELATION_ID, &correlation_id);
@@ -978,6 +1161,7 @@ impl DynamicsClient {
                 self.http_client
                     .put(&url) // Schema updates use PUT, not PATCH
                     .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.schema)
                     .header("Content-Type", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header(headers::X_CORRELATION_ID, &correlation_id)
@@ -1017,6 +1201,7 @@ impl DynamicsClient {
                 self.http_client
                     .delete(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.schema)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header(headers::X_CORRELATION_ID, &correlation_id)
                     .send()
@@ -1034,6 +1219,50 @@ impl DynamicsClient {
         .await
     }
 
+    /// Create a global (shared) option set
+    async fn create_global_option_set(
+        &self,
+        option_set_data: &Value,
+        solution_name: Option<&str>,
+        resilience: &ResilienceConfig,
+    ) -> anyhow::Result<OperationResult> {
+        let url = constants::global_option_sets_endpoint(&self.base_url);
+        let correlation_id = uuid::Uuid::new_v4().to_string();
+
+        // Apply rate limiting before making the request
+        let _permit = self.apply_rate_limiting().await?;
+
+        let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
+        let response = retry_policy
+            .execute(|| async {
+                let mut request = self
+                    .http_client
+                    .post(&url)
+                    .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.schema)
+                    .header("Content-Type", headers::CONTENT_TYPE_JSON)
+                    .header("OData-Version", headers::ODATA_VERSION)
+                    .header(headers::X_CORRELATION_ID, &correlation_id);
+
+                // Add solution header if provided
+                if let Some(solution) = solution_name {
+                    request = request.header("MSCRM.SolutionUniqueName", solution);
+                }
+
+                request.json(option_set_data).send().await
+            })
+            .await?;
+
+        self.parse_response(
+            Operation::CreateGlobalOptionSet {
+                option_set_data: option_set_data.clone(),
+                solution_name: solution_name.map(|s| s.to_string()),
+            },
+            response,
+        )
+        .await
+    }
+
     /// Publish all customizations to make schema changes active
     async fn publish_all_xml(
         &self,
@@ -1051,6 +1280,7 @@ impl DynamicsClient {
                 self.http_client
                     .post(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.schema)
                     .header("Content-Type", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header(headers::X_CORRELATION_ID, &correlation_id)
@@ -1063,28 +1293,79 @@ impl DynamicsClient {
             .await
     }
 
+    /// Publish customizations for the given entities only
+    async fn publish_xml(
+        &self,
+        entities: &[String],
+        resilience: &ResilienceConfig,
+    ) -> anyhow::Result<OperationResult> {
+        let url = constants::publish_xml_endpoint(&self.base_url);
+        let correlation_id = uuid::Uuid::new_v4().to_string();
+        let body = serde_json::json!({
+            "ParameterXml": Operation::publish_parameter_xml(entities)
+        });
+
+        // Apply rate limiting before making the request
+        let _permit = self.apply_rate_limiting().await?;
+
+        let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
+        let response = retry_policy
+            .execute(|| async {
+                self.http_client
+                    .post(&url)
+                    .bearer_auth(&self.access_token)
+                    .timeout(resilience.timeout.schema)
+                    .header("Content-Type", headers::CONTENT_TYPE_JSON)
+                    .header("OData-Version", headers::ODATA_VERSION)
+                    .header(headers::X_CORRELATION_ID, &correlation_id)
+                    .json(&body)
+                    .send()
+                    .await
+            })
+            .await?;
+
+        self.parse_response(
+            Operation::PublishXml {
+                entities: entities.to_vec(),
+            },
+            response,
+        )
+        .await
+    }
+
     /// Execute operations using the $batch endpoint
     async fn execute_batch_request(
         &self,
         operations: &[Operation],
         resilience: &ResilienceConfig,
+        continue_on_error: bool,
     ) -> anyhow::Result<Vec<OperationResult>> {
         let url = constants::batch_endpoint(&self.base_url);
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
-        // Apply rate limiting before making the request
-        let _permit = self.apply_rate_limiting().await?;
+        // Apply rate limiting before making the request, once per entity with an override
+        let _permit = self.concurrency_limiter.acquire().await;
+        let entities: std::collections::BTreeSet<&str> =
+            operations.iter().map(|op| op.entity()).collect();
+        self.rate_limiter.acquire_for_entities(entities).await;
 
         // Build bypass headers to include on each operation within the batch
-        let bypass_headers = build_bypass_headers(&resilience.bypass);
+        let bypass_headers = build_operation_headers(resilience);
+        let timeout = resilience.timeout.for_operations(operations);
 
         // Build the batch request using the proper builder
-        let batch_request = BatchRequestBuilder::new(&self.base_url)
+        let builder = BatchRequestBuilder::new(&self.base_url)
             .with_bypass_headers(bypass_headers.clone())
-            .add_changeset(operations)
-            .build();
+            .suppress_duplicate_detection(resilience.bypass.suppress_duplicate_detection)
+            .continue_on_error(continue_on_error);
+        let batch_request = if continue_on_error {
+            builder.add_requests(operations).build()
+        } else {
+            builder.add_changeset(operations).build()
+        };
 
         let content_type = batch_request.content_type().to_string();
+        let prefer = batch_request.prefer().map(str::to_string);
         let body = batch_request.body().to_string();
 
         log::debug!(
@@ -1107,10 +1388,15 @@ impl DynamicsClient {
                     .http_client
                     .post(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(timeout)
                     .header("Content-Type", content_type.clone())
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header(headers::X_CORRELATION_ID, &correlation_id);
 
+                if let Some(prefer) = &prefer {
+                    request = request.header("Prefer", prefer);
+                }
+
                 // Apply bypass headers if configured
                 for (name, value) in &bypass_headers {
                     request = request.header(*name, value);
@@ -1298,7 +1584,9 @@ impl DynamicsClient {
                 error: None,
                 status_code: Some(status_code),
                 headers,
-            })
+                id: None,
+            }
+            .with_created_id())
         } else {
             let error_text = response
                 .text()
@@ -1318,9 +1606,10 @@ impl DynamicsClient {
                 operation,
                 success: false,
                 data: None,
-                error: Some(error_text),
+                error: Some(OperationResult::failure_message(status_code, error_text)),
                 status_code: Some(status_code),
                 headers,
+                id: None,
             })
         }
     }
@@ -1389,6 +1678,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&metadata_url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
                     .header("Accept", "application/xml")
                     .header("OData-Version", headers::ODATA_VERSION)
                     .send()
@@ -1605,6 +1895,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .send()
@@ -1673,6 +1964,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .send()
@@ -1817,6 +2109,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .send()
@@ -1880,6 +2173,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .send()
@@ -1915,6 +2209,91 @@ impl DynamicsClient {
         }
     }
 
+    /// Fetch global option sets referenced by an entity's choice attributes
+    /// Returns a HashMap keyed by attribute logical_name containing the expanded
+    /// GlobalOptionSet JSON; attributes with local option sets are omitted
+    pub async fn fetch_attribute_global_option_sets(
+        &self,
+        entity_name: &str,
+    ) -> anyhow::Result<HashMap<String, Value>> {
+        let mut option_sets = HashMap::new();
+
+        for cast in [
+            "PicklistAttributeMetadata",
+            "MultiSelectPicklistAttributeMetadata",
+        ] {
+            let url = format!(
+                "{}/Microsoft.Dynamics.CRM.{}?$select=LogicalName&$expand=GlobalOptionSet",
+                constants::entity_attributes_endpoint(&self.base_url, entity_name),
+                cast
+            );
+            let json = self.fetch_metadata_json(&url).await?;
+
+            for attr in json["value"].as_array().into_iter().flatten() {
+                let Some(logical_name) = attr["LogicalName"].as_str() else {
+                    continue;
+                };
+                let option_set = &attr["GlobalOptionSet"];
+                if option_set["IsGlobal"].as_bool() == Some(true) {
+                    option_sets.insert(logical_name.to_string(), option_set.clone());
+                }
+            }
+        }
+
+        Ok(option_sets)
+    }
+
+    /// Fetch the names of all global option sets in the environment
+    pub async fn fetch_global_option_set_names(&self) -> anyhow::Result<HashSet<String>> {
+        let url = format!(
+            "{}?$select=Name",
+            constants::global_option_sets_endpoint(&self.base_url)
+        );
+        let json = self.fetch_metadata_json(&url).await?;
+
+        Ok(json["value"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|set| set["Name"].as_str().map(|name| name.to_string()))
+            .collect())
+    }
+
+    /// GET a metadata endpoint and return the JSON body
+    async fn fetch_metadata_json(&self, url: &str) -> anyhow::Result<Value> {
+        // Apply rate limiting before making the request
+        let _permit = self.apply_rate_limiting().await?;
+
+        let response = self
+            .retry_policy
+            .execute(|| async {
+                self.http_client
+                    .get(url)
+                    .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
+                    .header("Accept", headers::CONTENT_TYPE_JSON)
+                    .header("OData-Version", headers::ODATA_VERSION)
+                    .send()
+                    .await
+            })
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(response.json().await?)
+        } else {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            anyhow::bail!(
+                "Metadata fetch failed with status {}: {}",
+                status,
+                error_text
+            )
+        }
+    }
+
     /// Fetch entity fields by combining both metadata sources
     /// - XML metadata: NavigationProperties (relationships)
     /// - EntityDefinitions API: Attributes with proper lookup targets
@@ -2058,6 +2437,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .send()
@@ -2120,6 +2500,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .send()
@@ -2182,6 +2563,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .send()
@@ -2256,6 +2638,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .send()
@@ -2341,6 +2724,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.schema)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .send()
@@ -2567,6 +2951,39 @@ impl DynamicsClient {
         columns
     }
 
+    /// Call the `WhoAmI` function to confirm the credentials work
+    ///
+    /// Returns the ids of the signed-in user, their business unit and the organization.
+    pub async fn who_am_i(&self) -> anyhow::Result<WhoAmI> {
+        let _permit = self.apply_rate_limiting().await?;
+        let url = constants::who_am_i_endpoint(&self.base_url);
+
+        let response = self
+            .retry_policy
+            .execute(|| async {
+                self.http_client
+                    .get(&url)
+                    .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.read)
+                    .header("Accept", headers::CONTENT_TYPE_JSON)
+                    .header("OData-Version", headers::ODATA_VERSION)
+                    .send()
+                    .await
+            })
+            .await?;
+
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(
+                FetchError::from_response(Some(status.as_u16()), &HashMap::new(), &text).into(),
+            );
+        }
+
+        serde_json::from_str(&text)
+            .map_err(|e| anyhow::anyhow!("Failed to parse WhoAmI response: {}", e))
+    }
+
     /// Fetch a single record by ID
     /// Returns the full record as JSON with all fields and formatted values
     pub async fn fetch_record_by_id(
@@ -2574,7 +2991,7 @@ impl DynamicsClient {
         entity_name: &str,
         record_id: &str,
     ) -> anyhow::Result<serde_json::Value> {
-        let _permit = self.apply_rate_limiting().await?;
+        let _permit = self.apply_entity_rate_limiting(entity_name).await?;
 
         // Pluralize entity name for the endpoint
         let plural_entity = super::pluralization::pluralize_entity_name(entity_name);
@@ -2595,6 +3012,7 @@ impl DynamicsClient {
                 self.http_client
                     .get(&url)
                     .bearer_auth(&self.access_token)
+                    .timeout(self.timeouts.read)
                     .header("Accept", headers::CONTENT_TYPE_JSON)
                     .header("OData-Version", headers::ODATA_VERSION)
                     .header(
@@ -2632,3 +3050,162 @@ impl DynamicsClient {
         Ok(record)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_impersonated_user_id_sent_as_caller_id() {
+        let user_id = "3f2504e0-4f89-11d3-9a0c-0305e82c3301";
+        let resilience = ResilienceConfig::builder()
+            .impersonate_user(user_id)
+            .build();
+
+        let write_headers = build_write_headers(&resilience);
+        assert!(write_headers.contains(&(headers::CALLER_ID, user_id.to_string())));
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .with_bypass_headers(build_operation_headers(&resilience))
+            .add_operation(&Operation::create("contacts", serde_json::json!({})))
+            .build();
+        assert!(
+            batch
+                .body()
+                .contains(&format!("MSCRMCallerID: {}", user_id))
+        );
+
+        let default_headers = build_write_headers(&ResilienceConfig::default());
+        assert!(
+            default_headers
+                .iter()
+                .all(|(name, _)| *name != headers::CALLER_ID)
+        );
+    }
+
+    #[test]
+    fn test_unbound_action_request_url_and_body() {
+        let params = serde_json::json!({
+            "Target": {"accountid": "3f2504e0-4f89-11d3-9a0c-0305e82c3301", "@odata.type": "Microsoft.Dynamics.CRM.account"},
+            "Reason": "Merged",
+        });
+
+        let (url, body) = action_request(
+            "https://test.crm.dynamics.com",
+            "new_ArchiveRecord",
+            None,
+            &params,
+        )
+        .unwrap();
+        assert_eq!(
+            url,
+            "https://test.crm.dynamics.com/api/data/v9.2/new_ArchiveRecord"
+        );
+        assert_eq!(body, params);
+
+        let (bound_url, empty_body) = action_request(
+            "https://test.crm.dynamics.com",
+            "new_Recalculate",
+            Some(("accounts", "123")),
+            &Value::Null,
+        )
+        .unwrap();
+        assert_eq!(
+            bound_url,
+            "https://test.crm.dynamics.com/api/data/v9.2/accounts(123)/Microsoft.Dynamics.CRM.new_Recalculate"
+        );
+        assert_eq!(empty_body, serde_json::json!({}));
+
+        assert!(
+            action_request(
+                "https://test.crm.dynamics.com",
+                "WinOpportunity",
+                None,
+                &serde_json::json!([1, 2])
+            )
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_is_classified_and_retried() {
+        use crate::api::resilience::RetryableError;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::time::Duration;
+
+        // Server that accepts connections but never responds
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let connections = Arc::new(AtomicU32::new(0));
+        let connections_clone = connections.clone();
+        tokio::spawn(async move {
+            let mut open = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                connections_clone.fetch_add(1, Ordering::SeqCst);
+                open.push(socket);
+            }
+        });
+
+        let timeouts = TimeoutConfig {
+            read: Duration::from_millis(50),
+            write: Duration::from_millis(50),
+            schema: Duration::from_secs(60),
+        };
+        let operation = Operation::create("accounts", serde_json::json!({ "name": "Slow" }));
+        assert_eq!(
+            timeouts.for_operation(&operation),
+            Duration::from_millis(50)
+        );
+        assert_eq!(
+            timeouts.for_operation(&Operation::publish_all_xml()),
+            Duration::from_secs(60)
+        );
+
+        let resilience = ResilienceConfig::builder()
+            .retry_config(RetryConfig {
+                max_attempts: 2,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(10),
+                backoff_multiplier: 2.0,
+                jitter: false,
+            })
+            .timeout_config(timeouts)
+            .build();
+        // No pooling so each attempt opens its own connection
+        let http_client = reqwest::Client::builder()
+            .pool_max_idle_per_host(0)
+            .build()
+            .unwrap();
+        let client = DynamicsClient::with_custom_client(base_url, "token".to_string(), http_client);
+
+        let error = client.execute(&operation, &resilience).await.unwrap_err();
+
+        let reqwest_error = error.downcast_ref::<reqwest::Error>().unwrap();
+        assert_eq!(
+            RetryableError::from_reqwest_error(reqwest_error),
+            RetryableError::Timeout
+        );
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_parse_who_am_i_response() {
+        let body = r#"{
+            "@odata.context": "https://test.crm.dynamics.com/api/data/v9.2/$metadata#Microsoft.Dynamics.CRM.WhoAmIResponse",
+            "BusinessUnitId": "6f202e6c-e471-ec11-8941-000d3a2b2dd7",
+            "UserId": "88057198-a9b1-ec11-9840-00224823e9e3",
+            "OrganizationId": "e34c95a5-f34c-430c-a05e-a23437e5b9fa"
+        }"#;
+
+        let who: WhoAmI = serde_json::from_str(body).unwrap();
+        assert_eq!(
+            who,
+            WhoAmI {
+                user_id: "88057198-a9b1-ec11-9840-00224823e9e3".to_string(),
+                business_unit_id: "6f202e6c-e471-ec11-8941-000d3a2b2dd7".to_string(),
+                organization_id: "e34c95a5-f34c-430c-a05e-a23437e5b9fa".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! In-flight request coalescing
+//!
+//! When several tasks ask for the same resource at the same time (e.g. the
+//! transfer preview fetching metadata for one entity from multiple code paths),
+//! only the first caller runs the fetch; the others wait for and share its
+//! result. Entries are dropped once the fetch completes, so later callers start
+//! a fresh fetch (normally served by the SQLite cache the first one wrote).
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+
+/// Coalesces concurrent async calls that share the same key
+pub struct InFlight<K, V> {
+    pending: Mutex<HashMap<K, Arc<OnceCell<V>>>>,
+}
+
+impl<K, V> Default for InFlight<K, V> {
+    fn default() -> Self {
+        Self {
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K, V> InFlight<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `fetch` for `key`, or join a fetch for the same key that is already running
+    pub async fn run<F, Fut>(&self, key: K, fetch: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V>,
+    {
+        let cell = {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            pending
+                .entry(key.clone())
+                .or_insert_with(|| Arc::new(OnceCell::new()))
+                .clone()
+        };
+
+        let value = cell.get_or_init(fetch).await.clone();
+
+        // Only remove the entry if nobody replaced it in the meantime
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        if pending
+            .get(&key)
+            .is_some_and(|current| Arc::ptr_eq(current, &cell))
+        {
+            pending.remove(&key);
+        }
+
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_concurrent_calls_share_one_fetch() {
+        let inflight: InFlight<(String, String), Result<usize, String>> = InFlight::new();
+        let calls = AtomicUsize::new(0);
+        let key = ("dev".to_string(), "account".to_string());
+
+        let fetch = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(42)
+        };
+
+        let (a, b) = tokio::join!(
+            inflight.run(key.clone(), fetch),
+            inflight.run(key.clone(), fetch)
+        );
+
+        assert_eq!(a, Ok(42));
+        assert_eq!(b, Ok(42));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Completed entries are dropped, so a later call fetches again
+        inflight.run(key, fetch).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
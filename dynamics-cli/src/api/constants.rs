@@ -37,6 +37,9 @@ pub mod headers {
     /// Prefer header for minimal metadata
     pub const PREFER_MINIMAL_METADATA: &str = "odata.metadata=minimal";
 
+    /// Prefer header asking $batch to keep going after a failed request
+    pub const PREFER_CONTINUE_ON_ERROR: &str = "odata.continue-on-error";
+
     /// Header to get entity ID on create
     pub const PREFER_INCLUDE_ANNOTATIONS: &str = "odata.include-annotations=\"*\"";
 
@@ -56,6 +59,12 @@ pub mod headers {
 
     /// Bypass Power Automate flows triggered by Dataverse events (no privilege required)
     pub const SUPPRESS_POWER_AUTOMATE: &str = "MSCRM.SuppressCallbackRegistrationExpanderJob";
+
+    /// Skip duplicate detection rules on create and update
+    pub const SUPPRESS_DUPLICATE_DETECTION: &str = "MSCRM.SuppressDuplicateDetection";
+
+    /// Run the request as another user (systemuser id)
+    pub const CALLER_ID: &str = "MSCRMCallerID";
 }
 
 /// HTTP methods for operations
@@ -118,8 +127,52 @@ pub fn entity_attribute_endpoint(base_url: &str, entity: &str, attribute: &str)
     )
 }
 
+/// Build global option set endpoint
+/// GET/POST /GlobalOptionSetDefinitions
+pub fn global_option_sets_endpoint(base_url: &str) -> String {
+    format!("{}{}/GlobalOptionSetDefinitions", base_url, api_path())
+}
+
 /// Build PublishAllXml endpoint
 /// POST /PublishAllXml
 pub fn publish_all_xml_endpoint(base_url: &str) -> String {
     format!("{}{}/PublishAllXml", base_url, api_path())
 }
+
+/// Build PublishXml endpoint
+/// POST /PublishXml
+pub fn publish_xml_endpoint(base_url: &str) -> String {
+    format!("{}{}/PublishXml", base_url, api_path())
+}
+
+/// Build WhoAmI endpoint
+/// GET /WhoAmI
+pub fn who_am_i_endpoint(base_url: &str) -> String {
+    format!("{}{}/WhoAmI", base_url, api_path())
+}
+
+/// Build a Dataverse action endpoint, optionally bound to a record
+/// POST /{action} or POST /{entity_set}({id})/Microsoft.Dynamics.CRM.{action}
+///
+/// Bound action names are qualified with the `Microsoft.Dynamics.CRM` namespace
+/// unless they already carry a namespace.
+pub fn action_endpoint(base_url: &str, action: &str, bound_entity: Option<(&str, &str)>) -> String {
+    match bound_entity {
+        None => format!("{}{}/{}", base_url, api_path(), action),
+        Some((entity_set, id)) => {
+            let qualified = if action.contains('.') {
+                action.to_string()
+            } else {
+                format!("Microsoft.Dynamics.CRM.{}", action)
+            };
+            format!(
+                "{}{}/{}({})/{}",
+                base_url,
+                api_path(),
+                entity_set,
+                id,
+                qualified
+            )
+        }
+    }
+}
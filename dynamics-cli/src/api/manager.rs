@@ -1,6 +1,12 @@
 use super::auth::AuthManager;
 use super::client::DynamicsClient;
+use super::coalesce::InFlight;
+use super::metadata::EntityMetadata;
 use super::models::{CredentialSet, Environment, TokenInfo};
+use super::resilience::{
+    ConcurrencyConfig, ConcurrencyPartitions, ConcurrencyStats, RateLimitConfig,
+    RateLimitPartitions, TimeoutConfig,
+};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -12,6 +18,12 @@ pub struct ClientManager {
     environments: Arc<RwLock<HashMap<String, Environment>>>,
     current_env: Arc<RwLock<Option<String>>>,
     tokens: Arc<RwLock<HashMap<String, TokenInfo>>>,
+    /// Metadata fetches currently running, keyed by (environment, entity)
+    metadata_fetches: InFlight<(String, String), Result<EntityMetadata, String>>,
+    /// Concurrency limits per environment, shared by all clients for that environment
+    concurrency: ConcurrencyPartitions,
+    /// Rate limits per environment, shared by all clients for that environment
+    rate_limits: RateLimitPartitions,
 }
 
 impl ClientManager {
@@ -53,6 +65,9 @@ impl ClientManager {
             environments: Arc::new(RwLock::new(environments)),
             current_env: Arc::new(RwLock::new(Some(".env".to_string()))),
             tokens: Arc::new(RwLock::new(HashMap::new())),
+            metadata_fetches: InFlight::new(),
+            concurrency: ConcurrencyPartitions::new(ConcurrencyConfig::default()),
+            rate_limits: RateLimitPartitions::new(RateLimitConfig::default()),
         })
     }
 
@@ -112,6 +127,9 @@ impl ClientManager {
             environments: Arc::new(RwLock::new(environments)),
             current_env: Arc::new(RwLock::new(current_env)),
             tokens: Arc::new(RwLock::new(tokens)),
+            metadata_fetches: InFlight::new(),
+            concurrency: ConcurrencyPartitions::new(ConcurrencyConfig::load_from_options().await),
+            rate_limits: RateLimitPartitions::new(RateLimitConfig::load_from_options().await),
         })
     }
 
@@ -455,10 +473,68 @@ impl ClientManager {
         // Get or refresh token with automatic authentication
         let token_info = self.get_or_refresh_token(env_name).await?;
 
-        Ok(DynamicsClient::new(
-            environment.host.clone(),
-            token_info.access_token,
-        ))
+        let timeouts = TimeoutConfig::load_from_options().await;
+        Ok(
+            DynamicsClient::new(environment.host.clone(), token_info.access_token)
+                .with_timeouts(timeouts)
+                .with_rate_limiter(self.rate_limits.limiter(env_name))
+                .with_concurrency_limiter(self.concurrency.limiter(env_name)),
+        )
+    }
+
+    /// Concurrency statistics for an environment's partition
+    pub fn concurrency_stats(&self, env_name: &str) -> Option<ConcurrencyStats> {
+        self.concurrency.stats(env_name)
+    }
+
+    /// Fetch field metadata, entity set name and primary ID for an entity from the API
+    ///
+    /// Concurrent calls for the same environment and entity share a single set of
+    /// API requests. The result is written to the metadata cache before it is returned.
+    pub async fn fetch_entity_metadata_shared(
+        &self,
+        env_name: &str,
+        entity_name: &str,
+    ) -> Result<EntityMetadata, String> {
+        let key = (env_name.to_string(), entity_name.to_string());
+        self.metadata_fetches
+            .run(key, || async {
+                let client = self
+                    .get_client(env_name)
+                    .await
+                    .map_err(|e| format!("Failed to get client for {}: {}", env_name, e))?;
+
+                let fields = client
+                    .fetch_entity_fields_alt(entity_name)
+                    .await
+                    .map_err(|e| {
+                        format!("Failed to fetch field metadata for {}: {}", entity_name, e)
+                    })?;
+
+                let entity_info = client
+                    .fetch_entity_metadata_info(entity_name)
+                    .await
+                    .map_err(|e| {
+                        format!("Failed to fetch entity info for {}: {}", entity_name, e)
+                    })?;
+
+                let metadata = EntityMetadata {
+                    fields,
+                    entity_set_name: Some(entity_info.entity_set_name),
+                    primary_id_attribute: Some(entity_info.primary_id_attribute),
+                    ..Default::default()
+                };
+
+                if let Err(e) = crate::global_config()
+                    .set_entity_metadata_cache(env_name, entity_name, &metadata)
+                    .await
+                {
+                    log::warn!("[{}] Failed to cache metadata: {}", entity_name, e);
+                }
+
+                Ok(metadata)
+            })
+            .await
     }
 
     /// Get a configured DynamicsClient for the current environment
@@ -24,6 +24,25 @@ pub struct FieldMetadata {
     pub option_values: Vec<OptionSetValue>,
 }
 
+#[cfg(test)]
+impl FieldMetadata {
+    /// Plain optional string field, for test fixtures
+    pub fn string(logical_name: &str) -> Self {
+        Self {
+            logical_name: logical_name.to_string(),
+            schema_name: None,
+            display_name: None,
+            field_type: FieldType::String,
+            is_required: false,
+            is_primary_key: false,
+            max_length: None,
+            related_entity: None,
+            navigation_property_name: None,
+            option_values: vec![],
+        }
+    }
+}
+
 /// Field data types in Dynamics 365
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum FieldType {
@@ -7,6 +7,7 @@
 
 pub mod auth;
 pub mod client;
+pub mod coalesce;
 pub mod constants;
 pub mod manager;
 pub mod metadata;
@@ -23,11 +24,12 @@ pub use metadata::{
     EntityMetadata, FieldMetadata, FieldType, FormMetadata, RelationshipMetadata, RelationshipType,
     ViewMetadata, parse_entity_list, parse_entity_metadata,
 };
-pub use models::{CredentialSet, Environment, TokenInfo};
-pub use operations::{Operation, OperationResult, Operations};
-pub use query::{Filter, FilterValue, OrderBy, Query, QueryBuilder, QueryResult};
+pub use models::{CredentialSet, Environment, TokenInfo, WhoAmI};
+pub use operations::{ExecuteMultiple, Operation, OperationResult, Operations};
+pub use query::{FetchError, Filter, FilterValue, OrderBy, Query, QueryBuilder, QueryResult};
 pub use resilience::{
     ApiLogger, EntityMetrics, GlobalMetrics, LogLevel, MetricsCollector, MetricsSnapshot,
     MonitoringConfig, OperationContext, OperationMetrics, OperationTypeMetrics, RateLimitConfig,
     RateLimiter, RateLimiterStats, ResilienceConfig, RetryConfig, RetryPolicy, RetryableError,
+    TimeoutConfig,
 };
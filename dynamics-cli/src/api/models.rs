@@ -1,3 +1,4 @@
+use serde::Deserialize;
 use std::time::SystemTime;
 
 /// Environment configuration linking to credentials
@@ -40,3 +41,12 @@ pub struct TokenInfo {
     pub expires_at: SystemTime,
     pub refresh_token: Option<String>,
 }
+
+/// Identity of the signed-in user, as returned by the `WhoAmI` function
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WhoAmI {
+    pub user_id: String,
+    pub business_unit_id: String,
+    pub organization_id: String,
+}
@@ -18,6 +18,10 @@ pub struct BatchRequestBuilder {
     requests: Vec<BatchItem>,
     /// Bypass headers to apply to each operation in the batch
     bypass_headers: Vec<(String, String)>,
+    /// Keep executing independent requests after one fails
+    continue_on_error: bool,
+    /// Skip duplicate detection rules on creates and updates
+    suppress_duplicate_detection: bool,
 }
 
 /// Individual item in a batch request
@@ -53,6 +57,8 @@ impl BatchRequestBuilder {
             base_url: base_url.into(),
             requests: Vec::new(),
             bypass_headers: Vec::new(),
+            continue_on_error: false,
+            suppress_duplicate_detection: false,
         }
     }
 
@@ -68,6 +74,48 @@ impl BatchRequestBuilder {
         self
     }
 
+    /// Ask the server to continue past failed requests instead of stopping the batch
+    ///
+    /// Only affects requests added with `add_requests`; a changeset is atomic and
+    /// still rolls back as a whole.
+    pub fn continue_on_error(mut self, continue_on_error: bool) -> Self {
+        self.continue_on_error = continue_on_error;
+        self
+    }
+
+    /// Skip duplicate detection rules for records created or updated by this batch
+    ///
+    /// Must be set before operations are added.
+    pub fn suppress_duplicate_detection(mut self, suppress: bool) -> Self {
+        self.suppress_duplicate_detection = suppress;
+        self
+    }
+
+    /// Add operations as independent requests (non-transactional)
+    ///
+    /// Each operation succeeds or fails on its own. Content-ID references are not
+    /// available outside a changeset, so operations containing a `CreateWithRefs`
+    /// are added as a changeset instead.
+    pub fn add_requests(mut self, operations: &[Operation]) -> Self {
+        if operations
+            .iter()
+            .any(|op| matches!(op, Operation::CreateWithRefs { .. }))
+        {
+            return self.add_changeset(operations);
+        }
+
+        for (index, operation) in operations.iter().enumerate() {
+            let op = self.operation_to_changeset_operation(operation, (index + 1) as u32);
+            self.requests.push(BatchItem::DirectRequest {
+                method: op.method,
+                path: op.path,
+                headers: op.headers,
+                body: op.body,
+            });
+        }
+        self
+    }
+
     /// Add operations as a changeset (transactional)
     pub fn add_changeset(mut self, operations: &[Operation]) -> Self {
         if operations.is_empty() {
@@ -104,6 +152,18 @@ impl BatchRequestBuilder {
         headers
     }
 
+    /// Build headers for operations that write record data (create, update, upsert)
+    fn build_write_headers(&self, base_headers: Vec<(String, String)>) -> Vec<(String, String)> {
+        let mut op_headers = self.build_op_headers(base_headers);
+        if self.suppress_duplicate_detection {
+            op_headers.push((
+                headers::SUPPRESS_DUPLICATE_DETECTION.to_string(),
+                "true".to_string(),
+            ));
+        }
+        op_headers
+    }
+
     /// Convert an Operation to a ChangeSetOperation
     fn operation_to_changeset_operation(
         &self,
@@ -119,7 +179,7 @@ impl BatchRequestBuilder {
                     content_id,
                     method: methods::POST.to_string(),
                     path,
-                    headers: self.build_op_headers(vec![
+                    headers: self.build_write_headers(vec![
                         (
                             "Content-Type".to_string(),
                             headers::CONTENT_TYPE_JSON.to_string(),
@@ -153,7 +213,7 @@ impl BatchRequestBuilder {
                     content_id,
                     method: methods::POST.to_string(),
                     path,
-                    headers: self.build_op_headers(vec![
+                    headers: self.build_write_headers(vec![
                         (
                             "Content-Type".to_string(),
                             headers::CONTENT_TYPE_JSON.to_string(),
@@ -166,7 +226,12 @@ impl BatchRequestBuilder {
                     body: Some(body),
                 }
             }
-            Operation::Update { entity, id, data } => {
+            Operation::Update {
+                entity,
+                id,
+                data,
+                if_match,
+            } => {
                 let path = format!("{}/{}({})", constants::api_path(), entity, id);
                 let body = serde_json::to_string(data).unwrap_or_default();
 
@@ -174,12 +239,17 @@ impl BatchRequestBuilder {
                     content_id,
                     method: methods::PATCH.to_string(),
                     path,
-                    headers: self.build_op_headers(vec![
+                    headers: self.build_write_headers(vec![
                         (
                             "Content-Type".to_string(),
                             headers::CONTENT_TYPE_JSON.to_string(),
                         ),
-                        ("If-Match".to_string(), headers::IF_MATCH_ANY.to_string()),
+                        (
+                            "If-Match".to_string(),
+                            if_match
+                                .clone()
+                                .unwrap_or_else(|| headers::IF_MATCH_ANY.to_string()),
+                        ),
                         (
                             "Prefer".to_string(),
                             headers::PREFER_RETURN_REPRESENTATION.to_string(),
@@ -218,7 +288,7 @@ impl BatchRequestBuilder {
                     content_id,
                     method: methods::PATCH.to_string(),
                     path,
-                    headers: self.build_op_headers(vec![
+                    headers: self.build_write_headers(vec![
                         (
                             "Content-Type".to_string(),
                             headers::CONTENT_TYPE_JSON.to_string(),
@@ -369,6 +439,29 @@ impl BatchRequestBuilder {
                     body: None,
                 }
             }
+            Operation::CreateGlobalOptionSet {
+                option_set_data,
+                solution_name,
+            } => {
+                let path = format!("{}/GlobalOptionSetDefinitions", constants::api_path());
+                let body = serde_json::to_string(option_set_data).unwrap_or_default();
+
+                let mut op_headers = vec![(
+                    "Content-Type".to_string(),
+                    headers::CONTENT_TYPE_JSON.to_string(),
+                )];
+                if let Some(solution) = solution_name {
+                    op_headers.push(("MSCRM.SolutionUniqueName".to_string(), solution.clone()));
+                }
+
+                ChangeSetOperation {
+                    content_id,
+                    method: methods::POST.to_string(),
+                    path,
+                    headers: self.build_op_headers(op_headers),
+                    body: Some(body),
+                }
+            }
             Operation::PublishAllXml => {
                 let path = format!("{}/PublishAllXml", constants::api_path());
 
@@ -383,6 +476,24 @@ impl BatchRequestBuilder {
                     body: None,
                 }
             }
+            Operation::PublishXml { entities } => {
+                let path = format!("{}/PublishXml", constants::api_path());
+                let body = serde_json::json!({
+                    "ParameterXml": Operation::publish_parameter_xml(entities)
+                })
+                .to_string();
+
+                ChangeSetOperation {
+                    content_id,
+                    method: methods::POST.to_string(),
+                    path,
+                    headers: self.build_op_headers(vec![(
+                        "Content-Type".to_string(),
+                        headers::CONTENT_TYPE_JSON.to_string(),
+                    )]),
+                    body: Some(body),
+                }
+            }
         }
     }
 
@@ -471,6 +582,9 @@ impl BatchRequestBuilder {
 
         BatchRequest {
             content_type: format!("multipart/mixed; boundary=\"{}\"", self.batch_id),
+            prefer: self
+                .continue_on_error
+                .then(|| headers::PREFER_CONTINUE_ON_ERROR.to_string()),
             body,
         }
     }
@@ -490,6 +604,8 @@ impl BatchRequestBuilder {
 #[derive(Debug, Clone)]
 pub struct BatchRequest {
     pub content_type: String,
+    /// Prefer header for the outer $batch request, if any
+    pub prefer: Option<String>,
     pub body: String,
 }
 
@@ -499,6 +615,11 @@ impl BatchRequest {
         &self.content_type
     }
 
+    /// Get the Prefer header value for the outer request, if any
+    pub fn prefer(&self) -> Option<&str> {
+        self.prefer.as_deref()
+    }
+
     /// Get the request body
     pub fn body(&self) -> &str {
         &self.body
@@ -594,4 +715,93 @@ mod tests {
         );
         assert!(batch.body.contains("\"firstname\":\"Jane\""));
     }
+
+    #[test]
+    fn test_update_if_match_header() {
+        let conditional =
+            Operation::update_if_match("contacts", "123", json!({"lastname": "A"}), "W/\"42\"");
+        let unconditional = Operation::update("contacts", "456", json!({"lastname": "B"}));
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_operation(&conditional)
+            .add_operation(&unconditional)
+            .build();
+
+        assert!(batch.body.contains("If-Match: W/\"42\""));
+        assert!(batch.body.contains("If-Match: *"));
+    }
+
+    #[test]
+    fn test_suppress_duplicate_detection_header_on_create() {
+        let create = Operation::create("contacts", json!({"firstname": "John"}));
+
+        let suppressed = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .suppress_duplicate_detection(true)
+            .add_operation(&create)
+            .build();
+        assert!(
+            suppressed
+                .body
+                .contains("MSCRM.SuppressDuplicateDetection: true")
+        );
+
+        let default_batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_operation(&create)
+            .build();
+        assert!(
+            !default_batch
+                .body
+                .contains("MSCRM.SuppressDuplicateDetection")
+        );
+    }
+
+    #[test]
+    fn test_continue_on_error_requests() {
+        let operations = vec![
+            Operation::create("contacts", json!({"firstname": "John"})),
+            Operation::delete("contacts", "789-012"),
+        ];
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .continue_on_error(true)
+            .add_requests(&operations)
+            .build();
+
+        assert_eq!(batch.prefer(), Some("odata.continue-on-error"));
+        // Independent requests, no changeset wrapper
+        assert!(!batch.body.contains("changeset_"));
+        assert!(!batch.body.contains("Content-ID:"));
+        assert!(batch.body.contains("POST /api/data/v9.2/contacts HTTP/1.1"));
+        assert!(
+            batch
+                .body
+                .contains("DELETE /api/data/v9.2/contacts(789-012) HTTP/1.1")
+        );
+
+        let default_batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_changeset(&operations)
+            .build();
+        assert_eq!(default_batch.prefer(), None);
+
+        // Content-ID references only resolve within a changeset
+        let with_refs = vec![
+            Operation::create("contacts", json!({"firstname": "John"})),
+            Operation::CreateWithRefs {
+                entity: "tasks".to_string(),
+                data: json!({"subject": "Call"}),
+                content_id_refs: [(
+                    "regardingobjectid_contact@odata.bind".to_string(),
+                    "$1".to_string(),
+                )]
+                .into_iter()
+                .collect(),
+            },
+        ];
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_requests(&with_refs)
+            .build();
+        assert!(batch.body.contains("changeset_"));
+        assert!(batch.body.contains("Content-ID: 1"));
+        assert!(batch.body.contains("Content-ID: 2"));
+    }
 }
@@ -4,6 +4,8 @@
 
 pub mod builder;
 pub mod parser;
+pub mod splitter;
 
 pub use builder::{BatchRequest, BatchRequestBuilder};
 pub use parser::{BatchResponse, BatchResponseItem, BatchResponseParser};
+pub use splitter::BatchSplitter;
@@ -288,18 +288,23 @@ impl BatchResponseParser {
                     Self::extract_error_message(item.body.as_ref())
                         .or_else(|| item.body.clone())
                         .or_else(|| Some(format!("HTTP {}", item.status_code)))
+                        .map(|e| OperationResult::failure_message(item.status_code, e))
                 } else {
                     None
                 };
 
-                results.push(OperationResult {
-                    operation: operation.clone(),
-                    success: item.is_success,
-                    data,
-                    error,
-                    status_code: Some(item.status_code),
-                    headers: item.headers.clone(),
-                });
+                results.push(
+                    OperationResult {
+                        operation: operation.clone(),
+                        success: item.is_success,
+                        data,
+                        error,
+                        status_code: Some(item.status_code),
+                        headers: item.headers.clone(),
+                        id: None,
+                    }
+                    .with_created_id(),
+                );
             } else {
                 // No response found for this operation
                 results.push(OperationResult {
@@ -309,6 +314,7 @@ impl BatchResponseParser {
                     error: Some("No response found in batch".to_string()),
                     status_code: None,
                     headers: HashMap::new(),
+                    id: None,
                 });
             }
         }
@@ -455,4 +461,61 @@ OData-Version: 4.0
         let empty_msg = BatchResponseParser::extract_error_message(None);
         assert_eq!(empty_msg, None);
     }
+
+    #[test]
+    fn test_parse_continue_on_error_response() {
+        let response = r#"--batchresponse_c1bd45c1-dd81-470d-b897-e965846aad2f
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+
+HTTP/1.1 204 No Content
+OData-Version: 4.0
+OData-EntityId: https://test.crm.dynamics.com/api/data/v9.2/contacts(abc-1)
+
+
+--batchresponse_c1bd45c1-dd81-470d-b897-e965846aad2f
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+
+HTTP/1.1 404 Not Found
+Content-Type: application/json; odata.metadata=minimal
+OData-Version: 4.0
+
+{"error":{"code":"0x80040217","message":"contact With Id = 789-012 Does Not Exist"}}
+--batchresponse_c1bd45c1-dd81-470d-b897-e965846aad2f
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+
+HTTP/1.1 204 No Content
+OData-Version: 4.0
+
+
+--batchresponse_c1bd45c1-dd81-470d-b897-e965846aad2f--"#;
+
+        let operations = vec![
+            Operation::create("contacts", json!({"firstname": "John"})),
+            Operation::delete("contacts", "789-012"),
+            Operation::update("contacts", "345-678", json!({"lastname": "Doe"})),
+        ];
+
+        let results = BatchResponseParser::parse(response, &operations).unwrap();
+        assert_eq!(results.len(), 3);
+
+        assert!(results[0].is_success());
+        assert_eq!(results[0].status_code, Some(204));
+
+        assert!(results[1].is_error());
+        assert_eq!(results[1].status_code, Some(404));
+        assert_eq!(results[1].operation.operation_type(), "delete");
+        assert!(
+            results[1]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("Does Not Exist")
+        );
+
+        assert!(results[2].is_success());
+        assert_eq!(results[2].operation.operation_type(), "update");
+    }
 }
@@ -0,0 +1,159 @@
+//! Automatic batch splitting
+//!
+//! Packs operations into as few `$batch` requests as possible while staying
+//! under the service's per-batch request limit and a payload size ceiling.
+
+use crate::api::operations::Operation;
+
+/// Maximum number of requests Dynamics 365 accepts in a single `$batch`
+pub const MAX_BATCH_REQUESTS: usize = 1000;
+
+/// Default ceiling for the estimated batch body size (bytes)
+pub const DEFAULT_MAX_PAYLOAD_BYTES: usize = 4 * 1024 * 1024;
+
+/// Approximate per-request framing cost: boundary, MIME and HTTP headers
+const REQUEST_OVERHEAD_BYTES: usize = 256;
+
+/// Groups operations into batches under a count and payload-size ceiling
+#[derive(Debug, Clone, Copy)]
+pub struct BatchSplitter {
+    max_operations: usize,
+    max_payload_bytes: usize,
+}
+
+impl Default for BatchSplitter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BatchSplitter {
+    pub fn new() -> Self {
+        Self {
+            max_operations: MAX_BATCH_REQUESTS,
+            max_payload_bytes: DEFAULT_MAX_PAYLOAD_BYTES,
+        }
+    }
+
+    /// Limit the number of operations per batch (clamped to 1..=1000)
+    pub fn max_operations(mut self, max: usize) -> Self {
+        self.max_operations = max.clamp(1, MAX_BATCH_REQUESTS);
+        self
+    }
+
+    /// Limit the estimated payload size per batch
+    pub fn max_payload_bytes(mut self, max: usize) -> Self {
+        self.max_payload_bytes = max.max(1);
+        self
+    }
+
+    /// Estimate the serialized size of an operation inside a batch body
+    pub fn estimate_size(operation: &Operation) -> usize {
+        let body = serde_json::to_string(operation).map_or(0, |s| s.len());
+        body + REQUEST_OVERHEAD_BYTES
+    }
+
+    /// Split operations into batches, preserving order
+    ///
+    /// An operation larger than the payload ceiling gets a batch of its own.
+    pub fn split(&self, operations: Vec<Operation>) -> Vec<Vec<Operation>> {
+        self.split_changesets(operations.into_iter().map(|op| vec![op]).collect())
+            .into_iter()
+            .map(|batch| batch.into_iter().flatten().collect())
+            .collect()
+    }
+
+    /// Pack whole changesets into batches without splitting any changeset
+    ///
+    /// Changesets are atomic and may use Content-ID references between their
+    /// operations, so each one always lands in a single batch. A changeset
+    /// over either limit is placed alone rather than broken up.
+    pub fn split_changesets(&self, changesets: Vec<Vec<Operation>>) -> Vec<Vec<Vec<Operation>>> {
+        let mut batches = Vec::new();
+        let mut current: Vec<Vec<Operation>> = Vec::new();
+        let mut current_ops = 0;
+        let mut current_bytes = 0;
+
+        for changeset in changesets {
+            if changeset.is_empty() {
+                continue;
+            }
+            let ops = changeset.len();
+            let bytes: usize = changeset.iter().map(Self::estimate_size).sum();
+
+            if !current.is_empty()
+                && (current_ops + ops > self.max_operations
+                    || current_bytes + bytes > self.max_payload_bytes)
+            {
+                batches.push(std::mem::take(&mut current));
+                current_ops = 0;
+                current_bytes = 0;
+            }
+
+            current.push(changeset);
+            current_ops += ops;
+            current_bytes += bytes;
+        }
+
+        if !current.is_empty() {
+            batches.push(current);
+        }
+        batches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create(name: &str) -> Operation {
+        Operation::create("contacts", json!({ "firstname": name }))
+    }
+
+    #[test]
+    fn test_split_respects_payload_ceiling() {
+        let ops: Vec<Operation> = (0..10).map(|i| create(&format!("c{}", i))).collect();
+        let per_op = BatchSplitter::estimate_size(&ops[0]);
+
+        // Room for exactly three operations per batch
+        let splitter = BatchSplitter::new().max_payload_bytes(per_op * 3 + per_op / 2);
+        let batches = splitter.split(ops);
+
+        assert_eq!(batches.len(), 4);
+        assert_eq!(
+            batches.iter().map(|b| b.len()).collect::<Vec<_>>(),
+            vec![3, 3, 3, 1]
+        );
+    }
+
+    #[test]
+    fn test_split_respects_max_operations() {
+        let ops: Vec<Operation> = (0..2500).map(|i| create(&i.to_string())).collect();
+        let batches = BatchSplitter::new().split(ops);
+
+        assert_eq!(
+            batches.iter().map(|b| b.len()).collect::<Vec<_>>(),
+            vec![1000, 1000, 500]
+        );
+    }
+
+    #[test]
+    fn test_changesets_are_never_split() {
+        let changesets = vec![
+            vec![create("a"), create("b")],
+            vec![create("c"), create("d"), create("e")],
+            vec![create("f")],
+        ];
+
+        let batches = BatchSplitter::new()
+            .max_operations(4)
+            .split_changesets(changesets);
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].len(), 1);
+        assert_eq!(batches[0][0].len(), 2);
+        assert_eq!(batches[1].len(), 2);
+        assert_eq!(batches[1][0].len(), 3);
+    }
+}
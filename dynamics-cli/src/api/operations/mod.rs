@@ -8,5 +8,5 @@ pub mod operation;
 pub mod operations;
 
 pub use batch::{BatchRequest, BatchRequestBuilder, BatchResponseParser};
-pub use operation::{Operation, OperationResult};
-pub use operations::Operations;
+pub use operation::{Operation, OperationError, OperationResult, parse_entity_id_header};
+pub use operations::{ExecuteMultiple, Operations};
@@ -1,5 +1,6 @@
 //! Core Operation types for Dynamics 365 CRUD operations
 
+use crate::api::resilience::RetryableError;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -33,6 +34,9 @@ pub enum Operation {
         id: String,
         /// Updated field data as JSON
         data: Value,
+        /// ETag the record must still have (If-Match); `None` updates unconditionally
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        if_match: Option<String>,
     },
     /// Delete a record
     Delete {
@@ -109,9 +113,25 @@ pub enum Operation {
         attribute: String,
     },
 
+    /// Create a global (shared) option set
+    /// POST /GlobalOptionSetDefinitions
+    CreateGlobalOptionSet {
+        /// Option set metadata as JSON (OptionSetMetadata payload)
+        option_set_data: Value,
+        /// Optional solution unique name to associate the option set with
+        solution_name: Option<String>,
+    },
+
     /// Publish all customizations to make schema changes active
     /// POST /PublishAllXml
     PublishAllXml,
+
+    /// Publish customizations for specific entities only
+    /// POST /PublishXml
+    PublishXml {
+        /// Logical names of the entities to publish
+        entities: Vec<String>,
+    },
 }
 
 /// Result of executing an Operation
@@ -129,6 +149,9 @@ pub struct OperationResult {
     pub status_code: Option<u16>,
     /// Response headers that might be useful (e.g., OData-EntityId)
     pub headers: HashMap<String, String>,
+    /// Server-assigned record ID for creates/upserts (from OData-EntityId or the response body)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
 }
 
 impl Operation {
@@ -146,6 +169,22 @@ impl Operation {
             entity: entity.into(),
             id: id.into(),
             data,
+            if_match: None,
+        }
+    }
+
+    /// Create an Update operation that only applies if the record's ETag still matches
+    pub fn update_if_match(
+        entity: impl Into<String>,
+        id: impl Into<String>,
+        data: Value,
+        etag: impl Into<String>,
+    ) -> Self {
+        Self::Update {
+            entity: entity.into(),
+            id: id.into(),
+            data,
+            if_match: Some(etag.into()),
         }
     }
 
@@ -206,11 +245,36 @@ impl Operation {
         }
     }
 
+    /// Create a new CreateGlobalOptionSet operation (schema)
+    pub fn create_global_option_set(option_set_data: Value, solution_name: Option<String>) -> Self {
+        Self::CreateGlobalOptionSet {
+            option_set_data,
+            solution_name,
+        }
+    }
+
     /// Create a new PublishAllXml operation (schema)
     pub fn publish_all_xml() -> Self {
         Self::PublishAllXml
     }
 
+    /// Create a new PublishXml operation scoped to the given entities (schema)
+    pub fn publish_xml(entities: Vec<String>) -> Self {
+        Self::PublishXml { entities }
+    }
+
+    /// Build the `ParameterXml` for a PublishXml request
+    pub fn publish_parameter_xml(entities: &[String]) -> String {
+        let entities: String = entities
+            .iter()
+            .map(|e| format!("<entity>{}</entity>", e))
+            .collect();
+        format!(
+            "<importexportxml><entities>{}</entities></importexportxml>",
+            entities
+        )
+    }
+
     /// Get the entity name for this operation
     pub fn entity(&self) -> &str {
         match self {
@@ -224,7 +288,9 @@ impl Operation {
             Self::CreateAttribute { entity, .. } => entity,
             Self::UpdateAttribute { entity, .. } => entity,
             Self::DeleteAttribute { entity, .. } => entity,
+            Self::CreateGlobalOptionSet { .. } => "GlobalOptionSetDefinitions",
             Self::PublishAllXml => "EntityDefinitions",
+            Self::PublishXml { .. } => "EntityDefinitions",
         }
     }
 
@@ -241,7 +307,9 @@ impl Operation {
             Self::CreateAttribute { .. } => "POST",
             Self::UpdateAttribute { .. } => "PUT", // Schema updates use PUT, not PATCH
             Self::DeleteAttribute { .. } => "DELETE",
+            Self::CreateGlobalOptionSet { .. } => "POST",
             Self::PublishAllXml => "POST",
+            Self::PublishXml { .. } => "POST",
         }
     }
 
@@ -258,7 +326,9 @@ impl Operation {
             Self::CreateAttribute { .. } => "create_attribute",
             Self::UpdateAttribute { .. } => "update_attribute",
             Self::DeleteAttribute { .. } => "delete_attribute",
+            Self::CreateGlobalOptionSet { .. } => "create_global_option_set",
             Self::PublishAllXml => "publish_all_xml",
+            Self::PublishXml { .. } => "publish_xml",
         }
     }
 
@@ -273,6 +343,38 @@ impl Operation {
 }
 
 impl OperationResult {
+    /// Error message for a failed response
+    ///
+    /// 412 Precondition Failed means an If-Match ETag no longer matched, so the
+    /// record was edited after it was fetched; say so instead of echoing the raw body.
+    /// Duplicate detection also answers 412, but with a recognized error code.
+    pub fn failure_message(status_code: u16, error_text: String) -> String {
+        if is_etag_conflict(status_code, &error_text) {
+            format!(
+                "Record changed since fetch (412 Precondition Failed): {}",
+                error_text
+            )
+        } else {
+            error_text
+        }
+    }
+
+    /// Retry category of a failed result (`None` on success or without a status code)
+    ///
+    /// ETag conflicts get their own non-retryable `Conflict` category so retry
+    /// logic can tell them apart from other 4xx errors.
+    pub fn failure_kind(&self) -> Option<RetryableError> {
+        if self.success {
+            return None;
+        }
+        let status_code = self.status_code?;
+        if is_etag_conflict(status_code, self.error.as_deref().unwrap_or_default()) {
+            Some(RetryableError::Conflict)
+        } else {
+            Some(RetryableError::from_status_code(status_code))
+        }
+    }
+
     /// Create a new successful result
     pub fn success(operation: Operation, data: Option<Value>) -> Self {
         Self {
@@ -282,6 +384,7 @@ impl OperationResult {
             error: None,
             status_code: Some(200),
             headers: HashMap::new(),
+            id: None,
         }
     }
 
@@ -294,9 +397,23 @@ impl OperationResult {
             error: Some(error),
             status_code,
             headers: HashMap::new(),
+            id: None,
         }
     }
 
+    /// Fill in `id` from the response for successful creates and upserts
+    pub fn with_created_id(mut self) -> Self {
+        if self.success && self.id.is_none() {
+            self.id = created_id(&self.operation, &self.headers, self.data.as_ref());
+        }
+        self
+    }
+
+    /// Server-assigned ID of the created (or upserted) record, if known
+    pub fn created_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
     /// Check if this result represents a successful operation
     pub fn is_success(&self) -> bool {
         self.success
@@ -307,6 +424,22 @@ impl OperationResult {
         !self.success
     }
 
+    /// Parsed error code and message, if the operation failed with one
+    pub fn error_details(&self) -> Option<OperationError> {
+        self.error.as_deref().map(OperationError::parse)
+    }
+
+    /// Error to show the user: an explanation for known Dynamics error codes,
+    /// otherwise the raw error text
+    pub fn display_error(&self) -> Option<String> {
+        let error = self.error.as_ref()?;
+        Some(
+            OperationError::parse(error)
+                .friendly_message()
+                .unwrap_or_else(|| error.clone()),
+        )
+    }
+
     /// Get the result data, returning an error if the operation failed
     pub fn into_result(self) -> Result<Value, String> {
         if self.success {
@@ -316,3 +449,300 @@ impl OperationResult {
         }
     }
 }
+
+/// Dynamics error codes with an explanation of what usually causes them
+const KNOWN_ERROR_CODES: &[(&str, &str)] = &[
+    (
+        "0x80040333",
+        "Duplicate detected: a duplicate detection rule matched an existing record",
+    ),
+    (
+        "0x80040237",
+        "Duplicate detected: a record with the same key already exists",
+    ),
+    (
+        "0x80060891",
+        "Duplicate detected: another record already has the same alternate key values",
+    ),
+    (
+        "0x80040220",
+        "Privilege missing: the user's security roles don't allow this operation",
+    ),
+    (
+        "0x80042f09",
+        "Privilege missing: the user isn't allowed to access this record",
+    ),
+    (
+        "0x80040217",
+        "Record not found: it was deleted, or a lookup points to a missing record",
+    ),
+    (
+        "0x80040203",
+        "Required field missing or invalid: check the values sent for required columns",
+    ),
+    (
+        "0x80048d19",
+        "Invalid payload: a field name or value type doesn't match the entity metadata",
+    ),
+];
+
+/// Whether a failed response is an If-Match ETag conflict
+///
+/// Duplicate detection also answers 412, but with a recognized error code.
+fn is_etag_conflict(status_code: u16, error_text: &str) -> bool {
+    status_code == 412 && OperationError::parse(error_text).explanation().is_none()
+}
+
+/// Error code and message from a failed operation's Dynamics error envelope
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationError {
+    /// Dynamics error code, e.g. `0x80040237`
+    pub code: Option<String>,
+    /// Error message reported by the service
+    pub message: String,
+}
+
+impl OperationError {
+    /// Parse raw error text, which may wrap a `{"error":{"code","message"}}` body
+    ///
+    /// Text that isn't a JSON envelope is kept as the message, with the code
+    /// taken from the first `0x........` token if there is one.
+    pub fn parse(error_text: &str) -> Self {
+        let envelope = error_text
+            .find('{')
+            .zip(error_text.rfind('}'))
+            .and_then(|(start, end)| serde_json::from_str::<Value>(&error_text[start..=end]).ok());
+        let error = envelope.as_ref().and_then(|json| json.get("error"));
+
+        if let Some(message) = error
+            .and_then(|e| e.get("message"))
+            .and_then(|m| m.as_str())
+        {
+            return Self {
+                code: error
+                    .and_then(|e| e.get("code"))
+                    .and_then(|c| c.as_str())
+                    .filter(|c| !c.is_empty())
+                    .map(|c| c.to_lowercase()),
+                message: message.to_string(),
+            };
+        }
+
+        let code = error_text
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .find(|token| {
+                token.len() == 10
+                    && token.starts_with("0x")
+                    && token[2..].chars().all(|c| c.is_ascii_hexdigit())
+            })
+            .map(|token| token.to_lowercase());
+        Self {
+            code,
+            message: error_text.trim().to_string(),
+        }
+    }
+
+    /// What a known error code usually means, if the code is recognized
+    pub fn explanation(&self) -> Option<&'static str> {
+        let code = self.code.as_deref()?;
+        KNOWN_ERROR_CODES
+            .iter()
+            .find(|(known, _)| *known == code)
+            .map(|(_, explanation)| *explanation)
+    }
+
+    /// Explanation followed by the service's own message and code
+    pub fn friendly_message(&self) -> Option<String> {
+        let explanation = self.explanation()?;
+        Some(format!(
+            "{}. {} ({})",
+            explanation,
+            self.message,
+            self.code.as_deref().unwrap_or_default()
+        ))
+    }
+}
+
+/// Extract the record GUID from an `OData-EntityId` or `Location` header value
+///
+/// Format: `/entityset(guid)` or `https://host/api/data/v9.2/entityset(guid)`
+pub fn parse_entity_id_header(value: &str) -> Option<String> {
+    parse_entity_reference(value).map(|(_, id)| id.to_string())
+}
+
+/// Split an entity reference like `.../entityset(guid)` into its entity set and ID
+fn parse_entity_reference(value: &str) -> Option<(&str, &str)> {
+    let start = value.rfind('(')?;
+    let end = value.rfind(')')?;
+    if end <= start + 1 {
+        return None;
+    }
+    let entity_set = value[..start].rsplit('/').next()?;
+    Some((entity_set, &value[start + 1..end]))
+}
+
+/// Whether `entity_set` is the plural of the logical name `entity`
+/// (e.g. "contact" → "contacts", "nrq_category" → "nrq_categories")
+fn is_entity_set_of(entity_set: &str, entity: &str) -> bool {
+    let Some(rest) = entity_set.strip_prefix(entity) else {
+        return entity
+            .strip_suffix('y')
+            .and_then(|stem| entity_set.strip_prefix(stem))
+            == Some("ies");
+    };
+    matches!(rest, "s" | "es")
+}
+
+/// Work out the created record's ID from response headers, falling back to the
+/// primary key in a `Prefer: return=representation` body
+fn created_id(
+    operation: &Operation,
+    headers: &HashMap<String, String>,
+    data: Option<&Value>,
+) -> Option<String> {
+    let entity = match operation {
+        Operation::Create { entity, .. }
+        | Operation::CreateWithRefs { entity, .. }
+        | Operation::Upsert { entity, .. } => entity,
+        _ => return None,
+    };
+
+    let from_header = headers
+        .iter()
+        .filter(|(key, _)| {
+            key.eq_ignore_ascii_case("odata-entityid") || key.eq_ignore_ascii_case("location")
+        })
+        .filter_map(|(_, value)| parse_entity_reference(value))
+        .find(|(entity_set, _)| entity_set == entity)
+        .map(|(_, id)| id.to_string());
+    if from_header.is_some() {
+        return from_header;
+    }
+
+    // Primary key is "{logical name}id"; the entity set is the pluralized logical name
+    data?
+        .as_object()?
+        .iter()
+        .find(|(key, value)| {
+            let Some(logical_name) = key.strip_suffix("id") else {
+                return false;
+            };
+            !logical_name.is_empty()
+                && !key.starts_with('_')
+                && value.is_string()
+                && is_entity_set_of(entity, logical_name)
+        })
+        .and_then(|(_, value)| value.as_str().map(str::to_string))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_created_id_from_entity_id_header() {
+        assert_eq!(
+            parse_entity_id_header(
+                "https://test.crm.dynamics.com/api/data/v9.2/contacts(00000000-0000-0000-0000-000000000001)"
+            )
+            .as_deref(),
+            Some("00000000-0000-0000-0000-000000000001")
+        );
+        assert_eq!(parse_entity_id_header("/contacts()"), None);
+        assert_eq!(parse_entity_id_header("no id here"), None);
+        assert_eq!(
+            parse_entity_reference("/api/data/v9.2/contacts(abc)"),
+            Some(("contacts", "abc"))
+        );
+
+        let mut result = OperationResult::success(
+            Operation::create("contacts", json!({"firstname": "John"})),
+            None,
+        );
+        result.headers.insert(
+            "odata-entityid".to_string(),
+            "https://test.crm.dynamics.com/api/data/v9.2/contacts(7d3a1c2e-0000-0000-0000-000000000042)"
+                .to_string(),
+        );
+        let result = result.with_created_id();
+        assert_eq!(
+            result.created_id(),
+            Some("7d3a1c2e-0000-0000-0000-000000000042")
+        );
+    }
+
+    #[test]
+    fn test_created_id_from_representation_body() {
+        let result = OperationResult::success(
+            Operation::create("nrq_categories", json!({"nrq_name": "A"})),
+            Some(json!({
+                "@odata.etag": "W/\"123\"",
+                "_ownerid_value": "owner-guid",
+                "nrq_name": "A",
+                "nrq_categoryid": "category-guid"
+            })),
+        )
+        .with_created_id();
+        assert_eq!(result.created_id(), Some("category-guid"));
+
+        // Only the primary key of the exact entity set counts, not a prefix of it
+        let result = OperationResult::success(
+            Operation::create("contacts", json!({"lastname": "Doe"})),
+            Some(json!({
+                "conid": "prefix-guid",
+                "lastname": "Doe",
+                "contactid": "contact-guid"
+            })),
+        )
+        .with_created_id();
+        assert_eq!(result.created_id(), Some("contact-guid"));
+        assert!(is_entity_set_of("addresses", "address"));
+        assert!(!is_entity_set_of("accounts", "acc"));
+
+        // Non-create operations never report a created ID
+        let mut delete = OperationResult::success(Operation::delete("contacts", "abc"), None);
+        delete
+            .headers
+            .insert("OData-EntityId".to_string(), "/contacts(abc)".to_string());
+        assert_eq!(delete.with_created_id().created_id(), None);
+    }
+
+    #[test]
+    fn test_duplicate_detected_error_gets_friendly_message() {
+        let body = r#"{"error":{"code":"0x80040333","message":"A record was not created or updated because a duplicate of the current record already exists."}}"#;
+        let result = OperationResult::error(
+            Operation::create("contacts", json!({"lastname": "Doe"})),
+            OperationResult::failure_message(412, body.to_string()),
+            Some(412),
+        );
+
+        assert!(!result.error.as_ref().unwrap().starts_with("Record changed"));
+        assert_eq!(
+            result.failure_kind(),
+            Some(RetryableError::ClientError(412))
+        );
+        let details = result.error_details().unwrap();
+        assert_eq!(details.code.as_deref(), Some("0x80040333"));
+        assert_eq!(
+            result.display_error().as_deref(),
+            Some(
+                "Duplicate detected: a duplicate detection rule matched an existing record. \
+                 A record was not created or updated because a duplicate of the current record \
+                 already exists. (0x80040333)"
+            )
+        );
+
+        // Unknown codes keep the raw text
+        let unknown = OperationResult::error(
+            Operation::delete("contacts", "abc"),
+            r#"{"error":{"code":"0x8000ffff","message":"Unexpected"}}"#.to_string(),
+            Some(500),
+        );
+        assert_eq!(unknown.display_error(), unknown.error);
+        assert_eq!(
+            OperationError::parse("Batch failed: 0x80040220 SecLib::AccessCheckEx failed").code,
+            Some("0x80040220".to_string())
+        );
+    }
+}
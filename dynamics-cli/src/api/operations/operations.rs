@@ -1,5 +1,6 @@
 //! Batch operations for executing multiple Operations together
 
+use super::batch::{BatchRequest, BatchRequestBuilder, BatchResponseParser};
 use super::operation::{Operation, OperationResult};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -139,6 +140,17 @@ impl Operations {
         client.execute_batch(&self.operations, resilience).await
     }
 
+    /// Batch execution without a changeset: each operation succeeds or fails on its own
+    pub async fn execute_continue_on_error(
+        &self,
+        client: &crate::api::DynamicsClient,
+        resilience: &crate::api::ResilienceConfig,
+    ) -> anyhow::Result<Vec<OperationResult>> {
+        client
+            .execute_batch_continue_on_error(&self.operations, resilience)
+            .await
+    }
+
     /// Execute operations in parallel (each operation as separate concurrent HTTP request)
     pub async fn execute_parallel(
         &self,
@@ -174,6 +186,89 @@ impl Operations {
     }
 }
 
+/// Packages multiple operations into a single `$batch` request of independent requests.
+///
+/// With `continue_on_error` set, each operation goes in as its own request and a
+/// failure in one doesn't stop the rest. Without it the operations run as one
+/// atomic changeset. Content-ID references only work inside a changeset, so
+/// `CreateWithRefs` operations can't be sent with `continue_on_error`.
+#[derive(Debug, Clone)]
+pub struct ExecuteMultiple {
+    operations: Vec<Operation>,
+    continue_on_error: bool,
+}
+
+impl ExecuteMultiple {
+    /// Create a request that stops at the first failed operation
+    pub fn new(operations: impl Into<Operations>) -> Self {
+        Self {
+            operations: operations.into().operations,
+            continue_on_error: false,
+        }
+    }
+
+    /// Keep executing the remaining requests after one fails
+    pub fn continue_on_error(mut self, continue_on_error: bool) -> Self {
+        self.continue_on_error = continue_on_error;
+        self
+    }
+
+    /// Get the packaged operations
+    pub fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+
+    /// Build the `$batch` request for the packaged operations
+    pub fn build_request(&self, base_url: &str) -> anyhow::Result<BatchRequest> {
+        self.validate()?;
+
+        let builder = BatchRequestBuilder::new(base_url).continue_on_error(self.continue_on_error);
+        let builder = if self.continue_on_error {
+            builder.add_requests(&self.operations)
+        } else {
+            builder.add_changeset(&self.operations)
+        };
+        Ok(builder.build())
+    }
+
+    /// Parse the `$batch` response into one result per packaged operation
+    pub fn parse_response(&self, response_text: &str) -> anyhow::Result<Vec<OperationResult>> {
+        BatchResponseParser::parse(response_text, &self.operations)
+    }
+
+    /// Send the packaged operations in a single `$batch` request
+    pub async fn execute(
+        &self,
+        client: &crate::api::DynamicsClient,
+        resilience: &crate::api::ResilienceConfig,
+    ) -> anyhow::Result<Vec<OperationResult>> {
+        self.validate()?;
+
+        if self.continue_on_error {
+            client
+                .execute_batch_continue_on_error(&self.operations, resilience)
+                .await
+        } else {
+            client.execute_batch(&self.operations, resilience).await
+        }
+    }
+
+    /// Reject operations that can't be sent as independent requests
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.continue_on_error
+            && self
+                .operations
+                .iter()
+                .any(|op| matches!(op, Operation::CreateWithRefs { .. }))
+        {
+            anyhow::bail!(
+                "CreateWithRefs operation can only be executed within a batch changeset. Disable continue_on_error or use execute_batch() instead."
+            );
+        }
+        Ok(())
+    }
+}
+
 impl Default for Operations {
     fn default() -> Self {
         Self::new()
@@ -209,3 +304,100 @@ impl<'a> IntoIterator for &'a Operations {
         self.operations.iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema_request() -> ExecuteMultiple {
+        ExecuteMultiple::new(vec![
+            Operation::create_attribute(
+                "nrq_fund",
+                json!({ "SchemaName": "nrq_Amount" }),
+                Some("core".to_string()),
+            ),
+            Operation::create_attribute("nrq_fund", json!({ "SchemaName": "nrq_Code" }), None),
+        ])
+        .continue_on_error(true)
+    }
+
+    #[test]
+    fn test_execute_multiple_request() {
+        let batch = schema_request()
+            .build_request("https://test.crm.dynamics.com")
+            .unwrap();
+
+        assert_eq!(batch.prefer(), Some("odata.continue-on-error"));
+        // Independent requests, no changeset wrapper
+        assert!(!batch.body().contains("changeset_"));
+        assert_eq!(
+            batch
+                .body()
+                .matches(
+                    "POST /api/data/v9.2/EntityDefinitions(LogicalName='nrq_fund')/Attributes HTTP/1.1"
+                )
+                .count(),
+            2
+        );
+        assert!(batch.body().contains("MSCRM.SolutionUniqueName: core"));
+        assert!(batch.body().contains(r#"{"SchemaName":"nrq_Code"}"#));
+
+        // Content-ID references only work inside a $batch changeset
+        let with_refs = ExecuteMultiple::new(Operation::CreateWithRefs {
+            entity: "contacts".to_string(),
+            data: json!({}),
+            content_id_refs: Default::default(),
+        });
+        assert!(
+            with_refs
+                .build_request("https://test.crm.dynamics.com")
+                .is_ok()
+        );
+        assert!(
+            with_refs
+                .continue_on_error(true)
+                .build_request("https://test.crm.dynamics.com")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_execute_multiple_response_success_and_failure() {
+        let response = r#"--batchresponse_c1bd45c1-dd81-470d-b897-e965846aad2f
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+
+HTTP/1.1 204 No Content
+OData-Version: 4.0
+
+
+--batchresponse_c1bd45c1-dd81-470d-b897-e965846aad2f
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+
+HTTP/1.1 400 Bad Request
+Content-Type: application/json; odata.metadata=minimal
+OData-Version: 4.0
+
+{"error":{"code":"0x80044363","message":"An attribute with the specified name already exists"}}
+--batchresponse_c1bd45c1-dd81-470d-b897-e965846aad2f--"#;
+
+        let results = schema_request().parse_response(response).unwrap();
+        assert_eq!(results.len(), 2);
+
+        assert!(results[0].is_success());
+        assert_eq!(results[0].status_code, Some(204));
+
+        assert!(results[1].is_error());
+        assert_eq!(results[1].status_code, Some(400));
+        assert_eq!(results[1].operation.operation_type(), "create_attribute");
+        assert!(
+            results[1]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("already exists")
+        );
+    }
+}
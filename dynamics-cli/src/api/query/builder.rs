@@ -64,7 +64,8 @@ impl QueryBuilder {
         self
     }
 
-    /// Include count in response
+    /// Include count in response (OData `$count`, capped at 5000; use
+    /// [`Self::execute_count`] for an exact total)
     pub fn count(mut self) -> Self {
         self.query.count = true;
         self
@@ -80,6 +81,17 @@ impl QueryBuilder {
         let query = self.build();
         client.execute_query(&query).await
     }
+
+    /// Build and return only the number of matching rows
+    ///
+    /// `entity_name` is the logical name; see [`Query::execute_count`].
+    pub async fn execute_count(
+        self,
+        client: &DynamicsClient,
+        entity_name: &str,
+    ) -> anyhow::Result<u64> {
+        self.build().execute_count(client, entity_name).await
+    }
 }
 
 // Convenience methods for common patterns
@@ -0,0 +1,203 @@
+//! Structured query failures
+//!
+//! Classifies failed OData responses by HTTP status so callers can react
+//! differently to expired credentials, throttling and missing entities.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::Value;
+
+/// A failed query, classified by HTTP status category
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchError {
+    /// 401/403 - the token is missing, expired or lacks privileges
+    Auth { status: u16, message: String },
+    /// 429 - the service is throttling requests
+    Throttled {
+        retry_after_secs: Option<u64>,
+        message: String,
+    },
+    /// 404 - the entity set or record does not exist
+    NotFound { message: String },
+    /// Other 4xx - the request itself is invalid (bad field names, filters, ...)
+    BadRequest { status: u16, message: String },
+    /// 5xx - the service failed to handle the request
+    Server { status: u16, message: String },
+    /// No usable status (empty or unparseable response, transport failure)
+    Other {
+        status: Option<u16>,
+        message: String,
+    },
+}
+
+impl FetchError {
+    /// Classify a failed response from its status, headers and body
+    ///
+    /// The message is taken from the OData `error.message` field when the body
+    /// is a Dynamics error document, otherwise the raw body is kept.
+    pub fn from_response(
+        status: Option<u16>,
+        headers: &HashMap<String, String>,
+        body: &str,
+    ) -> Self {
+        let message = odata_error_message(body).unwrap_or_else(|| body.trim().to_string());
+
+        match status {
+            Some(status @ (401 | 403)) => FetchError::Auth { status, message },
+            Some(429) => FetchError::Throttled {
+                retry_after_secs: headers
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+                    .and_then(|(_, value)| value.trim().parse().ok()),
+                message,
+            },
+            Some(404) => FetchError::NotFound { message },
+            Some(status @ 400..=499) => FetchError::BadRequest { status, message },
+            Some(status @ 500..=599) => FetchError::Server { status, message },
+            status => FetchError::Other { status, message },
+        }
+    }
+
+    /// HTTP status of the failed response, if there was one
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            FetchError::Auth { status, .. }
+            | FetchError::BadRequest { status, .. }
+            | FetchError::Server { status, .. } => Some(*status),
+            FetchError::Throttled { .. } => Some(429),
+            FetchError::NotFound { .. } => Some(404),
+            FetchError::Other { status, .. } => *status,
+        }
+    }
+
+    /// Error message reported by the service
+    pub fn message(&self) -> &str {
+        match self {
+            FetchError::Auth { message, .. }
+            | FetchError::Throttled { message, .. }
+            | FetchError::NotFound { message }
+            | FetchError::BadRequest { message, .. }
+            | FetchError::Server { message, .. }
+            | FetchError::Other { message, .. } => message,
+        }
+    }
+
+    /// Whether signing in again might fix the request
+    pub fn is_auth(&self) -> bool {
+        matches!(self, FetchError::Auth { .. })
+    }
+
+    /// Whether the request should be retried after backing off
+    pub fn is_throttled(&self) -> bool {
+        matches!(self, FetchError::Throttled { .. })
+    }
+
+    /// Whether the queried resource does not exist
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, FetchError::NotFound { .. })
+    }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Auth { status, message } => {
+                write!(f, "Authentication failed ({}): {}", status, message)
+            }
+            FetchError::Throttled {
+                retry_after_secs: Some(secs),
+                message,
+            } => write!(f, "Throttled, retry after {}s: {}", secs, message),
+            FetchError::Throttled {
+                retry_after_secs: None,
+                message,
+            } => write!(f, "Throttled: {}", message),
+            FetchError::NotFound { message } => write!(f, "Not found: {}", message),
+            FetchError::BadRequest { status, message } => {
+                write!(f, "Bad request ({}): {}", status, message)
+            }
+            FetchError::Server { status, message } => {
+                write!(f, "Server error ({}): {}", status, message)
+            }
+            FetchError::Other {
+                status: Some(status),
+                message,
+            } => write!(f, "Request failed ({}): {}", status, message),
+            FetchError::Other {
+                status: None,
+                message,
+            } => write!(f, "Request failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Extract `error.message` from a Dynamics OData error document
+fn odata_error_message(body: &str) -> Option<String> {
+    let json: Value = serde_json::from_str(body).ok()?;
+    json.get("error")?
+        .get("message")?
+        .as_str()
+        .map(|s| s.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_responses_map_to_variants() {
+        let no_headers = HashMap::new();
+        let odata =
+            r#"{"error":{"code":"0x80040220","message":"Principal user is missing privileges"}}"#;
+
+        assert_eq!(
+            FetchError::from_response(Some(401), &no_headers, ""),
+            FetchError::Auth {
+                status: 401,
+                message: String::new()
+            }
+        );
+        let forbidden = FetchError::from_response(Some(403), &no_headers, odata);
+        assert!(forbidden.is_auth());
+        assert_eq!(forbidden.message(), "Principal user is missing privileges");
+
+        let mut headers = HashMap::new();
+        headers.insert("Retry-After".to_string(), "12".to_string());
+        let throttled = FetchError::from_response(Some(429), &headers, "Too many requests");
+        assert_eq!(
+            throttled,
+            FetchError::Throttled {
+                retry_after_secs: Some(12),
+                message: "Too many requests".to_string()
+            }
+        );
+        assert_eq!(
+            throttled.to_string(),
+            "Throttled, retry after 12s: Too many requests"
+        );
+
+        let missing = FetchError::from_response(
+            Some(404),
+            &no_headers,
+            r#"{"error":{"code":"0x80060888","message":"Resource not found for the segment 'cgk_widgets'."}}"#,
+        );
+        assert!(missing.is_not_found());
+        assert_eq!(missing.status(), Some(404));
+
+        assert!(matches!(
+            FetchError::from_response(Some(400), &no_headers, odata),
+            FetchError::BadRequest { status: 400, .. }
+        ));
+        assert!(matches!(
+            FetchError::from_response(Some(503), &no_headers, "Service Unavailable"),
+            FetchError::Server { status: 503, .. }
+        ));
+        assert!(matches!(
+            FetchError::from_response(None, &no_headers, "Empty response from server"),
+            FetchError::Other { status: None, .. }
+        ));
+    }
+}
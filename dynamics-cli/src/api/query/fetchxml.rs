@@ -0,0 +1,613 @@
+//! FetchXML builder
+//!
+//! Typed construction of FetchXML documents for cases OData can't express
+//! well (aggregates beyond the 5000 record `$count` cap, multi-level joins).
+//! Output is compact single-line XML suitable for the `fetchXml` query parameter.
+
+use super::filters::{Filter, FilterValue};
+
+/// Aggregate function applied to an attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateFunction {
+    Count,
+    CountColumn,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+impl AggregateFunction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Count => "count",
+            Self::CountColumn => "countcolumn",
+            Self::Sum => "sum",
+            Self::Avg => "avg",
+            Self::Min => "min",
+            Self::Max => "max",
+        }
+    }
+}
+
+/// Condition operator for `<condition>` elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionOperator {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Like,
+    NotLike,
+    In,
+    NotIn,
+    Null,
+    NotNull,
+}
+
+impl ConditionOperator {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Eq => "eq",
+            Self::Ne => "ne",
+            Self::Gt => "gt",
+            Self::Ge => "ge",
+            Self::Lt => "lt",
+            Self::Le => "le",
+            Self::Like => "like",
+            Self::NotLike => "not-like",
+            Self::In => "in",
+            Self::NotIn => "not-in",
+            Self::Null => "null",
+            Self::NotNull => "not-null",
+        }
+    }
+}
+
+/// A single `<condition>` inside a filter
+#[derive(Debug, Clone)]
+pub struct Condition {
+    pub attribute: String,
+    pub operator: ConditionOperator,
+    pub values: Vec<String>,
+}
+
+impl Condition {
+    pub fn new(
+        attribute: impl Into<String>,
+        operator: ConditionOperator,
+        value: impl ToString,
+    ) -> Self {
+        Self {
+            attribute: attribute.into(),
+            operator,
+            values: vec![value.to_string()],
+        }
+    }
+
+    pub fn eq(attribute: impl Into<String>, value: impl ToString) -> Self {
+        Self::new(attribute, ConditionOperator::Eq, value)
+    }
+
+    pub fn ne(attribute: impl Into<String>, value: impl ToString) -> Self {
+        Self::new(attribute, ConditionOperator::Ne, value)
+    }
+
+    pub fn like(attribute: impl Into<String>, pattern: impl ToString) -> Self {
+        Self::new(attribute, ConditionOperator::Like, pattern)
+    }
+
+    pub fn is_in<V: ToString>(attribute: impl Into<String>, values: &[V]) -> Self {
+        Self {
+            attribute: attribute.into(),
+            operator: ConditionOperator::In,
+            values: values.iter().map(|v| v.to_string()).collect(),
+        }
+    }
+
+    pub fn null(attribute: impl Into<String>) -> Self {
+        Self {
+            attribute: attribute.into(),
+            operator: ConditionOperator::Null,
+            values: Vec::new(),
+        }
+    }
+
+    pub fn not_null(attribute: impl Into<String>) -> Self {
+        Self {
+            attribute: attribute.into(),
+            operator: ConditionOperator::NotNull,
+            values: Vec::new(),
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        out.push_str(&format!(
+            r#"<condition attribute="{}" operator="{}""#,
+            escape(&self.attribute),
+            self.operator.as_str()
+        ));
+        match (self.operator, self.values.as_slice()) {
+            (_, []) => out.push_str("/>"),
+            (ConditionOperator::In | ConditionOperator::NotIn, values) => {
+                out.push('>');
+                for value in values {
+                    out.push_str(&format!("<value>{}</value>", escape(value)));
+                }
+                out.push_str("</condition>");
+            }
+            (_, [value, ..]) => out.push_str(&format!(r#" value="{}"/>"#, escape(value))),
+        }
+    }
+}
+
+/// Logical grouping of conditions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterType {
+    And,
+    Or,
+}
+
+/// A `<filter>` element with conditions and nested filters
+#[derive(Debug, Clone)]
+pub struct FetchFilter {
+    pub filter_type: FilterType,
+    pub conditions: Vec<Condition>,
+    pub filters: Vec<FetchFilter>,
+}
+
+impl FetchFilter {
+    pub fn and() -> Self {
+        Self {
+            filter_type: FilterType::And,
+            conditions: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+
+    pub fn or() -> Self {
+        Self {
+            filter_type: FilterType::Or,
+            ..Self::and()
+        }
+    }
+
+    pub fn condition(mut self, condition: Condition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn filter(mut self, filter: FetchFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Translate an OData filter into FetchXML conditions
+    ///
+    /// Returns `None` for filters with no FetchXML equivalent (`Raw`, `Not`).
+    pub fn from_odata(filter: &Filter) -> Option<Self> {
+        match filter {
+            Filter::And(filters) => filters
+                .iter()
+                .try_fold(Self::and(), |acc, f| acc.push_odata(f)),
+            Filter::Or(filters) => filters
+                .iter()
+                .try_fold(Self::or(), |acc, f| acc.push_odata(f)),
+            other => Self::and().push_odata(other),
+        }
+    }
+
+    fn push_odata(self, filter: &Filter) -> Option<Self> {
+        let condition = match filter {
+            Filter::Eq(field, FilterValue::Null) => Condition::null(field),
+            Filter::Ne(field, FilterValue::Null) => Condition::not_null(field),
+            Filter::Eq(field, value) => Condition::eq(field, fetch_value(value)),
+            Filter::Ne(field, value) => Condition::ne(field, fetch_value(value)),
+            Filter::Gt(field, value) => {
+                Condition::new(field, ConditionOperator::Gt, fetch_value(value))
+            }
+            Filter::Ge(field, value) => {
+                Condition::new(field, ConditionOperator::Ge, fetch_value(value))
+            }
+            Filter::Lt(field, value) => {
+                Condition::new(field, ConditionOperator::Lt, fetch_value(value))
+            }
+            Filter::Le(field, value) => {
+                Condition::new(field, ConditionOperator::Le, fetch_value(value))
+            }
+            Filter::Contains(field, value) => Condition::like(field, format!("%{}%", value)),
+            Filter::StartsWith(field, value) => Condition::like(field, format!("{}%", value)),
+            Filter::EndsWith(field, value) => Condition::like(field, format!("%{}", value)),
+            Filter::And(_) | Filter::Or(_) => return Some(self.filter(Self::from_odata(filter)?)),
+            Filter::Not(_) | Filter::Raw(_) => return None,
+        };
+        Some(self.condition(condition))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.conditions.is_empty() && self.filters.iter().all(|f| f.is_empty())
+    }
+
+    fn write(&self, out: &mut String) {
+        if self.is_empty() {
+            return;
+        }
+        let kind = match self.filter_type {
+            FilterType::And => "and",
+            FilterType::Or => "or",
+        };
+        out.push_str(&format!(r#"<filter type="{}">"#, kind));
+        for condition in &self.conditions {
+            condition.write(out);
+        }
+        for filter in &self.filters {
+            filter.write(out);
+        }
+        out.push_str("</filter>");
+    }
+}
+
+/// An `<attribute>` element, optionally aggregated or grouped
+#[derive(Debug, Clone)]
+struct FetchAttribute {
+    name: String,
+    aggregate: Option<AggregateFunction>,
+    alias: Option<String>,
+    group_by: bool,
+}
+
+impl FetchAttribute {
+    fn plain(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            aggregate: None,
+            alias: None,
+            group_by: false,
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        out.push_str(&format!(r#"<attribute name="{}""#, escape(&self.name)));
+        if let Some(aggregate) = self.aggregate {
+            out.push_str(&format!(r#" aggregate="{}""#, aggregate.as_str()));
+        }
+        if let Some(alias) = &self.alias {
+            out.push_str(&format!(r#" alias="{}""#, escape(alias)));
+        }
+        if self.group_by {
+            out.push_str(r#" groupby="true""#);
+        }
+        out.push_str("/>");
+    }
+}
+
+/// Join type for link-entities
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkType {
+    Inner,
+    Outer,
+}
+
+/// A `<link-entity>` join to a related entity
+#[derive(Debug, Clone)]
+pub struct LinkEntity {
+    name: String,
+    from: String,
+    to: String,
+    alias: Option<String>,
+    link_type: LinkType,
+    attributes: Vec<FetchAttribute>,
+    filter: Option<FetchFilter>,
+    links: Vec<LinkEntity>,
+}
+
+impl LinkEntity {
+    /// Join `name` where `name.from` equals `parent.to`
+    pub fn new(name: impl Into<String>, from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            from: from.into(),
+            to: to.into(),
+            alias: None,
+            link_type: LinkType::Inner,
+            attributes: Vec::new(),
+            filter: None,
+            links: Vec::new(),
+        }
+    }
+
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    pub fn outer(mut self) -> Self {
+        self.link_type = LinkType::Outer;
+        self
+    }
+
+    pub fn attribute(mut self, name: impl Into<String>) -> Self {
+        self.attributes.push(FetchAttribute::plain(name));
+        self
+    }
+
+    pub fn filter(mut self, filter: FetchFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn link_entity(mut self, link: LinkEntity) -> Self {
+        self.links.push(link);
+        self
+    }
+
+    fn write(&self, out: &mut String) {
+        out.push_str(&format!(
+            r#"<link-entity name="{}" from="{}" to="{}""#,
+            escape(&self.name),
+            escape(&self.from),
+            escape(&self.to)
+        ));
+        if let Some(alias) = &self.alias {
+            out.push_str(&format!(r#" alias="{}""#, escape(alias)));
+        }
+        let link_type = match self.link_type {
+            LinkType::Inner => "inner",
+            LinkType::Outer => "outer",
+        };
+        out.push_str(&format!(r#" link-type="{}">"#, link_type));
+        for attribute in &self.attributes {
+            attribute.write(out);
+        }
+        if let Some(filter) = &self.filter {
+            filter.write(out);
+        }
+        for link in &self.links {
+            link.write(out);
+        }
+        out.push_str("</link-entity>");
+    }
+}
+
+/// Fluent builder producing a FetchXML document
+#[derive(Debug, Clone)]
+pub struct FetchXmlBuilder {
+    entity: String,
+    attributes: Vec<FetchAttribute>,
+    filter: Option<FetchFilter>,
+    orders: Vec<(String, bool)>,
+    links: Vec<LinkEntity>,
+    top: Option<u32>,
+    distinct: bool,
+}
+
+impl FetchXmlBuilder {
+    /// Start a query against an entity logical name (e.g. "contact")
+    pub fn new(entity: impl Into<String>) -> Self {
+        Self {
+            entity: entity.into(),
+            attributes: Vec::new(),
+            filter: None,
+            orders: Vec::new(),
+            links: Vec::new(),
+            top: None,
+            distinct: false,
+        }
+    }
+
+    /// Select an attribute
+    pub fn attribute(mut self, name: impl Into<String>) -> Self {
+        self.attributes.push(FetchAttribute::plain(name));
+        self
+    }
+
+    /// Select multiple attributes
+    pub fn attributes(mut self, names: &[&str]) -> Self {
+        self.attributes
+            .extend(names.iter().map(|n| FetchAttribute::plain(*n)));
+        self
+    }
+
+    /// Aggregate an attribute under `alias`; marks the fetch as aggregate
+    pub fn aggregate(
+        mut self,
+        attribute: impl Into<String>,
+        function: AggregateFunction,
+        alias: impl Into<String>,
+    ) -> Self {
+        self.attributes.push(FetchAttribute {
+            name: attribute.into(),
+            aggregate: Some(function),
+            alias: Some(alias.into()),
+            group_by: false,
+        });
+        self
+    }
+
+    /// Group aggregate results by an attribute
+    pub fn group_by(mut self, attribute: impl Into<String>, alias: impl Into<String>) -> Self {
+        self.attributes.push(FetchAttribute {
+            name: attribute.into(),
+            aggregate: None,
+            alias: Some(alias.into()),
+            group_by: true,
+        });
+        self
+    }
+
+    /// Set the root filter
+    pub fn filter(mut self, filter: FetchFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Add a condition to the root filter (creating an `and` filter if needed)
+    pub fn condition(mut self, condition: Condition) -> Self {
+        let filter = self.filter.take().unwrap_or_else(FetchFilter::and);
+        self.filter = Some(filter.condition(condition));
+        self
+    }
+
+    /// Order by an attribute
+    pub fn order(mut self, attribute: impl Into<String>, descending: bool) -> Self {
+        self.orders.push((attribute.into(), descending));
+        self
+    }
+
+    /// Join a related entity
+    pub fn link_entity(mut self, link: LinkEntity) -> Self {
+        self.links.push(link);
+        self
+    }
+
+    /// Limit number of results
+    pub fn top(mut self, top: u32) -> Self {
+        self.top = Some(top);
+        self
+    }
+
+    /// Return distinct rows only
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    fn is_aggregate(&self) -> bool {
+        self.attributes
+            .iter()
+            .any(|a| a.aggregate.is_some() || a.group_by)
+    }
+
+    /// Render the FetchXML document
+    pub fn build(&self) -> String {
+        let mut out = String::from("<fetch");
+        if self.is_aggregate() {
+            out.push_str(r#" aggregate="true""#);
+        }
+        if self.distinct {
+            out.push_str(r#" distinct="true""#);
+        }
+        if let Some(top) = self.top {
+            out.push_str(&format!(r#" top="{}""#, top));
+        }
+        out.push_str(&format!(r#"><entity name="{}">"#, escape(&self.entity)));
+        for attribute in &self.attributes {
+            attribute.write(&mut out);
+        }
+        for (attribute, descending) in &self.orders {
+            out.push_str(&format!(
+                r#"<order attribute="{}" descending="{}"/>"#,
+                escape(attribute),
+                descending
+            ));
+        }
+        if let Some(filter) = &self.filter {
+            filter.write(&mut out);
+        }
+        for link in &self.links {
+            link.write(&mut out);
+        }
+        out.push_str("</entity></fetch>");
+        out
+    }
+}
+
+fn fetch_value(value: &FilterValue) -> String {
+    match value {
+        FilterValue::String(s) | FilterValue::Guid(s) => s.clone(),
+        FilterValue::Number(n) => n.to_string(),
+        FilterValue::Integer(i) => i.to_string(),
+        FilterValue::Boolean(b) => if *b { "1" } else { "0" }.to_string(),
+        FilterValue::Null => String::new(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_aggregate() {
+        let xml = FetchXmlBuilder::new("contact")
+            .aggregate("contactid", AggregateFunction::Count, "total")
+            .build();
+
+        assert_eq!(
+            xml,
+            r#"<fetch aggregate="true"><entity name="contact"><attribute name="contactid" aggregate="count" alias="total"/></entity></fetch>"#
+        );
+    }
+
+    #[test]
+    fn test_link_entity_join() {
+        let xml = FetchXmlBuilder::new("contact")
+            .attributes(&["fullname", "emailaddress1"])
+            .condition(Condition::eq("statecode", 0))
+            .link_entity(
+                LinkEntity::new("account", "accountid", "parentcustomerid")
+                    .alias("acc")
+                    .attribute("name")
+                    .filter(FetchFilter::and().condition(Condition::like("name", "A&B%"))),
+            )
+            .build();
+
+        assert_eq!(
+            xml,
+            concat!(
+                r#"<fetch><entity name="contact">"#,
+                r#"<attribute name="fullname"/><attribute name="emailaddress1"/>"#,
+                r#"<filter type="and"><condition attribute="statecode" operator="eq" value="0"/></filter>"#,
+                r#"<link-entity name="account" from="accountid" to="parentcustomerid" alias="acc" link-type="inner">"#,
+                r#"<attribute name="name"/>"#,
+                r#"<filter type="and"><condition attribute="name" operator="like" value="A&amp;B%"/></filter>"#,
+                r#"</link-entity></entity></fetch>"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_odata_filter() {
+        let filter = Filter::and(vec![
+            Filter::eq("statecode", 0),
+            Filter::or(vec![
+                Filter::starts_with("name", "Acme"),
+                Filter::eq("parentaccountid", FilterValue::Null),
+            ]),
+        ]);
+        let mut out = String::new();
+        FetchFilter::from_odata(&filter).unwrap().write(&mut out);
+
+        assert_eq!(
+            out,
+            concat!(
+                r#"<filter type="and"><condition attribute="statecode" operator="eq" value="0"/>"#,
+                r#"<filter type="or"><condition attribute="name" operator="like" value="Acme%"/>"#,
+                r#"<condition attribute="parentaccountid" operator="null"/></filter></filter>"#
+            )
+        );
+        assert!(FetchFilter::from_odata(&Filter::raw("x eq 1")).is_none());
+    }
+
+    #[test]
+    fn test_in_condition_renders_values() {
+        let xml = FetchXmlBuilder::new("account")
+            .condition(Condition::is_in("statuscode", &[1, 2]))
+            .top(10)
+            .build();
+
+        assert!(xml.starts_with(r#"<fetch top="10">"#));
+        assert!(xml.contains(
+            r#"<condition attribute="statuscode" operator="in"><value>1</value><value>2</value></condition>"#
+        ));
+    }
+}
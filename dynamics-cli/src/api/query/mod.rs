@@ -4,12 +4,18 @@
 //! Follows the same pattern as operations with Query (reusable) and QueryBuilder (fluent).
 
 pub mod builder;
+pub mod error;
+pub mod fetchxml;
 pub mod filters;
 pub mod orderby;
 pub mod query;
 pub mod result;
 
 pub use builder::QueryBuilder;
+pub use error::FetchError;
+pub use fetchxml::{
+    AggregateFunction, Condition, ConditionOperator, FetchFilter, FetchXmlBuilder, LinkEntity,
+};
 pub use filters::{Filter, FilterValue};
 pub use orderby::OrderBy;
 pub use query::Query;
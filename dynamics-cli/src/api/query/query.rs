@@ -2,10 +2,16 @@
 //!
 //! Represents a complete OData query that can be executed multiple times
 
+use super::fetchxml::{AggregateFunction, FetchFilter, FetchXmlBuilder};
 use super::filters::Filter;
 use super::orderby::OrderByClause;
+use crate::api::client::DynamicsClient;
+use serde_json::Value;
 use std::collections::HashMap;
 
+/// Alias used for the aggregate column in count requests
+const COUNT_ALIAS: &str = "total";
+
 #[derive(Debug, Clone)]
 pub struct Query {
     pub entity: String,
@@ -122,6 +128,53 @@ impl Query {
 
         params
     }
+
+    /// Build a FetchXML count aggregate equivalent to this query's filter
+    ///
+    /// `entity_name` is the logical name (e.g. "contact"); `self.entity` holds
+    /// the entity set name, which FetchXML doesn't accept. Select, order and
+    /// paging are ignored since only the row count is requested.
+    pub fn count_fetchxml(&self, entity_name: &str) -> anyhow::Result<String> {
+        let mut builder = FetchXmlBuilder::new(entity_name).aggregate(
+            format!("{}id", entity_name),
+            AggregateFunction::Count,
+            COUNT_ALIAS,
+        );
+        if let Some(filter) = &self.filter {
+            let fetch_filter = FetchFilter::from_odata(filter).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Filter '{}' cannot be expressed in FetchXML",
+                    filter.to_odata_string()
+                )
+            })?;
+            builder = builder.filter(fetch_filter);
+        }
+        Ok(builder.build())
+    }
+
+    /// Extract the row count from a count aggregate response
+    pub fn parse_count(response: &Value) -> Option<u64> {
+        response
+            .get("value")
+            .and_then(|v| v.as_array())
+            .and_then(|arr| arr.first())
+            .and_then(|obj| obj.get(COUNT_ALIAS))
+            .and_then(|t| t.as_u64())
+    }
+
+    /// Count matching rows without fetching them
+    ///
+    /// Uses a FetchXML aggregate since OData `$count` caps at 5000.
+    pub async fn execute_count(
+        &self,
+        client: &DynamicsClient,
+        entity_name: &str,
+    ) -> anyhow::Result<u64> {
+        let fetchxml = self.count_fetchxml(entity_name)?;
+        let response = client.execute_fetchxml(entity_name, &fetchxml).await?;
+        Self::parse_count(&response)
+            .ok_or_else(|| anyhow::anyhow!("Count response for {} had no total", entity_name))
+    }
 }
 
 #[cfg(test)]
@@ -183,4 +236,29 @@ mod tests {
         assert_eq!(query_with_top.top, Some(10));
         assert!(query_with_filter.filter.is_some());
     }
+
+    #[test]
+    fn test_count_request_and_parse() {
+        let query = Query::new("contacts").with_filter(Filter::eq("statecode", 0));
+
+        assert_eq!(
+            query.count_fetchxml("contact").unwrap(),
+            concat!(
+                r#"<fetch aggregate="true"><entity name="contact">"#,
+                r#"<attribute name="contactid" aggregate="count" alias="total"/>"#,
+                r#"<filter type="and"><condition attribute="statecode" operator="eq" value="0"/></filter>"#,
+                r#"</entity></fetch>"#
+            )
+        );
+
+        let response = serde_json::json!({ "value": [{ "total": 12345 }] });
+        assert_eq!(Query::parse_count(&response), Some(12345));
+        assert_eq!(
+            Query::parse_count(&serde_json::json!({ "value": [] })),
+            None
+        );
+
+        let raw = Query::new("contacts").with_filter(Filter::raw("statecode eq 0"));
+        assert!(raw.count_fetchxml("contact").is_err());
+    }
 }
@@ -5,6 +5,8 @@
 use serde_json::Value;
 use std::collections::HashMap;
 
+use super::FetchError;
+
 #[derive(Debug, Clone)]
 pub struct QueryResult {
     pub success: bool,
@@ -58,6 +60,18 @@ impl QueryResult {
         !self.success
     }
 
+    /// Classify a failed result by HTTP status (`None` on success)
+    pub fn fetch_error(&self) -> Option<FetchError> {
+        if self.success {
+            return None;
+        }
+        Some(FetchError::from_response(
+            self.status_code,
+            &self.headers,
+            self.error.as_deref().unwrap_or("Unknown error"),
+        ))
+    }
+
     /// Get the records from the response
     pub fn records(&self) -> Option<&Vec<Value>> {
         self.data.as_ref().map(|d| &d.value)
@@ -5,8 +5,9 @@
 
 use super::config::ConcurrencyConfig;
 use log::debug;
-use std::sync::Arc;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
 /// Semaphore-based concurrency limiter for controlling concurrent API requests
@@ -136,6 +137,63 @@ impl ConcurrencyLimiter {
     }
 }
 
+/// Per-environment concurrency limiters (bulkheads)
+///
+/// Each environment gets its own semaphore, so a congested environment can't
+/// starve requests to another one during cross-environment work.
+#[derive(Debug, Clone)]
+pub struct ConcurrencyPartitions {
+    config: ConcurrencyConfig,
+    limiters: Arc<Mutex<HashMap<String, ConcurrencyLimiter>>>,
+}
+
+impl ConcurrencyPartitions {
+    /// Create partitions that give every environment the same limits
+    pub fn new(config: ConcurrencyConfig) -> Self {
+        Self {
+            config,
+            limiters: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Get the limiter for an environment, creating it on first use
+    pub fn limiter(&self, env_name: &str) -> ConcurrencyLimiter {
+        self.limiters
+            .lock()
+            .unwrap()
+            .entry(env_name.to_string())
+            .or_insert_with(|| ConcurrencyLimiter::new(self.config.clone()))
+            .clone()
+    }
+
+    /// Acquire a permit from an environment's partition. Waits if that environment is at capacity.
+    pub async fn acquire(&self, env_name: &str) -> OwnedSemaphorePermit {
+        self.limiter(env_name).acquire().await
+    }
+
+    /// Statistics for one environment, if it has made any requests
+    pub fn stats(&self, env_name: &str) -> Option<ConcurrencyStats> {
+        self.limiters
+            .lock()
+            .unwrap()
+            .get(env_name)
+            .map(ConcurrencyLimiter::stats)
+    }
+
+    /// Statistics for every environment, sorted by name
+    pub fn all_stats(&self) -> Vec<(String, ConcurrencyStats)> {
+        let mut stats: Vec<_> = self
+            .limiters
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, limiter)| (name.clone(), limiter.stats()))
+            .collect();
+        stats.sort_by(|a, b| a.0.cmp(&b.0));
+        stats
+    }
+}
+
 /// Statistics for the concurrency limiter
 #[derive(Debug, Clone)]
 pub struct ConcurrencyStats {
@@ -307,4 +365,30 @@ mod tests {
         assert_eq!(limiter.max_queue_items(), 10);
         assert_eq!(limiter.max_concurrent_requests(), 20);
     }
+
+    #[tokio::test]
+    async fn test_partitions_isolate_environments() {
+        let partitions = ConcurrencyPartitions::new(ConcurrencyConfig {
+            max_concurrent_requests: 1,
+            max_queue_items: 1,
+            enabled: true,
+        });
+
+        // Saturate env A
+        let _held = partitions.acquire("a").await;
+        assert!(partitions.limiter("a").try_acquire().is_none());
+
+        // Env B has its own semaphore and isn't blocked
+        let permit_b = tokio::time::timeout(
+            tokio::time::Duration::from_millis(100),
+            partitions.acquire("b"),
+        )
+        .await;
+        assert!(permit_b.is_ok());
+
+        assert_eq!(partitions.stats("a").unwrap().in_use(), 1);
+        assert_eq!(partitions.stats("b").unwrap().requests_acquired, 1);
+        assert!(partitions.stats("c").is_none());
+        assert_eq!(partitions.all_stats().len(), 2);
+    }
 }
@@ -4,6 +4,8 @@
 //! and monitoring features with sane defaults.
 
 use super::retry::RetryConfig;
+use crate::api::operations::Operation;
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Global resilience configuration for API operations
@@ -14,6 +16,9 @@ pub struct ResilienceConfig {
     pub concurrency: ConcurrencyConfig,
     pub monitoring: MonitoringConfig,
     pub bypass: BypassConfig,
+    pub timeout: TimeoutConfig,
+    /// Systemuser id to impersonate on write requests (sent as `MSCRMCallerID`)
+    pub impersonate_user_id: Option<String>,
 }
 
 /// Concurrency limiting configuration
@@ -33,6 +38,22 @@ pub struct RateLimitConfig {
     pub requests_per_minute: u32,
     pub burst_capacity: u32,
     pub enabled: bool,
+    /// Tighter requests-per-minute limits for specific entities (entity set name → limit)
+    pub entity_overrides: HashMap<String, u32>,
+}
+
+/// Per-request timeouts by kind of operation
+///
+/// A request that exceeds its timeout is cancelled and classified as
+/// `RetryableError::Timeout`, so it is retried like any other transient failure.
+#[derive(Debug, Clone)]
+pub struct TimeoutConfig {
+    /// Queries, FetchXML and single-record reads
+    pub read: Duration,
+    /// Record writes (create, update, delete, associate) and data batches
+    pub write: Duration,
+    /// Metadata reads and schema changes (attributes, publish)
+    pub schema: Duration,
 }
 
 /// Monitoring and logging configuration
@@ -70,6 +91,8 @@ pub struct BypassConfig {
     pub step_ids: Vec<String>,
     /// Bypass Power Automate flows triggered by Dataverse events (no privilege required)
     pub power_automate_flows: bool,
+    /// Skip duplicate detection rules on creates and updates (no privilege required)
+    pub suppress_duplicate_detection: bool,
 }
 
 impl Default for BypassConfig {
@@ -79,6 +102,7 @@ impl Default for BypassConfig {
             custom_async: false,
             step_ids: Vec::new(),
             power_automate_flows: false,
+            suppress_duplicate_detection: false,
         }
     }
 }
@@ -90,6 +114,7 @@ impl BypassConfig {
             || self.custom_async
             || !self.step_ids.is_empty()
             || self.power_automate_flows
+            || self.suppress_duplicate_detection
     }
 
     /// Create a config that bypasses all custom logic (plugins, workflows, flows)
@@ -99,6 +124,7 @@ impl BypassConfig {
             custom_async: true,
             step_ids: Vec::new(),
             power_automate_flows: true,
+            suppress_duplicate_detection: false,
         }
     }
 }
@@ -111,6 +137,67 @@ impl Default for ResilienceConfig {
             concurrency: ConcurrencyConfig::default(),
             monitoring: MonitoringConfig::default(),
             bypass: BypassConfig::default(),
+            timeout: TimeoutConfig::default(),
+            impersonate_user_id: None,
+        }
+    }
+}
+
+impl Default for TimeoutConfig {
+    fn default() -> Self {
+        Self {
+            read: Duration::from_secs(300),
+            write: Duration::from_secs(600), // Large batches can take minutes
+            schema: Duration::from_secs(900), // Attribute creation and publish are slow
+        }
+    }
+}
+
+impl TimeoutConfig {
+    /// Timeout for a single operation
+    pub fn for_operation(&self, operation: &Operation) -> Duration {
+        match operation {
+            Operation::CreateAttribute { .. }
+            | Operation::UpdateAttribute { .. }
+            | Operation::DeleteAttribute { .. }
+            | Operation::CreateGlobalOptionSet { .. }
+            | Operation::PublishAllXml
+            | Operation::PublishXml { .. } => self.schema,
+            _ => self.write,
+        }
+    }
+
+    /// Timeout for a batch: the longest timeout of any operation in it
+    pub fn for_operations(&self, operations: &[Operation]) -> Duration {
+        operations
+            .iter()
+            .map(|operation| self.for_operation(operation))
+            .max()
+            .unwrap_or(self.write)
+    }
+
+    /// Load timeouts from the options system
+    pub async fn load_from_options() -> Self {
+        let options = &crate::global_config().options;
+        let defaults = Self::default();
+
+        let read_secs = options
+            .get_uint("api.timeout.read_secs")
+            .await
+            .unwrap_or(defaults.read.as_secs());
+        let write_secs = options
+            .get_uint("api.timeout.write_secs")
+            .await
+            .unwrap_or(defaults.write.as_secs());
+        let schema_secs = options
+            .get_uint("api.timeout.schema_secs")
+            .await
+            .unwrap_or(defaults.schema.as_secs());
+
+        Self {
+            read: Duration::from_secs(read_secs),
+            write: Duration::from_secs(write_secs),
+            schema: Duration::from_secs(schema_secs),
         }
     }
 }
@@ -121,6 +208,7 @@ impl Default for RateLimitConfig {
             requests_per_minute: 600, // Conservative (Dataverse allows 1200/min)
             burst_capacity: 30,       // Allow moderate bursts
             enabled: true,
+            entity_overrides: HashMap::new(),
         }
     }
 }
@@ -146,6 +234,90 @@ impl Default for MonitoringConfig {
     }
 }
 
+impl ConcurrencyConfig {
+    /// Load concurrency settings from the options system
+    pub async fn load_from_options() -> Self {
+        let options = &crate::global_config().options;
+
+        let enabled = options
+            .get_bool("api.concurrency.enabled")
+            .await
+            .unwrap_or(true);
+        let max_concurrent_requests = options
+            .get_uint("api.concurrency.max_concurrent_requests")
+            .await
+            .unwrap_or(20) as usize;
+        let max_queue_items = options
+            .get_uint("api.concurrency.max_queue_items")
+            .await
+            .unwrap_or(10) as usize;
+
+        Self {
+            max_concurrent_requests,
+            max_queue_items,
+            enabled,
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// Load rate limit settings from the options system
+    pub async fn load_from_options() -> Self {
+        let options = &crate::global_config().options;
+
+        let enabled = options
+            .get_bool("api.rate_limit.enabled")
+            .await
+            .unwrap_or(true);
+        let requests_per_minute = options
+            .get_uint("api.rate_limit.requests_per_minute")
+            .await
+            .unwrap_or(600) as u32;
+        let burst_capacity = options
+            .get_uint("api.rate_limit.burst_capacity")
+            .await
+            .unwrap_or(30) as u32;
+        let entity_overrides = options
+            .get_string("api.rate_limit.entity_overrides")
+            .await
+            .map(|value| Self::parse_entity_overrides(&value))
+            .unwrap_or_default();
+
+        Self {
+            requests_per_minute,
+            burst_capacity,
+            enabled,
+            entity_overrides,
+        }
+    }
+
+    /// Parse overrides in the form "contacts=60, accounts=120".
+    /// Malformed entries are skipped.
+    pub fn parse_entity_overrides(value: &str) -> HashMap<String, u32> {
+        value
+            .split(',')
+            .filter_map(|entry| {
+                let (entity, limit) = entry.split_once('=')?;
+                let entity = entity.trim();
+                let limit = limit
+                    .trim()
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|limit| *limit > 0)?;
+                (!entity.is_empty()).then(|| (entity.to_string(), limit))
+            })
+            .collect()
+    }
+
+    /// Requests-per-minute limit that applies to an entity
+    pub fn limit_for(&self, entity: &str) -> u32 {
+        self.entity_overrides
+            .get(entity)
+            .copied()
+            .unwrap_or(self.requests_per_minute)
+    }
+}
+
 impl ResilienceConfig {
     /// Create a new builder for ResilienceConfig
     pub fn builder() -> ResilienceConfigBuilder {
@@ -160,6 +332,7 @@ impl ResilienceConfig {
                 requests_per_minute: 300, // Very conservative
                 burst_capacity: 15,
                 enabled: true,
+                entity_overrides: HashMap::new(),
             },
             concurrency: ConcurrencyConfig {
                 max_concurrent_requests: 10,
@@ -173,6 +346,8 @@ impl ResilienceConfig {
                 log_level: LogLevel::Warn, // Less verbose in production
             },
             bypass: BypassConfig::default(),
+            timeout: TimeoutConfig::default(),
+            impersonate_user_id: None,
         }
     }
 
@@ -184,6 +359,7 @@ impl ResilienceConfig {
                 requests_per_minute: 1000, // Higher limits for dev
                 burst_capacity: 50,
                 enabled: false, // Often disabled in dev
+                entity_overrides: HashMap::new(),
             },
             concurrency: ConcurrencyConfig {
                 max_concurrent_requests: 40,
@@ -197,6 +373,8 @@ impl ResilienceConfig {
                 log_level: LogLevel::Debug, // More verbose for debugging
             },
             bypass: BypassConfig::default(),
+            timeout: TimeoutConfig::default(),
+            impersonate_user_id: None,
         }
     }
 
@@ -214,6 +392,7 @@ impl ResilienceConfig {
                 requests_per_minute: u32::MAX,
                 burst_capacity: u32::MAX,
                 enabled: false,
+                entity_overrides: HashMap::new(),
             },
             concurrency: ConcurrencyConfig {
                 max_concurrent_requests: usize::MAX,
@@ -227,6 +406,8 @@ impl ResilienceConfig {
                 log_level: LogLevel::Error,
             },
             bypass: BypassConfig::default(),
+            timeout: TimeoutConfig::default(),
+            impersonate_user_id: None,
         }
     }
 
@@ -243,6 +424,7 @@ impl ResilienceConfig {
                 requests_per_minute: 600,
                 burst_capacity: 30,
                 enabled: true,
+                entity_overrides: HashMap::new(),
             },
             concurrency: ConcurrencyConfig {
                 max_concurrent_requests: 20,
@@ -256,6 +438,8 @@ impl ResilienceConfig {
                 log_level: LogLevel::Info,
             },
             bypass: BypassConfig::all(),
+            timeout: TimeoutConfig::default(),
+            impersonate_user_id: None,
         }
     }
 
@@ -295,40 +479,6 @@ impl ResilienceConfig {
             .await
             .unwrap_or(true);
 
-        // Load rate limit options
-        let rate_limit_enabled = config
-            .options
-            .get_bool("api.rate_limit.enabled")
-            .await
-            .unwrap_or(true);
-        let requests_per_minute = config
-            .options
-            .get_uint("api.rate_limit.requests_per_minute")
-            .await
-            .unwrap_or(600) as u32;
-        let burst_capacity = config
-            .options
-            .get_uint("api.rate_limit.burst_capacity")
-            .await
-            .unwrap_or(30) as u32;
-
-        // Load concurrency options
-        let concurrency_enabled = config
-            .options
-            .get_bool("api.concurrency.enabled")
-            .await
-            .unwrap_or(true);
-        let max_concurrent_requests = config
-            .options
-            .get_uint("api.concurrency.max_concurrent_requests")
-            .await
-            .unwrap_or(20) as usize;
-        let max_queue_items = config
-            .options
-            .get_uint("api.concurrency.max_queue_items")
-            .await
-            .unwrap_or(10) as usize;
-
         // Load monitoring options
         let correlation_ids = config
             .options
@@ -376,6 +526,17 @@ impl ResilienceConfig {
             .get_bool("api.bypass.power_automate")
             .await
             .unwrap_or(false);
+        let suppress_duplicate_detection = config
+            .options
+            .get_bool("api.bypass.suppress_duplicate_detection")
+            .await
+            .unwrap_or(false);
+        let impersonate_user_id_str = config
+            .options
+            .get_string("api.impersonate_user_id")
+            .await
+            .unwrap_or_default();
+        let impersonate_user_id = parse_impersonate_user_id(&impersonate_user_id_str);
         let bypass_step_ids_str = config
             .options
             .get_string("api.bypass.step_ids")
@@ -398,16 +559,8 @@ impl ResilienceConfig {
                 backoff_multiplier,
                 jitter,
             },
-            rate_limit: RateLimitConfig {
-                requests_per_minute,
-                burst_capacity,
-                enabled: rate_limit_enabled,
-            },
-            concurrency: ConcurrencyConfig {
-                max_concurrent_requests,
-                max_queue_items,
-                enabled: concurrency_enabled,
-            },
+            rate_limit: RateLimitConfig::load_from_options().await,
+            concurrency: ConcurrencyConfig::load_from_options().await,
             monitoring: MonitoringConfig {
                 correlation_ids,
                 request_logging,
@@ -419,11 +572,29 @@ impl ResilienceConfig {
                 custom_async: bypass_custom_async,
                 step_ids: bypass_step_ids,
                 power_automate_flows: bypass_power_automate,
+                suppress_duplicate_detection,
             },
+            timeout: TimeoutConfig::load_from_options().await,
+            impersonate_user_id,
         })
     }
 }
 
+/// Parse the impersonated user id option, ignoring blank or malformed GUIDs
+fn parse_impersonate_user_id(value: &str) -> Option<String> {
+    let value = value.trim().trim_matches(|c| c == '{' || c == '}');
+    if value.is_empty() {
+        return None;
+    }
+    match uuid::Uuid::parse_str(value) {
+        Ok(id) => Some(id.to_string()),
+        Err(_) => {
+            log::warn!("Ignoring invalid impersonation user id '{}'", value);
+            None
+        }
+    }
+}
+
 /// Builder for ResilienceConfig
 #[derive(Debug)]
 pub struct ResilienceConfigBuilder {
@@ -467,6 +638,15 @@ impl ResilienceConfigBuilder {
         self
     }
 
+    /// Set a tighter requests-per-minute limit for one entity
+    pub fn entity_rate_limit(mut self, entity: impl Into<String>, rpm: u32) -> Self {
+        self.config
+            .rate_limit
+            .entity_overrides
+            .insert(entity.into(), rpm);
+        self
+    }
+
     /// Configure concurrency limiting
     pub fn concurrency_config(mut self, concurrency: ConcurrencyConfig) -> Self {
         self.config.concurrency = concurrency;
@@ -551,12 +731,30 @@ impl ResilienceConfigBuilder {
         self
     }
 
+    /// Enable/disable skipping duplicate detection rules on creates and updates
+    pub fn suppress_duplicate_detection(mut self, enabled: bool) -> Self {
+        self.config.bypass.suppress_duplicate_detection = enabled;
+        self
+    }
+
+    /// Run write requests as another user (systemuser id)
+    pub fn impersonate_user(mut self, user_id: impl Into<String>) -> Self {
+        self.config.impersonate_user_id = Some(user_id.into());
+        self
+    }
+
     /// Enable bypassing all custom business logic (sync, async, and Power Automate)
     pub fn bypass_all_custom_logic(mut self) -> Self {
         self.config.bypass = BypassConfig::all();
         self
     }
 
+    /// Configure per-request timeouts
+    pub fn timeout_config(mut self, timeout: TimeoutConfig) -> Self {
+        self.config.timeout = timeout;
+        self
+    }
+
     /// Build the final configuration
     pub fn build(self) -> ResilienceConfig {
         self.config
@@ -642,4 +840,19 @@ mod tests {
         assert!(config.concurrency.enabled);
         assert!(config.monitoring.correlation_ids);
     }
+
+    #[test]
+    fn test_parse_entity_overrides() {
+        let overrides =
+            RateLimitConfig::parse_entity_overrides("contacts=60, accounts = 120,bad,zero=0,=5");
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides["contacts"], 60);
+        assert_eq!(overrides["accounts"], 120);
+
+        let config = ResilienceConfig::builder()
+            .entity_rate_limit("contacts", 60)
+            .build();
+        assert_eq!(config.rate_limit.limit_for("contacts"), 60);
+        assert_eq!(config.rate_limit.limit_for("accounts"), 600);
+    }
 }
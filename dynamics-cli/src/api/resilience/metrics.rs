@@ -6,7 +6,9 @@
 use super::config::MonitoringConfig;
 use super::logging::OperationMetrics;
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
 use std::collections::HashMap;
+use std::fmt::Write;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
@@ -164,6 +166,16 @@ impl MetricsCollector {
         }
     }
 
+    /// Export current metrics in the Prometheus text exposition format
+    pub fn export_prometheus(&self) -> String {
+        self.snapshot().to_prometheus()
+    }
+
+    /// Export current metrics as JSON
+    pub fn export_json(&self) -> Value {
+        self.snapshot().to_json()
+    }
+
     /// Reset all metrics
     pub fn reset(&self) {
         let mut inner = self.inner.lock().unwrap();
@@ -216,6 +228,255 @@ impl MetricsCollector {
     }
 }
 
+impl MetricsSnapshot {
+    /// Render the snapshot in the Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let mut operations: Vec<_> = self.operations.iter().collect();
+        operations.sort_by(|a, b| a.operation_type.cmp(&b.operation_type));
+        let mut entities: Vec<_> = self.entities.iter().collect();
+        entities.sort_by(|a, b| a.entity_name.cmp(&b.entity_name));
+
+        let mut out = String::new();
+
+        write_header(
+            &mut out,
+            "dynamics_operations_total",
+            "counter",
+            "API operations by type and outcome",
+        );
+        for op in &operations {
+            let op_type = escape_label(&op.operation_type);
+            let _ = writeln!(
+                out,
+                "dynamics_operations_total{{operation_type=\"{}\",outcome=\"success\"}} {}",
+                op_type, op.successful_operations
+            );
+            let _ = writeln!(
+                out,
+                "dynamics_operations_total{{operation_type=\"{}\",outcome=\"failure\"}} {}",
+                op_type, op.failed_operations
+            );
+        }
+
+        write_header(
+            &mut out,
+            "dynamics_operation_duration_seconds",
+            "summary",
+            "API operation latency by type",
+        );
+        for op in &operations {
+            let op_type = escape_label(&op.operation_type);
+            let _ = writeln!(
+                out,
+                "dynamics_operation_duration_seconds_sum{{operation_type=\"{}\"}} {}",
+                op_type,
+                op.total_duration.as_secs_f64()
+            );
+            let _ = writeln!(
+                out,
+                "dynamics_operation_duration_seconds_count{{operation_type=\"{}\"}} {}",
+                op_type, op.total_operations
+            );
+        }
+
+        write_header(
+            &mut out,
+            "dynamics_operation_duration_max_seconds",
+            "gauge",
+            "Slowest observed API operation by type",
+        );
+        for op in &operations {
+            let _ = writeln!(
+                out,
+                "dynamics_operation_duration_max_seconds{{operation_type=\"{}\"}} {}",
+                escape_label(&op.operation_type),
+                op.max_duration.as_secs_f64()
+            );
+        }
+
+        write_header(
+            &mut out,
+            "dynamics_operation_error_ratio",
+            "gauge",
+            "Fraction of failed API operations by type",
+        );
+        for op in &operations {
+            let _ = writeln!(
+                out,
+                "dynamics_operation_error_ratio{{operation_type=\"{}\"}} {}",
+                escape_label(&op.operation_type),
+                op.error_rate() / 100.0
+            );
+        }
+
+        write_header(
+            &mut out,
+            "dynamics_operation_retries_total",
+            "counter",
+            "Retry attempts by operation type",
+        );
+        for op in &operations {
+            let _ = writeln!(
+                out,
+                "dynamics_operation_retries_total{{operation_type=\"{}\"}} {}",
+                escape_label(&op.operation_type),
+                op.total_retries
+            );
+        }
+
+        write_header(
+            &mut out,
+            "dynamics_http_responses_total",
+            "counter",
+            "HTTP status codes by operation type",
+        );
+        for op in &operations {
+            let mut codes: Vec<_> = op.status_codes.iter().collect();
+            codes.sort();
+            for (code, count) in codes {
+                let _ = writeln!(
+                    out,
+                    "dynamics_http_responses_total{{operation_type=\"{}\",code=\"{}\"}} {}",
+                    escape_label(&op.operation_type),
+                    code,
+                    count
+                );
+            }
+        }
+
+        write_header(
+            &mut out,
+            "dynamics_entity_operations_total",
+            "counter",
+            "API operations by entity and outcome",
+        );
+        for entity in &entities {
+            let name = escape_label(&entity.entity_name);
+            let _ = writeln!(
+                out,
+                "dynamics_entity_operations_total{{entity=\"{}\",outcome=\"success\"}} {}",
+                name, entity.successful_operations
+            );
+            let _ = writeln!(
+                out,
+                "dynamics_entity_operations_total{{entity=\"{}\",outcome=\"failure\"}} {}",
+                name, entity.failed_operations
+            );
+        }
+
+        write_header(
+            &mut out,
+            "dynamics_error_ratio",
+            "gauge",
+            "Fraction of failed API operations overall",
+        );
+        let _ = writeln!(
+            out,
+            "dynamics_error_ratio {}",
+            self.global.error_rate / 100.0
+        );
+
+        write_header(
+            &mut out,
+            "dynamics_operations_per_second",
+            "gauge",
+            "Average API operation throughput since start",
+        );
+        let _ = writeln!(
+            out,
+            "dynamics_operations_per_second {}",
+            self.global.operations_per_second
+        );
+
+        write_header(
+            &mut out,
+            "dynamics_uptime_seconds",
+            "gauge",
+            "Time since metrics collection started",
+        );
+        let _ = writeln!(
+            out,
+            "dynamics_uptime_seconds {}",
+            self.global.uptime.as_secs_f64()
+        );
+
+        out
+    }
+
+    /// Render the snapshot as JSON with durations in milliseconds
+    pub fn to_json(&self) -> Value {
+        let mut operations: Vec<_> = self.operations.iter().collect();
+        operations.sort_by(|a, b| a.operation_type.cmp(&b.operation_type));
+        let mut entities: Vec<_> = self.entities.iter().collect();
+        entities.sort_by(|a, b| a.entity_name.cmp(&b.entity_name));
+
+        json!({
+            "timestamp": self.timestamp,
+            "global": {
+                "total_operations": self.global.total_operations,
+                "successful_operations": self.global.successful_operations,
+                "failed_operations": self.global.failed_operations,
+                "error_rate": self.global.error_rate,
+                "operations_per_second": self.global.operations_per_second,
+                "average_response_time_ms": self.global.average_response_time.as_millis() as u64,
+                "uptime_secs": self.global.uptime.as_secs(),
+            },
+            "operations": operations
+                .iter()
+                .map(|op| {
+                    json!({
+                        "operation_type": op.operation_type,
+                        "total_operations": op.total_operations,
+                        "successful_operations": op.successful_operations,
+                        "failed_operations": op.failed_operations,
+                        "error_rate": op.error_rate(),
+                        "average_duration_ms": op.average_duration().as_millis() as u64,
+                        "min_duration_ms": if op.total_operations == 0 {
+                            0
+                        } else {
+                            op.min_duration.as_millis() as u64
+                        },
+                        "max_duration_ms": op.max_duration.as_millis() as u64,
+                        "total_retries": op.total_retries,
+                        "status_codes": op
+                            .status_codes
+                            .iter()
+                            .map(|(code, count)| (code.to_string(), json!(count)))
+                            .collect::<serde_json::Map<_, _>>(),
+                    })
+                })
+                .collect::<Vec<_>>(),
+            "entities": entities
+                .iter()
+                .map(|entity| {
+                    json!({
+                        "entity": entity.entity_name,
+                        "total_operations": entity.total_operations,
+                        "successful_operations": entity.successful_operations,
+                        "failed_operations": entity.failed_operations,
+                        "success_rate": entity.success_rate(),
+                        "average_duration_ms": entity.average_duration.as_millis() as u64,
+                    })
+                })
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Write the HELP and TYPE lines that precede a Prometheus metric family
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+/// Escape a Prometheus label value
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
 impl OperationTypeMetrics {
     fn new(operation_type: &str) -> Self {
         Self {
@@ -504,4 +765,75 @@ mod tests {
         let snapshot = collector.snapshot();
         assert_eq!(snapshot.global.total_operations, 0);
     }
+
+    #[test]
+    fn test_export_prometheus_and_json() {
+        let collector = MetricsCollector::new(MonitoringConfig::default());
+
+        let ok = OperationMetrics {
+            duration: Duration::from_millis(250),
+            retry_attempts: 0,
+            success: true,
+            status_code: Some(201),
+            error_message: None,
+            rate_limit_delays: vec![],
+        };
+        let failed = OperationMetrics {
+            duration: Duration::from_millis(750),
+            retry_attempts: 2,
+            success: false,
+            status_code: Some(503),
+            error_message: Some("Service unavailable".to_string()),
+            rate_limit_delays: vec![],
+        };
+        collector.record_operation("create", "contacts", &ok);
+        collector.record_operation("create", "contacts", &failed);
+
+        let text = collector.export_prometheus();
+        for name in [
+            "dynamics_operations_total",
+            "dynamics_operation_duration_seconds",
+            "dynamics_operation_error_ratio",
+            "dynamics_operation_retries_total",
+            "dynamics_http_responses_total",
+            "dynamics_entity_operations_total",
+        ] {
+            assert!(
+                text.contains(&format!("# TYPE {} ", name)),
+                "missing {}",
+                name
+            );
+        }
+        assert!(text.contains("# TYPE dynamics_operations_total counter\n"));
+        assert!(text.lines().any(|line| line
+            == "dynamics_operations_total{operation_type=\"create\",outcome=\"success\"} 1"));
+        assert!(
+            text.contains("dynamics_operation_duration_seconds_sum{operation_type=\"create\"} 1\n")
+        );
+        assert!(text.contains("dynamics_operation_error_ratio{operation_type=\"create\"} 0.5\n"));
+        assert!(
+            text.contains(
+                "dynamics_http_responses_total{operation_type=\"create\",code=\"503\"} 1\n"
+            )
+        );
+
+        // Every sample line is "name{labels} value" with a numeric value
+        for line in text.lines().filter(|line| !line.starts_with('#')) {
+            let (_, value) = line.rsplit_once(' ').unwrap();
+            assert!(value.parse::<f64>().is_ok(), "bad sample line: {}", line);
+        }
+
+        let json = collector.export_json();
+        assert_eq!(json["global"]["total_operations"], 2);
+        assert_eq!(json["operations"][0]["operation_type"], "create");
+        assert_eq!(json["operations"][0]["error_rate"], 50.0);
+        assert_eq!(json["operations"][0]["max_duration_ms"], 750);
+        assert_eq!(json["operations"][0]["status_codes"]["201"], 1);
+        assert_eq!(json["entities"][0]["entity"], "contacts");
+    }
+
+    #[test]
+    fn test_escape_label() {
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
 }
@@ -10,13 +10,14 @@ pub mod metrics;
 pub mod rate_limiter;
 pub mod retry;
 
-pub use concurrency::{ConcurrencyLimiter, ConcurrencyStats};
+pub use concurrency::{ConcurrencyLimiter, ConcurrencyPartitions, ConcurrencyStats};
 pub use config::{
     BypassConfig, ConcurrencyConfig, LogLevel, MonitoringConfig, RateLimitConfig, ResilienceConfig,
+    TimeoutConfig,
 };
 pub use logging::{ApiLogger, OperationContext, OperationMetrics};
 pub use metrics::{
     EntityMetrics, GlobalMetrics, MetricsCollector, MetricsSnapshot, OperationTypeMetrics,
 };
-pub use rate_limiter::{RateLimiter, RateLimiterStats};
+pub use rate_limiter::{RateLimitPartitions, RateLimiter, RateLimiterStats};
 pub use retry::{RetryConfig, RetryPolicy, RetryableError};
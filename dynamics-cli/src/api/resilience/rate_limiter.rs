@@ -5,6 +5,7 @@
 
 use super::config::RateLimitConfig;
 use log::{debug, warn};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
@@ -22,6 +23,44 @@ struct RateLimiterInner {
     last_refill: Instant,
     requests_made: u64,
     requests_rejected: u64,
+    /// Separate buckets for entities with a rate-limit override
+    entity_buckets: HashMap<String, EntityBucket>,
+}
+
+/// Token bucket for a single entity with its own requests-per-minute limit
+#[derive(Debug)]
+struct EntityBucket {
+    tokens: f64,
+    last_refill: Instant,
+    requests_per_minute: u32,
+    capacity: f64,
+}
+
+impl EntityBucket {
+    fn new(requests_per_minute: u32, global_burst: u32) -> Self {
+        // Never allow a burst larger than the entity's whole per-minute budget
+        let capacity = global_burst.min(requests_per_minute).max(1) as f64;
+        Self {
+            tokens: capacity,
+            last_refill: Instant::now(),
+            requests_per_minute,
+            capacity,
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill);
+        let tokens_to_add = elapsed.as_secs_f64() * self.requests_per_minute as f64 / 60.0;
+        if tokens_to_add > 0.0 {
+            self.tokens = (self.tokens + tokens_to_add).min(self.capacity);
+            self.last_refill = now;
+        }
+    }
+
+    fn wait_time(&self) -> Duration {
+        Duration::from_secs_f64(60.0 / self.requests_per_minute.max(1) as f64)
+    }
 }
 
 impl RateLimiter {
@@ -39,6 +78,7 @@ impl RateLimiter {
                 last_refill: Instant::now(),
                 requests_made: 0,
                 requests_rejected: 0,
+                entity_buckets: HashMap::new(),
             })),
             config,
         }
@@ -87,6 +127,74 @@ impl RateLimiter {
         }
     }
 
+    /// Acquire a token for a request against a specific entity
+    ///
+    /// Entities with an override in `RateLimitConfig::entity_overrides` first wait on
+    /// their own bucket, so a hot entity can't use up the global budget. Every request
+    /// still takes a token from the global bucket.
+    pub async fn acquire_for_entity(&self, entity: &str) -> bool {
+        self.acquire_for_entities([entity]).await
+    }
+
+    /// Acquire one token per overridden entity plus a single global token,
+    /// for a request (such as a batch) that touches several entities
+    pub async fn acquire_for_entities<'a>(
+        &self,
+        entities: impl IntoIterator<Item = &'a str>,
+    ) -> bool {
+        if !self.config.enabled {
+            return true;
+        }
+
+        for entity in entities {
+            while let Some(wait_duration) = self.take_entity_token(entity) {
+                debug!(
+                    "Rate limiter: Entity '{}' waiting {:?} for next token",
+                    entity, wait_duration
+                );
+                sleep(wait_duration).await;
+            }
+        }
+
+        self.acquire().await
+    }
+
+    /// Try to acquire an entity token and a global token without waiting
+    /// Returns true if acquired, false if rate limited
+    pub fn try_acquire_for_entity(&self, entity: &str) -> bool {
+        if !self.config.enabled {
+            return true;
+        }
+
+        if self.take_entity_token(entity).is_some() {
+            self.inner.lock().unwrap().requests_rejected += 1;
+            return false;
+        }
+
+        self.try_acquire()
+    }
+
+    /// Take a token from the entity's override bucket.
+    /// Returns the time to wait if the bucket is empty, None if a token was taken
+    /// or the entity has no override.
+    fn take_entity_token(&self, entity: &str) -> Option<Duration> {
+        let requests_per_minute = *self.config.entity_overrides.get(entity)?;
+
+        let mut inner = self.inner.lock().unwrap();
+        let bucket = inner
+            .entity_buckets
+            .entry(entity.to_string())
+            .or_insert_with(|| EntityBucket::new(requests_per_minute, self.config.burst_capacity));
+        bucket.refill();
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            None
+        } else {
+            Some(bucket.wait_time())
+        }
+    }
+
     /// Try to acquire a token without waiting
     /// Returns true if acquired, false if rate limited
     pub fn try_acquire(&self) -> bool {
@@ -141,6 +249,7 @@ impl RateLimiter {
         inner.last_refill = Instant::now();
         inner.requests_made = 0;
         inner.requests_rejected = 0;
+        inner.entity_buckets.clear();
     }
 
     /// Refill tokens based on elapsed time
@@ -178,6 +287,36 @@ impl RateLimiter {
     }
 }
 
+/// Per-environment rate limiters
+///
+/// Each environment keeps one limiter for the lifetime of the client manager,
+/// so token buckets (including per-entity overrides) persist across clients.
+#[derive(Debug, Clone)]
+pub struct RateLimitPartitions {
+    config: RateLimitConfig,
+    limiters: Arc<Mutex<HashMap<String, RateLimiter>>>,
+}
+
+impl RateLimitPartitions {
+    /// Create partitions that give every environment the same limits
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            limiters: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Get the limiter for an environment, creating it on first use
+    pub fn limiter(&self, env_name: &str) -> RateLimiter {
+        self.limiters
+            .lock()
+            .unwrap()
+            .entry(env_name.to_string())
+            .or_insert_with(|| RateLimiter::new(self.config.clone()))
+            .clone()
+    }
+}
+
 /// Rate limiter statistics
 #[derive(Debug, Clone)]
 pub struct RateLimiterStats {
@@ -223,6 +362,7 @@ mod tests {
             requests_per_minute: 60,
             burst_capacity: 10,
             enabled: false,
+            entity_overrides: HashMap::new(),
         };
 
         let limiter = RateLimiter::new(config);
@@ -239,6 +379,7 @@ mod tests {
             requests_per_minute: 60,
             burst_capacity: 5,
             enabled: true,
+            entity_overrides: HashMap::new(),
         };
 
         let limiter = RateLimiter::new(config);
@@ -258,6 +399,7 @@ mod tests {
             requests_per_minute: 120, // 2 requests per second
             burst_capacity: 2,
             enabled: true,
+            entity_overrides: HashMap::new(),
         };
 
         let limiter = RateLimiter::new(config);
@@ -281,6 +423,7 @@ mod tests {
             requests_per_minute: 60,
             burst_capacity: 3,
             enabled: true,
+            entity_overrides: HashMap::new(),
         };
 
         let limiter = RateLimiter::new(config);
@@ -305,6 +448,7 @@ mod tests {
             requests_per_minute: 120, // Very fast for testing
             burst_capacity: 1,
             enabled: true,
+            entity_overrides: HashMap::new(),
         };
 
         let limiter = RateLimiter::new(config);
@@ -320,4 +464,47 @@ mod tests {
         // Should have waited approximately 0.5 seconds (60/120 = 0.5s per token)
         assert!(elapsed >= Duration::from_millis(400)); // Allow some tolerance
     }
+
+    #[tokio::test]
+    async fn test_entity_override_limited_independently() {
+        let config = RateLimitConfig {
+            requests_per_minute: 600,
+            burst_capacity: 10,
+            enabled: true,
+            entity_overrides: HashMap::from([("contacts".to_string(), 2)]),
+        };
+
+        let limiter = RateLimiter::new(config);
+
+        // The override caps the burst at the entity's per-minute limit
+        assert!(limiter.try_acquire_for_entity("contacts"));
+        assert!(limiter.try_acquire_for_entity("contacts"));
+        assert!(!limiter.try_acquire_for_entity("contacts"));
+
+        // Other entities fall back to the global limit and are unaffected
+        for _ in 0..5 {
+            assert!(limiter.try_acquire_for_entity("accounts"));
+        }
+
+        // Overridden requests still count against the global bucket
+        assert_eq!(limiter.stats().requests_made, 7);
+    }
+
+    #[test]
+    fn test_partitions_share_limiter_per_environment() {
+        let partitions = RateLimitPartitions::new(RateLimitConfig {
+            requests_per_minute: 60,
+            burst_capacity: 10,
+            enabled: true,
+            entity_overrides: HashMap::from([("contacts".to_string(), 1)]),
+        });
+
+        // The entity bucket drained through one client stays drained for the next
+        assert!(partitions.limiter("a").try_acquire_for_entity("contacts"));
+        assert!(!partitions.limiter("a").try_acquire_for_entity("contacts"));
+
+        // Another environment has its own buckets
+        assert!(partitions.limiter("b").try_acquire_for_entity("contacts"));
+        assert_eq!(partitions.limiter("a").stats().requests_made, 1);
+    }
 }
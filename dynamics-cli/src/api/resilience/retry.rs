@@ -68,6 +68,9 @@ pub enum RetryableError {
     ClientError(u16),
     /// Authentication/authorization errors
     AuthError,
+    /// HTTP 412 ETag conflict: the record changed after it was fetched.
+    /// Resending the same If-Match header fails the same way.
+    Conflict,
     /// Unknown/other errors
     Unknown,
 }
@@ -82,6 +85,7 @@ impl RetryableError {
             RetryableError::Timeout => true,
             RetryableError::ClientError(_) => false,
             RetryableError::AuthError => false,
+            RetryableError::Conflict => false,
             RetryableError::Unknown => false,
         }
     }
@@ -178,7 +182,7 @@ impl RetryPolicy {
     }
 
     /// Calculate exponential backoff delay with optional jitter
-    fn calculate_delay(&self, attempt: u32) -> Duration {
+    pub fn calculate_delay(&self, attempt: u32) -> Duration {
         // Calculate base exponential delay
         let delay_ms = (self.config.base_delay.as_millis() as f64)
             * self.config.backoff_multiplier.powi(attempt as i32 - 1);
@@ -53,6 +53,28 @@ pub async fn connect_memory() -> Result<SqlitePool> {
     Ok(pool)
 }
 
+/// Insert test environments (sharing one credential set) for rows that reference them
+#[cfg(test)]
+pub async fn seed_environments(pool: &SqlitePool, names: &[&str]) -> Result<()> {
+    sqlx::query("INSERT INTO credentials (name, type, data) VALUES (?, ?, ?)")
+        .bind("test_creds")
+        .bind("username_password")
+        .bind("{}")
+        .execute(pool)
+        .await
+        .context("Failed to insert test credentials")?;
+    for name in names {
+        sqlx::query("INSERT INTO environments (name, host, credentials_ref) VALUES (?, ?, ?)")
+            .bind(name)
+            .bind(format!("https://{}.crm.dynamics.com", name))
+            .bind("test_creds")
+            .execute(pool)
+            .await
+            .context("Failed to insert test environment")?;
+    }
+    Ok(())
+}
+
 /// Run database migrations using the new migration system
 pub async fn run_migrations(pool: &SqlitePool) -> Result<()> {
     log::debug!("Running database migrations");
@@ -0,0 +1,10 @@
+-- Named search presets for the entity comparison app
+-- A preset stores a search query together with the match mode and hide mode
+CREATE TABLE search_presets (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    query TEXT NOT NULL,
+    match_mode TEXT NOT NULL,
+    hide_mode TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,6 @@
+-- Revert queue retry tracking
+
+-- Requires SQLite 3.35.0+ for DROP COLUMN
+ALTER TABLE queue_items DROP COLUMN last_error;
+ALTER TABLE queue_items DROP COLUMN max_retries;
+ALTER TABLE queue_items DROP COLUMN retry_count;
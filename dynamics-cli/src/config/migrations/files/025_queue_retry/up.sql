@@ -0,0 +1,8 @@
+-- Add automatic retry tracking to queue items
+
+-- retry_count: automatic retries already attempted for transient failures
+-- max_retries: retry budget before the item is marked permanently failed
+-- last_error: error message from the most recent failed attempt
+ALTER TABLE queue_items ADD COLUMN retry_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE queue_items ADD COLUMN max_retries INTEGER NOT NULL DEFAULT 3;
+ALTER TABLE queue_items ADD COLUMN last_error TEXT;
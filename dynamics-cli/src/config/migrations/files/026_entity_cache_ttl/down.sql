@@ -0,0 +1,4 @@
+-- Revert per-entity cache TTL override
+
+-- Requires SQLite 3.35.0+ for DROP COLUMN
+ALTER TABLE transfer_entity_mappings DROP COLUMN cache_ttl_minutes;
@@ -0,0 +1,4 @@
+-- Add per-entity cache TTL override to transfer entity mappings
+
+-- cache_ttl_minutes: NULL uses the global transfer.cache_ttl_minutes option
+ALTER TABLE transfer_entity_mappings ADD COLUMN cache_ttl_minutes INTEGER;
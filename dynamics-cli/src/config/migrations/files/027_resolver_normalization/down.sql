@@ -0,0 +1,4 @@
+-- Revert resolver match normalization options
+
+-- Requires SQLite 3.35.0+ for DROP COLUMN
+ALTER TABLE transfer_resolvers DROP COLUMN normalization_json;
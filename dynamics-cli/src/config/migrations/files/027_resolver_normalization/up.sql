@@ -0,0 +1,4 @@
+-- Add match normalization options to transfer resolvers
+
+-- normalization_json: serialized MatchNormalization, NULL uses the default (case-insensitive + trim)
+ALTER TABLE transfer_resolvers ADD COLUMN normalization_json TEXT;
@@ -0,0 +1,9 @@
+-- Field sheet columns chosen for the entity comparison Excel export
+CREATE TABLE export_columns (
+    id INTEGER PRIMARY KEY,
+    source_entity TEXT NOT NULL,
+    target_entity TEXT NOT NULL,
+    column_key TEXT NOT NULL, -- FieldColumn key (e.g., "display_name")
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(source_entity, target_entity, column_key)
+);
@@ -0,0 +1,12 @@
+-- Saved transfer preview edits, one edit-set per transfer config
+-- Each row is a dirty record: its action override and field values
+CREATE TABLE preview_edits (
+    id INTEGER PRIMARY KEY,
+    config_name TEXT NOT NULL,
+    entity_name TEXT NOT NULL,
+    source_id TEXT NOT NULL,
+    action TEXT NOT NULL,   -- RecordAction variant (e.g., "Skip")
+    fields_json TEXT NOT NULL, -- JSON map of field name to Value
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(config_name, entity_name, source_id)
+);
@@ -0,0 +1,9 @@
+-- Named field filter presets for the copy questionnaire app
+-- A preset copies either every field except the listed ones, or only the listed ones
+CREATE TABLE copy_filter_presets (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    mode TEXT NOT NULL,        -- "all_except" or "only"
+    fields_json TEXT NOT NULL, -- JSON array of field logical names
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
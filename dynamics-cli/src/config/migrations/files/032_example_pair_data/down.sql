@@ -0,0 +1,4 @@
+-- Revert embedded example pair data
+
+-- Requires SQLite 3.35.0+ for DROP COLUMN
+ALTER TABLE example_pairs DROP COLUMN embedded_data;
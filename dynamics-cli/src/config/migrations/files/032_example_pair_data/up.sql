@@ -0,0 +1,4 @@
+-- Store record data embedded in imported comparison bundles
+
+-- embedded_data: JSON {"source": ..., "target": ...}, NULL fetches the records live
+ALTER TABLE example_pairs ADD COLUMN embedded_data TEXT;
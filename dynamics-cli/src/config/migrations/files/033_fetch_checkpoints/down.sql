@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS fetch_checkpoint_pages;
+DROP TABLE IF EXISTS fetch_checkpoints;
@@ -0,0 +1,22 @@
+-- Progress of interrupted record fetches, so a re-run resumes from the last page
+CREATE TABLE fetch_checkpoints (
+    environment_name TEXT NOT NULL,
+    entity_name TEXT NOT NULL,
+    query TEXT NOT NULL,          -- Query the fetch started from; a different query starts over
+    next_link TEXT NOT NULL,      -- @odata.nextLink of the next page to fetch
+    fetched_count INTEGER NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (environment_name, entity_name),
+    FOREIGN KEY (environment_name) REFERENCES environments(name) ON DELETE CASCADE
+);
+
+-- Records of the pages fetched so far, one row per page
+CREATE TABLE fetch_checkpoint_pages (
+    environment_name TEXT NOT NULL,
+    entity_name TEXT NOT NULL,
+    page INTEGER NOT NULL,
+    data TEXT NOT NULL, -- JSON array of the page's records
+    PRIMARY KEY (environment_name, entity_name, page),
+    FOREIGN KEY (environment_name, entity_name)
+        REFERENCES fetch_checkpoints(environment_name, entity_name) ON DELETE CASCADE
+);
@@ -0,0 +1,4 @@
+-- Revert retried operation tracking
+
+-- Requires SQLite 3.35.0+ for DROP COLUMN
+ALTER TABLE queue_items DROP COLUMN retried_indices_json;
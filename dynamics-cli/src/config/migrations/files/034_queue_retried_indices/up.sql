@@ -0,0 +1,4 @@
+-- Track operations handed off to separate retry items
+
+-- retried_indices_json: operations this item no longer executes; they don't count as succeeded
+ALTER TABLE queue_items ADD COLUMN retried_indices_json TEXT DEFAULT '[]';
@@ -304,13 +304,23 @@ impl Config {
         environment_name: &str,
         entity_name: &str,
         max_age_hours: i64,
+    ) -> Result<Option<crate::api::EntityMetadata>> {
+        self.get_entity_metadata_cache_minutes(environment_name, entity_name, max_age_hours * 60)
+            .await
+    }
+
+    pub async fn get_entity_metadata_cache_minutes(
+        &self,
+        environment_name: &str,
+        entity_name: &str,
+        max_age_minutes: i64,
     ) -> Result<Option<crate::api::EntityMetadata>> {
         if let Some((metadata, cached_at)) =
             repository::entity_metadata_cache::get(&self.pool, environment_name, entity_name)
                 .await?
         {
             let age = chrono::Utc::now().signed_duration_since(cached_at);
-            if age.num_hours() < max_age_hours {
+            if age.num_minutes() < max_age_minutes {
                 return Ok(Some(metadata));
             }
         }
@@ -346,18 +356,43 @@ impl Config {
         environment_name: &str,
         entity_name: &str,
         max_age_hours: i64,
+    ) -> Result<Option<Vec<serde_json::Value>>> {
+        self.get_entity_data_cache_minutes(environment_name, entity_name, max_age_hours * 60)
+            .await
+    }
+
+    pub async fn get_entity_data_cache_minutes(
+        &self,
+        environment_name: &str,
+        entity_name: &str,
+        max_age_minutes: i64,
     ) -> Result<Option<Vec<serde_json::Value>>> {
         if let Some((data, cached_at)) =
             repository::entity_data_cache::get(&self.pool, environment_name, entity_name).await?
         {
             let age = chrono::Utc::now().signed_duration_since(cached_at);
-            if age.num_hours() < max_age_hours {
+            if age.num_minutes() < max_age_minutes {
                 return Ok(Some(data));
             }
         }
         Ok(None)
     }
 
+    /// Cache TTL in minutes for transfer data and metadata
+    ///
+    /// Uses the per-entity override when set, otherwise the `transfer.cache_ttl_minutes` option.
+    pub async fn transfer_cache_ttl_minutes(&self, entity_override: Option<u32>) -> i64 {
+        match entity_override {
+            Some(minutes) => minutes as i64,
+            None => self
+                .options
+                .get_uint("transfer.cache_ttl_minutes")
+                .await
+                .map(|minutes| minutes as i64)
+                .unwrap_or(60),
+        }
+    }
+
     pub async fn set_entity_data_cache(
         &self,
         environment_name: &str,
@@ -380,6 +415,72 @@ impl Config {
             .await
     }
 
+    /// Invalidate every cache for an environment (entity list, metadata and records)
+    pub async fn clear_environment_caches(&self, environment_name: &str) -> Result<()> {
+        self.delete_entity_cache(environment_name).await?;
+        self.delete_all_entity_metadata_cache(environment_name)
+            .await?;
+        repository::fetch_checkpoints::delete_all_for_environment(&self.pool, environment_name)
+            .await?;
+        self.delete_all_entity_data_cache(environment_name).await
+    }
+
+    /// Invalidate the metadata and record caches of a single entity
+    pub async fn clear_entity_caches(
+        &self,
+        environment_name: &str,
+        entity_name: &str,
+    ) -> Result<()> {
+        self.delete_entity_metadata_cache(environment_name, entity_name)
+            .await?;
+        self.delete_entity_data_cache(environment_name, entity_name)
+            .await
+    }
+
+    // Fetch checkpoint methods
+
+    /// Checkpoint of an interrupted record fetch, with the records fetched so far
+    pub async fn get_fetch_checkpoint(
+        &self,
+        environment_name: &str,
+        entity_name: &str,
+    ) -> Result<Option<repository::fetch_checkpoints::FetchCheckpoint>> {
+        repository::fetch_checkpoints::get(&self.pool, environment_name, entity_name).await
+    }
+
+    /// Record a fetched page so an interrupted fetch can resume after it
+    #[allow(clippy::too_many_arguments)]
+    pub async fn save_fetch_checkpoint_page(
+        &self,
+        environment_name: &str,
+        entity_name: &str,
+        query: &str,
+        page: usize,
+        records: &[serde_json::Value],
+        next_link: &str,
+        fetched_count: usize,
+    ) -> Result<()> {
+        repository::fetch_checkpoints::save_page(
+            &self.pool,
+            environment_name,
+            entity_name,
+            query,
+            page,
+            records,
+            next_link,
+            fetched_count,
+        )
+        .await
+    }
+
+    pub async fn delete_fetch_checkpoint(
+        &self,
+        environment_name: &str,
+        entity_name: &str,
+    ) -> Result<()> {
+        repository::fetch_checkpoints::delete(&self.pool, environment_name, entity_name).await
+    }
+
     // Field and prefix mapping methods
     pub async fn get_field_mappings(
         &self,
@@ -536,6 +637,26 @@ impl Config {
         repository::mappings::clear_ignored_items(&self.pool, source_entity, target_entity).await
     }
 
+    /// Get the export column keys chosen for an entity comparison
+    pub async fn get_export_columns(
+        &self,
+        source_entity: &str,
+        target_entity: &str,
+    ) -> Result<Vec<String>> {
+        repository::mappings::get_export_columns(&self.pool, source_entity, target_entity).await
+    }
+
+    /// Save the export column keys for an entity comparison
+    pub async fn set_export_columns(
+        &self,
+        source_entity: &str,
+        target_entity: &str,
+        columns: &[String],
+    ) -> Result<()> {
+        repository::mappings::set_export_columns(&self.pool, source_entity, target_entity, columns)
+            .await
+    }
+
     pub async fn get_negative_matches(
         &self,
         source_entity: &str,
@@ -559,6 +680,21 @@ impl Config {
         .await
     }
 
+    pub async fn import_negative_matches(
+        &self,
+        source_entity: &str,
+        target_entity: &str,
+        source_fields: &[String],
+    ) -> Result<usize> {
+        repository::mappings::import_negative_matches(
+            &self.pool,
+            source_entity,
+            target_entity,
+            source_fields,
+        )
+        .await
+    }
+
     pub async fn delete_negative_match(
         &self,
         source_entity: &str,
@@ -574,6 +710,46 @@ impl Config {
         .await
     }
 
+    /// List saved search presets for entity comparison
+    pub async fn list_search_presets(
+        &self,
+    ) -> Result<Vec<repository::search_presets::SearchPreset>> {
+        repository::search_presets::list_search_presets(&self.pool).await
+    }
+
+    /// Save a search preset (replaces an existing preset with the same name)
+    pub async fn save_search_preset(
+        &self,
+        preset: &repository::search_presets::SearchPreset,
+    ) -> Result<()> {
+        repository::search_presets::save_search_preset(&self.pool, preset).await
+    }
+
+    /// Delete a search preset by name
+    pub async fn delete_search_preset(&self, name: &str) -> Result<()> {
+        repository::search_presets::delete_search_preset(&self.pool, name).await
+    }
+
+    /// List saved field filter presets for copying questionnaires
+    pub async fn list_copy_filter_presets(
+        &self,
+    ) -> Result<Vec<repository::copy_filter_presets::CopyFilterPreset>> {
+        repository::copy_filter_presets::list_copy_filter_presets(&self.pool).await
+    }
+
+    /// Save a field filter preset (replaces an existing preset with the same name)
+    pub async fn save_copy_filter_preset(
+        &self,
+        preset: &repository::copy_filter_presets::CopyFilterPreset,
+    ) -> Result<()> {
+        repository::copy_filter_presets::save_copy_filter_preset(&self.pool, preset).await
+    }
+
+    /// Delete a field filter preset by name
+    pub async fn delete_copy_filter_preset(&self, name: &str) -> Result<()> {
+        repository::copy_filter_presets::delete_copy_filter_preset(&self.pool, name).await
+    }
+
     /// Get example pairs for entity comparison
     pub async fn get_example_pairs(
         &self,
@@ -649,6 +825,23 @@ impl Config {
             .await
     }
 
+    pub async fn update_queue_item_retried_indices(
+        &self,
+        id: &str,
+        retried_indices: &[usize],
+    ) -> Result<()> {
+        repository::queue::update_queue_item_retried_indices(&self.pool, id, retried_indices).await
+    }
+
+    pub async fn update_queue_item_retry(
+        &self,
+        id: &str,
+        retry_count: u32,
+        last_error: Option<&str>,
+    ) -> Result<()> {
+        repository::queue::update_queue_item_retry(&self.pool, id, retry_count, last_error).await
+    }
+
     pub async fn mark_queue_item_interrupted(
         &self,
         id: &str,
@@ -665,6 +858,10 @@ impl Config {
         repository::queue::delete_queue_item(&self.pool, id).await
     }
 
+    pub async fn prune_completed_queue_items(&self) -> Result<u64> {
+        repository::queue::prune_completed_queue_items(&self.pool).await
+    }
+
     pub async fn clear_queue(&self) -> Result<()> {
         repository::queue::clear_queue(&self.pool).await
     }
@@ -680,3 +877,39 @@ impl Config {
         repository::queue::save_queue_settings(&self.pool, settings).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_zero_minute_ttl_always_misses() {
+        let config = Config::new_test().await.unwrap();
+
+        // Cache rows reference an environment
+        db::seed_environments(&config.pool, &["dev"]).await.unwrap();
+
+        let records = vec![serde_json::json!({ "accountid": "1" })];
+        config
+            .set_entity_data_cache("dev", "account", &records)
+            .await
+            .unwrap();
+
+        let ttl = config.transfer_cache_ttl_minutes(Some(0)).await;
+        assert_eq!(ttl, 0);
+        let cached = config
+            .get_entity_data_cache_minutes("dev", "account", ttl)
+            .await
+            .unwrap();
+        assert!(cached.is_none());
+
+        // Without an override the global default (60 minutes) applies
+        let ttl = config.transfer_cache_ttl_minutes(None).await;
+        assert_eq!(ttl, 60);
+        let cached = config
+            .get_entity_data_cache_minutes("dev", "account", ttl)
+            .await
+            .unwrap();
+        assert_eq!(cached, Some(records));
+    }
+}
@@ -79,6 +79,16 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?,
     )?;
 
+    registry.register(
+        OptionDefBuilder::new("api", "rate_limit.entity_overrides")
+            .display_name("Entity Rate Limits")
+            .description(
+                "Per-entity requests per minute, e.g. \"contacts=60, accounts=120\" (entity set names)",
+            )
+            .string_type("", Some(500))
+            .build()?,
+    )?;
+
     // Concurrency options
     registry.register(
         OptionDefBuilder::new("api", "concurrency.enabled")
@@ -106,6 +116,33 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?,
     )?;
 
+    // Timeout options
+    registry.register(
+        OptionDefBuilder::new("api", "timeout.read_secs")
+            .display_name("Read Timeout (s)")
+            .description("Cancel queries and record reads after this many seconds (10-3600)")
+            .uint_type(300, Some(10), Some(3600))
+            .build()?,
+    )?;
+
+    registry.register(
+        OptionDefBuilder::new("api", "timeout.write_secs")
+            .display_name("Write Timeout (s)")
+            .description("Cancel record writes and batches after this many seconds (10-3600)")
+            .uint_type(600, Some(10), Some(3600))
+            .build()?,
+    )?;
+
+    registry.register(
+        OptionDefBuilder::new("api", "timeout.schema_secs")
+            .display_name("Schema Timeout (s)")
+            .description(
+                "Cancel metadata reads and schema changes after this many seconds (10-3600)",
+            )
+            .uint_type(900, Some(10), Some(3600))
+            .build()?,
+    )?;
+
     // Monitoring options
     registry.register(
         OptionDefBuilder::new("api", "monitoring.correlation_ids")
@@ -176,6 +213,27 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?,
     )?;
 
-    log::info!("Registered {} API options", 20);
+    registry.register(
+        OptionDefBuilder::new("api", "bypass.suppress_duplicate_detection")
+            .display_name("Suppress Duplicate Detection")
+            .description(
+                "Skip duplicate detection rules when transfers and syncs create or update records (no privilege required)",
+            )
+            .bool_type(false)
+            .build()?,
+    )?;
+
+    // Impersonation - run writes as another user to preserve ownership
+    registry.register(
+        OptionDefBuilder::new("api", "impersonate_user_id")
+            .display_name("Impersonate User ID")
+            .description(
+                "Systemuser GUID that transfers, syncs and other queued writes run as (sent as MSCRMCallerID). Requires the prvActOnBehalfOfAnotherUser privilege. Empty disables impersonation.",
+            )
+            .string_type("", Some(38))
+            .build()?,
+    )?;
+
+    log::info!("Registered {} API options", 26);
     Ok(())
 }
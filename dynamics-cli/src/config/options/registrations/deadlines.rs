@@ -0,0 +1,21 @@
+//! Deadlines-related options registration
+
+use crate::config::options::{OptionDefBuilder, OptionsRegistry};
+use anyhow::Result;
+
+/// Register all deadlines-related options
+pub fn register(registry: &OptionsRegistry) -> Result<()> {
+    // Natural key used to match imported rows to existing deadlines
+    registry.register(
+        OptionDefBuilder::new("deadlines", "match_key")
+            .display_name("Deadline Match Key")
+            .description(
+                "Comma-separated fields that identify a deadline when importing, e.g. \"name, date\" or \"cgk_code\". Use name and date for the deadline name and date, or any field's logical name.",
+            )
+            .string_type("name, date", Some(500))
+            .build()?,
+    )?;
+
+    log::info!("Registered {} deadlines options", 1);
+    Ok(())
+}
@@ -4,6 +4,7 @@ use crate::config::options::{OptionDefBuilder, OptionsRegistry};
 use crate::tui::KeyBinding;
 use anyhow::Result;
 use crossterm::event::KeyCode;
+use std::collections::HashMap;
 
 /// List all apps that have registered keybinds
 pub fn list_apps(registry: &OptionsRegistry) -> Vec<String> {
@@ -75,6 +76,44 @@ pub fn list_all_actions(registry: &OptionsRegistry) -> Vec<String> {
     all_actions
 }
 
+/// Get the key bound to an action ("app.action" format), falling back to its registered default
+pub fn effective_keybind(
+    registry: &OptionsRegistry,
+    keybinds: &HashMap<String, KeyBinding>,
+    action: &str,
+) -> Option<KeyBinding> {
+    keybinds.get(action).copied().or_else(|| {
+        registry
+            .get(&format!("keybind.{}", action))
+            .and_then(|def| def.default.as_string().ok())
+            .and_then(|default| default.parse().ok())
+    })
+}
+
+/// Find the actions that `key` would clash with if bound to `action`
+///
+/// Actions clash when they belong to the same app, or when either one is
+/// global, since global keybinds apply in every app.
+pub fn find_conflicts(
+    registry: &OptionsRegistry,
+    keybinds: &HashMap<String, KeyBinding>,
+    action: &str,
+    key: KeyBinding,
+) -> Vec<String> {
+    let app_of = |action: &str| action.split('.').next().unwrap_or_default().to_string();
+    let app = app_of(action);
+
+    list_all_actions(registry)
+        .into_iter()
+        .filter(|other| other != action)
+        .filter(|other| {
+            let other_app = app_of(other);
+            other_app == app || other_app == "global" || app == "global"
+        })
+        .filter(|other| effective_keybind(registry, keybinds, other) == Some(key))
+        .collect()
+}
+
 /// Register all keybind-related options
 pub fn register(registry: &OptionsRegistry) -> Result<()> {
     // Global keybinds
@@ -102,6 +141,14 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?,
     )?;
 
+    registry.register(
+        OptionDefBuilder::new("keybind", "global.command_palette")
+            .display_name("Command Palette")
+            .description("Search apps and actions and jump to them")
+            .keybind_type(KeyBinding::ctrl(KeyCode::Char('p')))
+            .build()?,
+    )?;
+
     // Migration Environment app keybinds
     registry.register(
         OptionDefBuilder::new("keybind", "migration_env.create")
@@ -168,6 +215,16 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?,
     )?;
 
+    registry.register(
+        OptionDefBuilder::new("keybind", "migration_comparison.diff")
+            .display_name("Diff Comparisons")
+            .description(
+                "Mark the selected comparison as diff base, or diff it against the marked one",
+            )
+            .keybind_type(KeyCode::Char('c'))
+            .build()?,
+    )?;
+
     // Entity Comparison app keybinds
     registry.register(
         OptionDefBuilder::new("keybind", "entity_comparison.back")
@@ -217,6 +274,22 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?,
     )?;
 
+    registry.register(
+        OptionDefBuilder::new("keybind", "entity_comparison.collapse_all")
+            .display_name("Collapse All")
+            .description("Collapse every node in the source and target trees")
+            .keybind_type(KeyCode::Char('z'))
+            .build()?,
+    )?;
+
+    registry.register(
+        OptionDefBuilder::new("keybind", "entity_comparison.expand_all")
+            .display_name("Expand All")
+            .description("Expand every node in the source and target trees")
+            .keybind_type(KeyCode::Char('Z'))
+            .build()?,
+    )?;
+
     registry.register(
         OptionDefBuilder::new("keybind", "entity_comparison.refresh")
             .display_name("Refresh Metadata")
@@ -353,6 +426,14 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?,
     )?;
 
+    registry.register(
+        OptionDefBuilder::new("keybind", "entity_comparison.open_search_presets")
+            .display_name("Search Presets")
+            .description("Save and load named search presets")
+            .keybind_type(KeyCode::Char('v'))
+            .build()?,
+    )?;
+
     registry.register(
         OptionDefBuilder::new("keybind", "entity_comparison.export")
             .display_name("Export to Excel")
@@ -367,3 +448,123 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
     );
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> OptionsRegistry {
+        let registry = OptionsRegistry::new();
+        register(&registry).unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_remapped_keybind_replaces_default() {
+        let registry = registry();
+        let mut keybinds = HashMap::new();
+        assert_eq!(
+            effective_keybind(&registry, &keybinds, "migration_env.create"),
+            Some(KeyBinding::new(KeyCode::Char('n')))
+        );
+
+        keybinds.insert(
+            "migration_env.create".to_string(),
+            KeyBinding::ctrl(KeyCode::Char('n')),
+        );
+        assert_eq!(
+            effective_keybind(&registry, &keybinds, "migration_env.create"),
+            Some(KeyBinding::ctrl(KeyCode::Char('n')))
+        );
+    }
+
+    #[test]
+    fn test_find_conflicts_within_app_and_global() {
+        let registry = registry();
+        let keybinds = HashMap::new();
+
+        // Same app
+        assert_eq!(
+            find_conflicts(
+                &registry,
+                &keybinds,
+                "migration_env.create",
+                KeyBinding::new(KeyCode::Char('d'))
+            ),
+            vec!["migration_env.delete".to_string()]
+        );
+        // Other apps' keys are free to reuse
+        assert!(
+            find_conflicts(
+                &registry,
+                &keybinds,
+                "migration_env.create",
+                KeyBinding::new(KeyCode::Char('b'))
+            )
+            .is_empty()
+        );
+        // Global keybinds apply in every app
+        assert_eq!(
+            find_conflicts(
+                &registry,
+                &keybinds,
+                "migration_env.create",
+                KeyBinding::ctrl(KeyCode::Char('a'))
+            ),
+            vec!["global.app_launcher".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_remapped_key_dispatches_mapped_message() {
+        use crate::tui::apps::migration::migration_environment_app::Msg;
+        use crate::tui::apps::migration::{MigrationEnvironmentApp, MigrationEnvironmentState};
+        use crate::tui::state::RuntimeConfig;
+        use crate::tui::{App, Subscription};
+        use crossterm::event::KeyModifiers;
+        use std::sync::Arc;
+
+        // Resolve every action the way RuntimeConfig::load_from_options does, with one remap
+        let registry = registry();
+        let mut overrides = HashMap::new();
+        overrides.insert(
+            "migration_env.create".to_string(),
+            KeyBinding::ctrl(KeyCode::Char('n')),
+        );
+        let keybinds = list_all_actions(&registry)
+            .into_iter()
+            .filter_map(|action| {
+                effective_keybind(&registry, &overrides, &action).map(|key| (action, key))
+            })
+            .collect();
+        crate::RUNTIME_CONFIG
+            .get_or_init(|| arc_swap::ArcSwap::from_pointee(RuntimeConfig::default()))
+            .store(Arc::new(RuntimeConfig {
+                keybinds,
+                ..RuntimeConfig::default()
+            }));
+
+        // Look up key events the way the runtime dispatches global subscriptions
+        let subscriptions: HashMap<KeyBinding, Msg> =
+            MigrationEnvironmentApp::subscriptions(&MigrationEnvironmentState::new())
+                .into_iter()
+                .filter_map(|sub| match sub {
+                    Subscription::Keyboard { key, msg, .. } => Some((key, msg)),
+                    _ => None,
+                })
+                .collect();
+        let dispatch =
+            |code, modifiers| subscriptions.get(&KeyBinding::with_modifiers(code, modifiers));
+
+        assert!(matches!(
+            dispatch(KeyCode::Char('n'), KeyModifiers::CONTROL),
+            Some(Msg::OpenCreateModal)
+        ));
+        assert!(dispatch(KeyCode::Char('n'), KeyModifiers::NONE).is_none());
+        // Unmapped actions keep their defaults
+        assert!(matches!(
+            dispatch(KeyCode::Char('d'), KeyModifiers::NONE),
+            Some(Msg::RequestDelete)
+        ));
+    }
+}
@@ -0,0 +1,32 @@
+//! Migration-related options registration
+
+use crate::config::options::{OptionDefBuilder, OptionsRegistry};
+use anyhow::Result;
+
+/// Register all migration-related options
+pub fn register(registry: &OptionsRegistry) -> Result<()> {
+    // How otherwise unmatched names are compared for Similar matches
+    registry.register(
+        OptionDefBuilder::new("migration", "name_similarity")
+            .display_name("Name Similarity")
+            .description(
+                "How otherwise unmatched field and entity names are compared for Similar matches: character edit distance, or token overlap that ignores word order (first_name_last ~ last_first_name).",
+            )
+            .enum_type(vec!["edit_distance", "token_set"], "edit_distance")
+            .build()?,
+    )?;
+
+    // Matches at or above this confidence are copied into a seeded transfer config
+    registry.register(
+        OptionDefBuilder::new("migration", "seed_min_confidence")
+            .display_name("Seed Min Confidence")
+            .description(
+                "Minimum match confidence for a field match to become a Copy mapping when seeding a transfer config from a comparison (exact/manual/import 1.0, prefix 0.9, type mismatches 0.6-0.7).",
+            )
+            .float_type(0.9, Some(0.0), Some(1.0))
+            .build()?,
+    )?;
+
+    log::info!("Registered {} migration options", 2);
+    Ok(())
+}
@@ -1,9 +1,13 @@
 //! Registration of all application options
 
 pub mod api;
+pub mod deadlines;
 pub mod keybinds;
 pub mod keys;
+pub mod migration;
+pub mod sync;
 pub mod themes;
+pub mod transfer;
 pub mod tui;
 pub mod update;
 
@@ -13,10 +17,14 @@ use anyhow::Result;
 /// Register all options from all modules
 pub fn register_all(registry: &OptionsRegistry) -> Result<()> {
     api::register(registry)?;
+    deadlines::register(registry)?;
     tui::register(registry)?;
     themes::register(registry)?;
     keybinds::register(registry)?;
     keys::register(registry)?;
+    migration::register(registry)?;
+    sync::register(registry)?;
+    transfer::register(registry)?;
     update::register(registry)?;
     Ok(())
 }
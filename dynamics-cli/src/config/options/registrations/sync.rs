@@ -0,0 +1,32 @@
+//! Sync-related options registration
+
+use crate::config::options::{OptionDefBuilder, OptionsRegistry};
+use anyhow::Result;
+
+/// Register all sync-related options
+pub fn register(registry: &OptionsRegistry) -> Result<()> {
+    // Optimistic concurrency for updates to existing target records
+    registry.register(
+        OptionDefBuilder::new("sync", "optimistic_concurrency")
+            .display_name("Optimistic Concurrency")
+            .description(
+                "Send updates with If-Match on the ETag fetched during analysis. Records edited in the target since then fail with \"changed since fetch\" instead of being overwritten.",
+            )
+            .bool_type(false)
+            .build()?,
+    )?;
+
+    // Changeset size for queued sync operations
+    registry.register(
+        OptionDefBuilder::new("sync", "changeset_size")
+            .display_name("Changeset Size")
+            .description(
+                "Maximum number of operations per queued changeset. Each changeset runs as one transaction, so smaller sizes keep a failure from rolling back as much work.",
+            )
+            .uint_type(50, Some(1), Some(1000))
+            .build()?,
+    )?;
+
+    log::info!("Registered {} sync options", 2);
+    Ok(())
+}
@@ -0,0 +1,43 @@
+//! Transfer-related options registration
+
+use crate::config::options::{OptionDefBuilder, OptionsRegistry};
+use anyhow::Result;
+
+/// Register all transfer-related options
+pub fn register(registry: &OptionsRegistry) -> Result<()> {
+    // Lua module directory option
+    registry.register(
+        OptionDefBuilder::new("transfer", "lua_module_dir")
+            .display_name("Lua Module Directory")
+            .description(
+                "Directory that Lua transform scripts can load shared modules from with require(\"name\"). Empty disables require.",
+            )
+            .string_type("", Some(500))
+            .build()?,
+    )?;
+
+    // Cache TTL for transfer record and metadata fetches
+    registry.register(
+        OptionDefBuilder::new("transfer", "cache_ttl_minutes")
+            .display_name("Transfer Cache TTL (minutes)")
+            .description(
+                "How long fetched records and metadata stay cached for transfer previews. Entity mappings can override this. 0 disables the cache.",
+            )
+            .uint_type(60, Some(0), Some(10080))
+            .build()?,
+    )?;
+
+    // Lowest lib.* log level kept from transform runs
+    registry.register(
+        OptionDefBuilder::new("transfer", "lua_log_level")
+            .display_name("Lua Log Level")
+            .description(
+                "Lowest level of lib.debug/lib.log/lib.warn/lib.error_log messages kept from transform runs. Raise it to hide debug output from noisy scripts.",
+            )
+            .enum_type(vec!["debug", "info", "warn", "error"], "debug")
+            .build()?,
+    )?;
+
+    log::info!("Registered {} transfer options", 3);
+    Ok(())
+}
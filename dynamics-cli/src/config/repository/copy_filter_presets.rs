@@ -0,0 +1,105 @@
+//! Field filter presets repository for the copy questionnaire app
+
+use anyhow::{Context, Result};
+use sqlx::SqlitePool;
+
+/// A named field filter preset (mode + listed fields)
+///
+/// The mode is stored by its key so the table stays independent of the TUI enums.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyFilterPreset {
+    pub name: String,
+    pub mode: String,
+    pub fields: Vec<String>,
+}
+
+/// List all field filter presets ordered by name
+pub async fn list_copy_filter_presets(pool: &SqlitePool) -> Result<Vec<CopyFilterPreset>> {
+    let rows: Vec<(String, String, String)> = sqlx::query_as(
+        "SELECT name, mode, fields_json FROM copy_filter_presets
+         ORDER BY name",
+    )
+    .fetch_all(pool)
+    .await
+    .context("Failed to list copy filter presets")?;
+
+    rows.into_iter()
+        .map(|(name, mode, fields_json)| {
+            Ok(CopyFilterPreset {
+                name,
+                mode,
+                fields: serde_json::from_str(&fields_json)
+                    .context("Failed to parse preset fields")?,
+            })
+        })
+        .collect()
+}
+
+/// Save a field filter preset (insert or replace by name)
+pub async fn save_copy_filter_preset(pool: &SqlitePool, preset: &CopyFilterPreset) -> Result<()> {
+    let fields_json =
+        serde_json::to_string(&preset.fields).context("Failed to serialize preset fields")?;
+
+    sqlx::query(
+        "INSERT INTO copy_filter_presets (name, mode, fields_json)
+         VALUES (?, ?, ?)
+         ON CONFLICT(name)
+         DO UPDATE SET mode = excluded.mode, fields_json = excluded.fields_json",
+    )
+    .bind(&preset.name)
+    .bind(&preset.mode)
+    .bind(fields_json)
+    .execute(pool)
+    .await
+    .context("Failed to save copy filter preset")?;
+
+    Ok(())
+}
+
+/// Delete a field filter preset by name
+pub async fn delete_copy_filter_preset(pool: &SqlitePool, name: &str) -> Result<()> {
+    sqlx::query("DELETE FROM copy_filter_presets WHERE name = ?")
+        .bind(name)
+        .execute(pool)
+        .await
+        .context("Failed to delete copy filter preset")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+
+    #[tokio::test]
+    async fn test_copy_filter_preset_crud() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+
+        let mut preset = CopyFilterPreset {
+            name: "texts only".to_string(),
+            mode: "only".to_string(),
+            fields: vec!["nrq_questiontext".to_string(), "nrq_tooltip".to_string()],
+        };
+
+        // Create
+        save_copy_filter_preset(&pool, &preset).await.unwrap();
+        let presets = list_copy_filter_presets(&pool).await.unwrap();
+        assert_eq!(presets, vec![preset.clone()]);
+
+        // Update (same name replaces the row)
+        preset.mode = "all_except".to_string();
+        preset.fields = vec!["nrq_regex".to_string()];
+        save_copy_filter_preset(&pool, &preset).await.unwrap();
+        let presets = list_copy_filter_presets(&pool).await.unwrap();
+        assert_eq!(presets, vec![preset]);
+
+        // Delete
+        delete_copy_filter_preset(&pool, "texts only")
+            .await
+            .unwrap();
+        let presets = list_copy_filter_presets(&pool).await.unwrap();
+        assert!(presets.is_empty());
+    }
+}
@@ -90,3 +90,34 @@ pub async fn delete_all_for_environment(pool: &SqlitePool, environment_name: &st
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+
+    #[tokio::test]
+    async fn test_clear_removes_only_matching_rows() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+        db::seed_environments(&pool, &["dev", "prod"])
+            .await
+            .unwrap();
+
+        let records = vec![serde_json::json!({ "id": "1" })];
+        for (env, entity) in [("dev", "account"), ("dev", "contact"), ("prod", "account")] {
+            set(&pool, env, entity, &records).await.unwrap();
+        }
+
+        // Clearing one entity leaves its siblings and other environments alone
+        delete(&pool, "dev", "account").await.unwrap();
+        assert!(get(&pool, "dev", "account").await.unwrap().is_none());
+        assert!(get(&pool, "dev", "contact").await.unwrap().is_some());
+        assert!(get(&pool, "prod", "account").await.unwrap().is_some());
+
+        // Clearing an environment leaves other environments alone
+        delete_all_for_environment(&pool, "dev").await.unwrap();
+        assert!(get(&pool, "dev", "contact").await.unwrap().is_none());
+        assert!(get(&pool, "prod", "account").await.unwrap().is_some());
+    }
+}
@@ -9,8 +9,8 @@ pub async fn get_example_pairs(
     source_entity: &str,
     target_entity: &str,
 ) -> Result<Vec<crate::tui::apps::migration::entity_comparison::ExamplePair>> {
-    let rows: Vec<(String, String, String, Option<String>)> = sqlx::query_as(
-        "SELECT id, source_uuid, target_uuid, label FROM example_pairs
+    let rows: Vec<(String, String, String, Option<String>, Option<String>)> = sqlx::query_as(
+        "SELECT id, source_uuid, target_uuid, label, embedded_data FROM example_pairs
          WHERE source_entity = ? AND target_entity = ?
          ORDER BY created_at DESC",
     )
@@ -22,13 +22,14 @@ pub async fn get_example_pairs(
 
     Ok(rows
         .into_iter()
-        .map(|(id, source_uuid, target_uuid, label)| {
+        .map(|(id, source_uuid, target_uuid, label, embedded_data)| {
             let mut pair = crate::tui::apps::migration::entity_comparison::ExamplePair::new(
                 source_uuid,
                 target_uuid,
             );
             pair.id = id;
             pair.label = label;
+            pair.embedded_data = embedded_data.and_then(|json| serde_json::from_str(&json).ok());
             pair
         })
         .collect())
@@ -41,11 +42,18 @@ pub async fn save_example_pair(
     target_entity: &str,
     pair: &crate::tui::apps::migration::entity_comparison::ExamplePair,
 ) -> Result<()> {
+    let embedded_data = pair
+        .embedded_data
+        .as_ref()
+        .map(serde_json::to_string)
+        .transpose()
+        .context("Failed to serialize embedded example data")?;
+
     sqlx::query(
-        "INSERT INTO example_pairs (id, source_entity, target_entity, source_uuid, target_uuid, label)
-         VALUES (?, ?, ?, ?, ?, ?)
+        "INSERT INTO example_pairs (id, source_entity, target_entity, source_uuid, target_uuid, label, embedded_data)
+         VALUES (?, ?, ?, ?, ?, ?, ?)
          ON CONFLICT(id)
-         DO UPDATE SET source_uuid = excluded.source_uuid, target_uuid = excluded.target_uuid, label = excluded.label",
+         DO UPDATE SET source_uuid = excluded.source_uuid, target_uuid = excluded.target_uuid, label = excluded.label, embedded_data = excluded.embedded_data",
     )
     .bind(&pair.id)
     .bind(source_entity)
@@ -53,6 +61,7 @@ pub async fn save_example_pair(
     .bind(&pair.source_record_id)
     .bind(&pair.target_record_id)
     .bind(&pair.label)
+    .bind(embedded_data)
     .execute(pool)
     .await
     .context("Failed to save example pair")?;
@@ -0,0 +1,168 @@
+//! Repository for resumable record fetch checkpoints
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use sqlx::SqlitePool;
+
+/// Where an interrupted fetch left off
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchCheckpoint {
+    /// Query the fetch started from
+    pub query: String,
+    /// `@odata.nextLink` of the next page to fetch
+    pub next_link: String,
+    /// Number of records fetched so far
+    pub fetched_count: usize,
+    /// Number of pages fetched so far
+    pub pages: usize,
+    /// Records of all pages fetched so far, in page order
+    pub records: Vec<Value>,
+    /// When the last page was checkpointed
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Get the checkpoint for an entity, with the records fetched so far
+pub async fn get(
+    pool: &SqlitePool,
+    environment_name: &str,
+    entity_name: &str,
+) -> Result<Option<FetchCheckpoint>> {
+    let row: Option<(String, String, i64, chrono::DateTime<chrono::Utc>)> = sqlx::query_as(
+        r#"
+        SELECT query, next_link, fetched_count, updated_at
+        FROM fetch_checkpoints
+        WHERE environment_name = ? AND entity_name = ?
+        "#,
+    )
+    .bind(environment_name)
+    .bind(entity_name)
+    .fetch_optional(pool)
+    .await
+    .context("Failed to fetch fetch checkpoint")?;
+
+    let Some((query, next_link, fetched_count, updated_at)) = row else {
+        return Ok(None);
+    };
+
+    let pages: Vec<(String,)> = sqlx::query_as(
+        r#"
+        SELECT data
+        FROM fetch_checkpoint_pages
+        WHERE environment_name = ? AND entity_name = ?
+        ORDER BY page
+        "#,
+    )
+    .bind(environment_name)
+    .bind(entity_name)
+    .fetch_all(pool)
+    .await
+    .context("Failed to fetch fetch checkpoint pages")?;
+
+    let mut records = Vec::new();
+    for (data_json,) in &pages {
+        let page: Vec<Value> =
+            serde_json::from_str(data_json).context("Failed to parse checkpoint page JSON")?;
+        records.extend(page);
+    }
+
+    Ok(Some(FetchCheckpoint {
+        query,
+        next_link,
+        fetched_count: fetched_count as usize,
+        pages: pages.len(),
+        records,
+        updated_at,
+    }))
+}
+
+/// Record a fetched page and the link to the page after it
+///
+/// Pages are numbered from 1. Only the new page's records are written, so
+/// checkpointing stays cheap as the fetch grows.
+#[allow(clippy::too_many_arguments)]
+pub async fn save_page(
+    pool: &SqlitePool,
+    environment_name: &str,
+    entity_name: &str,
+    query: &str,
+    page: usize,
+    records: &[Value],
+    next_link: &str,
+    fetched_count: usize,
+) -> Result<()> {
+    let data_json =
+        serde_json::to_string(records).context("Failed to serialize checkpoint page to JSON")?;
+
+    let mut tx = pool.begin().await?;
+
+    // Upsert rather than REPLACE, which would cascade-delete the stored pages
+    sqlx::query(
+        r#"
+        INSERT INTO fetch_checkpoints (environment_name, entity_name, query, next_link, fetched_count, updated_at)
+        VALUES (?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+        ON CONFLICT(environment_name, entity_name) DO UPDATE SET
+            query = excluded.query,
+            next_link = excluded.next_link,
+            fetched_count = excluded.fetched_count,
+            updated_at = CURRENT_TIMESTAMP
+        "#,
+    )
+    .bind(environment_name)
+    .bind(entity_name)
+    .bind(query)
+    .bind(next_link)
+    .bind(fetched_count as i64)
+    .execute(&mut *tx)
+    .await
+    .context("Failed to save fetch checkpoint")?;
+
+    sqlx::query(
+        r#"
+        INSERT OR REPLACE INTO fetch_checkpoint_pages (environment_name, entity_name, page, data)
+        VALUES (?, ?, ?, ?)
+        "#,
+    )
+    .bind(environment_name)
+    .bind(entity_name)
+    .bind(page as i64)
+    .bind(data_json)
+    .execute(&mut *tx)
+    .await
+    .context("Failed to save fetch checkpoint page")?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Delete the checkpoint for an entity (its pages cascade)
+pub async fn delete(pool: &SqlitePool, environment_name: &str, entity_name: &str) -> Result<()> {
+    sqlx::query(
+        r#"
+        DELETE FROM fetch_checkpoints
+        WHERE environment_name = ? AND entity_name = ?
+        "#,
+    )
+    .bind(environment_name)
+    .bind(entity_name)
+    .execute(pool)
+    .await
+    .context("Failed to delete fetch checkpoint")?;
+
+    Ok(())
+}
+
+/// Delete all fetch checkpoints for an environment
+pub async fn delete_all_for_environment(pool: &SqlitePool, environment_name: &str) -> Result<()> {
+    sqlx::query(
+        r#"
+        DELETE FROM fetch_checkpoints
+        WHERE environment_name = ?
+        "#,
+    )
+    .bind(environment_name)
+    .execute(pool)
+    .await
+    .context("Failed to delete fetch checkpoints for environment")?;
+
+    Ok(())
+}
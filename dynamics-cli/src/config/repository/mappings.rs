@@ -375,6 +375,64 @@ pub async fn clear_ignored_items(
     Ok(())
 }
 
+/// Get the export column keys chosen for an entity comparison
+/// Returns an empty Vec when no selection has been saved
+pub async fn get_export_columns(
+    pool: &SqlitePool,
+    source_entity: &str,
+    target_entity: &str,
+) -> Result<Vec<String>> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        "SELECT column_key FROM export_columns
+         WHERE source_entity = ? AND target_entity = ?
+         ORDER BY id",
+    )
+    .bind(source_entity)
+    .bind(target_entity)
+    .fetch_all(pool)
+    .await
+    .context("Failed to fetch export columns")?;
+
+    Ok(rows.into_iter().map(|(column_key,)| column_key).collect())
+}
+
+/// Replace the export column keys for an entity comparison
+pub async fn set_export_columns(
+    pool: &SqlitePool,
+    source_entity: &str,
+    target_entity: &str,
+    columns: &[String],
+) -> Result<()> {
+    let mut tx = pool.begin().await.context("Failed to begin transaction")?;
+
+    sqlx::query(
+        "DELETE FROM export_columns
+         WHERE source_entity = ? AND target_entity = ?",
+    )
+    .bind(source_entity)
+    .bind(target_entity)
+    .execute(&mut *tx)
+    .await
+    .context("Failed to clear export columns")?;
+
+    for column_key in columns {
+        sqlx::query(
+            "INSERT INTO export_columns (source_entity, target_entity, column_key)
+             VALUES (?, ?, ?)",
+        )
+        .bind(source_entity)
+        .bind(target_entity)
+        .bind(column_key)
+        .execute(&mut *tx)
+        .await
+        .context("Failed to insert export column")?;
+    }
+
+    tx.commit().await.context("Failed to commit transaction")?;
+
+    Ok(())
+}
+
 /// Get all negative matches for a source/target entity pair
 /// Returns HashSet of source field names that should be blocked from prefix matching
 pub async fn get_negative_matches(
@@ -440,6 +498,66 @@ pub async fn delete_negative_match(
     Ok(())
 }
 
+/// Import a batch of negative matches for a source/target entity pair
+/// Returns how many of the fields were not already blocked
+pub async fn import_negative_matches(
+    pool: &SqlitePool,
+    source_entity: &str,
+    target_entity: &str,
+    source_fields: &[String],
+) -> Result<usize> {
+    let mut tx = pool.begin().await.context("Failed to begin transaction")?;
+    let mut added = 0;
+
+    for source_field in source_fields {
+        let result = sqlx::query(
+            "INSERT INTO negative_matches (source_entity, target_entity, source_field)
+             VALUES (?, ?, ?)
+             ON CONFLICT(source_entity, target_entity, source_field)
+             DO NOTHING",
+        )
+        .bind(source_entity)
+        .bind(target_entity)
+        .bind(source_field)
+        .execute(&mut *tx)
+        .await
+        .context("Failed to import negative match")?;
+        added += result.rows_affected() as usize;
+    }
+
+    tx.commit()
+        .await
+        .context("Failed to commit negative matches")?;
+    Ok(added)
+}
+
+/// Parse a negative matches file into source field names
+/// One field per line; blank lines and `#` comments are skipped
+pub fn parse_negative_matches(content: &str) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter(|line| seen.insert(line.to_string()))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Format negative matches as a shareable file, one field per line in sorted order
+pub fn format_negative_matches(source_fields: &std::collections::HashSet<String>) -> String {
+    let mut fields: Vec<&String> = source_fields.iter().collect();
+    fields.sort();
+
+    let mut content =
+        String::from("# Negative matches: source fields blocked from prefix matching\n");
+    for field in fields {
+        content.push_str(field);
+        content.push('\n');
+    }
+    content
+}
+
 // ============================================================================
 // Multi-Entity Support (N:M Comparisons)
 // ============================================================================
@@ -562,3 +680,61 @@ pub async fn delete_specific_field_mapping_qualified(
     )
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::metadata::FieldMetadata;
+    use crate::config::db;
+    use crate::services::matching::core::compute_field_matches;
+
+    #[tokio::test]
+    async fn test_imported_negative_matches_persist_and_block_prefix_matches() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+
+        add_negative_match(&pool, "cgk_contact", "nrq_contact", "cgk_status")
+            .await
+            .unwrap();
+
+        let file = "# shared false positives\ncgk_status\n\ncgk_code\ncgk_code\n";
+        let fields = parse_negative_matches(file);
+        assert_eq!(fields, vec!["cgk_status", "cgk_code"]);
+
+        let added = import_negative_matches(&pool, "cgk_contact", "nrq_contact", &fields)
+            .await
+            .unwrap();
+        assert_eq!(added, 1);
+
+        // Reload from the database
+        let negative = get_negative_matches(&pool, "cgk_contact", "nrq_contact")
+            .await
+            .unwrap();
+        assert_eq!(negative.len(), 2);
+        assert!(negative.contains("cgk_code"));
+        assert_eq!(
+            parse_negative_matches(&format_negative_matches(&negative)),
+            vec!["cgk_code", "cgk_status"]
+        );
+
+        let mut prefixes = HashMap::new();
+        prefixes.insert("cgk_".to_string(), vec!["nrq_".to_string()]);
+        let matches = compute_field_matches(
+            &[
+                FieldMetadata::string("cgk_code"),
+                FieldMetadata::string("cgk_name"),
+            ],
+            &[
+                FieldMetadata::string("nrq_code"),
+                FieldMetadata::string("nrq_name"),
+            ],
+            &HashMap::new(),
+            &HashMap::new(),
+            &prefixes,
+            &negative,
+            crate::services::matching::NameSimilarity::EditDistance,
+        );
+        assert!(!matches.contains_key("cgk_code"));
+        assert!(matches.contains_key("cgk_name"));
+    }
+}
@@ -1,15 +1,19 @@
 //! Repository layer for database operations
 
+pub mod copy_filter_presets;
 pub mod credentials;
 pub mod entity_cache;
 pub mod entity_data_cache;
 pub mod entity_metadata_cache;
 pub mod environments;
 pub mod examples;
+pub mod fetch_checkpoints;
 pub mod legacy;
 pub mod mappings;
 pub mod migrations;
+pub mod preview_edits;
 pub mod queue;
+pub mod search_presets;
 pub mod tokens;
 pub mod transfer;
 pub mod update_metadata;
@@ -0,0 +1,182 @@
+//! Saved transfer preview edits, one edit-set per transfer config
+
+use anyhow::{Context, Result};
+use sqlx::SqlitePool;
+
+use crate::transfer::{RecordAction, RecordEdit};
+
+/// Replace the saved edit-set for a transfer config
+pub async fn save_preview_edits(
+    pool: &SqlitePool,
+    config_name: &str,
+    edits: &[RecordEdit],
+) -> Result<()> {
+    let mut tx = pool.begin().await.context("Failed to start transaction")?;
+
+    sqlx::query("DELETE FROM preview_edits WHERE config_name = ?")
+        .bind(config_name)
+        .execute(&mut *tx)
+        .await
+        .context("Failed to clear preview edits")?;
+
+    for edit in edits {
+        let action = serde_json::to_value(edit.action)
+            .context("Failed to serialize record action")?
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        let fields_json =
+            serde_json::to_string(&edit.fields).context("Failed to serialize field values")?;
+
+        sqlx::query(
+            "INSERT INTO preview_edits (config_name, entity_name, source_id, action, fields_json)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(config_name)
+        .bind(&edit.entity_name)
+        .bind(edit.source_id.to_string())
+        .bind(action)
+        .bind(fields_json)
+        .execute(&mut *tx)
+        .await
+        .context("Failed to insert preview edit")?;
+    }
+
+    tx.commit().await.context("Failed to commit transaction")?;
+
+    Ok(())
+}
+
+/// Load the saved edit-set for a transfer config (empty if none was saved)
+pub async fn load_preview_edits(pool: &SqlitePool, config_name: &str) -> Result<Vec<RecordEdit>> {
+    let rows: Vec<(String, String, String, String)> = sqlx::query_as(
+        "SELECT entity_name, source_id, action, fields_json FROM preview_edits
+         WHERE config_name = ?
+         ORDER BY id",
+    )
+    .bind(config_name)
+    .fetch_all(pool)
+    .await
+    .context("Failed to load preview edits")?;
+
+    rows.into_iter()
+        .map(|(entity_name, source_id, action, fields_json)| {
+            let action: RecordAction = serde_json::from_value(serde_json::Value::String(action))
+                .context("Failed to parse record action")?;
+            Ok(RecordEdit {
+                entity_name,
+                source_id: uuid::Uuid::parse_str(&source_id)
+                    .context("Failed to parse source ID")?,
+                action,
+                fields: serde_json::from_str(&fields_json)
+                    .context("Failed to parse field values")?,
+            })
+        })
+        .collect()
+}
+
+/// Delete the saved edit-set for a transfer config
+pub async fn delete_preview_edits(pool: &SqlitePool, config_name: &str) -> Result<()> {
+    sqlx::query("DELETE FROM preview_edits WHERE config_name = ?")
+        .bind(config_name)
+        .execute(pool)
+        .await
+        .context("Failed to delete preview edits")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+    use crate::transfer::{ResolvedEntity, ResolvedRecord, ResolvedTransfer, Value};
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    fn transfer(ids: &[Uuid]) -> ResolvedTransfer {
+        let mut entity = ResolvedEntity::new("account", 1, "accountid");
+        for id in ids {
+            let mut fields = HashMap::new();
+            fields.insert("name".to_string(), Value::String("Original".to_string()));
+            entity.add_record(ResolvedRecord::create(*id, fields));
+        }
+        let mut transfer = ResolvedTransfer::new("accounts", "dev", "prod");
+        transfer.add_entity(entity);
+        transfer
+    }
+
+    #[tokio::test]
+    async fn test_edit_set_round_trip_and_reapply() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+
+        let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+
+        // Hand-edit two records: skip one, rename the other
+        let mut edited = transfer(&ids);
+        let entity = &mut edited.entities[0];
+        entity.records[0].action = RecordAction::Skip;
+        entity.mark_dirty(ids[0]);
+        entity.records[2]
+            .fields
+            .insert("name".to_string(), Value::String("Renamed".to_string()));
+        entity.records[2]
+            .fields
+            .insert("revenue".to_string(), Value::Float(12.5));
+        entity.mark_dirty(ids[2]);
+
+        let edits = edited.dirty_edits();
+        assert_eq!(edits.len(), 2);
+
+        save_preview_edits(&pool, "accounts", &edits).await.unwrap();
+        let mut loaded = load_preview_edits(&pool, "accounts").await.unwrap();
+        loaded.sort_by_key(|e| e.source_id);
+        let mut expected = edits.clone();
+        expected.sort_by_key(|e| e.source_id);
+        assert_eq!(loaded, expected);
+
+        // Other configs are unaffected
+        assert!(
+            load_preview_edits(&pool, "contacts")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // Reapply to a freshly resolved transfer
+        let mut refreshed = transfer(&ids);
+        assert_eq!(refreshed.apply_edits(&loaded), 2);
+
+        let entity = &refreshed.entities[0];
+        assert_eq!(entity.records[0].action, RecordAction::Skip);
+        assert_eq!(entity.records[1].action, RecordAction::Create);
+        assert_eq!(
+            entity.records[2].fields.get("name"),
+            Some(&Value::String("Renamed".to_string()))
+        );
+        assert_eq!(
+            entity.records[2].fields.get("revenue"),
+            Some(&Value::Float(12.5))
+        );
+        assert!(entity.is_dirty(ids[0]));
+        assert!(!entity.is_dirty(ids[1]));
+        assert!(entity.is_dirty(ids[2]));
+
+        // Saving again replaces the edit-set; delete clears it
+        save_preview_edits(&pool, "accounts", &loaded[..1])
+            .await
+            .unwrap();
+        assert_eq!(
+            load_preview_edits(&pool, "accounts").await.unwrap().len(),
+            1
+        );
+        delete_preview_edits(&pool, "accounts").await.unwrap();
+        assert!(
+            load_preview_edits(&pool, "accounts")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
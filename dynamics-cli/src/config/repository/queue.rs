@@ -37,15 +37,18 @@ pub async fn save_queue_item(pool: &SqlitePool, item: &QueueItem) -> Result<()>
         .flatten();
     let succeeded_indices_json = serde_json::to_string(&item.succeeded_indices)
         .context("Failed to serialize succeeded_indices")?;
+    let retried_indices_json = serde_json::to_string(&item.retried_indices)
+        .context("Failed to serialize retried_indices")?;
 
     sqlx::query(
         r#"
         INSERT INTO queue_items (
             id, environment_name, operations_json, metadata_json,
             status, priority, result_json, was_interrupted, interrupted_at,
-            succeeded_indices_json, created_at, updated_at
+            succeeded_indices_json, retried_indices_json, retry_count, max_retries, last_error,
+            created_at, updated_at
         )
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
         ON CONFLICT(id) DO UPDATE SET
             status = excluded.status,
             priority = excluded.priority,
@@ -53,6 +56,10 @@ pub async fn save_queue_item(pool: &SqlitePool, item: &QueueItem) -> Result<()>
             was_interrupted = excluded.was_interrupted,
             interrupted_at = excluded.interrupted_at,
             succeeded_indices_json = excluded.succeeded_indices_json,
+            retried_indices_json = excluded.retried_indices_json,
+            retry_count = excluded.retry_count,
+            max_retries = excluded.max_retries,
+            last_error = excluded.last_error,
             updated_at = CURRENT_TIMESTAMP
         "#,
     )
@@ -66,6 +73,10 @@ pub async fn save_queue_item(pool: &SqlitePool, item: &QueueItem) -> Result<()>
     .bind(item.was_interrupted)
     .bind(item.interrupted_at)
     .bind(&succeeded_indices_json)
+    .bind(&retried_indices_json)
+    .bind(item.retry_count as i64)
+    .bind(item.max_retries as i64)
+    .bind(&item.last_error)
     .execute(pool)
     .await
     .with_context(|| format!("Failed to save queue item '{}'", item.id))?;
@@ -79,7 +90,8 @@ pub async fn get_queue_item(pool: &SqlitePool, id: &str) -> Result<Option<QueueI
         r#"
         SELECT id, environment_name, operations_json, metadata_json,
                status, priority, result_json, was_interrupted, interrupted_at,
-               succeeded_indices_json
+               succeeded_indices_json, retried_indices_json, retry_count, max_retries,
+               last_error
         FROM queue_items
         WHERE id = ?
         "#,
@@ -102,7 +114,8 @@ pub async fn list_queue_items(pool: &SqlitePool) -> Result<Vec<QueueItem>> {
         r#"
         SELECT id, environment_name, operations_json, metadata_json,
                status, priority, result_json, was_interrupted, interrupted_at,
-               succeeded_indices_json
+               succeeded_indices_json, retried_indices_json, retry_count, max_retries,
+               last_error
         FROM queue_items
         ORDER BY priority ASC, created_at ASC
         "#,
@@ -183,6 +196,55 @@ pub async fn update_queue_item_result(
     Ok(())
 }
 
+/// Update automatic retry bookkeeping for a queue item
+pub async fn update_queue_item_retry(
+    pool: &SqlitePool,
+    id: &str,
+    retry_count: u32,
+    last_error: Option<&str>,
+) -> Result<()> {
+    let query_result = sqlx::query(
+        "UPDATE queue_items SET retry_count = ?, last_error = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+    )
+    .bind(retry_count as i64)
+    .bind(last_error)
+    .bind(id)
+    .execute(pool)
+    .await
+    .with_context(|| format!("Failed to update retry state for queue item '{}'", id))?;
+
+    if query_result.rows_affected() == 0 {
+        anyhow::bail!("Queue item '{}' not found", id);
+    }
+
+    Ok(())
+}
+
+/// Update the indices of operations handed off to separate retry items
+pub async fn update_queue_item_retried_indices(
+    pool: &SqlitePool,
+    id: &str,
+    retried_indices: &[usize],
+) -> Result<()> {
+    let indices_json =
+        serde_json::to_string(retried_indices).context("Failed to serialize retried_indices")?;
+
+    let query_result = sqlx::query(
+        "UPDATE queue_items SET retried_indices_json = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?"
+    )
+    .bind(&indices_json)
+    .bind(id)
+    .execute(pool)
+    .await
+    .with_context(|| format!("Failed to update retried_indices for queue item '{}'", id))?;
+
+    if query_result.rows_affected() == 0 {
+        anyhow::bail!("Queue item '{}' not found", id);
+    }
+
+    Ok(())
+}
+
 /// Update succeeded indices for a queue item (for partial retry support)
 pub async fn update_queue_item_succeeded_indices(
     pool: &SqlitePool,
@@ -274,6 +336,18 @@ pub async fn delete_queue_item(pool: &SqlitePool, id: &str) -> Result<()> {
     Ok(())
 }
 
+/// Delete all completed (Done) queue items, returning how many were removed
+pub async fn prune_completed_queue_items(pool: &SqlitePool) -> Result<u64> {
+    let result = sqlx::query("DELETE FROM queue_items WHERE status = ?")
+        .bind(status_to_string(&OperationStatus::Done))
+        .execute(pool)
+        .await
+        .context("Failed to prune completed queue items")?;
+
+    log::info!("Pruned {} completed queue items", result.rows_affected());
+    Ok(result.rows_affected())
+}
+
 /// Clear all queue items
 pub async fn clear_queue(pool: &SqlitePool) -> Result<()> {
     sqlx::query("DELETE FROM queue_items")
@@ -351,6 +425,10 @@ fn parse_queue_item_row(row: sqlx::sqlite::SqliteRow) -> Result<QueueItem> {
     let was_interrupted: bool = row.try_get("was_interrupted")?;
     let interrupted_at: Option<DateTime<Utc>> = row.try_get("interrupted_at")?;
     let succeeded_indices_json: Option<String> = row.try_get("succeeded_indices_json").ok();
+    let retried_indices_json: Option<String> = row.try_get("retried_indices_json").ok();
+    let retry_count: i64 = row.try_get("retry_count")?;
+    let max_retries: i64 = row.try_get("max_retries")?;
+    let last_error: Option<String> = row.try_get("last_error")?;
 
     let operations = serde_json::from_str(&operations_json)
         .with_context(|| format!("Failed to deserialize operations for queue item '{}'", id))?;
@@ -363,6 +441,9 @@ fn parse_queue_item_row(row: sqlx::sqlite::SqliteRow) -> Result<QueueItem> {
     let succeeded_indices: Vec<usize> = succeeded_indices_json
         .and_then(|json| serde_json::from_str(&json).ok())
         .unwrap_or_default();
+    let retried_indices: Vec<usize> = retried_indices_json
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
 
     Ok(QueueItem {
         id,
@@ -375,6 +456,11 @@ fn parse_queue_item_row(row: sqlx::sqlite::SqliteRow) -> Result<QueueItem> {
         was_interrupted,
         interrupted_at,
         succeeded_indices,
+        retried_indices,
+        retry_count: retry_count as u32,
+        max_retries: max_retries as u32,
+        last_error,
+        retry_after: None,    // Runtime state, not persisted
         cached_columns: None, // Computed at render time
     })
 }
@@ -439,3 +525,91 @@ fn parse_sort_mode(s: &str) -> SortMode {
         _ => SortMode::Priority, // Default fallback
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::operations::{Operation, Operations};
+    use crate::config::db;
+    use crate::tui::apps::queue::models::QueueMetadata;
+
+    fn item(description: &str, status: OperationStatus) -> QueueItem {
+        let mut item = QueueItem::new(
+            Operations::from_operations(vec![Operation::create(
+                "accounts",
+                serde_json::json!({ "name": description }),
+            )]),
+            QueueMetadata {
+                source: "Test".to_string(),
+                entity_type: "account".to_string(),
+                description: description.to_string(),
+                row_number: None,
+                environment_name: "dev".to_string(),
+            },
+            10,
+        );
+        item.status = status;
+        item
+    }
+
+    fn descriptions(items: &[QueueItem], status: OperationStatus) -> Vec<String> {
+        let mut names: Vec<String> = items
+            .iter()
+            .filter(|i| i.status == status)
+            .map(|i| i.metadata.description.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn test_queue_round_trip_and_prune() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+
+        // Queue items reference an environment
+        db::seed_environments(&pool, &["dev"]).await.unwrap();
+
+        let mut failed = item("failed", OperationStatus::Failed);
+        failed.retry_count = 2;
+        failed.last_error = Some("HTTP 503".to_string());
+        failed.retried_indices = vec![0];
+
+        for queue_item in [
+            item("pending-1", OperationStatus::Pending),
+            item("pending-2", OperationStatus::Pending),
+            failed,
+            item("done", OperationStatus::Done),
+        ] {
+            save_queue_item(&pool, &queue_item).await.unwrap();
+        }
+
+        let loaded = list_queue_items(&pool).await.unwrap();
+        assert_eq!(loaded.len(), 4);
+        assert_eq!(
+            descriptions(&loaded, OperationStatus::Pending),
+            vec!["pending-1", "pending-2"]
+        );
+        assert_eq!(
+            descriptions(&loaded, OperationStatus::Failed),
+            vec!["failed"]
+        );
+        assert_eq!(descriptions(&loaded, OperationStatus::Done), vec!["done"]);
+
+        let failed = loaded
+            .iter()
+            .find(|i| i.status == OperationStatus::Failed)
+            .unwrap();
+        assert_eq!(failed.retry_count, 2);
+        assert_eq!(failed.last_error.as_deref(), Some("HTTP 503"));
+        assert_eq!(failed.operations.len(), 1);
+        assert_eq!(failed.retried_indices, vec![0]);
+        assert!(failed.succeeded_indices.is_empty());
+
+        // Pruning only removes completed items
+        assert_eq!(prune_completed_queue_items(&pool).await.unwrap(), 1);
+        let loaded = list_queue_items(&pool).await.unwrap();
+        assert_eq!(loaded.len(), 3);
+        assert!(descriptions(&loaded, OperationStatus::Done).is_empty());
+    }
+}
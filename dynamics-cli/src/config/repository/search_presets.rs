@@ -0,0 +1,106 @@
+//! Search presets repository for the entity comparison app
+
+use anyhow::{Context, Result};
+use sqlx::SqlitePool;
+
+/// A named search preset (query + match mode + hide mode)
+///
+/// Modes are stored by their key so the table stays independent of the TUI enums.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchPreset {
+    pub name: String,
+    pub query: String,
+    pub match_mode: String,
+    pub hide_mode: String,
+}
+
+/// List all search presets ordered by name
+pub async fn list_search_presets(pool: &SqlitePool) -> Result<Vec<SearchPreset>> {
+    let rows: Vec<(String, String, String, String)> = sqlx::query_as(
+        "SELECT name, query, match_mode, hide_mode FROM search_presets
+         ORDER BY name",
+    )
+    .fetch_all(pool)
+    .await
+    .context("Failed to list search presets")?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(name, query, match_mode, hide_mode)| SearchPreset {
+            name,
+            query,
+            match_mode,
+            hide_mode,
+        })
+        .collect())
+}
+
+/// Save a search preset (insert or replace by name)
+pub async fn save_search_preset(pool: &SqlitePool, preset: &SearchPreset) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO search_presets (name, query, match_mode, hide_mode)
+         VALUES (?, ?, ?, ?)
+         ON CONFLICT(name)
+         DO UPDATE SET query = excluded.query, match_mode = excluded.match_mode, hide_mode = excluded.hide_mode",
+    )
+    .bind(&preset.name)
+    .bind(&preset.query)
+    .bind(&preset.match_mode)
+    .bind(&preset.hide_mode)
+    .execute(pool)
+    .await
+    .context("Failed to save search preset")?;
+
+    Ok(())
+}
+
+/// Delete a search preset by name
+pub async fn delete_search_preset(pool: &SqlitePool, name: &str) -> Result<()> {
+    sqlx::query("DELETE FROM search_presets WHERE name = ?")
+        .bind(name)
+        .execute(pool)
+        .await
+        .context("Failed to delete search preset")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+
+    #[tokio::test]
+    async fn test_search_preset_crud() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+
+        let mut preset = SearchPreset {
+            name: "unmatched custom".to_string(),
+            query: "nrq_".to_string(),
+            match_mode: "substring".to_string(),
+            hide_mode: "hide_matched".to_string(),
+        };
+
+        // Create
+        save_search_preset(&pool, &preset).await.unwrap();
+        let presets = list_search_presets(&pool).await.unwrap();
+        assert_eq!(presets, vec![preset.clone()]);
+
+        // Update (same name replaces the row)
+        preset.query = "cr123_".to_string();
+        preset.match_mode = "fuzzy".to_string();
+        save_search_preset(&pool, &preset).await.unwrap();
+        let presets = list_search_presets(&pool).await.unwrap();
+        assert_eq!(presets.len(), 1);
+        assert_eq!(presets[0].query, "cr123_");
+        assert_eq!(presets[0].match_mode, "fuzzy");
+
+        // Delete
+        delete_search_preset(&pool, "unmatched custom")
+            .await
+            .unwrap();
+        let presets = list_search_presets(&pool).await.unwrap();
+        assert!(presets.is_empty());
+    }
+}
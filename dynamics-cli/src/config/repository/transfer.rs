@@ -4,8 +4,8 @@ use anyhow::{Context, Result};
 use sqlx::{Row, SqlitePool};
 
 use crate::transfer::{
-    EntityMapping, FieldMapping, MatchField, OperationFilter, Resolver, ResolverFallback,
-    SourceFilter, TransferConfig, TransferMode, Transform,
+    EntityMapping, FieldMapping, MatchField, MatchNormalization, OperationFilter, Resolver,
+    ResolverFallback, SourceFilter, TransferConfig, TransferMode, Transform,
 };
 
 /// Summary of a transfer config (for listing)
@@ -106,7 +106,7 @@ pub async fn get_transfer_config(pool: &SqlitePool, name: &str) -> Result<Option
         r#"
         SELECT id, source_entity, target_entity, priority,
                allow_creates, allow_updates, allow_deletes, allow_deactivates,
-               source_filter_json, target_filter_json
+               source_filter_json, target_filter_json, cache_ttl_minutes
         FROM transfer_entity_mappings
         WHERE config_id = ?
         ORDER BY priority, source_entity
@@ -159,7 +159,7 @@ pub async fn get_transfer_config(pool: &SqlitePool, name: &str) -> Result<Option
         // Get resolvers for this entity mapping
         let resolver_rows = sqlx::query(
             r#"
-            SELECT id, name, source_entity, match_fields_json, fallback
+            SELECT id, name, source_entity, match_fields_json, fallback, normalization_json
             FROM transfer_resolvers
             WHERE entity_mapping_id = ?
             ORDER BY name
@@ -190,12 +190,19 @@ pub async fn get_transfer_config(pool: &SqlitePool, name: &str) -> Result<Option
             let match_fields =
                 serde_json::from_str::<Vec<MatchField>>(&match_fields_json).unwrap_or_default();
 
+            // Parse normalization_json (NULL = default normalization)
+            let normalization = row
+                .try_get::<Option<String>, _>("normalization_json")?
+                .and_then(|json| serde_json::from_str::<MatchNormalization>(&json).ok())
+                .unwrap_or_default();
+
             resolvers.push(Resolver {
                 id: Some(row.try_get("id")?),
                 name: row.try_get("name")?,
                 source_entity: row.try_get("source_entity")?,
                 match_fields,
                 fallback,
+                normalization,
             });
         }
 
@@ -217,6 +224,9 @@ pub async fn get_transfer_config(pool: &SqlitePool, name: &str) -> Result<Option
             operation_filter,
             source_filter,
             target_filter,
+            cache_ttl_minutes: entity_row
+                .try_get::<Option<i64>, _>("cache_ttl_minutes")?
+                .map(|minutes| minutes as u32),
             resolvers,
             field_mappings,
         });
@@ -308,9 +318,9 @@ pub async fn save_transfer_config(pool: &SqlitePool, config: &TransferConfig) ->
             INSERT INTO transfer_entity_mappings (
                 config_id, source_entity, target_entity, priority,
                 allow_creates, allow_updates, allow_deletes, allow_deactivates,
-                source_filter_json, target_filter_json
+                source_filter_json, target_filter_json, cache_ttl_minutes
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(config_id)
@@ -339,6 +349,7 @@ pub async fn save_transfer_config(pool: &SqlitePool, config: &TransferConfig) ->
         })
         .bind(&source_filter_json)
         .bind(&target_filter_json)
+        .bind(entity.cache_ttl_minutes.map(|minutes| minutes as i64))
         .execute(&mut *tx)
         .await
         .context("Failed to insert entity mapping")?;
@@ -376,10 +387,17 @@ pub async fn save_transfer_config(pool: &SqlitePool, config: &TransferConfig) ->
             let match_fields_json =
                 serde_json::to_string(&resolver.match_fields).unwrap_or_else(|_| "[]".to_string());
 
+            // Only persist non-default normalization
+            let normalization_json = if resolver.normalization.is_default() {
+                None
+            } else {
+                serde_json::to_string(&resolver.normalization).ok()
+            };
+
             sqlx::query(
                 r#"
-                INSERT INTO transfer_resolvers (entity_mapping_id, name, source_entity, match_fields_json, fallback)
-                VALUES (?, ?, ?, ?, ?)
+                INSERT INTO transfer_resolvers (entity_mapping_id, name, source_entity, match_fields_json, fallback, normalization_json)
+                VALUES (?, ?, ?, ?, ?, ?)
                 "#,
             )
             .bind(entity_id)
@@ -387,6 +405,7 @@ pub async fn save_transfer_config(pool: &SqlitePool, config: &TransferConfig) ->
             .bind(&resolver.source_entity)
             .bind(&match_fields_json)
             .bind(&fallback_str)
+            .bind(&normalization_json)
             .execute(&mut *tx)
             .await
             .context("Failed to insert resolver")?;
@@ -2,6 +2,9 @@
 //!
 //! Parses CSV files containing field mappings for Dynamics 365 migration.
 //! CSV format: source_field, target_field, match_type, notes
+//!
+//! The delimiter (comma, semicolon or tab) and the text encoding (UTF-8,
+//! UTF-16 via BOM, Latin-1 fallback) are detected automatically.
 
 use csv::ReaderBuilder;
 use serde::Deserialize;
@@ -53,13 +56,19 @@ pub struct CsvImportData {
 /// - Empty target_field → source-side ignore
 /// - Empty source_field → target-side ignore
 ///
+/// The delimiter is sniffed from the first lines (see `detect_delimiter`).
+///
 /// Returns: CsvImportData with mappings distributed by type
 pub fn parse_csv_field_mappings(content: &str) -> Result<CsvImportData, String> {
     let mut data = CsvImportData::default();
 
+    let content = content.strip_prefix('\u{FEFF}').unwrap_or(content);
+    let delimiter = detect_delimiter(content);
+
     // Parse CSV
     let mut reader = ReaderBuilder::new()
         .has_headers(true)
+        .delimiter(delimiter)
         .trim(csv::Trim::All)
         .from_reader(content.as_bytes());
 
@@ -211,6 +220,152 @@ pub fn parse_csv_field_mappings(content: &str) -> Result<CsvImportData, String>
     Ok(data)
 }
 
+/// Parse CSV field mappings from raw file bytes, detecting the encoding first
+pub fn parse_csv_field_mappings_bytes(bytes: &[u8]) -> Result<CsvImportData, String> {
+    let content = decode_csv_bytes(bytes)?;
+    parse_csv_field_mappings(&content)
+}
+
+/// Parse a two-column CSV of source → target field pairs
+///
+/// ```csv
+/// source_field,target_field
+/// cgk_name,nrq_name
+/// cgk_accountid,nrq_accountid
+/// ```
+///
+/// The first row is a header. Extra columns are ignored and rows missing
+/// either field are skipped. The delimiter is sniffed like the mapping file.
+pub fn parse_csv_column_map(content: &str) -> Result<Vec<(String, String)>, String> {
+    let content = content.strip_prefix('\u{FEFF}').unwrap_or(content);
+    let delimiter = detect_delimiter(content);
+
+    let mut reader = ReaderBuilder::new()
+        .has_headers(true)
+        .flexible(true)
+        .delimiter(delimiter)
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+
+    let mut pairs = Vec::new();
+    for (line_num, result) in reader.records().enumerate() {
+        let record = result.map_err(|e| format!("Line {}: {}", line_num + 2, e))?;
+        let source = record.get(0).unwrap_or_default();
+        let target = record.get(1).unwrap_or_default();
+        if source.is_empty() || target.is_empty() {
+            log::warn!("Skipping incomplete row at line {}", line_num + 2);
+            continue;
+        }
+        pairs.push((source.to_string(), target.to_string()));
+    }
+
+    if pairs.is_empty() {
+        return Err("No source/target field pairs found in CSV. Check file format.".to_string());
+    }
+
+    Ok(pairs)
+}
+
+/// Decode raw CSV bytes to UTF-8
+///
+/// - UTF-8 BOM → UTF-8 (BOM stripped)
+/// - UTF-16LE/BE BOM → UTF-16
+/// - Valid UTF-8 without BOM → UTF-8
+/// - Anything else → Latin-1 (every byte maps to a char, so this never fails)
+pub fn decode_csv_bytes(bytes: &[u8]) -> Result<String, String> {
+    if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return String::from_utf8(rest.to_vec())
+            .map_err(|e| format!("Invalid UTF-8 after BOM: {}", e));
+    }
+
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        return decode_utf16(rest, u16::from_le_bytes);
+    }
+
+    if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        return decode_utf16(rest, u16::from_be_bytes);
+    }
+
+    match std::str::from_utf8(bytes) {
+        Ok(content) => Ok(content.to_string()),
+        Err(_) => {
+            log::info!("CSV is not valid UTF-8, decoding as Latin-1");
+            Ok(bytes.iter().map(|&b| b as char).collect())
+        }
+    }
+}
+
+fn decode_utf16(bytes: &[u8], to_u16: fn([u8; 2]) -> u16) -> Result<String, String> {
+    if bytes.len() % 2 != 0 {
+        return Err("Invalid UTF-16: odd number of bytes".to_string());
+    }
+
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| to_u16([pair[0], pair[1]]))
+        .collect();
+
+    String::from_utf16(&units).map_err(|e| format!("Invalid UTF-16: {}", e))
+}
+
+/// Detect the delimiter by sniffing the first few non-empty lines
+///
+/// Picks the candidate (comma, semicolon, tab) that appears the same non-zero
+/// number of times on every sampled line, preferring the highest count.
+/// Falls back to the most frequent candidate in the header, then to comma.
+fn detect_delimiter(content: &str) -> u8 {
+    const CANDIDATES: [u8; 3] = [b',', b';', b'\t'];
+
+    let lines: Vec<&str> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(5)
+        .collect();
+
+    let Some(header) = lines.first() else {
+        return b',';
+    };
+
+    let consistent = CANDIDATES
+        .iter()
+        .filter_map(|&delimiter| {
+            let header_count = count_unquoted(header, delimiter);
+            let all_match = lines
+                .iter()
+                .all(|line| count_unquoted(line, delimiter) == header_count);
+            (header_count > 0 && all_match).then_some((delimiter, header_count))
+        })
+        .max_by_key(|&(_, count)| count);
+
+    if let Some((delimiter, _)) = consistent {
+        return delimiter;
+    }
+
+    CANDIDATES
+        .iter()
+        .map(|&delimiter| (delimiter, count_unquoted(header, delimiter)))
+        .filter(|&(_, count)| count > 0)
+        .max_by_key(|&(_, count)| count)
+        .map(|(delimiter, _)| delimiter)
+        .unwrap_or(b',')
+}
+
+/// Count occurrences of a delimiter outside double-quoted sections
+fn count_unquoted(line: &str, delimiter: u8) -> usize {
+    let mut in_quotes = false;
+    let mut count = 0;
+
+    for byte in line.bytes() {
+        if byte == b'"' {
+            in_quotes = !in_quotes;
+        } else if byte == delimiter && !in_quotes {
+            count += 1;
+        }
+    }
+
+    count
+}
+
 /// Extract prefix from field name
 /// Returns the prefix including the separator (e.g., "cgk_accountid" -> "cgk_")
 fn extract_prefix(field: &str) -> Option<String> {
@@ -338,6 +493,62 @@ field1,field2,unknown_type,Some field
         );
     }
 
+    const BASELINE_CSV: &str = "source_field,target_field,match_type,notes
+createdby,createdby,exact,Exact match
+vaf_isan,nrq_isan,manual,\"Manual, with comma\"
+dev_field,,ignore,Développement
+";
+
+    fn assert_same_rows(result: &CsvImportData, baseline: &CsvImportData) {
+        assert_eq!(result.imported_mappings, baseline.imported_mappings);
+        assert_eq!(result.manual_mappings, baseline.manual_mappings);
+        assert_eq!(result.prefix_mappings, baseline.prefix_mappings);
+        assert_eq!(result.source_ignores, baseline.source_ignores);
+        assert_eq!(result.target_ignores, baseline.target_ignores);
+    }
+
+    #[test]
+    fn test_semicolon_delimiter_matches_comma_baseline() {
+        let baseline = parse_csv_field_mappings(BASELINE_CSV).unwrap();
+
+        let semicolon_csv = "source_field;target_field;match_type;notes
+createdby;createdby;exact;Exact match
+vaf_isan;nrq_isan;manual;\"Manual, with comma\"
+dev_field;;ignore;Développement
+";
+        assert_eq!(detect_delimiter(semicolon_csv), b';');
+
+        let result = parse_csv_field_mappings_bytes(semicolon_csv.as_bytes()).unwrap();
+        assert_same_rows(&result, &baseline);
+        assert_eq!(baseline.manual_mappings.len(), 1);
+    }
+
+    #[test]
+    fn test_utf16_bom_matches_utf8_baseline() {
+        let baseline = parse_csv_field_mappings(BASELINE_CSV).unwrap();
+
+        let mut utf16le = vec![0xFF, 0xFE];
+        utf16le.extend(
+            BASELINE_CSV
+                .encode_utf16()
+                .flat_map(|unit| unit.to_le_bytes()),
+        );
+        let result = parse_csv_field_mappings_bytes(&utf16le).unwrap();
+        assert_same_rows(&result, &baseline);
+
+        let mut utf8_bom = vec![0xEF, 0xBB, 0xBF];
+        utf8_bom.extend(BASELINE_CSV.as_bytes());
+        let result = parse_csv_field_mappings_bytes(&utf8_bom).unwrap();
+        assert_same_rows(&result, &baseline);
+    }
+
+    #[test]
+    fn test_latin1_fallback() {
+        // "Développement" in Latin-1 (é = 0xE9) is not valid UTF-8
+        let bytes = b"D\xE9veloppement";
+        assert_eq!(decode_csv_bytes(bytes).unwrap(), "Développement");
+    }
+
     #[test]
     fn test_whitespace_trimming() {
         let csv = r#"source_field,target_field,match_type,notes
@@ -1,7 +1,7 @@
 //! Core matching functions for Dynamics 365 entity comparison
 //! Phase 1: Excludes example-based matching for simplicity
 
-use super::models::{MatchInfo, MatchType};
+use super::models::{MatchInfo, MatchType, NameSimilarity};
 use crate::api::metadata::{EntityMetadata, FieldMetadata, FieldType, RelationshipMetadata};
 use std::collections::{HashMap, HashSet};
 
@@ -25,18 +25,190 @@ pub fn extract_entities(relationships: &[RelationshipMetadata]) -> Vec<(String,
     entities
 }
 
+/// Split a name into lowercase tokens on `_`, `-`, spaces and camelCase boundaries
+pub fn tokenize_name(name: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+
+    for c in name.chars() {
+        if matches!(c, '_' | '-' | ' ' | '.') {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower && !current.is_empty() {
+            tokens.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// Score how similar two names are, from 0.0 (unrelated) to 1.0 (same)
+pub fn name_similarity(a: &str, b: &str, strategy: NameSimilarity) -> f64 {
+    match strategy {
+        NameSimilarity::EditDistance => {
+            let a: Vec<char> = a.to_lowercase().chars().collect();
+            let b: Vec<char> = b.to_lowercase().chars().collect();
+            let longest = a.len().max(b.len());
+            if longest == 0 {
+                return 1.0;
+            }
+            1.0 - edit_distance(&a, &b) as f64 / longest as f64
+        }
+        NameSimilarity::TokenSet => {
+            let a: HashSet<String> = tokenize_name(a).into_iter().collect();
+            let b: HashSet<String> = tokenize_name(b).into_iter().collect();
+            if a.is_empty() && b.is_empty() {
+                return 1.0;
+            }
+            // Dice coefficient over the token sets
+            2.0 * a.intersection(&b).count() as f64 / (a.len() + b.len()) as f64
+        }
+    }
+}
+
+/// Levenshtein distance between two character sequences
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Minimum name similarity for an otherwise unmatched name to count as a match
+pub const SIMILARITY_THRESHOLD: f64 = 0.85;
+
+/// Pick the most similar candidate name, if any reaches `SIMILARITY_THRESHOLD`
+///
+/// Ties go to the alphabetically first candidate so results are stable.
+fn best_similar<'a>(
+    name: &str,
+    candidates: impl Iterator<Item = &'a str>,
+    strategy: NameSimilarity,
+) -> Option<(&'a str, f64)> {
+    candidates
+        .map(|candidate| (candidate, name_similarity(name, candidate, strategy)))
+        .filter(|(_, score)| *score >= SIMILARITY_THRESHOLD)
+        .max_by(|(a, sa), (b, sb)| sa.total_cmp(sb).then_with(|| b.cmp(a)))
+}
+
+/// Source side of a prefix rule
+///
+/// Plain keys are literal prefixes. Keys containing `*` are wildcards that must
+/// cover the whole name (`nrq_*` → `new_*`), each `*` in the target taking the
+/// text matched by the same `*` in the source. Keys starting with `re:` are
+/// regexes matched at the start of the name; the target may reference captures
+/// (`$1`) and the rest of the name is kept, as with a literal prefix.
+#[derive(Debug, Clone)]
+enum PrefixPattern {
+    Literal(String),
+    Wildcard(regex::Regex),
+    Regex(regex::Regex),
+}
+
+/// A compiled prefix rule with its target prefixes (1-to-N)
+#[derive(Debug, Clone)]
+struct PrefixRule {
+    pattern: PrefixPattern,
+    targets: Vec<String>,
+}
+
+impl PrefixPattern {
+    fn parse(source: &str) -> Result<Self, String> {
+        if let Some(pattern) = source.strip_prefix("re:") {
+            regex::Regex::new(&format!("^(?:{})", pattern))
+                .map(PrefixPattern::Regex)
+                .map_err(|e| format!("Invalid regex '{}': {}", pattern, e))
+        } else if source.contains('*') {
+            let parts: Vec<String> = source.split('*').map(regex::escape).collect();
+            regex::Regex::new(&format!("^{}$", parts.join("(.*?)")))
+                .map(PrefixPattern::Wildcard)
+                .map_err(|e| format!("Invalid wildcard '{}': {}", source, e))
+        } else {
+            Ok(PrefixPattern::Literal(source.to_string()))
+        }
+    }
+
+    /// Rewrite a name with the target prefix, or None if the rule doesn't apply
+    fn transform(&self, name: &str, target: &str) -> Option<String> {
+        match self {
+            PrefixPattern::Literal(prefix) => name
+                .strip_prefix(prefix.as_str())
+                .map(|suffix| format!("{}{}", target, suffix)),
+            PrefixPattern::Wildcard(re) => {
+                let caps = re.captures(name)?;
+                let mut captured = caps.iter().skip(1).map(|m| m.map_or("", |m| m.as_str()));
+                let mut parts = target.split('*');
+                let mut result = parts.next().unwrap_or_default().to_string();
+                for part in parts {
+                    result.push_str(captured.next().unwrap_or_default());
+                    result.push_str(part);
+                }
+                Some(result)
+            }
+            PrefixPattern::Regex(re) => {
+                let caps = re.captures(name)?;
+                let mut result = String::new();
+                caps.expand(target, &mut result);
+                result.push_str(&name[caps.get(0)?.end()..]);
+                Some(result)
+            }
+        }
+    }
+}
+
+/// Check that a source prefix is a valid literal, wildcard or `re:` pattern
+pub fn validate_prefix_rule(source_prefix: &str) -> Result<(), String> {
+    PrefixPattern::parse(source_prefix).map(|_| ())
+}
+
+/// Compile prefix mappings into rules, skipping invalid patterns
+fn compile_prefix_rules(prefix_mappings: &HashMap<String, Vec<String>>) -> Vec<PrefixRule> {
+    let mut sources: Vec<&String> = prefix_mappings.keys().collect();
+    sources.sort();
+
+    sources
+        .into_iter()
+        .filter_map(|source| match PrefixPattern::parse(source) {
+            Ok(pattern) => Some(PrefixRule {
+                pattern,
+                targets: prefix_mappings[source].clone(),
+            }),
+            Err(e) => {
+                log::warn!("Skipping prefix rule: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
 /// Apply prefix transformation to a name
 /// Returns list of transformed names (supports 1-to-N prefix mappings)
-fn apply_prefix_transform(
-    name: &str,
-    prefix_mappings: &HashMap<String, Vec<String>>,
-) -> Vec<String> {
+fn apply_prefix_transform(name: &str, prefix_rules: &[PrefixRule]) -> Vec<String> {
     let mut results = Vec::new();
-    for (source_prefix, target_prefixes) in prefix_mappings {
-        if let Some(suffix) = name.strip_prefix(source_prefix) {
-            // Generate transformed name for each target prefix (1-to-N support)
-            for target_prefix in target_prefixes {
-                results.push(format!("{}{}", target_prefix, suffix));
+    for rule in prefix_rules {
+        // Generate transformed name for each target prefix (1-to-N support)
+        for target_prefix in &rule.targets {
+            if let Some(transformed) = rule.pattern.transform(name, target_prefix) {
+                results.push(transformed);
             }
         }
     }
@@ -45,14 +217,16 @@ fn apply_prefix_transform(
 
 /// Compute entity matches between source and target
 /// Returns map of source_entity_name -> MatchInfo
-/// Priority: Manual → Exact → Prefix
+/// Priority: Manual → Exact → Prefix → Similar
 pub fn compute_entity_matches(
     source_entities: &[(String, usize)],
     target_entities: &[(String, usize)],
     manual_mappings: &HashMap<String, Vec<String>>,
     prefix_mappings: &HashMap<String, Vec<String>>,
+    similarity: NameSimilarity,
 ) -> HashMap<String, MatchInfo> {
     let mut matches = HashMap::new();
+    let prefix_rules = compile_prefix_rules(prefix_mappings);
 
     // Build target entity lookup
     let target_lookup: HashMap<String, ()> = target_entities
@@ -97,7 +271,7 @@ pub fn compute_entity_matches(
         }
 
         // 3. Check prefix-transformed matches (1-to-N support)
-        let transformed_names = apply_prefix_transform(source_name, prefix_mappings);
+        let transformed_names = apply_prefix_transform(source_name, &prefix_rules);
         let valid_transformed: Vec<String> = transformed_names
             .iter()
             .filter(|tn| target_lookup.contains_key(*tn))
@@ -120,7 +294,17 @@ pub fn compute_entity_matches(
             continue;
         }
 
-        // No match found - don't insert anything
+        // 4. Check name similarity, scored below prefix matches
+        if let Some((target, score)) = best_similar(
+            source_name,
+            target_entities.iter().map(|(name, _count)| name.as_str()),
+            similarity,
+        ) {
+            matches.insert(
+                source_name.clone(),
+                MatchInfo::single(target.to_string(), MatchType::Similar, 0.8 * score),
+            );
+        }
     }
 
     matches
@@ -128,7 +312,10 @@ pub fn compute_entity_matches(
 
 /// Compute field matches between source and target
 /// Returns map of source_field_name -> MatchInfo
-/// Priority: Manual → Import → Exact → Prefix
+/// Priority: Manual → Import → Exact → Prefix → Similar
+///
+/// Similar matches only pair fields left over once every other rule has run,
+/// so they never take a target another rule would claim.
 pub fn compute_field_matches(
     source_fields: &[FieldMetadata],
     target_fields: &[FieldMetadata],
@@ -136,8 +323,10 @@ pub fn compute_field_matches(
     imported_mappings: &HashMap<String, Vec<String>>,
     prefix_mappings: &HashMap<String, Vec<String>>,
     negative_matches: &HashSet<String>,
+    similarity: NameSimilarity,
 ) -> HashMap<String, MatchInfo> {
     let mut matches = HashMap::new();
+    let prefix_rules = compile_prefix_rules(prefix_mappings);
 
     // Build target field lookup
     let target_lookup: HashMap<String, &FieldMetadata> = target_fields
@@ -235,7 +424,7 @@ pub fn compute_field_matches(
         // 4. Check prefix-transformed matches (1-to-N support)
         // Skip if this field is in negative_matches (blocks prefix matching)
         if !negative_matches.contains(source_name) {
-            let transformed_names = apply_prefix_transform(source_name, prefix_mappings);
+            let transformed_names = apply_prefix_transform(source_name, &prefix_rules);
             let mut valid_transformed = Vec::new();
             for transformed in transformed_names {
                 if let Some(target_field) = target_lookup.get(&transformed) {
@@ -274,6 +463,42 @@ pub fn compute_field_matches(
         // No match found - don't insert anything
     }
 
+    // 5. Pair remaining fields by name similarity (negative matches block this too)
+    for source_field in source_fields {
+        let source_name = &source_field.logical_name;
+        if matches.contains_key(source_name) || negative_matches.contains(source_name) {
+            continue;
+        }
+
+        let candidates = target_fields
+            .iter()
+            .map(|f| f.logical_name.as_str())
+            .filter(|name| !already_matched.contains(*name));
+        if let Some((target, score)) = best_similar(source_name, candidates, similarity) {
+            let types_match = target_lookup
+                .get(target)
+                .is_some_and(|t| t.field_type == source_field.field_type);
+            let target = target.to_string();
+            already_matched.insert(target.clone());
+            matches.insert(
+                source_name.clone(),
+                MatchInfo::single(
+                    target,
+                    if types_match {
+                        MatchType::Similar
+                    } else {
+                        MatchType::TypeMismatch(Box::new(MatchType::Similar))
+                    },
+                    if types_match {
+                        0.8 * score
+                    } else {
+                        0.5 * score
+                    },
+                ),
+            );
+        }
+    }
+
     matches
 }
 
@@ -303,6 +528,7 @@ pub fn compute_relationship_matches(
     entity_matches: &HashMap<String, MatchInfo>,
 ) -> HashMap<String, MatchInfo> {
     let mut matches = HashMap::new();
+    let prefix_rules = compile_prefix_rules(prefix_mappings);
 
     // Build target relationship lookup
     let target_lookup: HashMap<String, &RelationshipMetadata> = target_relationships
@@ -363,7 +589,7 @@ pub fn compute_relationship_matches(
         }
 
         // 3. Check prefix-transformed matches (1-to-N support)
-        let transformed_names = apply_prefix_transform(source_name, prefix_mappings);
+        let transformed_names = apply_prefix_transform(source_name, &prefix_rules);
         let mut valid_transformed = Vec::new();
         for transformed in transformed_names {
             if let Some(target_rel) = target_lookup.get(&transformed) {
@@ -406,3 +632,134 @@ pub fn compute_relationship_matches(
 
     matches
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wildcard_prefix_rule_matches_many_fields() {
+        let source = [
+            FieldMetadata::string("nrq_name"),
+            FieldMetadata::string("nrq_code"),
+            FieldMetadata::string("nrq_x_status_c"),
+            FieldMetadata::string("other_name"),
+        ];
+        let target = [
+            FieldMetadata::string("new_name"),
+            FieldMetadata::string("new_code"),
+            FieldMetadata::string("new_status"),
+            FieldMetadata::string("other_title"),
+        ];
+
+        let mut prefixes = HashMap::new();
+        prefixes.insert("nrq_*".to_string(), vec!["new_*".to_string()]);
+        prefixes.insert("re:nrq_x_(\\w+)_c$".to_string(), vec!["new_$1".to_string()]);
+
+        let matches = compute_field_matches(
+            &source,
+            &target,
+            &HashMap::new(),
+            &HashMap::new(),
+            &prefixes,
+            &HashSet::new(),
+            NameSimilarity::EditDistance,
+        );
+
+        assert_eq!(matches["nrq_name"].target_fields, vec!["new_name"]);
+        assert_eq!(matches["nrq_code"].target_fields, vec!["new_code"]);
+        assert_eq!(matches["nrq_x_status_c"].target_fields, vec!["new_status"]);
+        assert!(matches!(
+            matches["nrq_name"].match_types["new_name"],
+            MatchType::Prefix
+        ));
+        assert!(!matches.contains_key("other_name"));
+
+        let entities = compute_entity_matches(
+            &[("nrq_project".to_string(), 1)],
+            &[("new_project".to_string(), 1)],
+            &HashMap::new(),
+            &prefixes,
+            NameSimilarity::EditDistance,
+        );
+        assert_eq!(entities["nrq_project"].target_fields, vec!["new_project"]);
+
+        assert!(validate_prefix_rule("re:nrq_(").is_err());
+    }
+
+    #[test]
+    fn test_token_set_ignores_word_order() {
+        assert_eq!(
+            tokenize_name("firstNameLast"),
+            vec!["first", "name", "last"]
+        );
+
+        let token = name_similarity(
+            "first_name_last",
+            "last_first_name",
+            NameSimilarity::TokenSet,
+        );
+        let edit = name_similarity(
+            "first_name_last",
+            "last_first_name",
+            NameSimilarity::EditDistance,
+        );
+        assert_eq!(token, 1.0);
+        assert!(edit < 0.5, "edit distance scored {}", edit);
+
+        let source = [
+            FieldMetadata::string("first_name_last"),
+            FieldMetadata::string("cgk_budget"),
+            FieldMetadata::string("name"),
+        ];
+        let target = [
+            FieldMetadata::string("last_first_name"),
+            FieldMetadata::string("nrq_amount"),
+            FieldMetadata::string("name"),
+        ];
+        let matches = compute_field_matches(
+            &source,
+            &target,
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashSet::new(),
+            NameSimilarity::TokenSet,
+        );
+        assert_eq!(
+            matches["first_name_last"].target_fields,
+            vec!["last_first_name"]
+        );
+        assert_eq!(
+            matches["first_name_last"].match_types["last_first_name"],
+            MatchType::Similar
+        );
+        assert!(matches["first_name_last"].confidences["last_first_name"] < 0.9);
+        assert_eq!(matches["name"].match_types["name"], MatchType::Exact);
+        assert!(!matches.contains_key("cgk_budget"));
+
+        // Edit distance doesn't see reordered words as similar
+        let matches = compute_field_matches(
+            &source,
+            &target,
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashSet::new(),
+            NameSimilarity::EditDistance,
+        );
+        assert!(!matches.contains_key("first_name_last"));
+
+        let entities = compute_entity_matches(
+            &[("cgk_project_task".to_string(), 1)],
+            &[("cgk_task_project".to_string(), 1)],
+            &HashMap::new(),
+            &HashMap::new(),
+            NameSimilarity::TokenSet,
+        );
+        assert_eq!(
+            entities["cgk_project_task"].match_types["cgk_task_project"],
+            MatchType::Similar
+        );
+    }
+}
@@ -5,9 +5,10 @@
 
 pub mod core;
 pub mod models;
+pub mod transfer_seed;
 
 // Re-export commonly used types
-pub use models::{MatchInfo, MatchType};
+pub use models::{MatchInfo, MatchType, NameSimilarity};
 
 use crate::api::metadata::EntityMetadata;
 use std::collections::{HashMap, HashSet};
@@ -28,6 +29,8 @@ pub struct MatchingMappings {
     pub prefix_mappings: HashMap<String, Vec<String>>,
     pub imported_mappings: HashMap<String, Vec<String>>,
     pub negative_matches: HashSet<String>,
+    /// How leftover names are scored for similarity matches
+    pub name_similarity: NameSimilarity,
 }
 
 /// Complete matching results
@@ -54,6 +57,7 @@ pub fn compute_all_matches(
         &mappings.imported_mappings,
         &mappings.prefix_mappings,
         &mappings.negative_matches,
+        mappings.name_similarity,
     );
 
     // Extract entities from relationships
@@ -66,6 +70,7 @@ pub fn compute_all_matches(
         &target_entities,
         &mappings.field_mappings,
         &mappings.prefix_mappings,
+        mappings.name_similarity,
     );
 
     // Compute relationship matches (entity-aware)
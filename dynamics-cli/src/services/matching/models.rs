@@ -9,6 +9,7 @@ pub enum MatchType {
     Manual,                       // User-created mapping (overrides type checking)
     ExampleValue,                 // Value-based match from example data
     Import,                       // Imported from C# mapping file
+    Similar,                      // Name similarity above threshold (migration.name_similarity)
 }
 
 impl MatchType {
@@ -26,6 +27,27 @@ impl MatchType {
             MatchType::Manual => "[Manual]".to_string(),
             MatchType::ExampleValue => "[Example]".to_string(),
             MatchType::Import => "[Import]".to_string(),
+            MatchType::Similar => "[Similar]".to_string(),
+        }
+    }
+}
+
+/// How two names are compared when matching otherwise unmatched names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameSimilarity {
+    /// Character edit distance over the whole name
+    #[default]
+    EditDistance,
+    /// Overlap of `_`/camelCase tokens, ignoring their order
+    TokenSet,
+}
+
+impl NameSimilarity {
+    /// Parse the `migration.name_similarity` option value
+    pub fn from_option(value: &str) -> Self {
+        match value {
+            "token_set" => NameSimilarity::TokenSet,
+            _ => NameSimilarity::EditDistance,
         }
     }
 }
@@ -0,0 +1,138 @@
+//! Seed transfer configs from comparison matches
+//!
+//! Promotes confident field matches into Copy field mappings so the output of
+//! an entity comparison can be used as the starting point for a transfer.
+
+use super::models::MatchInfo;
+use crate::transfer::{EntityMapping, FieldMapping, TransferConfig};
+use std::collections::HashMap;
+
+/// Strip an `entity.` qualifier, rejecting names qualified with another entity
+///
+/// Multi-entity comparisons key matches as "entity.field"; single-entity ones
+/// use plain field names, which are accepted as-is.
+fn unqualify<'a>(name: &'a str, entity: &str) -> Option<&'a str> {
+    match name.split_once('.') {
+        Some((qualifier, field)) => (qualifier == entity).then_some(field),
+        None => Some(name),
+    }
+}
+
+/// Add a Copy mapping for every match at or above `min_confidence`
+///
+/// Targets that already have a field mapping are left untouched. Returns the
+/// number of field mappings added.
+pub fn seed_field_mappings(
+    entity: &mut EntityMapping,
+    field_matches: &HashMap<String, MatchInfo>,
+    min_confidence: f64,
+) -> usize {
+    let mut sources: Vec<&String> = field_matches.keys().collect();
+    sources.sort();
+
+    let mut added = 0;
+    for source_key in sources {
+        let Some(source_field) = unqualify(source_key, &entity.source_entity) else {
+            continue;
+        };
+        let info = &field_matches[source_key];
+
+        for target_key in &info.target_fields {
+            let confidence = info.confidences.get(target_key).copied().unwrap_or(0.0);
+            if confidence < min_confidence {
+                continue;
+            }
+            let Some(target_field) = unqualify(target_key, &entity.target_entity) else {
+                continue;
+            };
+            if entity.find_field_mapping(target_field).is_some() {
+                continue;
+            }
+
+            entity.add_field_mapping(FieldMapping::copy_from(target_field, source_field));
+            added += 1;
+        }
+    }
+
+    added
+}
+
+/// Seed the entity mapping for a source/target pair in a transfer config
+///
+/// An existing mapping for the pair is extended; otherwise a new one is added
+/// after the existing entities, but only if at least one match qualified.
+/// Returns the number of field mappings added.
+pub fn seed_transfer_config(
+    config: &mut TransferConfig,
+    source_entity: &str,
+    target_entity: &str,
+    field_matches: &HashMap<String, MatchInfo>,
+    min_confidence: f64,
+) -> usize {
+    if let Some(entity) = config
+        .entity_mappings
+        .iter_mut()
+        .find(|m| m.source_entity == source_entity && m.target_entity == target_entity)
+    {
+        return seed_field_mappings(entity, field_matches, min_confidence);
+    }
+
+    let priority = config
+        .entity_mappings
+        .iter()
+        .map(|m| m.priority + 1)
+        .max()
+        .unwrap_or(1);
+    let mut entity = EntityMapping::new(source_entity, target_entity, priority);
+    let added = seed_field_mappings(&mut entity, field_matches, min_confidence);
+    if added > 0 {
+        config.add_entity_mapping(entity);
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::matching::MatchType;
+
+    #[test]
+    fn test_only_confident_matches_become_copy_mappings() {
+        let mut matches = HashMap::new();
+        matches.insert(
+            "cgk_name".to_string(),
+            MatchInfo::single("nrq_name".to_string(), MatchType::Prefix, 0.9),
+        );
+        matches.insert(
+            "cgk_code".to_string(),
+            MatchInfo::single("cgk_code".to_string(), MatchType::Exact, 1.0),
+        );
+        matches.insert(
+            "cgk_budget".to_string(),
+            MatchInfo::single(
+                "nrq_budget".to_string(),
+                MatchType::TypeMismatch(Box::new(MatchType::Prefix)),
+                0.6,
+            ),
+        );
+
+        let mut config = TransferConfig::new("seeded", "dev", "prod");
+        let added = seed_transfer_config(&mut config, "cgk_project", "nrq_project", &matches, 0.9);
+
+        assert_eq!(added, 2);
+        assert_eq!(config.entity_mappings.len(), 1);
+        assert_eq!(
+            config.entity_mappings[0].field_mappings,
+            vec![
+                FieldMapping::copy_from("cgk_code", "cgk_code"),
+                FieldMapping::copy_from("nrq_name", "cgk_name"),
+            ]
+        );
+
+        // Seeding again adds nothing new
+        assert_eq!(
+            seed_transfer_config(&mut config, "cgk_project", "nrq_project", &matches, 0.9),
+            0
+        );
+    }
+}
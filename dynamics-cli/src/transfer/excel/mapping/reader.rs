@@ -6,8 +6,8 @@ use anyhow::{Context, Result, bail};
 use calamine::{Data, Reader, Xlsx, open_workbook};
 
 use crate::transfer::{
-    Condition, EntityMapping, Fallback, FieldMapping, FieldPath, Replacement, TransferConfig,
-    Transform, Value,
+    AggregateFunction, Condition, EntityMapping, Fallback, FieldMapping, FieldPath, Replacement,
+    TransferConfig, Transform, Value,
 };
 
 use super::values::{parse_condition, parse_fallback, parse_value};
@@ -246,6 +246,31 @@ pub fn read_mapping_excel(
                 continue; // Don't add field yet, wait for entries
             }
 
+            "aggregate" => {
+                let source_field = get_cell_string(row, cols::SOURCE_FIELD);
+                let function_str = get_cell_string(row, cols::CONDITION);
+                let separator = get_cell_string(row, cols::FALLBACK);
+
+                if source_field.is_empty() {
+                    bail!("Row {}: aggregate transform requires source_field", row_num);
+                }
+
+                let function = AggregateFunction::parse(&function_str).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Row {}: unknown aggregate function '{}' (expected sum, count, concat, min or max)",
+                        row_num,
+                        function_str
+                    )
+                })?;
+
+                Transform::Aggregate {
+                    source_path: FieldPath::parse(&source_field)
+                        .with_context(|| format!("Row {}: invalid source_field path", row_num))?,
+                    function,
+                    separator: (!separator.is_empty()).then_some(separator),
+                }
+            }
+
             other => {
                 bail!("Row {}: unknown transform_type '{}'", row_num, other);
             }
@@ -157,6 +157,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::approx_constant)] // 3.14 is a sample cell value, not PI
     fn test_parse_value_primitives() {
         assert_eq!(parse_value(""), Value::Null);
         assert_eq!(parse_value("true"), Value::Bool(true));
@@ -188,6 +188,22 @@ fn write_field_mapping(
 
             Ok(row)
         }
+
+        Transform::Aggregate {
+            source_path,
+            function,
+            separator,
+        } => {
+            write_common_cols(ws, start_row, entity, &field.target_field)?;
+            ws.write_string(start_row, cols::TRANSFORM_TYPE, "aggregate")?;
+            ws.write_string(start_row, cols::SOURCE_FIELD, &source_path.to_string())?;
+            // Function goes in the condition column, concat separator in fallback
+            ws.write_string(start_row, cols::CONDITION, &function.to_string())?;
+            if let Some(separator) = separator {
+                ws.write_string(start_row, cols::FALLBACK, separator)?;
+            }
+            Ok(start_row + 1)
+        }
     }
 }
 
@@ -6,4 +6,4 @@ pub mod resolved;
 
 pub use mapping::{read_mapping_excel, write_mapping_excel};
 pub use operations::{OperationType, ParsedOperations, SheetOperations, read_operations_excel};
-pub use resolved::{read_resolved_excel, write_resolved_excel};
+pub use resolved::{read_resolved_excel, write_errors_excel, write_resolved_excel};
@@ -254,6 +254,7 @@ pub fn read_operations_excel<P: AsRef<Path>>(path: P) -> Result<ParsedOperations
                         entity: entity.clone(),
                         id,
                         data: Value::Object(data),
+                        if_match: None,
                     }
                 }
                 OperationType::Delete => {
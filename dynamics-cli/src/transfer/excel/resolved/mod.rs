@@ -4,4 +4,4 @@ mod reader;
 mod writer;
 
 pub use reader::read_resolved_excel;
-pub use writer::write_resolved_excel;
+pub use writer::{write_errors_excel, write_resolved_excel};
@@ -67,6 +67,46 @@ pub fn write_resolved_excel(entity: &ResolvedEntity, path: &str) -> Result<()> {
     Ok(())
 }
 
+/// Write only the error records of a ResolvedEntity to an Excel file
+///
+/// Columns are `_source_id`, `_error`, then the entity's fields so the sheet can be
+/// handed to data owners for triage. Returns the number of records written.
+pub fn write_errors_excel(entity: &ResolvedEntity, path: &str) -> Result<usize> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_name(&entity.entity_name)?;
+
+    // Header: special columns first, then field columns
+    worksheet.write_string(0, 0, special_cols::SOURCE_ID)?;
+    worksheet.write_string(0, 1, special_cols::ERROR)?;
+    for (col_idx, field_name) in entity.field_names.iter().enumerate() {
+        worksheet.write_string(0, (col_idx + 2) as u16, field_name)?;
+    }
+
+    let errors = entity.records.iter().filter(|r| r.is_error());
+    let mut count = 0;
+    for (row_idx, record) in errors.enumerate() {
+        let row = (row_idx + 1) as u32;
+
+        worksheet.write_string(row, 0, &record.source_id.to_string())?;
+        worksheet.write_string(row, 1, record.error.as_deref().unwrap_or_default())?;
+
+        for (col_idx, field_name) in entity.field_names.iter().enumerate() {
+            if let Some(value) = record.fields.get(field_name) {
+                write_value(worksheet, row, (col_idx + 2) as u16, value)?;
+            }
+        }
+        count += 1;
+    }
+
+    workbook
+        .save(path)
+        .with_context(|| format!("Failed to save Excel file: {}", path))?;
+
+    Ok(count)
+}
+
 fn format_action(action: &RecordAction) -> &'static str {
     match action {
         RecordAction::Create => "create",
@@ -110,3 +150,61 @@ fn write_value(ws: &mut Worksheet, row: u32, col: u16, value: &Value) -> Result<
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transfer::ResolvedRecord;
+    use calamine::{Data, Reader, Xlsx, open_workbook};
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_write_errors_excel_only_writes_error_records() {
+        let error_id = Uuid::new_v4();
+        let mut entity = ResolvedEntity::new("account", 1, "accountid");
+        entity.set_field_names(vec!["name".to_string()]);
+        entity.add_record(ResolvedRecord::create(
+            Uuid::new_v4(),
+            HashMap::from([("name".to_string(), Value::String("Fine".to_string()))]),
+        ));
+        entity.add_record(ResolvedRecord::error_with_fields(
+            error_id,
+            HashMap::from([("name".to_string(), Value::String("Broken".to_string()))]),
+            "Resolver 'owner': no match found",
+        ));
+        entity.add_record(ResolvedRecord::skip(Uuid::new_v4(), HashMap::new()));
+
+        let path = std::env::temp_dir().join(format!("dynamics-errors-{}.xlsx", Uuid::new_v4()));
+        let path_str = path.to_string_lossy().to_string();
+
+        let written = write_errors_excel(&entity, &path_str).unwrap();
+        assert_eq!(written, 1);
+
+        let mut workbook: Xlsx<_> = open_workbook(&path_str).unwrap();
+        let range = workbook.worksheet_range("account").unwrap();
+        let rows: Vec<Vec<String>> = range
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| match cell {
+                        Data::String(s) => s.clone(),
+                        _ => String::new(),
+                    })
+                    .collect()
+            })
+            .collect();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], vec!["_source_id", "_error", "name"]);
+        assert_eq!(
+            rows[1],
+            vec![
+                error_id.to_string(),
+                "Resolver 'owner': no match found".to_string(),
+                "Broken".to_string()
+            ]
+        );
+    }
+}
@@ -4,13 +4,17 @@
 //! and cancellation support.
 
 use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::mpsc;
 
 use super::runtime::LuaRuntime;
-use super::stdlib::{LogMessage, StatusUpdate};
-use super::types::{Declaration, LuaOperation};
+use super::stdlib::{LogLevel, LogMessage, StatusUpdate, TransformInfo};
+use super::types::{Declaration, LuaOperation, OperationType};
+use super::validate::ValidationError;
+use crate::api::EntityMetadata;
 
 /// Status update sent during transform execution
 #[derive(Debug, Clone)]
@@ -18,7 +22,11 @@ pub enum ExecutionUpdate {
     /// Status message from lib.status()
     Status(String),
     /// Progress update from lib.progress()
-    Progress { current: usize, total: usize },
+    Progress {
+        current: usize,
+        total: usize,
+        label: Option<String>,
+    },
     /// Log message from lib.log()
     Log(String),
     /// Warning from lib.warn()
@@ -37,6 +45,12 @@ pub struct ExecutionContext {
     pub update_tx: mpsc::Sender<ExecutionUpdate>,
     /// Flag to signal cancellation
     pub cancel_flag: Arc<AtomicBool>,
+    /// Run details exposed to the script through `lib.context()`
+    pub info: TransformInfo,
+    /// Script log messages below this level are dropped
+    pub min_log_level: LogLevel,
+    /// Directory `require` loads shared modules from (None disables it)
+    pub module_dir: Option<PathBuf>,
 }
 
 impl ExecutionContext {
@@ -45,9 +59,30 @@ impl ExecutionContext {
         ExecutionContext {
             update_tx,
             cancel_flag,
+            info: TransformInfo::default(),
+            min_log_level: LogLevel::default(),
+            module_dir: None,
         }
     }
 
+    /// Set the config and environment names the script can read via `lib.context()`
+    pub fn with_info(mut self, info: TransformInfo) -> Self {
+        self.info = info;
+        self
+    }
+
+    /// Drop script log messages below `level`
+    pub fn with_min_log_level(mut self, level: LogLevel) -> Self {
+        self.min_log_level = level;
+        self
+    }
+
+    /// Let the script `require` shared modules from `dir`
+    pub fn with_module_dir(mut self, dir: Option<PathBuf>) -> Self {
+        self.module_dir = dir;
+        self
+    }
+
     /// Check if cancellation has been requested
     pub fn is_cancelled(&self) -> bool {
         self.cancel_flag.load(Ordering::Relaxed)
@@ -71,6 +106,57 @@ pub struct ExecutionResult {
     pub was_cancelled: bool,
 }
 
+/// Summary of a dry run: what a transform would emit, without queueing anything
+#[derive(Debug, Clone, Default)]
+pub struct DryRunSummary {
+    /// Operation counts per type, in `OperationType::ALL` order (zero counts omitted)
+    pub counts: Vec<(OperationType, usize)>,
+    /// Total number of operations emitted
+    pub total: usize,
+    /// Errors from `validate_operations`
+    pub validation_errors: Vec<ValidationError>,
+    /// Log messages captured during execution
+    pub logs: Vec<LogMessage>,
+}
+
+impl DryRunSummary {
+    /// Build a summary from the operations a transform returned, checking them
+    /// against `metadata` when provided (see `validate_operations`)
+    pub fn from_operations(
+        operations: &[LuaOperation],
+        logs: Vec<LogMessage>,
+        metadata: Option<&HashMap<String, EntityMetadata>>,
+    ) -> Self {
+        let counts = OperationType::ALL
+            .iter()
+            .map(|op_type| {
+                let count = operations
+                    .iter()
+                    .filter(|op| op.operation == *op_type)
+                    .count();
+                (*op_type, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+
+        DryRunSummary {
+            counts,
+            total: operations.len(),
+            validation_errors: validate_operations(operations, metadata),
+            logs,
+        }
+    }
+
+    /// Number of operations of the given type
+    pub fn count(&self, op_type: OperationType) -> usize {
+        self.counts
+            .iter()
+            .find(|(t, _)| *t == op_type)
+            .map(|(_, count)| *count)
+            .unwrap_or(0)
+    }
+}
+
 /// Execute a Lua transform script
 ///
 /// This function runs the transform in a blocking manner (Lua is not async).
@@ -81,7 +167,16 @@ pub fn execute_transform(
     target_data: &serde_json::Value,
 ) -> Result<ExecutionResult> {
     let runtime = LuaRuntime::new().context("Failed to create Lua runtime")?;
+    run_in_runtime(&runtime, script, source_data, target_data)
+}
 
+/// Load and run a transform script in an existing runtime
+fn run_in_runtime(
+    runtime: &LuaRuntime,
+    script: &str,
+    source_data: &serde_json::Value,
+    target_data: &serde_json::Value,
+) -> Result<ExecutionResult> {
     let module = runtime
         .load_script(script)
         .context("Failed to load script")?;
@@ -120,9 +215,21 @@ pub async fn execute_transform_async(
     // Run in a blocking task since Lua is not async
     let cancel_flag = ctx.cancel_flag.clone();
     let update_tx = ctx.update_tx.clone();
+    let info = ctx.info.clone();
+    let min_log_level = ctx.min_log_level;
+    let module_dir = ctx.module_dir.clone();
 
     let result = tokio::task::spawn_blocking(move || {
-        execute_transform_with_updates(&script, &source_data, &target_data, cancel_flag, update_tx)
+        execute_transform_with_updates(
+            &script,
+            &source_data,
+            &target_data,
+            info,
+            min_log_level,
+            module_dir.as_deref(),
+            cancel_flag,
+            update_tx,
+        )
     })
     .await
     .context("Transform task panicked")?;
@@ -144,25 +251,28 @@ pub async fn execute_transform_async(
 /// Format a progress message combining status and progress info
 ///
 /// Output format: "{status} - {current}/{total} ({pct:.1}%)"
-fn format_progress_message(status: &Option<String>, progress: &Option<(usize, usize)>) -> String {
+fn format_progress_message(
+    status: &Option<String>,
+    progress: &Option<(usize, usize, Option<String>)>,
+) -> String {
+    let format_progress = |current: usize, total: usize, label: &Option<String>| {
+        let pct = if total > 0 {
+            (current as f64 / total as f64) * 100.0
+        } else {
+            0.0
+        };
+        match label {
+            Some(label) => format!("{} {}/{} ({:.1}%)", label, current, total, pct),
+            None => format!("{}/{} ({:.1}%)", current, total, pct),
+        }
+    };
+
     match (status, progress) {
-        (Some(s), Some((current, total))) => {
-            let pct = if *total > 0 {
-                (*current as f64 / *total as f64) * 100.0
-            } else {
-                0.0
-            };
-            format!("{} - {}/{} ({:.1}%)", s, current, total, pct)
+        (Some(s), Some((current, total, label))) => {
+            format!("{} - {}", s, format_progress(*current, *total, label))
         }
         (Some(s), None) => s.clone(),
-        (None, Some((current, total))) => {
-            let pct = if *total > 0 {
-                (*current as f64 / *total as f64) * 100.0
-            } else {
-                0.0
-            };
-            format!("{}/{} ({:.1}%)", current, total, pct)
-        }
+        (None, Some((current, total, label))) => format_progress(*current, *total, label),
         (None, None) => String::new(),
     }
 }
@@ -172,6 +282,9 @@ fn execute_transform_with_updates(
     script: &str,
     source_data: &serde_json::Value,
     target_data: &serde_json::Value,
+    info: TransformInfo,
+    min_log_level: LogLevel,
+    module_dir: Option<&Path>,
     cancel_flag: Arc<AtomicBool>,
     update_tx: mpsc::Sender<ExecutionUpdate>,
 ) -> Result<ExecutionResult> {
@@ -184,7 +297,11 @@ fn execute_transform_with_updates(
         });
     }
 
-    let runtime = LuaRuntime::new().context("Failed to create Lua runtime")?;
+    let runtime =
+        LuaRuntime::with_module_dir(module_dir).context("Failed to create Lua runtime")?;
+    runtime.set_transform_info(info);
+    runtime.set_min_log_level(min_log_level);
+    runtime.set_cancel_flag(cancel_flag.clone());
 
     // Set up real-time status channel
     // Using std::sync::mpsc because Lua runs synchronously
@@ -209,7 +326,7 @@ fn execute_transform_with_updates(
     let update_tx_clone = update_tx.clone();
     let forward_handle = std::thread::spawn(move || {
         let mut last_status: Option<String> = None;
-        let mut last_progress: Option<(usize, usize)> = None;
+        let mut last_progress: Option<(usize, usize, Option<String>)> = None;
 
         // Block on receiving status updates until channel closes
         while let Ok(update) = status_rx.recv() {
@@ -217,8 +334,17 @@ fn execute_transform_with_updates(
                 StatusUpdate::Status(msg) => {
                     last_status = Some(msg);
                 }
-                StatusUpdate::Progress { current, total } => {
-                    last_progress = Some((current, total));
+                StatusUpdate::Progress {
+                    current,
+                    total,
+                    label,
+                } => {
+                    let _ = update_tx_clone.try_send(ExecutionUpdate::Progress {
+                        current,
+                        total,
+                        label: label.clone(),
+                    });
+                    last_progress = Some((current, total, label));
                 }
             }
 
@@ -270,14 +396,18 @@ pub fn execute_transform_sync(
     script: &str,
     source_data: &serde_json::Value,
     target_data: &serde_json::Value,
+    module_dir: Option<&Path>,
 ) -> Result<Vec<LuaOperation>> {
-    let result = execute_transform(script, source_data, target_data)?;
+    let runtime =
+        LuaRuntime::with_module_dir(module_dir).context("Failed to create Lua runtime")?;
+    let result = run_in_runtime(&runtime, script, source_data, target_data)?;
     Ok(result.operations)
 }
 
 /// Run only the declare phase of a script
-pub fn run_declare(script: &str) -> Result<Declaration> {
-    let runtime = LuaRuntime::new().context("Failed to create Lua runtime")?;
+pub fn run_declare(script: &str, module_dir: Option<&Path>) -> Result<Declaration> {
+    let runtime =
+        LuaRuntime::with_module_dir(module_dir).context("Failed to create Lua runtime")?;
 
     let module = runtime
         .load_script(script)
@@ -289,18 +419,71 @@ pub fn run_declare(script: &str) -> Result<Declaration> {
 }
 
 /// Validate operations returned by a transform
-pub fn validate_operations(operations: &[LuaOperation]) -> Vec<String> {
+///
+/// Always checks structural validity. When `metadata` (target entity logical
+/// name -> metadata) is provided, operations must also target an entity in the
+/// map and only set fields that exist on it. Skip and error operations are
+/// audit-only and are not checked against metadata.
+pub fn validate_operations(
+    operations: &[LuaOperation],
+    metadata: Option<&HashMap<String, EntityMetadata>>,
+) -> Vec<ValidationError> {
     let mut errors = Vec::new();
 
     for (i, op) in operations.iter().enumerate() {
         if let Err(e) = op.validate() {
-            errors.push(format!("Operation {}: {}", i + 1, e));
+            errors.push(ValidationError::new(format!("Operation {}: {}", i + 1, e)));
+        }
+
+        let Some(metadata) = metadata else {
+            continue;
+        };
+        if matches!(op.operation, OperationType::Skip | OperationType::Error) {
+            continue;
+        }
+
+        let Some(entity_metadata) = metadata.get(&op.entity) else {
+            errors.push(ValidationError::new(format!(
+                "Operation {}: unknown entity '{}'",
+                i + 1,
+                op.entity
+            )));
+            continue;
+        };
+
+        let mut unknown_fields: Vec<&String> = op
+            .fields
+            .keys()
+            .filter(|field| !entity_has_field(entity_metadata, field))
+            .collect();
+        unknown_fields.sort();
+        for field in unknown_fields {
+            errors.push(ValidationError::new(format!(
+                "Operation {}: unknown field '{}' on entity '{}'",
+                i + 1,
+                field,
+                op.entity
+            )));
         }
     }
 
     errors
 }
 
+/// Whether `field` (optionally with an `@odata.bind` style suffix) exists on the entity
+fn entity_has_field(metadata: &EntityMetadata, field: &str) -> bool {
+    let name = field.split('@').next().unwrap_or(field);
+    metadata.fields.iter().any(|f| {
+        f.logical_name.eq_ignore_ascii_case(name)
+            || f.navigation_property_name
+                .as_deref()
+                .is_some_and(|nav| nav.eq_ignore_ascii_case(name))
+            || f.schema_name
+                .as_deref()
+                .is_some_and(|schema| schema.eq_ignore_ascii_case(name))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -381,6 +564,59 @@ mod tests {
         assert!(matches!(&result.logs[1], LogMessage::Warn(s) if s == "This is a warning"));
     }
 
+    #[test]
+    fn test_dry_run_counts_match_operations() {
+        let script = r#"
+            local M = {}
+            function M.declare() return { source = {}, target = {} } end
+            function M.transform(source, target)
+                local ops = {}
+                for _, acc in ipairs(source.account) do
+                    table.insert(ops, { entity = "account", operation = "create", fields = { name = acc.name } })
+                end
+                table.insert(ops, { entity = "account", operation = "update", id = "12345678-1234-1234-1234-123456789012", fields = { name = "X" } })
+                table.insert(ops, { entity = "account", operation = "create", fields = {} })
+                return ops
+            end
+            return M
+        "#;
+
+        let source = serde_json::json!({ "account": [{ "name": "A" }, { "name": "B" }] });
+        let target = serde_json::json!({});
+
+        let result = execute_transform(script, &source, &target).unwrap();
+        let summary = DryRunSummary::from_operations(&result.operations, result.logs, None);
+
+        assert_eq!(summary.total, 4);
+        assert_eq!(summary.count(OperationType::Create), 3);
+        assert_eq!(summary.count(OperationType::Update), 1);
+        assert_eq!(summary.count(OperationType::Delete), 0);
+        assert_eq!(
+            summary.counts,
+            vec![(OperationType::Create, 3), (OperationType::Update, 1)]
+        );
+        // The create without fields fails validation (sorted after the other creates)
+        assert_eq!(summary.validation_errors.len(), 1);
+        assert!(
+            summary.validation_errors[0]
+                .message
+                .starts_with("Operation 3:")
+        );
+    }
+
+    #[test]
+    fn test_format_progress_message_with_label() {
+        let progress = Some((340, 1200, Some("Processing account".to_string())));
+        assert_eq!(
+            format_progress_message(&None, &progress),
+            "Processing account 340/1200 (28.3%)"
+        );
+        assert_eq!(
+            format_progress_message(&Some("Accounts".to_string()), &Some((1, 4, None))),
+            "Accounts - 1/4 (25.0%)"
+        );
+    }
+
     #[test]
     fn test_run_declare() {
         let script = r#"
@@ -399,7 +635,7 @@ mod tests {
             return M
         "#;
 
-        let declaration = run_declare(script).unwrap();
+        let declaration = run_declare(script, None).unwrap();
 
         assert!(declaration.source.contains_key("account"));
         assert!(declaration.target.contains_key("account"));
@@ -431,11 +667,96 @@ mod tests {
             },
         ];
 
-        let errors = validate_operations(&operations);
+        let errors = validate_operations(&operations, None);
 
         assert_eq!(errors.len(), 2);
     }
 
+    #[test]
+    fn test_validate_operations_reports_missing_ids_and_fields() {
+        let name: std::collections::HashMap<String, serde_json::Value> =
+            [("name".to_string(), serde_json::json!("Test"))]
+                .into_iter()
+                .collect();
+
+        let mut update_without_id =
+            LuaOperation::update("account", uuid::Uuid::nil(), name.clone());
+        update_without_id.id = None;
+        let operations = vec![
+            LuaOperation::create("account", name.clone()),
+            update_without_id,
+            LuaOperation::create("contact", std::collections::HashMap::new()),
+            LuaOperation::delete("account", uuid::Uuid::nil()),
+        ];
+
+        let errors: Vec<String> = validate_operations(&operations, None)
+            .into_iter()
+            .map(|e| e.message)
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Operation 2: Update operation requires id",
+                "Operation 3: Create operation requires fields",
+                "Operation 4: Delete operation requires a non-empty id (got the nil UUID)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_operations_against_metadata() {
+        use crate::api::metadata::{FieldMetadata, FieldType};
+
+        let field = |name: &str| FieldMetadata {
+            logical_name: name.to_string(),
+            schema_name: None,
+            display_name: None,
+            field_type: FieldType::String,
+            is_required: false,
+            is_primary_key: false,
+            max_length: None,
+            related_entity: None,
+            navigation_property_name: None,
+            option_values: Vec::new(),
+        };
+        let metadata: HashMap<String, EntityMetadata> = [(
+            "account".to_string(),
+            EntityMetadata {
+                fields: vec![field("name"), field("revenue")],
+                ..Default::default()
+            },
+        )]
+        .into_iter()
+        .collect();
+
+        let op = |entity: &str, field: &str| {
+            LuaOperation::create(
+                entity,
+                [(field.to_string(), serde_json::json!("x"))]
+                    .into_iter()
+                    .collect(),
+            )
+        };
+        let operations = vec![
+            op("account", "name"),
+            op("account", "revnue"),
+            op("acount", "name"),
+        ];
+
+        let errors = validate_operations(&operations, Some(&metadata));
+
+        let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Operation 2: unknown field 'revnue' on entity 'account'",
+                "Operation 3: unknown entity 'acount'",
+            ]
+        );
+        // Without metadata only structural checks apply
+        assert!(validate_operations(&operations, None).is_empty());
+    }
+
     #[tokio::test]
     async fn test_execute_async() {
         let script = r#"
@@ -479,6 +800,189 @@ mod tests {
         assert!(received_completed);
     }
 
+    #[test]
+    fn test_operation_order_is_deterministic() {
+        // Keyed tables iterate in hash order, which varies between Lua states
+        let script = r#"
+            local M = {}
+            function M.declare() return { source = {}, target = {} } end
+            function M.transform(source, target)
+                local ops = {}
+                for dept, people in pairs(lib.group_by(source.contact, "dept")) do
+                    for _, p in ipairs(people) do
+                        table.insert(ops, { entity = "contact", operation = "create", fields = { name = p.name, dept = dept } })
+                    end
+                    table.insert(ops, { entity = "account", operation = "skip", reason = dept })
+                end
+                return ops
+            end
+            return M
+        "#;
+        let depts = [
+            "Sales", "Ops", "Finance", "Legal", "R&D", "Support", "IT", "HR",
+        ];
+        let contacts: Vec<serde_json::Value> = depts
+            .iter()
+            .enumerate()
+            .map(|(i, dept)| serde_json::json!({ "name": format!("Person {}", i), "dept": dept }))
+            .collect();
+        let source = serde_json::json!({ "contact": contacts });
+
+        let summarize = |result: ExecutionResult| -> Vec<String> {
+            result
+                .operations
+                .iter()
+                .map(|op| {
+                    format!(
+                        "{}:{}:{:?}:{:?}",
+                        op.entity,
+                        op.operation.label(),
+                        op.fields.get("name"),
+                        op.reason
+                    )
+                })
+                .collect()
+        };
+
+        let first = summarize(execute_transform(script, &source, &serde_json::json!({})).unwrap());
+        for _ in 0..5 {
+            let again =
+                summarize(execute_transform(script, &source, &serde_json::json!({})).unwrap());
+            assert_eq!(first, again);
+        }
+        // Grouped by entity, then operation type
+        assert!(first[0].starts_with("account:Skip"));
+        assert!(first.last().unwrap().starts_with("contact:Create"));
+
+        // Scripts can opt out and keep their own sequence
+        let ordered = r#"
+            local M = { preserve_order = true }
+            function M.declare() return { source = {}, target = {} } end
+            function M.transform(source, target)
+                return {
+                    { entity = "contact", operation = "create", fields = { name = "B" } },
+                    { entity = "account", operation = "create", fields = { name = "A" } }
+                }
+            end
+            return M
+        "#;
+        let result =
+            execute_transform(ordered, &serde_json::json!({}), &serde_json::json!({})).unwrap();
+        assert_eq!(result.operations[0].entity, "contact");
+    }
+
+    #[tokio::test]
+    async fn test_script_reads_execution_context_info() {
+        let script = r#"
+            local M = {}
+            function M.declare() return { source = {}, target = {} } end
+            function M.transform(source, target)
+                local ctx = lib.context()
+                return {
+                    {
+                        entity = "account",
+                        operation = "create",
+                        fields = {
+                            name = ctx.config_name .. ":" .. ctx.source_env .. "->" .. ctx.target_env
+                        }
+                    }
+                }
+            end
+            return M
+        "#
+        .to_string();
+
+        let (tx, _rx) = mpsc::channel(100);
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let ctx = ExecutionContext::new(tx, cancel_flag).with_info(TransformInfo {
+            config_name: "accounts".to_string(),
+            source_env: "dev".to_string(),
+            target_env: "prod".to_string(),
+        });
+
+        let result =
+            execute_transform_async(script, serde_json::json!({}), serde_json::json!({}), ctx)
+                .await
+                .unwrap();
+
+        assert_eq!(
+            result.operations[0].fields.get("name"),
+            Some(&serde_json::json!("accounts:dev->prod"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_min_log_level_filters_script_logs() {
+        let script = r#"
+            local M = {}
+            function M.declare() return { source = {}, target = {} } end
+            function M.transform(source, target)
+                lib.debug("noisy detail")
+                lib.warn("worth seeing")
+                return {}
+            end
+            return M
+        "#
+        .to_string();
+
+        let (tx, _rx) = mpsc::channel(100);
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let ctx = ExecutionContext::new(tx, cancel_flag).with_min_log_level(LogLevel::Warn);
+
+        let result =
+            execute_transform_async(script, serde_json::json!({}), serde_json::json!({}), ctx)
+                .await
+                .unwrap();
+
+        assert_eq!(result.logs.len(), 1);
+        assert!(matches!(&result.logs[0], LogMessage::Warn(s) if s == "worth seeing"));
+    }
+
+    #[tokio::test]
+    async fn test_progress_updates_arrive_in_order() {
+        let script = r#"
+            local M = {}
+            function M.declare() return { source = {}, target = {} } end
+            function M.transform(source, target)
+                lib.progress(1, 3, "account")
+                lib.progress(2, 3, "contact")
+                lib.progress(3, 3)
+                return {}
+            end
+            return M
+        "#
+        .to_string();
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let ctx = ExecutionContext::new(tx, cancel_flag);
+
+        execute_transform_async(script, serde_json::json!({}), serde_json::json!({}), ctx)
+            .await
+            .unwrap();
+
+        let mut progress = Vec::new();
+        while let Ok(update) = rx.try_recv() {
+            if let ExecutionUpdate::Progress {
+                current,
+                total,
+                label,
+            } = update
+            {
+                progress.push((current, total, label));
+            }
+        }
+
+        assert_eq!(
+            progress,
+            vec![
+                (1, 3, Some("account".to_string())),
+                (2, 3, Some("contact".to_string())),
+                (3, 3, None),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_cancellation() {
         let script = r#"
@@ -501,4 +1005,41 @@ mod tests {
         assert!(result.was_cancelled);
         assert!(result.operations.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_cancel_flag_stops_running_script() {
+        use crate::transfer::lua::TransformError;
+
+        let script = r#"
+            local M = {}
+            function M.declare() return { source = {}, target = {} } end
+            function M.transform(source, target)
+                lib.status("Spinning")
+                while true do end
+            end
+            return M
+        "#
+        .to_string();
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let ctx = ExecutionContext::new(tx, cancel_flag.clone());
+
+        let run = tokio::spawn(execute_transform_async(
+            script,
+            serde_json::json!({}),
+            serde_json::json!({}),
+            ctx,
+        ));
+
+        // Only cancel once the script is inside its loop
+        while !matches!(rx.recv().await, Some(ExecutionUpdate::Status(_))) {}
+        cancel_flag.store(true, Ordering::Relaxed);
+
+        let err = run.await.unwrap().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<TransformError>(),
+            Some(&TransformError::Cancelled)
+        );
+    }
 }
@@ -74,6 +74,7 @@
 //! - `lib.status(msg)`, `lib.progress(current, total)` - Progress updates
 
 mod execute;
+mod modules;
 mod runtime;
 mod stdlib;
 mod types;
@@ -81,10 +82,10 @@ mod validate;
 
 // Re-export public types
 pub use execute::{
-    ExecutionContext, ExecutionResult, ExecutionUpdate, execute_transform, execute_transform_async,
-    execute_transform_sync, run_declare, validate_operations,
+    DryRunSummary, ExecutionContext, ExecutionResult, ExecutionUpdate, execute_transform,
+    execute_transform_async, execute_transform_sync, run_declare, validate_operations,
 };
-pub use runtime::LuaRuntime;
-pub use stdlib::{LogMessage, StatusUpdate, StdlibContext};
-pub use types::{Declaration, EntityDeclaration, LuaOperation, OperationType};
+pub use runtime::{LuaRuntime, TransformError};
+pub use stdlib::{LogLevel, LogMessage, StatusUpdate, StdlibContext, TransformInfo};
+pub use types::{Declaration, EntityDeclaration, LuaOperation, OperationType, sort_operations};
 pub use validate::{ValidationError, ValidationResult, validate_script, validate_script_execution};
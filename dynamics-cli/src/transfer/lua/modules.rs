@@ -0,0 +1,109 @@
+//! Shared module loading for Lua scripts
+//!
+//! Provides a limited `require("name")` that loads `.lua` files from the module
+//! directory a runtime was created with. Module names are restricted to identifier
+//! segments separated by dots (`helpers.dates` -> `helpers/dates.lua`), and
+//! resolved paths must stay inside the module directory, so scripts cannot
+//! use `require` to read arbitrary files.
+
+use mlua::{Lua, Result as LuaResult, Table, Value};
+use std::path::{Path, PathBuf};
+
+/// Resolve a module name to a file inside `dir`
+fn resolve_module_path(dir: &Path, name: &str) -> Result<PathBuf, String> {
+    let valid = !name.is_empty()
+        && name.split('.').all(|segment| {
+            !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        });
+    if !valid {
+        return Err(format!(
+            "require: invalid module name '{}' (use names like 'helpers' or 'shared.dates')",
+            name
+        ));
+    }
+
+    let mut path = dir.to_path_buf();
+    for segment in name.split('.') {
+        path.push(segment);
+    }
+    path.set_extension("lua");
+
+    // Canonicalize to catch symlinks pointing outside the module directory
+    let root = dir
+        .canonicalize()
+        .map_err(|e| format!("require: module directory '{}': {}", dir.display(), e))?;
+    let resolved = path
+        .canonicalize()
+        .map_err(|_| format!("require: module '{}' not found in {}", name, dir.display()))?;
+    if !resolved.starts_with(&root) {
+        return Err(format!(
+            "require: module '{}' resolves outside the module directory",
+            name
+        ));
+    }
+
+    Ok(resolved)
+}
+
+/// Install a global `require` that loads modules from `dir`
+///
+/// Loaded modules are cached for the lifetime of the Lua state, so each module
+/// body runs at most once per runtime. A module that returns nothing is cached
+/// as `true`, matching standard Lua.
+pub fn register_require(lua: &Lua, dir: PathBuf) -> LuaResult<()> {
+    let cache: Table = lua.create_table()?;
+
+    let require = lua.create_function(move |lua, name: String| {
+        match cache.raw_get::<Value>(name.as_str())? {
+            Value::Boolean(false) => {
+                return Err(mlua::Error::RuntimeError(format!(
+                    "require: circular dependency while loading '{}'",
+                    name
+                )));
+            }
+            Value::Nil => {}
+            loaded => return Ok(loaded),
+        }
+
+        let path = resolve_module_path(&dir, &name).map_err(mlua::Error::RuntimeError)?;
+        let source = std::fs::read_to_string(&path).map_err(|e| {
+            mlua::Error::RuntimeError(format!("require: failed to read '{}': {}", name, e))
+        })?;
+
+        // Mark as loading so circular requires fail instead of recursing forever
+        cache.raw_set(name.as_str(), false)?;
+        let result = lua
+            .load(&source)
+            .set_name(format!("@{}.lua", name.replace('.', "/")))
+            .eval::<Value>();
+
+        let module = match result {
+            Ok(Value::Nil) => Value::Boolean(true),
+            Ok(value) => value,
+            Err(e) => {
+                cache.raw_set(name.as_str(), Value::Nil)?;
+                return Err(e);
+            }
+        };
+        cache.raw_set(name.as_str(), module.clone())?;
+        Ok(module)
+    })?;
+
+    lua.globals().set("require", require)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_rejects_escape() {
+        let dir = std::env::temp_dir();
+        for name in ["../etc/passwd", "a/b", "", "a..b", "/abs"] {
+            assert!(resolve_module_path(&dir, name).is_err(), "{}", name);
+        }
+    }
+}
@@ -3,20 +3,45 @@
 //! Provides a sandboxed Lua environment for running transform scripts.
 
 use anyhow::{Context, Result};
-use mlua::{Function, Lua, StdLib, Table, Value};
+use mlua::{Function, HookTriggers, Lua, StdLib, Table, Value, VmState};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
+use super::modules;
 use super::stdlib::{StdlibContext, register_stdlib};
 use super::types::Declaration;
 
+/// Number of VM instructions executed between cancel flag checks
+const CANCEL_CHECK_INSTRUCTIONS: u32 = 1000;
+
+/// Failure that stops a transform from outside the script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformError {
+    /// The cancel flag was set while the script was running
+    Cancelled,
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransformError::Cancelled => write!(f, "Transform was cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for TransformError {}
+
 /// A sandboxed Lua runtime for executing transform scripts
 pub struct LuaRuntime {
     lua: Lua,
     context: Arc<Mutex<StdlibContext>>,
+    cancel_flag: Mutex<Option<Arc<AtomicBool>>>,
 }
 
 impl LuaRuntime {
-    /// Create a new sandboxed Lua runtime
+    /// Create a new sandboxed Lua runtime (without `require`)
     pub fn new() -> Result<Self> {
         // Create Lua with limited standard libraries (no io, os, debug, etc.)
         let lua = Lua::new_with(
@@ -33,7 +58,25 @@ impl LuaRuntime {
         // Register our standard library
         register_stdlib(&lua, context.clone()).context("Failed to register stdlib")?;
 
-        Ok(LuaRuntime { lua, context })
+        Ok(LuaRuntime {
+            lua,
+            context,
+            cancel_flag: Mutex::new(None),
+        })
+    }
+
+    /// Create a runtime whose `require` loads from `module_dir` (None leaves it disabled)
+    pub fn with_module_dir(module_dir: Option<&Path>) -> Result<Self> {
+        let runtime = Self::new()?;
+        if let Some(dir) = module_dir {
+            runtime.enable_require(dir)?;
+        }
+        Ok(runtime)
+    }
+
+    /// Enable `require("name")`, loading shared modules from `dir`
+    pub fn enable_require(&self, dir: impl Into<PathBuf>) -> Result<()> {
+        modules::register_require(&self.lua, dir.into()).context("Failed to register require")
     }
 
     /// Load and validate a script
@@ -137,6 +180,9 @@ impl LuaRuntime {
     }
 
     /// Run the transform function with source and target data
+    ///
+    /// Operations are returned in a stable order (see `sort_operations`) unless
+    /// the module sets `M.preserve_order = true` to keep the order it emitted.
     pub fn run_transform(
         &self,
         module: &Table,
@@ -150,12 +196,17 @@ impl LuaRuntime {
         let target_table = self.json_to_lua(target_data)?;
 
         // Call transform(source, target)
-        let result: Table = transform_fn
-            .call((source_table, target_table))
-            .context("Failed to call transform(source, target)")?;
+        let result: Table = match transform_fn.call((source_table, target_table)) {
+            Err(_) if self.is_cancelled() => return Err(TransformError::Cancelled.into()),
+            result => result.context("Failed to call transform(source, target)")?,
+        };
 
         // Parse operations
-        self.parse_operations(result)
+        let mut operations = self.parse_operations(result)?;
+        if !module.get::<bool>("preserve_order").unwrap_or(false) {
+            super::types::sort_operations(&mut operations);
+        }
+        Ok(operations)
     }
 
     /// Convert JSON value to Lua value
@@ -337,6 +388,20 @@ impl LuaRuntime {
         }
     }
 
+    /// Drop log messages below `level` (e.g. suppress `lib.debug` in production runs)
+    pub fn set_min_log_level(&self, level: super::stdlib::LogLevel) {
+        if let Ok(mut ctx) = self.context.lock() {
+            ctx.min_level = level;
+        }
+    }
+
+    /// Set the run details returned by `lib.context()`
+    pub fn set_transform_info(&self, info: super::stdlib::TransformInfo) {
+        if let Ok(mut ctx) = self.context.lock() {
+            ctx.info = info;
+        }
+    }
+
     /// Set a channel for real-time status updates
     ///
     /// When set, calls to `lib.status()` and `lib.progress()` will immediately
@@ -347,6 +412,35 @@ impl LuaRuntime {
         }
     }
 
+    /// Abort running scripts once `flag` is set
+    ///
+    /// The flag is checked every few VM instructions, so even a script stuck in
+    /// a loop stops and `run_transform` returns `TransformError::Cancelled`.
+    pub fn set_cancel_flag(&self, flag: Arc<AtomicBool>) {
+        if let Ok(mut cancel_flag) = self.cancel_flag.lock() {
+            *cancel_flag = Some(flag.clone());
+        }
+        self.lua.set_hook(
+            HookTriggers::new().every_nth_instruction(CANCEL_CHECK_INSTRUCTIONS),
+            move |_, _| {
+                if flag.load(Ordering::Relaxed) {
+                    Err(mlua::Error::external(TransformError::Cancelled))
+                } else {
+                    Ok(VmState::Continue)
+                }
+            },
+        );
+    }
+
+    /// Whether the cancel flag passed to `set_cancel_flag` has been set
+    fn is_cancelled(&self) -> bool {
+        self.cancel_flag
+            .lock()
+            .ok()
+            .and_then(|flag| flag.as_ref().map(|f| f.load(Ordering::Relaxed)))
+            .unwrap_or(false)
+    }
+
     /// Get access to the underlying Lua instance
     pub fn lua(&self) -> &Lua {
         &self.lua
@@ -509,21 +603,22 @@ mod tests {
 
         assert_eq!(operations.len(), 2);
 
-        // First should be update (Acme Corp exists)
+        // Operations come back sorted: creates before updates
+        // First should be create (New Company doesn't exist)
         assert_eq!(operations[0].entity, "account");
         assert_eq!(
             operations[0].operation,
-            super::super::types::OperationType::Update
+            super::super::types::OperationType::Create
         );
-        assert!(operations[0].id.is_some());
+        assert!(operations[0].id.is_none());
 
-        // Second should be create (New Company doesn't exist)
+        // Second should be update (Acme Corp exists)
         assert_eq!(operations[1].entity, "account");
         assert_eq!(
             operations[1].operation,
-            super::super::types::OperationType::Create
+            super::super::types::OperationType::Update
         );
-        assert!(operations[1].id.is_none());
+        assert!(operations[1].id.is_some());
     }
 
     #[test]
@@ -571,4 +666,58 @@ mod tests {
             "package should not be available"
         );
     }
+
+    #[test]
+    fn test_require_shared_module() {
+        let dir =
+            std::env::temp_dir().join(format!("dynamics-lua-modules-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(dir.join("shared")).unwrap();
+        std::fs::write(
+            dir.join("shared").join("names.lua"),
+            r#"
+            local N = {}
+            function N.full_name(first, last) return first .. " " .. last end
+            return N
+        "#,
+        )
+        .unwrap();
+
+        // Runtimes only get require when created with a module directory
+        let plain = LuaRuntime::new().unwrap();
+        let result: Value = plain.lua().load("return require").eval().unwrap();
+        assert!(matches!(result, Value::Nil));
+
+        let runtime = LuaRuntime::with_module_dir(Some(&dir)).unwrap();
+
+        let script = r#"
+            local names = require("shared.names")
+            local M = {}
+            function M.declare() return { source = {}, target = {} } end
+            function M.transform(source, target)
+                local again = require("shared.names")
+                return {
+                    {
+                        entity = "contact",
+                        operation = "create",
+                        fields = { fullname = names.full_name("Ada", "Lovelace"), same = tostring(again == names) }
+                    }
+                }
+            end
+            return M
+        "#;
+
+        let module = runtime.load_script(script).unwrap();
+        let ops = runtime
+            .run_transform(&module, &serde_json::json!({}), &serde_json::json!({}))
+            .unwrap();
+        assert_eq!(ops[0].fields["fullname"], "Ada Lovelace");
+        assert_eq!(ops[0].fields["same"], "true");
+
+        // Escaping the module directory is rejected
+        let result: mlua::Result<Value> =
+            runtime.lua().load(r#"return require("../secret")"#).eval();
+        assert!(result.is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -3,21 +3,72 @@
 //! Implements the `lib.*` namespace available in transform scripts.
 
 use mlua::{Function, Lua, Result as LuaResult, Table, Value};
+use sha2::{Digest, Sha256};
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
-/// Messages captured from lib.log/lib.warn calls
+/// Severity of a captured log message, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum LogLevel {
+    #[default]
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Parse the `transfer.lua_log_level` option value
+    pub fn from_option(value: &str) -> Self {
+        match value {
+            "info" => LogLevel::Info,
+            "warn" => LogLevel::Warn,
+            "error" => LogLevel::Error,
+            _ => LogLevel::Debug,
+        }
+    }
+}
+
+/// Messages captured from lib.debug/lib.log/lib.warn/lib.error_log calls
 #[derive(Debug, Clone)]
 pub enum LogMessage {
+    Debug(String),
     Info(String),
     Warn(String),
+    Error(String),
+}
+
+impl LogMessage {
+    pub fn level(&self) -> LogLevel {
+        match self {
+            LogMessage::Debug(_) => LogLevel::Debug,
+            LogMessage::Info(_) => LogLevel::Info,
+            LogMessage::Warn(_) => LogLevel::Warn,
+            LogMessage::Error(_) => LogLevel::Error,
+        }
+    }
 }
 
 /// Status updates from lib.status/lib.progress calls
 #[derive(Debug, Clone)]
 pub enum StatusUpdate {
     Status(String),
-    Progress { current: usize, total: usize },
+    Progress {
+        current: usize,
+        total: usize,
+        label: Option<String>,
+    },
+}
+
+/// Details about the current run, exposed to scripts through `lib.context()`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransformInfo {
+    /// Name of the transfer config being run
+    pub config_name: String,
+    /// Source environment name
+    pub source_env: String,
+    /// Target environment name
+    pub target_env: String,
 }
 
 /// Context for stdlib functions that need to communicate with the host
@@ -28,6 +79,19 @@ pub struct StdlibContext {
     pub status: Option<StatusUpdate>,
     /// Real-time status sender (for streaming during execution)
     pub status_tx: Option<std::sync::mpsc::Sender<StatusUpdate>>,
+    /// Messages below this level are dropped
+    pub min_level: LogLevel,
+    /// Run details returned by `lib.context()`
+    pub info: TransformInfo,
+}
+
+impl StdlibContext {
+    /// Record a log message unless it is below `min_level`
+    pub fn push_log(&mut self, msg: LogMessage) {
+        if msg.level() >= self.min_level {
+            self.logs.push(msg);
+        }
+    }
 }
 
 impl Default for StdlibContext {
@@ -36,6 +100,8 @@ impl Default for StdlibContext {
             logs: Vec::new(),
             status: None,
             status_tx: None,
+            min_level: LogLevel::default(),
+            info: TransformInfo::default(),
         }
     }
 }
@@ -46,6 +112,8 @@ impl std::fmt::Debug for StdlibContext {
             .field("logs", &self.logs)
             .field("status", &self.status)
             .field("status_tx", &self.status_tx.is_some())
+            .field("min_level", &self.min_level)
+            .field("info", &self.info)
             .finish()
     }
 }
@@ -56,12 +124,20 @@ pub fn register_stdlib(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResu
 
     // Collection functions
     lib.set("find", create_find_fn(lua)?)?;
+    lib.set("find_all", create_find_all_fn(lua)?)?;
     lib.set("filter", create_filter_fn(lua)?)?;
     lib.set("map", create_map_fn(lua)?)?;
     lib.set("group_by", create_group_by_fn(lua)?)?;
+    lib.set("count_by", create_count_by_fn(lua)?)?;
+    lib.set("chunk", create_chunk_fn(lua)?)?;
+    lib.set("partition", create_partition_fn(lua)?)?;
+    lib.set("unique", create_unique_fn(lua)?)?;
+    lib.set("distinct_by", create_distinct_by_fn(lua)?)?;
+    lib.set("deep_equal", create_deep_equal_fn(lua)?)?;
 
     // GUID functions
     lib.set("guid", create_guid_fn(lua)?)?;
+    lib.set("uuid_v5", create_uuid_v5_fn(lua)?)?;
     lib.set("is_guid", create_is_guid_fn(lua)?)?;
 
     // String functions
@@ -72,31 +148,53 @@ pub fn register_stdlib(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResu
     lib.set("contains", create_contains_fn(lua)?)?;
     lib.set("starts_with", create_starts_with_fn(lua)?)?;
     lib.set("ends_with", create_ends_with_fn(lua)?)?;
+    lib.set("pad_left", create_pad_left_fn(lua)?)?;
+    lib.set("pad_right", create_pad_right_fn(lua)?)?;
+    lib.set("truncate", create_truncate_fn(lua)?)?;
+    lib.set("slugify", create_slugify_fn(lua)?)?;
 
     // Date functions
     lib.set("now", create_now_fn(lua)?)?;
+    lib.set("now_epoch", create_now_epoch_fn(lua)?)?;
+    lib.set("now_local", create_now_local_fn(lua)?)?;
     lib.set("parse_date", create_parse_date_fn(lua)?)?;
     lib.set("format_date", create_format_date_fn(lua)?)?;
 
+    // Hash functions
+    lib.set("hash", create_hash_fn(lua)?)?;
+    lib.set("short_hash", create_short_hash_fn(lua)?)?;
+
     // Type check functions
     lib.set("is_nil", create_is_nil_fn(lua)?)?;
     lib.set("is_string", create_is_string_fn(lua)?)?;
     lib.set("is_number", create_is_number_fn(lua)?)?;
     lib.set("is_table", create_is_table_fn(lua)?)?;
     lib.set("is_boolean", create_is_boolean_fn(lua)?)?;
+    lib.set("is_empty", create_is_empty_fn(lua)?)?;
+
+    // Debug functions
+    lib.set("debug_dump", create_debug_dump_fn(lua)?)?;
 
     // Logging functions (with context)
     let ctx = context.clone();
     lib.set("log", create_log_fn(lua, ctx)?)?;
     let ctx = context.clone();
     lib.set("warn", create_warn_fn(lua, ctx)?)?;
+    let ctx = context.clone();
+    lib.set("debug", create_debug_fn(lua, ctx)?)?;
+    let ctx = context.clone();
+    lib.set("error_log", create_error_log_fn(lua, ctx)?)?;
 
     // Status functions (with context)
     let ctx = context.clone();
     lib.set("status", create_status_fn(lua, ctx)?)?;
-    let ctx = context;
+    let ctx = context.clone();
     lib.set("progress", create_progress_fn(lua, ctx)?)?;
 
+    // Run details (read-only)
+    let ctx = context;
+    lib.set("context", create_context_fn(lua, ctx)?)?;
+
     lua.globals().set("lib", lib)?;
     Ok(())
 }
@@ -122,6 +220,24 @@ fn create_find_fn(lua: &Lua) -> LuaResult<Function> {
     })
 }
 
+/// lib.find_all(records, field, value) -> records
+/// Find every record where record[field] == value
+fn create_find_all_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|lua, (records, field, value): (Table, String, Value)| {
+        let result = lua.create_table()?;
+        let mut idx = 1;
+        for (_, record) in records.pairs::<Value, Table>().flatten() {
+            if let Ok(field_value) = record.get::<Value>(field.as_str()) {
+                if values_equal(&field_value, &value) {
+                    result.set(idx, record)?;
+                    idx += 1;
+                }
+            }
+        }
+        Ok(result)
+    })
+}
+
 /// lib.filter(records, fn) -> records
 /// Filter records by predicate function
 fn create_filter_fn(lua: &Lua) -> LuaResult<Function> {
@@ -189,6 +305,122 @@ fn create_group_by_fn(lua: &Lua) -> LuaResult<Function> {
     })
 }
 
+/// lib.count_by(records, field) -> table
+/// Count records per field value
+fn create_count_by_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|lua, (records, field): (Table, String)| {
+        let mut counts: std::collections::HashMap<String, i64> = std::collections::HashMap::new();
+
+        for (_, record) in records.pairs::<Value, Table>().flatten() {
+            if let Ok(key) = record.get::<Value>(field.as_str()) {
+                *counts.entry(value_to_string(&key)).or_insert(0) += 1;
+            }
+        }
+
+        let result = lua.create_table()?;
+        for (key, count) in counts {
+            result.set(key, count)?;
+        }
+        Ok(result)
+    })
+}
+
+/// lib.chunk(records, size) -> table of tables
+/// Split records into consecutive groups of `size` (last group may be shorter)
+fn create_chunk_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|lua, (records, size): (Table, i64)| {
+        if size < 1 {
+            return Err(mlua::Error::RuntimeError(format!(
+                "lib.chunk: size must be at least 1, got {}",
+                size
+            )));
+        }
+
+        let result = lua.create_table()?;
+        let mut current = lua.create_table()?;
+        let mut current_len = 0;
+        for pair in records.pairs::<Value, Value>() {
+            if let Ok((_, record)) = pair {
+                current_len += 1;
+                current.set(current_len, record)?;
+                if current_len == size {
+                    result.push(current)?;
+                    current = lua.create_table()?;
+                    current_len = 0;
+                }
+            }
+        }
+        if current_len > 0 {
+            result.push(current)?;
+        }
+        Ok(result)
+    })
+}
+
+/// lib.partition(records, fn) -> matching, non_matching
+/// Split records into two tables by predicate function
+fn create_partition_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|lua, (records, predicate): (Table, Function)| {
+        let matching = lua.create_table()?;
+        let non_matching = lua.create_table()?;
+        for pair in records.pairs::<Value, Value>() {
+            if let Ok((_, record)) = pair {
+                let keep: bool = predicate.call(record.clone())?;
+                if keep {
+                    matching.push(record)?;
+                } else {
+                    non_matching.push(record)?;
+                }
+            }
+        }
+        Ok((matching, non_matching))
+    })
+}
+
+/// lib.unique(values) -> values
+/// Remove duplicate scalar values, preserving first-seen order
+fn create_unique_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|lua, values: Table| {
+        let result = lua.create_table()?;
+        let mut seen: Vec<Value> = Vec::new();
+        for pair in values.pairs::<Value, Value>() {
+            if let Ok((_, value)) = pair {
+                if !seen.iter().any(|s| values_equal(s, &value)) {
+                    seen.push(value.clone());
+                    result.push(value)?;
+                }
+            }
+        }
+        Ok(result)
+    })
+}
+
+/// lib.distinct_by(records, field) -> records
+/// Keep the first record for each distinct value of record[field]
+fn create_distinct_by_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|lua, (records, field): (Table, String)| {
+        let result = lua.create_table()?;
+        let mut seen = std::collections::HashSet::new();
+        for pair in records.pairs::<Value, Table>() {
+            if let Ok((_, record)) = pair {
+                let key = value_to_string(&record.get::<Value>(field.as_str())?);
+                if seen.insert(key) {
+                    result.push(record)?;
+                }
+            }
+        }
+        Ok(result)
+    })
+}
+
+/// lib.deep_equal(a, b) -> bool
+/// Structurally compare two values, recursing into tables
+fn create_deep_equal_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, (a, b): (Value, Value)| {
+        deep_equal(&a, &b, &mut std::collections::HashSet::new())
+    })
+}
+
 // =============================================================================
 // GUID functions
 // =============================================================================
@@ -199,6 +431,28 @@ fn create_guid_fn(lua: &Lua) -> LuaResult<Function> {
     lua.create_function(|_, ()| Ok(Uuid::new_v4().to_string()))
 }
 
+/// lib.uuid_v5(namespace, name) -> string
+/// Generate a deterministic (name-based, SHA-1) GUID from a namespace and name.
+/// Namespace is a GUID string or one of "dns", "url", "oid", "x500".
+fn create_uuid_v5_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, (namespace, name): (String, String)| {
+        let namespace_uuid = match namespace.to_lowercase().as_str() {
+            "dns" => Uuid::NAMESPACE_DNS,
+            "url" => Uuid::NAMESPACE_URL,
+            "oid" => Uuid::NAMESPACE_OID,
+            "x500" => Uuid::NAMESPACE_X500,
+            _ => Uuid::parse_str(&namespace).map_err(|e| {
+                mlua::Error::RuntimeError(format!(
+                    "lib.uuid_v5: invalid namespace '{}': {}",
+                    namespace, e
+                ))
+            })?,
+        };
+
+        Ok(Uuid::new_v5(&namespace_uuid, name.as_bytes()).to_string())
+    })
+}
+
 /// lib.is_guid(value) -> bool
 /// Check if value is a valid GUID string
 fn create_is_guid_fn(lua: &Lua) -> LuaResult<Function> {
@@ -256,6 +510,43 @@ fn create_ends_with_fn(lua: &Lua) -> LuaResult<Function> {
     lua.create_function(|_, (s, suffix): (String, String)| Ok(s.ends_with(&suffix)))
 }
 
+/// lib.pad_left(s, width, char?) -> string
+/// Left-pad to `width` characters (default pad char is a space)
+fn create_pad_left_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, (s, width, pad): (String, usize, Option<String>)| {
+        let padding = padding_for(&s, width, pad, "lib.pad_left")?;
+        Ok(format!("{}{}", padding, s))
+    })
+}
+
+/// lib.pad_right(s, width, char?) -> string
+/// Right-pad to `width` characters (default pad char is a space)
+fn create_pad_right_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, (s, width, pad): (String, usize, Option<String>)| {
+        let padding = padding_for(&s, width, pad, "lib.pad_right")?;
+        Ok(format!("{}{}", s, padding))
+    })
+}
+
+/// lib.truncate(s, max_len, ellipsis?) -> string
+/// Shorten to at most `max_len` characters; `ellipsis` (default "") is appended
+/// only when the string was cut and counts toward the limit
+fn create_truncate_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(
+        |_, (s, max_len, ellipsis): (String, usize, Option<String>)| {
+            Ok(truncate_chars(&s, max_len, &ellipsis.unwrap_or_default()))
+        },
+    )
+}
+
+/// lib.slugify(s, sep?) -> string
+/// Lowercase, fold basic accents, and join alphanumeric runs with `sep` (default "-")
+fn create_slugify_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, (s, sep): (String, Option<String>)| {
+        Ok(slugify(&s, sep.as_deref().unwrap_or("-")))
+    })
+}
+
 // =============================================================================
 // Date functions
 // =============================================================================
@@ -266,6 +557,23 @@ fn create_now_fn(lua: &Lua) -> LuaResult<Function> {
     lua.create_function(|_, ()| Ok(chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()))
 }
 
+/// lib.now_epoch() -> integer
+/// Returns current time as Unix seconds
+fn create_now_epoch_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, ()| Ok(chrono::Utc::now().timestamp()))
+}
+
+/// lib.now_local(tz?) -> string
+/// Returns current time in ISO 8601 with the offset of an IANA timezone (UTC if unknown)
+fn create_now_local_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, tz: Option<String>| {
+        Ok(format_in_timezone(
+            chrono::Utc::now(),
+            tz.as_deref().unwrap_or("UTC"),
+        ))
+    })
+}
+
 /// lib.parse_date(s) -> string|nil
 /// Parse various date formats to ISO 8601
 fn create_parse_date_fn(lua: &Lua) -> LuaResult<Function> {
@@ -301,6 +609,7 @@ fn create_parse_date_fn(lua: &Lua) -> LuaResult<Function> {
 
 /// lib.format_date(dt, fmt) -> string|nil
 /// Format ISO date string with given format
+/// Inputs with a UTC offset (e.g. `+02:00`) are converted to UTC first
 fn create_format_date_fn(lua: &Lua) -> LuaResult<Function> {
     lua.create_function(|_, (dt, fmt): (String, String)| {
         if let Ok(parsed) = chrono::NaiveDateTime::parse_from_str(&dt, "%Y-%m-%dT%H:%M:%SZ") {
@@ -309,12 +618,35 @@ fn create_format_date_fn(lua: &Lua) -> LuaResult<Function> {
             chrono::NaiveDateTime::parse_from_str(&dt, "%Y-%m-%dT%H:%M:%S%.fZ")
         {
             Ok(Some(parsed.format(&fmt).to_string()))
+        } else if let Ok(parsed) = chrono::DateTime::parse_from_str(&dt, "%Y-%m-%dT%H:%M:%S%.f%:z")
+        {
+            Ok(Some(parsed.naive_utc().format(&fmt).to_string()))
         } else {
             Ok(None)
         }
     })
 }
 
+// =============================================================================
+// Hash functions
+// =============================================================================
+
+/// lib.hash(value) -> string
+/// Hex SHA-256 of the value's canonical form
+fn create_hash_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, v: Value| hash_value(&v))
+}
+
+/// lib.short_hash(value) -> string
+/// First 8 hex characters of lib.hash(value)
+fn create_short_hash_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, v: Value| {
+        let mut hash = hash_value(&v)?;
+        hash.truncate(8);
+        Ok(hash)
+    })
+}
+
 // =============================================================================
 // Type check functions
 // =============================================================================
@@ -344,6 +676,31 @@ fn create_is_boolean_fn(lua: &Lua) -> LuaResult<Function> {
     lua.create_function(|_, v: Value| Ok(matches!(v, Value::Boolean(_))))
 }
 
+/// lib.is_empty(v) -> bool
+/// True for nil, empty/whitespace-only strings and tables without entries
+fn create_is_empty_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, v: Value| match v {
+        Value::Nil => Ok(true),
+        Value::String(s) => Ok(s.to_str()?.trim().is_empty()),
+        Value::Table(t) => Ok(t.pairs::<Value, Value>().next().is_none()),
+        _ => Ok(false),
+    })
+}
+
+// =============================================================================
+// Debug functions
+// =============================================================================
+
+/// lib.debug_dump(value) -> string
+/// Indented, human-readable representation of any value
+fn create_debug_dump_fn(lua: &Lua) -> LuaResult<Function> {
+    lua.create_function(|_, v: Value| {
+        let mut out = String::new();
+        dump_value(&v, 0, &mut std::collections::HashSet::new(), &mut out)?;
+        Ok(out)
+    })
+}
+
 // =============================================================================
 // Logging functions
 // =============================================================================
@@ -352,7 +709,7 @@ fn create_is_boolean_fn(lua: &Lua) -> LuaResult<Function> {
 fn create_log_fn(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResult<Function> {
     lua.create_function(move |_, msg: String| {
         if let Ok(mut ctx) = context.lock() {
-            ctx.logs.push(LogMessage::Info(msg));
+            ctx.push_log(LogMessage::Info(msg));
         }
         Ok(())
     })
@@ -362,7 +719,27 @@ fn create_log_fn(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResult<Fun
 fn create_warn_fn(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResult<Function> {
     lua.create_function(move |_, msg: String| {
         if let Ok(mut ctx) = context.lock() {
-            ctx.logs.push(LogMessage::Warn(msg));
+            ctx.push_log(LogMessage::Warn(msg));
+        }
+        Ok(())
+    })
+}
+
+/// lib.debug(msg) - Debug log, dropped when the minimum level is above Debug
+fn create_debug_fn(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResult<Function> {
+    lua.create_function(move |_, msg: String| {
+        if let Ok(mut ctx) = context.lock() {
+            ctx.push_log(LogMessage::Debug(msg));
+        }
+        Ok(())
+    })
+}
+
+/// lib.error_log(msg) - Error log (does not abort the script)
+fn create_error_log_fn(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResult<Function> {
+    lua.create_function(move |_, msg: String| {
+        if let Ok(mut ctx) = context.lock() {
+            ctx.push_log(LogMessage::Error(msg));
         }
         Ok(())
     })
@@ -388,19 +765,46 @@ fn create_status_fn(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResult<
     })
 }
 
-/// lib.progress(current, total) - Update progress bar
+/// lib.progress(current, total, label?) - Update progress bar with an optional label
 fn create_progress_fn(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResult<Function> {
-    lua.create_function(move |_, (current, total): (usize, usize)| {
-        if let Ok(mut ctx) = context.lock() {
-            let update = StatusUpdate::Progress { current, total };
-            // Send to real-time channel if present
-            if let Some(ref tx) = ctx.status_tx {
-                let _ = tx.send(update.clone());
+    lua.create_function(
+        move |_, (current, total, label): (usize, usize, Option<String>)| {
+            if let Ok(mut ctx) = context.lock() {
+                let update = StatusUpdate::Progress {
+                    current,
+                    total,
+                    label,
+                };
+                // Send to real-time channel if present
+                if let Some(ref tx) = ctx.status_tx {
+                    let _ = tx.send(update.clone());
+                }
+                // Also store for polling
+                ctx.status = Some(update);
             }
-            // Also store for polling
-            ctx.status = Some(update);
-        }
-        Ok(())
+            Ok(())
+        },
+    )
+}
+
+// =============================================================================
+// Run context
+// =============================================================================
+
+/// lib.context() -> table
+/// Read-only table with `config_name`, `source_env` and `target_env`
+fn create_context_fn(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResult<Function> {
+    lua.create_function(move |lua, ()| {
+        let info = context
+            .lock()
+            .map(|ctx| ctx.info.clone())
+            .unwrap_or_default();
+
+        let fields = lua.create_table()?;
+        fields.set("config_name", info.config_name)?;
+        fields.set("source_env", info.source_env)?;
+        fields.set("target_env", info.target_env)?;
+        read_only_table(lua, fields)
     })
 }
 
@@ -408,6 +812,26 @@ fn create_progress_fn(lua: &Lua, context: Arc<Mutex<StdlibContext>>) -> LuaResul
 // Helper functions
 // =============================================================================
 
+/// Wrap `fields` in a proxy table that rejects writes and hides its metatable
+fn read_only_table(lua: &Lua, fields: Table) -> LuaResult<Table> {
+    let meta = lua.create_table()?;
+    meta.set("__index", fields)?;
+    meta.set(
+        "__newindex",
+        lua.create_function(|_, (_, key, _): (Table, Value, Value)| -> LuaResult<()> {
+            Err(mlua::Error::RuntimeError(format!(
+                "lib.context() is read-only (tried to set '{}')",
+                value_to_string(&key)
+            )))
+        })?,
+    )?;
+    meta.set("__metatable", false)?;
+
+    let proxy = lua.create_table()?;
+    proxy.set_metatable(Some(meta));
+    Ok(proxy)
+}
+
 /// Compare two Lua values for equality
 fn values_equal(a: &Value, b: &Value) -> bool {
     match (a, b) {
@@ -423,6 +847,228 @@ fn values_equal(a: &Value, b: &Value) -> bool {
     }
 }
 
+/// Recursive structural equality. Tables are equal when they have the same key
+/// set and every value is deep-equal. `visited` holds table pairs already under
+/// comparison so cyclic tables terminate; a revisited pair is assumed equal.
+fn deep_equal(
+    a: &Value,
+    b: &Value,
+    visited: &mut std::collections::HashSet<(usize, usize)>,
+) -> LuaResult<bool> {
+    let (Value::Table(a), Value::Table(b)) = (a, b) else {
+        return Ok(values_equal(a, b));
+    };
+
+    if a == b || !visited.insert((a.to_pointer() as usize, b.to_pointer() as usize)) {
+        return Ok(true);
+    }
+
+    let mut a_len = 0;
+    for pair in a.pairs::<Value, Value>() {
+        let (key, a_value) = pair?;
+        let b_value: Value = b.raw_get(key)?;
+        if b_value.is_nil() || !deep_equal(&a_value, &b_value, visited)? {
+            return Ok(false);
+        }
+        a_len += 1;
+    }
+
+    Ok(b.pairs::<Value, Value>().count() == a_len)
+}
+
+/// SHA-256 of a value's canonical JSON serialization as lowercase hex, so
+/// values of different types (`1` and `"1"`) never hash alike.
+fn hash_value(v: &Value) -> LuaResult<String> {
+    let canonical = canonical_json(v, &mut std::collections::HashSet::new())?.to_string();
+    Ok(format!("{:x}", Sha256::digest(canonical.as_bytes())))
+}
+
+/// Convert a value to JSON with a stable shape: sequences become arrays,
+/// other tables become objects (serde_json's map is key-sorted) keyed by
+/// each key's JSON, and integral floats collapse to integers so `10` and
+/// `10.0` hash alike.
+fn canonical_json(
+    v: &Value,
+    visited: &mut std::collections::HashSet<usize>,
+) -> LuaResult<serde_json::Value> {
+    Ok(match v {
+        Value::Nil => serde_json::Value::Null,
+        Value::Boolean(b) => serde_json::Value::Bool(*b),
+        Value::Integer(i) => serde_json::json!(i),
+        Value::Number(n) if n.fract() == 0.0 && n.abs() < i64::MAX as f64 => {
+            serde_json::json!(*n as i64)
+        }
+        Value::Number(n) => serde_json::json!(n),
+        Value::String(s) => serde_json::Value::String(s.to_str()?.to_string()),
+        Value::Table(t) => {
+            let ptr = t.to_pointer() as usize;
+            if !visited.insert(ptr) {
+                return Err(mlua::Error::RuntimeError(
+                    "hash: cannot hash a cyclic table".to_string(),
+                ));
+            }
+
+            let len = t.raw_len();
+            let count = t.pairs::<Value, Value>().count();
+            let json = if len > 0 && len == count {
+                let mut arr = Vec::with_capacity(len);
+                for item in t.sequence_values::<Value>() {
+                    arr.push(canonical_json(&item?, visited)?);
+                }
+                serde_json::Value::Array(arr)
+            } else {
+                let mut obj = serde_json::Map::new();
+                for pair in t.pairs::<Value, Value>() {
+                    let (key, value) = pair?;
+                    // Keys are stored as their own JSON so `[1]` and `["1"]` stay distinct
+                    let key = canonical_json(&key, visited)?.to_string();
+                    obj.insert(key, canonical_json(&value, visited)?);
+                }
+                serde_json::Value::Object(obj)
+            };
+
+            visited.remove(&ptr);
+            json
+        }
+        other => serde_json::Value::String(value_to_string(other)),
+    })
+}
+
+/// Append an indented dump of `v` to `out`. Table keys are sorted so output
+/// is stable; tables already being dumped further up print as `<cycle>`.
+fn dump_value(
+    v: &Value,
+    depth: usize,
+    visited: &mut std::collections::HashSet<usize>,
+    out: &mut String,
+) -> LuaResult<()> {
+    let Value::Table(t) = v else {
+        match v {
+            Value::String(s) => out.push_str(&format!("{:?}", s.to_str()?.to_string())),
+            Value::Nil | Value::Boolean(_) | Value::Integer(_) | Value::Number(_) => {
+                out.push_str(&value_to_string(v))
+            }
+            other => out.push_str(&format!("<{}>", other.type_name())),
+        }
+        return Ok(());
+    };
+
+    let ptr = t.to_pointer() as usize;
+    if !visited.insert(ptr) {
+        out.push_str("<cycle>");
+        return Ok(());
+    }
+
+    let mut entries = Vec::new();
+    for pair in t.pairs::<Value, Value>() {
+        entries.push(pair?);
+    }
+
+    if entries.is_empty() {
+        out.push_str("{}");
+    } else {
+        // Integer keys in numeric order first, then everything else by string form
+        entries.sort_by(|(a, _), (b, _)| match (a, b) {
+            (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+            (Value::Integer(_), _) => std::cmp::Ordering::Less,
+            (_, Value::Integer(_)) => std::cmp::Ordering::Greater,
+            _ => value_to_string(a).cmp(&value_to_string(b)),
+        });
+
+        let indent = "  ".repeat(depth + 1);
+        out.push_str("{\n");
+        for (key, value) in &entries {
+            out.push_str(&indent);
+            match key {
+                Value::String(s) => out.push_str(&s.to_str()?),
+                other => out.push_str(&format!("[{}]", value_to_string(other))),
+            }
+            out.push_str(" = ");
+            dump_value(value, depth + 1, visited, out)?;
+            out.push_str(",\n");
+        }
+        out.push_str(&"  ".repeat(depth));
+        out.push('}');
+    }
+
+    visited.remove(&ptr);
+    Ok(())
+}
+
+/// Build the padding needed to bring `s` up to `width` characters.
+/// The pad string must be exactly one character.
+fn padding_for(s: &str, width: usize, pad: Option<String>, fn_name: &str) -> LuaResult<String> {
+    let pad = pad.unwrap_or_else(|| " ".to_string());
+    let mut chars = pad.chars();
+    let (Some(pad_char), None) = (chars.next(), chars.next()) else {
+        return Err(mlua::Error::RuntimeError(format!(
+            "{}: pad character must be exactly one character, got '{}'",
+            fn_name, pad
+        )));
+    };
+
+    let len = s.chars().count();
+    Ok(std::iter::repeat_n(pad_char, width.saturating_sub(len)).collect())
+}
+
+/// Format a UTC instant as ISO 8601 in an IANA timezone, falling back to UTC
+fn format_in_timezone(now: chrono::DateTime<chrono::Utc>, tz: &str) -> String {
+    let zone: chrono_tz::Tz = tz.parse().unwrap_or_else(|_| {
+        log::warn!("lib.now_local: unknown timezone '{}', using UTC", tz);
+        chrono_tz::UTC
+    });
+    now.with_timezone(&zone)
+        .format("%Y-%m-%dT%H:%M:%S%:z")
+        .to_string()
+}
+
+/// Truncate on a character boundary, keeping the result within `max_len` characters
+fn truncate_chars(s: &str, max_len: usize, ellipsis: &str) -> String {
+    if s.chars().count() <= max_len {
+        return s.to_string();
+    }
+
+    let keep = max_len.saturating_sub(ellipsis.chars().count());
+    let truncated: String = s.chars().take(keep).chain(ellipsis.chars()).collect();
+    // An ellipsis longer than the limit is itself cut to fit
+    truncated.chars().take(max_len).collect()
+}
+
+/// Build a slug: alphanumeric runs joined by `sep`, no leading or trailing separator
+fn slugify(s: &str, sep: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.to_lowercase().chars() {
+        match fold_accent(c) {
+            Some(ascii) => folded.push_str(ascii),
+            None => folded.push(c),
+        }
+    }
+
+    folded
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+/// ASCII replacement for common accented Latin letters (lowercase only)
+fn fold_accent(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => "a",
+        'æ' => "ae",
+        'ç' => "c",
+        'è' | 'é' | 'ê' | 'ë' => "e",
+        'ì' | 'í' | 'î' | 'ï' => "i",
+        'ñ' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => "o",
+        'œ' => "oe",
+        'ù' | 'ú' | 'û' | 'ü' => "u",
+        'ý' | 'ÿ' => "y",
+        'ß' => "ss",
+        _ => return None,
+    })
+}
+
 /// Convert a Lua value to a string key
 fn value_to_string(v: &Value) -> String {
     match v {
@@ -465,6 +1111,38 @@ mod tests {
         assert!(!is_invalid);
     }
 
+    #[test]
+    fn test_uuid_v5() {
+        let (lua, _) = create_test_lua();
+
+        let first: String = lua
+            .load("return lib.uuid_v5('dns', 'contoso.com')")
+            .eval()
+            .unwrap();
+        let second: String = lua
+            .load("return lib.uuid_v5('DNS', 'contoso.com')")
+            .eval()
+            .unwrap();
+        assert_eq!(first, second);
+
+        let parsed = Uuid::parse_str(&first).unwrap();
+        assert_eq!(parsed.get_version_num(), 5);
+        assert_eq!(parsed, Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"contoso.com"));
+
+        // Explicit namespace GUID
+        let custom: String = lua
+            .load("return lib.uuid_v5('550e8400-e29b-41d4-a716-446655440000', 'account-42')")
+            .eval()
+            .unwrap();
+        assert_ne!(custom, first);
+
+        // Invalid namespace raises
+        let result: mlua::Result<String> = lua
+            .load("return lib.uuid_v5('not-a-namespace', 'x')")
+            .eval();
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_string_functions() {
         let (lua, _) = create_test_lua();
@@ -497,6 +1175,73 @@ mod tests {
         assert!(ends);
     }
 
+    #[test]
+    fn test_pad() {
+        let (lua, _) = create_test_lua();
+
+        let padded: String = lua
+            .load(r#"return lib.pad_left("42", 6, "0")"#)
+            .eval()
+            .unwrap();
+        assert_eq!(padded, "000042");
+
+        let padded: String = lua.load(r#"return lib.pad_right("ab", 4)"#).eval().unwrap();
+        assert_eq!(padded, "ab  ");
+
+        // Already at or over width: unchanged
+        let unchanged: String = lua
+            .load(r#"return lib.pad_left("1234567", 6, "0")"#)
+            .eval()
+            .unwrap();
+        assert_eq!(unchanged, "1234567");
+
+        let result: mlua::Result<String> = lua.load(r#"return lib.pad_left("1", 6, "00")"#).eval();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_truncate() {
+        let (lua, _) = create_test_lua();
+
+        // Multibyte characters are counted and cut whole, ellipsis included in the limit
+        let truncated: String = lua
+            .load(r#"return lib.truncate("Zürich–Genève", 8, "…")"#)
+            .eval()
+            .unwrap();
+        assert_eq!(truncated, "Zürich–…");
+
+        let truncated: String = lua
+            .load(r#"return lib.truncate("日本語のテキスト", 3)"#)
+            .eval()
+            .unwrap();
+        assert_eq!(truncated, "日本語");
+
+        // Shorter than the limit: returned unchanged, no ellipsis
+        let unchanged: String = lua
+            .load(r#"return lib.truncate("short", 10, "...")"#)
+            .eval()
+            .unwrap();
+        assert_eq!(unchanged, "short");
+    }
+
+    #[test]
+    fn test_slugify() {
+        let (lua, _) = create_test_lua();
+
+        let slug: String = lua
+            .load(r#"return lib.slugify("Café Déjà Vu!")"#)
+            .eval()
+            .unwrap();
+        assert_eq!(slug, "cafe-deja-vu");
+
+        // Runs of spaces and punctuation collapse to one separator
+        let slug: String = lua
+            .load(r#"return lib.slugify("  Project   Alpha -- Phase  2  ", "_")"#)
+            .eval()
+            .unwrap();
+        assert_eq!(slug, "project_alpha_phase_2");
+    }
+
     #[test]
     fn test_split() {
         let (lua, _) = create_test_lua();
@@ -534,6 +1279,33 @@ mod tests {
         assert!(is_bool);
     }
 
+    #[test]
+    fn test_is_empty() {
+        let (lua, _) = create_test_lua();
+
+        for expr in [
+            "nil",
+            "''",
+            "'   '",
+            "{}",
+            "(function() local t = { a = 1 } t.a = nil return t end)()",
+        ] {
+            let empty: bool = lua
+                .load(format!("return lib.is_empty({})", expr))
+                .eval()
+                .unwrap();
+            assert!(empty, "{} should be empty", expr);
+        }
+
+        for expr in ["'x'", "{ 1 }", "{ a = 1 }", "0", "false"] {
+            let empty: bool = lua
+                .load(format!("return lib.is_empty({})", expr))
+                .eval()
+                .unwrap();
+            assert!(!empty, "{} should not be empty", expr);
+        }
+    }
+
     #[test]
     fn test_find() {
         let (lua, _) = create_test_lua();
@@ -556,6 +1328,40 @@ mod tests {
         assert_eq!(result, "25");
     }
 
+    #[test]
+    fn test_find_all() {
+        let (lua, _) = create_test_lua();
+
+        let names: Vec<String> = lua
+            .load(
+                r#"
+            local records = {
+                { name = "Alice", dept = "A" },
+                { name = "Bob", dept = "B" },
+                { name = "Charlie", dept = "A" }
+            }
+            local found = lib.find_all(records, "dept", "A")
+            local names = {}
+            for i, r in ipairs(found) do names[i] = r.name end
+            return names
+        "#,
+            )
+            .eval()
+            .unwrap();
+        assert_eq!(names, vec!["Alice", "Charlie"]);
+
+        let count: i32 = lua
+            .load(
+                r#"
+            local records = { { name = "Alice", dept = "A" } }
+            return #lib.find_all(records, "dept", "Z")
+        "#,
+            )
+            .eval()
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
     #[test]
     fn test_filter() {
         let (lua, _) = create_test_lua();
@@ -578,6 +1384,132 @@ mod tests {
         assert_eq!(count, 2);
     }
 
+    #[test]
+    fn test_chunk_uneven() {
+        let (lua, _) = create_test_lua();
+
+        let sizes: Vec<i64> = lua
+            .load(
+                r#"
+            local chunks = lib.chunk({ 1, 2, 3, 4, 5, 6, 7 }, 3)
+            local sizes = {}
+            for i, c in ipairs(chunks) do sizes[i] = #c end
+            return sizes
+        "#,
+            )
+            .eval()
+            .unwrap();
+        assert_eq!(sizes, vec![3, 3, 1]);
+
+        let last: i64 = lua
+            .load("local c = lib.chunk({ 1, 2, 3, 4, 5, 6, 7 }, 3) return c[3][1]")
+            .eval()
+            .unwrap();
+        assert_eq!(last, 7);
+
+        let result: mlua::Result<Table> = lua.load("return lib.chunk({ 1, 2 }, 0)").eval();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_partition() {
+        let (lua, _) = create_test_lua();
+
+        let (evens, odds): (Vec<i64>, Vec<i64>) = lua
+            .load(
+                r#"
+            return lib.partition({ 1, 2, 3, 4, 5, 6 }, function(n) return n % 2 == 0 end)
+        "#,
+            )
+            .eval()
+            .unwrap();
+
+        assert_eq!(evens, vec![2, 4, 6]);
+        assert_eq!(odds, vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn test_unique() {
+        let (lua, _) = create_test_lua();
+
+        let result: Vec<String> = lua
+            .load(r#"return lib.unique({ "b", "a", "b", "c", "a" })"#)
+            .eval()
+            .unwrap();
+        assert_eq!(result, vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_deep_equal() {
+        let (lua, _) = create_test_lua();
+
+        let equal: bool = lua
+            .load(
+                r#"
+            local a = { name = "Acme", revenue = 10, tags = { "x", "y" }, owner = { id = 1 } }
+            local b = { name = "Acme", revenue = 10.0, tags = { "x", "y" }, owner = { id = 1 } }
+            return lib.deep_equal(a, b)
+        "#,
+            )
+            .eval()
+            .unwrap();
+        assert!(equal);
+
+        let nested_differs: bool = lua
+            .load("return lib.deep_equal({ owner = { id = 1 } }, { owner = { id = 2 } })")
+            .eval()
+            .unwrap();
+        assert!(!nested_differs);
+
+        let extra_key: bool = lua
+            .load("return lib.deep_equal({ a = 1 }, { a = 1, b = 2 })")
+            .eval()
+            .unwrap();
+        assert!(!extra_key);
+
+        let missing_key: bool = lua
+            .load("return lib.deep_equal({ a = 1, b = 2 }, { a = 1 })")
+            .eval()
+            .unwrap();
+        assert!(!missing_key);
+
+        let cyclic: bool = lua
+            .load(
+                r#"
+            local a = { id = 1 }
+            a.self = a
+            local b = { id = 1 }
+            b.self = b
+            return lib.deep_equal(a, b)
+        "#,
+            )
+            .eval()
+            .unwrap();
+        assert!(cyclic);
+    }
+
+    #[test]
+    fn test_distinct_by() {
+        let (lua, _) = create_test_lua();
+
+        let result: Vec<String> = lua
+            .load(
+                r#"
+            local records = {
+                { name = "Alice", city = "Ghent" },
+                { name = "Bob", city = "Antwerp" },
+                { name = "Charlie", city = "Ghent" },
+                { name = "Dana", city = "Brussels" },
+                { name = "Eve", city = "Antwerp" }
+            }
+            return lib.map(lib.distinct_by(records, "city"), function(r) return r.name end)
+        "#,
+            )
+            .eval()
+            .unwrap();
+        assert_eq!(result, vec!["Alice", "Bob", "Dana"]);
+    }
+
     #[test]
     fn test_map() {
         let (lua, _) = create_test_lua();
@@ -621,6 +1553,101 @@ mod tests {
         assert_eq!(count_a, 2);
     }
 
+    #[test]
+    fn test_count_by() {
+        let (lua, _) = create_test_lua();
+
+        let (a, b, c, missing): (i64, i64, i64, Option<i64>) = lua
+            .load(
+                r#"
+            local records = {
+                { name = "Alice", dept = "A" },
+                { name = "Bob", dept = "B" },
+                { name = "Charlie", dept = "A" },
+                { name = "Dana", dept = "C" },
+                { name = "Eve", dept = "A" }
+            }
+            local counts = lib.count_by(records, "dept")
+            return counts["A"], counts["B"], counts["C"], counts["D"]
+        "#,
+            )
+            .eval()
+            .unwrap();
+
+        assert_eq!((a, b, c), (3, 1, 1));
+        assert!(missing.is_none());
+    }
+
+    #[test]
+    fn test_hash_ignores_key_order() {
+        let (lua, _) = create_test_lua();
+
+        let (a, b, c): (String, String, String) = lua
+            .load(
+                r#"
+            local a = {}
+            a.name = "Acme"
+            a.revenue = 10
+            a.tags = { "x", "y" }
+            local b = {}
+            b.tags = { "x", "y" }
+            b.revenue = 10.0
+            b.name = "Acme"
+            local c = { name = "Acme", revenue = 11, tags = { "x", "y" } }
+            return lib.hash(a), lib.hash(b), lib.hash(c)
+        "#,
+            )
+            .eval()
+            .unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.len(), 64);
+
+        let short: String = lua.load("return lib.short_hash('abc')").eval().unwrap();
+        assert_eq!(short, "6cc43f85");
+
+        // Scalars of different types don't collide
+        let (one, one_str, yes, yes_str): (String, String, String, String) = lua
+            .load(r#"return lib.hash(1), lib.hash("1"), lib.hash(true), lib.hash("true")"#)
+            .eval()
+            .unwrap();
+        assert_ne!(one, one_str);
+        assert_ne!(yes, yes_str);
+
+        // Nor do table keys of different types
+        let (int_key, str_key): (String, String) = lua
+            .load(r#"return lib.hash({ [1] = "a", [3] = "b" }), lib.hash({ ["1"] = "a", [3] = "b" })"#)
+            .eval()
+            .unwrap();
+        assert_ne!(int_key, str_key);
+    }
+
+    #[test]
+    fn test_debug_dump() {
+        let (lua, _) = create_test_lua();
+
+        let dump: String = lua
+            .load(
+                r#"
+            local record = { name = "Acme", revenue = 10, owner = { id = 1, tags = { "a" } } }
+            record.owner.parent = record
+            return lib.debug_dump(record)
+        "#,
+            )
+            .eval()
+            .unwrap();
+
+        assert!(dump.lines().count() > 1);
+        assert!(dump.contains("name = \"Acme\""));
+        assert!(dump.contains("revenue = 10"));
+        assert!(dump.contains("    id = 1"));
+        assert!(dump.contains("[1] = \"a\""));
+        assert!(dump.contains("parent = <cycle>"));
+
+        let scalar: String = lua.load("return lib.debug_dump(nil)").eval().unwrap();
+        assert_eq!(scalar, "nil");
+    }
+
     #[test]
     fn test_logging() {
         let (lua, context) = create_test_lua();
@@ -640,6 +1667,27 @@ mod tests {
         assert!(matches!(&ctx.logs[1], LogMessage::Warn(s) if s == "Warning message"));
     }
 
+    #[test]
+    fn test_min_log_level_drops_debug() {
+        let (lua, context) = create_test_lua();
+        context.lock().unwrap().min_level = LogLevel::Info;
+
+        lua.load(
+            r#"
+            lib.debug("Debug message")
+            lib.log("Info message")
+            lib.error_log("Error message")
+        "#,
+        )
+        .exec()
+        .unwrap();
+
+        let ctx = context.lock().unwrap();
+        assert_eq!(ctx.logs.len(), 2);
+        assert!(matches!(&ctx.logs[0], LogMessage::Info(s) if s == "Info message"));
+        assert!(matches!(&ctx.logs[1], LogMessage::Error(s) if s == "Error message"));
+    }
+
     #[test]
     fn test_status() {
         let (lua, context) = create_test_lua();
@@ -656,14 +1704,27 @@ mod tests {
 
         lua.load(r#"lib.progress(50, 100)"#).exec().unwrap();
 
-        let ctx = context.lock().unwrap();
         assert!(matches!(
-            &ctx.status,
+            &context.lock().unwrap().status,
             Some(StatusUpdate::Progress {
                 current: 50,
-                total: 100
+                total: 100,
+                label: None,
             })
         ));
+
+        lua.load(r#"lib.progress(340, 1200, "Processing account")"#)
+            .exec()
+            .unwrap();
+
+        assert!(matches!(
+            &context.lock().unwrap().status,
+            Some(StatusUpdate::Progress {
+                current: 340,
+                total: 1200,
+                label: Some(l),
+            }) if l == "Processing account"
+        ));
     }
 
     #[test]
@@ -675,4 +1736,95 @@ mod tests {
         assert!(now.contains("T"));
         assert!(now.ends_with("Z"));
     }
+
+    #[test]
+    fn test_format_date_with_offset() {
+        let (lua, _) = create_test_lua();
+
+        let formatted: String = lua
+            .load(r#"return lib.format_date("2024-03-10T08:15:00+05:30", "%Y-%m-%d %H:%M")"#)
+            .eval()
+            .unwrap();
+        assert_eq!(formatted, "2024-03-10 02:45");
+
+        let formatted: String = lua
+            .load(r#"return lib.format_date("2024-03-10T08:15:00Z", "%H:%M")"#)
+            .eval()
+            .unwrap();
+        assert_eq!(formatted, "08:15");
+
+        let invalid: Option<String> = lua
+            .load(r#"return lib.format_date("not a date", "%Y")"#)
+            .eval()
+            .unwrap();
+        assert!(invalid.is_none());
+    }
+
+    #[test]
+    fn test_context_reflects_execution_context() {
+        let (lua, context) = create_test_lua();
+        context.lock().unwrap().info = TransformInfo {
+            config_name: "accounts".to_string(),
+            source_env: "dev".to_string(),
+            target_env: "prod".to_string(),
+        };
+
+        let (config, source, target): (String, String, String) = lua
+            .load(
+                r#"
+            local ctx = lib.context()
+            return ctx.config_name, ctx.source_env, ctx.target_env
+        "#,
+            )
+            .eval()
+            .unwrap();
+        assert_eq!(
+            (config.as_str(), source.as_str(), target.as_str()),
+            ("accounts", "dev", "prod")
+        );
+
+        // Writes and metatable swaps are rejected
+        let result: mlua::Result<()> = lua.load(r#"lib.context().target_env = "dev""#).exec();
+        assert!(result.is_err());
+        let result: mlua::Result<()> = lua.load("setmetatable(lib.context(), {})").exec();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_now_epoch() {
+        let (lua, _) = create_test_lua();
+
+        let epoch: Value = lua.load("return lib.now_epoch()").eval().unwrap();
+        let Value::Integer(secs) = epoch else {
+            panic!("expected an integer, got {:?}", epoch);
+        };
+        assert!(secs > 0);
+    }
+
+    #[test]
+    fn test_now_local() {
+        use chrono::TimeZone;
+
+        let instant = chrono::Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        assert_eq!(
+            format_in_timezone(instant, "Asia/Kolkata"),
+            "2024-01-15T17:30:00+05:30"
+        );
+        assert_eq!(
+            format_in_timezone(instant, "Europe/Brussels"),
+            "2024-01-15T13:00:00+01:00"
+        );
+        // Unknown zones fall back to UTC
+        assert_eq!(
+            format_in_timezone(instant, "Mars/Olympus"),
+            "2024-01-15T12:00:00+00:00"
+        );
+
+        let (lua, _) = create_test_lua();
+        let now: String = lua
+            .load(r#"return lib.now_local("Asia/Kolkata")"#)
+            .eval()
+            .unwrap();
+        assert!(now.ends_with("+05:30"));
+    }
 }
@@ -54,6 +54,16 @@ pub enum OperationType {
 }
 
 impl OperationType {
+    /// All operation types, in display order
+    pub const ALL: [OperationType; 6] = [
+        OperationType::Create,
+        OperationType::Update,
+        OperationType::Delete,
+        OperationType::Deactivate,
+        OperationType::Skip,
+        OperationType::Error,
+    ];
+
     /// Get display label
     pub fn label(&self) -> &'static str {
         match self {
@@ -178,6 +188,18 @@ impl LuaOperation {
         }
     }
 
+    /// Check that the operation targets a record: an id that is present and not the nil UUID
+    fn require_id(&self) -> Result<(), String> {
+        match self.id {
+            None => Err(format!("{} operation requires id", self.operation.label())),
+            Some(id) if id.is_nil() => Err(format!(
+                "{} operation requires a non-empty id (got the nil UUID)",
+                self.operation.label()
+            )),
+            Some(_) => Ok(()),
+        }
+    }
+
     /// Validate that the operation has required fields
     pub fn validate(&self) -> Result<(), String> {
         match self.operation {
@@ -187,17 +209,13 @@ impl LuaOperation {
                 }
             }
             OperationType::Update => {
-                if self.id.is_none() {
-                    return Err("Update operation requires id".to_string());
-                }
+                self.require_id()?;
                 if self.fields.is_empty() {
                     return Err("Update operation requires fields".to_string());
                 }
             }
             OperationType::Delete | OperationType::Deactivate => {
-                if self.id.is_none() {
-                    return Err(format!("{} operation requires id", self.operation.label()));
-                }
+                self.require_id()?;
             }
             OperationType::Skip | OperationType::Error => {
                 // No required fields
@@ -207,6 +225,29 @@ impl LuaOperation {
     }
 }
 
+/// Sort operations by entity, operation type (in `OperationType::ALL` order) and id
+///
+/// Operations sharing those keys (e.g. creates without an id) are ordered by
+/// their field values, so Lua table iteration order never leaks into the result.
+pub fn sort_operations(operations: &mut [LuaOperation]) {
+    operations.sort_by_cached_key(|op| {
+        let rank = OperationType::ALL
+            .iter()
+            .position(|t| *t == op.operation)
+            .unwrap_or(usize::MAX);
+        let fields: std::collections::BTreeMap<&String, &serde_json::Value> =
+            op.fields.iter().collect();
+        (
+            op.entity.clone(),
+            rank,
+            op.id,
+            serde_json::to_string(&fields).unwrap_or_default(),
+            op.reason.clone(),
+            op.error.clone(),
+        )
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,6 +3,7 @@
 //! Validates script syntax, structure, and declaration contents.
 
 use anyhow::Result;
+use std::path::Path;
 
 use super::runtime::LuaRuntime;
 use super::types::Declaration;
@@ -64,6 +65,8 @@ pub struct ValidationError {
     pub line: Option<usize>,
     /// Column number (if available)
     pub column: Option<usize>,
+    /// Unprocessed error text (e.g. the full mlua message with traceback)
+    pub raw: Option<String>,
 }
 
 impl ValidationError {
@@ -72,6 +75,7 @@ impl ValidationError {
             message: message.into(),
             line: None,
             column: None,
+            raw: None,
         }
     }
 
@@ -85,6 +89,11 @@ impl ValidationError {
         self.line = Some(line);
         self
     }
+
+    pub fn with_raw(mut self, raw: impl Into<String>) -> Self {
+        self.raw = Some(raw.into());
+        self
+    }
 }
 
 impl std::fmt::Display for ValidationError {
@@ -102,14 +111,16 @@ impl std::fmt::Display for ValidationError {
 }
 
 /// Validate a Lua transform script
-pub fn validate_script(script: &str) -> ValidationResult {
+///
+/// `module_dir` is where the script's `require` calls load shared modules from.
+pub fn validate_script(script: &str, module_dir: Option<&Path>) -> ValidationResult {
     // Check for empty script
     if script.trim().is_empty() {
         return ValidationResult::error("Script is empty");
     }
 
     // Create runtime and load script
-    let runtime = match LuaRuntime::new() {
+    let runtime = match LuaRuntime::with_module_dir(module_dir) {
         Ok(r) => r,
         Err(e) => return ValidationResult::error(format!("Failed to create Lua runtime: {}", e)),
     };
@@ -118,7 +129,7 @@ pub fn validate_script(script: &str) -> ValidationResult {
     let module = match runtime.load_script(script) {
         Ok(m) => m,
         Err(e) => {
-            let error = parse_lua_error(&e.to_string());
+            let error = parse_lua_error(&format!("{:#}", e));
             return ValidationResult {
                 is_valid: false,
                 declaration: None,
@@ -132,7 +143,7 @@ pub fn validate_script(script: &str) -> ValidationResult {
     let declaration = match runtime.run_declare(&module) {
         Ok(d) => d,
         Err(e) => {
-            let error = parse_lua_error(&e.to_string());
+            let error = parse_lua_error(&format!("{:#}", e));
             return ValidationResult {
                 is_valid: false,
                 declaration: None,
@@ -194,23 +205,25 @@ pub fn validate_script(script: &str) -> ValidationResult {
 }
 
 /// Parse Lua error message to extract line numbers
+///
+/// Lua errors look like `syntax error: [string "..."]:5: message`, where the
+/// chunk name inside `[string "..."]` may itself contain `file:line:col`, so
+/// the line is taken from after the closing `"]`. The returned message drops
+/// the chunk prefix and any traceback; the full text is kept in `raw`.
 fn parse_lua_error(error: &str) -> ValidationError {
-    // Lua errors often look like: "[string \"...\"]:5: error message"
-    // or "runtime error: [string \"...\"]:5: error message"
+    let chunk_regex = regex::Regex::new(r#"\[string "[^"]*"\]:(\d+):\s*"#).unwrap();
 
-    let line_regex = regex::Regex::new(r":(\d+):").ok();
+    let first_line = error.lines().next().unwrap_or(error);
+    let Some(captures) = chunk_regex.captures(first_line) else {
+        return ValidationError::new(first_line.to_string()).with_raw(error);
+    };
 
-    if let Some(re) = line_regex {
-        if let Some(captures) = re.captures(error) {
-            if let Some(line_match) = captures.get(1) {
-                if let Ok(line) = line_match.as_str().parse::<usize>() {
-                    return ValidationError::new(error.to_string()).with_line(line);
-                }
-            }
-        }
+    let message = chunk_regex.replace(first_line, "").to_string();
+    let parsed = ValidationError::new(message).with_raw(error);
+    match captures[1].parse::<usize>() {
+        Ok(line) => parsed.with_line(line),
+        Err(_) => parsed,
     }
-
-    ValidationError::new(error.to_string())
 }
 
 /// Basic validation of OData filter syntax
@@ -284,15 +297,15 @@ fn validate_odata_filter(filter: &str) -> Result<(), String> {
 
 /// Validate that a script can execute without data
 /// (dry run to catch runtime errors in the script structure)
-pub fn validate_script_execution(script: &str) -> ValidationResult {
-    let basic_result = validate_script(script);
+pub fn validate_script_execution(script: &str, module_dir: Option<&Path>) -> ValidationResult {
+    let basic_result = validate_script(script, module_dir);
 
     if !basic_result.is_valid {
         return basic_result;
     }
 
     // Try executing transform with empty data
-    let runtime = match LuaRuntime::new() {
+    let runtime = match LuaRuntime::with_module_dir(module_dir) {
         Ok(r) => r,
         Err(e) => return ValidationResult::error(format!("Failed to create Lua runtime: {}", e)),
     };
@@ -323,7 +336,7 @@ mod tests {
 
     #[test]
     fn test_validate_empty_script() {
-        let result = validate_script("");
+        let result = validate_script("", None);
         assert!(!result.is_valid);
         assert!(!result.errors.is_empty());
     }
@@ -337,7 +350,7 @@ mod tests {
             return M
         "#;
 
-        let result = validate_script(script);
+        let result = validate_script(script, None);
         assert!(result.is_valid);
         assert!(result.errors.is_empty());
     }
@@ -351,11 +364,35 @@ mod tests {
             return M
         "#;
 
-        let result = validate_script(script);
+        let result = validate_script(script, None);
         assert!(!result.is_valid);
         assert!(!result.errors.is_empty());
     }
 
+    #[test]
+    fn test_syntax_error_reports_script_line() {
+        let script = "local M = {}\nlocal unused = 1\nfunction M.declare() return { source = {} target = {} } end\nreturn M\n";
+
+        let result = validate_script(script, None);
+
+        assert!(!result.is_valid);
+        let error = &result.errors[0];
+        assert_eq!(error.line, Some(3));
+        assert!(error.message.contains("'}' expected near 'target'"));
+        assert!(!error.message.contains("[string"));
+        assert!(error.raw.as_deref().unwrap().contains("[string"));
+    }
+
+    #[test]
+    fn test_runtime_error_in_declare_reports_script_line() {
+        let script = "local M = {}\nfunction M.declare()\n  local t = nil\n  return t.x\nend\nfunction M.transform() return {} end\nreturn M\n";
+
+        let result = validate_script(script, None);
+
+        assert_eq!(result.errors[0].line, Some(4));
+        assert!(!result.errors[0].message.contains("stack traceback"));
+    }
+
     #[test]
     fn test_validate_script_missing_declare() {
         let script = r#"
@@ -364,7 +401,7 @@ mod tests {
             return M
         "#;
 
-        let result = validate_script(script);
+        let result = validate_script(script, None);
         assert!(!result.is_valid);
     }
 
@@ -376,7 +413,7 @@ mod tests {
             return M
         "#;
 
-        let result = validate_script(script);
+        let result = validate_script(script, None);
         assert!(!result.is_valid);
     }
 
@@ -399,7 +436,7 @@ mod tests {
             return M
         "#;
 
-        let result = validate_script(script);
+        let result = validate_script(script, None);
         assert!(result.is_valid);
     }
 
@@ -422,7 +459,7 @@ mod tests {
             return M
         "#;
 
-        let result = validate_script(script);
+        let result = validate_script(script, None);
         // Should have error about invalid filter
         assert!(!result.is_valid || !result.errors.is_empty());
     }
@@ -461,7 +498,7 @@ mod tests {
             return M
         "#;
 
-        let result = validate_script(script);
+        let result = validate_script(script, None);
         assert!(result.is_valid);
         assert!(!result.warnings.is_empty());
     }
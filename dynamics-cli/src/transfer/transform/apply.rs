@@ -3,9 +3,11 @@
 use chrono::Utc;
 use uuid::Uuid;
 
-use crate::transfer::{Condition, DynamicValue, Fallback, ResolverContext, Transform, Value};
+use crate::transfer::{
+    AggregateFunction, Condition, DynamicValue, Fallback, ResolverContext, Transform, Value,
+};
 
-use super::path::resolve_path;
+use super::path::{resolve_path, resolve_path_values};
 
 /// Result of applying a transform
 pub type TransformResult = Result<Value, String>;
@@ -180,6 +182,81 @@ pub fn apply_transform(
                 )),
             }
         }
+
+        Transform::Aggregate {
+            source_path,
+            function,
+            separator,
+        } => {
+            let values: Vec<Value> = resolve_path_values(record, source_path)
+                .into_iter()
+                .filter(|v| !v.is_null())
+                .collect();
+            aggregate_values(*function, &values, separator.as_deref().unwrap_or(", "))
+        }
+    }
+}
+
+/// Combine the non-null child values of an aggregate transform
+fn aggregate_values(
+    function: AggregateFunction,
+    values: &[Value],
+    separator: &str,
+) -> TransformResult {
+    match function {
+        AggregateFunction::Count => Ok(Value::Int(values.len() as i64)),
+        AggregateFunction::Sum => {
+            // Stay integral unless a fractional value is involved
+            if values.iter().all(|v| matches!(v, Value::Int(_))) {
+                return Ok(Value::Int(values.iter().filter_map(Value::as_int).sum()));
+            }
+            let mut total = 0.0;
+            for value in values {
+                total += value.as_float().ok_or_else(|| {
+                    format!("Aggregate sum requires numeric values, got: {}", value)
+                })?;
+            }
+            Ok(Value::Float(total))
+        }
+        AggregateFunction::Concat => {
+            if values.is_empty() {
+                return Ok(Value::Null);
+            }
+            let parts: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+            Ok(Value::String(parts.join(separator)))
+        }
+        AggregateFunction::Min | AggregateFunction::Max => {
+            let mut best: Option<&Value> = None;
+            for value in values {
+                let Some(current) = best else {
+                    best = Some(value);
+                    continue;
+                };
+                let ordering = compare_values(value, current).ok_or_else(|| {
+                    format!(
+                        "Aggregate {} cannot compare {} and {}",
+                        function, current, value
+                    )
+                })?;
+                let better = match function {
+                    AggregateFunction::Min => ordering == std::cmp::Ordering::Less,
+                    _ => ordering == std::cmp::Ordering::Greater,
+                };
+                if better {
+                    best = Some(value);
+                }
+            }
+            Ok(best.cloned().unwrap_or(Value::Null))
+        }
+    }
+}
+
+/// Order two values of the same kind (numbers, strings or dates)
+fn compare_values(a: &Value, b: &Value) -> Option<std::cmp::Ordering> {
+    match (a, b) {
+        (Value::String(x), Value::String(y)) => Some(x.cmp(y)),
+        (Value::DateTime(x), Value::DateTime(y)) => Some(x.cmp(y)),
+        _ => a.as_float()?.partial_cmp(&b.as_float()?),
     }
 }
 
@@ -478,4 +555,57 @@ mod tests {
         let result = apply_transform(&transform, &record, None).unwrap();
         assert_eq!(result, Value::String("Hi World".into()));
     }
+
+    #[test]
+    fn test_apply_aggregate_functions() {
+        let record = json!({
+            "lines": [
+                {"amount": 10.5, "sku": "B-2"},
+                {"amount": 4, "sku": "A-1"},
+                {"amount": null, "sku": "C-3"}
+            ]
+        });
+        let aggregate = |path: &str, function: AggregateFunction| {
+            let transform = Transform::aggregate(path, function).unwrap();
+            apply_transform(&transform, &record, None).unwrap()
+        };
+
+        assert_eq!(
+            aggregate("lines.amount", AggregateFunction::Sum),
+            Value::Float(14.5)
+        );
+        assert_eq!(
+            aggregate("lines.amount", AggregateFunction::Count),
+            Value::Int(2)
+        );
+        assert_eq!(
+            aggregate("lines.sku", AggregateFunction::Concat),
+            Value::String("B-2, A-1, C-3".into())
+        );
+        assert_eq!(
+            aggregate("lines.amount", AggregateFunction::Min),
+            Value::Int(4)
+        );
+        assert_eq!(
+            aggregate("lines.sku", AggregateFunction::Max),
+            Value::String("C-3".into())
+        );
+        // Empty collection: sum is zero, min has nothing to pick
+        assert_eq!(
+            aggregate("missing.amount", AggregateFunction::Sum),
+            Value::Int(0)
+        );
+        assert_eq!(
+            aggregate("missing.amount", AggregateFunction::Min),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn test_apply_aggregate_sum_rejects_text() {
+        let record = json!({"lines": [{"amount": 1}, {"amount": "n/a"}]});
+        let transform = Transform::aggregate("lines.amount", AggregateFunction::Sum).unwrap();
+
+        assert!(apply_transform(&transform, &record, None).is_err());
+    }
 }
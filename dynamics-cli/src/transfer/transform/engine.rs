@@ -1,6 +1,7 @@
 //! Transform engine - orchestrates applying transforms to source records
 
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use uuid::Uuid;
 
 use crate::transfer::{
@@ -41,15 +42,23 @@ impl TransformEngine {
     ///
     /// `source_data` is a map of entity name -> records
     /// `target_data` is a map of entity name -> records (for comparison)
+    /// `lua_module_dir` is where Lua mode scripts `require` shared modules from
     pub fn transform_all(
         config: &TransferConfig,
         source_data: &HashMap<String, Vec<serde_json::Value>>,
         target_data: &HashMap<String, Vec<serde_json::Value>>,
         primary_keys: &HashMap<String, String>,
+        lua_module_dir: Option<&Path>,
     ) -> ResolvedTransfer {
         // For Lua mode, use the Lua transform engine
         if config.mode == TransferMode::Lua {
-            return Self::transform_all_lua(config, source_data, target_data, primary_keys);
+            return Self::transform_all_lua(
+                config,
+                source_data,
+                target_data,
+                primary_keys,
+                lua_module_dir,
+            );
         }
 
         let mut resolved =
@@ -377,7 +386,7 @@ impl TransformEngine {
 
     /// Identify which fields differ between resolved and target record
     /// Returns empty set if all fields match
-    fn identify_changed_fields(
+    pub fn identify_changed_fields(
         resolved: &HashMap<String, Value>,
         target: &serde_json::Value,
         field_names: &[String],
@@ -450,6 +459,7 @@ impl TransformEngine {
         source_data: &HashMap<String, Vec<serde_json::Value>>,
         target_data: &HashMap<String, Vec<serde_json::Value>>,
         primary_keys: &HashMap<String, String>,
+        lua_module_dir: Option<&Path>,
     ) -> ResolvedTransfer {
         use crate::transfer::lua::{LuaRuntime, execute_transform_sync};
 
@@ -469,13 +479,14 @@ impl TransformEngine {
         let target_json = serde_json::to_value(target_data).unwrap_or_default();
 
         // Execute the Lua transform
-        let operations = match execute_transform_sync(script, &source_json, &target_json) {
-            Ok(ops) => ops,
-            Err(e) => {
-                log::error!("Lua transform failed: {}", e);
-                return resolved;
-            }
-        };
+        let operations =
+            match execute_transform_sync(script, &source_json, &target_json, lua_module_dir) {
+                Ok(ops) => ops,
+                Err(e) => {
+                    log::error!("Lua transform failed: {}", e);
+                    return resolved;
+                }
+            };
 
         // Convert Lua operations to resolved entities/records
         Self::lua_operations_to_resolved(&mut resolved, operations, primary_keys);
@@ -619,6 +630,146 @@ mod tests {
         assert_eq!(result.get_field("was_migrated"), Some(&Value::Bool(true)));
     }
 
+    #[test]
+    fn test_transform_record_sums_child_amounts() {
+        let source = json!({
+            "accountid": "a1b2c3d4-e5f6-7890-abcd-ef1234567890",
+            "name": "Contoso",
+            "opportunity_customer_accounts": [
+                {"estimatedvalue": 1500.25},
+                {"estimatedvalue": 500},
+                {"estimatedvalue": null}
+            ]
+        });
+
+        let mappings = vec![FieldMapping::new(
+            "nrq_pipelinetotal",
+            Transform::aggregate(
+                "opportunity_customer_accounts.estimatedvalue",
+                crate::transfer::AggregateFunction::Sum,
+            )
+            .unwrap(),
+        )];
+
+        // The collection is fetched through $expand only, never $select
+        assert!(mappings[0].transform.source_fields().is_empty());
+        let mut tree = crate::transfer::ExpandTree::new();
+        tree.add_transform(&mappings[0].transform);
+        assert_eq!(
+            tree.build_expand_clauses(None, None),
+            vec!["opportunity_customer_accounts($select=estimatedvalue)"]
+        );
+
+        let target_index = HashMap::new();
+        let field_names = vec!["nrq_pipelinetotal".to_string()];
+
+        let result = TransformEngine::transform_record(
+            &source,
+            &mappings,
+            &target_index,
+            &field_names,
+            &make_ctx(),
+            &empty_resolver_ctx(),
+        );
+
+        assert!(result.is_create());
+        assert_eq!(
+            result.get_field("nrq_pipelinetotal"),
+            Some(&Value::Float(2000.25))
+        );
+    }
+
+    fn two_hop_mappings() -> Vec<FieldMapping> {
+        use crate::transfer::transform::format::NullHandling;
+
+        vec![
+            FieldMapping::new(
+                "nrq_contactemail",
+                Transform::copy("parentaccountid.primarycontactid.emailaddress1").unwrap(),
+            ),
+            FieldMapping::new(
+                "description",
+                Transform::format_with_null_handling(
+                    "Contact: ${parentaccountid.primarycontactid.emailaddress1}",
+                    NullHandling::Empty,
+                )
+                .unwrap(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_transform_record_resolves_two_hop_lookup() {
+        let source = json!({
+            "accountid": "a1b2c3d4-e5f6-7890-abcd-ef1234567890",
+            "parentaccountid": {
+                "name": "Parent Co",
+                "primarycontactid": {
+                    "emailaddress1": "jane@parent.example"
+                }
+            }
+        });
+        let mappings = two_hop_mappings();
+
+        // The expand clause reaches the same depth the transform reads
+        let mut tree = crate::transfer::ExpandTree::new();
+        for mapping in &mappings {
+            for path in mapping.transform.lookup_paths() {
+                tree.add_path(path);
+            }
+        }
+        let clauses = tree.build_expand_clauses(None, None);
+        assert_eq!(clauses.len(), 1);
+        assert!(clauses[0].contains("$expand=primarycontactid($select=emailaddress1)"));
+
+        let field_names = vec!["nrq_contactemail".to_string(), "description".to_string()];
+        let result = TransformEngine::transform_record(
+            &source,
+            &mappings,
+            &HashMap::new(),
+            &field_names,
+            &make_ctx(),
+            &empty_resolver_ctx(),
+        );
+
+        assert_eq!(
+            result.get_field("nrq_contactemail"),
+            Some(&Value::String("jane@parent.example".into()))
+        );
+        assert_eq!(
+            result.get_field("description"),
+            Some(&Value::String("Contact: jane@parent.example".into()))
+        );
+    }
+
+    #[test]
+    fn test_transform_record_null_middle_hop_short_circuits() {
+        let source = json!({
+            "accountid": "a1b2c3d4-e5f6-7890-abcd-ef1234567890",
+            "parentaccountid": {
+                "name": "Parent Co",
+                "primarycontactid": null
+            }
+        });
+
+        let field_names = vec!["nrq_contactemail".to_string(), "description".to_string()];
+        let result = TransformEngine::transform_record(
+            &source,
+            &two_hop_mappings(),
+            &HashMap::new(),
+            &field_names,
+            &make_ctx(),
+            &empty_resolver_ctx(),
+        );
+
+        assert!(result.is_create());
+        assert_eq!(result.get_field("nrq_contactemail"), Some(&Value::Null));
+        assert_eq!(
+            result.get_field("description"),
+            Some(&Value::String("Contact: ".into()))
+        );
+    }
+
     #[test]
     fn test_transform_record_nochange_when_target_matches() {
         let source = json!({
@@ -749,6 +900,7 @@ mod tests {
             target_entity: "account".to_string(),
             priority: 1,
             operation_filter: OperationFilter::default(),
+            cache_ttl_minutes: None,
             field_mappings: vec![FieldMapping::new(
                 "name",
                 Transform::Copy {
@@ -791,6 +943,7 @@ mod tests {
                 target_entity: "account".to_string(),
                 priority: 1,
                 operation_filter: OperationFilter::default(),
+                cache_ttl_minutes: None,
                 field_mappings: vec![FieldMapping::new(
                     "name",
                     Transform::Copy {
@@ -815,8 +968,13 @@ mod tests {
         let mut primary_keys = HashMap::new();
         primary_keys.insert("account".to_string(), "accountid".to_string());
 
-        let result =
-            TransformEngine::transform_all(&config, &source_data, &target_data, &primary_keys);
+        let result = TransformEngine::transform_all(
+            &config,
+            &source_data,
+            &target_data,
+            &primary_keys,
+            None,
+        );
 
         assert_eq!(result.config_name, "test-migration");
         assert_eq!(result.entities.len(), 1);
@@ -1280,6 +1438,7 @@ mod tests {
                 target_entity: "account".to_string(),
                 priority: 1,
                 operation_filter: OperationFilter::default(),
+                cache_ttl_minutes: None,
                 field_mappings: vec![
                     FieldMapping::new(
                         "name",
@@ -1330,8 +1489,13 @@ mod tests {
         primary_keys.insert("account".to_string(), "accountid".to_string());
         primary_keys.insert("contact".to_string(), "contactid".to_string());
 
-        let result =
-            TransformEngine::transform_all(&config, &source_data, &target_data, &primary_keys);
+        let result = TransformEngine::transform_all(
+            &config,
+            &source_data,
+            &target_data,
+            &primary_keys,
+            None,
+        );
 
         assert_eq!(result.config_name, "test-with-resolver");
         assert_eq!(result.entities.len(), 1);
@@ -62,9 +62,15 @@ impl ExpandTree {
             return;
         }
 
-        let segments = path.segments();
-        let lookup_segments = &segments[..segments.len() - 1];
-        let target_field = path.target_field();
+        // Wildcards mark collections and don't appear in the query
+        let segments: Vec<String> = path
+            .field_segments()
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let Some((target_field, lookup_segments)) = segments.split_last() else {
+            return;
+        };
 
         // Use recursive helper to navigate/create the tree structure
         Self::add_to_node_map(&mut self.nodes, lookup_segments, target_field);
@@ -231,6 +237,15 @@ mod tests {
         assert_eq!(clauses[0], "accountid($select=name)");
     }
 
+    #[test]
+    fn test_wildcard_segment_is_not_expanded() {
+        let mut tree = ExpandTree::new();
+        tree.add_path(&FieldPath::parse("lines.*.amount").unwrap());
+
+        let clauses = tree.build_expand_clauses(None, None);
+        assert_eq!(clauses, vec!["lines($select=amount)"]);
+    }
+
     #[test]
     fn test_single_lookup_multiple_fields() {
         let mut tree = ExpandTree::new();
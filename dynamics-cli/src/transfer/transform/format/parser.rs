@@ -578,13 +578,13 @@ impl<'a> Parser<'a> {
                 let name = name.clone();
                 self.advance()?;
 
-                // Check for lookup traversal (field.lookup)
-                if self.current == Token::Dot {
+                // Check for lookup traversal (field.lookup, field.lookup.lookup, ...)
+                let mut segments = vec![name];
+                while self.current == Token::Dot {
                     self.advance()?;
                     if let Token::Ident(lookup) = &self.current {
-                        let lookup = lookup.clone();
+                        segments.push(lookup.clone());
                         self.advance()?;
-                        return Ok(FormatExpr::Field(FieldPath::lookup(name, lookup)));
                     } else {
                         return Err(ParseError {
                             message: "expected field name after '.'".to_string(),
@@ -594,7 +594,13 @@ impl<'a> Parser<'a> {
                     }
                 }
 
-                Ok(FormatExpr::Field(FieldPath::simple(name)))
+                FieldPath::parse(&segments.join("."))
+                    .map(FormatExpr::Field)
+                    .map_err(|e| ParseError {
+                        message: e.to_string(),
+                        position: self.lexer.current_pos(),
+                        context: String::new(),
+                    })
             }
             _ => Err(ParseError {
                 message: format!("unexpected token: {:?}", self.current),
@@ -680,6 +686,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_multi_hop_lookup_field() {
+        let template = parse_template("${parentaccountid.primarycontactid.emailaddress1}").unwrap();
+        if let FormatPart::Expr(FormatExpr::Field(path)) = &template.parts[0] {
+            assert_eq!(
+                path.segments(),
+                &["parentaccountid", "primarycontactid", "emailaddress1"]
+            );
+        } else {
+            panic!("expected field expression");
+        }
+
+        // Paths are limited to FieldPath::MAX_SEGMENTS
+        assert!(parse_template("${a.b.c.d.e}").is_err());
+    }
+
     #[test]
     fn test_parse_lookup_field() {
         let template = parse_template("${accountid.name}").unwrap();
@@ -9,4 +9,4 @@ mod path;
 pub use apply::apply_transform;
 pub use engine::{TransformContext, TransformEngine, TransformError};
 pub use expand::ExpandTree;
-pub use path::resolve_path;
+pub use path::{resolve_path, resolve_path_values};
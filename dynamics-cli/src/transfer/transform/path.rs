@@ -50,6 +50,45 @@ pub fn resolve_path(record: &serde_json::Value, path: &FieldPath) -> Value {
     Value::from_json(current)
 }
 
+/// Resolve every value a path reaches, traversing collections along the way
+///
+/// Arrays met while navigating are fanned out, so "lines.amount" on
+/// `{"lines": [{"amount": 1}, {"amount": 2}]}` yields both amounts. A `*`
+/// segment marks a collection explicitly and is otherwise skipped. Missing
+/// fields contribute nothing.
+pub fn resolve_path_values(record: &serde_json::Value, path: &FieldPath) -> Vec<Value> {
+    let mut current: Vec<&serde_json::Value> = vec![record];
+
+    for segment in path.segments() {
+        if segment == FieldPath::WILDCARD {
+            continue;
+        }
+        current = current
+            .into_iter()
+            .flat_map(flatten_array)
+            .filter_map(|node| {
+                node.get(segment.as_str())
+                    .or_else(|| find_case_insensitive(node, segment))
+                    .or_else(|| node.get(format!("_{}_value", segment)))
+            })
+            .collect();
+    }
+
+    current
+        .into_iter()
+        .flat_map(flatten_array)
+        .map(Value::from_json)
+        .collect()
+}
+
+/// Yield the elements of an array, or the value itself if it isn't one
+fn flatten_array(value: &serde_json::Value) -> Vec<&serde_json::Value> {
+    match value.as_array() {
+        Some(items) => items.iter().collect(),
+        None => vec![value],
+    }
+}
+
 /// Find a key in a JSON object using case-insensitive matching
 fn find_case_insensitive<'a>(
     obj: &'a serde_json::Value,
@@ -263,4 +302,35 @@ mod tests {
         let value = resolve_path(&record, &path);
         assert_eq!(value, Value::String("Test Deadline".into()));
     }
+
+    #[test]
+    fn test_resolve_path_values_over_collection() {
+        let record = json!({
+            "name": "Order 1",
+            "lines": [
+                {"amount": 10, "product": {"name": "A"}},
+                {"amount": 5, "product": {"name": "B"}},
+                {"product": null}
+            ]
+        });
+
+        let path = FieldPath::parse("lines.amount").unwrap();
+        assert_eq!(
+            resolve_path_values(&record, &path),
+            vec![Value::Int(10), Value::Int(5)]
+        );
+
+        let path = FieldPath::parse("lines.*.product.name").unwrap();
+        assert_eq!(
+            resolve_path_values(&record, &path),
+            vec![Value::String("A".into()), Value::String("B".into())]
+        );
+
+        // A plain field resolves to a single value
+        let path = FieldPath::simple("name");
+        assert_eq!(
+            resolve_path_values(&record, &path),
+            vec![Value::String("Order 1".into())]
+        );
+    }
 }
@@ -300,6 +300,24 @@ impl TransferConfig {
             .find(|m| m.source_entity == source_entity)
     }
 
+    /// Cache TTL override for an entity, from the first mapping that reads or writes it
+    pub fn cache_ttl_override(&self, entity: &str) -> Option<u32> {
+        self.entity_mappings
+            .iter()
+            .filter(|m| m.source_entity == entity || m.target_entity == entity)
+            .find_map(|m| m.cache_ttl_minutes)
+    }
+
+    /// Target fields matched by resolvers that look up records of an entity
+    pub fn resolver_match_fields(&self, entity: &str) -> Vec<&str> {
+        self.entity_mappings
+            .iter()
+            .flat_map(|m| m.resolvers.iter())
+            .filter(|r| r.source_entity == entity)
+            .flat_map(|r| r.target_fields())
+            .collect()
+    }
+
     /// Find an entity mapping by source entity name (mutable)
     pub fn find_entity_mapping_mut(&mut self, source_entity: &str) -> Option<&mut EntityMapping> {
         self.entity_mappings
@@ -379,6 +397,9 @@ pub struct EntityMapping {
     /// Optional filter for target records - only matching records are considered during matching
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub target_filter: Option<SourceFilter>,
+    /// Cache TTL in minutes for this entity's records and metadata (None = global default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_ttl_minutes: Option<u32>,
     /// Resolvers for lookup field resolution (scoped to this entity)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub resolvers: Vec<Resolver>,
@@ -401,6 +422,7 @@ impl EntityMapping {
             operation_filter: OperationFilter::default(),
             source_filter: None,
             target_filter: None,
+            cache_ttl_minutes: None,
             resolvers: Vec::new(),
             field_mappings: Vec::new(),
         }
@@ -431,6 +453,31 @@ impl EntityMapping {
             .find(|m| m.target_field == target_field)
     }
 
+    /// Copy another mapping's field mappings and resolvers as unsaved entries
+    ///
+    /// Used when duplicating an entity mapping, so the copy shares no IDs
+    /// with the original.
+    pub fn copy_mappings_from(&mut self, other: &EntityMapping) {
+        self.field_mappings = other
+            .field_mappings
+            .iter()
+            .cloned()
+            .map(|mut mapping| {
+                mapping.id = None;
+                mapping
+            })
+            .collect();
+        self.resolvers = other
+            .resolvers
+            .iter()
+            .cloned()
+            .map(|mut resolver| {
+                resolver.id = None;
+                resolver
+            })
+            .collect();
+    }
+
     /// Get the number of field mappings
     pub fn field_count(&self) -> usize {
         self.field_mappings.len()
@@ -565,4 +612,55 @@ mod tests {
         assert_eq!(sorted[1].source_entity, "contact");
         assert_eq!(sorted[2].source_entity, "opportunity");
     }
+
+    #[test]
+    fn test_resolver_match_fields_for_entity() {
+        let mut config = TransferConfig::new("test", "dev", "prod");
+        let mut mapping = EntityMapping::same_entity("contact", 1);
+        mapping.resolvers.push(Resolver::compound(
+            "contact_by_name_and_birthdate",
+            "contact",
+            vec![("lastname", "lastname"), ("birthdate", "birthdate")],
+        ));
+        mapping.resolvers.push(Resolver::new(
+            "account_by_number",
+            "account",
+            "accountnumber",
+        ));
+        config.add_entity_mapping(mapping);
+
+        assert_eq!(
+            config.resolver_match_fields("contact"),
+            vec!["lastname", "birthdate"]
+        );
+        assert_eq!(
+            config.resolver_match_fields("account"),
+            vec!["accountnumber"]
+        );
+        assert!(config.resolver_match_fields("lead").is_empty());
+    }
+
+    #[test]
+    fn test_copied_mappings_are_unsaved_and_independent() {
+        let mut original = EntityMapping::same_entity("contact", 1);
+        original.id = Some(7);
+        let mut field_mapping = FieldMapping::copy("fullname");
+        field_mapping.id = Some(11);
+        original.add_field_mapping(field_mapping);
+
+        let mut copy = EntityMapping::same_entity("lead", 2);
+        copy.copy_mappings_from(&original);
+        assert_eq!(copy.field_count(), 1);
+        assert_eq!(copy.field_mappings[0].target_field, "fullname");
+        assert_eq!(copy.field_mappings[0].id, None);
+        assert_eq!(copy.id, None);
+
+        copy.field_mappings[0].transform =
+            Transform::constant(crate::transfer::Value::String("edited".to_string()));
+        assert_eq!(original.field_mappings[0].id, Some(11));
+        assert_eq!(
+            original.field_mappings[0].transform,
+            FieldMapping::copy("fullname").transform
+        );
+    }
 }
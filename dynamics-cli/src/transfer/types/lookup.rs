@@ -20,6 +20,9 @@ pub struct LookupBindingInfo {
 pub struct LookupBindingContext {
     /// Map: field_name -> binding info
     pub lookups: HashMap<String, LookupBindingInfo>,
+    /// Map: field_name -> reason the lookup could not be bound
+    /// Only populated by `from_field_metadata_partial`
+    pub unbound: HashMap<String, LookupBindingError>,
 }
 
 /// Error building lookup binding context
@@ -89,42 +92,79 @@ impl LookupBindingContext {
         let mut lookups = HashMap::new();
 
         for field in fields {
-            // Only process lookup fields
-            if !matches!(field.field_type, FieldType::Lookup) {
-                continue;
+            if let Some(info) = Self::bind_field(field, entity_set_map)? {
+                lookups.insert(field.logical_name.clone(), info);
             }
+        }
 
-            // Get target entity - must be single target
-            let target_entity = match &field.related_entity {
-                Some(target) => target,
-                None => continue, // No target means not a real lookup (skip)
-            };
+        Ok(LookupBindingContext {
+            lookups,
+            unbound: HashMap::new(),
+        })
+    }
 
-            // Check for schema name
-            let schema_name = field.schema_name.as_ref().ok_or_else(|| {
-                LookupBindingError::MissingSchemaName {
-                    field_name: field.logical_name.clone(),
-                }
-            })?;
+    /// Build lookup binding context, collecting unbindable lookups instead of failing
+    ///
+    /// Lookups that cannot be bound are recorded in `unbound` so only the
+    /// records that actually write them need to be rejected.
+    pub fn from_field_metadata_partial(
+        fields: &[FieldMetadata],
+        entity_set_map: &HashMap<String, String>,
+    ) -> Self {
+        let mut ctx = LookupBindingContext::default();
 
-            // Get entity set name for target
-            let target_entity_set = entity_set_map.get(target_entity).ok_or_else(|| {
-                LookupBindingError::MissingEntitySet {
-                    entity_name: target_entity.clone(),
+        for field in fields {
+            match Self::bind_field(field, entity_set_map) {
+                Ok(Some(info)) => {
+                    ctx.lookups.insert(field.logical_name.clone(), info);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    ctx.unbound.insert(field.logical_name.clone(), e);
                 }
-            })?;
+            }
+        }
 
-            lookups.insert(
-                field.logical_name.clone(),
-                LookupBindingInfo {
-                    field_name: field.logical_name.clone(),
-                    schema_name: schema_name.clone(),
-                    target_entity_set: target_entity_set.clone(),
-                },
-            );
+        ctx
+    }
+
+    /// Build binding info for a single field (None if it is not a lookup)
+    fn bind_field(
+        field: &FieldMetadata,
+        entity_set_map: &HashMap<String, String>,
+    ) -> Result<Option<LookupBindingInfo>, LookupBindingError> {
+        // Only process lookup fields
+        if !matches!(field.field_type, FieldType::Lookup) {
+            return Ok(None);
         }
 
-        Ok(LookupBindingContext { lookups })
+        // Get target entity - must be single target
+        let target_entity = match &field.related_entity {
+            Some(target) => target,
+            None => return Ok(None), // No target means not a real lookup (skip)
+        };
+
+        // Check for schema name
+        let schema_name =
+            field
+                .schema_name
+                .as_ref()
+                .ok_or_else(|| LookupBindingError::MissingSchemaName {
+                    field_name: field.logical_name.clone(),
+                })?;
+
+        // Get entity set name for target
+        let target_entity_set = entity_set_map.get(target_entity).ok_or_else(|| {
+            LookupBindingError::MissingEntitySet {
+                entity_name: target_entity.clone(),
+            }
+        })?;
+
+        Ok(Some(LookupBindingInfo {
+            field_name: field.logical_name.clone(),
+            schema_name: schema_name.clone(),
+            target_entity_set: target_entity_set.clone(),
+        }))
     }
 
     /// Check if a field is a lookup that needs binding
@@ -136,6 +176,11 @@ impl LookupBindingContext {
     pub fn get(&self, field_name: &str) -> Option<&LookupBindingInfo> {
         self.lookups.get(field_name)
     }
+
+    /// Get the binding error for a lookup field that could not be bound
+    pub fn unbound_error(&self, field_name: &str) -> Option<&LookupBindingError> {
+        self.unbound.get(field_name)
+    }
 }
 
 #[cfg(test)]
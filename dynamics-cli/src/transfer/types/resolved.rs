@@ -111,6 +111,64 @@ impl ResolvedTransfer {
             .iter_mut()
             .find(|e| e.entity_name == entity_name)
     }
+
+    /// Collect the user edits (action + field values) of all dirty records
+    pub fn dirty_edits(&self) -> Vec<RecordEdit> {
+        self.entities
+            .iter()
+            .flat_map(|entity| {
+                entity.dirty_records().into_iter().map(|record| RecordEdit {
+                    entity_name: entity.entity_name.clone(),
+                    source_id: record.source_id,
+                    action: record.action,
+                    fields: record.fields.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Reapply saved edits to records with a matching entity and source ID
+    ///
+    /// Matched records take the saved action and field values and are marked
+    /// dirty. Returns the number of records the edits were applied to.
+    pub fn apply_edits(&mut self, edits: &[RecordEdit]) -> usize {
+        let mut applied = 0;
+        for edit in edits {
+            let Some(entity) = self.find_entity_mut(&edit.entity_name) else {
+                continue;
+            };
+            let Some(record) = entity.find_record_mut(edit.source_id) else {
+                continue;
+            };
+            record.action = edit.action;
+            if edit.action != RecordAction::Error {
+                record.error = None;
+            }
+            for (field_name, value) in &edit.fields {
+                record.fields.insert(field_name.clone(), value.clone());
+            }
+            entity.mark_dirty(edit.source_id);
+            applied += 1;
+        }
+        applied
+    }
+
+    /// Reapply saved edits after the transform re-ran
+    ///
+    /// Like `apply_edits`, but records already dirty (in-memory edits merged on
+    /// refresh) keep their newer values. Returns the number of records updated.
+    pub fn apply_saved_edits(&mut self, edits: &[RecordEdit]) -> usize {
+        let pending: Vec<RecordEdit> = edits
+            .iter()
+            .filter(|edit| {
+                !self
+                    .find_entity(&edit.entity_name)
+                    .is_some_and(|entity| entity.is_dirty(edit.source_id))
+            })
+            .cloned()
+            .collect();
+        self.apply_edits(&pending)
+    }
 }
 
 /// Resolved records for a single entity
@@ -172,6 +230,42 @@ impl ResolvedEntity {
         self.lookup_context = Some(ctx);
     }
 
+    /// Mark records that write an unbindable lookup as errors
+    ///
+    /// Only creates and updates that carry a non-null value for a lookup in the
+    /// context's `unbound` set are affected. Returns the number of records marked.
+    pub fn mark_unbound_lookup_errors(&mut self) -> usize {
+        let Some(ctx) = &self.lookup_context else {
+            return 0;
+        };
+        if ctx.unbound.is_empty() {
+            return 0;
+        }
+
+        let mut marked = 0;
+        for record in &mut self.records {
+            if !matches!(record.action, RecordAction::Create | RecordAction::Update) {
+                continue;
+            }
+
+            let failed = ctx.unbound.iter().find(|(field, _)| {
+                let writes_field = record
+                    .changed_fields
+                    .as_ref()
+                    .is_none_or(|changed| changed.contains(field.as_str()));
+                writes_field
+                    && !matches!(record.fields.get(field.as_str()), None | Some(Value::Null))
+            });
+
+            if let Some((field, err)) = failed {
+                record.action = RecordAction::Error;
+                record.error = Some(format!("Cannot bind lookup '{}': {}", field, err));
+                marked += 1;
+            }
+        }
+        marked
+    }
+
     /// Set the entity set name for API calls
     pub fn set_entity_set_name(&mut self, name: String) {
         self.entity_set_name = Some(name);
@@ -489,6 +583,19 @@ impl ResolvedRecord {
     }
 }
 
+/// A user edit to a single record, kept so it can be saved and reapplied
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordEdit {
+    /// Target entity logical name
+    pub entity_name: String,
+    /// Source record ID
+    pub source_id: Uuid,
+    /// Action override
+    pub action: RecordAction,
+    /// Field values at the time of the edit
+    pub fields: HashMap<String, Value>,
+}
+
 /// Action to take for a resolved record
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RecordAction {
@@ -571,6 +678,67 @@ mod tests {
         assert!(transfer.has_errors());
     }
 
+    #[test]
+    fn test_unbound_lookup_marks_only_affected_records() {
+        use crate::api::metadata::{FieldMetadata, FieldType};
+
+        let lookup = |name: &str, schema: &str, target: &str| FieldMetadata {
+            logical_name: name.to_string(),
+            schema_name: Some(schema.to_string()),
+            display_name: None,
+            field_type: FieldType::Lookup,
+            is_required: false,
+            is_primary_key: false,
+            max_length: None,
+            related_entity: Some(target.to_string()),
+            navigation_property_name: None,
+            option_values: vec![],
+        };
+        let fields = vec![
+            lookup("parentaccountid", "ParentAccountId", "account"),
+            lookup("ownerteamid", "OwnerTeamId", "team"),
+        ];
+        // No entity set known for "team" - that lookup cannot be bound
+        let entity_set_map = HashMap::from([("account".to_string(), "accounts".to_string())]);
+
+        let bad_id = Uuid::new_v4();
+        let good_id = Uuid::new_v4();
+        let null_id = Uuid::new_v4();
+
+        let mut entity = ResolvedEntity::new("account", 1, "accountid");
+        entity.add_record(ResolvedRecord::create(
+            bad_id,
+            HashMap::from([("ownerteamid".to_string(), Value::Guid(Uuid::new_v4()))]),
+        ));
+        entity.add_record(ResolvedRecord::create(
+            good_id,
+            HashMap::from([("parentaccountid".to_string(), Value::Guid(Uuid::new_v4()))]),
+        ));
+        entity.add_record(ResolvedRecord::update(
+            null_id,
+            HashMap::from([("ownerteamid".to_string(), Value::Null)]),
+        ));
+        entity.set_lookup_context(LookupBindingContext::from_field_metadata_partial(
+            &fields,
+            &entity_set_map,
+        ));
+
+        assert_eq!(entity.mark_unbound_lookup_errors(), 1);
+
+        let bad = entity.find_record(bad_id).unwrap();
+        assert!(bad.is_error());
+        assert!(bad.error.as_ref().unwrap().contains("ownerteamid"));
+        assert!(entity.find_record(good_id).unwrap().is_create());
+        assert!(entity.find_record(null_id).unwrap().is_update());
+        assert!(
+            entity
+                .lookup_context
+                .as_ref()
+                .unwrap()
+                .is_lookup("parentaccountid")
+        );
+    }
+
     #[test]
     fn test_dirty_tracking() {
         let mut entity = ResolvedEntity::new("account", 1, "accountid");
@@ -583,4 +751,38 @@ mod tests {
         entity.clear_dirty(id);
         assert!(!entity.is_dirty(id));
     }
+
+    #[test]
+    fn test_saved_edits_do_not_override_pending_edits() {
+        let mut transfer = ResolvedTransfer::new("test", "dev", "prod");
+        let mut entity = ResolvedEntity::new("account", 1, "accountid");
+        let saved_id = Uuid::new_v4();
+        let pending_id = Uuid::new_v4();
+        entity.add_record(ResolvedRecord::create(saved_id, HashMap::new()));
+        entity.add_record(ResolvedRecord::create(pending_id, HashMap::new()));
+        entity.mark_dirty(pending_id);
+        transfer.add_entity(entity);
+
+        let skip = |source_id| RecordEdit {
+            entity_name: "account".to_string(),
+            source_id,
+            action: RecordAction::Skip,
+            fields: HashMap::new(),
+        };
+        assert_eq!(
+            transfer.apply_saved_edits(&[skip(saved_id), skip(pending_id)]),
+            1
+        );
+
+        let entity = transfer.find_entity("account").unwrap();
+        assert_eq!(
+            entity.find_record(saved_id).unwrap().action,
+            RecordAction::Skip
+        );
+        assert!(entity.is_dirty(saved_id));
+        assert_eq!(
+            entity.find_record(pending_id).unwrap().action,
+            RecordAction::Create
+        );
+    }
 }
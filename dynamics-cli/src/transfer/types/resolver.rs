@@ -88,6 +88,9 @@ pub struct Resolver {
     /// What to do when no match is found
     #[serde(default)]
     pub fallback: ResolverFallback,
+    /// How string values are normalized before matching
+    #[serde(default, skip_serializing_if = "MatchNormalization::is_default")]
+    pub normalization: MatchNormalization,
 }
 
 impl Resolver {
@@ -106,6 +109,7 @@ impl Resolver {
             source_entity: source_entity.into(),
             match_fields: vec![MatchField::simple(field)],
             fallback: ResolverFallback::default(),
+            normalization: MatchNormalization::default(),
         }
     }
 
@@ -123,6 +127,7 @@ impl Resolver {
             source_entity: source_entity.into(),
             match_fields: vec![MatchField::simple(field)],
             fallback,
+            normalization: MatchNormalization::default(),
         }
     }
 
@@ -153,6 +158,7 @@ impl Resolver {
                 .map(|(source, target)| MatchField::new(FieldPath::simple(source.into()), target))
                 .collect(),
             fallback: ResolverFallback::default(),
+            normalization: MatchNormalization::default(),
         }
     }
 
@@ -172,6 +178,7 @@ impl Resolver {
                 .map(|(source, target)| MatchField::new(FieldPath::simple(source.into()), target))
                 .collect(),
             fallback,
+            normalization: MatchNormalization::default(),
         }
     }
 
@@ -188,9 +195,16 @@ impl Resolver {
             source_entity: source_entity.into(),
             match_fields,
             fallback,
+            normalization: MatchNormalization::default(),
         }
     }
 
+    /// Set the normalization applied to string values when matching
+    pub fn with_normalization(mut self, normalization: MatchNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
     /// Check if this is a compound key resolver (more than one match field)
     pub fn is_compound(&self) -> bool {
         self.match_fields.len() > 1
@@ -253,6 +267,116 @@ impl ResolverFallback {
     }
 }
 
+/// String normalization applied symmetrically to target data and source values
+///
+/// The default (case-insensitive + trim) matches the behavior of resolvers
+/// saved before these options existed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchNormalization {
+    /// Compare strings ignoring case
+    #[serde(default)]
+    pub case_insensitive: bool,
+    /// Strip leading and trailing whitespace
+    #[serde(default)]
+    pub trim: bool,
+    /// Collapse runs of internal whitespace into a single space
+    #[serde(default)]
+    pub collapse_whitespace: bool,
+}
+
+impl Default for MatchNormalization {
+    fn default() -> Self {
+        MatchNormalization {
+            case_insensitive: true,
+            trim: true,
+            collapse_whitespace: false,
+        }
+    }
+}
+
+impl MatchNormalization {
+    /// Exact matching - strings are compared as-is
+    pub fn exact() -> Self {
+        MatchNormalization {
+            case_insensitive: false,
+            trim: false,
+            collapse_whitespace: false,
+        }
+    }
+
+    /// Most lenient matching - ignore case and all whitespace differences
+    pub fn lenient() -> Self {
+        MatchNormalization {
+            case_insensitive: true,
+            trim: true,
+            collapse_whitespace: true,
+        }
+    }
+
+    /// Check if this is the default normalization
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Apply the normalization to a string
+    pub fn apply(&self, value: &str) -> String {
+        let mut result = if self.collapse_whitespace {
+            let mut collapsed = String::with_capacity(value.len());
+            let mut in_whitespace = false;
+            for c in value.chars() {
+                if c.is_whitespace() {
+                    if !in_whitespace {
+                        collapsed.push(' ');
+                    }
+                    in_whitespace = true;
+                } else {
+                    collapsed.push(c);
+                    in_whitespace = false;
+                }
+            }
+            collapsed
+        } else {
+            value.to_string()
+        };
+        if self.trim {
+            result = result.trim().to_string();
+        }
+        if self.case_insensitive {
+            result = result.to_lowercase();
+        }
+        result
+    }
+
+    /// Get display label for UI
+    pub fn label(&self) -> String {
+        if *self == Self::exact() {
+            return "Exact".to_string();
+        }
+        let mut parts = Vec::new();
+        if self.case_insensitive {
+            parts.push("ignore case");
+        }
+        if self.trim {
+            parts.push("trim");
+        }
+        if self.collapse_whitespace {
+            parts.push("collapse spaces");
+        }
+        parts.join(" + ")
+    }
+
+    /// Cycle through the common presets (Default -> Lenient -> Exact -> Default)
+    pub fn cycle(&self) -> Self {
+        if *self == Self::default() {
+            Self::lenient()
+        } else if *self == Self::lenient() {
+            Self::exact()
+        } else {
+            Self::default()
+        }
+    }
+}
+
 use std::collections::HashMap;
 use uuid::Uuid;
 
@@ -276,6 +400,8 @@ pub struct ResolverContext {
     fallbacks: HashMap<String, ResolverFallback>,
     /// Match field configurations for each resolver (needed for resolution)
     match_fields: HashMap<String, Vec<MatchField>>,
+    /// String normalization for each resolver
+    normalizations: HashMap<String, MatchNormalization>,
 }
 
 /// Result of a resolver lookup
@@ -397,8 +523,11 @@ impl ResolverContext {
                 };
 
                 // Build composite key from all match fields (sorted by target_field)
-                let composite_key =
-                    Self::build_composite_key_from_record(record, &sorted_match_fields);
+                let composite_key = Self::build_composite_key_from_record(
+                    record,
+                    &sorted_match_fields,
+                    &resolver.normalization,
+                );
                 if composite_key.is_empty() {
                     continue;
                 }
@@ -430,6 +559,8 @@ impl ResolverContext {
                 .insert(resolver.name.clone(), resolver.fallback.clone());
             ctx.match_fields
                 .insert(resolver.name.clone(), sorted_match_fields);
+            ctx.normalizations
+                .insert(resolver.name.clone(), resolver.normalization);
         }
 
         ctx
@@ -439,6 +570,7 @@ impl ResolverContext {
     fn build_composite_key_from_record(
         record: &serde_json::Value,
         match_fields: &[MatchField],
+        normalization: &MatchNormalization,
     ) -> String {
         let mut parts: Vec<String> = Vec::with_capacity(match_fields.len());
 
@@ -451,7 +583,7 @@ impl ResolverContext {
             let Some(value) = value else {
                 return String::new(); // Missing field, skip this record
             };
-            let normalized = Self::normalize_value(value);
+            let normalized = Self::normalize_value(value, normalization);
             if normalized.is_empty() {
                 return String::new(); // Empty value, skip this record
             }
@@ -465,13 +597,21 @@ impl ResolverContext {
     ///
     /// The pairs are sorted by field name to ensure order-independent matching.
     pub fn build_composite_key(pairs: &[(&str, &serde_json::Value)]) -> String {
+        Self::build_composite_key_with(pairs, &MatchNormalization::default())
+    }
+
+    /// Build a composite key from field-value pairs using a specific normalization
+    fn build_composite_key_with(
+        pairs: &[(&str, &serde_json::Value)],
+        normalization: &MatchNormalization,
+    ) -> String {
         // Sort by field name for order-independent matching
         let mut sorted_pairs: Vec<_> = pairs.iter().collect();
         sorted_pairs.sort_by(|a, b| a.0.cmp(b.0));
 
         let mut parts: Vec<String> = Vec::with_capacity(sorted_pairs.len());
         for (field, value) in sorted_pairs {
-            let normalized = Self::normalize_value(value);
+            let normalized = Self::normalize_value(value, normalization);
             if normalized.is_empty() {
                 return String::new(); // Empty value means no match
             }
@@ -524,7 +664,12 @@ impl ResolverContext {
         resolver_name: &str,
         pairs: &[(&str, &serde_json::Value)],
     ) -> ResolveResult {
-        let composite_key = Self::build_composite_key(pairs);
+        let normalization = self
+            .normalizations
+            .get(resolver_name)
+            .copied()
+            .unwrap_or_default();
+        let composite_key = Self::build_composite_key_with(pairs, &normalization);
         if composite_key.is_empty() {
             log::debug!(
                 "Resolver '{}': empty composite key from pairs: {:?}",
@@ -613,9 +758,16 @@ impl ResolverContext {
             ResolveResult::Found(guid) => Ok(Value::Guid(guid)),
             ResolveResult::NotFound => match fallback {
                 ResolverFallback::Error => {
+                    let normalization = self
+                        .normalizations
+                        .get(resolver_name)
+                        .copied()
+                        .unwrap_or_default();
                     let display_values: Vec<_> = pairs
                         .iter()
-                        .map(|(field, value)| format!("{}={}", field, Self::normalize_value(value)))
+                        .map(|(field, value)| {
+                            format!("{}={}", field, Self::normalize_value(value, &normalization))
+                        })
                         .collect();
                     Err(format!(
                         "Resolver '{}': no match found for [{}]",
@@ -657,10 +809,10 @@ impl ResolverContext {
         self.resolve_composite_to_value(resolver_name, &pairs)
     }
 
-    /// Normalize a JSON value for case-insensitive lookup
-    fn normalize_value(value: &serde_json::Value) -> String {
+    /// Normalize a JSON value for lookup
+    fn normalize_value(value: &serde_json::Value, normalization: &MatchNormalization) -> String {
         match value {
-            serde_json::Value::String(s) => s.to_lowercase().trim().to_string(),
+            serde_json::Value::String(s) => normalization.apply(s),
             serde_json::Value::Number(n) => n.to_string(),
             serde_json::Value::Bool(b) => b.to_string(),
             serde_json::Value::Null => String::new(),
@@ -966,6 +1118,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolver_compound_key_disambiguates_colliding_field() {
+        use serde_json::json;
+
+        // Two contacts share a last name - only the (lastname, birthdate) pair is unique
+        let resolvers = vec![Resolver::compound(
+            "contact_by_name_and_birthdate",
+            "contact",
+            vec![("lastname", "lastname"), ("birthdate", "birthdate")],
+        )];
+
+        let mut target_data = HashMap::new();
+        target_data.insert(
+            "contact".to_string(),
+            vec![
+                json!({
+                    "contactid": "11111111-1111-1111-1111-111111111111",
+                    "lastname": "Peeters",
+                    "birthdate": "1980-01-01"
+                }),
+                json!({
+                    "contactid": "22222222-2222-2222-2222-222222222222",
+                    "lastname": "Peeters",
+                    "birthdate": "1990-06-15"
+                }),
+            ],
+        );
+
+        let mut primary_keys = HashMap::new();
+        primary_keys.insert("contact".to_string(), "contactid".to_string());
+
+        let ctx = ResolverContext::build(&resolvers, &target_data, &primary_keys);
+
+        let pairs = [
+            ("lastname", &json!("Peeters") as &serde_json::Value),
+            ("birthdate", &json!("1990-06-15")),
+        ];
+        assert_eq!(
+            ctx.resolve_composite("contact_by_name_and_birthdate", &pairs),
+            ResolveResult::Found(Uuid::parse_str("22222222-2222-2222-2222-222222222222").unwrap())
+        );
+
+        let pairs = [
+            ("lastname", &json!("Peeters") as &serde_json::Value),
+            ("birthdate", &json!("1980-01-01")),
+        ];
+        assert_eq!(
+            ctx.resolve_composite("contact_by_name_and_birthdate", &pairs),
+            ResolveResult::Found(Uuid::parse_str("11111111-1111-1111-1111-111111111111").unwrap())
+        );
+
+        // The colliding field alone is not a key
+        let pairs = [("lastname", &json!("Peeters") as &serde_json::Value)];
+        assert_eq!(
+            ctx.resolve_composite("contact_by_name_and_birthdate", &pairs),
+            ResolveResult::NotFound
+        );
+    }
+
     #[test]
     fn test_resolver_compound_key_case_insensitive() {
         use serde_json::json;
@@ -1006,6 +1217,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolver_normalization_case_insensitive_and_trim() {
+        use serde_json::json;
+
+        let mut target_data = HashMap::new();
+        target_data.insert(
+            "account".to_string(),
+            vec![json!({
+                "accountid": "11111111-1111-1111-1111-111111111111",
+                "name": "ACME "
+            })],
+        );
+        let mut primary_keys = HashMap::new();
+        primary_keys.insert("account".to_string(), "accountid".to_string());
+
+        let lenient = Resolver::new("account_by_name", "account", "name").with_normalization(
+            MatchNormalization {
+                case_insensitive: true,
+                trim: true,
+                collapse_whitespace: false,
+            },
+        );
+        let ctx = ResolverContext::build(&[lenient], &target_data, &primary_keys);
+        let pairs = [("name", &json!("acme") as &serde_json::Value)];
+        assert_eq!(
+            ctx.resolve_composite("account_by_name", &pairs),
+            ResolveResult::Found(Uuid::parse_str("11111111-1111-1111-1111-111111111111").unwrap())
+        );
+
+        let exact = Resolver::new("account_by_name", "account", "name")
+            .with_normalization(MatchNormalization::exact());
+        let ctx = ResolverContext::build(&[exact], &target_data, &primary_keys);
+        assert_eq!(
+            ctx.resolve_composite("account_by_name", &pairs),
+            ResolveResult::NotFound
+        );
+        let pairs = [("name", &json!("ACME ") as &serde_json::Value)];
+        assert_eq!(
+            ctx.resolve_composite("account_by_name", &pairs),
+            ResolveResult::Found(Uuid::parse_str("11111111-1111-1111-1111-111111111111").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_match_normalization_collapse_whitespace() {
+        assert_eq!(
+            MatchNormalization::lenient().apply("  Acme   Corp\t "),
+            "acme corp"
+        );
+        assert_eq!(
+            MatchNormalization::default().apply(" Acme  Corp "),
+            "acme  corp"
+        );
+        assert_eq!(MatchNormalization::exact().apply(" Acme "), " Acme ");
+    }
+
+    #[test]
+    fn test_resolver_normalization_serde_default() {
+        // Resolvers saved without normalization keep the case-insensitive + trim behavior
+        let json = r#"{"name":"r","source_entity":"account","match_fields":[]}"#;
+        let resolver: Resolver = serde_json::from_str(json).unwrap();
+        assert_eq!(resolver.normalization, MatchNormalization::default());
+        assert!(
+            !serde_json::to_string(&resolver)
+                .unwrap()
+                .contains("normalization")
+        );
+    }
+
     #[test]
     fn test_resolver_compound_to_value() {
         use serde_json::json;
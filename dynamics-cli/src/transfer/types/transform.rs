@@ -59,6 +59,69 @@ pub enum Transform {
         /// Replacement operations applied in order
         replacements: Vec<Replacement>,
     },
+    /// Roll up a child collection into a single value (e.g., sum of line amounts)
+    Aggregate {
+        /// Path through a collection to the child field (e.g., "lines.amount" or "lines.*.amount")
+        source_path: FieldPath,
+        /// Aggregate function applied to the child values
+        function: AggregateFunction,
+        /// Separator for concat (defaults to ", ")
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        separator: Option<String>,
+    },
+}
+
+/// Function used by an aggregate transform to combine child values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AggregateFunction {
+    /// Sum of numeric values
+    #[default]
+    Sum,
+    /// Number of non-null values
+    Count,
+    /// Values joined into a string
+    Concat,
+    /// Smallest value
+    Min,
+    /// Largest value
+    Max,
+}
+
+impl AggregateFunction {
+    /// All functions in cycling order
+    pub const ALL: [AggregateFunction; 5] = [
+        AggregateFunction::Sum,
+        AggregateFunction::Count,
+        AggregateFunction::Concat,
+        AggregateFunction::Min,
+        AggregateFunction::Max,
+    ];
+
+    /// Parse from its lowercase name (as shown by Display)
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|f| f.to_string().eq_ignore_ascii_case(s.trim()))
+    }
+
+    /// The next function in cycling order
+    pub fn next(&self) -> Self {
+        let idx = Self::ALL.iter().position(|f| f == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+}
+
+impl std::fmt::Display for AggregateFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AggregateFunction::Sum => write!(f, "sum"),
+            AggregateFunction::Count => write!(f, "count"),
+            AggregateFunction::Concat => write!(f, "concat"),
+            AggregateFunction::Min => write!(f, "min"),
+            AggregateFunction::Max => write!(f, "max"),
+        }
+    }
 }
 
 /// A single replacement operation (pattern → replacement, optionally regex)
@@ -213,11 +276,21 @@ impl Transform {
             } => {
                 format!("replace({}) [{} rules]", source_path, replacements.len())
             }
+            Transform::Aggregate {
+                source_path,
+                function,
+                ..
+            } => {
+                format!("{}({})", function, source_path)
+            }
         }
     }
 
     /// Get the source field(s) required by this transform
     /// Returns the base field name (first segment of path)
+    ///
+    /// Aggregates read a collection-valued navigation property, which can't be
+    /// selected; it is fetched through `lookup_paths()` as an `$expand` instead.
     pub fn source_fields(&self) -> Vec<&str> {
         match self {
             Transform::Copy { source_path, .. } => vec![source_path.base_field()],
@@ -226,6 +299,7 @@ impl Transform {
             Transform::ValueMap { source_path, .. } => vec![source_path.base_field()],
             Transform::Format { template, .. } => template.base_fields(),
             Transform::Replace { source_path, .. } => vec![source_path.base_field()],
+            Transform::Aggregate { .. } => vec![],
         }
     }
 
@@ -263,7 +337,7 @@ impl Transform {
             }
             #[allow(deprecated)]
             Transform::Format { template, .. } => template.expand_specs(),
-            Transform::Replace { source_path, .. } => {
+            Transform::Replace { source_path, .. } | Transform::Aggregate { source_path, .. } => {
                 if let Some(target) = source_path.lookup_field() {
                     vec![(source_path.base_field(), target)]
                 } else {
@@ -307,6 +381,7 @@ impl Transform {
                     vec![]
                 }
             }
+            Transform::Aggregate { source_path, .. } => vec![source_path],
         }
     }
 
@@ -318,6 +393,18 @@ impl Transform {
         }
     }
 
+    /// Create an aggregate transform over a collection path
+    pub fn aggregate(
+        source_field: &str,
+        function: AggregateFunction,
+    ) -> Result<Self, FieldPathError> {
+        Ok(Transform::Aggregate {
+            source_path: FieldPath::parse(source_field)?,
+            function,
+            separator: None,
+        })
+    }
+
     /// Create a format transform from a template string
     pub fn format(template: &str) -> Result<Self, crate::transfer::transform::format::ParseError> {
         let parsed = crate::transfer::transform::format::parse_template(template)?;
@@ -349,6 +436,10 @@ impl Transform {
 /// - "userid.contactid.parentcustomerid_account.name" -> 3 lookup traversals (max)
 ///
 /// Limited to at most 4 segments (3 lookup traversals).
+///
+/// A `*` segment marks a collection whose elements are each traversed
+/// (e.g., "lines.*.amount"); collections along a path are also traversed
+/// implicitly when resolving all values with `resolve_path_values`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct FieldPath {
     /// The field segments (1 to 4 elements)
@@ -388,6 +479,9 @@ impl FieldPath {
     /// Maximum number of segments allowed in a field path
     pub const MAX_SEGMENTS: usize = 4;
 
+    /// Segment marking "every element" of a collection
+    pub const WILDCARD: &'static str = "*";
+
     /// Parse a field path from a string
     ///
     /// Validates that:
@@ -472,6 +566,15 @@ impl FieldPath {
     pub fn segments(&self) -> &[String] {
         &self.segments
     }
+
+    /// Get all segments except wildcards (the fields that need to be fetched)
+    pub fn field_segments(&self) -> Vec<&str> {
+        self.segments
+            .iter()
+            .map(|s| s.as_str())
+            .filter(|s| *s != Self::WILDCARD)
+            .collect()
+    }
 }
 
 impl std::fmt::Display for FieldPath {
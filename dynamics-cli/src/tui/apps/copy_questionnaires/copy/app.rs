@@ -1,6 +1,8 @@
 use super::data_loading::{build_domain_model, load_full_snapshot};
+use super::field_filter::FieldFilter;
 use super::models::*;
 use super::view;
+use crate::config::repository::copy_filter_presets::CopyFilterPreset;
 use crate::tui::{
     Resource,
     app::App,
@@ -44,6 +46,26 @@ fn validate_copy_params(copy_name: &str, copy_code: &str) -> Result<(), String>
     Ok(())
 }
 
+/// Load saved filter presets (empty on error)
+async fn list_filter_presets() -> Vec<CopyFilterPreset> {
+    let config = crate::global_config();
+    config.list_copy_filter_presets().await.unwrap_or_else(|e| {
+        log::error!("Failed to load filter presets: {}", e);
+        Vec::new()
+    })
+}
+
+fn reload_filter_presets() -> Command<Msg> {
+    Command::perform(list_filter_presets(), Msg::FilterPresetsLoaded)
+}
+
+/// Rebuild the dry-run report after the field filter changed
+fn refresh_copy_report(state: &mut State) {
+    if let Resource::Success(ref questionnaire) = state.questionnaire {
+        state.copy_report = Some(questionnaire.copy_report(&state.field_filter));
+    }
+}
+
 pub struct CopyQuestionnaireApp;
 
 impl crate::tui::AppState for State {}
@@ -62,10 +84,17 @@ impl App for CopyQuestionnaireApp {
             questionnaire_id: params.questionnaire_id.clone(),
             questionnaire_name: params.questionnaire_name,
             questionnaire: Resource::Loading,
+            copy_report: None,
             tree_state: crate::tui::widgets::TreeState::with_selection(),
             copy_name_input,
             copy_code_input: crate::tui::widgets::fields::TextInputField::new(),
             validation_error: None,
+            field_filter: super::field_filter::FieldFilter::default(),
+            show_filter_modal: false,
+            filter_fields_input: crate::tui::widgets::fields::TextInputField::new(),
+            filter_preset_name_input: crate::tui::widgets::fields::TextInputField::new(),
+            filter_presets: Vec::new(),
+            filter_presets_list_state: crate::tui::widgets::ListState::new(),
         };
 
         // Load complete questionnaire snapshot - single task that loads everything sequentially
@@ -109,6 +138,7 @@ impl App for CopyQuestionnaireApp {
                             log::debug!("No copypostfix found in questionnaire");
                         }
 
+                        state.copy_report = Some(questionnaire.copy_report(&state.field_filter));
                         state.questionnaire = Resource::Success(questionnaire);
                     }
                     Err(e) => {
@@ -163,9 +193,13 @@ impl App for CopyQuestionnaireApp {
                     copy_code
                 );
 
-                // Extract the questionnaire from state
+                // Extract the questionnaire from state, minus the fields the filter drops
                 let questionnaire = match &state.questionnaire {
-                    Resource::Success(q) => std::sync::Arc::new(q.clone()),
+                    Resource::Success(q) => {
+                        let mut q = q.clone();
+                        q.apply_field_filter(&state.field_filter);
+                        std::sync::Arc::new(q)
+                    }
                     _ => {
                         log::error!("Cannot continue: questionnaire not loaded");
                         return Command::None;
@@ -189,6 +223,122 @@ impl App for CopyQuestionnaireApp {
                 Command::navigate_to(AppId::SelectQuestionnaire),
                 Command::quit_self(),
             ]),
+            Msg::OpenFilterModal => {
+                state.show_filter_modal = true;
+                state
+                    .filter_fields_input
+                    .set_value(state.field_filter.fields_text());
+                state.filter_preset_name_input.value.clear();
+                reload_filter_presets()
+            }
+            Msg::CloseFilterModal => {
+                state.show_filter_modal = false;
+                refresh_copy_report(state);
+                Command::None
+            }
+            Msg::ToggleFilterMode => {
+                state.field_filter.mode = state.field_filter.mode.toggle();
+                Command::None
+            }
+            Msg::FilterFieldsInputEvent(event) => {
+                state.filter_fields_input.handle_event(event, None);
+                state.field_filter.fields =
+                    FieldFilter::parse_fields(state.filter_fields_input.value());
+                Command::None
+            }
+            Msg::FilterPresetNameInputEvent(event) => {
+                state.filter_preset_name_input.handle_event(event, None);
+                Command::None
+            }
+            Msg::FilterPresetsLoaded(presets) => {
+                state.filter_presets = presets;
+                let item_count = state.filter_presets.len();
+                let selected = state
+                    .filter_presets_list_state
+                    .selected()
+                    .filter(|idx| *idx < item_count)
+                    .or(if item_count > 0 { Some(0) } else { None });
+                state
+                    .filter_presets_list_state
+                    .select_and_scroll(selected, item_count);
+                Command::None
+            }
+            Msg::FilterPresetsListNavigate(key) => {
+                state
+                    .filter_presets_list_state
+                    .handle_key(key, state.filter_presets.len(), 10);
+                Command::None
+            }
+            Msg::FilterPresetsListSelect(index) => {
+                let item_count = state.filter_presets.len();
+                state
+                    .filter_presets_list_state
+                    .select_and_scroll(Some(index), item_count);
+                Command::None
+            }
+            Msg::FilterPresetsListActivate(index) => {
+                Self::update(state, Msg::FilterPresetsListSelect(index));
+                Self::update(state, Msg::LoadFilterPreset)
+            }
+            Msg::SaveFilterPreset => {
+                let name = state.filter_preset_name_input.value().trim().to_string();
+                if name.is_empty() {
+                    log::warn!("Cannot save filter preset: name must be provided");
+                    return Command::None;
+                }
+
+                let preset = state.field_filter.to_preset(name);
+                state.filter_preset_name_input.value.clear();
+
+                Command::perform(
+                    async move {
+                        let config = crate::global_config();
+                        if let Err(e) = config.save_copy_filter_preset(&preset).await {
+                            log::error!("Failed to save filter preset: {}", e);
+                        }
+                        list_filter_presets().await
+                    },
+                    Msg::FilterPresetsLoaded,
+                )
+            }
+            Msg::LoadFilterPreset => {
+                let Some(preset) = state
+                    .filter_presets_list_state
+                    .selected()
+                    .and_then(|idx| state.filter_presets.get(idx))
+                else {
+                    return Command::None;
+                };
+
+                state.field_filter = FieldFilter::from_preset(preset);
+                log::info!("Loaded filter preset '{}'", preset.name);
+                state.show_filter_modal = false;
+                refresh_copy_report(state);
+                Command::None
+            }
+            Msg::DeleteFilterPreset => {
+                let Some(name) = state
+                    .filter_presets_list_state
+                    .selected()
+                    .and_then(|idx| state.filter_presets.get(idx))
+                    .map(|p| p.name.clone())
+                else {
+                    return Command::None;
+                };
+
+                state.filter_presets.retain(|p| p.name != name);
+
+                Command::perform(
+                    async move {
+                        let config = crate::global_config();
+                        if let Err(e) = config.delete_copy_filter_preset(&name).await {
+                            log::error!("Failed to delete filter preset: {}", e);
+                        }
+                        list_filter_presets().await
+                    },
+                    Msg::FilterPresetsLoaded,
+                )
+            }
         }
     }
 
@@ -196,9 +346,25 @@ impl App for CopyQuestionnaireApp {
         view::render_view(state)
     }
 
-    fn subscriptions(_state: &Self::State) -> Vec<Subscription<Self::Msg>> {
+    fn subscriptions(state: &Self::State) -> Vec<Subscription<Self::Msg>> {
+        if state.show_filter_modal {
+            return vec![
+                Subscription::ctrl_key(
+                    KeyCode::Char('t'),
+                    "Toggle filter mode",
+                    Msg::ToggleFilterMode,
+                ),
+                Subscription::keyboard(KeyCode::Esc, "Close modal", Msg::CloseFilterModal),
+            ];
+        }
+
         vec![
             Subscription::keyboard(KeyCode::Esc, "Back to selection", Msg::Back),
+            Subscription::ctrl_key(
+                KeyCode::Char('f'),
+                "Field filter presets",
+                Msg::OpenFilterModal,
+            ),
             // TODO: Add keybinding for starting copy when implemented
             // Subscription::keyboard(KeyCode::Char('c'), "Start copy", Msg::StartCopy),
         ]
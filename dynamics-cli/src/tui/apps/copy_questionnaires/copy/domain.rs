@@ -1,6 +1,9 @@
 /// Domain model representing the complete questionnaire structure
 /// This is what will actually be copied, and what the tree view displays
+use super::field_filter::{FieldFilter, RelevantFields};
+use crate::tui::apps::copy_questionnaires::push::field_specs::{self, FieldSpec, FieldType};
 use serde_json::Value;
+use std::collections::BTreeMap;
 
 /// Complete questionnaire with all related entities in hierarchical structure
 #[derive(Clone, Debug)]
@@ -122,3 +125,391 @@ impl Questionnaire {
         total
     }
 }
+
+/// Dry-run summary of a copy: what will be created and what gets left behind
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CopyReport {
+    /// Records created (or associated, for classifications) per entity type, in copy order
+    pub counts: Vec<(&'static str, usize)>,
+    /// Populated fields the copy can't carry over
+    pub skipped: Vec<SkippedField>,
+}
+
+/// A field that holds data but isn't part of the copy, per entity type
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkippedField {
+    pub entity: &'static str,
+    pub field: String,
+    /// Number of records with a value in this field
+    pub records: usize,
+}
+
+impl CopyReport {
+    /// Total number of create and associate operations
+    pub fn total(&self) -> usize {
+        self.counts.iter().map(|(_, count)| count).sum()
+    }
+}
+
+/// Records of one entity type with what decides which of their fields are copied
+struct RecordSet<'a> {
+    entity: &'static str,
+    raws: Vec<&'a Value>,
+    relevant: RelevantFields,
+    specs: &'static [FieldSpec],
+    /// Fields the copy sets through a later step instead
+    handled: &'static [&'static str],
+}
+
+impl<'a> RecordSet<'a> {
+    fn new(
+        entity: &'static str,
+        raws: Vec<&'a Value>,
+        relevant: RelevantFields,
+        specs: &'static [FieldSpec],
+        handled: &'static [&'static str],
+    ) -> Self {
+        Self {
+            entity,
+            raws,
+            relevant,
+            specs,
+            handled,
+        }
+    }
+}
+
+impl Questionnaire {
+    /// Every copied record grouped by entity type, in copy order
+    fn record_sets(&self) -> Vec<RecordSet<'_>> {
+        let groups: Vec<&Group> = self.pages.iter().flat_map(|p| &p.groups).collect();
+        let set = RecordSet::new;
+
+        vec![
+            set(
+                "Questionnaire",
+                vec![&self.raw],
+                RelevantFields::for_questionnaire(),
+                field_specs::QUESTIONNAIRE_FIELDS,
+                &[],
+            ),
+            set(
+                "Pages",
+                self.pages.iter().map(|p| &p.raw).collect(),
+                RelevantFields::for_page(),
+                field_specs::PAGE_FIELDS,
+                &[],
+            ),
+            set(
+                "Page Lines",
+                self.page_lines.iter().collect(),
+                RelevantFields::for_page_line(),
+                field_specs::PAGE_LINE_FIELDS,
+                &[],
+            ),
+            set(
+                "Groups",
+                groups.iter().map(|g| &g.raw).collect(),
+                RelevantFields::for_group(),
+                field_specs::GROUP_FIELDS,
+                &[],
+            ),
+            set(
+                "Group Lines",
+                self.group_lines.iter().collect(),
+                RelevantFields::for_group_line(),
+                field_specs::GROUP_LINE_FIELDS,
+                &[],
+            ),
+            set(
+                "Questions",
+                groups
+                    .iter()
+                    .flat_map(|g| &g.questions)
+                    .map(|q| &q.raw)
+                    .collect(),
+                RelevantFields::for_question(),
+                field_specs::QUESTION_FIELDS,
+                &[],
+            ),
+            set(
+                "Template Lines",
+                self.template_lines.iter().map(|t| &t.raw).collect(),
+                RelevantFields::for_template_line(),
+                field_specs::TEMPLATE_LINE_FIELDS,
+                &[],
+            ),
+            set(
+                "Conditions",
+                self.conditions.iter().map(|c| &c.raw).collect(),
+                RelevantFields::for_condition(),
+                field_specs::CONDITION_FIELDS,
+                // Restored when conditions are published after their actions exist
+                &["statuscode"],
+            ),
+            set(
+                "Condition Actions",
+                self.conditions
+                    .iter()
+                    .flat_map(|c| &c.actions)
+                    .map(|a| &a.raw)
+                    .collect(),
+                RelevantFields::for_condition_action(),
+                field_specs::CONDITION_ACTION_FIELDS,
+                &[],
+            ),
+        ]
+    }
+
+    /// Build the dry-run report without touching the target environment
+    pub fn copy_report(&self, filter: &FieldFilter) -> CopyReport {
+        let c = &self.classifications;
+        let classifications = c.categories.len()
+            + c.domains.len()
+            + c.funds.len()
+            + c.supports.len()
+            + c.types.len()
+            + c.subcategories.len()
+            + c.flemish_shares.len();
+
+        let mut report = CopyReport::default();
+        for set in self.record_sets() {
+            report.counts.push((set.entity, set.raws.len()));
+            report.skipped.extend(skipped_fields(&set, filter));
+        }
+        report.counts.push(("Classifications", classifications));
+        report
+    }
+
+    /// Drop the value fields the filter excludes, so the copy leaves them out
+    pub fn apply_field_filter(&mut self, filter: &FieldFilter) {
+        let strip = |raw: &mut Value, specs: &[FieldSpec]| {
+            let Some(obj) = raw.as_object_mut() else {
+                return;
+            };
+            for spec in specs {
+                if matches!(spec.field_type, FieldType::Value) && !filter.copies(spec.source_name) {
+                    obj.remove(spec.source_name);
+                }
+            }
+        };
+
+        strip(&mut self.raw, field_specs::QUESTIONNAIRE_FIELDS);
+        for page in &mut self.pages {
+            strip(&mut page.raw, field_specs::PAGE_FIELDS);
+            for group in &mut page.groups {
+                strip(&mut group.raw, field_specs::GROUP_FIELDS);
+                for question in &mut group.questions {
+                    strip(&mut question.raw, field_specs::QUESTION_FIELDS);
+                }
+            }
+        }
+        for line in &mut self.page_lines {
+            strip(line, field_specs::PAGE_LINE_FIELDS);
+        }
+        for line in &mut self.group_lines {
+            strip(line, field_specs::GROUP_LINE_FIELDS);
+        }
+        for line in &mut self.template_lines {
+            strip(&mut line.raw, field_specs::TEMPLATE_LINE_FIELDS);
+        }
+        for condition in &mut self.conditions {
+            strip(&mut condition.raw, field_specs::CONDITION_FIELDS);
+            for action in &mut condition.actions {
+                strip(&mut action.raw, field_specs::CONDITION_ACTION_FIELDS);
+            }
+        }
+    }
+}
+
+/// Non-null fields of a record set that the copy won't carry over
+///
+/// That's relevant fields no field spec copies, plus copyable value fields
+/// the filter excludes.
+fn skipped_fields(set: &RecordSet, filter: &FieldFilter) -> Vec<SkippedField> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for raw in &set.raws {
+        let Some(obj) = raw.as_object() else {
+            continue;
+        };
+        for (field, value) in obj {
+            if value.is_null() || set.handled.contains(&field.as_str()) {
+                continue;
+            }
+            let skipped = match set.specs.iter().find(|spec| spec.source_name == field) {
+                Some(spec) => matches!(spec.field_type, FieldType::Value) && !filter.copies(field),
+                None => set.relevant.should_include(field),
+            };
+            if skipped {
+                *counts.entry(field.clone()).or_default() += 1;
+            }
+        }
+    }
+
+    counts
+        .into_iter()
+        .map(|(field, records)| SkippedField {
+            entity: set.entity,
+            field,
+            records,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn reference(id: &str) -> Reference {
+        Reference {
+            id: id.to_string(),
+            name: None,
+        }
+    }
+
+    fn question(id: &str) -> Question {
+        Question {
+            id: id.to_string(),
+            name: id.to_string(),
+            raw: json!({ "nrq_name": id, "nrq_questiontext": "?", "statecode": 0 }),
+            tag: None,
+            template: None,
+        }
+    }
+
+    fn questionnaire() -> Questionnaire {
+        let group = |id: &str, questions: Vec<Question>| Group {
+            id: id.to_string(),
+            name: id.to_string(),
+            order: None,
+            raw: json!({ "nrq_name": id }),
+            questions,
+        };
+        Questionnaire {
+            id: "q".to_string(),
+            name: "Intake".to_string(),
+            raw: json!({ "nrq_name": "Intake", "nrq_description": null }),
+            pages: vec![Page {
+                id: "p1".to_string(),
+                name: "Page".to_string(),
+                order: Some(1),
+                raw: json!({ "nrq_name": "Page", "nrq_settings": "{}", "createdon": "2026-01-01" }),
+                groups: vec![
+                    group("g1", vec![question("q1"), question("q2")]),
+                    group("g2", vec![question("q3")]),
+                ],
+            }],
+            page_lines: vec![json!({ "nrq_order": 1 })],
+            group_lines: vec![json!({ "nrq_order": 1 }), json!({ "nrq_order": 2 })],
+            template_lines: vec![],
+            conditions: vec![Condition {
+                id: "c1".to_string(),
+                name: "Show".to_string(),
+                raw: json!({ "nrq_name": "Show", "statuscode": 170590001 }),
+                actions: vec![ConditionAction {
+                    id: "a1".to_string(),
+                    name: "Show q3".to_string(),
+                    raw: json!({ "nrq_name": "Show q3" }),
+                }],
+            }],
+            classifications: Classifications {
+                categories: vec![reference("cat")],
+                domains: vec![],
+                funds: vec![reference("fund1"), reference("fund2")],
+                supports: vec![],
+                types: vec![],
+                subcategories: vec![],
+                flemish_shares: vec![],
+            },
+        }
+    }
+
+    #[test]
+    fn test_copy_report_counts_match_planned_operations() {
+        let questionnaire = questionnaire();
+        let report = questionnaire.copy_report(&FieldFilter::default());
+
+        assert_eq!(
+            report.counts,
+            vec![
+                ("Questionnaire", 1),
+                ("Pages", 1),
+                ("Page Lines", 1),
+                ("Groups", 2),
+                ("Group Lines", 2),
+                ("Questions", 3),
+                ("Template Lines", 0),
+                ("Conditions", 1),
+                ("Condition Actions", 1),
+                ("Classifications", 3),
+            ]
+        );
+        assert_eq!(report.total(), questionnaire.total_entities());
+
+        // System fields, nulls and the condition status (set in a later step) aren't reported
+        assert_eq!(
+            report.skipped,
+            vec![
+                SkippedField {
+                    entity: "Pages",
+                    field: "nrq_settings".to_string(),
+                    records: 1,
+                },
+                SkippedField {
+                    entity: "Questions",
+                    field: "statecode".to_string(),
+                    records: 3,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_applying_a_preset_filters_copied_fields() {
+        use crate::config::repository::copy_filter_presets::CopyFilterPreset;
+
+        let mut questionnaire = questionnaire();
+        let raw = &mut questionnaire.pages[0].groups[0].questions[0].raw;
+        raw["nrq_tooltip"] = json!("Hint");
+        raw["nrq_regex"] = json!("^\\d+$");
+        raw["_nrq_questiongroupid_value"] = json!("g1");
+
+        let preset = CopyFilterPreset {
+            name: "texts only".to_string(),
+            mode: "only".to_string(),
+            fields: vec!["nrq_questiontext".to_string(), "nrq_tooltip".to_string()],
+        };
+        let filter = FieldFilter::from_preset(&preset);
+        assert_eq!(filter.to_preset("texts only".to_string()), preset);
+
+        let report = questionnaire.copy_report(&filter);
+        assert!(report.skipped.contains(&SkippedField {
+            entity: "Questions",
+            field: "nrq_regex".to_string(),
+            records: 1,
+        }));
+
+        questionnaire.apply_field_filter(&filter);
+        let raw = &questionnaire.pages[0].groups[0].questions[0].raw;
+        let mut fields: Vec<&str> = raw
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        fields.sort();
+
+        // Names and lookups stay; fields the copy never sends are left alone
+        assert_eq!(
+            fields,
+            vec![
+                "_nrq_questiongroupid_value",
+                "nrq_name",
+                "nrq_questiontext",
+                "nrq_tooltip",
+                "statecode",
+            ]
+        );
+    }
+}
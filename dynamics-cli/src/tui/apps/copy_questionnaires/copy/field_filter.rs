@@ -1,5 +1,6 @@
+use crate::config::repository::copy_filter_presets::CopyFilterPreset;
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 /// System fields that should always be excluded
 const SYSTEM_FIELDS: &[&str] = &[
@@ -230,6 +231,101 @@ impl RelevantFields {
     }
 }
 
+/// Fields every record needs to be created, copied whatever the filter says
+const ALWAYS_COPIED: &[&str] = &["nrq_name"];
+
+/// How a field filter treats the fields it lists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FieldFilterMode {
+    /// Copy every supported field except the listed ones
+    #[default]
+    AllExcept,
+    /// Copy only the listed fields
+    Only,
+}
+
+impl FieldFilterMode {
+    /// Stable key used for persistence
+    pub fn key(&self) -> &'static str {
+        match self {
+            FieldFilterMode::AllExcept => "all_except",
+            FieldFilterMode::Only => "only",
+        }
+    }
+
+    /// Parse a persisted key, falling back to the default
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "only" => FieldFilterMode::Only,
+            _ => FieldFilterMode::AllExcept,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FieldFilterMode::AllExcept => "All except listed",
+            FieldFilterMode::Only => "Only listed",
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            FieldFilterMode::AllExcept => FieldFilterMode::Only,
+            FieldFilterMode::Only => FieldFilterMode::AllExcept,
+        }
+    }
+}
+
+/// The user's choice of which value fields a copy carries over
+///
+/// Lookups are never filtered - they hold the questionnaire structure together.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldFilter {
+    pub mode: FieldFilterMode,
+    pub fields: BTreeSet<String>,
+}
+
+impl FieldFilter {
+    /// Check if a value field should be copied
+    pub fn copies(&self, field_name: &str) -> bool {
+        if ALWAYS_COPIED.contains(&field_name) {
+            return true;
+        }
+        match self.mode {
+            FieldFilterMode::AllExcept => !self.fields.contains(field_name),
+            FieldFilterMode::Only => self.fields.contains(field_name),
+        }
+    }
+
+    /// Parse a comma or whitespace separated list of field names
+    pub fn parse_fields(text: &str) -> BTreeSet<String> {
+        text.split(|c: char| c == ',' || c.is_whitespace())
+            .map(|f| f.trim().to_lowercase())
+            .filter(|f| !f.is_empty())
+            .collect()
+    }
+
+    /// Listed fields as editable text
+    pub fn fields_text(&self) -> String {
+        self.fields.iter().cloned().collect::<Vec<_>>().join(", ")
+    }
+
+    pub fn from_preset(preset: &CopyFilterPreset) -> Self {
+        Self {
+            mode: FieldFilterMode::from_key(&preset.mode),
+            fields: preset.fields.iter().cloned().collect(),
+        }
+    }
+
+    pub fn to_preset(&self, name: String) -> CopyFilterPreset {
+        CopyFilterPreset {
+            name,
+            mode: self.mode.key().to_string(),
+            fields: self.fields.iter().cloned().collect(),
+        }
+    }
+}
+
 /// Parsed condition JSON structure
 #[derive(Debug, Clone)]
 pub struct ConditionLogic {
@@ -1,7 +1,9 @@
-use super::domain::Questionnaire;
+use super::domain::{CopyReport, Questionnaire};
+use super::field_filter::FieldFilter;
+use crate::config::repository::copy_filter_presets::CopyFilterPreset;
 use crate::tui::{
     Resource,
-    widgets::{TreeState, fields::TextInputField},
+    widgets::{ListState, TreeState, fields::TextInputField},
 };
 use serde_json::Value;
 
@@ -10,10 +12,20 @@ pub struct State {
     pub questionnaire_id: String,
     pub questionnaire_name: String,
     pub questionnaire: Resource<Questionnaire>,
+    /// Dry-run summary, built once the questionnaire has loaded
+    pub copy_report: Option<CopyReport>,
     pub tree_state: TreeState,
     pub copy_name_input: TextInputField,
     pub copy_code_input: TextInputField,
     pub validation_error: Option<String>,
+
+    // Field filter and its saved presets
+    pub field_filter: FieldFilter,
+    pub show_filter_modal: bool,
+    pub filter_fields_input: TextInputField,
+    pub filter_preset_name_input: TextInputField,
+    pub filter_presets: Vec<CopyFilterPreset>,
+    pub filter_presets_list_state: ListState,
 }
 
 impl Default for State {
@@ -22,10 +34,17 @@ impl Default for State {
             questionnaire_id: String::new(),
             questionnaire_name: String::new(),
             questionnaire: Resource::NotAsked,
+            copy_report: None,
             tree_state: TreeState::with_selection(),
             copy_name_input: TextInputField::new(),
             copy_code_input: TextInputField::new(),
             validation_error: None,
+            field_filter: FieldFilter::default(),
+            show_filter_modal: false,
+            filter_fields_input: TextInputField::new(),
+            filter_preset_name_input: TextInputField::new(),
+            filter_presets: Vec::new(),
+            filter_presets_list_state: ListState::new(),
         }
     }
 }
@@ -88,6 +107,20 @@ pub enum Msg {
     CopyCodeInputEvent(crate::tui::widgets::TextInputEvent),
     Continue,
     Back,
+
+    // Field filter presets
+    OpenFilterModal,
+    CloseFilterModal,
+    ToggleFilterMode,
+    FilterFieldsInputEvent(crate::tui::widgets::TextInputEvent),
+    FilterPresetNameInputEvent(crate::tui::widgets::TextInputEvent),
+    FilterPresetsLoaded(Vec<CopyFilterPreset>),
+    FilterPresetsListNavigate(crossterm::event::KeyCode),
+    FilterPresetsListSelect(usize),
+    FilterPresetsListActivate(usize),
+    SaveFilterPreset,
+    LoadFilterPreset,
+    DeleteFilterPreset,
 }
 
 pub struct CopyQuestionnaireParams {
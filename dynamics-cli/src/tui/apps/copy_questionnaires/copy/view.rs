@@ -1,7 +1,10 @@
-use super::models::State;
+use super::domain::CopyReport;
+use super::field_filter::{FieldFilter, FieldFilterMode};
+use super::models::{Msg, State};
 use super::tree_builder::build_snapshot_tree;
-use crate::tui::{Element, Resource, renderer::LayeredView};
-use crate::{col, row, use_constraints};
+use crate::tui::widgets::ListItem;
+use crate::tui::{Alignment as LayerAlignment, Element, FocusId, Resource, renderer::LayeredView};
+use crate::{button_row, col, row, spacer, use_constraints};
 use ratatui::{
     layout::Constraint as LayoutConstraint,
     prelude::Stylize,
@@ -23,7 +26,11 @@ pub fn render_view(state: &mut State) -> LayeredView<super::models::Msg> {
 
     let panel = Element::panel(content).title("Copy Questionnaire").build();
 
-    LayeredView::new(panel)
+    let mut view = LayeredView::new(panel);
+    if state.show_filter_modal {
+        view = view.with_app_modal(render_filter_modal(state), LayerAlignment::Center);
+    }
+    view
 }
 
 fn render_snapshot_summary(
@@ -41,6 +48,10 @@ fn render_snapshot_summary(
 
     // Build tree items from questionnaire
     let tree_items = build_snapshot_tree(questionnaire);
+    let report_panel = match state.copy_report {
+        Some(ref report) => render_copy_report(report, &state.field_filter, theme),
+        None => Element::text(""),
+    };
 
     if let Some(ref error) = state.validation_error {
         // Show validation error
@@ -72,16 +83,19 @@ fn render_snapshot_summary(
                 Span::styled(error.clone(), Style::default().fg(theme.text_primary)),
             ])).build() => Length(1),
 
-            // Tree widget wrapped in panel
-            Element::panel(
-                Element::tree("snapshot_tree", &tree_items, &mut state.tree_state, theme)
-                    .on_event(super::Msg::TreeEvent)
-                    .on_select(super::Msg::TreeNodeClicked)
-                    .on_render(super::Msg::ViewportHeight)
-                    .build()
-            )
-            .title("Questionnaire Structure")
-            .build() => Fill(1),
+            // Tree widget and dry-run report side by side
+            row![
+                Element::panel(
+                    Element::tree("snapshot_tree", &tree_items, &mut state.tree_state, theme)
+                        .on_event(super::Msg::TreeEvent)
+                        .on_select(super::Msg::TreeNodeClicked)
+                        .on_render(super::Msg::ViewportHeight)
+                        .build()
+                )
+                .title("Questionnaire Structure")
+                .build() => Fill(2),
+                report_panel => Fill(1),
+            ] => Fill(1),
 
             // Continue button
             Element::button("continue_button", "Continue")
@@ -112,16 +126,19 @@ fn render_snapshot_summary(
                 .build(),
             ] => Length(3),
 
-            // Tree widget wrapped in panel
-            Element::panel(
-                Element::tree("snapshot_tree", &tree_items, &mut state.tree_state, theme)
-                    .on_event(super::Msg::TreeEvent)
-                    .on_select(super::Msg::TreeNodeClicked)
-                    .on_render(super::Msg::ViewportHeight)
-                    .build()
-            )
-            .title("Questionnaire Structure")
-            .build() => Fill(1),
+            // Tree widget and dry-run report side by side
+            row![
+                Element::panel(
+                    Element::tree("snapshot_tree", &tree_items, &mut state.tree_state, theme)
+                        .on_event(super::Msg::TreeEvent)
+                        .on_select(super::Msg::TreeNodeClicked)
+                        .on_render(super::Msg::ViewportHeight)
+                        .build()
+                )
+                .title("Questionnaire Structure")
+                .build() => Fill(2),
+                report_panel => Fill(1),
+            ] => Fill(1),
 
             // Continue button
             Element::button("continue_button", "Continue")
@@ -131,6 +148,213 @@ fn render_snapshot_summary(
     }
 }
 
+/// Dry-run report: records the copy will create and fields it leaves behind
+fn render_copy_report(
+    report: &CopyReport,
+    filter: &FieldFilter,
+    theme: &crate::tui::Theme,
+) -> Element<super::models::Msg> {
+    let filter_summary = if filter == &FieldFilter::default() {
+        "all fields".to_string()
+    } else {
+        format!("{} ({})", filter.mode.label(), filter.fields.len())
+    };
+    let mut lines = vec![
+        Element::styled_text(Line::from(vec![
+            Span::styled("Field filter: ", Style::default().fg(theme.text_secondary)),
+            Span::styled(filter_summary, Style::default().fg(theme.text_primary)),
+            Span::styled("  Ctrl+F", Style::default().fg(theme.text_tertiary)),
+        ]))
+        .build(),
+        Element::text(""),
+        Element::styled_text(Line::from(vec![Span::styled(
+            format!("Will create {} records", report.total()),
+            Style::default().fg(theme.accent_primary).bold(),
+        )]))
+        .build(),
+    ];
+
+    for (entity, count) in &report.counts {
+        lines.push(
+            Element::styled_text(Line::from(vec![
+                Span::styled(
+                    format!("  {:<20}", entity),
+                    Style::default().fg(theme.text_secondary),
+                ),
+                Span::styled(count.to_string(), Style::default().fg(theme.text_primary)),
+            ]))
+            .build(),
+        );
+    }
+
+    lines.push(Element::text(""));
+    if report.skipped.is_empty() {
+        lines.push(
+            Element::styled_text(Line::from(vec![Span::styled(
+                "All populated fields will be copied",
+                Style::default().fg(theme.accent_success),
+            )]))
+            .build(),
+        );
+    } else {
+        lines.push(
+            Element::styled_text(Line::from(vec![Span::styled(
+                "Not copied",
+                Style::default().fg(theme.accent_warning).bold(),
+            )]))
+            .build(),
+        );
+        for skipped in &report.skipped {
+            lines.push(
+                Element::styled_text(Line::from(vec![
+                    Span::styled(
+                        format!("  {} · {}", skipped.entity, skipped.field),
+                        Style::default().fg(theme.text_primary),
+                    ),
+                    Span::styled(
+                        format!(" ({} records)", skipped.records),
+                        Style::default().fg(theme.text_tertiary),
+                    ),
+                ]))
+                .build(),
+            );
+        }
+    }
+
+    Element::panel(Element::column(lines).spacing(0).build())
+        .title("Copy Report")
+        .build()
+}
+
+/// Saved filter preset for display in the list
+#[derive(Clone)]
+struct FilterPresetItem {
+    name: String,
+    mode: &'static str,
+    fields: String,
+}
+
+impl ListItem for FilterPresetItem {
+    type Msg = Msg;
+
+    fn to_element(
+        &self,
+        is_selected: bool,
+        _is_multi_selected: bool,
+        _is_hovered: bool,
+    ) -> Element<Self::Msg> {
+        let theme = &crate::global_runtime_config().theme;
+
+        let mut builder = Element::styled_text(Line::from(vec![
+            Span::styled(self.name.clone(), Style::default().fg(theme.text_primary)),
+            Span::styled(
+                format!("  {} · {}", self.mode, self.fields),
+                Style::default().fg(theme.text_tertiary),
+            ),
+        ]));
+
+        if is_selected {
+            builder = builder.background(Style::default().bg(theme.bg_surface));
+        }
+
+        builder.build()
+    }
+}
+
+/// Field filter editor with save/load of named presets
+fn render_filter_modal(state: &State) -> Element<Msg> {
+    use_constraints!();
+    let theme = &crate::global_runtime_config().theme;
+
+    let preset_items: Vec<FilterPresetItem> = state
+        .filter_presets
+        .iter()
+        .map(|preset| FilterPresetItem {
+            name: preset.name.clone(),
+            mode: FieldFilterMode::from_key(&preset.mode).label(),
+            fields: preset.fields.join(", "),
+        })
+        .collect();
+
+    let mode_line = Element::styled_text(Line::from(vec![
+        Span::styled("Mode: ", Style::default().fg(theme.text_secondary)),
+        Span::styled(
+            state.field_filter.mode.label(),
+            Style::default().fg(theme.accent_primary).bold(),
+        ),
+        Span::styled(
+            "  (Ctrl+T to toggle, names are always copied)",
+            Style::default().fg(theme.text_tertiary),
+        ),
+    ]))
+    .build();
+
+    let fields_input = Element::panel(
+        Element::text_input(
+            FocusId::new("filter-fields-input"),
+            state.filter_fields_input.value(),
+            &state.filter_fields_input.state,
+        )
+        .placeholder("e.g., nrq_tooltip, nrq_regex")
+        .on_event(Msg::FilterFieldsInputEvent)
+        .build(),
+    )
+    .title("Listed Fields")
+    .build();
+
+    let name_input = Element::panel(
+        Element::text_input(
+            FocusId::new("filter-preset-name-input"),
+            state.filter_preset_name_input.value(),
+            &state.filter_preset_name_input.state,
+        )
+        .placeholder("e.g., Everything except validation")
+        .on_event(Msg::FilterPresetNameInputEvent)
+        .build(),
+    )
+    .title("Save Current Filter As")
+    .build();
+
+    let presets_list = Element::list(
+        FocusId::new("filter-presets-list"),
+        &preset_items,
+        &state.filter_presets_list_state,
+        theme,
+    )
+    .on_select(Msg::FilterPresetsListSelect)
+    .on_navigate(Msg::FilterPresetsListNavigate)
+    .on_activate(Msg::FilterPresetsListActivate)
+    .build();
+    let presets_panel = Element::panel(presets_list).title("Saved Presets").build();
+
+    let buttons = button_row![
+        ("filter-preset-save", "Save", Msg::SaveFilterPreset),
+        ("filter-preset-load", "Load", Msg::LoadFilterPreset),
+        ("filter-preset-delete", "Delete", Msg::DeleteFilterPreset),
+        ("filter-preset-close", "Close", Msg::CloseFilterModal),
+    ];
+
+    let modal_body = col![
+        Element::styled_text(Line::from(vec![Span::styled(
+            "Field Filter",
+            Style::default().fg(theme.accent_tertiary).bold(),
+        )]))
+        .build() => Length(1),
+        spacer!() => Length(1),
+        mode_line => Length(1),
+        fields_input => Length(3),
+        name_input => Length(3),
+        presets_panel => Fill(1),
+        spacer!() => Length(1),
+        buttons => Length(3),
+    ];
+
+    Element::panel(Element::container(modal_body).padding(2).build())
+        .width(80)
+        .height(28)
+        .build()
+}
+
 fn render_error(err: &str, theme: &crate::tui::Theme) -> Element<super::models::Msg> {
     Element::column(vec![
         Element::styled_text(Line::from(vec![
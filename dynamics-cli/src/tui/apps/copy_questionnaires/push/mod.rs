@@ -5,3 +5,4 @@ mod view;
 
 pub use app::PushQuestionnaireApp;
 pub use models::{Msg, PushQuestionnaireParams, State};
+pub(crate) use step_commands::field_specs;
@@ -269,13 +269,8 @@ pub fn extract_entity_id(
     // Primary method: Extract from OData-EntityId or Location header
     for (key, value) in &result.headers {
         if key.eq_ignore_ascii_case("odata-entityid") || key.eq_ignore_ascii_case("location") {
-            // Format: /entityset(guid) or https://host/api/data/v9.2/entityset(guid)
-            if let Some(start) = value.rfind('(') {
-                if let Some(end) = value.rfind(')') {
-                    if end > start {
-                        return Ok(value[start + 1..end].to_string());
-                    }
-                }
+            if let Some(id) = crate::api::operations::parse_entity_id_header(value) {
+                return Ok(id);
             }
             return Err(format!("Failed to parse {} header: {}", key, value));
         }
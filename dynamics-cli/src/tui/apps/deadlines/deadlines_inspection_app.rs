@@ -7,7 +7,7 @@ use ratatui::style::{Style, Stylize};
 use ratatui::text::{Line, Span};
 use std::collections::HashMap;
 
-use super::diff::diff_associations;
+use super::diff::{MergedDeadline, diff_associations, merge_preview};
 use super::models::{DeadlineMode, InspectionParams, TransformedDeadline};
 use super::operation_builder::{
     build_associate_operations, build_create_junction_operations, build_delete_junction_operations,
@@ -150,6 +150,8 @@ impl ModeFilter {
 pub struct State {
     entity_type: String,
     transformed_records: Vec<TransformedDeadline>,
+    /// Merged view of each record against its existing deadline (same order)
+    merged: Vec<MergedDeadline>,
     list_state: ListState,
     selected_record_idx: usize,
     /// Current mode filter
@@ -182,9 +184,12 @@ impl State {
             list_state.select_and_scroll(Some(0), filtered_indices.len());
         }
 
+        let merged = merge_preview(&transformed_records, &entity_type);
+
         Self {
             entity_type,
             transformed_records,
+            merged,
             list_state,
             selected_record_idx: 0,
             mode_filter,
@@ -546,7 +551,10 @@ impl App for DeadlinesInspectionApp {
 
         // Right panel: details for selected record
         let detail_content = if let Some(record) = state.selected_record() {
-            build_detail_panel(record, &state.entity_type)
+            let merged = state
+                .get_record_index(state.selected_record_idx)
+                .and_then(|idx| state.merged.get(idx));
+            build_detail_panel(record, merged, &state.entity_type)
         } else {
             col![
                 Element::styled_text(Line::from(vec![Span::styled(
@@ -692,7 +700,11 @@ impl App for DeadlinesInspectionApp {
 }
 
 /// Build the detail panel for a selected record
-fn build_detail_panel(record: &TransformedDeadline, entity_type: &str) -> Element<Msg> {
+fn build_detail_panel(
+    record: &TransformedDeadline,
+    merged: Option<&MergedDeadline>,
+    entity_type: &str,
+) -> Element<Msg> {
     let theme = &crate::global_runtime_config().theme;
     use crate::tui::element::ColumnBuilder;
 
@@ -746,100 +758,50 @@ fn build_detail_panel(record: &TransformedDeadline, entity_type: &str) -> Elemen
 
     // For Update records, show what will change
     if record.is_update() {
-        // Show field changes
-        if let Some(ref existing_fields) = record.existing_fields {
-            let mut field_changes: Vec<(String, String, String)> = Vec::new(); // (field_name, old_value, new_value)
-
-            // Check direct fields for changes
-            for (field_name, new_value) in &record.direct_fields {
-                let old_value = existing_fields
-                    .get(field_name)
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("")
-                    .to_string();
-
-                if new_value != &old_value {
-                    field_changes.push((field_name.clone(), old_value, new_value.clone()));
-                }
-            }
-
-            // Check picklist fields
-            for (field_name, new_value) in &record.picklist_fields {
-                let old_value = existing_fields
-                    .get(field_name)
-                    .and_then(|v| v.as_i64())
-                    .map(|v| v as i32);
-
-                if old_value != Some(*new_value) {
-                    field_changes.push((
-                        field_name.clone(),
-                        old_value
-                            .map(|v| v.to_string())
-                            .unwrap_or_else(|| "null".to_string()),
-                        new_value.to_string(),
-                    ));
-                }
-            }
+        // Show field changes from the merge preview
+        let field_changes = merged.map(|m| m.changes.as_slice()).unwrap_or_default();
+        if !field_changes.is_empty() {
+            builder = builder.add(
+                Element::styled_text(Line::from(vec![Span::styled(
+                    "📝 Field Changes",
+                    Style::default().fg(theme.accent_info).bold(),
+                )]))
+                .build(),
+                Length(1),
+            );
 
-            // Check boolean fields
-            for (field_name, new_value) in &record.boolean_fields {
-                let old_value = existing_fields.get(field_name).and_then(|v| v.as_bool());
-
-                if old_value != Some(*new_value) {
-                    field_changes.push((
-                        field_name.clone(),
-                        old_value
-                            .map(|v| v.to_string())
-                            .unwrap_or_else(|| "null".to_string()),
-                        new_value.to_string(),
-                    ));
-                }
-            }
+            for change in field_changes {
+                let old_display = match change.old.as_deref() {
+                    None | Some("") => "(empty)",
+                    Some(old) => old,
+                };
+                let new_display = if change.new.is_empty() {
+                    "(empty)"
+                } else {
+                    change.new.as_str()
+                };
 
-            if !field_changes.is_empty() {
                 builder = builder.add(
-                    Element::styled_text(Line::from(vec![Span::styled(
-                        "📝 Field Changes",
-                        Style::default().fg(theme.accent_info).bold(),
-                    )]))
+                    Element::styled_text(Line::from(vec![
+                        Span::styled(
+                            format!("  {}: ", change.field),
+                            Style::default().fg(theme.text_tertiary),
+                        ),
+                        Span::styled(
+                            old_display.to_string(),
+                            Style::default().fg(theme.accent_error),
+                        ),
+                        Span::styled(" → ", Style::default().fg(theme.text_tertiary)),
+                        Span::styled(
+                            new_display.to_string(),
+                            Style::default().fg(theme.accent_success),
+                        ),
+                    ]))
                     .build(),
                     Length(1),
                 );
-
-                for (field_name, old_val, new_val) in &field_changes {
-                    let old_display = if old_val.is_empty() {
-                        "(empty)"
-                    } else {
-                        old_val.as_str()
-                    };
-                    let new_display = if new_val.is_empty() {
-                        "(empty)"
-                    } else {
-                        new_val.as_str()
-                    };
-
-                    builder = builder.add(
-                        Element::styled_text(Line::from(vec![
-                            Span::styled(
-                                format!("  {}: ", field_name),
-                                Style::default().fg(theme.text_tertiary),
-                            ),
-                            Span::styled(
-                                old_display.to_string(),
-                                Style::default().fg(theme.accent_error),
-                            ),
-                            Span::styled(" → ", Style::default().fg(theme.text_tertiary)),
-                            Span::styled(
-                                new_display.to_string(),
-                                Style::default().fg(theme.accent_success),
-                            ),
-                        ]))
-                        .build(),
-                        Length(1),
-                    );
-                }
-                builder = builder.add(spacer!(), Length(1));
             }
+            builder = builder.add(spacer!(), Length(1));
         }
 
         if let Some(ref existing_assoc) = record.existing_associations {
@@ -1480,14 +1442,8 @@ fn extract_entity_guid_from_result(
     // Try headers first (OData-EntityId or Location)
     for (key, value) in &result.headers {
         if key.eq_ignore_ascii_case("odata-entityid") || key.eq_ignore_ascii_case("location") {
-            // Format: /entityset(guid) or https://host/api/data/v9.2/entityset(guid)
-            // Extract GUID using regex
-            if let Some(start) = value.rfind('(') {
-                if let Some(end) = value.rfind(')') {
-                    if end > start {
-                        return Some(value[start + 1..end].to_string());
-                    }
-                }
+            if let Some(id) = crate::api::operations::parse_entity_id_header(value) {
+                return Some(id);
             }
         }
     }
@@ -1251,7 +1251,12 @@ fn try_run_matching(state: &mut State) -> bool {
             state.transformed_records.len(),
             lookup_map.len()
         );
-        match_all_deadlines(&mut state.transformed_records, lookup_map, &entity_type);
+        match_all_deadlines(
+            &mut state.transformed_records,
+            lookup_map,
+            &entity_type,
+            &state.match_key,
+        );
     } else {
         // No existing deadlines loaded - all records are Create mode (already the default)
         log::info!(
@@ -1366,6 +1371,7 @@ pub struct State {
     existing_deadlines_loading: bool,
     existing_deadlines_lookup: Option<DeadlineLookupMap>,
     // Matching status
+    match_key: field_mappings::MatchKey,
     matching_complete: bool,
     // Mode counts (after matching)
     create_count: usize,
@@ -1398,6 +1404,7 @@ impl State {
             rows_with_warnings: 0,
             existing_deadlines_loading: false,
             existing_deadlines_lookup: None,
+            match_key: field_mappings::MatchKey::default(),
             matching_complete: false,
             create_count: 0,
             update_count: 0,
@@ -1416,6 +1423,7 @@ impl Default for State {
 #[derive(Clone)]
 pub enum Msg {
     EnvironmentLoaded(Option<String>),
+    MatchKeyLoaded(field_mappings::MatchKey),
     EntitiesLoaded(Result<Vec<String>, String>),
     EntitySelectorEvent(SelectEvent),
     StartDataLoading,
@@ -1445,6 +1453,10 @@ impl App for DeadlinesMappingApp {
                 },
                 Msg::EnvironmentLoaded,
             ),
+            Command::perform(
+                field_mappings::MatchKey::load_from_options(),
+                Msg::MatchKeyLoaded,
+            ),
             Command::perform_parallel()
                 .add_task("Loading entities".to_string(), async move {
                     use crate::api::metadata::parse_entity_list;
@@ -1502,6 +1514,10 @@ impl App for DeadlinesMappingApp {
                 state.current_environment = env;
                 Command::None
             }
+            Msg::MatchKeyLoaded(match_key) => {
+                state.match_key = match_key;
+                Command::None
+            }
             Msg::EntitiesLoaded(result) => {
                 state.entities = Resource::from_result(result);
 
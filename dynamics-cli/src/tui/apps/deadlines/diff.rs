@@ -5,11 +5,13 @@
 //! - Diffing fields to detect changes
 //! - Diffing N:N associations to determine add/remove operations
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
+use chrono::NaiveDate;
+
+use super::field_mappings::{MatchField, MatchKey};
 use super::models::{
-    DeadlineLookupKey, DeadlineLookupMap, DeadlineMode, ExistingAssociations, ExistingDeadline,
-    TransformedDeadline,
+    DeadlineLookupMap, DeadlineMode, ExistingAssociations, ExistingDeadline, TransformedDeadline,
 };
 
 /// Result of matching and diffing a single record
@@ -66,21 +68,107 @@ impl AssociationDiff {
     }
 }
 
-/// Match a transformed deadline against the existing deadlines lookup map.
+/// Existing deadlines grouped by their match key values
+pub type MatchIndex<'a> = HashMap<Vec<String>, Vec<&'a ExistingDeadline>>;
+
+/// Group existing deadlines by the configured match key.
+///
+/// Deadlines missing one of the key fields can't be matched and are left out.
+pub fn build_match_index<'a>(
+    lookup_map: &'a DeadlineLookupMap,
+    match_key: &MatchKey,
+) -> MatchIndex<'a> {
+    let mut index = MatchIndex::new();
+    for existing in lookup_map.values().flatten() {
+        if let Some(values) = existing_key_values(existing, match_key) {
+            index.entry(values).or_default().push(existing);
+        }
+    }
+    index
+}
+
+/// Match key values of a transformed record (None if a key field is missing)
+fn record_key_values(
+    transformed: &TransformedDeadline,
+    entity_type: &str,
+    match_key: &MatchKey,
+) -> Option<Vec<String>> {
+    match_key
+        .fields
+        .iter()
+        .map(|field| {
+            let value = match field {
+                MatchField::Name => transformed.get_deadline_name(entity_type)?.to_string(),
+                MatchField::Date => transformed.deadline_date?.to_string(),
+                MatchField::Field(name) => transformed
+                    .direct_fields
+                    .get(name)
+                    .cloned()
+                    .or_else(|| {
+                        transformed
+                            .lookup_fields
+                            .get(name)
+                            .map(|(guid, _)| guid.clone())
+                    })
+                    .or_else(|| transformed.picklist_fields.get(name).map(|v| v.to_string()))
+                    .or_else(|| transformed.boolean_fields.get(name).map(|v| v.to_string()))?,
+            };
+            Some(value.trim().to_lowercase())
+        })
+        .collect()
+}
+
+/// Match key values of an existing deadline (None if a key field is missing)
+fn existing_key_values(existing: &ExistingDeadline, match_key: &MatchKey) -> Option<Vec<String>> {
+    match_key
+        .fields
+        .iter()
+        .map(|field| {
+            let value = match field {
+                MatchField::Name => existing.name.clone(),
+                MatchField::Date => existing.date.to_string(),
+                MatchField::Field(name) => existing
+                    .fields
+                    .get(name)
+                    .or_else(|| {
+                        existing
+                            .fields
+                            .get(&format!("_{}_value", name.to_lowercase()))
+                    })
+                    .and_then(json_display)?,
+            };
+            Some(value.trim().to_lowercase())
+        })
+        .collect()
+}
+
+/// Human-readable key for logs and errors, e.g. "name=spring call, date=2026-03-01"
+fn describe_key(match_key: &MatchKey, values: &[String]) -> String {
+    match_key
+        .fields
+        .iter()
+        .zip(values)
+        .map(|(field, value)| format!("{}={}", field.label(), value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Match a transformed deadline against existing deadlines grouped by match key.
 ///
+/// Build `index` once per import with `build_match_index`.
 /// Returns the appropriate mode and existing record info if matched.
 pub fn match_deadline(
     transformed: &TransformedDeadline,
-    lookup_map: &DeadlineLookupMap,
+    index: &MatchIndex,
     entity_type: &str,
+    match_key: &MatchKey,
 ) -> MatchResult {
-    // Get the deadline name and date for lookup
-    let name = match transformed.get_deadline_name(entity_type) {
-        Some(n) => n.trim().to_lowercase(),
+    let values = match record_key_values(transformed, entity_type, match_key) {
+        Some(values) => values,
         None => {
-            // No name - can't match, treat as create
+            // Missing a key field - can't match, treat as create
             log::debug!(
-                "Row {}: No deadline name found, treating as Create",
+                "Row {}: Missing a match key field, treating as Create",
                 transformed.source_row
             );
             return MatchResult {
@@ -91,36 +179,16 @@ pub fn match_deadline(
             };
         }
     };
+    let key = describe_key(match_key, &values);
 
-    let date = match transformed.deadline_date {
-        Some(d) => d,
-        None => {
-            // No date - can't match, treat as create
-            log::debug!(
-                "Row {}: No deadline date found, treating as Create",
-                transformed.source_row
-            );
-            return MatchResult {
-                mode: DeadlineMode::Create,
-                existing_guid: None,
-                existing_fields: None,
-                existing_associations: None,
-            };
-        }
-    };
-
-    // Build lookup key (name, date)
-    let key: DeadlineLookupKey = (name.clone(), date);
-
-    // Look up in map
-    let candidates = match lookup_map.get(&key) {
+    // Look up in index
+    let candidates = match index.get(&values) {
         None => {
             // No match - create new
             log::debug!(
-                "Row {}: No existing deadline found for ({}, {}), mode=Create",
+                "Row {}: No existing deadline found for ({}), mode=Create",
                 transformed.source_row,
-                name,
-                date
+                key
             );
             return MatchResult {
                 mode: DeadlineMode::Create,
@@ -134,17 +202,15 @@ pub fn match_deadline(
 
     // Single candidate - use it directly
     if candidates.len() == 1 {
-        let existing = &candidates[0];
-        return build_match_result(transformed, existing, entity_type);
+        return build_match_result(transformed, candidates[0], entity_type);
     }
 
     // Multiple candidates - try secondary matching
     log::debug!(
-        "Row {}: Found {} candidates for ({}, {}), attempting secondary match",
+        "Row {}: Found {} candidates for ({}), attempting secondary match",
         transformed.source_row,
         candidates.len(),
-        name,
-        date
+        key
     );
 
     // Extract description from transformed record
@@ -212,18 +278,16 @@ pub fn match_deadline(
 
     // No unique match found - report error
     log::warn!(
-        "Row {}: Multiple candidates ({}) for ({}, {}), could not narrow down. Treating as Create.",
+        "Row {}: Multiple candidates ({}) for ({}), could not narrow down. Treating as Create.",
         transformed.source_row,
         candidates.len(),
-        name,
-        date
+        key
     );
     MatchResult {
         mode: DeadlineMode::Error(format!(
-            "Multiple existing deadlines ({}) match (name={}, date={})",
+            "Multiple existing deadlines ({}) match ({})",
             candidates.len(),
-            name,
-            date
+            key
         )),
         existing_guid: None,
         existing_fields: None,
@@ -277,13 +341,18 @@ fn build_match_result(
 }
 
 /// Match all transformed records against existing deadlines and update their modes.
+///
+/// Rows and existing deadlines are paired by `match_key`; the associations of
+/// the deadline each row matches are what `diff_associations` compares against.
 pub fn match_all_deadlines(
     records: &mut [TransformedDeadline],
     lookup_map: &DeadlineLookupMap,
     entity_type: &str,
+    match_key: &MatchKey,
 ) {
+    let index = build_match_index(lookup_map, match_key);
     for record in records.iter_mut() {
-        let result = match_deadline(record, lookup_map, entity_type);
+        let result = match_deadline(record, &index, entity_type, match_key);
         record.mode = result.mode;
         record.existing_guid = result.existing_guid;
         record.existing_fields = result.existing_fields;
@@ -313,28 +382,7 @@ fn diff_fields(
     existing: &ExistingDeadline,
     entity_type: &str,
 ) -> bool {
-    let is_cgk = entity_type == "cgk_deadline";
-
-    // Non-editable fields that we skip in diff
-    let non_editable: HashSet<&str> = if is_cgk {
-        [
-            "cgk_deadlinename",
-            "cgk_date",
-            "cgk_datumcommissievergadering",
-        ]
-        .iter()
-        .copied()
-        .collect()
-    } else {
-        [
-            "nrq_deadlinename",
-            "nrq_deadlinedate",
-            "nrq_committeemeetingdate",
-        ]
-        .iter()
-        .copied()
-        .collect()
-    };
+    let non_editable = non_editable_fields(entity_type);
 
     // Check direct fields
     for (field_name, new_value) in &transformed.direct_fields {
@@ -427,6 +475,148 @@ fn diff_fields(
     false
 }
 
+/// Fields used to match deadlines, which updates never change
+fn non_editable_fields(entity_type: &str) -> HashSet<&'static str> {
+    if entity_type == "cgk_deadline" {
+        [
+            "cgk_deadlinename",
+            "cgk_date",
+            "cgk_datumcommissievergadering",
+        ]
+        .into_iter()
+        .collect()
+    } else {
+        [
+            "nrq_deadlinename",
+            "nrq_deadlinedate",
+            "nrq_committeemeetingdate",
+        ]
+        .into_iter()
+        .collect()
+    }
+}
+
+/// What applying the file will do to one deadline
+///
+/// The import never deletes, so existing deadlines without a row in the file
+/// aren't part of the merged view.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MergeOutcome {
+    Create,
+    Update,
+    Unchanged,
+    Error(String),
+}
+
+/// A field whose value the file changes on an existing deadline
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: String,
+}
+
+/// One file row in the merged view of file and existing deadlines
+#[derive(Clone, Debug)]
+pub struct MergedDeadline {
+    pub outcome: MergeOutcome,
+    /// Excel row the deadline comes from
+    pub source_row: usize,
+    /// GUID of the matched existing deadline
+    pub existing_guid: Option<String>,
+    pub name: String,
+    pub date: Option<NaiveDate>,
+    /// Field values after the merge
+    pub fields: BTreeMap<String, String>,
+    /// Fields the file changes (only for updates)
+    pub changes: Vec<FieldChange>,
+}
+
+/// Build the merged view of records already run through `match_all_deadlines`,
+/// one entry per record in file order.
+pub fn merge_preview(records: &[TransformedDeadline], entity_type: &str) -> Vec<MergedDeadline> {
+    records
+        .iter()
+        .map(|record| merged_record(record, entity_type))
+        .collect()
+}
+
+/// Merged view of a matched file row
+fn merged_record(record: &TransformedDeadline, entity_type: &str) -> MergedDeadline {
+    // Values the file writes, keyed as they appear on the existing record
+    let mut written: Vec<(String, String, String)> = Vec::new(); // (field, existing key, value)
+    for (field, value) in &record.direct_fields {
+        written.push((field.clone(), field.clone(), value.clone()));
+    }
+    for (field, (guid, _)) in &record.lookup_fields {
+        let key = format!("_{}_value", field.to_lowercase());
+        written.push((field.clone(), key, guid.to_lowercase()));
+    }
+    for (field, value) in &record.picklist_fields {
+        written.push((field.clone(), field.clone(), value.to_string()));
+    }
+    for (field, value) in &record.boolean_fields {
+        written.push((field.clone(), field.clone(), value.to_string()));
+    }
+    written.sort();
+
+    let outcome = match &record.mode {
+        DeadlineMode::Create => MergeOutcome::Create,
+        DeadlineMode::Update => MergeOutcome::Update,
+        DeadlineMode::Unchanged => MergeOutcome::Unchanged,
+        DeadlineMode::Error(msg) => MergeOutcome::Error(msg.clone()),
+    };
+
+    let mut changes = Vec::new();
+    if outcome == MergeOutcome::Update {
+        let non_editable = non_editable_fields(entity_type);
+        let existing_fields = record.existing_fields.as_ref();
+        for (field, key, new) in &written {
+            if non_editable.contains(field.as_str()) {
+                continue;
+            }
+            let old = existing_fields
+                .and_then(|fields| fields.get(key))
+                .and_then(json_display);
+            let unchanged = old
+                .as_deref()
+                .is_some_and(|old| old.eq_ignore_ascii_case(new));
+            if !unchanged {
+                changes.push(FieldChange {
+                    field: field.clone(),
+                    old,
+                    new: new.clone(),
+                });
+            }
+        }
+    }
+
+    MergedDeadline {
+        outcome,
+        source_row: record.source_row,
+        existing_guid: record.existing_guid.clone(),
+        name: record
+            .get_deadline_name(entity_type)
+            .unwrap_or_default()
+            .to_string(),
+        date: record.deadline_date,
+        fields: written
+            .into_iter()
+            .map(|(field, _, value)| (field, value))
+            .collect(),
+        changes,
+    }
+}
+
+/// Display form of an existing field value (None for nulls)
+fn json_display(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
 /// Diff associations between Excel-derived IDs and existing associations.
 ///
 /// Returns what needs to be added and removed for each relationship.
@@ -612,7 +802,8 @@ mod tests {
         );
         let lookup_map = DeadlineLookupMap::new();
 
-        let result = match_deadline(&transformed, &lookup_map, "cgk_deadline");
+        let index = build_match_index(&lookup_map, &MatchKey::default());
+        let result = match_deadline(&transformed, &index, "cgk_deadline", &MatchKey::default());
 
         assert_eq!(result.mode, DeadlineMode::Create);
         assert!(result.existing_guid.is_none());
@@ -627,7 +818,8 @@ mod tests {
         let mut lookup_map = DeadlineLookupMap::new();
         lookup_map.insert(("test deadline".to_string(), date), vec![existing]);
 
-        let result = match_deadline(&transformed, &lookup_map, "cgk_deadline");
+        let index = build_match_index(&lookup_map, &MatchKey::default());
+        let result = match_deadline(&transformed, &index, "cgk_deadline", &MatchKey::default());
 
         assert_eq!(result.mode, DeadlineMode::Unchanged);
         assert_eq!(result.existing_guid, Some("guid-123".to_string()));
@@ -667,4 +859,147 @@ mod tests {
         assert!(diff.support_to_add.is_empty());
         assert!(diff.support_to_remove.contains("support-1"));
     }
+
+    #[test]
+    fn test_match_on_alternate_key_diffs_matched_associations() {
+        let date = NaiveDate::from_ymd_opt(2026, 5, 1).unwrap();
+
+        // Renamed in the file, but the info text still identifies it
+        let mut existing = make_existing("guid-ref", "Old Name", date);
+        existing
+            .fields
+            .insert("cgk_info".to_string(), serde_json::json!("REF-042"));
+        existing.associations.support_ids = ["support-1".to_string()].into_iter().collect();
+        let mut lookup_map = DeadlineLookupMap::new();
+        lookup_map.insert(("old name".to_string(), date), vec![existing]);
+
+        let mut record = make_transformed("New Name", date, "cgk_deadline");
+        record
+            .direct_fields
+            .insert("cgk_info".to_string(), " ref-042 ".to_string());
+        record.checkbox_relationships.insert(
+            "cgk_deadline_cgk_support".to_string(),
+            vec!["support-2".to_string()],
+        );
+
+        // The default (name, date) key doesn't find it
+        let mut records = vec![record.clone()];
+        match_all_deadlines(
+            &mut records,
+            &lookup_map,
+            "cgk_deadline",
+            &MatchKey::default(),
+        );
+        assert_eq!(records[0].mode, DeadlineMode::Create);
+
+        let match_key = MatchKey::composite(vec![
+            MatchField::Field("cgk_info".to_string()),
+            MatchField::Date,
+        ]);
+        let mut records = vec![record];
+        match_all_deadlines(&mut records, &lookup_map, "cgk_deadline", &match_key);
+
+        let matched = &records[0];
+        assert_eq!(matched.mode, DeadlineMode::Update);
+        assert_eq!(matched.existing_guid.as_deref(), Some("guid-ref"));
+
+        let diff = diff_associations(
+            matched,
+            matched.existing_associations.as_ref().unwrap(),
+            "cgk_deadline",
+        );
+        assert_eq!(
+            diff.support_to_add,
+            ["support-2".to_string()].into_iter().collect()
+        );
+        assert_eq!(
+            diff.support_to_remove,
+            ["support-1".to_string()].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn test_merge_preview_covers_every_outcome() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let key = |name: &str| (name.to_lowercase(), date);
+
+        let mut changed = make_existing("guid-update", "Spring Call", date);
+        changed
+            .fields
+            .insert("cgk_info".to_string(), serde_json::json!("Old info"));
+        let mut same = make_existing("guid-same", "Summer Call", date);
+        same.fields
+            .insert("cgk_info".to_string(), serde_json::json!("Same info"));
+
+        let mut lookup_map = DeadlineLookupMap::new();
+        lookup_map.insert(key("Spring Call"), vec![changed]);
+        lookup_map.insert(key("Summer Call"), vec![same]);
+        // Not in the file: the import leaves it alone
+        lookup_map.insert(
+            key("Autumn Call"),
+            vec![make_existing("guid-untouched", "Autumn Call", date)],
+        );
+
+        let with_info = |name: &str, info: &str| {
+            let mut record = make_transformed(name, date, "cgk_deadline");
+            record
+                .direct_fields
+                .insert("cgk_info".to_string(), info.to_string());
+            record
+        };
+        let mut records = vec![
+            make_transformed("New Call", date, "cgk_deadline"),
+            with_info("Spring Call", "New info"),
+            with_info("Summer Call", "Same info"),
+        ];
+
+        match_all_deadlines(
+            &mut records,
+            &lookup_map,
+            "cgk_deadline",
+            &MatchKey::default(),
+        );
+        let merged = merge_preview(&records, "cgk_deadline");
+        let outcomes: Vec<_> = merged
+            .iter()
+            .map(|m| (m.name.as_str(), m.outcome.clone()))
+            .collect();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                ("New Call", MergeOutcome::Create),
+                ("Spring Call", MergeOutcome::Update),
+                ("Summer Call", MergeOutcome::Unchanged),
+            ]
+        );
+        assert!(
+            merged
+                .iter()
+                .all(|m| m.existing_guid.as_deref() != Some("guid-untouched"))
+        );
+
+        let create = &merged[0];
+        assert_eq!(create.existing_guid, None);
+        assert!(create.changes.is_empty());
+
+        let update = &merged[1];
+        assert_eq!(update.existing_guid.as_deref(), Some("guid-update"));
+        assert_eq!(
+            update.fields.get("cgk_info").map(String::as_str),
+            Some("New info")
+        );
+        assert_eq!(
+            update.changes,
+            vec![FieldChange {
+                field: "cgk_info".to_string(),
+                old: Some("Old info".to_string()),
+                new: "New info".to_string(),
+            }]
+        );
+
+        let unchanged = &merged[2];
+        assert_eq!(unchanged.existing_guid.as_deref(), Some("guid-same"));
+        assert!(unchanged.changes.is_empty());
+    }
 }
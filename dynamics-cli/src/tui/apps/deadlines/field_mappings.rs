@@ -384,10 +384,108 @@ pub fn get_constant_fields(entity_name: &str) -> HashMap<String, serde_json::Val
     }
 }
 
+/// One component of the key that matches Excel rows to existing deadlines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchField {
+    /// The deadline name (cgk_deadlinename or nrq_deadlinename)
+    Name,
+    /// The deadline date (date portion only)
+    Date,
+    /// Any other mapped field, compared as trimmed lowercase text
+    /// Lookups compare by GUID
+    Field(String),
+}
+
+impl MatchField {
+    /// Label used in match logs and error messages
+    pub fn label(&self) -> &str {
+        match self {
+            MatchField::Name => "name",
+            MatchField::Date => "date",
+            MatchField::Field(field) => field,
+        }
+    }
+}
+
+/// The natural key used to match deadlines - a single field or a composite
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchKey {
+    pub fields: Vec<MatchField>,
+}
+
+impl MatchKey {
+    /// Match on a single field
+    pub fn single(field: MatchField) -> Self {
+        Self {
+            fields: vec![field],
+        }
+    }
+
+    /// Match on several fields that must all be equal
+    pub fn composite(fields: Vec<MatchField>) -> Self {
+        Self { fields }
+    }
+
+    /// Parse a comma-separated field list such as "name, date" or "cgk_code".
+    /// `name` and `date` select the deadline name and date; anything else is a field name.
+    /// An empty list falls back to the default key.
+    pub fn parse(value: &str) -> Self {
+        let fields: Vec<MatchField> = value
+            .split(',')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .map(|field| match field.to_lowercase().as_str() {
+                "name" => MatchField::Name,
+                "date" => MatchField::Date,
+                _ => MatchField::Field(field.to_string()),
+            })
+            .collect();
+        if fields.is_empty() {
+            Self::default()
+        } else {
+            Self::composite(fields)
+        }
+    }
+
+    /// Load the configured match key from the `deadlines.match_key` option
+    pub async fn load_from_options() -> Self {
+        crate::global_config()
+            .options
+            .get_string("deadlines.match_key")
+            .await
+            .map(|value| Self::parse(&value))
+            .unwrap_or_default()
+    }
+}
+
+impl Default for MatchKey {
+    /// Name and date, which identify a deadline in both CGK and NRQ
+    fn default() -> Self {
+        Self::composite(vec![MatchField::Name, MatchField::Date])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_match_key() {
+        assert_eq!(MatchKey::parse("name, date"), MatchKey::default());
+        assert_eq!(
+            MatchKey::parse("Name, cgk_code"),
+            MatchKey::composite(vec![
+                MatchField::Name,
+                MatchField::Field("cgk_code".to_string())
+            ])
+        );
+        assert_eq!(
+            MatchKey::parse(" cgk_code "),
+            MatchKey::single(MatchField::Field("cgk_code".to_string()))
+        );
+        assert_eq!(MatchKey::parse(" , "), MatchKey::default());
+    }
+
     #[test]
     fn test_detect_cgk_entity() {
         let entities = vec!["cgk_pillar".to_string(), "cgk_deadline".to_string()];
@@ -10,6 +10,9 @@ pub mod operation_builder;
 pub use deadlines_file_select_app::{DeadlinesFileSelectApp, State as DeadlinesFileSelectState};
 pub use deadlines_inspection_app::{DeadlinesInspectionApp, State as DeadlinesInspectionState};
 pub use deadlines_mapping_app::{DeadlinesMappingApp, State as DeadlinesMappingState};
-pub use diff::{AssociationDiff, diff_associations, match_all_deadlines};
+pub use diff::{
+    AssociationDiff, MergeOutcome, MergedDeadline, diff_associations, match_all_deadlines,
+    merge_preview,
+};
 pub use existing_deadlines::fetch_existing_deadlines;
 pub use models::{DeadlineMode, InspectionParams, MappingParams};
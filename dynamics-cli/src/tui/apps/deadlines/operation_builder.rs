@@ -201,6 +201,7 @@ impl TransformedDeadline {
             entity: entity_set,
             id: entity_guid,
             data: payload,
+            if_match: None,
         }]
     }
 
@@ -1,7 +1,7 @@
 use crate::api::models::{CredentialSet, Environment as ApiEnvironment};
 use crate::tui::apps::screens::ErrorScreenParams;
 use crate::tui::renderer::LayeredView;
-use crate::tui::widgets::{SelectEvent, SelectField, TextInputEvent, TextInputField};
+use crate::tui::widgets::{InputMask, SelectEvent, SelectField, TextInputEvent, TextInputField};
 use crate::tui::{App, AppId, Command, Element, FocusId, Resource, Subscription, Theme};
 use crate::{col, row, spacer, use_constraints};
 use crossterm::event::KeyCode;
@@ -27,6 +27,8 @@ pub struct State {
     env_name_field: TextInputField,
     env_host_field: TextInputField,
     env_creds_selector: SelectField,
+    /// Entity to clear the cache for; blank clears the whole environment
+    env_cache_entity_field: TextInputField,
     active_env_selector: SelectField,
 
     // Credential panel
@@ -45,6 +47,9 @@ pub struct State {
     data_load_state: Resource<()>,
     env_save_state: Resource<()>,
     env_delete_state: Resource<()>,
+    env_cache_clear_state: Resource<()>,
+    /// Outcome of the last connection test, with latency
+    env_connection_test_state: Resource<String>,
     cred_save_state: Resource<()>,
     cred_delete_state: Resource<()>,
     set_current_state: Resource<()>,
@@ -69,6 +74,7 @@ impl State {
             env_name_field: TextInputField::new(),
             env_host_field: TextInputField::new(),
             env_creds_selector: SelectField::new(),
+            env_cache_entity_field: TextInputField::new(),
             active_env_selector: SelectField::new(),
 
             cred_selector: SelectField::new(),
@@ -76,7 +82,7 @@ impl State {
             cred_type_selector: SelectField::new(),
             cred_username_field: TextInputField::new(),
             cred_password_field: TextInputField::new(),
-            cred_client_id_field: TextInputField::new(),
+            cred_client_id_field: TextInputField::new().with_mask(InputMask::GUID),
             cred_client_secret_field: TextInputField::new(),
             cred_tenant_id_field: TextInputField::new(),
             cred_cert_path_field: TextInputField::new(),
@@ -84,6 +90,8 @@ impl State {
             data_load_state: Resource::NotAsked,
             env_save_state: Resource::NotAsked,
             env_delete_state: Resource::NotAsked,
+            env_cache_clear_state: Resource::NotAsked,
+            env_connection_test_state: Resource::NotAsked,
             cred_save_state: Resource::NotAsked,
             cred_delete_state: Resource::NotAsked,
             set_current_state: Resource::NotAsked,
@@ -155,6 +163,11 @@ pub enum Msg {
     EnvironmentSaved(Result<(), String>),
     DeleteEnvironment,
     EnvironmentDeleted(Result<(), String>),
+    CacheEntityChanged(TextInputEvent),
+    ClearEnvironmentCache,
+    EnvironmentCacheCleared(Result<(), String>),
+    TestConnection,
+    ConnectionTested(String, Result<String, String>),
     NewEnvironment,
 
     // Credential selector
@@ -359,6 +372,8 @@ impl App for EnvironmentSelectorApp {
                             .env_creds_selector
                             .set_value(Some(env.credentials_ref.clone()));
                         state.env_panel_dirty = false;
+                        state.env_cache_clear_state = Resource::NotAsked;
+                        state.env_connection_test_state = Resource::NotAsked;
                     }
                 }
 
@@ -374,6 +389,8 @@ impl App for EnvironmentSelectorApp {
                         .env_creds_selector
                         .set_value(Some(env.credentials_ref.clone()));
                     state.env_panel_dirty = false;
+                    state.env_cache_clear_state = Resource::NotAsked;
+                    state.env_connection_test_state = Resource::NotAsked;
                 }
                 Command::None
             }
@@ -588,6 +605,73 @@ impl App for EnvironmentSelectorApp {
                 Command::None
             }
 
+            Msg::CacheEntityChanged(event) => {
+                state.env_cache_entity_field.handle_event(event, None);
+                Command::None
+            }
+
+            Msg::ClearEnvironmentCache => {
+                if let Some(env_name) = state.env_selector.value() {
+                    let env_name = env_name.to_string();
+                    let entity_name = state.env_cache_entity_field.value().trim().to_string();
+                    state.env_cache_clear_state = Resource::Loading;
+
+                    Command::perform(
+                        async move {
+                            let config = crate::global_config();
+                            let result = if entity_name.is_empty() {
+                                config.clear_environment_caches(&env_name).await
+                            } else {
+                                config.clear_entity_caches(&env_name, &entity_name).await
+                            };
+                            result.map_err(|e| e.to_string())
+                        },
+                        Msg::EnvironmentCacheCleared,
+                    )
+                } else {
+                    Command::None
+                }
+            }
+
+            Msg::EnvironmentCacheCleared(Ok(())) => {
+                state.env_cache_clear_state = Resource::Success(());
+                log::info!("Cleared entity caches for selected environment");
+                Command::None
+            }
+
+            Msg::EnvironmentCacheCleared(Err(err)) => {
+                state.env_cache_clear_state = Resource::Failure(err.clone());
+                log::error!("Failed to clear environment cache: {}", err);
+                Command::None
+            }
+
+            Msg::TestConnection => {
+                if let Some(env_name) = state.env_selector.value() {
+                    let env_name = env_name.to_string();
+                    state.env_connection_test_state = Resource::Loading;
+
+                    Command::perform(test_connection(env_name.clone()), move |result| {
+                        Msg::ConnectionTested(env_name.clone(), result)
+                    })
+                } else {
+                    Command::None
+                }
+            }
+
+            Msg::ConnectionTested(env_name, result) => {
+                match &result {
+                    Ok(summary) => {
+                        log::info!("Connection test for {} succeeded: {}", env_name, summary)
+                    }
+                    Err(err) => log::warn!("Connection test for {} failed: {}", env_name, err),
+                }
+                // Drop results for an environment that is no longer selected
+                if state.env_selector.value() == Some(env_name.as_str()) {
+                    state.env_connection_test_state = Resource::from_result(result);
+                }
+                Command::None
+            }
+
             Msg::CredSelectorEvent(event) => {
                 let (cmd, selection) = state.cred_selector.handle_event(event, &state.credentials);
 
@@ -800,6 +884,11 @@ impl App for EnvironmentSelectorApp {
                     return Command::None;
                 }
 
+                if let Some(err) = state.cred_client_id_field.error() {
+                    state.cred_save_state = Resource::Failure(format!("Client ID: {}", err));
+                    return Command::None;
+                }
+
                 // Remember the name so we can select it after reload
                 state.recently_saved_cred = Some(name.clone());
 
@@ -1088,6 +1177,32 @@ impl App for EnvironmentSelectorApp {
     }
 }
 
+// ============================================================================
+// Connection Test
+// ============================================================================
+
+/// Authenticate against an environment and call `WhoAmI`, reporting the round-trip latency
+async fn test_connection(env_name: String) -> Result<String, String> {
+    let start = std::time::Instant::now();
+    let result = async {
+        let client = crate::client_manager()
+            .get_client(&env_name)
+            .await
+            .map_err(|e| format!("Authentication failed: {}", e))?;
+        client.who_am_i().await.map_err(|e| e.to_string())
+    }
+    .await;
+    let elapsed_ms = start.elapsed().as_millis();
+
+    match result {
+        Ok(who) => Ok(format!(
+            "Connected as user {} ({} ms)",
+            who.user_id, elapsed_ms
+        )),
+        Err(err) => Err(format!("{} ({} ms)", err, elapsed_ms)),
+    }
+}
+
 // ============================================================================
 // View Helpers
 // ============================================================================
@@ -1153,6 +1268,18 @@ where
     .build();
     let creds_panel = Element::panel(creds_select).title("Credentials").build();
 
+    let cache_entity_input = Element::text_input(
+        "env-cache-entity",
+        state.env_cache_entity_field.value(),
+        &state.env_cache_entity_field.state,
+    )
+    .placeholder("blank = all entities")
+    .on_event(|e| AppMsg::CacheEntityChanged(e).into())
+    .build();
+    let cache_entity_panel = Element::panel(cache_entity_input)
+        .title("Clear Cache For Entity")
+        .build();
+
     // Action buttons
     let save_btn = if state.env_panel_dirty {
         Element::button("env-save-btn", "Save")
@@ -1170,6 +1297,23 @@ where
         Element::button("env-delete-btn", "Delete").build()
     };
 
+    let clear_cache_btn = if state.env_selector.value().is_some() {
+        Element::button("env-clear-cache-btn", "Clear Cache")
+            .on_press(AppMsg::ClearEnvironmentCache.into())
+            .build()
+    } else {
+        Element::button("env-clear-cache-btn", "Clear Cache").build()
+    };
+
+    let test_btn =
+        if state.env_selector.value().is_some() && !state.env_connection_test_state.is_loading() {
+            Element::button("env-test-btn", "Test")
+                .on_press(AppMsg::TestConnection.into())
+                .build()
+        } else {
+            Element::button("env-test-btn", "Test").build()
+        };
+
     let new_btn = Element::button("env-new-btn", "New")
         .on_press(AppMsg::NewEnvironment.into())
         .build();
@@ -1179,15 +1323,54 @@ where
         spacer!() => Length(1),
         delete_btn => Length(10),
         spacer!() => Length(1),
+        clear_cache_btn => Length(15),
+        spacer!() => Length(1),
+        test_btn => Length(10),
+        spacer!() => Length(1),
         new_btn => Length(10)
     ];
 
+    let test_status = match &state.env_connection_test_state {
+        Resource::NotAsked => Line::from(""),
+        Resource::Loading => Line::from(Span::styled(
+            "Testing connection...",
+            Style::default().fg(theme.text_tertiary),
+        )),
+        Resource::Success(summary) => Line::from(Span::styled(
+            format!("✓ {}", summary),
+            Style::default().fg(theme.accent_success),
+        )),
+        Resource::Failure(err) => Line::from(Span::styled(
+            format!("✗ {}", err),
+            Style::default().fg(theme.accent_error),
+        )),
+    };
+
+    let cache_status = match &state.env_cache_clear_state {
+        Resource::NotAsked => Line::from(""),
+        Resource::Loading => Line::from(Span::styled(
+            "Clearing cache...",
+            Style::default().fg(theme.text_tertiary),
+        )),
+        Resource::Success(()) => Line::from(Span::styled(
+            "✓ Cache cleared",
+            Style::default().fg(theme.accent_success),
+        )),
+        Resource::Failure(err) => Line::from(Span::styled(
+            format!("✗ {}", err),
+            Style::default().fg(theme.accent_error),
+        )),
+    };
+
     let form_fields = col![
         env_select_panel => Length(3),
         name_panel => Length(3),
         host_panel => Length(3),
         creds_panel => Length(3),
-        button_row => Length(3)
+        cache_entity_panel => Length(3),
+        button_row => Length(3),
+        Element::styled_text(test_status).build() => Length(1),
+        Element::styled_text(cache_status).build() => Length(1)
     ];
 
     let details_panel = Element::panel(form_fields)
@@ -1266,6 +1449,31 @@ where
         .add(name_panel, Length(3))
         .add(type_panel, Length(3));
 
+    // Every credential type has a client ID; the GUID mask's hint renders below it
+    let client_id_input = Element::text_input(
+        "cred-client-id",
+        state.cred_client_id_field.value(),
+        &state.cred_client_id_field.state,
+    )
+    .placeholder("00000000-0000-0000-0000-000000000000")
+    .on_event(|e| AppMsg::CredClientIdChanged(e).into())
+    .build();
+    let client_id_panel = Element::panel(client_id_input).title("Client ID").build();
+    let (client_id_panel, client_id_height) = match state.cred_client_id_field.error() {
+        Some(err) => (
+            col![
+                client_id_panel => Length(3),
+                Element::styled_text(Line::from(Span::styled(
+                    format!("⚠ {}", err),
+                    Style::default().fg(theme.accent_error),
+                )))
+                .build() => Length(1)
+            ],
+            4,
+        ),
+        None => (client_id_panel, 3),
+    };
+
     let selected_type = state
         .cred_type_selector
         .value()
@@ -1292,15 +1500,6 @@ where
             .build();
             let password_panel = Element::panel(password_input).title("Password").build();
 
-            let client_id_input = Element::text_input(
-                "cred-client-id",
-                state.cred_client_id_field.value(),
-                &state.cred_client_id_field.state,
-            )
-            .on_event(|e| AppMsg::CredClientIdChanged(e).into())
-            .build();
-            let client_id_panel = Element::panel(client_id_input).title("Client ID").build();
-
             let client_secret_input = Element::text_input(
                 "cred-client-secret",
                 state.cred_client_secret_field.value(),
@@ -1316,19 +1515,10 @@ where
             builder = builder
                 .add(username_panel, Length(3))
                 .add(password_panel, Length(3))
-                .add(client_id_panel, Length(3))
+                .add(client_id_panel, Length(client_id_height))
                 .add(client_secret_panel, Length(3));
         }
         "Client Credentials" | "Device Code" => {
-            let client_id_input = Element::text_input(
-                "cred-client-id",
-                state.cred_client_id_field.value(),
-                &state.cred_client_id_field.state,
-            )
-            .on_event(|e| AppMsg::CredClientIdChanged(e).into())
-            .build();
-            let client_id_panel = Element::panel(client_id_input).title("Client ID").build();
-
             let tenant_id_input = Element::text_input(
                 "cred-tenant-id",
                 state.cred_tenant_id_field.value(),
@@ -1339,7 +1529,7 @@ where
             let tenant_id_panel = Element::panel(tenant_id_input).title("Tenant ID").build();
 
             builder = builder
-                .add(client_id_panel, Length(3))
+                .add(client_id_panel, Length(client_id_height))
                 .add(tenant_id_panel, Length(3));
 
             if selected_type == "Client Credentials" {
@@ -1359,15 +1549,6 @@ where
             }
         }
         "Certificate" => {
-            let client_id_input = Element::text_input(
-                "cred-client-id",
-                state.cred_client_id_field.value(),
-                &state.cred_client_id_field.state,
-            )
-            .on_event(|e| AppMsg::CredClientIdChanged(e).into())
-            .build();
-            let client_id_panel = Element::panel(client_id_input).title("Client ID").build();
-
             let tenant_id_input = Element::text_input(
                 "cred-tenant-id",
                 state.cred_tenant_id_field.value(),
@@ -1389,7 +1570,7 @@ where
                 .build();
 
             builder = builder
-                .add(client_id_panel, Length(3))
+                .add(client_id_panel, Length(client_id_height))
                 .add(tenant_id_panel, Length(3))
                 .add(cert_path_panel, Length(3));
         }
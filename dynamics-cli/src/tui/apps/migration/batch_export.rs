@@ -3,14 +3,46 @@
 //! Exports all entity comparisons with stored mappings into a single Excel workbook,
 //! with one sheet per entity comparison. Shows both source→target and target→source
 //! perspectives to properly display N-to-1 and 1-to-N relationships.
+//!
+//! A migration can also be snapshotted as a zip archive holding one workbook per
+//! comparison.
 
 use crate::config::repository::migrations::SavedComparison;
 use anyhow::{Context, Result};
 use rust_xlsxwriter::*;
 use sqlx::SqlitePool;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
 use std::path::PathBuf;
 
+/// Cell formats shared by every comparison sheet
+struct SheetFormats {
+    header: Format,
+    section: Format,
+    manual: Format,
+    import: Format,
+}
+
+impl SheetFormats {
+    fn new() -> Self {
+        Self {
+            header: Format::new()
+                .set_bold()
+                .set_background_color(Color::RGB(0x4472C4))
+                .set_font_color(Color::White),
+            section: Format::new()
+                .set_bold()
+                .set_font_size(12)
+                .set_background_color(Color::RGB(0xE7E6E6)),
+            manual: Format::new().set_background_color(Color::RGB(0x87CEEB)), // Sky Blue
+            import: Format::new().set_background_color(Color::RGB(0xAFEEEE)), // Pale Turquoise/Cyan
+        }
+    }
+}
+
+/// Combined mappings for one comparison plus the type of each source's mapping
+type ComparisonMappings = (HashMap<String, Vec<String>>, HashMap<String, &'static str>);
+
 /// Export all comparisons to a single Excel workbook
 pub async fn export_all_comparisons_to_excel(
     pool: &SqlitePool,
@@ -29,52 +61,16 @@ pub async fn export_all_comparisons_to_excel(
 
     let mut workbook = Workbook::new();
     let mut sheets_created = 0;
-
-    // Format definitions
-    let header_format = Format::new()
-        .set_bold()
-        .set_background_color(Color::RGB(0x4472C4))
-        .set_font_color(Color::White);
-
-    let section_format = Format::new()
-        .set_bold()
-        .set_font_size(12)
-        .set_background_color(Color::RGB(0xE7E6E6));
-
-    let manual_format = Format::new().set_background_color(Color::RGB(0x87CEEB)); // Sky Blue
-
-    let import_format = Format::new().set_background_color(Color::RGB(0xAFEEEE)); // Pale Turquoise/Cyan
+    let mut used_sheet_names = HashSet::new();
+    let formats = SheetFormats::new();
 
     for comparison in comparisons {
         log::debug!("📊 Processing comparison '{}'", comparison.name);
 
-        // Query field_mappings from database
-        let manual_mappings =
-            fetch_field_mappings(pool, &comparison.source_entity, &comparison.target_entity)
-                .await?;
-
-        // Query imported_mappings from database
-        let imported_mappings =
-            fetch_imported_mappings(pool, &comparison.source_entity, &comparison.target_entity)
-                .await?;
-
-        // Merge mappings: start with manual, override with imported
-        let mut combined_mappings: HashMap<String, Vec<String>> = manual_mappings.clone();
-        let mut mapping_types: HashMap<String, &str> = HashMap::new();
-
-        // Mark manual mappings
-        for source in manual_mappings.keys() {
-            mapping_types.insert(source.clone(), "Manual");
-        }
-
-        // Override with imported (imported wins)
-        for (source, targets) in &imported_mappings {
-            combined_mappings.insert(source.clone(), targets.clone());
-            mapping_types.insert(source.clone(), "Import");
-        }
+        let (mappings, mapping_types) = load_combined_mappings(pool, comparison).await?;
 
         // Skip if no mappings
-        if combined_mappings.is_empty() {
+        if mappings.is_empty() {
             log::debug!(
                 "📊   ⏭️  Skipping comparison '{}' (no mappings)",
                 comparison.name
@@ -83,128 +79,15 @@ pub async fn export_all_comparisons_to_excel(
         }
 
         log::info!(
-            "📊   ✅ Creating sheet for comparison '{}' ({} total, {} manual, {} imported)",
+            "📊   ✅ Creating sheet for comparison '{}' ({} total)",
             comparison.name,
-            combined_mappings.len(),
-            manual_mappings.len(),
-            imported_mappings.len()
+            mappings.len()
         );
 
         // Create worksheet for this comparison
-        let mut sheet = workbook.add_worksheet();
-        sheet.set_name(&comparison.name)?;
-
-        let mut row: u32 = 0;
-
-        // Title
-        sheet.write_string(
-            row,
-            0,
-            &format!(
-                "{} → {}",
-                comparison.source_entity, comparison.target_entity
-            ),
-        )?;
-        row += 2;
-
-        // === SOURCE PERSPECTIVE SECTION ===
-        sheet.write_string_with_format(row, 0, "Source → Target Mappings", &section_format)?;
-        row += 1;
-
-        // Header row
-        sheet.write_string_with_format(row, 0, "Source Field", &header_format)?;
-        sheet.write_string_with_format(row, 1, "Target Fields", &header_format)?;
-        sheet.write_string_with_format(row, 2, "Type", &header_format)?;
-        row += 1;
-
-        // Write combined mappings (sorted for consistency)
-        let mut sorted_sources: Vec<_> = combined_mappings.keys().collect();
-        sorted_sources.sort();
-
-        for source in sorted_sources {
-            let targets = &combined_mappings[source];
-            let mapping_type = mapping_types.get(source).unwrap_or(&"Manual");
-            let targets_str = targets.join(", ");
-
-            let format = if *mapping_type == "Import" {
-                &import_format
-            } else {
-                &manual_format
-            };
-
-            sheet.write_string_with_format(row, 0, source, format)?;
-            sheet.write_string_with_format(row, 1, &targets_str, format)?;
-            sheet.write_string_with_format(row, 2, *mapping_type, format)?;
-            row += 1;
-        }
-
-        row += 2; // Blank row
-
-        // === TARGET PERSPECTIVE SECTION ===
-        sheet.write_string_with_format(row, 0, "Target → Source Mappings", &section_format)?;
-        row += 1;
-
-        // Header row
-        sheet.write_string_with_format(row, 0, "Target Field", &header_format)?;
-        sheet.write_string_with_format(row, 1, "Source Fields", &header_format)?;
-        sheet.write_string_with_format(row, 2, "Type", &header_format)?;
-        row += 1;
-
-        // Build reverse mapping (target -> sources) from combined mappings
-        let mut target_to_sources: HashMap<String, Vec<(String, &str)>> = HashMap::new();
-
-        for (source, targets) in &combined_mappings {
-            let mapping_type = mapping_types.get(source).unwrap_or(&"Manual");
-            for target in targets {
-                target_to_sources
-                    .entry(target.clone())
-                    .or_insert_with(Vec::new)
-                    .push((source.clone(), *mapping_type));
-            }
-        }
-
-        // Sort target fields alphabetically for consistent output
-        let mut sorted_targets: Vec<_> = target_to_sources.iter().collect();
-        sorted_targets.sort_by(|a, b| a.0.cmp(b.0));
-
-        // Write reverse mappings
-        for (target, source_types) in sorted_targets {
-            if source_types.is_empty() {
-                continue; // Skip if no sources (shouldn't happen but be safe)
-            }
-
-            // Check if all sources have the same type
-            let types: Vec<&str> = source_types.iter().map(|(_, t)| *t).collect();
-            let all_same_type = types.len() == 1 || types.windows(2).all(|w| w[0] == w[1]);
-
-            let mapping_type = if all_same_type {
-                types[0]
-            } else {
-                "Mixed" // Different sources have different types
-            };
-
-            let sources_str = source_types
-                .iter()
-                .map(|(s, _)| s.as_str())
-                .collect::<Vec<_>>()
-                .join(", ");
-
-            let format = if mapping_type == "Import" {
-                &import_format
-            } else {
-                &manual_format
-            };
-
-            sheet.write_string_with_format(row, 0, target, format)?;
-            sheet.write_string_with_format(row, 1, &sources_str, format)?;
-            sheet.write_string_with_format(row, 2, mapping_type, format)?;
-            row += 1;
-        }
-
-        // Auto-size columns
-        sheet.set_column_width(0, 30)?;
-        sheet.set_column_width(1, 40)?;
-        sheet.set_column_width(2, 12)?;
+        let sheet = workbook.add_worksheet();
+        sheet.set_name(unique_sheet_name(&comparison.name, &mut used_sheet_names))?;
+        write_comparison_sheet(sheet, comparison, &mappings, &mapping_types, &formats)?;
 
         sheets_created += 1;
     }
@@ -237,6 +120,281 @@ pub async fn export_all_comparisons_to_excel(
     Ok(())
 }
 
+/// Export every comparison as its own workbook, bundled into one zip archive
+///
+/// Unlike the combined workbook, comparisons without mappings still get a file,
+/// so the archive is a snapshot of the whole migration. `on_progress` is called
+/// after each comparison with (done, total, comparison name). Returns the
+/// archive entry names, one per comparison.
+pub async fn export_comparisons_to_zip(
+    pool: &SqlitePool,
+    comparisons: &[SavedComparison],
+    output_path: PathBuf,
+    on_progress: impl Fn(usize, usize, &str),
+) -> Result<Vec<String>> {
+    log::info!(
+        "📦 Exporting {} comparisons as separate workbooks to {:?}",
+        comparisons.len(),
+        output_path
+    );
+
+    let formats = SheetFormats::new();
+    let entry_names = archive_entry_names(comparisons);
+    let file = std::fs::File::create(&output_path)
+        .with_context(|| format!("Failed to create {:?}", output_path))?;
+    let mut archive = zip::ZipWriter::new(file);
+
+    for (i, (comparison, entry_name)) in comparisons.iter().zip(&entry_names).enumerate() {
+        let (mappings, mapping_types) = load_combined_mappings(pool, comparison).await?;
+
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        sheet.set_name(sheet_name(&comparison.name))?;
+        write_comparison_sheet(sheet, comparison, &mappings, &mapping_types, &formats)?;
+        let buffer = workbook
+            .save_to_buffer()
+            .with_context(|| format!("Failed to build workbook for '{}'", comparison.name))?;
+
+        archive.start_file(
+            entry_name.as_str(),
+            zip::write::SimpleFileOptions::default(),
+        )?;
+        archive.write_all(&buffer)?;
+
+        on_progress(i + 1, comparisons.len(), &comparison.name);
+    }
+
+    archive.finish().context("Failed to finish zip archive")?;
+    log::info!(
+        "📦 ✅ Exported {} comparison workbooks to {:?}",
+        entry_names.len(),
+        output_path
+    );
+
+    Ok(entry_names)
+}
+
+/// File name for each comparison's workbook inside the archive
+///
+/// Characters that aren't safe in file names are replaced, and repeated names
+/// get a numeric suffix so every comparison keeps its own entry.
+fn archive_entry_names(comparisons: &[SavedComparison]) -> Vec<String> {
+    let mut used = HashSet::new();
+    comparisons
+        .iter()
+        .map(|comparison| {
+            let cleaned: String = comparison
+                .name
+                .trim()
+                .chars()
+                .map(|c| {
+                    if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.') {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+            let base = if cleaned.is_empty() {
+                "comparison".to_string()
+            } else {
+                cleaned
+            };
+
+            let mut name = format!("{}.xlsx", base);
+            let mut n = 2;
+            while !used.insert(name.to_lowercase()) {
+                name = format!("{} ({}).xlsx", base, n);
+                n += 1;
+            }
+            name
+        })
+        .collect()
+}
+
+/// Excel sheet name for a comparison (max 31 chars, no []:*?/\)
+fn sheet_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !matches!(c, '[' | ']' | ':' | '*' | '?' | '/' | '\\'))
+        .take(31)
+        .collect();
+    if cleaned.trim().is_empty() {
+        "Comparison".to_string()
+    } else {
+        cleaned
+    }
+}
+
+/// Sheet name for a comparison that no earlier sheet in the workbook uses
+///
+/// Excel compares sheet names case-insensitively, so repeats get a " (n)"
+/// suffix, shortening the name to stay within 31 characters.
+fn unique_sheet_name(name: &str, used: &mut HashSet<String>) -> String {
+    let base = sheet_name(name);
+    let mut candidate = base.clone();
+    let mut n = 2;
+    while !used.insert(candidate.to_lowercase()) {
+        let suffix = format!(" ({})", n);
+        let stem: String = base.chars().take(31 - suffix.chars().count()).collect();
+        candidate = format!("{}{}", stem, suffix);
+        n += 1;
+    }
+    candidate
+}
+
+/// Load manual and imported mappings for a comparison, imported winning
+async fn load_combined_mappings(
+    pool: &SqlitePool,
+    comparison: &SavedComparison,
+) -> Result<ComparisonMappings> {
+    // Query field_mappings from database
+    let manual_mappings =
+        fetch_field_mappings(pool, &comparison.source_entity, &comparison.target_entity).await?;
+
+    // Query imported_mappings from database
+    let imported_mappings =
+        fetch_imported_mappings(pool, &comparison.source_entity, &comparison.target_entity).await?;
+
+    // Merge mappings: start with manual, override with imported
+    let mut mapping_types: HashMap<String, &'static str> = manual_mappings
+        .keys()
+        .map(|source| (source.clone(), "Manual"))
+        .collect();
+    let mut combined_mappings = manual_mappings;
+
+    // Override with imported (imported wins)
+    for (source, targets) in imported_mappings {
+        mapping_types.insert(source.clone(), "Import");
+        combined_mappings.insert(source, targets);
+    }
+
+    Ok((combined_mappings, mapping_types))
+}
+
+/// Write the source→target and target→source mapping tables for a comparison
+fn write_comparison_sheet(
+    sheet: &mut Worksheet,
+    comparison: &SavedComparison,
+    mappings: &HashMap<String, Vec<String>>,
+    mapping_types: &HashMap<String, &'static str>,
+    formats: &SheetFormats,
+) -> Result<()> {
+    let mut row: u32 = 0;
+
+    // Title
+    sheet.write_string(
+        row,
+        0,
+        &format!(
+            "{} → {}",
+            comparison.source_entity, comparison.target_entity
+        ),
+    )?;
+    row += 2;
+
+    // === SOURCE PERSPECTIVE SECTION ===
+    sheet.write_string_with_format(row, 0, "Source → Target Mappings", &formats.section)?;
+    row += 1;
+
+    // Header row
+    sheet.write_string_with_format(row, 0, "Source Field", &formats.header)?;
+    sheet.write_string_with_format(row, 1, "Target Fields", &formats.header)?;
+    sheet.write_string_with_format(row, 2, "Type", &formats.header)?;
+    row += 1;
+
+    // Write combined mappings (sorted for consistency)
+    let mut sorted_sources: Vec<_> = mappings.keys().collect();
+    sorted_sources.sort();
+
+    for source in sorted_sources {
+        let targets = &mappings[source];
+        let mapping_type = mapping_types.get(source).unwrap_or(&"Manual");
+        let targets_str = targets.join(", ");
+
+        let format = if *mapping_type == "Import" {
+            &formats.import
+        } else {
+            &formats.manual
+        };
+
+        sheet.write_string_with_format(row, 0, source, format)?;
+        sheet.write_string_with_format(row, 1, &targets_str, format)?;
+        sheet.write_string_with_format(row, 2, *mapping_type, format)?;
+        row += 1;
+    }
+
+    row += 2; // Blank row
+
+    // === TARGET PERSPECTIVE SECTION ===
+    sheet.write_string_with_format(row, 0, "Target → Source Mappings", &formats.section)?;
+    row += 1;
+
+    // Header row
+    sheet.write_string_with_format(row, 0, "Target Field", &formats.header)?;
+    sheet.write_string_with_format(row, 1, "Source Fields", &formats.header)?;
+    sheet.write_string_with_format(row, 2, "Type", &formats.header)?;
+    row += 1;
+
+    // Build reverse mapping (target -> sources) from combined mappings
+    let mut target_to_sources: HashMap<String, Vec<(String, &str)>> = HashMap::new();
+
+    for (source, targets) in mappings {
+        let mapping_type = mapping_types.get(source).unwrap_or(&"Manual");
+        for target in targets {
+            target_to_sources
+                .entry(target.clone())
+                .or_insert_with(Vec::new)
+                .push((source.clone(), *mapping_type));
+        }
+    }
+
+    // Sort target fields alphabetically for consistent output
+    let mut sorted_targets: Vec<_> = target_to_sources.iter().collect();
+    sorted_targets.sort_by(|a, b| a.0.cmp(b.0));
+
+    // Write reverse mappings
+    for (target, source_types) in sorted_targets {
+        if source_types.is_empty() {
+            continue; // Skip if no sources (shouldn't happen but be safe)
+        }
+
+        // Check if all sources have the same type
+        let types: Vec<&str> = source_types.iter().map(|(_, t)| *t).collect();
+        let all_same_type = types.len() == 1 || types.windows(2).all(|w| w[0] == w[1]);
+
+        let mapping_type = if all_same_type {
+            types[0]
+        } else {
+            "Mixed" // Different sources have different types
+        };
+
+        let sources_str = source_types
+            .iter()
+            .map(|(s, _)| s.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let format = if mapping_type == "Import" {
+            &formats.import
+        } else {
+            &formats.manual
+        };
+
+        sheet.write_string_with_format(row, 0, target, format)?;
+        sheet.write_string_with_format(row, 1, &sources_str, format)?;
+        sheet.write_string_with_format(row, 2, mapping_type, format)?;
+        row += 1;
+    }
+
+    // Auto-size columns
+    sheet.set_column_width(0, 30)?;
+    sheet.set_column_width(1, 40)?;
+    sheet.set_column_width(2, 12)?;
+
+    Ok(())
+}
+
 /// Fetch field mappings from database for a specific entity pair
 async fn fetch_field_mappings(
     pool: &SqlitePool,
@@ -340,3 +498,83 @@ fn try_open_file(file_path: &str) {
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn comparison(id: i64, name: &str, source: &str, target: &str) -> SavedComparison {
+        let now = chrono::Utc::now();
+        SavedComparison {
+            id,
+            migration_name: "crm-upgrade".to_string(),
+            name: name.to_string(),
+            source_entity: source.to_string(),
+            target_entity: target.to_string(),
+            source_entities: vec![source.to_string()],
+            target_entities: vec![target.to_string()],
+            entity_comparison: None,
+            created_at: now,
+            last_used: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zip_export_has_one_workbook_per_comparison() {
+        let config = crate::config::Config::new_test().await.unwrap();
+        let comparisons = vec![
+            comparison(1, "Contacts", "cgk_contact", "nrq_contact"),
+            comparison(2, "Accounts/Legacy", "cgk_account", "nrq_account"),
+            comparison(3, "Contacts", "cgk_lead", "nrq_contact"),
+        ];
+        let path = std::env::temp_dir().join(format!(
+            "dynamics-batch-export-{}.zip",
+            uuid::Uuid::new_v4()
+        ));
+
+        let progress_calls = Cell::new(0);
+        let entries = export_comparisons_to_zip(
+            &config.pool,
+            &comparisons,
+            path.clone(),
+            |done, total, _| {
+                assert_eq!(total, 3);
+                progress_calls.set(done);
+            },
+        )
+        .await
+        .unwrap();
+
+        let expected = vec!["Contacts.xlsx", "Accounts_Legacy.xlsx", "Contacts (2).xlsx"];
+        assert_eq!(entries, expected);
+        assert_eq!(progress_calls.get(), 3);
+
+        let file = std::fs::File::open(&path).unwrap();
+        let archive = zip::ZipArchive::new(file).unwrap();
+        let names: Vec<&str> = archive.file_names().collect();
+        assert_eq!(names.len(), comparisons.len());
+        for name in expected {
+            assert!(names.contains(&name), "missing {}", name);
+        }
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_repeated_sheet_names_are_made_unique() {
+        let mut used = HashSet::new();
+        let long = "A very long comparison name that exceeds the limit";
+        let names: Vec<String> = ["Contacts", "contacts", "Contacts", long, long]
+            .iter()
+            .map(|name| unique_sheet_name(name, &mut used))
+            .collect();
+
+        assert_eq!(names[0], "Contacts");
+        assert_eq!(names[1], "contacts (2)");
+        assert_eq!(names[2], "Contacts (3)");
+        assert_eq!(names[3], "A very long comparison name tha");
+        assert_eq!(names[4], "A very long comparison name (2)");
+        assert!(names.iter().all(|name| name.chars().count() <= 31));
+    }
+}
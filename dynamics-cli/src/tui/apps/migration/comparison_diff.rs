@@ -0,0 +1,354 @@
+//! Side-by-side diff of two saved entity comparisons
+//!
+//! Uses `ComparisonExportData` as the comparison unit so the same shape that is
+//! written to disk on export is what gets compared here.
+
+use super::migration_comparison_select_app::ComparisonExportData;
+use std::collections::{BTreeSet, HashMap};
+
+/// A mapping whose targets differ between the two comparisons
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChangedMapping {
+    pub source: String,
+    pub old_targets: Vec<String>,
+    pub new_targets: Vec<String>,
+}
+
+/// Differences between two `source -> targets` mapping maps
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MappingDiff {
+    pub added: Vec<(String, Vec<String>)>,
+    pub removed: Vec<(String, Vec<String>)>,
+    pub changed: Vec<ChangedMapping>,
+}
+
+impl MappingDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Differences between two ignored-item lists
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ItemDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl ItemDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Full diff between a base ("left") and other ("right") comparison
+#[derive(Clone, Debug)]
+pub struct ComparisonDiff {
+    pub left_name: String,
+    pub right_name: String,
+    pub field_mappings: MappingDiff,
+    pub prefix_mappings: MappingDiff,
+    pub ignored_items: ItemDiff,
+}
+
+impl ComparisonDiff {
+    pub fn is_empty(&self) -> bool {
+        self.field_mappings.is_empty()
+            && self.prefix_mappings.is_empty()
+            && self.ignored_items.is_empty()
+    }
+
+    /// Lay the diff out as side-by-side rows, base on the left and other on the right
+    pub fn to_rows(&self) -> Vec<DiffRow> {
+        let mut rows = vec![DiffRow::new(
+            DiffRowKind::Heading,
+            format!("Base: {}", self.left_name),
+            format!("Other: {}", self.right_name),
+        )];
+
+        if self.is_empty() {
+            rows.push(DiffRow::new(
+                DiffRowKind::Heading,
+                "No differences".to_string(),
+                "No differences".to_string(),
+            ));
+            return rows;
+        }
+
+        push_mapping_rows(&mut rows, "Field mappings", &self.field_mappings);
+        push_mapping_rows(&mut rows, "Prefix mappings", &self.prefix_mappings);
+
+        if !self.ignored_items.is_empty() {
+            rows.push(DiffRow::heading(format!(
+                "Ignored items (+{} -{})",
+                self.ignored_items.added.len(),
+                self.ignored_items.removed.len()
+            )));
+            for item in &self.ignored_items.removed {
+                rows.push(DiffRow::new(
+                    DiffRowKind::Removed,
+                    item.clone(),
+                    String::new(),
+                ));
+            }
+            for item in &self.ignored_items.added {
+                rows.push(DiffRow::new(
+                    DiffRowKind::Added,
+                    String::new(),
+                    item.clone(),
+                ));
+            }
+        }
+
+        rows
+    }
+}
+
+/// How a side-by-side row differs between the two comparisons
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffRowKind {
+    /// Section title, shown on both sides
+    Heading,
+    /// Only in the other comparison (right)
+    Added,
+    /// Only in the base comparison (left)
+    Removed,
+    /// In both, with different targets
+    Changed,
+}
+
+/// One row of the side-by-side diff view
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiffRow {
+    pub kind: DiffRowKind,
+    pub left: String,
+    pub right: String,
+}
+
+impl DiffRow {
+    fn new(kind: DiffRowKind, left: String, right: String) -> Self {
+        Self { kind, left, right }
+    }
+
+    fn heading(title: String) -> Self {
+        Self::new(DiffRowKind::Heading, title.clone(), title)
+    }
+}
+
+fn push_mapping_rows(rows: &mut Vec<DiffRow>, title: &str, diff: &MappingDiff) {
+    if diff.is_empty() {
+        return;
+    }
+
+    rows.push(DiffRow::heading(format!(
+        "{} (+{} -{} ~{})",
+        title,
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len()
+    )));
+    for change in &diff.changed {
+        rows.push(DiffRow::new(
+            DiffRowKind::Changed,
+            format!("{} -> {}", change.source, change.old_targets.join(", ")),
+            format!("{} -> {}", change.source, change.new_targets.join(", ")),
+        ));
+    }
+    for (source, targets) in &diff.removed {
+        rows.push(DiffRow::new(
+            DiffRowKind::Removed,
+            format!("{} -> {}", source, targets.join(", ")),
+            String::new(),
+        ));
+    }
+    for (source, targets) in &diff.added {
+        rows.push(DiffRow::new(
+            DiffRowKind::Added,
+            String::new(),
+            format!("{} -> {}", source, targets.join(", ")),
+        ));
+    }
+}
+
+/// Diff two mapping maps. Target order is ignored; output is sorted by source key.
+pub fn diff_mappings(
+    old: &HashMap<String, Vec<String>>,
+    new: &HashMap<String, Vec<String>>,
+) -> MappingDiff {
+    let mut diff = MappingDiff::default();
+
+    let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for key in keys {
+        match (old.get(key), new.get(key)) {
+            (Some(old_targets), Some(new_targets)) => {
+                let old_sorted = sorted(old_targets);
+                let new_sorted = sorted(new_targets);
+                if old_sorted != new_sorted {
+                    diff.changed.push(ChangedMapping {
+                        source: key.clone(),
+                        old_targets: old_sorted,
+                        new_targets: new_sorted,
+                    });
+                }
+            }
+            (None, Some(new_targets)) => diff.added.push((key.clone(), sorted(new_targets))),
+            (Some(old_targets), None) => diff.removed.push((key.clone(), sorted(old_targets))),
+            (None, None) => {}
+        }
+    }
+
+    diff
+}
+
+/// Diff two item lists as sets
+pub fn diff_items(old: &[String], new: &[String]) -> ItemDiff {
+    let old: BTreeSet<&String> = old.iter().collect();
+    let new: BTreeSet<&String> = new.iter().collect();
+
+    ItemDiff {
+        added: new.difference(&old).map(|s| (*s).clone()).collect(),
+        removed: old.difference(&new).map(|s| (*s).clone()).collect(),
+    }
+}
+
+/// Diff two comparisons, treating `left` as the base
+///
+/// Both must compare the same source/target entity pair; mappings of
+/// different entities share no field names, so their diff means nothing.
+pub fn diff_comparisons(
+    left_name: String,
+    left: &ComparisonExportData,
+    right_name: String,
+    right: &ComparisonExportData,
+) -> Result<ComparisonDiff, String> {
+    if left.source_entity != right.source_entity || left.target_entity != right.target_entity {
+        return Err(format!(
+            "'{}' compares {} -> {} but '{}' compares {} -> {}; only comparisons of the same entities can be diffed",
+            left_name,
+            left.source_entity,
+            left.target_entity,
+            right_name,
+            right.source_entity,
+            right.target_entity
+        ));
+    }
+
+    Ok(ComparisonDiff {
+        left_name,
+        right_name,
+        field_mappings: diff_mappings(&left.field_mappings, &right.field_mappings),
+        prefix_mappings: diff_mappings(&left.prefix_mappings, &right.prefix_mappings),
+        ignored_items: diff_items(&left.ignored_items, &right.ignored_items),
+    })
+}
+
+fn sorted(values: &[String]) -> Vec<String> {
+    let mut values = values.to_vec();
+    values.sort();
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(entries: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.iter().map(|s| s.to_string()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_mappings() {
+        let old = map(&[
+            ("name", &["name"]),
+            ("email", &["emailaddress1"]),
+            ("phone", &["telephone1", "telephone2"]),
+            ("fax", &["fax"]),
+        ]);
+        let new = map(&[
+            ("name", &["name"]),
+            ("email", &["emailaddress2"]),
+            ("phone", &["telephone2", "telephone1"]),
+            ("website", &["websiteurl"]),
+        ]);
+
+        let diff = diff_mappings(&old, &new);
+
+        assert_eq!(
+            diff.added,
+            vec![("website".to_string(), vec!["websiteurl".to_string()])]
+        );
+        assert_eq!(
+            diff.removed,
+            vec![("fax".to_string(), vec!["fax".to_string()])]
+        );
+        // Reordered targets are not a change
+        assert_eq!(
+            diff.changed,
+            vec![ChangedMapping {
+                source: "email".to_string(),
+                old_targets: vec!["emailaddress1".to_string()],
+                new_targets: vec!["emailaddress2".to_string()],
+            }]
+        );
+        assert!(diff_mappings(&old, &old).is_empty());
+    }
+
+    fn comparison(
+        source_entity: &str,
+        target_entity: &str,
+        field_mappings: HashMap<String, Vec<String>>,
+    ) -> ComparisonExportData {
+        ComparisonExportData {
+            version: "1".to_string(),
+            export_date: String::new(),
+            source_entity: source_entity.to_string(),
+            target_entity: target_entity.to_string(),
+            field_mappings,
+            prefix_mappings: HashMap::new(),
+            imported_mappings: HashMap::new(),
+            import_source_file: None,
+            ignored_items: vec![],
+            example_pairs: vec![],
+        }
+    }
+
+    #[test]
+    fn test_diff_comparisons_side_by_side() {
+        let base = comparison(
+            "contact",
+            "contact",
+            map(&[("email", &["emailaddress1"]), ("fax", &["fax"])]),
+        );
+        let other = comparison(
+            "contact",
+            "contact",
+            map(&[("email", &["emailaddress2"]), ("website", &["websiteurl"])]),
+        );
+
+        let diff = diff_comparisons("v1".to_string(), &base, "v2".to_string(), &other).unwrap();
+        let rows = diff.to_rows();
+        let cells = |kind: DiffRowKind| -> Vec<(&str, &str)> {
+            rows.iter()
+                .filter(|row| row.kind == kind)
+                .map(|row| (row.left.as_str(), row.right.as_str()))
+                .collect()
+        };
+
+        assert_eq!(
+            cells(DiffRowKind::Changed),
+            vec![("email -> emailaddress1", "email -> emailaddress2")]
+        );
+        assert_eq!(cells(DiffRowKind::Removed), vec![("fax -> fax", "")]);
+        assert_eq!(
+            cells(DiffRowKind::Added),
+            vec![("", "website -> websiteurl")]
+        );
+
+        // Comparisons of different entity pairs are rejected
+        let account = comparison("account", "account", HashMap::new());
+        assert!(diff_comparisons("v1".to_string(), &base, "acc".to_string(), &account).is_err());
+    }
+}
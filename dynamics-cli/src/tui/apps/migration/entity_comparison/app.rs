@@ -264,6 +264,7 @@ pub struct State {
     // Import modal state
     pub(super) show_import_modal: bool,
     pub(super) import_file_browser: crate::tui::widgets::FileBrowserState,
+    pub(super) import_negative_matches: bool, // File browser picks a negative matches file
     pub(super) show_import_results_modal: bool,
     pub(super) import_results: Option<ImportResults>,
     pub(super) import_results_list: crate::tui::widgets::ListState,
@@ -273,6 +274,17 @@ pub struct State {
     pub(super) show_ignore_modal: bool,
     pub(super) ignore_list_state: crate::tui::widgets::ListState,
 
+    // Search presets modal state
+    pub(super) show_search_presets_modal: bool,
+    pub(super) search_presets: Vec<crate::config::repository::search_presets::SearchPreset>,
+    pub(super) search_presets_list_state: crate::tui::widgets::ListState,
+    pub(super) search_preset_name_input: crate::tui::widgets::TextInputField,
+
+    // Export column selection modal state
+    pub(super) show_export_columns_modal: bool,
+    pub(super) export_columns: Vec<super::export::columns::FieldColumn>,
+    pub(super) export_columns_list_state: crate::tui::widgets::ListState,
+
     // Search state
     pub(super) search_mode: super::models::SearchMode,
     pub(super) match_mode: super::models::MatchMode,
@@ -370,6 +382,7 @@ impl Default for State {
             show_manual_mappings_modal: false,
             manual_mappings_list_state: crate::tui::widgets::ListState::new(),
             show_import_modal: false,
+            import_negative_matches: false,
             import_file_browser: crate::tui::widgets::FileBrowserState::new(
                 std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("/")),
             ),
@@ -379,6 +392,13 @@ impl Default for State {
             ignored_items: std::collections::HashSet::new(),
             show_ignore_modal: false,
             ignore_list_state: crate::tui::widgets::ListState::new(),
+            show_search_presets_modal: false,
+            search_presets: Vec::new(),
+            search_presets_list_state: crate::tui::widgets::ListState::new(),
+            search_preset_name_input: crate::tui::widgets::TextInputField::new(),
+            show_export_columns_modal: false,
+            export_columns: super::export::columns::FieldColumn::defaults(),
+            export_columns_list_state: crate::tui::widgets::ListState::new(),
             search_mode: super::models::SearchMode::default(),
             match_mode: super::models::MatchMode::default(),
             unified_search: crate::tui::widgets::TextInputField::new(),
@@ -831,6 +851,7 @@ impl App for EntityComparisonApp {
             show_manual_mappings_modal: false,
             manual_mappings_list_state: crate::tui::widgets::ListState::new(),
             show_import_modal: false,
+            import_negative_matches: false,
             import_file_browser: crate::tui::widgets::FileBrowserState::new(
                 std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("/")),
             ),
@@ -840,6 +861,13 @@ impl App for EntityComparisonApp {
             ignored_items: std::collections::HashSet::new(),
             show_ignore_modal: false,
             ignore_list_state: crate::tui::widgets::ListState::new(),
+            show_search_presets_modal: false,
+            search_presets: Vec::new(),
+            search_presets_list_state: crate::tui::widgets::ListState::new(),
+            search_preset_name_input: crate::tui::widgets::TextInputField::new(),
+            show_export_columns_modal: false,
+            export_columns: super::export::columns::FieldColumn::defaults(),
+            export_columns_list_state: crate::tui::widgets::ListState::new(),
             search_mode: super::models::SearchMode::default(),
             match_mode: super::models::MatchMode::default(),
             unified_search: crate::tui::widgets::TextInputField::new(),
@@ -1175,6 +1203,20 @@ impl App for EntityComparisonApp {
             );
         }
 
+        if state.show_search_presets_modal {
+            view = view.with_app_modal(
+                super::view::render_search_presets_modal(state),
+                LayerAlignment::Center,
+            );
+        }
+
+        if state.show_export_columns_modal {
+            view = view.with_app_modal(
+                super::view::render_export_columns_modal(state),
+                LayerAlignment::Center,
+            );
+        }
+
         view
     }
 
@@ -1214,6 +1256,17 @@ impl App for EntityComparisonApp {
                 "Switch to Entities",
                 Msg::SwitchTab(5),
             ),
+            // Tree expansion
+            Subscription::keyboard(
+                config.get_keybind("entity_comparison.collapse_all"),
+                "Collapse all",
+                Msg::CollapseAllTrees,
+            ),
+            Subscription::keyboard(
+                config.get_keybind("entity_comparison.expand_all"),
+                "Expand all",
+                Msg::ExpandAllTrees,
+            ),
             // Refresh metadata
             Subscription::keyboard(
                 config.get_keybind("entity_comparison.refresh"),
@@ -1313,6 +1366,12 @@ impl App for EntityComparisonApp {
                 "Ignore manager",
                 Msg::OpenIgnoreModal,
             ),
+            // Search presets
+            Subscription::keyboard(
+                config.get_keybind("entity_comparison.open_search_presets"),
+                "Search presets",
+                Msg::OpenSearchPresetsModal,
+            ),
             // Export
             Subscription::keyboard(
                 config.get_keybind("entity_comparison.export"),
@@ -1324,6 +1383,11 @@ impl App for EntityComparisonApp {
                 "Export unmapped fields to CSV",
                 Msg::ExportUnmappedToCsv,
             ),
+            Subscription::ctrl_key(
+                KeyCode::Char('t'),
+                "Seed transfer config from matches",
+                Msg::SeedTransferConfig,
+            ),
         ];
 
         // Conditional 'd' key: Delete imported mapping if selected field has an imported match
@@ -1398,7 +1462,9 @@ impl App for EntityComparisonApp {
             || state.show_manual_mappings_modal
             || state.show_import_modal
             || state.show_import_results_modal
-            || state.show_ignore_modal;
+            || state.show_ignore_modal
+            || state.show_search_presets_modal
+            || state.show_export_columns_modal;
 
         if !any_modal_open {
             use crate::tui::widgets::TreeEvent;
@@ -1502,7 +1568,9 @@ impl App for EntityComparisonApp {
             || state.show_manual_mappings_modal
             || state.show_import_modal
             || state.show_import_results_modal
-            || state.show_ignore_modal;
+            || state.show_ignore_modal
+            || state.show_search_presets_modal
+            || state.show_export_columns_modal;
 
         if !any_modal_open {
             subs.push(Subscription::keyboard(
@@ -1608,6 +1676,16 @@ impl App for EntityComparisonApp {
                 "Delete negative match",
                 Msg::DeleteNegativeMatch,
             ));
+            subs.push(Subscription::keyboard(
+                KeyCode::Char('i'),
+                "Import negative matches",
+                Msg::OpenNegativeMatchesImport,
+            ));
+            subs.push(Subscription::keyboard(
+                KeyCode::Char('e'),
+                "Export negative matches",
+                Msg::ExportNegativeMatches,
+            ));
             subs.push(Subscription::keyboard(
                 KeyCode::Char('c'),
                 "Close modal",
@@ -1620,6 +1698,44 @@ impl App for EntityComparisonApp {
             ));
         }
 
+        // When showing search presets modal, add hotkeys
+        if state.show_search_presets_modal {
+            subs.push(Subscription::keyboard(
+                KeyCode::Char('l'),
+                "Load search preset",
+                Msg::LoadSearchPreset,
+            ));
+            subs.push(Subscription::keyboard(
+                KeyCode::Char('d'),
+                "Delete search preset",
+                Msg::DeleteSearchPreset,
+            ));
+            subs.push(Subscription::keyboard(
+                KeyCode::Esc,
+                "Close modal",
+                Msg::CloseSearchPresetsModal,
+            ));
+        }
+
+        // When showing export columns modal, add hotkeys
+        if state.show_export_columns_modal {
+            subs.push(Subscription::keyboard(
+                KeyCode::Char(' '),
+                "Toggle column",
+                Msg::ToggleExportColumn(state.export_columns_list_state.selected().unwrap_or(0)),
+            ));
+            subs.push(Subscription::keyboard(
+                KeyCode::Char('e'),
+                "Export",
+                Msg::ConfirmExportToExcel,
+            ));
+            subs.push(Subscription::keyboard(
+                KeyCode::Esc,
+                "Close modal",
+                Msg::CloseExportColumnsModal,
+            ));
+        }
+
         // When showing manual mappings modal, add hotkeys
         if state.show_manual_mappings_modal {
             subs.push(Subscription::keyboard(
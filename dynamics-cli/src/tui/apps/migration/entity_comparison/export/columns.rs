@@ -0,0 +1,223 @@
+//! User-selectable columns for the field mapping sheets
+
+use anyhow::Result;
+use rust_xlsxwriter::*;
+
+use crate::api::metadata::FieldMetadata;
+
+/// A field attribute that can appear as a column in the field sheets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldColumn {
+    FieldName,
+    DisplayName,
+    FieldType,
+    Required,
+    PrimaryKey,
+    MatchType,
+    RelatedEntity,
+    MappedField,
+    MappedType,
+}
+
+impl FieldColumn {
+    /// All columns in sheet order
+    pub const ALL: [FieldColumn; 9] = [
+        FieldColumn::FieldName,
+        FieldColumn::DisplayName,
+        FieldColumn::FieldType,
+        FieldColumn::Required,
+        FieldColumn::PrimaryKey,
+        FieldColumn::MatchType,
+        FieldColumn::RelatedEntity,
+        FieldColumn::MappedField,
+        FieldColumn::MappedType,
+    ];
+
+    /// Columns exported when nothing has been chosen for a comparison
+    pub fn defaults() -> Vec<FieldColumn> {
+        vec![
+            FieldColumn::FieldName,
+            FieldColumn::FieldType,
+            FieldColumn::MatchType,
+            FieldColumn::RelatedEntity,
+            FieldColumn::MappedField,
+            FieldColumn::MappedType,
+        ]
+    }
+
+    /// Stable key used for persistence
+    pub fn key(&self) -> &'static str {
+        match self {
+            FieldColumn::FieldName => "field_name",
+            FieldColumn::DisplayName => "display_name",
+            FieldColumn::FieldType => "field_type",
+            FieldColumn::Required => "required",
+            FieldColumn::PrimaryKey => "primary_key",
+            FieldColumn::MatchType => "match_type",
+            FieldColumn::RelatedEntity => "related_entity",
+            FieldColumn::MappedField => "mapped_field",
+            FieldColumn::MappedType => "mapped_type",
+        }
+    }
+
+    /// Human-readable label (also the sheet header, except for the mapped field)
+    pub fn label(&self) -> &'static str {
+        match self {
+            FieldColumn::FieldName => "Field Name",
+            FieldColumn::DisplayName => "Display Name",
+            FieldColumn::FieldType => "Field Type",
+            FieldColumn::Required => "Required",
+            FieldColumn::PrimaryKey => "Primary Key",
+            FieldColumn::MatchType => "Match Type",
+            FieldColumn::RelatedEntity => "Related Entity",
+            FieldColumn::MappedField => "Mapped Field",
+            FieldColumn::MappedType => "Mapped Type",
+        }
+    }
+
+    /// Parse persisted keys, falling back to the defaults when none are valid
+    pub fn from_keys(keys: &[String]) -> Vec<FieldColumn> {
+        let columns: Vec<FieldColumn> = Self::ALL
+            .into_iter()
+            .filter(|c| keys.iter().any(|k| k == c.key()))
+            .collect();
+        if columns.is_empty() {
+            Self::defaults()
+        } else {
+            columns
+        }
+    }
+}
+
+/// Values describing one field row, independent of which columns are shown
+pub struct FieldRow<'a> {
+    pub display_name: &'a str,
+    pub field: &'a FieldMetadata,
+    pub mapped: &'a str,
+    pub mapped_type: &'a str,
+    pub match_type: &'a str,
+}
+
+/// Header labels for the selected columns
+///
+/// `mapped_header` names the mapped field column from the sheet's perspective
+/// ("Mapped To" on the source sheet, "Mapped From" on the target sheet).
+pub fn column_headers(columns: &[FieldColumn], mapped_header: &'static str) -> Vec<&'static str> {
+    columns
+        .iter()
+        .map(|c| match c {
+            FieldColumn::MappedField => mapped_header,
+            other => other.label(),
+        })
+        .collect()
+}
+
+/// Cell values for the selected columns
+pub fn row_values(columns: &[FieldColumn], row: &FieldRow) -> Vec<String> {
+    let yes_no = |b: bool| if b { "Yes" } else { "No" }.to_string();
+
+    columns
+        .iter()
+        .map(|c| match c {
+            FieldColumn::FieldName => format!("    {}", row.display_name),
+            FieldColumn::DisplayName => row.field.display_name.clone().unwrap_or_default(),
+            FieldColumn::FieldType => format!("{:?}", row.field.field_type),
+            FieldColumn::Required => yes_no(row.field.is_required),
+            FieldColumn::PrimaryKey => yes_no(row.field.is_primary_key),
+            FieldColumn::MatchType => row.match_type.to_string(),
+            // Related Entity (only for Lookup fields)
+            FieldColumn::RelatedEntity => row.field.related_entity.clone().unwrap_or_default(),
+            FieldColumn::MappedField => row.mapped.to_string(),
+            FieldColumn::MappedType => row.mapped_type.to_string(),
+        })
+        .collect()
+}
+
+/// Write the header row for the selected columns
+pub fn write_headers(
+    sheet: &mut Worksheet,
+    row: u32,
+    columns: &[FieldColumn],
+    mapped_header: &'static str,
+    header_format: &Format,
+) -> Result<()> {
+    for (col, header) in column_headers(columns, mapped_header).iter().enumerate() {
+        sheet.write_string_with_format(row, col as u16, *header, header_format)?;
+    }
+    Ok(())
+}
+
+/// Write a field row for the selected columns; the field name column is indented
+pub fn write_field_row(
+    sheet: &mut Worksheet,
+    row: u32,
+    columns: &[FieldColumn],
+    values: &FieldRow,
+    row_format: &Format,
+    indent_format: &Format,
+) -> Result<()> {
+    for (col, (column, value)) in columns.iter().zip(row_values(columns, values)).enumerate() {
+        let format = if *column == FieldColumn::FieldName {
+            indent_format
+        } else {
+            row_format
+        };
+        sheet.write_string_with_format(row, col as u16, &value, format)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::metadata::FieldType;
+
+    fn field() -> FieldMetadata {
+        FieldMetadata {
+            display_name: Some("Company Name".to_string()),
+            field_type: FieldType::Lookup,
+            is_required: true,
+            related_entity: Some("account".to_string()),
+            ..FieldMetadata::string("parentcustomerid")
+        }
+    }
+
+    #[test]
+    fn test_only_selected_columns_are_written() {
+        let field = field();
+        let row = FieldRow {
+            display_name: "parentcustomerid",
+            field: &field,
+            mapped: "nrq_company",
+            mapped_type: "Lookup",
+            match_type: "Manual",
+        };
+        let columns = [
+            FieldColumn::DisplayName,
+            FieldColumn::Required,
+            FieldColumn::MappedField,
+        ];
+
+        assert_eq!(
+            column_headers(&columns, "Mapped To"),
+            vec!["Display Name", "Required", "Mapped To"]
+        );
+        assert_eq!(
+            row_values(&columns, &row),
+            vec!["Company Name", "Yes", "nrq_company"]
+        );
+    }
+
+    #[test]
+    fn test_from_keys_keeps_sheet_order_and_falls_back() {
+        let keys = vec!["mapped_type".to_string(), "field_name".to_string()];
+        assert_eq!(
+            FieldColumn::from_keys(&keys),
+            vec![FieldColumn::FieldName, FieldColumn::MappedType]
+        );
+        assert_eq!(
+            FieldColumn::from_keys(&["bogus".to_string()]),
+            FieldColumn::defaults()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Excel export functionality for migration analysis
 
+pub mod columns;
 pub mod csv_exporter;
 mod formatting;
 mod helpers;
@@ -9,6 +10,7 @@ use anyhow::{Context, Result};
 use rust_xlsxwriter::*;
 
 use super::app::State;
+use columns::FieldColumn;
 use helpers::try_open_file;
 use sheets::*;
 
@@ -17,19 +19,19 @@ pub struct MigrationExporter;
 
 impl MigrationExporter {
     /// Export migration analysis to Excel file and auto-open
-    pub fn export_and_open(state: &State, file_path: &str) -> Result<()> {
-        Self::export_to_excel(state, file_path)?;
+    pub fn export_and_open(state: &State, columns: &[FieldColumn], file_path: &str) -> Result<()> {
+        Self::export_to_excel(state, columns, file_path)?;
         try_open_file(file_path);
         Ok(())
     }
 
-    /// Export migration analysis to Excel file
-    pub fn export_to_excel(state: &State, file_path: &str) -> Result<()> {
+    /// Export migration analysis to Excel file, writing only `columns` in the field sheets
+    pub fn export_to_excel(state: &State, columns: &[FieldColumn], file_path: &str) -> Result<()> {
         let mut workbook = Workbook::new();
 
         // Create field mapping worksheets
-        create_source_fields_sheet(&mut workbook, state)?;
-        create_target_fields_sheet(&mut workbook, state)?;
+        create_source_fields_sheet(&mut workbook, state, columns)?;
+        create_target_fields_sheet(&mut workbook, state, columns)?;
 
         // Create statistics overview last
         create_stats_sheet(&mut workbook, state)?;
@@ -5,44 +5,17 @@ use rust_xlsxwriter::*;
 
 use super::super::super::MatchType;
 use super::super::super::app::State;
+use super::super::columns::{FieldColumn, FieldRow, write_field_row, write_headers};
 use super::super::formatting::*;
 use crate::api::metadata::FieldMetadata;
 use crate::tui::Resource;
 
-/// Write a field row with the new column structure (no Required/Primary Key)
-fn write_field_row(
-    sheet: &mut Worksheet,
-    row: u32,
-    field_display_name: &str,
-    field: &FieldMetadata,
-    mapped_to: &str,
-    mapped_type: &str,
-    match_type: &str,
-    row_format: &Format,
-    indent_format: &Format,
-) -> Result<()> {
-    // Columns: Field Name | Field Type | Match Type | Related Entity | Mapped To | Mapped Type
-    sheet.write_string_with_format(
-        row,
-        0,
-        &format!("    {}", field_display_name),
-        indent_format,
-    )?;
-    sheet.write_string_with_format(row, 1, &format!("{:?}", field.field_type), row_format)?;
-    sheet.write_string_with_format(row, 2, match_type, row_format)?;
-
-    // Related Entity (only for Lookup fields)
-    let related_entity_str = field.related_entity.as_deref().unwrap_or("");
-    sheet.write_string_with_format(row, 3, related_entity_str, row_format)?;
-
-    sheet.write_string_with_format(row, 4, mapped_to, row_format)?;
-    sheet.write_string_with_format(row, 5, mapped_type, row_format)?;
-
-    Ok(())
-}
-
 /// Create source fields sheet with mapping information
-pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Result<()> {
+pub fn create_source_fields_sheet(
+    workbook: &mut Workbook,
+    state: &State,
+    columns: &[FieldColumn],
+) -> Result<()> {
     let sheet = workbook.add_worksheet();
     sheet.set_name("Source Fields")?;
 
@@ -75,17 +48,7 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
     )?;
 
     // Headers
-    let headers = [
-        "Field Name",
-        "Field Type",
-        "Match Type",
-        "Related Entity",
-        "Mapped To",
-        "Mapped Type",
-    ];
-    for (col, header) in headers.iter().enumerate() {
-        sheet.write_string_with_format(2, col as u16, *header, &header_format)?;
-    }
+    write_headers(sheet, 2, columns, "Mapped To", &header_format)?;
 
     let mut row = 3u32;
     let exact_match_format = create_exact_match_format();
@@ -272,6 +235,22 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
             })
             .collect();
 
+        let similar_matches: Vec<_> = mapped_fields
+            .iter()
+            .filter(|(entity_name, f)| {
+                let field_key = make_field_key(entity_name, &f.logical_name);
+                state
+                    .field_matches
+                    .get(&field_key)
+                    .and_then(|m| {
+                        m.primary_target()
+                            .and_then(|primary| m.match_types.get(primary))
+                    })
+                    .map(|mt| mt == &MatchType::Similar)
+                    .unwrap_or(false)
+            })
+            .collect();
+
         // Exact Matches
         if !exact_matches.is_empty() {
             sheet.write_string_with_format(
@@ -301,11 +280,14 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &target_fields_str,
-                        &target_types_str,
-                        "Exact",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &target_fields_str,
+                            mapped_type: &target_types_str,
+                            match_type: "Exact",
+                        },
                         &exact_match_format,
                         &indent_format,
                     )?;
@@ -344,11 +326,14 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &target_fields_str,
-                        &target_types_str,
-                        "Manual",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &target_fields_str,
+                            mapped_type: &target_types_str,
+                            match_type: "Manual",
+                        },
                         &manual_mapping_format,
                         &indent_format,
                     )?;
@@ -387,11 +372,14 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &target_fields_str,
-                        &target_types_str,
-                        "Prefix",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &target_fields_str,
+                            mapped_type: &target_types_str,
+                            match_type: "Prefix",
+                        },
                         &prefix_match_format,
                         &indent_format,
                     )?;
@@ -432,11 +420,14 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &target_fields_str,
-                        &target_types_str,
-                        "Type Mismatch",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &target_fields_str,
+                            mapped_type: &target_types_str,
+                            match_type: "Type Mismatch",
+                        },
                         &type_mismatch_format,
                         &indent_format,
                     )?;
@@ -475,11 +466,14 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &target_fields_str,
-                        &target_types_str,
-                        "Example",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &target_fields_str,
+                            mapped_type: &target_types_str,
+                            match_type: "Example",
+                        },
                         &example_value_format,
                         &indent_format,
                     )?;
@@ -518,11 +512,14 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &target_fields_str,
-                        &target_types_str,
-                        "Import",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &target_fields_str,
+                            mapped_type: &target_types_str,
+                            match_type: "Import",
+                        },
                         &manual_mapping_format,
                         &indent_format,
                     )?;
@@ -531,6 +528,52 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
             }
             row += 1;
         }
+
+        // Similar Name Matches
+        if !similar_matches.is_empty() {
+            sheet.write_string_with_format(
+                row,
+                0,
+                "  Similar Name Matches",
+                &Format::new().set_bold(),
+            )?;
+            row += 1;
+
+            for (entity_name, field) in similar_matches {
+                let field_key = make_field_key(entity_name, &field.logical_name);
+                let field_display_name = make_field_key(entity_name, &field.logical_name);
+                if let Some(match_info) = state.field_matches.get(&field_key) {
+                    let target_fields_str = match_info.target_fields.join(", ");
+                    let target_types_str = match_info
+                        .target_fields
+                        .iter()
+                        .map(|tf| {
+                            target_field_types
+                                .get(tf.as_str())
+                                .map(|s| s.as_str())
+                                .unwrap_or("Unknown")
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    write_field_row(
+                        sheet,
+                        row,
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &target_fields_str,
+                            mapped_type: &target_types_str,
+                            match_type: "Similar",
+                        },
+                        &example_value_format,
+                        &indent_format,
+                    )?;
+                    row += 1;
+                }
+            }
+            row += 1;
+        }
     }
 
     // UNMAPPED FIELDS Section
@@ -543,11 +586,14 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
             write_field_row(
                 sheet,
                 row,
-                &field_display_name,
-                field,
-                "",
-                "",
-                "Unmapped",
+                columns,
+                &FieldRow {
+                    display_name: &field_display_name,
+                    field,
+                    mapped: "",
+                    mapped_type: "",
+                    match_type: "Unmapped",
+                },
                 &unmapped_format,
                 &indent_format,
             )?;
@@ -592,11 +638,14 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
             write_field_row(
                 sheet,
                 row,
-                &field_display_name,
-                field,
-                &mapped_to,
-                &mapped_type,
-                &match_type,
+                columns,
+                &FieldRow {
+                    display_name: &field_display_name,
+                    field,
+                    mapped: &mapped_to,
+                    mapped_type: &mapped_type,
+                    match_type: &match_type,
+                },
                 &unmapped_format,
                 &indent_format,
             )?;
@@ -607,3 +656,52 @@ pub fn create_source_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
     sheet.autofit();
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::metadata::EntityMetadata;
+    use calamine::{Reader, Xlsx};
+
+    #[test]
+    fn test_sheet_has_only_selected_columns() {
+        let metadata = EntityMetadata {
+            fields: vec![FieldMetadata::string("fullname")],
+            ..Default::default()
+        };
+        let mut state = State {
+            source_env: "dev".to_string(),
+            source_entities: vec!["contact".to_string()],
+            target_entities: vec!["contact".to_string()],
+            ..Default::default()
+        };
+        state
+            .source_metadata
+            .insert("contact".to_string(), Resource::Success(metadata.clone()));
+        state
+            .target_metadata
+            .insert("contact".to_string(), Resource::Success(metadata));
+
+        let mut workbook = Workbook::new();
+        let columns = [FieldColumn::FieldName, FieldColumn::MatchType];
+        create_source_fields_sheet(&mut workbook, &state, &columns).unwrap();
+        let buffer = workbook.save_to_buffer().unwrap();
+
+        let mut xlsx = Xlsx::new(std::io::Cursor::new(buffer)).unwrap();
+        let range = xlsx.worksheet_range("Source Fields").unwrap();
+        let row = |r: u32| -> Vec<String> {
+            (0..range.width() as u32)
+                .map(|c| {
+                    range
+                        .get_value((r, c))
+                        .map(|v| v.to_string())
+                        .unwrap_or_default()
+                })
+                .collect()
+        };
+        assert_eq!(range.width(), 2);
+        assert_eq!(row(2), vec!["Field Name", "Match Type"]);
+        // Unmapped section header, then the field itself
+        assert_eq!(row(4), vec!["    fullname", "Unmapped"]);
+    }
+}
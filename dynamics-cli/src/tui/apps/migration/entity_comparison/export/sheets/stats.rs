@@ -8,10 +8,12 @@ use super::super::super::app::State;
 use super::super::formatting::*;
 use crate::tui::Resource;
 
+const SHEET_NAME: &str = "Statistics";
+
 /// Create statistics overview sheet
 pub fn create_stats_sheet(workbook: &mut Workbook, state: &State) -> Result<()> {
     let sheet = workbook.add_worksheet();
-    sheet.set_name("Statistics")?;
+    sheet.set_name(SHEET_NAME)?;
 
     let header_format = create_header_format();
     let title_format = create_title_format();
@@ -126,6 +128,7 @@ pub fn create_stats_sheet(workbook: &mut Workbook, state: &State) -> Result<()>
     let mut type_mismatch_count = 0;
     let mut example_count = 0;
     let mut import_count = 0;
+    let mut similar_count = 0;
 
     for (entity_name, field) in &all_source_fields {
         // Construct field key: qualified in multi-entity mode, simple otherwise
@@ -152,6 +155,7 @@ pub fn create_stats_sheet(workbook: &mut Workbook, state: &State) -> Result<()>
                         MatchType::TypeMismatch(_) => type_mismatch_count += 1,
                         MatchType::ExampleValue => example_count += 1,
                         MatchType::Import => import_count += 1,
+                        MatchType::Similar => similar_count += 1,
                     }
                 }
             }
@@ -173,6 +177,7 @@ pub fn create_stats_sheet(workbook: &mut Workbook, state: &State) -> Result<()>
 
     sheet.write_string(row, 0, "Mapped")?;
     sheet.write_number(row, 1, source_mapped as f64)?;
+    add_match_rate_scale(sheet, row, 2)?;
     sheet.write_number_with_format(
         row,
         2,
@@ -277,6 +282,18 @@ pub fn create_stats_sheet(workbook: &mut Workbook, state: &State) -> Result<()>
         row += 1;
     }
 
+    if similar_count > 0 {
+        sheet.write_string(row, 0, "  Similar Name Matches")?;
+        sheet.write_number(row, 1, similar_count as f64)?;
+        sheet.write_number_with_format(
+            row,
+            2,
+            similar_count as f64 / source_total as f64,
+            &percent_format,
+        )?;
+        row += 1;
+    }
+
     row += 2;
 
     // ===== TARGET STATISTICS =====
@@ -328,6 +345,7 @@ pub fn create_stats_sheet(workbook: &mut Workbook, state: &State) -> Result<()>
 
     sheet.write_string(row, 0, "Mapped")?;
     sheet.write_number(row, 1, target_mapped as f64)?;
+    add_match_rate_scale(sheet, row, 2)?;
     sheet.write_number_with_format(
         row,
         2,
@@ -368,12 +386,14 @@ pub fn create_stats_sheet(workbook: &mut Workbook, state: &State) -> Result<()>
     let source_coverage = source_mapped as f64 / source_total as f64;
     sheet.write_string(row, 0, "Source Coverage")?;
     sheet.write_number_with_format(row, 1, source_coverage, &percent_format)?;
+    add_match_rate_scale(sheet, row, 1)?;
     row += 1;
 
     // Target coverage (mapped / total)
     let target_coverage = target_mapped as f64 / target_total as f64;
     sheet.write_string(row, 0, "Target Coverage")?;
     sheet.write_number_with_format(row, 1, target_coverage, &percent_format)?;
+    add_match_rate_scale(sheet, row, 1)?;
     row += 1;
 
     // Type mismatch ratio (type mismatches / total mapped)
@@ -400,6 +420,130 @@ pub fn create_stats_sheet(workbook: &mut Workbook, state: &State) -> Result<()>
         row += 1;
     }
 
+    // ===== COVERAGE CHART =====
+    row += 1;
+    write_coverage_chart(
+        sheet,
+        row,
+        &[
+            ("Source Fields", source_mapped, source_unmapped),
+            ("Target Fields", target_mapped, target_unmapped),
+        ],
+        &header_format,
+        &bold_format,
+    )?;
+
     sheet.autofit();
     Ok(())
 }
+
+/// Colour a match-rate cell on a fixed red (0%) → yellow (50%) → green (100%) scale
+///
+/// The scale uses absolute bounds rather than the range's own min/max, so a
+/// single cell is coloured by its value alone.
+fn add_match_rate_scale(sheet: &mut Worksheet, row: u32, col: u16) -> Result<()> {
+    let scale = ConditionalFormat3ColorScale::new()
+        .set_minimum(ConditionalFormatType::Number, 0)
+        .set_midpoint(ConditionalFormatType::Number, 0.5)
+        .set_maximum(ConditionalFormatType::Number, 1)
+        .set_minimum_color("F8696B")
+        .set_midpoint_color("FFEB84")
+        .set_maximum_color("63BE7B");
+    sheet.add_conditional_format(row, col, row, col, &scale)?;
+    Ok(())
+}
+
+/// Write the mapped/unmapped table for each category and a stacked bar chart over it
+///
+/// The chart is anchored to the right of the statistics tables; the raw
+/// numbers stay in the table it references.
+fn write_coverage_chart(
+    sheet: &mut Worksheet,
+    start_row: u32,
+    categories: &[(&str, usize, usize)],
+    header_format: &Format,
+    bold_format: &Format,
+) -> Result<u32> {
+    let mut row = start_row;
+    sheet.write_string_with_format(row, 0, "COVERAGE CHART DATA", header_format)?;
+    row += 1;
+
+    sheet.write_string_with_format(row, 0, "Category", bold_format)?;
+    sheet.write_string_with_format(row, 1, "Mapped", bold_format)?;
+    sheet.write_string_with_format(row, 2, "Unmapped", bold_format)?;
+    let header_row = row;
+    row += 1;
+
+    for (label, mapped, unmapped) in categories {
+        sheet.write_string(row, 0, *label)?;
+        sheet.write_number(row, 1, *mapped as f64)?;
+        sheet.write_number(row, 2, *unmapped as f64)?;
+        row += 1;
+    }
+
+    if categories.is_empty() {
+        return Ok(row);
+    }
+
+    let first = header_row + 1;
+    let last = row - 1;
+    let mut chart = Chart::new(ChartType::BarStacked);
+    chart.title().set_name("Matched vs Unmatched Fields");
+    for (col, color) in [(1u16, "63BE7B"), (2u16, "F8696B")] {
+        chart
+            .add_series()
+            .set_name((SHEET_NAME, header_row, col))
+            .set_categories((SHEET_NAME, first, 0, last, 0))
+            .set_values((SHEET_NAME, first, col, last, col))
+            .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color(color)));
+    }
+    sheet.insert_chart(2, 4, &chart)?;
+
+    Ok(row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Read};
+
+    fn read_part(buffer: &[u8], name: &str) -> Option<String> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+        let mut file = archive.by_name(name).ok()?;
+        let mut xml = String::new();
+        file.read_to_string(&mut xml).unwrap();
+        Some(xml)
+    }
+
+    #[test]
+    fn test_coverage_chart_and_match_rate_scale_are_written() {
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        sheet.set_name(SHEET_NAME).unwrap();
+
+        sheet.write_number(0, 2, 0.75).unwrap();
+        add_match_rate_scale(sheet, 0, 2).unwrap();
+        let next_row = write_coverage_chart(
+            sheet,
+            2,
+            &[("Source Fields", 30, 10), ("Target Fields", 25, 15)],
+            &Format::new(),
+            &Format::new(),
+        )
+        .unwrap();
+        assert_eq!(next_row, 6);
+
+        let buffer = workbook.save_to_buffer().unwrap();
+
+        let chart = read_part(&buffer, "xl/charts/chart1.xml").expect("chart part");
+        assert!(chart.contains("<c:barChart>"));
+        assert!(chart.contains("Statistics!$A$5:$A$6"));
+
+        let sheet_xml = read_part(&buffer, "xl/worksheets/sheet1.xml").unwrap();
+        assert!(sheet_xml.contains(r#"<conditionalFormatting sqref="C1">"#));
+        assert!(sheet_xml.contains(r#"type="colorScale""#));
+        assert!(sheet_xml.contains(r#"<cfvo type="num" val="0.5"/>"#));
+        // Raw numbers are still in the sheet
+        assert!(sheet_xml.contains("<v>30</v>"));
+    }
+}
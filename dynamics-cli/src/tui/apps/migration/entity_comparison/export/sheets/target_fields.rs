@@ -6,44 +6,17 @@ use std::collections::HashMap;
 
 use super::super::super::app::State;
 use super::super::super::{MatchInfo, MatchType};
+use super::super::columns::{FieldColumn, FieldRow, write_field_row, write_headers};
 use super::super::formatting::*;
 use crate::api::metadata::FieldMetadata;
 use crate::tui::Resource;
 
-/// Write a field row with the new column structure (no Required/Primary Key)
-fn write_field_row(
-    sheet: &mut Worksheet,
-    row: u32,
-    field_display_name: &str,
-    field: &FieldMetadata,
-    mapped_from: &str,
-    mapped_type: &str,
-    match_type: &str,
-    row_format: &Format,
-    indent_format: &Format,
-) -> Result<()> {
-    // Columns: Field Name | Field Type | Match Type | Related Entity | Mapped From | Mapped Type
-    sheet.write_string_with_format(
-        row,
-        0,
-        &format!("    {}", field_display_name),
-        indent_format,
-    )?;
-    sheet.write_string_with_format(row, 1, &format!("{:?}", field.field_type), row_format)?;
-    sheet.write_string_with_format(row, 2, match_type, row_format)?;
-
-    // Related Entity (only for Lookup fields)
-    let related_entity_str = field.related_entity.as_deref().unwrap_or("");
-    sheet.write_string_with_format(row, 3, related_entity_str, row_format)?;
-
-    sheet.write_string_with_format(row, 4, mapped_from, row_format)?;
-    sheet.write_string_with_format(row, 5, mapped_type, row_format)?;
-
-    Ok(())
-}
-
 /// Create target fields sheet with mapping information (reverse perspective)
-pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Result<()> {
+pub fn create_target_fields_sheet(
+    workbook: &mut Workbook,
+    state: &State,
+    columns: &[FieldColumn],
+) -> Result<()> {
     let sheet = workbook.add_worksheet();
     sheet.set_name("Target Fields")?;
 
@@ -76,17 +49,7 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
     )?;
 
     // Headers
-    let headers = [
-        "Field Name",
-        "Field Type",
-        "Match Type",
-        "Related Entity",
-        "Mapped From",
-        "Mapped Type",
-    ];
-    for (col, header) in headers.iter().enumerate() {
-        sheet.write_string_with_format(2, col as u16, *header, &header_format)?;
-    }
+    write_headers(sheet, 2, columns, "Mapped From", &header_format)?;
 
     let mut row = 3u32;
     let exact_match_format = create_exact_match_format();
@@ -267,6 +230,18 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
             })
             .collect();
 
+        let similar_matches: Vec<_> = mapped_fields
+            .iter()
+            .filter(|(entity_name, f)| {
+                let field_key = make_field_key(entity_name, &f.logical_name);
+                reverse_matches
+                    .get(&field_key)
+                    .and_then(|sources| sources.first())
+                    .map(|(_, mt)| mt == &MatchType::Similar)
+                    .unwrap_or(false)
+            })
+            .collect();
+
         // Exact Matches
         if !exact_matches.is_empty() {
             sheet.write_string_with_format(
@@ -296,11 +271,14 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &source_names_str,
-                        &source_types_str,
-                        "Exact",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &source_names_str,
+                            mapped_type: &source_types_str,
+                            match_type: "Exact",
+                        },
                         &exact_match_format,
                         &indent_format,
                     )?;
@@ -339,11 +317,14 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &source_names_str,
-                        &source_types_str,
-                        "Manual",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &source_names_str,
+                            mapped_type: &source_types_str,
+                            match_type: "Manual",
+                        },
                         &manual_mapping_format,
                         &indent_format,
                     )?;
@@ -382,11 +363,14 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &source_names_str,
-                        &source_types_str,
-                        "Prefix",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &source_names_str,
+                            mapped_type: &source_types_str,
+                            match_type: "Prefix",
+                        },
                         &prefix_match_format,
                         &indent_format,
                     )?;
@@ -427,11 +411,14 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &source_names_str,
-                        &source_types_str,
-                        "Type Mismatch",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &source_names_str,
+                            mapped_type: &source_types_str,
+                            match_type: "Type Mismatch",
+                        },
                         &type_mismatch_format,
                         &indent_format,
                     )?;
@@ -470,11 +457,14 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &source_names_str,
-                        &source_types_str,
-                        "Example",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &source_names_str,
+                            mapped_type: &source_types_str,
+                            match_type: "Example",
+                        },
                         &example_value_format,
                         &indent_format,
                     )?;
@@ -513,11 +503,14 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
                     write_field_row(
                         sheet,
                         row,
-                        &field_display_name,
-                        field,
-                        &source_names_str,
-                        &source_types_str,
-                        "Import",
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &source_names_str,
+                            mapped_type: &source_types_str,
+                            match_type: "Import",
+                        },
                         &manual_mapping_format,
                         &indent_format,
                     )?;
@@ -526,6 +519,52 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
             }
             row += 1;
         }
+
+        // Similar Name Matches
+        if !similar_matches.is_empty() {
+            sheet.write_string_with_format(
+                row,
+                0,
+                "  Similar Name Matches",
+                &Format::new().set_bold(),
+            )?;
+            row += 1;
+            for (entity_name, field) in similar_matches {
+                let field_key = make_field_key(entity_name, &field.logical_name);
+                let field_display_name = make_field_key(entity_name, &field.logical_name);
+                if let Some(sources) = reverse_matches.get(&field_key) {
+                    let source_names: Vec<String> =
+                        sources.iter().map(|(name, _)| name.clone()).collect();
+                    let source_names_str = source_names.join(", ");
+                    let source_types_str = source_names
+                        .iter()
+                        .map(|sn| {
+                            source_field_types
+                                .get(sn)
+                                .map(|s| s.as_str())
+                                .unwrap_or("Unknown")
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    write_field_row(
+                        sheet,
+                        row,
+                        columns,
+                        &FieldRow {
+                            display_name: &field_display_name,
+                            field,
+                            mapped: &source_names_str,
+                            mapped_type: &source_types_str,
+                            match_type: "Similar",
+                        },
+                        &example_value_format,
+                        &indent_format,
+                    )?;
+                    row += 1;
+                }
+            }
+            row += 1;
+        }
     }
 
     // UNMAPPED FIELDS Section
@@ -538,11 +577,14 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
             write_field_row(
                 sheet,
                 row,
-                &field_display_name,
-                field,
-                "",
-                "",
-                "Unmapped",
+                columns,
+                &FieldRow {
+                    display_name: &field_display_name,
+                    field,
+                    mapped: "",
+                    mapped_type: "",
+                    match_type: "Unmapped",
+                },
                 &unmapped_format,
                 &indent_format,
             )?;
@@ -587,11 +629,14 @@ pub fn create_target_fields_sheet(workbook: &mut Workbook, state: &State) -> Res
             write_field_row(
                 sheet,
                 row,
-                &field_display_name,
-                field,
-                &mapped_from,
-                &mapped_type,
-                &match_type,
+                columns,
+                &FieldRow {
+                    display_name: &field_display_name,
+                    field,
+                    mapped: &mapped_from,
+                    mapped_type: &mapped_type,
+                    match_type: &match_type,
+                },
                 &unmapped_format,
                 &indent_format,
             )?;
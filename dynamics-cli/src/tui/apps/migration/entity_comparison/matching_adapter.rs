@@ -2,7 +2,7 @@
 //! This provides the old interface while using the new service underneath
 //!
 //! Architecture:
-//! - Service provides core matching logic (Manual, Import, Exact, Prefix)
+//! - Service provides core matching logic (Manual, Import, Exact, Prefix, Similar)
 //! - Adapter augments with UI-specific features (example value matching)
 
 use crate::api::EntityMetadata;
@@ -94,9 +94,10 @@ pub fn recompute_all_matches(
         prefix_mappings: prefix_mappings.clone(),
         imported_mappings: imported_mappings.clone(),
         negative_matches: negative_matches.clone(),
+        name_similarity: crate::global_runtime_config().name_similarity,
     };
 
-    // Get base matches from service (Manual, Import, Exact, Prefix)
+    // Get base matches from service (Manual, Import, Exact, Prefix, Similar)
     let results = matching::compute_all_matches(&context, &mappings);
     let mut field_matches = results.field_matches;
 
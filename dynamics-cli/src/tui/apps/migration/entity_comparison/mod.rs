@@ -34,6 +34,8 @@ pub enum Msg {
     TargetTreeNodeClicked(String), // Node clicked in target tree
     SourceTreeFocused,           // Source tree gained focus
     TargetTreeFocused,           // Target tree gained focus
+    CollapseAllTrees,            // Collapse every node in both trees of the active tab
+    ExpandAllTrees,              // Expand every node in both trees of the active tab
     CreateManualMapping,         // Create mapping from selected source to selected target
     DeleteManualMapping,         // Delete mapping from selected field
     DeleteImportedMapping,       // Delete imported mapping from selected field
@@ -86,6 +88,10 @@ pub enum Msg {
     NegativeMatchesListSelect(usize),
     DeleteNegativeMatch,
     AddNegativeMatchFromTree, // Context-aware 'd' key on prefix-matched field
+    ExportNegativeMatches,
+    OpenNegativeMatchesImport,
+    NegativeMatchesFileLoaded(Result<Vec<String>, String>),
+    NegativeMatchesImported(Result<usize, String>),
 
     // Manual mappings modal messages
     OpenManualMappingsModal,
@@ -97,7 +103,7 @@ pub enum Msg {
     // Search messages
     ToggleSearch,     // Focus search (triggered by `/`)
     ToggleSearchMode, // Toggle between Unified and Independent modes (Ctrl+/)
-    ToggleMatchMode,  // Toggle between Fuzzy and Substring match modes (f)
+    ToggleMatchMode,  // Cycle between Fuzzy, Substring and Regex match modes (f)
     SearchInputEvent(crate::tui::widgets::TextInputEvent), // Unified search
     SourceSearchEvent(crate::tui::widgets::TextInputEvent), // Independent: source search
     TargetSearchEvent(crate::tui::widgets::TextInputEvent), // Independent: target search
@@ -107,14 +113,34 @@ pub enum Msg {
     ClearSearch,      // Clear search (Esc when focused)
     SearchSelectFirstMatch, // Enter in search box
 
+    // Search presets modal messages
+    OpenSearchPresetsModal,
+    CloseSearchPresetsModal,
+    SearchPresetsLoaded(Vec<crate::config::repository::search_presets::SearchPreset>),
+    SearchPresetsListNavigate(crossterm::event::KeyCode),
+    SearchPresetsListSelect(usize),
+    SearchPresetsListActivate(usize),
+    SearchPresetNameInputEvent(crate::tui::widgets::TextInputEvent),
+    SaveSearchPreset,
+    LoadSearchPreset,
+    DeleteSearchPreset,
+
     // Type filter messages
     ToggleTypeFilterMode, // Toggle between Unified and Independent modes (Shift+T)
     CycleSourceTypeFilter, // Cycle through source types (t)
     CycleTargetTypeFilter, // Cycle through target types (T)
 
     // Export
-    ExportToExcel,
+    ExportToExcel, // Opens the column selection modal
+    ExportColumnsLoaded(Vec<String>),
+    ExportColumnsListNavigate(crossterm::event::KeyCode),
+    ExportColumnsListSelect(usize),
+    ToggleExportColumn(usize),
+    ConfirmExportToExcel,
+    CloseExportColumnsModal,
     ExportUnmappedToCsv,
+    SeedTransferConfig,
+    TransferConfigSeeded(Result<(String, usize), String>),
 
     // Import from C# file
     OpenImportModal,
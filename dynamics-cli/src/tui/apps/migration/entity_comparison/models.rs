@@ -78,6 +78,30 @@ impl HideMode {
         }
     }
 
+    /// Stable key used when persisting the mode (e.g. in search presets)
+    pub fn key(&self) -> &'static str {
+        match self {
+            HideMode::Off => "off",
+            HideMode::HideMatched => "hide_matched",
+            HideMode::HideIgnored => "hide_ignored",
+            HideMode::HideMatchedAndIgnored => "hide_matched_and_ignored",
+            HideMode::HideExamples => "hide_examples",
+            HideMode::HideAll => "hide_all",
+        }
+    }
+
+    /// Parse a persisted key, falling back to the default for unknown keys
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "hide_matched" => HideMode::HideMatched,
+            "hide_ignored" => HideMode::HideIgnored,
+            "hide_matched_and_ignored" => HideMode::HideMatchedAndIgnored,
+            "hide_examples" => HideMode::HideExamples,
+            "hide_all" => HideMode::HideAll,
+            _ => HideMode::Off,
+        }
+    }
+
     pub fn toggle(&self) -> Self {
         match self {
             HideMode::Off => HideMode::HideMatched,
@@ -195,6 +219,7 @@ pub enum MatchMode {
     #[default]
     Fuzzy, // Fuzzy matching (typo-tolerant, approximate)
     Substring, // Case-insensitive substring matching (exact)
+    Regex,     // Case-insensitive regular expression matching
 }
 
 impl MatchMode {
@@ -202,13 +227,68 @@ impl MatchMode {
         match self {
             MatchMode::Fuzzy => "Fuzzy",
             MatchMode::Substring => "Substring",
+            MatchMode::Regex => "Regex",
+        }
+    }
+
+    /// Stable key used when persisting the mode (e.g. in search presets)
+    pub fn key(&self) -> &'static str {
+        match self {
+            MatchMode::Fuzzy => "fuzzy",
+            MatchMode::Substring => "substring",
+            MatchMode::Regex => "regex",
+        }
+    }
+
+    /// Parse a persisted key, falling back to the default for unknown keys
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "substring" => MatchMode::Substring,
+            "regex" => MatchMode::Regex,
+            _ => MatchMode::Fuzzy,
         }
     }
 
     pub fn toggle(&self) -> Self {
         match self {
             MatchMode::Fuzzy => MatchMode::Substring,
-            MatchMode::Substring => MatchMode::Fuzzy,
+            MatchMode::Substring => MatchMode::Regex,
+            MatchMode::Regex => MatchMode::Fuzzy,
+        }
+    }
+}
+
+/// A search query compiled for a specific match mode
+pub enum SearchMatcher {
+    Fuzzy(fuzzy_matcher::skim::SkimMatcherV2, String),
+    Substring(String),
+    Regex(regex::Regex),
+}
+
+impl SearchMatcher {
+    /// Compile a query for the given mode. Only regex mode can fail.
+    pub fn new(query: &str, match_mode: MatchMode) -> Result<Self, String> {
+        match match_mode {
+            MatchMode::Fuzzy => Ok(SearchMatcher::Fuzzy(
+                fuzzy_matcher::skim::SkimMatcherV2::default(),
+                query.to_string(),
+            )),
+            MatchMode::Substring => Ok(SearchMatcher::Substring(query.to_lowercase())),
+            MatchMode::Regex => regex::RegexBuilder::new(query)
+                .case_insensitive(true)
+                .build()
+                .map(SearchMatcher::Regex)
+                .map_err(|e| e.to_string()),
+        }
+    }
+
+    pub fn matches(&self, text: &str) -> bool {
+        use fuzzy_matcher::FuzzyMatcher;
+
+        match self {
+            SearchMatcher::Fuzzy(matcher, query) => matcher.fuzzy_match(text, query).is_some(),
+            SearchMatcher::Substring(query_lower) => text.to_lowercase().contains(query_lower),
+            SearchMatcher::Regex(regex) => regex.is_match(text),
         }
     }
 }
@@ -277,6 +357,19 @@ pub struct ExamplePair {
     pub source_record_id: String,
     pub target_record_id: String,
     pub label: Option<String>,
+    /// Record data imported with the pair, shown instead of fetching the records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedded_data: Option<ExampleRecordData>,
+}
+
+/// Source and target record JSON for an example pair
+///
+/// Embedded in exported comparison bundles so the examples still resolve in
+/// an environment where the record IDs don't exist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExampleRecordData {
+    pub source: serde_json::Value,
+    pub target: serde_json::Value,
 }
 
 impl ExamplePair {
@@ -286,6 +379,7 @@ impl ExamplePair {
             source_record_id,
             target_record_id,
             label: None,
+            embedded_data: None,
         }
     }
 
@@ -519,3 +613,32 @@ impl ExamplesState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regex_matcher_anchored_pattern() {
+        let fields = [
+            "nrq_accountid",
+            "nrq_contactid",
+            "nrq_name",
+            "old_nrq_parentid",
+            "NRQ_OwnerId",
+        ];
+
+        let matcher = SearchMatcher::new("^nrq_.*id$", MatchMode::Regex).unwrap();
+        let matched: Vec<&str> = fields
+            .iter()
+            .copied()
+            .filter(|f| matcher.matches(f))
+            .collect();
+
+        assert_eq!(
+            matched,
+            vec!["nrq_accountid", "nrq_contactid", "NRQ_OwnerId"]
+        );
+        assert!(SearchMatcher::new("nrq_(", MatchMode::Regex).is_err());
+    }
+}
@@ -250,6 +250,7 @@ impl TreeItem for FieldNode {
                 Some(MatchType::Manual) => theme.accent_success, // User override
                 Some(MatchType::Import) => theme.accent_success, // Imported from C# file
                 Some(MatchType::ExampleValue) => theme.palette_4, // Example value match
+                Some(MatchType::Similar) => theme.palette_4,    // Name similarity match
                 Some(MatchType::TypeMismatch(_)) => theme.accent_warning, // Name match but type differs
                 None => theme.accent_error,                               // No match
             }
@@ -397,6 +398,7 @@ impl TreeItem for RelationshipNode {
                 Some(MatchType::Manual) => theme.accent_success, // User override
                 Some(MatchType::Import) => theme.accent_success, // Imported from C# file
                 Some(MatchType::ExampleValue) => theme.palette_4, // Example value match
+                Some(MatchType::Similar) => theme.palette_4,    // Name similarity match
                 Some(MatchType::TypeMismatch(_)) => theme.accent_warning, // Name match but type differs
                 None => theme.accent_error,                               // No match
             }
@@ -665,6 +667,7 @@ impl TreeItem for EntityNode {
                 Some(MatchType::Manual) => theme.accent_success, // User override
                 Some(MatchType::Import) => theme.accent_success, // Imported from C# file
                 Some(MatchType::ExampleValue) => theme.palette_4, // Example value match
+                Some(MatchType::Similar) => theme.palette_4,    // Name similarity match
                 Some(MatchType::TypeMismatch(_)) => theme.accent_warning, // Should not happen for entities
                 None => theme.accent_error,                               // No match
             }
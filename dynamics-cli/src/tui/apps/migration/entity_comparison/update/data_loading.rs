@@ -620,9 +620,14 @@ pub fn handle_mappings_loaded(
                 )
             };
 
+        let embedded_data = pair.embedded_data.clone();
         builder = builder.add_task(
             format!("Loading example: {}", pair.display_name()),
             async move {
+                // Pairs imported with record data don't need the records to exist here
+                if let Some(data) = embedded_data {
+                    return Ok(FetchedData::ExampleData(pair_id, data.source, data.target));
+                }
                 super::super::fetch_example_pair_data(
                     &source_env,
                     &source_entity,
@@ -778,9 +783,14 @@ pub fn handle_refresh(state: &mut State) -> Command<Msg> {
                 )
             };
 
+        let embedded_data = pair.embedded_data.clone();
         builder = builder.add_task(
             format!("Refreshing example: {}", pair.display_name()),
             async move {
+                // Pairs imported with record data don't need the records to exist here
+                if let Some(data) = embedded_data {
+                    return Ok(FetchedData::ExampleData(pair_id, data.source, data.target));
+                }
                 super::super::fetch_example_pair_data(
                     &source_env,
                     &source_entity,
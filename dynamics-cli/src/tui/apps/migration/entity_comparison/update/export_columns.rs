@@ -0,0 +1,110 @@
+//! Export column selection handlers - choose which field attributes the Excel export writes
+
+use super::super::Msg;
+use super::super::app::State;
+use super::super::export::columns::FieldColumn;
+use crate::tui::command::Command;
+
+/// First source/target entity pair, used as the persistence key for the comparison
+fn comparison_key(state: &State) -> (String, String) {
+    (
+        state.source_entities.first().cloned().unwrap_or_default(),
+        state.target_entities.first().cloned().unwrap_or_default(),
+    )
+}
+
+pub fn handle_open_modal(state: &mut State) -> Command<Msg> {
+    if !state.all_metadata_loaded() {
+        log::warn!("Cannot export: metadata not fully loaded");
+        return Command::None;
+    }
+
+    state.show_export_columns_modal = true;
+    state.export_columns_list_state.select(Some(0));
+
+    let (source_entity, target_entity) = comparison_key(state);
+    Command::perform(
+        async move {
+            let config = crate::global_config();
+            config
+                .get_export_columns(&source_entity, &target_entity)
+                .await
+                .unwrap_or_else(|e| {
+                    log::error!("Failed to load export columns: {}", e);
+                    Vec::new()
+                })
+        },
+        Msg::ExportColumnsLoaded,
+    )
+}
+
+pub fn handle_close_modal(state: &mut State) -> Command<Msg> {
+    state.show_export_columns_modal = false;
+    Command::None
+}
+
+pub fn handle_columns_loaded(state: &mut State, keys: Vec<String>) -> Command<Msg> {
+    state.export_columns = FieldColumn::from_keys(&keys);
+    Command::None
+}
+
+pub fn handle_list_navigate(state: &mut State, key: crossterm::event::KeyCode) -> Command<Msg> {
+    state
+        .export_columns_list_state
+        .handle_key(key, FieldColumn::ALL.len(), 10);
+    Command::None
+}
+
+pub fn handle_list_select(state: &mut State, index: usize) -> Command<Msg> {
+    state
+        .export_columns_list_state
+        .select_and_scroll(Some(index), FieldColumn::ALL.len());
+    Command::None
+}
+
+/// Toggle a column on or off, keeping the selection in sheet order
+pub fn handle_toggle_column(state: &mut State, index: usize) -> Command<Msg> {
+    let Some(column) = FieldColumn::ALL.get(index).copied() else {
+        return Command::None;
+    };
+    state
+        .export_columns_list_state
+        .select_and_scroll(Some(index), FieldColumn::ALL.len());
+
+    if state.export_columns.contains(&column) {
+        state.export_columns.retain(|c| *c != column);
+    } else {
+        state.export_columns = FieldColumn::ALL
+            .into_iter()
+            .filter(|c| *c == column || state.export_columns.contains(c))
+            .collect();
+    }
+    Command::None
+}
+
+/// Persist the selection for this comparison and run the export
+pub fn handle_confirm_export(state: &mut State) -> Command<Msg> {
+    if state.export_columns.is_empty() {
+        log::warn!("Cannot export: no columns selected");
+        return Command::None;
+    }
+    state.show_export_columns_modal = false;
+
+    let (source_entity, target_entity) = comparison_key(state);
+    let keys: Vec<String> = state
+        .export_columns
+        .iter()
+        .map(|c| c.key().to_string())
+        .collect();
+    tokio::spawn(async move {
+        let config = crate::global_config();
+        if let Err(e) = config
+            .set_export_columns(&source_entity, &target_entity, &keys)
+            .await
+        {
+            log::error!("Failed to save export columns: {}", e);
+        }
+    });
+
+    super::mappings::handle_export_to_excel(state)
+}
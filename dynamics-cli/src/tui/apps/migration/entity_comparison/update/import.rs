@@ -29,6 +29,7 @@ pub fn handle_open_modal(state: &mut State) -> Command<Msg> {
 /// Close the import modal
 pub fn handle_close_modal(state: &mut State) -> Command<Msg> {
     state.show_import_modal = false;
+    state.import_negative_matches = false;
     Command::None
 }
 
@@ -80,7 +81,11 @@ pub fn handle_navigate(state: &mut State, key: KeyCode) -> Command<Msg> {
 }
 
 /// Handle file selection - read and parse the file (.cs or .csv)
-pub fn handle_file_selected(_state: &mut State, path: PathBuf) -> Command<Msg> {
+pub fn handle_file_selected(state: &mut State, path: PathBuf) -> Command<Msg> {
+    if state.import_negative_matches {
+        return super::negative_matches::read_file(path);
+    }
+
     // Detect file type by extension
     let is_csv = path
         .extension()
@@ -92,13 +97,13 @@ pub fn handle_file_selected(_state: &mut State, path: PathBuf) -> Command<Msg> {
         // Parse CSV file
         Command::perform(
             async move {
-                // Read file
-                let content = tokio::fs::read_to_string(&path)
+                // Read raw bytes; encoding and delimiter are detected by the parser
+                let bytes = tokio::fs::read(&path)
                     .await
                     .map_err(|e| format!("Failed to read file: {}", e))?;
 
                 // Parse CSV mappings
-                let csv_data = crate::csv_parser::parse_csv_field_mappings(&content)?;
+                let csv_data = crate::csv_parser::parse_csv_field_mappings_bytes(&bytes)?;
 
                 // Extract filename
                 let filename = path
@@ -849,9 +849,11 @@ pub fn handle_export_to_excel(state: &mut State) -> Command<Msg> {
 
     // Perform export in background
     let state_clone = state.clone();
+    let columns = state.export_columns.clone();
     tokio::spawn(async move {
         match super::super::export::MigrationExporter::export_and_open(
             &state_clone,
+            &columns,
             output_path.to_str().unwrap(),
         ) {
             Ok(_) => {
@@ -903,3 +905,84 @@ pub fn handle_export_unmapped_to_csv(state: &mut State) -> Command<Msg> {
 
     Command::None
 }
+
+/// Promote confident field matches into the comparison's transfer config
+///
+/// The config is named after the migration and source entities and created if
+/// it doesn't exist yet. Matches below `migration.seed_min_confidence` are left
+/// for the user to map by hand.
+pub fn handle_seed_transfer_config(state: &mut State) -> Command<Msg> {
+    if state.field_matches.is_empty() {
+        log::warn!("Cannot seed transfer config: no field matches");
+        return Command::None;
+    }
+
+    let config_name = format!(
+        "{} - {}",
+        state.migration_name,
+        state.source_entities.join(", ")
+    );
+    let source_env = state.source_env.clone();
+    let target_env = state.target_env.clone();
+    let source_entities = state.source_entities.clone();
+    let target_entities = state.target_entities.clone();
+    let field_matches = state.field_matches.clone();
+
+    Command::perform(
+        async move {
+            let config = crate::global_config();
+            let min_confidence = config
+                .options
+                .get_float("migration.seed_min_confidence")
+                .await
+                .unwrap_or(0.9);
+
+            let mut transfer = crate::config::repository::transfer::get_transfer_config(
+                &config.pool,
+                &config_name,
+            )
+            .await
+            .map_err(|e| e.to_string())?
+            .unwrap_or_else(|| {
+                crate::transfer::TransferConfig::new(&config_name, source_env, target_env)
+            });
+
+            let mut added = 0;
+            for source_entity in &source_entities {
+                for target_entity in &target_entities {
+                    added += crate::services::matching::transfer_seed::seed_transfer_config(
+                        &mut transfer,
+                        source_entity,
+                        target_entity,
+                        &field_matches,
+                        min_confidence,
+                    );
+                }
+            }
+
+            crate::config::repository::transfer::save_transfer_config(&config.pool, &transfer)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok((config_name, added))
+        },
+        Msg::TransferConfigSeeded,
+    )
+}
+
+/// Log the outcome of seeding a transfer config
+pub fn handle_transfer_config_seeded(
+    _state: &mut State,
+    result: Result<(String, usize), String>,
+) -> Command<Msg> {
+    match result {
+        Ok((name, added)) => {
+            log::info!(
+                "Seeded transfer config '{}' with {} field mappings",
+                name,
+                added
+            )
+        }
+        Err(e) => log::error!("Failed to seed transfer config: {}", e),
+    }
+    Command::None
+}
@@ -1,5 +1,6 @@
 pub mod data_loading;
 pub mod examples;
+pub mod export_columns;
 pub mod ignore;
 pub mod import;
 pub mod manual_mappings;
@@ -8,6 +9,7 @@ pub mod navigation;
 pub mod negative_matches;
 pub mod prefix_mappings;
 pub mod search;
+pub mod search_presets;
 pub mod tree_events;
 pub mod type_filter;
 
@@ -28,6 +30,8 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
         Msg::ConfirmBack => navigation::handle_confirm_back(),
         Msg::CancelBack => navigation::handle_cancel_back(state),
         Msg::SwitchTab(n) => navigation::handle_switch_tab(state, n),
+        Msg::CollapseAllTrees => navigation::handle_collapse_all(state),
+        Msg::ExpandAllTrees => navigation::handle_expand_all(state),
 
         // Tree events
         Msg::SourceTreeEvent(event) => tree_events::handle_source_tree_event(state, event),
@@ -101,6 +105,12 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
         Msg::AddNegativeMatchFromTree => {
             negative_matches::handle_add_negative_match_from_tree(state)
         }
+        Msg::ExportNegativeMatches => negative_matches::handle_export(state),
+        Msg::OpenNegativeMatchesImport => negative_matches::handle_open_import(state),
+        Msg::NegativeMatchesFileLoaded(result) => {
+            negative_matches::handle_file_loaded(state, result)
+        }
+        Msg::NegativeMatchesImported(result) => negative_matches::handle_imported(state, result),
 
         // Manual mappings
         Msg::OpenManualMappingsModal => manual_mappings::handle_open_modal(state),
@@ -122,14 +132,36 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
         Msg::ClearSearch => search::handle_clear_search(state),
         Msg::SearchSelectFirstMatch => search::handle_search_select_first_match(state),
 
+        // Search presets
+        Msg::OpenSearchPresetsModal => search_presets::handle_open_modal(state),
+        Msg::CloseSearchPresetsModal => search_presets::handle_close_modal(state),
+        Msg::SearchPresetsLoaded(presets) => search_presets::handle_presets_loaded(state, presets),
+        Msg::SearchPresetsListNavigate(key) => search_presets::handle_list_navigate(state, key),
+        Msg::SearchPresetsListSelect(idx) => search_presets::handle_list_select(state, idx),
+        Msg::SearchPresetsListActivate(idx) => search_presets::handle_list_activate(state, idx),
+        Msg::SearchPresetNameInputEvent(event) => {
+            search_presets::handle_name_input_event(state, event)
+        }
+        Msg::SaveSearchPreset => search_presets::handle_save_preset(state),
+        Msg::LoadSearchPreset => search_presets::handle_load_preset(state),
+        Msg::DeleteSearchPreset => search_presets::handle_delete_preset(state),
+
         // Type filtering
         Msg::ToggleTypeFilterMode => type_filter::handle_toggle_type_filter_mode(state),
         Msg::CycleSourceTypeFilter => type_filter::handle_cycle_source_type_filter(state),
         Msg::CycleTargetTypeFilter => type_filter::handle_cycle_target_type_filter(state),
 
         // Export
-        Msg::ExportToExcel => mappings::handle_export_to_excel(state),
+        Msg::ExportToExcel => export_columns::handle_open_modal(state),
+        Msg::ExportColumnsLoaded(keys) => export_columns::handle_columns_loaded(state, keys),
+        Msg::ExportColumnsListNavigate(key) => export_columns::handle_list_navigate(state, key),
+        Msg::ExportColumnsListSelect(idx) => export_columns::handle_list_select(state, idx),
+        Msg::ToggleExportColumn(idx) => export_columns::handle_toggle_column(state, idx),
+        Msg::ConfirmExportToExcel => export_columns::handle_confirm_export(state),
+        Msg::CloseExportColumnsModal => export_columns::handle_close_modal(state),
         Msg::ExportUnmappedToCsv => mappings::handle_export_unmapped_to_csv(state),
+        Msg::SeedTransferConfig => mappings::handle_seed_transfer_config(state),
+        Msg::TransferConfigSeeded(result) => mappings::handle_transfer_config_seeded(state, result),
 
         // Import from C# file or CSV
         Msg::OpenImportModal => import::handle_open_modal(state),
@@ -22,3 +22,19 @@ pub fn handle_switch_tab(state: &mut State, n: usize) -> Command<Msg> {
     }
     Command::None
 }
+
+pub fn handle_collapse_all(state: &mut State) -> Command<Msg> {
+    state.source_tree_for_tab().collapse_all();
+    state.target_tree_for_tab().collapse_all();
+    Command::None
+}
+
+pub fn handle_expand_all(state: &mut State) -> Command<Msg> {
+    let Some(cache) = state.tree_cache.take() else {
+        return Command::None;
+    };
+    state.source_tree_for_tab().expand_all(&cache.source_items);
+    state.target_tree_for_tab().expand_all(&cache.target_items);
+    state.tree_cache = Some(cache);
+    Command::None
+}
@@ -17,6 +17,93 @@ fn parse_qualified_name<'a>(name: &'a str, default_entity: &'a str) -> (&'a str,
     }
 }
 
+/// Recompute all matches after the negative match set changed
+fn recompute_matches(state: &mut State) {
+    let is_multi_entity = state.source_entities.len() > 1 || state.target_entities.len() > 1;
+
+    if is_multi_entity {
+        // Multi-entity mode: use recompute_all_matches_multi()
+        let source_metadata_map: HashMap<String, crate::api::EntityMetadata> = state
+            .source_metadata
+            .iter()
+            .filter_map(|(name, resource)| {
+                if let Resource::Success(metadata) = resource {
+                    Some((name.clone(), metadata.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let target_metadata_map: HashMap<String, crate::api::EntityMetadata> = state
+            .target_metadata
+            .iter()
+            .filter_map(|(name, resource)| {
+                if let Resource::Success(metadata) = resource {
+                    Some((name.clone(), metadata.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let (
+            field_matches,
+            relationship_matches,
+            entity_matches,
+            source_related_entities,
+            target_related_entities,
+        ) = recompute_all_matches_multi(
+            &source_metadata_map,
+            &target_metadata_map,
+            &state.source_entities,
+            &state.target_entities,
+            &state.field_mappings,
+            &state.imported_mappings,
+            &state.prefix_mappings,
+            &state.examples,
+            &state.negative_matches,
+        );
+        state.field_matches = field_matches;
+        state.relationship_matches = relationship_matches;
+        state.entity_matches = entity_matches;
+        state.source_related_entities = source_related_entities;
+        state.target_related_entities = target_related_entities;
+    } else {
+        // Single-entity mode: use first entity (backwards compatible)
+        let first_source_entity = state.source_entities.first().cloned().unwrap_or_default();
+        let first_target_entity = state.target_entities.first().cloned().unwrap_or_default();
+
+        if let (Some(Resource::Success(source)), Some(Resource::Success(target))) = (
+            state.source_metadata.get(&first_source_entity),
+            state.target_metadata.get(&first_target_entity),
+        ) {
+            let (
+                field_matches,
+                relationship_matches,
+                entity_matches,
+                source_related_entities,
+                target_related_entities,
+            ) = recompute_all_matches(
+                source,
+                target,
+                &state.field_mappings,
+                &state.imported_mappings,
+                &state.prefix_mappings,
+                &state.examples,
+                &first_source_entity,
+                &first_target_entity,
+                &state.negative_matches,
+            );
+            state.field_matches = field_matches;
+            state.relationship_matches = relationship_matches;
+            state.entity_matches = entity_matches;
+            state.source_related_entities = source_related_entities;
+            state.target_related_entities = target_related_entities;
+        }
+    }
+}
+
 pub fn handle_open_modal(state: &mut State) -> Command<Msg> {
     state.show_negative_matches_modal = true;
     Command::None
@@ -71,93 +158,7 @@ pub fn handle_delete_negative_match(state: &mut State) -> Command<Msg> {
             let source_entity = source_entity_str.to_string();
             let source_field_name = source_field_name.to_string();
 
-            // Recompute matches
-            let is_multi_entity =
-                state.source_entities.len() > 1 || state.target_entities.len() > 1;
-
-            if is_multi_entity {
-                // Multi-entity mode: use recompute_all_matches_multi()
-                let source_metadata_map: HashMap<String, crate::api::EntityMetadata> = state
-                    .source_metadata
-                    .iter()
-                    .filter_map(|(name, resource)| {
-                        if let Resource::Success(metadata) = resource {
-                            Some((name.clone(), metadata.clone()))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                let target_metadata_map: HashMap<String, crate::api::EntityMetadata> = state
-                    .target_metadata
-                    .iter()
-                    .filter_map(|(name, resource)| {
-                        if let Resource::Success(metadata) = resource {
-                            Some((name.clone(), metadata.clone()))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                let (
-                    field_matches,
-                    relationship_matches,
-                    entity_matches,
-                    source_related_entities,
-                    target_related_entities,
-                ) = recompute_all_matches_multi(
-                    &source_metadata_map,
-                    &target_metadata_map,
-                    &state.source_entities,
-                    &state.target_entities,
-                    &state.field_mappings,
-                    &state.imported_mappings,
-                    &state.prefix_mappings,
-                    &state.examples,
-                    &state.negative_matches,
-                );
-                state.field_matches = field_matches;
-                state.relationship_matches = relationship_matches;
-                state.entity_matches = entity_matches;
-                state.source_related_entities = source_related_entities;
-                state.target_related_entities = target_related_entities;
-            } else {
-                // Single-entity mode: use first entity (backwards compatible)
-                let first_source_entity =
-                    state.source_entities.first().cloned().unwrap_or_default();
-                let first_target_entity =
-                    state.target_entities.first().cloned().unwrap_or_default();
-
-                if let (Some(Resource::Success(source)), Some(Resource::Success(target))) = (
-                    state.source_metadata.get(&first_source_entity),
-                    state.target_metadata.get(&first_target_entity),
-                ) {
-                    let (
-                        field_matches,
-                        relationship_matches,
-                        entity_matches,
-                        source_related_entities,
-                        target_related_entities,
-                    ) = recompute_all_matches(
-                        source,
-                        target,
-                        &state.field_mappings,
-                        &state.imported_mappings,
-                        &state.prefix_mappings,
-                        &state.examples,
-                        &first_source_entity,
-                        &first_target_entity,
-                        &state.negative_matches,
-                    );
-                    state.field_matches = field_matches;
-                    state.relationship_matches = relationship_matches;
-                    state.entity_matches = entity_matches;
-                    state.source_related_entities = source_related_entities;
-                    state.target_related_entities = target_related_entities;
-                }
-            }
+            recompute_matches(state);
 
             // Delete from database for all target entities that might have had this negative match
             let target_entities = state.target_entities.clone();
@@ -234,92 +235,7 @@ pub fn handle_add_negative_match_from_tree(state: &mut State) -> Command<Msg> {
             state.negative_matches.insert(source_key.clone());
 
             // Recompute matches (this will now exclude the negative match)
-            let is_multi_entity =
-                state.source_entities.len() > 1 || state.target_entities.len() > 1;
-
-            if is_multi_entity {
-                // Multi-entity mode: use recompute_all_matches_multi()
-                let source_metadata_map: HashMap<String, crate::api::EntityMetadata> = state
-                    .source_metadata
-                    .iter()
-                    .filter_map(|(name, resource)| {
-                        if let Resource::Success(metadata) = resource {
-                            Some((name.clone(), metadata.clone()))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                let target_metadata_map: HashMap<String, crate::api::EntityMetadata> = state
-                    .target_metadata
-                    .iter()
-                    .filter_map(|(name, resource)| {
-                        if let Resource::Success(metadata) = resource {
-                            Some((name.clone(), metadata.clone()))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                let (
-                    field_matches,
-                    relationship_matches,
-                    entity_matches,
-                    source_related_entities,
-                    target_related_entities,
-                ) = recompute_all_matches_multi(
-                    &source_metadata_map,
-                    &target_metadata_map,
-                    &state.source_entities,
-                    &state.target_entities,
-                    &state.field_mappings,
-                    &state.imported_mappings,
-                    &state.prefix_mappings,
-                    &state.examples,
-                    &state.negative_matches,
-                );
-                state.field_matches = field_matches;
-                state.relationship_matches = relationship_matches;
-                state.entity_matches = entity_matches;
-                state.source_related_entities = source_related_entities;
-                state.target_related_entities = target_related_entities;
-            } else {
-                // Single-entity mode: use first entity (backwards compatible)
-                let first_source_entity =
-                    state.source_entities.first().cloned().unwrap_or_default();
-                let first_target_entity =
-                    state.target_entities.first().cloned().unwrap_or_default();
-
-                if let (Some(Resource::Success(source)), Some(Resource::Success(target))) = (
-                    state.source_metadata.get(&first_source_entity),
-                    state.target_metadata.get(&first_target_entity),
-                ) {
-                    let (
-                        field_matches,
-                        relationship_matches,
-                        entity_matches,
-                        source_related_entities,
-                        target_related_entities,
-                    ) = recompute_all_matches(
-                        source,
-                        target,
-                        &state.field_mappings,
-                        &state.imported_mappings,
-                        &state.prefix_mappings,
-                        &state.examples,
-                        &first_source_entity,
-                        &first_target_entity,
-                        &state.negative_matches,
-                    );
-                    state.field_matches = field_matches;
-                    state.relationship_matches = relationship_matches;
-                    state.entity_matches = entity_matches;
-                    state.source_related_entities = source_related_entities;
-                    state.target_related_entities = target_related_entities;
-                }
-            }
+            recompute_matches(state);
 
             // Save to database for all target entities
             let target_entities = state.target_entities.clone();
@@ -353,3 +269,148 @@ pub fn handle_add_negative_match_from_tree(state: &mut State) -> Command<Msg> {
 
     Command::None
 }
+
+/// Write the negative matches to a shareable file in the current directory
+pub fn handle_export(state: &mut State) -> Command<Msg> {
+    if state.negative_matches.is_empty() {
+        log::warn!("No negative matches to export");
+        return Command::None;
+    }
+
+    // Strip entity qualifiers so the file applies to any comparison
+    let fields: std::collections::HashSet<String> = state
+        .negative_matches
+        .iter()
+        .map(|name| parse_qualified_name(name, "").1.to_string())
+        .collect();
+    let content = crate::config::repository::mappings::format_negative_matches(&fields);
+
+    let first_source_entity = state.source_entities.first().cloned().unwrap_or_default();
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let filename = format!(
+        "{}_{}_negative_matches_{}.txt",
+        state.migration_name, first_source_entity, timestamp
+    );
+
+    tokio::spawn(async move {
+        match tokio::fs::write(&filename, content).await {
+            Ok(()) => log::info!("Exported {} negative matches to {}", fields.len(), filename),
+            Err(e) => log::error!("Failed to export negative matches: {}", e),
+        }
+    });
+
+    Command::None
+}
+
+/// Open the file browser to pick a negative matches file
+pub fn handle_open_import(state: &mut State) -> Command<Msg> {
+    state.show_negative_matches_modal = false;
+    state.import_negative_matches = true;
+    state.show_import_modal = true;
+
+    state
+        .import_file_browser
+        .set_filter(|entry| entry.is_dir || entry.name.to_lowercase().ends_with(".txt"));
+    let _ = state.import_file_browser.refresh();
+
+    Command::set_focus(crate::tui::FocusId::new("import-file-browser"))
+}
+
+/// Read a negative matches file picked in the file browser
+pub fn read_file(path: std::path::PathBuf) -> Command<Msg> {
+    Command::perform(
+        async move {
+            let content = tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| format!("Failed to read file: {}", e))?;
+            Ok(crate::config::repository::mappings::parse_negative_matches(
+                &content,
+            ))
+        },
+        Msg::NegativeMatchesFileLoaded,
+    )
+}
+
+/// Block every field from an imported file and persist it for each entity pair
+///
+/// Qualified names ("contact.fullname") apply to that source entity only;
+/// plain names apply to every source entity in the comparison.
+pub fn handle_file_loaded(state: &mut State, result: Result<Vec<String>, String>) -> Command<Msg> {
+    state.show_import_modal = false;
+    state.import_negative_matches = false;
+
+    let fields = match result {
+        Ok(fields) => fields,
+        Err(e) => {
+            log::error!("Failed to import negative matches: {}", e);
+            return Command::None;
+        }
+    };
+
+    let is_multi_entity = state.source_entities.len() > 1 || state.target_entities.len() > 1;
+    let mut by_entity: HashMap<String, Vec<String>> = HashMap::new();
+    for name in &fields {
+        let targets: Vec<(&str, &str)> = match name.split_once('.') {
+            Some((entity, field)) if state.source_entities.iter().any(|e| e == entity) => {
+                vec![(entity, field)]
+            }
+            Some(_) => {
+                log::warn!("Skipping negative match for unknown entity: {}", name);
+                continue;
+            }
+            None => state
+                .source_entities
+                .iter()
+                .map(|entity| (entity.as_str(), name.as_str()))
+                .collect(),
+        };
+
+        for (entity, field) in targets {
+            by_entity
+                .entry(entity.to_string())
+                .or_default()
+                .push(field.to_string());
+        }
+    }
+
+    for (entity, entity_fields) in &by_entity {
+        for field in entity_fields {
+            let key = if is_multi_entity {
+                format!("{}.{}", entity, field)
+            } else {
+                field.clone()
+            };
+            state.negative_matches.insert(key);
+        }
+    }
+
+    recompute_matches(state);
+    state.show_negative_matches_modal = true;
+
+    let target_entities = state.target_entities.clone();
+    Command::perform(
+        async move {
+            let config = crate::global_config();
+            let mut added = 0;
+            for (source_entity, entity_fields) in &by_entity {
+                for target_entity in &target_entities {
+                    added += config
+                        .import_negative_matches(source_entity, target_entity, entity_fields)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                }
+            }
+            Ok(added)
+        },
+        Msg::NegativeMatchesImported,
+    )
+}
+
+/// Log the outcome of persisting imported negative matches
+pub fn handle_imported(_state: &mut State, result: Result<usize, String>) -> Command<Msg> {
+    match result {
+        Ok(added) => log::info!("Imported {} new negative matches", added),
+        Err(e) => log::error!("Failed to save imported negative matches: {}", e),
+    }
+    Command::None
+}
@@ -58,6 +58,10 @@ pub fn handle_add_prefix_mapping(state: &mut State) -> Command<Msg> {
         log::warn!("Cannot add prefix mapping: both source and target prefixes must be provided");
         return Command::None;
     }
+    if let Err(e) = crate::services::matching::core::validate_prefix_rule(&source_prefix) {
+        log::warn!("Cannot add prefix mapping: {}", e);
+        return Command::None;
+    }
 
     // Add to state (wrap single target in Vec for 1-to-N support)
     state
@@ -55,7 +55,7 @@ pub fn handle_toggle_search_mode(state: &mut State) -> Command<Msg> {
     }
 }
 
-/// Handle toggle match mode - switch between Fuzzy and Substring match algorithms
+/// Handle toggle match mode - cycle between Fuzzy, Substring and Regex match algorithms
 pub fn handle_toggle_match_mode(state: &mut State) -> Command<Msg> {
     // Toggle the match mode
     state.match_mode = state.match_mode.toggle();
@@ -156,7 +156,7 @@ pub fn handle_clear_search(state: &mut State) -> Command<Msg> {
 }
 
 /// Helper to clear multi-selections from all tree states
-fn clear_all_multi_selections(state: &mut State) {
+pub(super) fn clear_all_multi_selections(state: &mut State) {
     state.source_fields_tree.clear_multi_selection();
     state.target_fields_tree.clear_multi_selection();
     state.source_relationships_tree.clear_multi_selection();
@@ -170,7 +170,7 @@ fn clear_all_multi_selections(state: &mut State) {
 }
 
 /// Helper to invalidate all tree caches
-fn invalidate_all_tree_caches(state: &mut State) {
+pub(super) fn invalidate_all_tree_caches(state: &mut State) {
     state.source_fields_tree.invalidate_cache();
     state.target_fields_tree.invalidate_cache();
     state.source_relationships_tree.invalidate_cache();
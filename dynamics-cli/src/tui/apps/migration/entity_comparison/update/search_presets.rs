@@ -0,0 +1,170 @@
+//! Search preset handlers - save/load named search views (query + match mode + hide mode)
+
+use super::super::Msg;
+use super::super::app::State;
+use super::super::models::{HideMode, MatchMode, SearchMode};
+use super::search::{clear_all_multi_selections, invalidate_all_tree_caches};
+use crate::config::repository::search_presets::SearchPreset;
+use crate::tui::command::Command;
+use crate::tui::widgets::TextInputEvent;
+
+/// Reload presets from the database
+fn reload_presets() -> Command<Msg> {
+    Command::perform(
+        async move {
+            let config = crate::global_config();
+            config.list_search_presets().await.unwrap_or_else(|e| {
+                log::error!("Failed to load search presets: {}", e);
+                Vec::new()
+            })
+        },
+        Msg::SearchPresetsLoaded,
+    )
+}
+
+pub fn handle_open_modal(state: &mut State) -> Command<Msg> {
+    state.show_search_presets_modal = true;
+    state.search_preset_name_input.value.clear();
+    reload_presets()
+}
+
+pub fn handle_close_modal(state: &mut State) -> Command<Msg> {
+    state.show_search_presets_modal = false;
+    Command::None
+}
+
+pub fn handle_presets_loaded(state: &mut State, presets: Vec<SearchPreset>) -> Command<Msg> {
+    state.search_presets = presets;
+    let item_count = state.search_presets.len();
+    let selected = state
+        .search_presets_list_state
+        .selected()
+        .filter(|idx| *idx < item_count)
+        .or(if item_count > 0 { Some(0) } else { None });
+    state
+        .search_presets_list_state
+        .select_and_scroll(selected, item_count);
+    Command::None
+}
+
+pub fn handle_list_navigate(state: &mut State, key: crossterm::event::KeyCode) -> Command<Msg> {
+    state
+        .search_presets_list_state
+        .handle_key(key, state.search_presets.len(), 10);
+    Command::None
+}
+
+pub fn handle_list_select(state: &mut State, index: usize) -> Command<Msg> {
+    let item_count = state.search_presets.len();
+    state
+        .search_presets_list_state
+        .select_and_scroll(Some(index), item_count);
+    Command::None
+}
+
+pub fn handle_list_activate(state: &mut State, index: usize) -> Command<Msg> {
+    handle_list_select(state, index);
+    handle_load_preset(state)
+}
+
+pub fn handle_name_input_event(state: &mut State, event: TextInputEvent) -> Command<Msg> {
+    state.search_preset_name_input.handle_event(event, None);
+    Command::None
+}
+
+/// Save the current search (unified query, match mode, hide mode) under the entered name
+pub fn handle_save_preset(state: &mut State) -> Command<Msg> {
+    let name = state.search_preset_name_input.value.trim().to_string();
+    if name.is_empty() {
+        log::warn!("Cannot save search preset: name must be provided");
+        return Command::None;
+    }
+
+    // Independent mode has two queries; a preset stores one, so prefer source
+    let query = match state.search_mode {
+        SearchMode::Unified => state.unified_search.value().to_string(),
+        SearchMode::Independent => {
+            if !state.source_search.value().is_empty() {
+                state.source_search.value().to_string()
+            } else {
+                state.target_search.value().to_string()
+            }
+        }
+    };
+
+    let preset = SearchPreset {
+        name,
+        query,
+        match_mode: state.match_mode.key().to_string(),
+        hide_mode: state.hide_mode.key().to_string(),
+    };
+    state.search_preset_name_input.value.clear();
+
+    Command::perform(
+        async move {
+            let config = crate::global_config();
+            if let Err(e) = config.save_search_preset(&preset).await {
+                log::error!("Failed to save search preset: {}", e);
+            }
+            config.list_search_presets().await.unwrap_or_else(|e| {
+                log::error!("Failed to load search presets: {}", e);
+                Vec::new()
+            })
+        },
+        Msg::SearchPresetsLoaded,
+    )
+}
+
+/// Apply the selected preset and close the modal
+pub fn handle_load_preset(state: &mut State) -> Command<Msg> {
+    let Some(preset) = state
+        .search_presets_list_state
+        .selected()
+        .and_then(|idx| state.search_presets.get(idx))
+        .cloned()
+    else {
+        return Command::None;
+    };
+
+    state.search_mode = SearchMode::Unified;
+    state.unified_search.set_value(preset.query.clone());
+    state.source_search.set_value(String::new());
+    state.target_search.set_value(String::new());
+    state.match_mode = MatchMode::from_key(&preset.match_mode);
+    state.hide_mode = HideMode::from_key(&preset.hide_mode);
+
+    // Same bookkeeping as ToggleMatchMode / search input changes
+    clear_all_multi_selections(state);
+    invalidate_all_tree_caches(state);
+
+    state.show_search_presets_modal = false;
+    log::info!("Loaded search preset '{}'", preset.name);
+    Command::None
+}
+
+pub fn handle_delete_preset(state: &mut State) -> Command<Msg> {
+    let Some(name) = state
+        .search_presets_list_state
+        .selected()
+        .and_then(|idx| state.search_presets.get(idx))
+        .map(|p| p.name.clone())
+    else {
+        return Command::None;
+    };
+
+    state.search_presets.retain(|p| p.name != name);
+
+    Command::perform(
+        async move {
+            let config = crate::global_config();
+            if let Err(e) = config.delete_search_preset(&name).await {
+                log::error!("Failed to delete search preset: {}", e);
+            }
+            config.list_search_presets().await.unwrap_or_else(|e| {
+                log::error!("Failed to load search presets: {}", e);
+                Vec::new()
+            })
+        },
+        Msg::SearchPresetsLoaded,
+    )
+}
@@ -148,15 +148,15 @@ pub fn render_main_layout(state: &mut State) -> Element<Msg> {
         ),
     };
 
-    // Auto-expand containers with matching children when searching or type filtering
+    // Expand to reveal matches when searching or type filtering
     // This ensures filtered children are visible even if containers were collapsed
     if source_search_active || source_type_filter_active {
-        auto_expand_containers_with_children(&source_items, source_tree_state);
+        expand_to_matches(&source_items, source_tree_state);
         // Note: Scroll reset moved to update handlers (only when filter changes)
     }
 
     if target_search_active || target_type_filter_active {
-        auto_expand_containers_with_children(&target_items, target_tree_state);
+        expand_to_matches(&target_items, target_tree_state);
         // Note: Scroll reset moved to update handlers (only when filter changes)
     }
 
@@ -220,6 +220,16 @@ pub fn render_main_layout(state: &mut State) -> Element<Msg> {
     let source_count = source_items.len();
     let target_count = target_items.len();
 
+    // Invalid regex patterns are reported inline in the search panel title
+    let regex_error = |query: &str| -> Option<String> {
+        if state.match_mode != super::models::MatchMode::Regex || query.is_empty() {
+            return None;
+        }
+        super::models::SearchMatcher::new(query, state.match_mode)
+            .err()
+            .map(|e| e.lines().last().unwrap_or_default().trim().to_string())
+    };
+
     // Create search UI based on mode
     use super::models::SearchMode;
     let search_ui = match state.search_mode {
@@ -235,7 +245,9 @@ pub fn render_main_layout(state: &mut State) -> Element<Msg> {
             .on_blur(Msg::SearchInputBlur)
             .build();
 
-            let title = if source_search_active {
+            let title = if let Some(err) = regex_error(state.unified_search.value()) {
+                format!("Search (Unified) - invalid regex: {}", err)
+            } else if source_search_active {
                 format!(
                     "Search (Unified) - {} matches in source, {} matches in target",
                     source_count, target_count
@@ -269,13 +281,17 @@ pub fn render_main_layout(state: &mut State) -> Element<Msg> {
             .build();
 
             // Wrap each in a panel with title showing match count
-            let source_panel_title = if source_search_active {
+            let source_panel_title = if let Some(err) = regex_error(state.source_search.value()) {
+                format!("Source Search - invalid regex: {}", err)
+            } else if source_search_active {
                 format!("Source Search ({} matches)", source_count)
             } else {
                 "Source Search".to_string()
             };
 
-            let target_panel_title = if target_search_active {
+            let target_panel_title = if let Some(err) = regex_error(state.target_search.value()) {
+                format!("Target Search - invalid regex: {}", err)
+            } else if target_search_active {
                 format!("Target Search ({} matches)", target_count)
             } else {
                 "Target Search".to_string()
@@ -443,10 +459,71 @@ pub fn render_negative_matches_modal(state: &State) -> Element<Msg> {
         .on_list_navigate(Msg::NegativeMatchesListNavigate)
         .on_list_select(Msg::NegativeMatchesListSelect)
         .on_delete(Msg::DeleteNegativeMatch)
+        .on_import(Msg::OpenNegativeMatchesImport)
+        .on_export(Msg::ExportNegativeMatches)
         .on_close(Msg::CloseNegativeMatchesModal)
         .build()
 }
 
+pub fn render_search_presets_modal(state: &State) -> Element<Msg> {
+    use crate::tui::modals::{SearchPresetItem, SearchPresetsModal};
+
+    let preset_items: Vec<SearchPresetItem<Msg>> = state
+        .search_presets
+        .iter()
+        .map(|preset| SearchPresetItem {
+            name: preset.name.clone(),
+            query: preset.query.clone(),
+            match_mode: super::models::MatchMode::from_key(&preset.match_mode)
+                .label()
+                .to_string(),
+            hide_mode: super::models::HideMode::from_key(&preset.hide_mode)
+                .label()
+                .to_string(),
+            _phantom: std::marker::PhantomData,
+        })
+        .collect();
+
+    SearchPresetsModal::new()
+        .presets(preset_items)
+        .name_input_state(state.search_preset_name_input.clone())
+        .list_state(state.search_presets_list_state.clone())
+        .on_name_input_event(Msg::SearchPresetNameInputEvent)
+        .on_list_navigate(Msg::SearchPresetsListNavigate)
+        .on_list_select(Msg::SearchPresetsListSelect)
+        .on_list_activate(Msg::SearchPresetsListActivate)
+        .on_save(Msg::SaveSearchPreset)
+        .on_load(Msg::LoadSearchPreset)
+        .on_delete(Msg::DeleteSearchPreset)
+        .on_close(Msg::CloseSearchPresetsModal)
+        .build()
+}
+
+pub fn render_export_columns_modal(state: &State) -> Element<Msg> {
+    use super::export::columns::FieldColumn;
+    use crate::tui::modals::{ExportColumnItem, ExportColumnsModal};
+
+    let column_items: Vec<ExportColumnItem<Msg>> = FieldColumn::ALL
+        .iter()
+        .enumerate()
+        .map(|(idx, column)| ExportColumnItem {
+            label: column.label().to_string(),
+            selected: state.export_columns.contains(column),
+            on_toggle: Msg::ToggleExportColumn(idx),
+        })
+        .collect();
+
+    ExportColumnsModal::new()
+        .columns(column_items)
+        .list_state(state.export_columns_list_state.clone())
+        .on_list_navigate(Msg::ExportColumnsListNavigate)
+        .on_list_select(Msg::ExportColumnsListSelect)
+        .on_list_activate(Msg::ToggleExportColumn)
+        .on_export(Msg::ConfirmExportToExcel)
+        .on_cancel(Msg::CloseExportColumnsModal)
+        .build()
+}
+
 /// Filter out matched items from tree (hide unmatched, show matched)
 /// Exception: ExampleValue matches are treated as unmatched (shown)
 pub fn filter_matched_items(
@@ -1180,19 +1257,31 @@ pub fn render_import_modal(state: &mut State) -> Element<Msg> {
         .on_render(Msg::ImportSetViewportHeight)
         .build();
 
+    let (file_kind, help, modal_title) = if state.import_negative_matches {
+        (
+            "Negative Matches File",
+            "Select a .txt file with one source field per line. ",
+            "Import Negative Matches",
+        )
+    } else {
+        (
+            "C# Mapping File",
+            "Select a .cs file to import field mappings. ",
+            "Import C# Field Mappings",
+        )
+    };
+
     let browser_panel = Element::panel(browser)
         .title(format!(
-            "Select C# Mapping File - {}",
+            "Select {} - {}",
+            file_kind,
             state.import_file_browser.current_path().display()
         ))
         .build();
 
     // Help text
     let help_text = Element::styled_text(Line::from(vec![
-        Span::styled(
-            "Select a .cs file to import field mappings. ",
-            Style::default().fg(theme.text_tertiary),
-        ),
+        Span::styled(help, Style::default().fg(theme.text_tertiary)),
         Span::styled("Navigate with ", Style::default().fg(theme.text_tertiary)),
         Span::styled("↑/↓", Style::default().fg(theme.accent_primary).bold()),
         Span::styled(", press ", Style::default().fg(theme.text_tertiary)),
@@ -1202,7 +1291,16 @@ pub fn render_import_modal(state: &mut State) -> Element<Msg> {
     .build();
 
     // Info about current import
-    let import_info = if let Some(ref file) = state.import_source_file {
+    let import_info = if state.import_negative_matches {
+        Element::styled_text(Line::from(vec![Span::styled(
+            format!(
+                "{} field(s) currently blocked from prefix matching",
+                state.negative_matches.len()
+            ),
+            Style::default().fg(theme.text_tertiary),
+        )]))
+        .build()
+    } else if let Some(ref file) = state.import_source_file {
         Element::styled_text(Line::from(vec![
             Span::styled(
                 "Currently imported: ",
@@ -1241,7 +1339,7 @@ pub fn render_import_modal(state: &mut State) -> Element<Msg> {
     ];
 
     Element::panel(Element::container(content).padding(2).build())
-        .title("Import C# Field Mappings")
+        .title(modal_title)
         .width(90)
         .height(35)
         .build()
@@ -1372,27 +1470,14 @@ pub fn render_ignore_modal(state: &mut State) -> Element<Msg> {
         .build()
 }
 
-/// Auto-expand containers that have children (after filtering)
-/// This ensures that filtered children are visible even if the container was previously collapsed
-fn auto_expand_containers_with_children(
-    items: &[super::tree_items::ComparisonTreeItem],
-    tree_state: &mut TreeState,
-) {
+/// Expand the ancestors of every remaining leaf (after filtering)
+/// This ensures that filtered matches are visible even if their containers were previously collapsed
+fn expand_to_matches(items: &[super::tree_items::ComparisonTreeItem], tree_state: &mut TreeState) {
     use super::tree_items::ComparisonTreeItem;
 
-    for item in items {
-        match item {
-            ComparisonTreeItem::Container(node) => {
-                // If container has children, expand it
-                if !node.children.is_empty() {
-                    tree_state.expand(&node.id);
-                    // Recursively expand nested containers
-                    auto_expand_containers_with_children(&node.children, tree_state);
-                }
-            }
-            _ => {}
-        }
-    }
+    tree_state.expand_to_match(items, |item| {
+        !matches!(item, ComparisonTreeItem::Container(_))
+    });
 }
 
 /// Filter tree items based on search query using the selected match mode
@@ -1406,32 +1491,19 @@ fn filter_tree_items_by_search(
     entity_name: &str,
 ) -> Vec<super::tree_items::ComparisonTreeItem> {
     use super::tree_items::ComparisonTreeItem;
-    use fuzzy_matcher::FuzzyMatcher;
-    use fuzzy_matcher::skim::SkimMatcherV2;
 
     if query.is_empty() {
         return items;
     }
 
-    // Create matcher based on mode
-    let fuzzy_matcher = if matches!(match_mode, super::models::MatchMode::Fuzzy) {
-        Some(SkimMatcherV2::default())
-    } else {
-        None
+    // Create matcher based on mode; an invalid regex leaves the tree unfiltered
+    // (the error is shown in the search panel title instead)
+    let Ok(matcher) = super::models::SearchMatcher::new(query, match_mode) else {
+        return items;
     };
-    let query_lower = query.to_lowercase();
 
     // Helper function to check if text matches query based on mode
-    let text_matches = |text: &str| -> bool {
-        match match_mode {
-            super::models::MatchMode::Fuzzy => fuzzy_matcher
-                .as_ref()
-                .unwrap()
-                .fuzzy_match(text, query)
-                .is_some(),
-            super::models::MatchMode::Substring => text.to_lowercase().contains(&query_lower),
-        }
-    };
+    let text_matches = |text: &str| -> bool { matcher.matches(text) };
 
     items
         .into_iter()
@@ -1,3 +1,5 @@
+use super::comparison_diff::{self, ComparisonDiff, DiffRowKind};
+use super::entity_comparison::{ExamplePair, ExampleRecordData};
 use crate::config::repository::migrations::SavedComparison;
 use crate::tui::{
     Resource,
@@ -11,7 +13,7 @@ use crate::tui::{
     widgets::list::{ListItem, ListState},
     widgets::{
         AutocompleteEvent, AutocompleteField, FileBrowserEvent, MultiSelectEvent, MultiSelectField,
-        TextInputEvent, TextInputField,
+        ScrollableState, TextInputEvent, TextInputField,
     },
 };
 use crate::{button_row, col, error_display, row, spacer, use_constraints};
@@ -58,9 +60,71 @@ pub struct ImportComparisonForm {
     #[validate(not_empty, message = "Comparison name is required")]
     name: TextInputField,
 
+    // Local entity names, prefilled from the bundle
+    source_entity: TextInputField,
+    target_entity: TextInputField,
+    // Publisher prefix renames, e.g. "cgk_=abc_, nrq_=xyz_"
+    prefix_renames: TextInputField,
+
     validation_error: Option<String>,
 }
 
+/// Local names applied to an imported comparison
+///
+/// Another org may use different logical names for the same entities; the
+/// remap rewrites every entity-qualified reference in the bundle to match.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImportRemap {
+    /// Local source entity name, empty keeps the exported name
+    pub source_entity: String,
+    /// Local target entity name, empty keeps the exported name
+    pub target_entity: String,
+    /// Field name prefix renames as (exported, local)
+    pub prefixes: Vec<(String, String)>,
+}
+
+impl ImportRemap {
+    /// Rename an entity-qualified name ("entity.field" or a bare field)
+    fn rename(&self, name: &str, exported_entity: &str, local_entity: &str) -> String {
+        let (entity, field) = match name.split_once('.') {
+            Some((entity, field)) if entity == exported_entity => (Some(local_entity), field),
+            Some((entity, field)) => (Some(entity), field),
+            None => (None, name),
+        };
+        let field = self
+            .prefixes
+            .iter()
+            .find_map(|(from, to)| {
+                field
+                    .strip_prefix(from.as_str())
+                    .map(|rest| format!("{}{}", to, rest))
+            })
+            .unwrap_or_else(|| field.to_string());
+        match entity {
+            Some(entity) => format!("{}.{}", entity, field),
+            None => field,
+        }
+    }
+}
+
+/// Parse prefix renames written as "from=to" pairs separated by commas
+fn parse_prefix_renames(input: &str) -> Result<Vec<(String, String)>, String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((from, to)) if !from.trim().is_empty() => {
+                Ok((from.trim().to_string(), to.trim().to_string()))
+            }
+            _ => Err(format!(
+                "Invalid prefix rename '{}', expected from=to",
+                pair
+            )),
+        })
+        .collect()
+}
+
 /// Export/Import JSON structure
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ComparisonExportData {
@@ -81,6 +145,71 @@ pub struct ExamplePairExport {
     pub source_record_id: String,
     pub target_record_id: String,
     pub label: Option<String>,
+    /// Record data embedded so the bundle works without access to the records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<ExampleRecordData>,
+}
+
+impl ComparisonExportData {
+    /// Rewrite entity names and field prefixes to the importing org's names
+    ///
+    /// Mapping keys and ignored source items follow the source entity, mapping
+    /// targets and ignored target items follow the target entity.
+    pub fn apply_remap(&mut self, remap: &ImportRemap) {
+        let exported_source = std::mem::take(&mut self.source_entity);
+        let exported_target = std::mem::take(&mut self.target_entity);
+        let local = |name: &str, exported: &str| {
+            if name.trim().is_empty() {
+                exported.to_string()
+            } else {
+                name.trim().to_string()
+            }
+        };
+        let local_source = local(&remap.source_entity, &exported_source);
+        let local_target = local(&remap.target_entity, &exported_target);
+
+        let source = |name: &str| remap.rename(name, &exported_source, &local_source);
+        let target = |name: &str| remap.rename(name, &exported_target, &local_target);
+        let rewrite = |mappings: &mut HashMap<String, Vec<String>>| {
+            *mappings = std::mem::take(mappings)
+                .into_iter()
+                .map(|(key, values)| (source(&key), values.iter().map(|v| target(v)).collect()))
+                .collect();
+        };
+        rewrite(&mut self.field_mappings);
+        rewrite(&mut self.prefix_mappings);
+        rewrite(&mut self.imported_mappings);
+
+        // Ignored items are "tab:side:name"
+        for item in &mut self.ignored_items {
+            let mut parts = item.splitn(3, ':');
+            if let (Some(tab), Some(side), Some(name)) = (parts.next(), parts.next(), parts.next())
+            {
+                let name = match side {
+                    "source" => source(name),
+                    "target" => target(name),
+                    _ => name.to_string(),
+                };
+                *item = format!("{}:{}:{}", tab, side, name);
+            }
+        }
+
+        self.source_entity = local_source;
+        self.target_entity = local_target;
+    }
+}
+
+impl ExamplePairExport {
+    /// Convert to a saved example pair, keeping embedded data only if requested
+    fn into_example_pair(self, use_embedded_data: bool) -> ExamplePair {
+        ExamplePair {
+            id: uuid::Uuid::new_v4().to_string(),
+            source_record_id: self.source_record_id,
+            target_record_id: self.target_record_id,
+            label: self.label,
+            embedded_data: self.data.filter(|_| use_embedded_data),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -106,16 +235,24 @@ pub struct State {
     export_filename: TextInputField,
     export_comparison_id: Option<i64>,
     export_comparison_name: Option<String>,
+    export_embed_examples: bool,
     // Import state
     show_import_browser: bool,
     show_import_config: bool,
     import_browser: FileBrowserState,
     import_form: ImportComparisonForm,
     import_file_path: Option<PathBuf>,
+    import_use_embedded_data: bool,
     // Batch export state
     show_batch_export_modal: bool,
     batch_export_browser: FileBrowserState,
     batch_export_filename: TextInputField,
+    batch_export_as_zip: bool,
+    // Diff state
+    diff_base: Option<(i64, String)>,
+    show_diff_modal: bool,
+    diff_result: Option<Result<ComparisonDiff, String>>,
+    diff_scroll_state: ScrollableState,
 }
 
 impl Default for State {
@@ -146,14 +283,21 @@ impl Default for State {
             export_filename: TextInputField::default(),
             export_comparison_id: None,
             export_comparison_name: None,
+            export_embed_examples: false,
             show_import_browser: false,
             show_import_config: false,
             import_browser: FileBrowserState::new(home_dir.clone()),
             import_form: ImportComparisonForm::default(),
             import_file_path: None,
+            import_use_embedded_data: true,
             show_batch_export_modal: false,
             batch_export_browser: FileBrowserState::new(home_dir.clone()),
             batch_export_filename: TextInputField::default(),
+            batch_export_as_zip: false,
+            diff_base: None,
+            show_diff_modal: false,
+            diff_result: None,
+            diff_scroll_state: ScrollableState::new(),
         }
     }
 }
@@ -200,6 +344,7 @@ pub enum Msg {
     ExportBrowseNavigate(KeyCode),
     ExportDirectoryEntered(PathBuf),
     ExportFilenameEvent(TextInputEvent),
+    ExportToggleEmbedExamples,
     ExportConfirm,
     ExportCancel,
     ExportComplete(Result<(), String>),
@@ -208,6 +353,11 @@ pub enum Msg {
     ImportBrowseNavigate(KeyCode),
     ImportFileSelected(PathBuf),
     ImportFormNameEvent(TextInputEvent),
+    ImportBundleLoaded(Result<(String, String), String>),
+    ImportFormSourceEntityEvent(TextInputEvent),
+    ImportFormTargetEntityEvent(TextInputEvent),
+    ImportFormPrefixEvent(TextInputEvent),
+    ImportToggleEmbeddedData,
     ImportFormSubmit,
     ImportFormCancel,
     ImportComplete(Result<i64, String>),
@@ -216,9 +366,16 @@ pub enum Msg {
     BatchExportBrowseNavigate(KeyCode),
     BatchExportDirectoryEntered(PathBuf),
     BatchExportFilenameEvent(TextInputEvent),
+    BatchExportToggleZip,
     BatchExportConfirm,
     BatchExportCancel,
     BatchExportComplete(Result<(), String>),
+    // Diff messages
+    RequestDiff,
+    DiffLoaded(Result<ComparisonDiff, String>),
+    DiffScroll(KeyCode),
+    DiffSetDimensions(usize, usize, usize, usize),
+    CloseDiff,
     Back,
 }
 
@@ -306,7 +463,9 @@ impl State {
 
         // Set default filename
         let default_filename = format!("{}.json", comparison_name);
+        self.export_filename = TextInputField::new().with_validator(validate_filename);
         self.export_filename.set_value(default_filename);
+        self.export_embed_examples = false;
 
         self.show_export_modal = true;
     }
@@ -349,6 +508,7 @@ impl State {
 
         self.import_form = ImportComparisonForm::default();
         self.import_form.name.set_value(default_name);
+        self.import_use_embedded_data = true;
 
         self.show_import_browser = false;
         self.show_import_config = true;
@@ -376,7 +536,9 @@ impl State {
         let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
         let migration_name = self.migration_name.as_deref().unwrap_or("migration");
         let default_filename = format!("{}_{}_mappings.xlsx", migration_name, timestamp);
+        self.batch_export_filename = TextInputField::new().with_validator(validate_filename);
         self.batch_export_filename.set_value(default_filename);
+        self.batch_export_as_zip = false;
 
         self.show_batch_export_modal = true;
     }
@@ -385,6 +547,12 @@ impl State {
         self.show_batch_export_modal = false;
         self.batch_export_filename = TextInputField::default();
     }
+
+    fn close_diff_modal(&mut self) {
+        self.show_diff_modal = false;
+        self.diff_result = None;
+        self.diff_scroll_state = ScrollableState::new();
+    }
 }
 
 pub struct MigrationSelectParams {
@@ -420,59 +588,11 @@ impl App for MigrationComparisonSelectApp {
         let cmd = Command::perform_parallel()
             .add_task(
                 format!("Loading source entities ({})", params.source_env),
-                {
-                    let source_env = params.source_env.clone();
-                    async move {
-                        use crate::api::metadata::parse_entity_list;
-                        let config = crate::global_config();
-                        let manager = crate::client_manager();
-
-                        match config.get_entity_cache(&source_env, 24).await {
-                            Ok(Some(cached)) => Ok::<Vec<String>, String>(cached),
-                            _ => {
-                                let client = manager
-                                    .get_client(&source_env)
-                                    .await
-                                    .map_err(|e| e.to_string())?;
-                                let metadata_xml =
-                                    client.fetch_metadata().await.map_err(|e| e.to_string())?;
-                                let entities =
-                                    parse_entity_list(&metadata_xml).map_err(|e| e.to_string())?;
-                                let _ =
-                                    config.set_entity_cache(&source_env, entities.clone()).await;
-                                Ok(entities)
-                            }
-                        }
-                    }
-                },
+                load_sorted_entities(params.source_env.clone()),
             )
             .add_task(
                 format!("Loading target entities ({})", params.target_env),
-                {
-                    let target_env = params.target_env.clone();
-                    async move {
-                        use crate::api::metadata::parse_entity_list;
-                        let config = crate::global_config();
-                        let manager = crate::client_manager();
-
-                        match config.get_entity_cache(&target_env, 24).await {
-                            Ok(Some(cached)) => Ok::<Vec<String>, String>(cached),
-                            _ => {
-                                let client = manager
-                                    .get_client(&target_env)
-                                    .await
-                                    .map_err(|e| e.to_string())?;
-                                let metadata_xml =
-                                    client.fetch_metadata().await.map_err(|e| e.to_string())?;
-                                let entities =
-                                    parse_entity_list(&metadata_xml).map_err(|e| e.to_string())?;
-                                let _ =
-                                    config.set_entity_cache(&target_env, entities.clone()).await;
-                                Ok(entities)
-                            }
-                        }
-                    }
-                },
+                load_sorted_entities(params.target_env.clone()),
             )
             .with_title("Loading Migration Data")
             .on_complete(AppId::MigrationComparisonSelect)
@@ -591,28 +711,24 @@ impl App for MigrationComparisonSelectApp {
                 Command::None
             }
             Msg::CreateFormSourceEvent(event) => {
-                let options = state
-                    .source_entities
-                    .as_ref()
-                    .ok()
-                    .cloned()
-                    .unwrap_or_default();
+                let options: &[String] = match &state.source_entities {
+                    Resource::Success(entities) => entities,
+                    _ => &[],
+                };
                 state
                     .create_form
                     .source_entities
-                    .handle_event(event, &options)
+                    .handle_event(event, options)
             }
             Msg::CreateFormTargetEvent(event) => {
-                let options = state
-                    .target_entities
-                    .as_ref()
-                    .ok()
-                    .cloned()
-                    .unwrap_or_default();
+                let options: &[String] = match &state.target_entities {
+                    Resource::Success(entities) => entities,
+                    _ => &[],
+                };
                 state
                     .create_form
                     .target_entities
-                    .handle_event(event, &options)
+                    .handle_event(event, options)
             }
             Msg::CreateFormSubmit => {
                 let name = state.create_form.name.value().trim().to_string();
@@ -987,6 +1103,10 @@ impl App for MigrationComparisonSelectApp {
                 state.export_filename.handle_event(event, Some(255));
                 Command::None
             }
+            Msg::ExportToggleEmbedExamples => {
+                state.export_embed_examples = !state.export_embed_examples;
+                Command::None
+            }
             Msg::ExportConfirm => {
                 if let (Some(id), Some(_name)) =
                     (state.export_comparison_id, &state.export_comparison_name)
@@ -994,18 +1114,22 @@ impl App for MigrationComparisonSelectApp {
                     let directory = state.export_browser.current_path().to_path_buf();
                     let filename = state.export_filename.value().trim().to_string();
 
-                    if filename.is_empty() {
+                    if filename.is_empty() || !state.export_filename.is_valid() {
                         return Command::None;
                     }
 
                     let file_path = directory.join(&filename);
+                    let embed_from = state
+                        .export_embed_examples
+                        .then(|| state.source_env.clone().zip(state.target_env.clone()))
+                        .flatten();
 
                     // Close modal immediately
                     state.close_export_modal();
 
                     // Perform export asynchronously
                     Command::perform(
-                        async move { export_comparison(id, file_path).await },
+                        async move { export_comparison(id, file_path, embed_from).await },
                         Msg::ExportComplete,
                     )
                 } else {
@@ -1045,8 +1169,14 @@ impl App for MigrationComparisonSelectApp {
                             match action {
                                 FileBrowserAction::FileSelected(path) => {
                                     // File selected, open import config
-                                    state.open_import_config(path);
-                                    return Command::set_focus(FocusId::new("import-name-input"));
+                                    state.open_import_config(path.clone());
+                                    return Command::batch(vec![
+                                        Command::set_focus(FocusId::new("import-name-input")),
+                                        Command::perform(
+                                            read_bundle_entities(path),
+                                            Msg::ImportBundleLoaded,
+                                        ),
+                                    ]);
                                 }
                                 FileBrowserAction::DirectoryEntered(path) => {
                                     // Enter the directory
@@ -1066,26 +1196,87 @@ impl App for MigrationComparisonSelectApp {
             }
             Msg::ImportFileSelected(file_path) => {
                 // No longer used since we handle Enter in Navigate
-                state.open_import_config(file_path);
-                Command::set_focus(FocusId::new("import-name-input"))
+                state.open_import_config(file_path.clone());
+                Command::batch(vec![
+                    Command::set_focus(FocusId::new("import-name-input")),
+                    Command::perform(read_bundle_entities(file_path), Msg::ImportBundleLoaded),
+                ])
             }
             Msg::ImportFormNameEvent(event) => {
                 state.import_form.name.handle_event(event, Some(50));
                 Command::None
             }
+            Msg::ImportBundleLoaded(result) => {
+                match result {
+                    Ok((source_entity, target_entity)) => {
+                        state.import_form.source_entity.set_value(source_entity);
+                        state.import_form.target_entity.set_value(target_entity);
+                    }
+                    Err(e) => state.import_form.validation_error = Some(e),
+                }
+                Command::None
+            }
+            Msg::ImportFormSourceEntityEvent(event) => {
+                state
+                    .import_form
+                    .source_entity
+                    .handle_event(event, Some(100));
+                Command::None
+            }
+            Msg::ImportFormTargetEntityEvent(event) => {
+                state
+                    .import_form
+                    .target_entity
+                    .handle_event(event, Some(100));
+                Command::None
+            }
+            Msg::ImportFormPrefixEvent(event) => {
+                state
+                    .import_form
+                    .prefix_renames
+                    .handle_event(event, Some(200));
+                Command::None
+            }
+            Msg::ImportToggleEmbeddedData => {
+                state.import_use_embedded_data = !state.import_use_embedded_data;
+                Command::None
+            }
             Msg::ImportFormSubmit => {
                 // Validate form
+                let prefixes = match parse_prefix_renames(state.import_form.prefix_renames.value())
+                {
+                    Ok(prefixes) => prefixes,
+                    Err(e) => {
+                        state.import_form.validation_error = Some(e);
+                        return Command::None;
+                    }
+                };
                 match state.import_form.validate() {
                     Ok(_) => {
                         let name = state.import_form.name.value().trim().to_string();
+                        let remap = ImportRemap {
+                            source_entity: state.import_form.source_entity.value().to_string(),
+                            target_entity: state.import_form.target_entity.value().to_string(),
+                            prefixes,
+                        };
                         let file_path = state.import_file_path.clone();
                         let migration_name = state.migration_name.clone().unwrap_or_default();
+                        let use_embedded_data = state.import_use_embedded_data;
 
                         state.close_import_config();
 
                         if let Some(path) = file_path {
                             Command::perform(
-                                async move { import_comparison(path, migration_name, name).await },
+                                async move {
+                                    import_comparison(
+                                        path,
+                                        migration_name,
+                                        name,
+                                        use_embedded_data,
+                                        remap,
+                                    )
+                                    .await
+                                },
                                 Msg::ImportComplete,
                             )
                         } else {
@@ -1151,6 +1342,23 @@ impl App for MigrationComparisonSelectApp {
                 state.batch_export_filename.handle_event(event, Some(255));
                 Command::None
             }
+            Msg::BatchExportToggleZip => {
+                state.batch_export_as_zip = !state.batch_export_as_zip;
+
+                // Keep the filename extension in step with the chosen format
+                let filename = state.batch_export_filename.value().to_string();
+                let (from, to) = if state.batch_export_as_zip {
+                    (".xlsx", ".zip")
+                } else {
+                    (".zip", ".xlsx")
+                };
+                if let Some(stem) = filename.strip_suffix(from) {
+                    state
+                        .batch_export_filename
+                        .set_value(format!("{}{}", stem, to));
+                }
+                Command::None
+            }
             Msg::BatchExportConfirm => {
                 let directory = state.batch_export_browser.current_path().to_path_buf();
                 let filename = state.batch_export_filename.value().trim().to_string();
@@ -1161,8 +1369,8 @@ impl App for MigrationComparisonSelectApp {
                     filename
                 );
 
-                if filename.is_empty() {
-                    log::warn!("Batch export cancelled: empty filename");
+                if filename.is_empty() || !state.batch_export_filename.is_valid() {
+                    log::warn!("Batch export cancelled: empty or invalid filename");
                     return Command::None;
                 }
 
@@ -1178,6 +1386,37 @@ impl App for MigrationComparisonSelectApp {
                 // Close modal immediately
                 state.close_batch_export_modal();
 
+                if state.batch_export_as_zip {
+                    let total = comparisons.len();
+                    return Command::perform_parallel()
+                        .with_title("Exporting Migration")
+                        .add_task_with_progress(
+                            format!("Exporting {} comparisons", total),
+                            move |progress| async move {
+                                let config = crate::global_config();
+                                super::batch_export::export_comparisons_to_zip(
+                                    &config.pool,
+                                    &comparisons,
+                                    file_path,
+                                    |done, total, name| {
+                                        let _ =
+                                            progress.send(format!("{}/{}: {}", done, total, name));
+                                    },
+                                )
+                                .await
+                                .map(|entries| {
+                                    log::info!("📦 Wrote {} workbooks to archive", entries.len());
+                                })
+                                .map_err(|e| e.to_string())
+                            },
+                        )
+                        .on_complete(AppId::MigrationComparisonSelect)
+                        .build(|_idx, result| {
+                            let result = result.downcast::<Result<(), String>>().unwrap();
+                            Msg::BatchExportComplete(*result)
+                        });
+                }
+
                 // Perform batch export asynchronously
                 Command::perform(
                     async move {
@@ -1213,6 +1452,78 @@ impl App for MigrationComparisonSelectApp {
                 }
                 Command::None
             }
+            // Diff handlers
+            Msg::RequestDiff => {
+                let Some(comparison) = state
+                    .list_state
+                    .selected()
+                    .and_then(|idx| state.comparisons.get(idx))
+                else {
+                    return Command::None;
+                };
+
+                match state.diff_base.take() {
+                    // First press marks the base, pressing again on the base unmarks it
+                    None => {
+                        state.diff_base = Some((comparison.id, comparison.name.clone()));
+                        Command::None
+                    }
+                    Some((base_id, _)) if base_id == comparison.id => Command::None,
+                    Some((base_id, base_name)) => {
+                        let other_id = comparison.id;
+                        let other_name = comparison.name.clone();
+                        state.show_diff_modal = true;
+                        state.diff_result = None;
+                        state.diff_scroll_state = ScrollableState::new();
+
+                        Command::perform(
+                            async move {
+                                let left = load_comparison_export_data(base_id).await?;
+                                let right = load_comparison_export_data(other_id).await?;
+                                comparison_diff::diff_comparisons(
+                                    base_name, &left, other_name, &right,
+                                )
+                            },
+                            Msg::DiffLoaded,
+                        )
+                    }
+                }
+            }
+            Msg::DiffLoaded(result) => {
+                if let Err(e) = &result {
+                    log::error!("Failed to diff comparisons: {}", e);
+                }
+                state.diff_result = Some(result);
+                Command::set_focus(FocusId::new("diff-scroll"))
+            }
+            Msg::DiffScroll(key) => {
+                let viewport_height = state.diff_scroll_state.viewport_height().unwrap_or(20);
+                let content_height = state.diff_scroll_state.content_height().unwrap_or(20);
+                state
+                    .diff_scroll_state
+                    .handle_key(key, content_height, viewport_height);
+                Command::None
+            }
+            Msg::DiffSetDimensions(
+                viewport_height,
+                content_height,
+                viewport_width,
+                content_width,
+            ) => {
+                state.diff_scroll_state.set_viewport_height(viewport_height);
+                state
+                    .diff_scroll_state
+                    .update_scroll(viewport_height, content_height);
+                state.diff_scroll_state.set_viewport_width(viewport_width);
+                state
+                    .diff_scroll_state
+                    .update_horizontal_scroll(viewport_width, content_width);
+                Command::None
+            }
+            Msg::CloseDiff => {
+                state.close_diff_modal();
+                Command::None
+            }
             Msg::Back => Command::batch(vec![
                 Command::navigate_to(AppId::MigrationEnvironment),
                 Command::quit_self(),
@@ -1244,7 +1555,11 @@ impl App for MigrationComparisonSelectApp {
             .build()
         };
 
-        let main_ui = Element::panel(list_content).title("Comparisons").build();
+        let list_title = match &state.diff_base {
+            Some((_, name)) => format!("Comparisons (diff base: {})", name),
+            None => "Comparisons".to_string(),
+        };
+        let main_ui = Element::panel(list_content).title(list_title).build();
 
         if state.show_delete_confirm {
             // Render delete confirmation modal
@@ -1449,6 +1764,15 @@ impl App for MigrationComparisonSelectApp {
             )
             .title("Filename")
             .build();
+            let filename_error = error_display!(state.export_filename.error(), theme);
+
+            let embed_checkbox = Element::checkbox(
+                "export-embed-examples",
+                "Embed example record data (fetches each pair)",
+                state.export_embed_examples,
+            )
+            .on_toggle(Msg::ExportToggleEmbedExamples)
+            .build();
 
             let buttons = button_row![
                 ("export-cancel", "Cancel", Msg::ExportCancel),
@@ -1462,6 +1786,8 @@ impl App for MigrationComparisonSelectApp {
                     file_browser => Fill(1),
                     spacer!() => Length(1),
                     filename_input => Length(3),
+                    filename_error => Length(2),
+                    embed_checkbox => Length(1),
                     spacer!() => Length(1),
                     buttons => Length(3),
                 ])
@@ -1470,7 +1796,7 @@ impl App for MigrationComparisonSelectApp {
             )
             .title("Export Comparison")
             .width(100)
-            .height(35)
+            .height(37)
             .build();
 
             LayeredView::new(main_ui).with_app_modal(modal_content, crate::tui::Alignment::Center)
@@ -1517,7 +1843,7 @@ impl App for MigrationComparisonSelectApp {
 
             LayeredView::new(main_ui).with_app_modal(modal_content, crate::tui::Alignment::Center)
         } else if state.show_import_config {
-            // Import configuration modal (name only, entities come from JSON)
+            // Import configuration modal (name plus optional entity/prefix renames)
             let name_input = Element::panel(
                 Element::text_input(
                     "import-name-input",
@@ -1531,6 +1857,51 @@ impl App for MigrationComparisonSelectApp {
             .title("Comparison Name")
             .build();
 
+            let entity_input = |id: &'static str, field: &TextInputField, title, on_event| {
+                Element::panel(
+                    Element::text_input(id, field.value(), &field.state)
+                        .placeholder("Entity logical name")
+                        .on_event(on_event)
+                        .build(),
+                )
+                .title(title)
+                .build()
+            };
+            let entity_inputs = row![
+                entity_input(
+                    "import-source-entity-input",
+                    &state.import_form.source_entity,
+                    "Source Entity",
+                    Msg::ImportFormSourceEntityEvent,
+                ) => Fill(1),
+                entity_input(
+                    "import-target-entity-input",
+                    &state.import_form.target_entity,
+                    "Target Entity",
+                    Msg::ImportFormTargetEntityEvent,
+                ) => Fill(1),
+            ];
+            let prefix_input = Element::panel(
+                Element::text_input(
+                    "import-prefix-input",
+                    state.import_form.prefix_renames.value(),
+                    &state.import_form.prefix_renames.state,
+                )
+                .placeholder("cgk_=abc_, nrq_=xyz_")
+                .on_event(Msg::ImportFormPrefixEvent)
+                .build(),
+            )
+            .title("Prefix Renames (optional)")
+            .build();
+
+            let embedded_checkbox = Element::checkbox(
+                "import-use-embedded-data",
+                "Use embedded example data (otherwise re-fetch)",
+                state.import_use_embedded_data,
+            )
+            .on_toggle(Msg::ImportToggleEmbeddedData)
+            .build();
+
             let buttons = button_row![
                 ("import-config-cancel", "Cancel", Msg::ImportFormCancel),
                 ("import-config-confirm", "Import", Msg::ImportFormSubmit),
@@ -1538,30 +1909,40 @@ impl App for MigrationComparisonSelectApp {
 
             let modal_body = if state.import_form.validation_error.is_some() {
                 col![
-                    Element::text("Entities will be read from the JSON file.") => Length(1),
+                    Element::text("Rename the exported entities to match this org.") => Length(1),
                     spacer!() => Length(1),
                     name_input => Length(3),
                     spacer!() => Length(1),
+                    entity_inputs => Length(3),
+                    prefix_input => Length(3),
+                    spacer!() => Length(1),
+                    embedded_checkbox => Length(1),
+                    spacer!() => Length(1),
                     error_display!(state.import_form.validation_error, theme) => Length(2),
                     buttons => Length(3),
                 ]
             } else {
                 col![
-                    Element::text("Entities will be read from the JSON file.") => Length(1),
+                    Element::text("Rename the exported entities to match this org.") => Length(1),
                     spacer!() => Length(1),
                     name_input => Length(3),
                     spacer!() => Length(1),
+                    entity_inputs => Length(3),
+                    prefix_input => Length(3),
+                    spacer!() => Length(1),
+                    embedded_checkbox => Length(1),
+                    spacer!() => Length(1),
                     buttons => Length(3),
                 ]
             };
 
             let modal_content = Element::panel(Element::container(modal_body).padding(2).build())
                 .title("Import Comparison")
-                .width(60)
+                .width(80)
                 .height(if state.import_form.validation_error.is_some() {
-                    15
+                    24
                 } else {
-                    13
+                    22
                 })
                 .build();
 
@@ -1603,6 +1984,20 @@ impl App for MigrationComparisonSelectApp {
             )
             .title("Filename")
             .build();
+            let filename_error = error_display!(state.batch_export_filename.error(), theme);
+
+            let zip_checkbox = Element::checkbox(
+                "batch-export-zip",
+                "Export one workbook per comparison as a zip archive",
+                state.batch_export_as_zip,
+            )
+            .on_toggle(Msg::BatchExportToggleZip)
+            .build();
+            let skip_note = if state.batch_export_as_zip {
+                "(Every comparison gets its own file)"
+            } else {
+                "(Comparisons without mappings will be skipped)"
+            };
 
             let buttons = button_row![
                 ("batch-export-cancel", "Cancel", Msg::BatchExportCancel),
@@ -1620,10 +2015,12 @@ impl App for MigrationComparisonSelectApp {
                     file_browser => Fill(1),
                     spacer!() => Length(1),
                     filename_input => Length(3),
+                    filename_error => Length(2),
+                    zip_checkbox => Length(1),
                     spacer!() => Length(1),
                     Element::text(format!("Will export mappings from {} comparison(s)",
                         state.comparisons.len())) => Length(1),
-                    Element::text("(Comparisons without mappings will be skipped)") => Length(1),
+                    Element::text(skip_note) => Length(1),
                     spacer!() => Length(1),
                     buttons => Length(3),
                 ])
@@ -1632,7 +2029,79 @@ impl App for MigrationComparisonSelectApp {
             )
             .title("Batch Export All Mappings")
             .width(100)
-            .height(38)
+            .height(40)
+            .build();
+
+            LayeredView::new(main_ui).with_app_modal(modal_content, crate::tui::Alignment::Center)
+        } else if state.show_diff_modal {
+            let diff_content = match &state.diff_result {
+                None => Element::text("Loading comparisons..."),
+                Some(Err(e)) => Element::styled_text(Line::from(vec![Span::styled(
+                    format!("Failed to diff comparisons: {}", e),
+                    Style::default().fg(theme.accent_error),
+                )]))
+                .build(),
+                Some(Ok(diff)) => {
+                    let lines = diff
+                        .to_rows()
+                        .into_iter()
+                        .map(|row| {
+                            let (left_color, right_color, marker) = match row.kind {
+                                DiffRowKind::Heading => {
+                                    (theme.accent_primary, theme.accent_primary, " ")
+                                }
+                                DiffRowKind::Added => {
+                                    (theme.text_tertiary, theme.accent_success, "+")
+                                }
+                                DiffRowKind::Removed => {
+                                    (theme.accent_error, theme.text_tertiary, "-")
+                                }
+                                DiffRowKind::Changed => {
+                                    (theme.accent_warning, theme.accent_warning, "~")
+                                }
+                            };
+                            Element::styled_text(Line::from(vec![
+                                Span::styled(
+                                    format!("{} ", marker),
+                                    Style::default().fg(theme.text_secondary),
+                                ),
+                                Span::styled(diff_cell(&row.left), Style::default().fg(left_color)),
+                                Span::styled(" │ ", Style::default().fg(theme.border_primary)),
+                                Span::styled(
+                                    diff_cell(&row.right),
+                                    Style::default().fg(right_color),
+                                ),
+                            ]))
+                            .build()
+                        })
+                        .collect();
+                    Element::column(lines).spacing(0).build()
+                }
+            };
+
+            let scrollable = Element::scrollable(
+                FocusId::new("diff-scroll"),
+                diff_content,
+                &state.diff_scroll_state,
+            )
+            .on_navigate(Msg::DiffScroll)
+            .on_render(Msg::DiffSetDimensions)
+            .build();
+
+            let buttons = button_row![("diff-close", "Close", Msg::CloseDiff),];
+
+            let modal_content = Element::panel(
+                Element::container(col![
+                    scrollable => Fill(1),
+                    spacer!() => Length(1),
+                    buttons => Length(3),
+                ])
+                .padding(2)
+                .build(),
+            )
+            .title("Comparison Diff")
+            .width(110)
+            .height(40)
             .build();
 
             LayeredView::new(main_ui).with_app_modal(modal_content, crate::tui::Alignment::Center)
@@ -1651,6 +2120,7 @@ impl App for MigrationComparisonSelectApp {
             && !state.show_import_browser
             && !state.show_import_config
             && !state.show_batch_export_modal
+            && !state.show_diff_modal
         {
             let config = crate::global_runtime_config();
 
@@ -1723,6 +2193,18 @@ impl App for MigrationComparisonSelectApp {
                     Msg::RequestBatchExport,
                 ));
             }
+
+            if state.list_state.selected().is_some() {
+                subs.push(Subscription::keyboard(
+                    config.get_keybind("migration_comparison.diff"),
+                    if state.diff_base.is_some() {
+                        "Diff against marked comparison"
+                    } else {
+                        "Mark comparison for diff"
+                    },
+                    Msg::RequestDiff,
+                ));
+            }
         } else if state.show_create_modal {
             subs.push(Subscription::keyboard(
                 KeyCode::Esc,
@@ -1765,6 +2247,12 @@ impl App for MigrationComparisonSelectApp {
                 "Cancel batch export",
                 Msg::BatchExportCancel,
             ));
+        } else if state.show_diff_modal {
+            subs.push(Subscription::keyboard(
+                KeyCode::Esc,
+                "Close diff",
+                Msg::CloseDiff,
+            ));
         }
 
         subs
@@ -1823,6 +2311,47 @@ impl App for MigrationComparisonSelectApp {
 
 // Helper functions
 
+/// Reject filenames containing path separators or characters Windows can't store
+fn validate_filename(filename: &str) -> Result<(), String> {
+    const INVALID: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+    if let Some(c) = filename.chars().find(|c| INVALID.contains(c)) {
+        return Err(format!("Filename cannot contain '{}'", c));
+    }
+    if filename.trim().is_empty() {
+        return Err("Filename is required".to_string());
+    }
+    Ok(())
+}
+
+/// Load an environment's entity names in alphabetical order
+///
+/// The metadata document lists entity sets in no particular order, so the list
+/// is sorted once here and cached sorted; the autocomplete then narrows it
+/// without re-sorting on every keystroke.
+async fn load_sorted_entities(env: String) -> Result<Vec<String>, String> {
+    use crate::api::metadata::parse_entity_list;
+    let config = crate::global_config();
+
+    let mut entities = match config.get_entity_cache(&env, 24).await {
+        Ok(Some(cached)) if cached.is_sorted() => return Ok(cached),
+        // Entries cached before the list was sorted
+        Ok(Some(cached)) => cached,
+        _ => {
+            let client = crate::client_manager()
+                .get_client(&env)
+                .await
+                .map_err(|e| e.to_string())?;
+            let metadata_xml = client.fetch_metadata().await.map_err(|e| e.to_string())?;
+            parse_entity_list(&metadata_xml).map_err(|e| e.to_string())?
+        }
+    };
+    entities.sort_unstable();
+    entities.dedup();
+    let _ = config.set_entity_cache(&env, entities.clone()).await;
+    Ok(entities)
+}
+
 fn reload_comparisons(migration_name: String) -> Command<Msg> {
     Command::perform(
         async move {
@@ -1837,9 +2366,69 @@ fn reload_comparisons(migration_name: String) -> Command<Msg> {
 }
 
 /// Export comparison data to JSON file
-async fn export_comparison(comparison_id: i64, file_path: PathBuf) -> Result<(), String> {
+///
+/// With `embed_from` set to the (source, target) environments, each example
+/// pair's record data is embedded in the bundle, fetching pairs that don't
+/// already carry it. Otherwise only the record IDs are exported.
+async fn export_comparison(
+    comparison_id: i64,
+    file_path: PathBuf,
+    embed_from: Option<(String, String)>,
+) -> Result<(), String> {
     log::info!("Exporting comparison {} to {:?}", comparison_id, file_path);
 
+    let mut export_data = load_comparison_export_data(comparison_id).await?;
+    match embed_from {
+        Some((source_env, target_env)) => {
+            for pair in &mut export_data.example_pairs {
+                if pair.data.is_some() {
+                    continue;
+                }
+                let (source, target) = super::entity_comparison::fetch_example_pair_data(
+                    &source_env,
+                    &export_data.source_entity,
+                    &pair.source_record_id,
+                    &target_env,
+                    &export_data.target_entity,
+                    &pair.target_record_id,
+                )
+                .await?;
+                pair.data = Some(ExampleRecordData { source, target });
+            }
+        }
+        None => {
+            for pair in &mut export_data.example_pairs {
+                pair.data = None;
+            }
+        }
+    }
+
+    // Serialize to JSON
+    let json = serde_json::to_string_pretty(&export_data)
+        .map_err(|e| format!("Failed to serialize: {}", e))?;
+
+    // Write to file
+    std::fs::write(&file_path, json).map_err(|e| format!("Failed to write file: {}", e))?;
+
+    log::info!("Successfully exported comparison to {:?}", file_path);
+    Ok(())
+}
+
+/// Width of each side of the diff view, in characters
+const DIFF_COLUMN_WIDTH: usize = 48;
+
+/// Pad or truncate one side of a diff row to the column width
+fn diff_cell(text: &str) -> String {
+    if text.chars().count() > DIFF_COLUMN_WIDTH {
+        let truncated: String = text.chars().take(DIFF_COLUMN_WIDTH - 1).collect();
+        format!("{}…", truncated)
+    } else {
+        format!("{:<width$}", text, width = DIFF_COLUMN_WIDTH)
+    }
+}
+
+/// Load a saved comparison into its export representation
+async fn load_comparison_export_data(comparison_id: i64) -> Result<ComparisonExportData, String> {
     let config = crate::global_config();
 
     // Fetch comparison from database
@@ -1873,11 +2462,11 @@ async fn export_comparison(comparison_id: i64, file_path: PathBuf) -> Result<(),
             source_record_id: pair.source_record_id,
             target_record_id: pair.target_record_id,
             label: pair.label,
+            data: pair.embedded_data,
         })
         .collect();
 
-    // Build export data
-    let export_data = ComparisonExportData {
+    Ok(ComparisonExportData {
         version: "1.0".to_string(),
         export_date: chrono::Utc::now().to_rfc3339(),
         source_entity: comparison.source_entity,
@@ -1888,24 +2477,30 @@ async fn export_comparison(comparison_id: i64, file_path: PathBuf) -> Result<(),
         import_source_file,
         ignored_items,
         example_pairs,
-    };
-
-    // Serialize to JSON
-    let json = serde_json::to_string_pretty(&export_data)
-        .map_err(|e| format!("Failed to serialize: {}", e))?;
-
-    // Write to file
-    std::fs::write(&file_path, json).map_err(|e| format!("Failed to write file: {}", e))?;
+    })
+}
 
-    log::info!("Successfully exported comparison to {:?}", file_path);
-    Ok(())
+/// Read the exported source/target entity names from a comparison bundle
+async fn read_bundle_entities(file_path: PathBuf) -> Result<(String, String), String> {
+    let json = tokio::fs::read_to_string(&file_path)
+        .await
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+    let data: ComparisonExportData =
+        serde_json::from_str(&json).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    Ok((data.source_entity, data.target_entity))
 }
 
 /// Import comparison data from JSON file
+///
+/// Entity names and field prefixes are rewritten with `remap` before saving.
+/// Example pairs keep any record data embedded in the bundle when
+/// `use_embedded_data` is set; otherwise their records are fetched live.
 async fn import_comparison(
     file_path: PathBuf,
     migration_name: String,
     name: String,
+    use_embedded_data: bool,
+    remap: ImportRemap,
 ) -> Result<i64, String> {
     log::info!("Importing comparison from {:?}", file_path);
 
@@ -1914,8 +2509,9 @@ async fn import_comparison(
         std::fs::read_to_string(&file_path).map_err(|e| format!("Failed to read file: {}", e))?;
 
     // Parse JSON
-    let import_data: ComparisonExportData =
+    let mut import_data: ComparisonExportData =
         serde_json::from_str(&json).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    import_data.apply_remap(&remap);
 
     // Get entities from JSON file
     let source_entity = import_data.source_entity.clone();
@@ -1973,12 +2569,7 @@ async fn import_comparison(
             continue;
         }
 
-        let example_pair = crate::tui::apps::migration::entity_comparison::ExamplePair {
-            id: uuid::Uuid::new_v4().to_string(),
-            source_record_id: pair.source_record_id,
-            target_record_id: pair.target_record_id,
-            label: pair.label,
-        };
+        let example_pair = pair.into_example_pair(use_embedded_data);
 
         config
             .save_example_pair(&source_entity, &target_entity, &example_pair)
@@ -2055,3 +2646,125 @@ fn build_entity_comparison_json(
     serde_json::to_string(&data)
         .map_err(|e| format!("Failed to serialize entity_comparison: {}", e))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle(example_pairs: Vec<ExamplePairExport>) -> ComparisonExportData {
+        ComparisonExportData {
+            version: "1.0".to_string(),
+            export_date: "2026-01-01T00:00:00+00:00".to_string(),
+            source_entity: "cgk_project".to_string(),
+            target_entity: "nrq_project".to_string(),
+            field_mappings: HashMap::from([("cgk_name".to_string(), vec!["nrq_name".to_string()])]),
+            prefix_mappings: HashMap::new(),
+            imported_mappings: HashMap::new(),
+            import_source_file: None,
+            ignored_items: Vec::new(),
+            example_pairs,
+        }
+    }
+
+    #[test]
+    fn test_bundle_with_embedded_example_data_round_trips() {
+        let data = ExampleRecordData {
+            source: serde_json::json!({ "cgk_projectid": "a1", "cgk_name": "Bridge" }),
+            target: serde_json::json!({ "nrq_projectid": "b2", "nrq_name": "Bridge" }),
+        };
+        let exported = bundle(vec![
+            ExamplePairExport {
+                source_record_id: "a1".to_string(),
+                target_record_id: "b2".to_string(),
+                label: Some("Bridge".to_string()),
+                data: Some(data.clone()),
+            },
+            ExamplePairExport {
+                source_record_id: "a3".to_string(),
+                target_record_id: "b4".to_string(),
+                label: None,
+                data: None,
+            },
+        ]);
+
+        let json = serde_json::to_string_pretty(&exported).unwrap();
+        let imported: ComparisonExportData = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(imported.example_pairs[0].data, Some(data.clone()));
+        assert_eq!(imported.example_pairs[1].data, None);
+        // Pairs without data are written exactly as before
+        assert_eq!(json.matches("\"data\"").count(), 1);
+
+        let pairs: Vec<ExamplePair> = imported
+            .example_pairs
+            .iter()
+            .cloned()
+            .map(|pair| pair.into_example_pair(true))
+            .collect();
+        assert_eq!(pairs[0].embedded_data, Some(data));
+        assert_eq!(pairs[0].label.as_deref(), Some("Bridge"));
+
+        // Choosing to re-fetch drops the embedded data
+        let refetched = imported.example_pairs[0].clone().into_example_pair(false);
+        assert_eq!(refetched.embedded_data, None);
+        assert_eq!(refetched.source_record_id, "a1");
+    }
+
+    #[test]
+    fn test_remap_rewrites_entity_references() {
+        let mut data = bundle(Vec::new());
+        data.source_entity = "account".to_string();
+        data.target_entity = "account".to_string();
+        data.field_mappings = HashMap::from([
+            ("account.name".to_string(), vec!["account.name".to_string()]),
+            (
+                "account.cgk_code".to_string(),
+                vec![
+                    "account.nrq_code".to_string(),
+                    "contact.nrq_code".to_string(),
+                ],
+            ),
+        ]);
+        data.prefix_mappings =
+            HashMap::from([("account.cgk_".to_string(), vec!["account.nrq_".to_string()])]);
+        data.ignored_items = vec![
+            "fields:source:account.fax".to_string(),
+            "fields:target:account.telex".to_string(),
+            "fields:source:cgk_legacy".to_string(),
+        ];
+
+        data.apply_remap(&ImportRemap {
+            source_entity: "acc".to_string(),
+            target_entity: "acc".to_string(),
+            prefixes: parse_prefix_renames("cgk_=abc_").unwrap(),
+        });
+
+        assert_eq!(data.source_entity, "acc");
+        assert_eq!(data.target_entity, "acc");
+        assert_eq!(
+            data.field_mappings,
+            HashMap::from([
+                ("acc.name".to_string(), vec!["acc.name".to_string()]),
+                (
+                    "acc.abc_code".to_string(),
+                    vec!["acc.nrq_code".to_string(), "contact.nrq_code".to_string()],
+                ),
+            ])
+        );
+        assert_eq!(
+            data.prefix_mappings,
+            HashMap::from([("acc.abc_".to_string(), vec!["acc.nrq_".to_string()])])
+        );
+        assert_eq!(
+            data.ignored_items,
+            vec![
+                "fields:source:acc.fax",
+                "fields:target:acc.telex",
+                "fields:source:abc_legacy",
+            ]
+        );
+
+        assert!(parse_prefix_renames("cgk_").is_err());
+        assert_eq!(parse_prefix_renames(" ").unwrap(), Vec::new());
+    }
+}
@@ -2,6 +2,7 @@
 // See todo.md for implementation plan
 
 pub mod batch_export;
+pub mod comparison_diff;
 pub mod entity_comparison;
 pub mod migration_comparison_select_app;
 pub mod migration_environment_app;
@@ -11,7 +11,7 @@ use super::views::{
     build_import_confirmation, build_import_file_browser, build_import_settings,
     build_interruption_warning_modal,
 };
-use crate::api::resilience::ResilienceConfig;
+use crate::api::resilience::{ResilienceConfig, RetryPolicy};
 use crate::transfer::excel::{ParsedOperations, read_operations_excel};
 use crate::tui::{
     ModalState,
@@ -53,11 +53,13 @@ pub enum Msg {
     TogglePauseSelected,
     DeleteSelected,
     RetrySelected,
+    RetryOperationSelected,
 
     // Queue management
     AddItems(Vec<QueueItem>),
     RequestClearQueue,
     ConfirmClearQueue,
+    PruneCompleted,
     RequestDeleteSelected,
     ConfirmDeleteSelected,
     CancelModal,
@@ -65,6 +67,7 @@ pub enum Msg {
     // Execution
     StartExecution(String),
     ExecutionCompleted(String, QueueResult),
+    RetryDue(String),
 
     // Filters/Settings
     SetFilter(QueueFilter),
@@ -670,6 +673,16 @@ impl App for OperationQueueApp {
             }
 
             Msg::RetryItem(id) => {
+                // Nothing to run when every remaining operation was handed off,
+                // and ETag conflicts would fail the same way again
+                if state
+                    .queue_items
+                    .iter()
+                    .any(|item| item.id == id && !item.can_retry())
+                {
+                    log::warn!("Queue item {} can't be retried as is", id);
+                    return Command::None;
+                }
                 if state.mutate_item(&id, |item| {
                     item.status = OperationStatus::Pending;
                     item.result = None;
@@ -751,18 +764,19 @@ impl App for OperationQueueApp {
                 let item = state.queue_items.iter().find(|i| i.id == id).cloned();
 
                 if let Some(item) = item {
-                    // Get operations to execute (excluding already-succeeded ones)
-                    let ops_to_execute = if item.succeeded_indices.is_empty() {
+                    // Get operations to execute (excluding succeeded and handed-off ones)
+                    let settled_indices = item.settled_indices();
+                    let ops_to_execute = if settled_indices.is_empty() {
                         item.operations.clone()
                     } else {
                         log::info!(
-                            "Retrying item {} with {} succeeded indices, executing {} of {} operations",
+                            "Retrying item {} with {} settled indices, executing {} of {} operations",
                             item.id,
-                            item.succeeded_indices.len(),
-                            item.operations.len() - item.succeeded_indices.len(),
+                            settled_indices.len(),
+                            item.operations.len() - settled_indices.len(),
                             item.operations.len()
                         );
-                        item.operations.without_indices(&item.succeeded_indices)
+                        item.operations.without_indices(&settled_indices)
                     };
 
                     let exec_cmd = Command::perform(
@@ -827,6 +841,21 @@ impl App for OperationQueueApp {
 
                 let mut publish_cmd = Command::None;
                 let mut persist_cmd = Command::None;
+                let mut retry_cmd = Command::None;
+
+                // Transient failures (429/5xx) are re-enqueued with backoff until the
+                // item's retry budget is exhausted
+                let retry_count = state
+                    .queue_items
+                    .iter()
+                    .find(|i| i.id == id)
+                    .filter(|item| item.should_auto_retry(&result))
+                    .map(|item| item.retry_count + 1);
+                let last_error = if result.success {
+                    None
+                } else {
+                    result.error_summary()
+                };
 
                 // First, read the item to compute new state
                 let item_data = state.queue_items.iter().find(|i| i.id == id).map(|item| {
@@ -841,9 +870,18 @@ impl App for OperationQueueApp {
                         .filter(|r| !r.success)
                         .count();
                     let total_count = result.operation_results.len();
+                    let settled_indices = item.settled_indices();
 
                     let (new_status, new_succeeded_indices) = if result.success {
-                        (OperationStatus::Done, vec![])
+                        if item.retried_indices.is_empty() {
+                            (OperationStatus::Done, vec![])
+                        } else {
+                            // Everything run here succeeded, but handed-off operations didn't
+                            let succeeded = (0..item.operations.len())
+                                .filter(|i| !item.retried_indices.contains(i))
+                                .collect();
+                            (OperationStatus::PartiallyFailed, succeeded)
+                        }
                     } else if succeeded_count > 0 && failed_count > 0 {
                         let newly_succeeded: Vec<usize> = result
                             .operation_results
@@ -851,13 +889,13 @@ impl App for OperationQueueApp {
                             .enumerate()
                             .filter(|(_, r)| r.success)
                             .map(|(idx, _)| {
-                                if item.succeeded_indices.is_empty() {
+                                if settled_indices.is_empty() {
                                     idx
                                 } else {
                                     let mut original_idx = 0;
                                     let mut count = 0;
                                     for i in 0..item.operations.len() {
-                                        if !item.succeeded_indices.contains(&i) {
+                                        if !settled_indices.contains(&i) {
                                             if count == idx {
                                                 original_idx = i;
                                                 break;
@@ -884,6 +922,12 @@ impl App for OperationQueueApp {
                         (OperationStatus::Failed, item.succeeded_indices.clone())
                     };
 
+                    let new_status = if retry_count.is_some() {
+                        OperationStatus::Pending
+                    } else {
+                        new_status
+                    };
+
                     (new_status, new_succeeded_indices, item.metadata.clone())
                 });
 
@@ -892,12 +936,40 @@ impl App for OperationQueueApp {
                     let new_status_clone = new_status.clone();
                     let new_succeeded_indices_clone = new_succeeded_indices.clone();
                     let result_clone = result.clone();
+                    let retry_delay =
+                        retry_count.map(|attempt| RetryPolicy::default().calculate_delay(attempt));
+                    let last_error_clone = last_error.clone();
                     state.mutate_item(&id, move |item| {
                         item.status = new_status_clone;
                         item.result = Some(result_clone);
                         item.succeeded_indices = new_succeeded_indices_clone;
+                        if let Some(count) = retry_count {
+                            item.retry_count = count;
+                        }
+                        if last_error_clone.is_some() {
+                            item.last_error = last_error_clone;
+                        }
+                        item.retry_after =
+                            retry_delay.map(|delay| std::time::Instant::now() + delay);
                     });
 
+                    if let (Some(attempt), Some(delay)) = (retry_count, retry_delay) {
+                        log::warn!(
+                            "Queue item {} failed with a transient error, retry {} in {:?}",
+                            id,
+                            attempt,
+                            delay
+                        );
+                        let retry_id = id.clone();
+                        retry_cmd = Command::perform(
+                            async move {
+                                tokio::time::sleep(delay).await;
+                                retry_id
+                            },
+                            Msg::RetryDue,
+                        );
+                    }
+
                     // Invalidate caches after status change so filter updates
                     state.invalidate_index_cache();
                     state.tree_state.invalidate_cache();
@@ -905,9 +977,24 @@ impl App for OperationQueueApp {
                     // Persist to database
                     let item_id = id.clone();
                     let result_for_persist = result.clone();
+                    let retry_state = last_error.clone().map(|error| {
+                        let count = state
+                            .queue_items
+                            .iter()
+                            .find(|i| i.id == id)
+                            .map(|i| i.retry_count)
+                            .unwrap_or_default();
+                        (count, error)
+                    });
                     persist_cmd = Command::perform(
                         async move {
                             let config = crate::global_config();
+                            if let Some((count, error)) = retry_state {
+                                config
+                                    .update_queue_item_retry(&item_id, count, Some(&error))
+                                    .await
+                                    .map_err(|e| format!("Failed to update retry state: {}", e))?;
+                            }
                             config
                                 .update_queue_item_status(&item_id, new_status)
                                 .await
@@ -981,20 +1068,22 @@ impl App for OperationQueueApp {
                         }
                     }
 
-                    // Publish completion event for subscribers
-                    let completion_data = serde_json::json!({
-                        "id": id,
-                        "result": result,
-                        "metadata": metadata,
-                    });
-                    publish_cmd = Command::Publish {
-                        topic: "queue:item_completed".to_string(),
-                        data: completion_data,
-                    };
+                    // Publish completion event for subscribers (not for scheduled retries)
+                    if retry_count.is_none() {
+                        let completion_data = serde_json::json!({
+                            "id": id,
+                            "result": result,
+                            "metadata": metadata,
+                        });
+                        publish_cmd = Command::Publish {
+                            topic: "queue:item_completed".to_string(),
+                            data: completion_data,
+                        };
+                    }
                 }
 
-                // Track failures and pause if we've hit the limit
-                if !result.success && state.auto_play {
+                // Track permanent failures and pause if we've hit the limit
+                if !result.success && retry_count.is_none() && state.auto_play {
                     state.session_failure_count += 1;
                     // max_failures of 0 means unlimited
                     if state.max_failures > 0 && state.session_failure_count >= state.max_failures {
@@ -1015,7 +1104,17 @@ impl App for OperationQueueApp {
                     Command::None
                 };
 
-                Command::Batch(vec![publish_cmd, persist_cmd, next_cmd])
+                Command::Batch(vec![publish_cmd, persist_cmd, retry_cmd, next_cmd])
+            }
+
+            Msg::RetryDue(id) => {
+                // Backoff elapsed: make the item eligible again and resume if playing
+                state.mutate_item(&id, |item| item.retry_after = None);
+                if state.auto_play {
+                    execute_up_to_max(state)
+                } else {
+                    Command::None
+                }
             }
 
             Msg::SetFilter(filter) => {
@@ -1149,6 +1248,14 @@ impl App for OperationQueueApp {
             Msg::RetrySelected => {
                 let id = state.selected_item_id.clone();
                 if let Some(id) = id {
+                    if state
+                        .queue_items
+                        .iter()
+                        .any(|item| item.id == id && !item.can_retry())
+                    {
+                        log::warn!("Queue item {} can't be retried as is", id);
+                        return Command::None;
+                    }
                     let found = state.mutate_item(&id, |item| {
                         item.status = OperationStatus::Pending;
                         item.result = None;
@@ -1186,6 +1293,65 @@ impl App for OperationQueueApp {
                 Command::None
             }
 
+            Msg::RetryOperationSelected => {
+                // Child node ids are "{parent_id}_{index}"
+                let Some((parent_id, index)) = state
+                    .selected_item_id
+                    .as_deref()
+                    .and_then(|id| id.rsplit_once('_'))
+                    .and_then(|(parent, idx)| {
+                        Some((parent.to_string(), idx.parse::<usize>().ok()?))
+                    })
+                else {
+                    return Command::None;
+                };
+
+                let retry_item = state
+                    .queue_items
+                    .iter()
+                    .find(|item| item.id == parent_id)
+                    .and_then(|item| item.retry_operation(index));
+                let Some(retry_item) = retry_item else {
+                    return Command::None;
+                };
+
+                let mut updated = None;
+                state.mutate_item(&parent_id, |item| {
+                    item.mark_operation_retried(index);
+                    updated = Some((item.status.clone(), item.retried_indices.clone()));
+                });
+                let Some((new_status, retried_indices)) = updated else {
+                    return Command::None;
+                };
+                state.invalidate_index_cache();
+                state.tree_state.invalidate_cache();
+
+                let item_id = parent_id.clone();
+                let persist_cmd = Command::perform(
+                    async move {
+                        let config = crate::global_config();
+                        config
+                            .update_queue_item_retried_indices(&item_id, &retried_indices)
+                            .await
+                            .map_err(|e| format!("Failed to update retried_indices: {}", e))?;
+                        config
+                            .update_queue_item_status(&item_id, new_status)
+                            .await
+                            .map_err(|e| format!("Failed to update status: {}", e))
+                    },
+                    |result| {
+                        if let Err(err) = result {
+                            Msg::PersistenceError(err)
+                        } else {
+                            Msg::PersistenceError("".to_string())
+                        }
+                    },
+                );
+
+                let add_cmd = Self::update(state, Msg::AddItems(vec![retry_item]));
+                Command::Batch(vec![persist_cmd, add_cmd])
+            }
+
             Msg::AddItems(items) => {
                 let was_empty = state.queue_items.is_empty();
 
@@ -1261,6 +1427,42 @@ impl App for OperationQueueApp {
                 )
             }
 
+            Msg::PruneCompleted => {
+                let before = state.queue_items.len();
+                state
+                    .queue_items
+                    .retain(|item| item.status != OperationStatus::Done);
+                if state.queue_items.len() == before {
+                    return Command::None;
+                }
+
+                if let Some(ref selected) = state.selected_item_id {
+                    if !state.queue_items.iter().any(|item| &item.id == selected) {
+                        state.selected_item_id = None;
+                        state.tree_state.select_and_scroll(None);
+                    }
+                }
+                state.invalidate_index_cache();
+                state.tree_state.invalidate_cache();
+
+                Command::perform(
+                    async move {
+                        crate::global_config()
+                            .prune_completed_queue_items()
+                            .await
+                            .map(|_| ())
+                            .map_err(|e| format!("Failed to prune completed items: {}", e))
+                    },
+                    |result| {
+                        if let Err(err) = result {
+                            Msg::PersistenceError(err)
+                        } else {
+                            Msg::PersistenceError("".to_string())
+                        }
+                    },
+                )
+            }
+
             Msg::CancelModal => {
                 state.clear_confirm_modal.close();
                 state.delete_confirm_modal.close();
@@ -1720,6 +1922,11 @@ impl App for OperationQueueApp {
                 "Clear queue",
                 Msg::RequestClearQueue,
             ),
+            Subscription::keyboard(
+                KeyBinding::new(KeyCode::Char('X')),
+                "Prune completed items",
+                Msg::PruneCompleted,
+            ),
             Subscription::keyboard(KeyBinding::new(KeyCode::Esc), "Back to launcher", Msg::Back),
             Subscription::keyboard(
                 KeyBinding::new(KeyCode::Char('=')),
@@ -1741,6 +1948,11 @@ impl App for OperationQueueApp {
                 "Retry (selected)",
                 Msg::RetrySelected,
             ),
+            Subscription::keyboard(
+                KeyBinding::new(KeyCode::Char('R')),
+                "Retry operation (selected)",
+                Msg::RetryOperationSelected,
+            ),
             Subscription::keyboard(
                 KeyBinding::new(KeyCode::Char('d')),
                 "Delete (selected)",
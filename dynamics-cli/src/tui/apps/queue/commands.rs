@@ -1,8 +1,9 @@
 //! Command helpers for queue execution
 
 use super::app::{Msg, State};
-use super::models::{OperationStatus, QueueResult};
+use super::models::{OperationStatus, QueueItem, QueueResult};
 use crate::tui::command::Command;
+use std::sync::Arc;
 
 /// Helper function to save queue settings to database
 /// Note: auto_play is NOT persisted (always starts paused)
@@ -34,15 +35,30 @@ pub fn save_settings_command(state: &State) -> Command<Msg> {
 /// Find the current priority tier (minimum priority among Pending or Running items).
 /// We must complete all items at a priority level before starting items at higher priorities
 /// because higher priority numbers may have dependencies on lower ones.
-fn current_priority_tier(state: &State) -> Option<u8> {
-    state
-        .queue_items
+fn current_priority_tier(items: &[Arc<QueueItem>]) -> Option<u8> {
+    items
         .iter()
         .filter(|i| i.status == OperationStatus::Pending || i.status == OperationStatus::Running)
         .map(|i| i.priority)
         .min()
 }
 
+/// Pick the next pending item to start: the first item (in insertion order)
+/// at the current priority tier whose retry backoff (if any) has elapsed.
+fn next_pending_item_id(items: &[Arc<QueueItem>]) -> Option<String> {
+    let current_tier = current_priority_tier(items)?;
+    let now = std::time::Instant::now();
+
+    items
+        .iter()
+        .find(|item| {
+            item.status == OperationStatus::Pending
+                && item.priority == current_tier
+                && item.is_ready(now)
+        })
+        .map(|item| item.id.clone())
+}
+
 /// Execute multiple items at the current priority tier, up to max_concurrent.
 /// This is the main entry point for starting queue execution.
 pub fn execute_up_to_max(state: &mut State) -> Command<Msg> {
@@ -73,18 +89,8 @@ pub fn execute_next_if_available(state: &mut State) -> Command<Msg> {
         return Command::None;
     }
 
-    // Find the current priority tier (min priority among Pending or Running)
-    let Some(current_tier) = current_priority_tier(state) else {
-        return Command::None;
-    };
-
     // Find next pending item AT the current tier only
-    let next = state
-        .queue_items
-        .iter()
-        .filter(|item| item.status == OperationStatus::Pending && item.priority == current_tier)
-        .map(|item| item.id.clone())
-        .next();
+    let next = next_pending_item_id(&state.queue_items);
 
     if let Some(id) = next {
         // Mark as running immediately and set start time
@@ -129,7 +135,6 @@ pub fn execute_next_if_available(state: &mut State) -> Command<Msg> {
                 async move {
                     use crate::api::resilience::ResilienceConfig;
                     let start = std::time::Instant::now();
-                    let op_count = item.operations.len();
 
                     // Get client for this environment from global client manager
                     let client = match crate::client_manager()
@@ -162,8 +167,19 @@ pub fn execute_next_if_available(state: &mut State) -> Command<Msg> {
                             ResilienceConfig::default()
                         }
                     };
-                    log::info!("Queue item {} - executing {} operations", item.id, op_count);
-                    let result = item.operations.execute(&client, &resilience).await;
+                    // Skip operations that already succeeded or were handed off to a retry item
+                    let settled_indices = item.settled_indices();
+                    let ops_to_execute = if settled_indices.is_empty() {
+                        item.operations.clone()
+                    } else {
+                        item.operations.without_indices(&settled_indices)
+                    };
+                    log::info!(
+                        "Queue item {} - executing {} operations",
+                        item.id,
+                        ops_to_execute.len()
+                    );
+                    let result = ops_to_execute.execute(&client, &resilience).await;
                     log::info!(
                         "Queue item {} - completed in {:?}",
                         item.id,
@@ -199,3 +215,47 @@ pub fn execute_next_if_available(state: &mut State) -> Command<Msg> {
         Command::None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::operations::Operations;
+    use crate::tui::apps::queue::models::QueueMetadata;
+
+    fn item(description: &str, priority: u8) -> Arc<QueueItem> {
+        Arc::new(QueueItem::new(
+            Operations::new(),
+            QueueMetadata {
+                source: "Test".to_string(),
+                entity_type: "account".to_string(),
+                description: description.to_string(),
+                row_number: None,
+                environment_name: "dev".to_string(),
+            },
+            priority,
+        ))
+    }
+
+    #[test]
+    fn test_drain_order_respects_priority_then_insertion() {
+        let mut items = vec![
+            item("backlog-1", 10),
+            item("urgent-1", 1),
+            item("backlog-2", 10),
+            item("urgent-2", 1),
+            item("mid", 5),
+        ];
+
+        let mut drained = Vec::new();
+        while let Some(id) = next_pending_item_id(&items) {
+            let item = Arc::make_mut(items.iter_mut().find(|i| i.id == id).unwrap());
+            drained.push(item.metadata.description.clone());
+            item.status = OperationStatus::Done;
+        }
+
+        assert_eq!(
+            drained,
+            vec!["urgent-1", "urgent-2", "mid", "backlog-1", "backlog-2"]
+        );
+    }
+}
@@ -1,9 +1,17 @@
 //! Data models for the operation queue
 
 use crate::api::operations::{OperationResult, Operations};
+use crate::api::resilience::RetryableError;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+/// Default number of automatic retries for transient failures
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+fn default_max_retries() -> u32 {
+    DEFAULT_MAX_RETRIES
+}
+
 /// Item in the operation queue
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct QueueItem {
@@ -32,6 +40,22 @@ pub struct QueueItem {
     /// On retry, only operations NOT in this set will be executed
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub succeeded_indices: Vec<usize>,
+    /// Indices of operations handed off to separate retry items
+    /// They are not executed here again, but don't count as succeeded either
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub retried_indices: Vec<usize>,
+    /// Automatic retries already attempted for transient failures
+    #[serde(default)]
+    pub retry_count: u32,
+    /// Maximum automatic retries before the item is marked permanently failed
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Error from the most recent failed attempt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    /// Earliest time a scheduled retry may start (backoff, not persisted)
+    #[serde(skip)]
+    pub retry_after: Option<Instant>,
     /// Cached column data for rendering (to avoid recomputing every frame)
     #[serde(skip)]
     pub cached_columns: Option<Vec<String>>,
@@ -51,6 +75,11 @@ impl QueueItem {
             was_interrupted: false,
             interrupted_at: None,
             succeeded_indices: Vec::new(),
+            retried_indices: Vec::new(),
+            retry_count: 0,
+            max_retries: DEFAULT_MAX_RETRIES,
+            last_error: None,
+            retry_after: None,
             cached_columns: None,
         }
     }
@@ -65,17 +94,97 @@ impl QueueItem {
         self.cached_columns = Some(columns);
     }
 
+    /// Indices of operations this item won't execute again: succeeded or handed off
+    pub fn settled_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self
+            .succeeded_indices
+            .iter()
+            .chain(&self.retried_indices)
+            .copied()
+            .collect();
+        indices.sort();
+        indices.dedup();
+        indices
+    }
+
     /// Get the number of operations that still need to be executed
     pub fn pending_operation_count(&self) -> usize {
         self.operations
             .len()
-            .saturating_sub(self.succeeded_indices.len())
+            .saturating_sub(self.settled_indices().len())
     }
 
     /// Check if this item has partial success (some ops succeeded, some failed)
     pub fn is_partially_succeeded(&self) -> bool {
         !self.succeeded_indices.is_empty() && self.succeeded_indices.len() < self.operations.len()
     }
+
+    /// Whether a manual retry can change the outcome
+    ///
+    /// Not when nothing is left to run, or when the last run hit an ETag
+    /// conflict: the operations carry the stale If-Match ETag, so they'd fail
+    /// the same way until the records are fetched again.
+    pub fn can_retry(&self) -> bool {
+        self.pending_operation_count() > 0
+            && !self
+                .result
+                .as_ref()
+                .is_some_and(|result| result.failure_kind() == RetryableError::Conflict)
+    }
+
+    /// Whether a failed result should be re-enqueued automatically.
+    /// Only transient failures (429/5xx/timeouts) are retried, up to `max_retries`.
+    pub fn should_auto_retry(&self, result: &QueueResult) -> bool {
+        !result.success
+            && self.retry_count < self.max_retries
+            && result.failure_kind().should_retry()
+    }
+
+    /// Extract a single operation into a new pending item so it can be retried on its own.
+    /// Returns None if the index is out of range, the operation already succeeded or
+    /// was handed off, or this item has not failed.
+    pub fn retry_operation(&self, index: usize) -> Option<QueueItem> {
+        if !matches!(
+            self.status,
+            OperationStatus::Failed | OperationStatus::PartiallyFailed
+        ) || self.settled_indices().contains(&index)
+        {
+            return None;
+        }
+        let operation = self.operations.operations().get(index)?.clone();
+
+        let metadata = QueueMetadata {
+            description: format!("{} (retry op {})", self.metadata.description, index + 1),
+            ..self.metadata.clone()
+        };
+        Some(QueueItem::new(
+            Operations::from_operations(vec![operation]),
+            metadata,
+            self.priority,
+        ))
+    }
+
+    /// Record that an operation was handed off to a separate retry item, so this item
+    /// won't execute it again. Handed-off operations don't count as succeeded, so the
+    /// item stays failed (or partially failed, if anything succeeded here).
+    pub fn mark_operation_retried(&mut self, index: usize) {
+        if !self.retried_indices.contains(&index) {
+            self.retried_indices.push(index);
+            self.retried_indices.sort();
+        }
+        self.status = if self.succeeded_indices.len() == self.operations.len() {
+            OperationStatus::Done
+        } else if self.succeeded_indices.is_empty() {
+            OperationStatus::Failed
+        } else {
+            OperationStatus::PartiallyFailed
+        };
+    }
+
+    /// Whether a scheduled retry's backoff has elapsed (always true if none is scheduled)
+    pub fn is_ready(&self, now: Instant) -> bool {
+        self.retry_after.is_none_or(|after| now >= after)
+    }
 }
 
 /// Metadata about where a queue item came from
@@ -149,6 +258,36 @@ pub struct QueueResult {
     pub duration_ms: u64,
 }
 
+impl QueueResult {
+    /// Classify the failure using the HTTP status codes of the failed operations.
+    /// Any non-retryable failure (e.g. 400 validation) makes the whole result non-retryable.
+    pub fn failure_kind(&self) -> RetryableError {
+        let mut kinds = self
+            .operation_results
+            .iter()
+            .filter_map(|r| r.failure_kind());
+
+        let Some(first) = kinds.next() else {
+            return RetryableError::Unknown;
+        };
+
+        if !first.should_retry() {
+            return first;
+        }
+        kinds.find(|kind| !kind.should_retry()).unwrap_or(first)
+    }
+
+    /// Best error message for display: the top-level error, else the first failed operation's
+    pub fn error_summary(&self) -> Option<String> {
+        self.error.clone().or_else(|| {
+            self.operation_results
+                .iter()
+                .find(|r| !r.success)
+                .and_then(|r| r.display_error())
+        })
+    }
+}
+
 /// Filter for displaying queue items
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum QueueFilter {
@@ -211,3 +350,122 @@ impl SortMode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::operations::Operation;
+
+    fn failed_item() -> QueueItem {
+        QueueItem::new(
+            Operations::new(),
+            QueueMetadata {
+                source: "Test".to_string(),
+                entity_type: "account".to_string(),
+                description: "Row 1".to_string(),
+                row_number: Some(1),
+                environment_name: "dev".to_string(),
+            },
+            10,
+        )
+    }
+
+    fn failed_result(status_code: u16) -> QueueResult {
+        let operation = Operation::create("accounts", serde_json::json!({ "name": "Test" }));
+        QueueResult {
+            success: false,
+            operation_results: vec![OperationResult::error(
+                operation,
+                format!("HTTP {}", status_code),
+                Some(status_code),
+            )],
+            error: None,
+            duration_ms: 5,
+        }
+    }
+
+    #[test]
+    fn test_transient_failure_is_retried_and_client_error_is_not() {
+        let mut item = failed_item();
+
+        assert!(item.should_auto_retry(&failed_result(503)));
+        assert!(item.should_auto_retry(&failed_result(429)));
+        assert!(!item.should_auto_retry(&failed_result(400)));
+
+        // Retries stop once the budget is exhausted
+        item.retry_count = item.max_retries;
+        assert!(!item.should_auto_retry(&failed_result(503)));
+    }
+
+    #[test]
+    fn test_etag_conflict_is_not_retryable() {
+        let mut item = failed_item();
+        item.operations = Operations::from_operations(vec![Operation::update(
+            "accounts",
+            "abc",
+            serde_json::json!({ "name": "Test" }),
+        )]);
+        item.status = OperationStatus::Failed;
+
+        let conflict = QueueResult {
+            success: false,
+            operation_results: vec![OperationResult::error(
+                item.operations.operations()[0].clone(),
+                OperationResult::failure_message(412, "Precondition Failed".to_string()),
+                Some(412),
+            )],
+            error: None,
+            duration_ms: 5,
+        };
+        assert_eq!(conflict.failure_kind(), RetryableError::Conflict);
+        assert!(!item.should_auto_retry(&conflict));
+
+        item.result = Some(failed_result(503));
+        assert!(item.can_retry());
+        item.result = Some(conflict);
+        assert!(!item.can_retry());
+    }
+
+    #[test]
+    fn test_retry_operation_extracts_single_operation() {
+        let mut item = failed_item();
+        item.operations = Operations::from_operations(vec![
+            Operation::create("accounts", serde_json::json!({ "name": "A" })),
+            Operation::create("accounts", serde_json::json!({ "name": "B" })),
+            Operation::create("accounts", serde_json::json!({ "name": "C" })),
+        ]);
+        item.status = OperationStatus::PartiallyFailed;
+        item.succeeded_indices = vec![0];
+
+        let retry = item
+            .retry_operation(1)
+            .expect("failed operation is retryable");
+        assert_eq!(retry.operations.len(), 1);
+        assert_eq!(
+            serde_json::to_value(&retry.operations.operations()[0]).unwrap(),
+            serde_json::to_value(&item.operations.operations()[1]).unwrap()
+        );
+        assert_eq!(retry.metadata.entity_type, "account");
+        assert_eq!(retry.metadata.environment_name, "dev");
+        assert_eq!(retry.status, OperationStatus::Pending);
+        assert_ne!(retry.id, item.id);
+
+        // Already-succeeded and out-of-range operations can't be retried
+        assert!(item.retry_operation(0).is_none());
+        assert!(item.retry_operation(3).is_none());
+
+        item.mark_operation_retried(1);
+        assert_eq!(item.status, OperationStatus::PartiallyFailed);
+        assert_eq!(item.pending_operation_count(), 1);
+        assert!(item.retry_operation(1).is_none());
+        item.mark_operation_retried(2);
+        assert_eq!(item.pending_operation_count(), 0);
+
+        // Only operation 0 actually succeeded here
+        assert_eq!(item.succeeded_indices, vec![0]);
+        assert_eq!(item.retried_indices, vec![1, 2]);
+        assert_eq!(item.settled_indices(), vec![0, 1, 2]);
+        assert!(item.is_partially_succeeded());
+        assert_eq!(item.status, OperationStatus::PartiallyFailed);
+    }
+}
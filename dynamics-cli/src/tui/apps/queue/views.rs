@@ -220,7 +220,9 @@ fn build_operation_details(
                 entity, attribute
             )
         }
+        Operation::CreateGlobalOptionSet { .. } => "POST /GlobalOptionSetDefinitions".to_string(),
         Operation::PublishAllXml => "POST /PublishAllXml".to_string(),
+        Operation::PublishXml { .. } => "POST /PublishXml".to_string(),
     };
 
     lines.push(
@@ -403,6 +405,41 @@ fn build_operation_details(
                 .build(),
             );
         }
+        Operation::CreateGlobalOptionSet {
+            option_set_data,
+            solution_name,
+        } => {
+            lines.push(Element::text(""));
+            lines.push(
+                Element::styled_text(RataLine::from(vec![Span::styled(
+                    "Option Set Data:",
+                    Style::default().fg(theme.accent_muted).bold(),
+                )]))
+                .build(),
+            );
+
+            if let Ok(json_str) = serde_json::to_string_pretty(option_set_data) {
+                for line in json_str.lines() {
+                    lines.push(
+                        Element::styled_text(RataLine::from(vec![Span::styled(
+                            format!("  {}", line),
+                            Style::default().fg(theme.text_primary),
+                        )]))
+                        .build(),
+                    );
+                }
+            }
+
+            if let Some(solution) = solution_name {
+                lines.push(
+                    Element::styled_text(RataLine::from(vec![
+                        Span::styled("Solution: ", Style::default().fg(theme.border_primary)),
+                        Span::styled(solution.clone(), Style::default().fg(theme.text_primary)),
+                    ]))
+                    .build(),
+                );
+            }
+        }
         Operation::PublishAllXml => {
             lines.push(Element::text(""));
             lines.push(
@@ -413,6 +450,16 @@ fn build_operation_details(
                 .build(),
             );
         }
+        Operation::PublishXml { entities } => {
+            lines.push(Element::text(""));
+            lines.push(
+                Element::styled_text(RataLine::from(vec![
+                    Span::styled("Entities: ", Style::default().fg(theme.border_primary)),
+                    Span::styled(entities.join(", "), Style::default().fg(theme.text_primary)),
+                ]))
+                .build(),
+            );
+        }
     }
 
     // Show result if operation completed
@@ -472,6 +519,19 @@ fn build_operation_details(
                     .build(),
                 );
 
+                if let Some(explanation) = op_result
+                    .error_details()
+                    .and_then(|details| details.explanation())
+                {
+                    lines.push(
+                        Element::styled_text(RataLine::from(vec![Span::styled(
+                            format!("    {}", explanation),
+                            Style::default().fg(theme.accent_warning),
+                        )]))
+                        .build(),
+                    );
+                }
+
                 for error_line in error.lines() {
                     lines.push(
                         Element::styled_text(RataLine::from(vec![Span::styled(
@@ -562,6 +622,29 @@ fn build_batch_overview(
         );
     }
 
+    // Automatic retry state
+    if item.retry_count > 0 {
+        lines.push(
+            Element::styled_text(RataLine::from(vec![
+                Span::styled("Retries: ", Style::default().fg(theme.border_primary)),
+                Span::styled(
+                    format!("{}/{}", item.retry_count, item.max_retries),
+                    Style::default().fg(theme.accent_warning),
+                ),
+            ]))
+            .build(),
+        );
+    }
+    if let Some(ref last_error) = item.last_error {
+        lines.push(
+            Element::styled_text(RataLine::from(vec![
+                Span::styled("Last error: ", Style::default().fg(theme.border_primary)),
+                Span::styled(last_error.clone(), Style::default().fg(theme.accent_error)),
+            ]))
+            .build(),
+        );
+    }
+
     // Warning section if interrupted
     if item.was_interrupted {
         lines.push(Element::text(""));
@@ -739,11 +822,9 @@ fn build_batch_overview(
                     theme.accent_error
                 };
 
-                let msg = if let Some(err) = &op_result.error {
-                    err.clone()
-                } else {
-                    "OK".to_string()
-                };
+                let msg = op_result
+                    .display_error()
+                    .unwrap_or_else(|| "OK".to_string());
 
                 lines.push(
                     Element::styled_text(RataLine::from(vec![
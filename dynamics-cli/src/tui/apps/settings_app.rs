@@ -49,6 +49,9 @@ pub struct State {
     create_theme_input_state: crate::tui::widgets::TextInputState,
     editing_color: Option<EditingColor>,
     color_picker_state: crate::tui::widgets::ColorPickerState,
+    importing_theme: bool,
+    theme_import_browser: crate::tui::widgets::FileBrowserState,
+    theme_notice: Option<String>,
 
     // Keybind editor state (keybind view)
     keybinds: std::collections::HashMap<String, crate::tui::KeyBinding>,
@@ -127,6 +130,14 @@ pub enum Msg {
     CancelEditColor,
     SaveColor,
     ColorSaved(Result<(), String>),
+    ExportTheme,
+    ThemeExported(Result<String, String>),
+    OpenImportTheme,
+    CloseImportTheme,
+    ImportThemeNavigate(crossterm::event::KeyCode),
+    ImportThemeSetViewportHeight(usize),
+    ImportThemeFileSelected(std::path::PathBuf),
+    ThemeImported(Result<String, String>),
 
     // Keybind editor
     KeybindsLoaded(std::collections::HashMap<String, crate::tui::KeyBinding>),
@@ -170,6 +181,11 @@ impl Default for State {
                 Color::Rgb(180, 190, 254),
                 ColorPickerMode::HSL,
             ),
+            importing_theme: false,
+            theme_import_browser: crate::tui::widgets::FileBrowserState::new(
+                std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("/")),
+            ),
+            theme_notice: None,
             keybinds: std::collections::HashMap::new(),
             keybind_actions: Vec::new(),
             selected_keybind_idx: 0,
@@ -193,6 +209,15 @@ impl State {
 
 impl crate::tui::AppState for State {}
 
+/// Theme name for an imported file: its name without ".json" or ".theme.json"
+fn imported_theme_name(path: &std::path::Path) -> Option<String> {
+    let file_name = path.file_name()?.to_str()?;
+    let name = file_name.strip_suffix(".json").unwrap_or(file_name);
+    let name = name.strip_suffix(".theme").unwrap_or(name).trim();
+    // Theme option keys are "theme.<name>.<color>", so the name can't contain dots
+    (!name.is_empty() && !name.contains('.')).then(|| name.to_string())
+}
+
 impl ListItem for String {
     type Msg = Msg;
 
@@ -1333,6 +1358,158 @@ impl App for SettingsApp {
                 Command::None
             }
 
+            Msg::ExportTheme => {
+                let theme_name = state.active_theme_name.clone();
+                let Some(theme) = state.themes.get(&theme_name) else {
+                    state.error = Some(format!("Theme '{}' is not loaded", theme_name));
+                    return Command::None;
+                };
+                let json = theme.to_json();
+
+                Command::perform(
+                    async move {
+                        let path = std::env::current_dir()
+                            .map_err(|e| e.to_string())?
+                            .join(format!("{}.theme.json", theme_name));
+                        let text =
+                            serde_json::to_string_pretty(&json).map_err(|e| e.to_string())?;
+                        tokio::fs::write(&path, text)
+                            .await
+                            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+                        Ok(format!(
+                            "Exported theme '{}' to {}",
+                            theme_name,
+                            path.display()
+                        ))
+                    },
+                    Msg::ThemeExported,
+                )
+            }
+
+            Msg::ThemeExported(Ok(notice)) => {
+                state.error = None;
+                state.theme_notice = Some(notice);
+                Command::None
+            }
+
+            Msg::ThemeExported(Err(e)) => {
+                state.error = Some(format!("Failed to export theme: {}", e));
+                Command::None
+            }
+
+            Msg::OpenImportTheme => {
+                state.importing_theme = true;
+                state.theme_import_browser.set_filter(|entry| {
+                    entry.is_dir || entry.name.to_lowercase().ends_with(".json")
+                });
+                let _ = state.theme_import_browser.refresh();
+                Command::set_focus(FocusId::new("theme-import-browser"))
+            }
+
+            Msg::CloseImportTheme => {
+                state.importing_theme = false;
+                Command::None
+            }
+
+            Msg::ImportThemeNavigate(key) => {
+                use crate::tui::widgets::{FileBrowserAction, FileBrowserEvent};
+                use crossterm::event::KeyCode;
+
+                match key {
+                    KeyCode::Enter => {
+                        if let Some(FileBrowserAction::FileSelected(path)) = state
+                            .theme_import_browser
+                            .handle_event(FileBrowserEvent::Activate)
+                        {
+                            return Self::update(state, Msg::ImportThemeFileSelected(path));
+                        }
+                    }
+                    KeyCode::Backspace => {
+                        state
+                            .theme_import_browser
+                            .handle_event(FileBrowserEvent::GoUp);
+                    }
+                    _ => state.theme_import_browser.handle_navigation_key(key),
+                }
+                Command::None
+            }
+
+            Msg::ImportThemeSetViewportHeight(height) => {
+                let item_count = state.theme_import_browser.entries().len();
+                let list_state = state.theme_import_browser.list_state_mut();
+                list_state.set_viewport_height(height);
+                list_state.update_scroll(height, item_count);
+                Command::None
+            }
+
+            Msg::ImportThemeFileSelected(path) => {
+                state.importing_theme = false;
+
+                // The imported theme is named after its file, e.g. "nord.theme.json" → "nord"
+                let Some(theme_name) = imported_theme_name(&path) else {
+                    state.error = Some(format!("Cannot name a theme after {}", path.display()));
+                    return Command::None;
+                };
+                if state.themes.contains_key(&theme_name) {
+                    state.error = Some(format!("Theme '{}' already exists", theme_name));
+                    return Command::None;
+                }
+
+                Command::perform(
+                    async move {
+                        use crate::tui::color::color_to_hex;
+
+                        let text = tokio::fs::read_to_string(&path)
+                            .await
+                            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+                        let json: serde_json::Value = serde_json::from_str(&text)
+                            .map_err(|e| format!("Invalid JSON: {}", e))?;
+                        let theme = Theme::from_json(&json).map_err(|e| e.to_string())?;
+
+                        // Store the imported palette as a new theme
+                        let config = crate::global_config();
+                        for (color_name, color) in theme.colors() {
+                            let key = format!("theme.{}.{}", theme_name, color_name);
+                            config
+                                .options
+                                .set_string(&key, color_to_hex(color))
+                                .await
+                                .map_err(|e| format!("Failed to write {}: {}", key, e))?;
+                        }
+
+                        Ok(format!(
+                            "Imported {} as theme '{}'",
+                            path.display(),
+                            theme_name
+                        ))
+                    },
+                    Msg::ThemeImported,
+                )
+            }
+
+            Msg::ThemeImported(Ok(notice)) => {
+                state.error = None;
+                state.theme_notice = Some(notice);
+
+                // Reload all themes so the preview shows the imported colors
+                Command::perform(
+                    async {
+                        use crate::config::options::registrations::themes;
+                        let config = crate::global_config();
+                        let registry = crate::options_registry();
+                        let theme_names = themes::list_themes(&registry);
+                        crate::tui::state::config::load_all_themes(&config.options, theme_names)
+                            .await
+                    },
+                    Msg::ThemesLoaded,
+                )
+            }
+
+            Msg::ThemeImported(Err(e)) => {
+                state.error = Some(format!("Failed to import theme: {}", e));
+                Command::None
+            }
+
             // Keybind editor messages
             Msg::KeybindsLoaded(keybinds) => {
                 use crate::config::options::registrations::keybinds;
@@ -1696,6 +1873,12 @@ impl App for SettingsApp {
             view = view.with_app_modal(modal, crate::tui::Alignment::Center);
         }
 
+        // Theme import file browser modal
+        if state.importing_theme {
+            let modal = Self::render_import_theme_modal(state);
+            view = view.with_app_modal(modal, crate::tui::Alignment::Center);
+        }
+
         // Color picker modal
         if state.editing_color.is_some() {
             let modal = Self::render_color_picker_modal(state);
@@ -1743,6 +1926,15 @@ impl App for SettingsApp {
             ));
         }
 
+        // If importing a theme, allow Escape to cancel
+        if state.importing_theme {
+            subs.push(Subscription::keyboard(
+                KeyCode::Esc,
+                "Cancel theme import",
+                Msg::CloseImportTheme,
+            ));
+        }
+
         // If editing color, allow Escape to cancel
         if state.editing_color.is_some() {
             subs.push(Subscription::keyboard(
@@ -1941,13 +2133,31 @@ impl SettingsApp {
             .on_press(Msg::DeleteSelectedTheme)
             .build();
 
+        let export_button = Element::button("theme-export", "Export")
+            .on_press(Msg::ExportTheme)
+            .build();
+
+        let import_button = Element::button("theme-import", "Import")
+            .on_press(Msg::OpenImportTheme)
+            .build();
+
         let buttons = row![
             create_button => Length(15),
             Element::text("") => Length(1),
             delete_button => Length(10),
+            Element::text("") => Length(1),
+            export_button => Length(10),
+            Element::text("") => Length(1),
+            import_button => Length(10),
             Element::text("") => Fill(1),
         ];
 
+        let notice = Element::styled_text(Line::from(vec![Span::styled(
+            state.theme_notice.clone().unwrap_or_default(),
+            Style::default().fg(theme.accent_success),
+        )]))
+        .build();
+
         // Color preview for selected theme
         let preview_panel = Self::render_theme_preview(state, theme);
 
@@ -1956,6 +2166,7 @@ impl SettingsApp {
             theme_select => Length(3),  // Same size as button (borders + content)
             preview_panel => Fill(1),  // 12 color buttons (3 lines each) + panel borders
             buttons => Length(3),  // Buttons with padding
+            notice => Length(1),
         ];
 
         let panel = Element::panel(content).title("Themes").build();
@@ -2135,19 +2346,20 @@ impl SettingsApp {
             }
 
             let display_name = keybinds::get_action_display_name(&registry, app, action_name);
-            let current_keybind = state
-                .keybinds
-                .get(action)
-                .map(|kb| kb.to_string())
-                .unwrap_or_else(|| {
-                    // Fall back to default from registry
-                    crate::global_runtime_config()
-                        .get_keybind(action)
-                        .to_string()
-                });
-
-            // Format: "Display Name: [Keybind]"
-            let label = format!("{}: [{}]", display_name, current_keybind);
+            let current_keybind = state.keybinds.get(action).copied().unwrap_or_else(|| {
+                // Fall back to default from registry
+                crate::global_runtime_config().get_keybind(action)
+            });
+            let has_conflict =
+                !keybinds::find_conflicts(&registry, &state.keybinds, action, current_keybind)
+                    .is_empty();
+
+            // Format: "Display Name: [Keybind]", flagged when another action uses the key
+            let label = if has_conflict {
+                format!("{}: [{}] ⚠ conflict", display_name, current_keybind)
+            } else {
+                format!("{}: [{}]", display_name, current_keybind)
+            };
 
             let item_id = Box::leak(format!("keybind-{}", action).into_boxed_str());
             let item = Element::button(FocusId::new(item_id), label)
@@ -2253,6 +2465,26 @@ impl SettingsApp {
                 Style::default().fg(theme.text_secondary)
             };
 
+            // Other actions already using the captured key
+            let conflicts: Vec<String> = state
+                .captured_key
+                .map(|key| keybinds::find_conflicts(&registry, &state.keybinds, action, key))
+                .unwrap_or_default()
+                .iter()
+                .map(|other| {
+                    let (other_app, other_action) = other.split_once('.').unwrap_or(("", other));
+                    keybinds::get_action_display_name(&registry, other_app, other_action)
+                })
+                .collect();
+            let conflict_line = if conflicts.is_empty() {
+                Line::from("")
+            } else {
+                Line::from(vec![Span::styled(
+                    format!("⚠ Already used by: {}", conflicts.join(", ")),
+                    Style::default().fg(theme.accent_warning),
+                )])
+            };
+
             let content = col![
                 Element::styled_text(Line::from(vec![
                     Span::styled(format!("Edit Keybind: {}", display_name), Style::default().fg(theme.accent_primary).bold())
@@ -2265,7 +2497,7 @@ impl SettingsApp {
                     Span::styled("Captured: ", Style::default().fg(theme.text_primary)),
                     Span::styled(captured_display, status_style),
                 ])).build() => Length(1),
-                spacer!() => Length(1),
+                Element::styled_text(conflict_line).build() => Length(1),
                 Element::styled_text(Line::from(vec![
                     Span::styled("Press any key combination to capture it.", Style::default().fg(theme.text_secondary))
                 ])).build() => Length(1),
@@ -2348,6 +2580,48 @@ impl SettingsApp {
         }
     }
 
+    fn render_import_theme_modal(state: &mut State) -> Element<Msg> {
+        let theme = &crate::global_runtime_config().theme;
+        use_constraints!();
+
+        let browser =
+            Element::file_browser("theme-import-browser", &state.theme_import_browser, theme)
+                .on_file_selected(Msg::ImportThemeFileSelected)
+                .on_navigate(Msg::ImportThemeNavigate)
+                .on_render(Msg::ImportThemeSetViewportHeight)
+                .build();
+
+        let browser_panel = Element::panel(browser)
+            .title(format!(
+                "Select Theme File - {}",
+                state.theme_import_browser.current_path().display()
+            ))
+            .build();
+
+        let hint_text = "The file is imported as a new theme named after it.";
+
+        let content = col![
+            browser_panel => Fill(1),
+            Element::text("") => Length(1),
+            Element::styled_text(Line::from(vec![
+                Span::styled(hint_text, Style::default().fg(theme.text_tertiary))
+            ])).build() => Length(1),
+            Element::styled_text(Line::from(vec![
+                Span::raw("Press "),
+                Span::styled("Enter", Style::default().fg(theme.accent_primary).bold()),
+                Span::raw(" to import, "),
+                Span::styled("Esc", Style::default().fg(theme.accent_primary).bold()),
+                Span::raw(" to cancel"),
+            ])).build() => Length(1),
+        ];
+
+        Element::panel(content)
+            .title("Import Theme")
+            .width(80)
+            .height(25)
+            .build()
+    }
+
     fn render_create_theme_modal(state: &mut State) -> Element<Msg> {
         let theme = &crate::global_runtime_config().theme;
         use_constraints!();
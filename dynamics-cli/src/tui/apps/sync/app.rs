@@ -16,7 +16,7 @@ use super::steps::{
     render_analysis, render_confirm, render_diff_review, render_entity_select,
     render_environment_select,
 };
-use super::types::SyncStep;
+use super::types::{DEFAULT_CHANGESET_SIZE, SyncStep};
 
 /// Entity Sync App - wizard for syncing entities between environments
 pub struct EntitySyncApp;
@@ -453,6 +453,14 @@ impl App for EntitySyncApp {
                 state.confirm.confirmed = !state.confirm.confirmed;
                 Command::None
             }
+            Msg::ToggleDeleteOrphans => {
+                if let Some(plan) = state.sync_plan.as_mut() {
+                    plan.delete_orphans = !plan.delete_orphans;
+                    // Changing what happens to target records invalidates the confirmation
+                    state.confirm.confirmed = false;
+                }
+                Command::None
+            }
             Msg::Execute => {
                 if !state.confirm.can_execute() {
                     return Command::None;
@@ -474,6 +482,7 @@ impl App for EntitySyncApp {
                 state.confirm.schema_batch_ids = ids.schema_ids;
                 state.confirm.update_batch_ids = ids.update_ids;
                 state.confirm.insert_batch_ids = ids.insert_ids;
+                state.confirm.lookup_patch_batch_ids = ids.lookup_patch_ids;
                 state.confirm.junction_batch_ids = ids.junction_ids;
 
                 // Set initial state
@@ -497,6 +506,9 @@ impl App for EntitySyncApp {
                 } else if !state.confirm.insert_batch_ids.is_empty() {
                     state.confirm.phase = super::state::ExecutionPhase::Inserting;
                     state.confirm.total_batches = state.confirm.insert_batch_ids.len();
+                } else if !state.confirm.lookup_patch_batch_ids.is_empty() {
+                    state.confirm.phase = super::state::ExecutionPhase::PatchingLookups;
+                    state.confirm.total_batches = state.confirm.lookup_patch_batch_ids.len();
                 } else if !state.confirm.junction_batch_ids.is_empty() {
                     state.confirm.phase = super::state::ExecutionPhase::InsertingJunctions;
                     state.confirm.total_batches = state.confirm.junction_batch_ids.len();
@@ -580,6 +592,10 @@ impl App for EntitySyncApp {
                             confirm.phase = super::state::ExecutionPhase::Inserting;
                             confirm.total_batches = confirm.insert_batch_ids.len();
                             confirm.current_batch = 1;
+                        } else if !confirm.lookup_patch_batch_ids.is_empty() {
+                            confirm.phase = super::state::ExecutionPhase::PatchingLookups;
+                            confirm.total_batches = confirm.lookup_patch_batch_ids.len();
+                            confirm.current_batch = 1;
                         } else if !confirm.junction_batch_ids.is_empty() {
                             confirm.phase = super::state::ExecutionPhase::InsertingJunctions;
                             confirm.total_batches = confirm.junction_batch_ids.len();
@@ -620,6 +636,10 @@ impl App for EntitySyncApp {
                                 confirm.phase = super::state::ExecutionPhase::Inserting;
                                 confirm.total_batches = confirm.insert_batch_ids.len();
                                 confirm.current_batch = 1;
+                            } else if !confirm.lookup_patch_batch_ids.is_empty() {
+                                confirm.phase = super::state::ExecutionPhase::PatchingLookups;
+                                confirm.total_batches = confirm.lookup_patch_batch_ids.len();
+                                confirm.current_batch = 1;
                             } else if !confirm.junction_batch_ids.is_empty() {
                                 confirm.phase = super::state::ExecutionPhase::InsertingJunctions;
                                 confirm.total_batches = confirm.junction_batch_ids.len();
@@ -644,6 +664,10 @@ impl App for EntitySyncApp {
                                 confirm.phase = super::state::ExecutionPhase::Inserting;
                                 confirm.total_batches = confirm.insert_batch_ids.len();
                                 confirm.current_batch = 1;
+                            } else if !confirm.lookup_patch_batch_ids.is_empty() {
+                                confirm.phase = super::state::ExecutionPhase::PatchingLookups;
+                                confirm.total_batches = confirm.lookup_patch_batch_ids.len();
+                                confirm.current_batch = 1;
                             } else if !confirm.junction_batch_ids.is_empty() {
                                 confirm.phase = super::state::ExecutionPhase::InsertingJunctions;
                                 confirm.total_batches = confirm.junction_batch_ids.len();
@@ -664,6 +688,10 @@ impl App for EntitySyncApp {
                                 confirm.phase = super::state::ExecutionPhase::Inserting;
                                 confirm.total_batches = confirm.insert_batch_ids.len();
                                 confirm.current_batch = 1;
+                            } else if !confirm.lookup_patch_batch_ids.is_empty() {
+                                confirm.phase = super::state::ExecutionPhase::PatchingLookups;
+                                confirm.total_batches = confirm.lookup_patch_batch_ids.len();
+                                confirm.current_batch = 1;
                             } else if !confirm.junction_batch_ids.is_empty() {
                                 confirm.phase = super::state::ExecutionPhase::InsertingJunctions;
                                 confirm.total_batches = confirm.junction_batch_ids.len();
@@ -680,6 +708,28 @@ impl App for EntitySyncApp {
                         confirm.current_batch =
                             confirm.total_batches - confirm.insert_batch_ids.len();
                         if confirm.insert_batch_ids.is_empty() {
+                            if !confirm.lookup_patch_batch_ids.is_empty() {
+                                confirm.phase = super::state::ExecutionPhase::PatchingLookups;
+                                confirm.total_batches = confirm.lookup_patch_batch_ids.len();
+                                confirm.current_batch = 1;
+                            } else if !confirm.junction_batch_ids.is_empty() {
+                                confirm.phase = super::state::ExecutionPhase::InsertingJunctions;
+                                confirm.total_batches = confirm.junction_batch_ids.len();
+                                confirm.current_batch = 1;
+                            } else {
+                                confirm.phase = super::state::ExecutionPhase::Complete;
+                                confirm.executing = false;
+                            }
+                        }
+                    } else if let Some(pos) = confirm
+                        .lookup_patch_batch_ids
+                        .iter()
+                        .position(|bid| bid == &id)
+                    {
+                        confirm.lookup_patch_batch_ids.remove(pos);
+                        confirm.current_batch =
+                            confirm.total_batches - confirm.lookup_patch_batch_ids.len();
+                        if confirm.lookup_patch_batch_ids.is_empty() {
                             if !confirm.junction_batch_ids.is_empty() {
                                 confirm.phase = super::state::ExecutionPhase::InsertingJunctions;
                                 confirm.total_batches = confirm.junction_batch_ids.len();
@@ -831,6 +881,11 @@ impl App for EntitySyncApp {
                         "Export",
                         Msg::ExportReport,
                     ));
+                    subs.push(Subscription::keyboard(
+                        KeyCode::Char('o'),
+                        "Toggle delete orphans",
+                        Msg::ToggleDeleteOrphans,
+                    ));
                     if state.confirm.confirmed {
                         subs.push(Subscription::keyboard(
                             KeyCode::Enter,
@@ -959,7 +1014,7 @@ async fn run_analysis(
     target_env: &str,
     selected_entities: &[String],
 ) -> Result<super::types::SyncPlan, String> {
-    use super::logic::{DependencyGraph, compare_schemas};
+    use super::logic::{DependencyGraph, compare_schemas, detect_missing_global_option_sets};
     use super::types::*;
     use super::{
         FetchStatus, init_analysis_progress, set_analysis_complete, set_analysis_phase,
@@ -1002,6 +1057,15 @@ async fn run_analysis(
     // Phase 1: Fetch all schemas and EntitySetNames in parallel
     set_analysis_phase("Fetching schemas...");
 
+    // Global option sets already in target; new choice fields may reference others
+    let target_option_sets = target_client
+        .fetch_global_option_set_names()
+        .await
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to fetch target global option sets: {}", e);
+            HashSet::new()
+        });
+
     let schema_futures: Vec<_> = selected_entities.iter().map(|entity_name| {
         let origin_client = Arc::clone(&origin_client);
         let target_client = Arc::clone(&target_client);
@@ -1011,11 +1075,12 @@ async fn run_analysis(
             set_entity_schema_status(&entity_name, FetchStatus::Fetching);
 
             // Fetch fields, entity metadata, and raw attribute metadata in parallel
-            let (origin_fields, target_fields, entity_metadata, origin_attrs_raw) = tokio::join!(
+            let (origin_fields, target_fields, entity_metadata, origin_attrs_raw, origin_option_sets) = tokio::join!(
                 origin_client.fetch_entity_fields_combined(&entity_name),
                 target_client.fetch_entity_fields_combined(&entity_name),
                 origin_client.fetch_entity_metadata_info(&entity_name),
-                origin_client.fetch_entity_attributes_raw(&entity_name)
+                origin_client.fetch_entity_attributes_raw(&entity_name),
+                origin_client.fetch_attribute_global_option_sets(&entity_name)
             );
 
             let origin_fields = origin_fields
@@ -1028,7 +1093,22 @@ async fn run_analysis(
 
             // Raw attributes are optional - log warning if failed but don't fail the whole entity
             let origin_attrs_raw = match origin_attrs_raw {
-                Ok(attrs) => Some(attrs),
+                Ok(mut attrs) => {
+                    // Attach referenced global option sets so schema diff can create them first
+                    match origin_option_sets {
+                        Ok(option_sets) => {
+                            for (attr_name, option_set) in option_sets {
+                                if let Some(attr) = attrs.get_mut(&attr_name).and_then(|a| a.as_object_mut()) {
+                                    attr.insert("GlobalOptionSet".to_string(), option_set);
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            log::warn!("Failed to fetch global option sets for {}: {}", entity_name, e);
+                        }
+                    }
+                    Some(attrs)
+                }
                 Err(e) => {
                     log::warn!("Failed to fetch raw attributes for {}: {} - schema sync will skip this entity", entity_name, e);
                     None
@@ -1289,12 +1369,14 @@ async fn run_analysis(
         entities_with_fields.push((entity_name.clone(), None, origin_fields.clone()));
 
         // Compare schemas - pass raw attribute metadata for CreateAttribute operations
-        let schema_diff = compare_schemas(
+        let mut schema_diff = compare_schemas(
             entity_name,
             origin_fields,
             target_fields,
             origin_attrs_raw.as_ref(),
         );
+        schema_diff.global_option_sets_to_add =
+            detect_missing_global_option_sets(&schema_diff, &target_option_sets);
 
         if schema_diff.has_changes() {
             has_schema_changes = true;
@@ -1469,24 +1551,33 @@ async fn run_analysis(
 
     // Get sorted order and update priorities
     set_analysis_phase("Computing sync order...");
-    if let Ok(sorted) = dep_graph.topological_sort() {
-        for (insert_priority, name) in sorted.iter().enumerate() {
-            if let Some(plan) = entity_plans
-                .iter_mut()
-                .find(|p| &p.entity_info.logical_name == name)
-            {
-                plan.entity_info.insert_priority = insert_priority as u32;
-                plan.entity_info.delete_priority = (sorted.len() - 1 - insert_priority) as u32;
-
-                // Preserve Junction category from is_intersect flag, otherwise use graph categorization
-                if plan.entity_info.category != DependencyCategory::Junction {
-                    plan.entity_info.category = dep_graph.categorize(name);
-                }
+    let resolved = dep_graph.resolve_order();
+    for cycle in &resolved.cycles {
+        log::warn!(
+            "Circular lookup dependency between {}; using fallback order, review before syncing",
+            cycle.join(", ")
+        );
+    }
+    for entity in dep_graph.self_referencing_entities() {
+        log::debug!("Deferring self-referencing lookups on {}", entity);
+    }
+    let sorted = &resolved.order;
+    for (insert_priority, name) in sorted.iter().enumerate() {
+        if let Some(plan) = entity_plans
+            .iter_mut()
+            .find(|p| &p.entity_info.logical_name == name)
+        {
+            plan.entity_info.insert_priority = insert_priority as u32;
+            plan.entity_info.delete_priority = (sorted.len() - 1 - insert_priority) as u32;
 
-                // Get dependents
-                if let Some(deps) = dep_graph.dependents.get(name) {
-                    plan.entity_info.dependents = deps.iter().cloned().collect();
-                }
+            // Preserve Junction category from is_intersect flag, otherwise use graph categorization
+            if plan.entity_info.category != DependencyCategory::Junction {
+                plan.entity_info.category = dep_graph.categorize(name);
+            }
+
+            // Get dependents
+            if let Some(deps) = dep_graph.dependents.get(name) {
+                plan.entity_info.dependents = deps.iter().cloned().collect();
             }
         }
     }
@@ -1611,6 +1702,21 @@ async fn run_analysis(
 
     set_analysis_complete();
 
+    let optimistic_concurrency = crate::config::options::Options::new(
+        crate::global_config().pool.clone(),
+        crate::options_registry(),
+    )
+    .get_bool("sync.optimistic_concurrency")
+    .await
+    .unwrap_or(false);
+    let changeset_size = crate::config::options::Options::new(
+        crate::global_config().pool.clone(),
+        crate::options_registry(),
+    )
+    .get_uint("sync.changeset_size")
+    .await
+    .map_or(DEFAULT_CHANGESET_SIZE, |size| size as usize);
+
     Ok(SyncPlan {
         origin_env: origin_env.to_string(),
         target_env: target_env.to_string(),
@@ -1619,6 +1725,10 @@ async fn run_analysis(
         has_schema_changes,
         total_delete_count,
         total_insert_count,
+        delete_orphans: false,
+        dependency_cycles: resolved.cycles,
+        optimistic_concurrency,
+        changeset_size,
     })
 }
 
@@ -1686,6 +1796,19 @@ async fn fetch_all_records(
     }
 
     let mut result = client.execute_query(&builder.build()).await?;
+    if let Some(error) = result.fetch_error() {
+        // A missing entity set has nothing to sync - skip it rather than failing the run
+        if error.is_not_found() {
+            log::warn!("Skipping {} ({}): {}", entity_name, entity_set_name, error);
+            return Ok(all_records);
+        }
+        return Err(anyhow::anyhow!(
+            "Failed to fetch {} ({}): {}",
+            entity_name,
+            entity_set_name,
+            error
+        ));
+    }
     if let Some(ref data) = result.data {
         all_records.extend(data.value.clone());
     }
@@ -1757,11 +1880,16 @@ async fn fetch_target_records(
             .and_then(|attr| record.get(attr))
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
+        let etag = record
+            .get("@odata.etag")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
         Some(super::types::TargetRecord {
             id,
             name,
             junction_parent_id: None,
             junction_target_id: None,
+            etag,
         })
     };
 
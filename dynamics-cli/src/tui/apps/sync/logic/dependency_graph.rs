@@ -3,10 +3,11 @@
 //! This module provides functions to:
 //! - Build a dependency graph from entity lookup relationships
 //! - Perform topological sort for correct insert/delete ordering
+//! - Detect dependency cycles and fall back to a deterministic order
 //! - Categorize entities as standalone, dependent, or junction
 //! - Assign operation priorities
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use crate::api::metadata::{FieldMetadata, FieldType};
 use crate::tui::apps::sync::types::{DependencyCategory, LookupInfo, SyncEntityInfo};
@@ -149,69 +150,189 @@ impl DependencyGraph {
         }
     }
 
-    /// Perform topological sort using Kahn's algorithm
-    /// Returns entities in insert order (dependencies first)
-    pub fn topological_sort(&self) -> Result<Vec<String>, CycleError> {
-        let mut in_degree: HashMap<String, usize> = HashMap::new();
-        let mut result = Vec::new();
-        let mut queue = VecDeque::new();
+    /// Entities with a lookup to themselves
+    ///
+    /// Self-references never block ordering: the record is created without
+    /// the lookup and the FK is patched once the whole entity is inserted.
+    pub fn self_referencing_entities(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .entities
+            .values()
+            .filter(|e| {
+                e.internal_lookups()
+                    .iter()
+                    .any(|l| l.target_entity == e.logical_name)
+            })
+            .map(|e| e.logical_name.clone())
+            .collect();
+        names.sort();
+        names
+    }
 
-        // Calculate in-degrees
-        for name in self.entities.keys() {
-            in_degree.insert(name.clone(), 0);
+    /// Find groups of entities that depend on each other in a cycle
+    ///
+    /// Returns the strongly connected components with more than one entity
+    /// (Tarjan's algorithm). Names within a group and the groups themselves
+    /// are sorted so reports are stable.
+    pub fn find_cycles(&self) -> Vec<Vec<String>> {
+        struct Tarjan<'a> {
+            graph: &'a DependencyGraph,
+            index: usize,
+            indices: HashMap<&'a str, usize>,
+            low_links: HashMap<&'a str, usize>,
+            stack: Vec<&'a str>,
+            on_stack: HashSet<&'a str>,
+            cycles: Vec<Vec<String>>,
         }
-        for deps in self.dependencies.values() {
-            for dep in deps {
-                if let Some(count) = in_degree.get_mut(dep) {
-                    *count += 1;
+
+        impl<'a> Tarjan<'a> {
+            fn visit(&mut self, node: &'a str) {
+                self.indices.insert(node, self.index);
+                self.low_links.insert(node, self.index);
+                self.index += 1;
+                self.stack.push(node);
+                self.on_stack.insert(node);
+
+                let mut deps: Vec<&'a str> = self
+                    .graph
+                    .dependencies
+                    .get(node)
+                    .map(|d| d.iter().map(|s| s.as_str()).collect())
+                    .unwrap_or_default();
+                deps.sort();
+
+                for dep in deps {
+                    if !self.indices.contains_key(dep) {
+                        self.visit(dep);
+                        let low = self.low_links[node].min(self.low_links[dep]);
+                        self.low_links.insert(node, low);
+                    } else if self.on_stack.contains(dep) {
+                        let low = self.low_links[node].min(self.indices[dep]);
+                        self.low_links.insert(node, low);
+                    }
+                }
+
+                if self.low_links[node] == self.indices[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = self.stack.pop() {
+                        self.on_stack.remove(member);
+                        component.push(member.to_string());
+                        if member == node {
+                            break;
+                        }
+                    }
+                    if component.len() > 1 {
+                        component.sort();
+                        self.cycles.push(component);
+                    }
                 }
             }
         }
 
-        // Actually, we need to reverse this - we want entities with NO dependencies first
-        // Let me recalculate: in_degree should be count of dependencies (outgoing), not dependents
-        in_degree.clear();
-        for (name, deps) in &self.dependencies {
-            in_degree.insert(name.clone(), deps.len());
-        }
+        let mut tarjan = Tarjan {
+            graph: self,
+            index: 0,
+            indices: HashMap::new(),
+            low_links: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            cycles: Vec::new(),
+        };
 
-        // Start with entities that have no dependencies
-        for (name, &degree) in &in_degree {
-            if degree == 0 {
-                queue.push_back(name.clone());
+        let mut names: Vec<&str> = self.dependencies.keys().map(|s| s.as_str()).collect();
+        names.sort();
+        for name in names {
+            if !tarjan.indices.contains_key(name) {
+                tarjan.visit(name);
             }
         }
 
-        while let Some(entity) = queue.pop_front() {
-            result.push(entity.clone());
+        let mut cycles = tarjan.cycles;
+        cycles.sort();
+        cycles
+    }
+
+    /// Order entities for insert, breaking any cycles deterministically
+    ///
+    /// Uses Kahn's algorithm, always taking the alphabetically first ready
+    /// entity. When only cyclic entities remain, the one with the fewest
+    /// unresolved dependencies (then alphabetically first) is forced next.
+    /// Every entity appears in the order; the cycles found are reported
+    /// alongside so they can be flagged for manual review.
+    pub fn resolve_order(&self) -> DependencyOrder {
+        // Remaining dependency count per entity (only dependencies inside the graph)
+        let mut remaining: HashMap<&str, usize> = self
+            .entities
+            .keys()
+            .map(|name| {
+                let count = self
+                    .dependencies
+                    .get(name)
+                    .map(|deps| {
+                        deps.iter()
+                            .filter(|d| self.entities.contains_key(*d))
+                            .count()
+                    })
+                    .unwrap_or(0);
+                (name.as_str(), count)
+            })
+            .collect();
+
+        let mut ready: BTreeSet<&str> = remaining
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(name, _)| *name)
+            .collect();
+        let mut order = Vec::with_capacity(self.entities.len());
+
+        while !remaining.is_empty() {
+            let next = match ready.pop_first() {
+                Some(name) => name,
+                None => {
+                    // Only cyclic entities left - break the cycle deterministically
+                    let (name, _) = remaining
+                        .iter()
+                        .min_by(|a, b| a.1.cmp(b.1).then(a.0.cmp(b.0)))
+                        .expect("remaining is not empty");
+                    log::warn!("Breaking dependency cycle by ordering '{}' first", name);
+                    *name
+                }
+            };
 
-            // For each entity that depends on this one, decrease their in-degree
-            if let Some(dependents) = self.dependents.get(&entity) {
+            remaining.remove(next);
+            order.push(next.to_string());
+
+            // Entities depending on this one have one less dependency to wait for
+            if let Some(dependents) = self.dependents.get(next) {
                 for dependent in dependents {
-                    if let Some(count) = in_degree.get_mut(dependent) {
-                        *count -= 1;
+                    if let Some(count) = remaining.get_mut(dependent.as_str()) {
+                        *count = count.saturating_sub(1);
                         if *count == 0 {
-                            queue.push_back(dependent.clone());
+                            ready.insert(dependent.as_str());
                         }
                     }
                 }
             }
         }
 
-        // Check for cycles
-        if result.len() != self.entities.len() {
-            let remaining: Vec<_> = self
-                .entities
-                .keys()
-                .filter(|e| !result.contains(e))
-                .cloned()
-                .collect();
-            return Err(CycleError {
-                entities: remaining,
-            });
+        DependencyOrder {
+            order,
+            cycles: self.find_cycles(),
         }
+    }
 
-        Ok(result)
+    /// Perform topological sort
+    /// Returns entities in insert order (dependencies first), or the cycles that prevent one
+    pub fn topological_sort(&self) -> Result<Vec<String>, CycleError> {
+        let resolved = self.resolve_order();
+        if resolved.cycles.is_empty() {
+            Ok(resolved.order)
+        } else {
+            Err(CycleError {
+                entities: resolved.cycles.iter().flatten().cloned().collect(),
+                cycles: resolved.cycles,
+            })
+        }
     }
 
     /// Get insert order (dependencies before dependents)
@@ -227,10 +348,22 @@ impl DependencyGraph {
     }
 }
 
+/// Insert order for a graph, with any cycles that had to be broken to produce it
+#[derive(Debug, Clone, Default)]
+pub struct DependencyOrder {
+    /// All entities, dependencies first
+    pub order: Vec<String>,
+    /// Groups of mutually dependent entities (empty when the order is a true topological sort)
+    pub cycles: Vec<Vec<String>>,
+}
+
 /// Error when a cycle is detected in the dependency graph
 #[derive(Debug, Clone)]
 pub struct CycleError {
+    /// All entities involved in any cycle
     pub entities: Vec<String>,
+    /// The individual cycles, one group of mutually dependent entities each
+    pub cycles: Vec<Vec<String>>,
 }
 
 impl std::fmt::Display for CycleError {
@@ -238,7 +371,11 @@ impl std::fmt::Display for CycleError {
         write!(
             f,
             "Circular dependency detected involving: {}",
-            self.entities.join(", ")
+            self.cycles
+                .iter()
+                .map(|c| c.join(" ↔ "))
+                .collect::<Vec<_>>()
+                .join("; ")
         )
     }
 }
@@ -462,5 +599,58 @@ mod tests {
         // Should still complete topological sort
         let order = graph.insert_order().unwrap();
         assert_eq!(order.len(), 1);
+
+        // But is reported as deferrable rather than as a cycle
+        assert_eq!(graph.self_referencing_entities(), vec!["account"]);
+        assert!(graph.find_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_two_entity_cycle_is_detected_and_reported() {
+        let entities = vec![
+            (
+                "invoice".to_string(),
+                None,
+                vec![make_lookup("latestpaymentid", "payment")],
+            ),
+            (
+                "payment".to_string(),
+                None,
+                vec![make_lookup("invoiceid", "invoice")],
+            ),
+            (
+                "receipt".to_string(),
+                None,
+                vec![make_lookup("paymentid", "payment")],
+            ),
+            (
+                "currency".to_string(),
+                None,
+                vec![make_string_field("name")],
+            ),
+        ];
+
+        let graph = DependencyGraph::build(entities);
+
+        assert_eq!(
+            graph.find_cycles(),
+            vec![vec!["invoice".to_string(), "payment".to_string()]]
+        );
+
+        let err = graph.insert_order().unwrap_err();
+        assert_eq!(err.entities, vec!["invoice", "payment"]);
+        assert_eq!(
+            err.to_string(),
+            "Circular dependency detected involving: invoice ↔ payment"
+        );
+
+        // Fallback order still contains every entity, deterministically
+        let resolved = graph.resolve_order();
+        assert_eq!(resolved.cycles, err.cycles);
+        assert_eq!(
+            resolved.order,
+            vec!["currency", "invoice", "payment", "receipt"]
+        );
+        assert_eq!(graph.resolve_order().order, resolved.order);
     }
 }
@@ -4,14 +4,15 @@
 
 use std::collections::HashSet;
 
+use crate::api::operations::batch::BatchSplitter;
 use crate::api::operations::{Operation, Operations};
 use crate::tui::apps::queue::models::{QueueItem, QueueMetadata};
 
 use super::super::types::SyncPlan;
 use super::operation_builder::{
-    DEFAULT_BATCH_SIZE, build_deactivate_operations, build_delete_operations,
+    build_deactivate_operations, build_deferred_lookup_operations, build_delete_operations,
     build_insert_operations, build_junction_operations, build_post_insert_deactivate_operations,
-    build_update_operations, chunk_operations,
+    build_update_operations,
 };
 
 /// Priority levels for sync operations (lower = higher priority)
@@ -21,6 +22,7 @@ pub mod priority {
     pub const SCHEMA: u8 = 64;
     pub const UPDATE: u8 = 80; // Regular entities, records in both
     pub const INSERT: u8 = 96; // Regular entities, origin-only records
+    pub const LOOKUP_PATCH: u8 = 104; // Deferred self-referencing/cyclic lookups on new records
     pub const POST_INSERT_DEACTIVATE: u8 = 112; // Deactivate newly created inactive records
     pub const JUNCTION: u8 = 128; // N:N associations
 }
@@ -38,6 +40,8 @@ pub struct SyncQueueItems {
     pub update_items: Vec<QueueItem>,
     /// Insert operation batches (regular entities, origin-only records)
     pub insert_items: Vec<QueueItem>,
+    /// Lookup patch batches (deferred lookups set once all records exist)
+    pub lookup_patch_items: Vec<QueueItem>,
     /// Post-insert deactivate batches (deactivate newly created inactive records)
     pub post_insert_deactivate_items: Vec<QueueItem>,
     /// Junction operation batches (N:N associations)
@@ -53,6 +57,7 @@ impl SyncQueueItems {
         items.extend(self.schema_items.clone());
         items.extend(self.update_items.clone());
         items.extend(self.insert_items.clone());
+        items.extend(self.lookup_patch_items.clone());
         items.extend(self.post_insert_deactivate_items.clone());
         items.extend(self.junction_items.clone());
         items
@@ -66,6 +71,11 @@ impl SyncQueueItems {
             schema_ids: self.schema_items.iter().map(|i| i.id.clone()).collect(),
             update_ids: self.update_items.iter().map(|i| i.id.clone()).collect(),
             insert_ids: self.insert_items.iter().map(|i| i.id.clone()).collect(),
+            lookup_patch_ids: self
+                .lookup_patch_items
+                .iter()
+                .map(|i| i.id.clone())
+                .collect(),
             post_insert_deactivate_ids: self
                 .post_insert_deactivate_items
                 .iter()
@@ -101,6 +111,11 @@ impl SyncQueueItems {
                 .iter()
                 .map(|i| i.operations.len())
                 .sum::<usize>()
+            + self
+                .lookup_patch_items
+                .iter()
+                .map(|i| i.operations.len())
+                .sum::<usize>()
             + self
                 .post_insert_deactivate_items
                 .iter()
@@ -122,6 +137,7 @@ pub struct SyncQueueItemIds {
     pub schema_ids: Vec<String>,
     pub update_ids: Vec<String>,
     pub insert_ids: Vec<String>,
+    pub lookup_patch_ids: Vec<String>,
     pub post_insert_deactivate_ids: Vec<String>,
     pub junction_ids: Vec<String>,
 }
@@ -141,20 +157,23 @@ pub fn build_sync_queue_items(plan: &SyncPlan, target_env: &str) -> SyncQueueIte
     let update_ops = build_update_operations(plan);
     // Phase 5: Insert/Create (regular entities, origin-only records)
     let insert_ops = build_insert_operations(plan);
-    // Phase 6: Post-insert deactivate (deactivate newly created inactive records)
+    // Phase 6: Lookup patch (self-referencing/cyclic lookups left out of the creates)
+    let lookup_patch_ops = build_deferred_lookup_operations(plan);
+    // Phase 7: Post-insert deactivate (deactivate newly created inactive records)
     let post_insert_deactivate_ops = build_post_insert_deactivate_operations(plan);
-    // Phase 7: Junction associations (N:N relationships)
+    // Phase 8: Junction associations (N:N relationships)
     let junction_ops = build_junction_operations(plan);
 
-    // Chunk into batches
-    let delete_batches = chunk_operations(delete_ops, DEFAULT_BATCH_SIZE);
-    let deactivate_batches = chunk_operations(deactivate_ops, DEFAULT_BATCH_SIZE);
-    let schema_batches = chunk_operations(schema_ops, DEFAULT_BATCH_SIZE);
-    let update_batches = chunk_operations(update_ops, DEFAULT_BATCH_SIZE);
-    let insert_batches = chunk_operations(insert_ops, DEFAULT_BATCH_SIZE);
-    let post_insert_deactivate_batches =
-        chunk_operations(post_insert_deactivate_ops, DEFAULT_BATCH_SIZE);
-    let junction_batches = chunk_operations(junction_ops, DEFAULT_BATCH_SIZE);
+    // Pack into changesets under the configured size and payload ceilings
+    let splitter = BatchSplitter::new().max_operations(plan.changeset_size);
+    let delete_batches = splitter.split(delete_ops);
+    let deactivate_batches = splitter.split(deactivate_ops);
+    let schema_batches = splitter.split(schema_ops);
+    let update_batches = splitter.split(update_ops);
+    let insert_batches = splitter.split(insert_ops);
+    let lookup_patch_batches = splitter.split(lookup_patch_ops);
+    let post_insert_deactivate_batches = splitter.split(post_insert_deactivate_ops);
+    let junction_batches = splitter.split(junction_ops);
 
     // Build queue items
     let delete_items =
@@ -171,6 +190,12 @@ pub fn build_sync_queue_items(plan: &SyncPlan, target_env: &str) -> SyncQueueIte
         build_queue_items_for_phase(update_batches, "update", priority::UPDATE, target_env);
     let insert_items =
         build_queue_items_for_phase(insert_batches, "create", priority::INSERT, target_env);
+    let lookup_patch_items = build_queue_items_for_phase(
+        lookup_patch_batches,
+        "set-lookups",
+        priority::LOOKUP_PATCH,
+        target_env,
+    );
     let post_insert_deactivate_items = build_queue_items_for_phase(
         post_insert_deactivate_batches,
         "deactivate-new",
@@ -186,6 +211,7 @@ pub fn build_sync_queue_items(plan: &SyncPlan, target_env: &str) -> SyncQueueIte
         schema_items,
         update_items,
         insert_items,
+        lookup_patch_items,
         post_insert_deactivate_items,
         junction_items,
     }
@@ -290,7 +316,8 @@ mod tests {
         assert!(priority::DEACTIVATE < priority::SCHEMA);
         assert!(priority::SCHEMA < priority::UPDATE);
         assert!(priority::UPDATE < priority::INSERT);
-        assert!(priority::INSERT < priority::POST_INSERT_DEACTIVATE);
+        assert!(priority::INSERT < priority::LOOKUP_PATCH);
+        assert!(priority::LOOKUP_PATCH < priority::POST_INSERT_DEACTIVATE);
         assert!(priority::POST_INSERT_DEACTIVATE < priority::JUNCTION);
     }
 
@@ -325,10 +352,120 @@ mod tests {
                 },
                 priority::INSERT,
             )],
+            lookup_patch_items: vec![],
             post_insert_deactivate_items: vec![],
             junction_items: vec![],
         };
 
         assert_eq!(items.total_operations(), 3);
     }
+
+    #[test]
+    fn test_operations_split_into_configured_changesets() {
+        let mut plan = make_self_referencing_plan();
+        plan.entity_plans[0].data_preview.origin_records = (0..7)
+            .map(|i| json!({"accountid": format!("a{}", i), "name": format!("Account {}", i)}))
+            .collect();
+        plan.changeset_size = 3;
+
+        let items = build_sync_queue_items(&plan, "test");
+
+        // Order is preserved across changesets: [0,1,2], [3,4,5], [6]
+        let sizes: Vec<usize> = items
+            .insert_items
+            .iter()
+            .map(|i| i.operations.len())
+            .collect();
+        assert_eq!(sizes, vec![3, 3, 1]);
+
+        // The default keeps changesets far below the 1000-request batch limit
+        let items = build_sync_queue_items(&make_self_referencing_plan(), "test");
+        assert_eq!(SyncPlan::default().changeset_size, 50);
+        assert_eq!(items.insert_items.len(), 1);
+    }
+
+    fn make_self_referencing_plan() -> SyncPlan {
+        use crate::tui::apps::sync::types::*;
+
+        SyncPlan {
+            origin_env: "dev".to_string(),
+            target_env: "test".to_string(),
+            entity_plans: vec![EntitySyncPlan {
+                entity_info: SyncEntityInfo {
+                    logical_name: "account".to_string(),
+                    display_name: None,
+                    entity_set_name: "accounts".to_string(),
+                    primary_name_attribute: Some("name".to_string()),
+                    category: DependencyCategory::Standalone,
+                    lookups: vec![LookupInfo {
+                        field_name: "parentaccountid".to_string(),
+                        schema_name: "ParentAccountId".to_string(),
+                        target_entity: "account".to_string(),
+                        is_internal: true,
+                    }],
+                    incoming_references: vec![],
+                    dependents: vec![],
+                    insert_priority: 0,
+                    delete_priority: 0,
+                    nn_relationship: None,
+                },
+                schema_diff: EntitySchemaDiff::default(),
+                data_preview: EntityDataPreview {
+                    entity_name: "account".to_string(),
+                    origin_count: 2,
+                    target_count: 0,
+                    // Child listed before its parent
+                    origin_records: vec![
+                        json!({"accountid": "a2", "name": "Subsidiary", "_parentaccountid_value": "a1"}),
+                        json!({"accountid": "a1", "name": "Holding"}),
+                    ],
+                    target_records: vec![],
+                    junction_target_raw: vec![],
+                },
+                nulled_lookups: vec![],
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_self_reference_inserts_then_sets_lookups() {
+        let items = build_sync_queue_items(&make_self_referencing_plan(), "test");
+
+        // Both records are created without the parent lookup
+        let creates: Vec<&Operation> = items
+            .insert_items
+            .iter()
+            .flat_map(|i| i.operations.operations())
+            .collect();
+        assert_eq!(creates.len(), 2);
+        for op in &creates {
+            let Operation::Create { data, .. } = op else {
+                panic!("Expected Create operation");
+            };
+            assert!(data.get("ParentAccountId@odata.bind").is_none());
+        }
+
+        // The lookup is set afterwards, in a later priority tier
+        assert_eq!(items.lookup_patch_items.len(), 1);
+        let patch = &items.lookup_patch_items[0];
+        assert!(
+            items
+                .insert_items
+                .iter()
+                .all(|i| i.priority < patch.priority)
+        );
+        match patch.operations.operations() {
+            [
+                Operation::Update {
+                    entity, id, data, ..
+                },
+            ] => {
+                assert_eq!(entity, "accounts");
+                assert_eq!(id, "a2");
+                assert_eq!(data["ParentAccountId@odata.bind"], "/accounts(a1)");
+            }
+            other => panic!("Expected a single Update, got {:?}", other),
+        }
+    }
 }
@@ -13,6 +13,7 @@ use serde_json::Value;
 use super::super::types::{
     EntitySyncPlan, FieldDiffEntry, NulledLookupInfo, SYSTEM_FIELDS, SyncPlan,
 };
+use super::schema_diff::referenced_global_option_set;
 use crate::api::operations::Operation;
 
 /// Context for cleaning records before insertion
@@ -397,7 +398,7 @@ pub fn build_operation_summary(sync_plan: &SyncPlan) -> OperationSummary {
                 target_type,
             } = &field.status
             {
-                format!("Type mismatch: {} vs {}", origin_type, target_type)
+                super::schema_diff::type_mismatch_reason(origin_type, target_type)
             } else {
                 "Type mismatch".to_string()
             };
@@ -497,7 +498,8 @@ pub fn build_delete_operations(plan: &SyncPlan) -> Vec<Operation> {
 }
 
 /// Build deactivate operations for target-only records in regular entities.
-/// These are records that exist in target but not in origin - they get deactivated.
+/// These are records that exist in target but not in origin - they get deactivated,
+/// or deleted when `plan.delete_orphans` is set.
 /// Returns operations in delete order (dependents before dependencies).
 pub fn build_deactivate_operations(plan: &SyncPlan) -> Vec<Operation> {
     let mut operations = Vec::new();
@@ -524,14 +526,22 @@ pub fn build_deactivate_operations(plan: &SyncPlan) -> Vec<Operation> {
             })
             .collect();
 
-        // Deactivate target records not in origin
+        // Deactivate (or delete, when mirroring) target records not in origin
         for target_record in &entity_plan.data_preview.target_records {
             if !origin_guids.contains(&target_record.id) {
-                operations.push(Operation::Update {
-                    entity: entity_set.clone(),
-                    id: target_record.id.clone(),
-                    data: serde_json::json!({"statecode": 1}),
-                });
+                if plan.delete_orphans {
+                    operations.push(Operation::Delete {
+                        entity: entity_set.clone(),
+                        id: target_record.id.clone(),
+                    });
+                } else {
+                    operations.push(Operation::Update {
+                        entity: entity_set.clone(),
+                        id: target_record.id.clone(),
+                        data: serde_json::json!({"statecode": 1}),
+                        if_match: None,
+                    });
+                }
             }
         }
     }
@@ -539,12 +549,35 @@ pub fn build_deactivate_operations(plan: &SyncPlan) -> Vec<Operation> {
     operations
 }
 
+/// Largest number of changed entities published with a scoped PublishXml;
+/// above this a single PublishAllXml is cheaper than listing them all.
+pub const SCOPED_PUBLISH_MAX_ENTITIES: usize = 10;
+
 /// Build schema operations for adding new fields to target.
-/// Returns CreateAttribute operations followed by PublishAllXml.
-/// Order doesn't matter for schema operations (no dependencies between fields).
+/// Returns CreateGlobalOptionSet operations, then CreateAttribute operations,
+/// followed by a publish. Option sets come first because choice fields
+/// bind to them; order between fields doesn't matter.
+///
+/// The publish is scoped to the changed entities (PublishXml) unless global
+/// option sets were created or more than `SCOPED_PUBLISH_MAX_ENTITIES`
+/// entities changed, in which case PublishAllXml is used.
 pub fn build_schema_operations(plan: &SyncPlan, solution_name: Option<&str>) -> Vec<Operation> {
     let mut operations = Vec::new();
 
+    // Global option sets shared across entities are created once
+    let mut created_option_sets = HashSet::new();
+    for entity_plan in &plan.entity_plans {
+        for option_set in &entity_plan.schema_diff.global_option_sets_to_add {
+            if created_option_sets.insert(option_set.name.as_str()) {
+                operations.push(Operation::CreateGlobalOptionSet {
+                    option_set_data: option_set.origin_metadata.clone(),
+                    solution_name: solution_name.map(|s| s.to_string()),
+                });
+            }
+        }
+    }
+
+    let mut changed_entities: Vec<String> = Vec::new();
     for entity_plan in &plan.entity_plans {
         let entity_name = &entity_plan.entity_info.logical_name;
 
@@ -566,23 +599,53 @@ pub fn build_schema_operations(plan: &SyncPlan, solution_name: Option<&str>) ->
 
             operations.push(Operation::CreateAttribute {
                 entity: entity_name.clone(),
-                attribute_data: attr_data.clone(),
+                attribute_data: bind_global_option_set(attr_data),
                 solution_name: solution_name.map(|s| s.to_string()),
             });
+            if !changed_entities.contains(entity_name) {
+                changed_entities.push(entity_name.clone());
+            }
         }
     }
 
-    // Add PublishAllXml at the end if any schema changes were made
+    // Publish at the end if any schema changes were made
     if !operations.is_empty() {
-        operations.push(Operation::PublishAllXml);
+        if created_option_sets.is_empty() && changed_entities.len() <= SCOPED_PUBLISH_MAX_ENTITIES {
+            operations.push(Operation::PublishXml {
+                entities: changed_entities,
+            });
+        } else {
+            operations.push(Operation::PublishAllXml);
+        }
     }
 
     operations
 }
 
+/// Replace an expanded GlobalOptionSet with a binding to the target's option set by name
+fn bind_global_option_set(attr_data: &Value) -> Value {
+    let Some(name) =
+        referenced_global_option_set(attr_data).and_then(|option_set| option_set["Name"].as_str())
+    else {
+        return attr_data.clone();
+    };
+
+    let mut data = attr_data.clone();
+    if let Some(obj) = data.as_object_mut() {
+        obj.remove("GlobalOptionSet");
+        obj.insert(
+            "GlobalOptionSet@odata.bind".to_string(),
+            Value::String(format!("/GlobalOptionSetDefinitions(Name='{}')", name)),
+        );
+    }
+    data
+}
+
 /// Build insert operations for origin-only records (records not in target).
 /// Returns operations in insert order (dependencies before dependents).
 /// Skips junction entities (handled by build_junction_operations).
+/// Self-referencing lookups (`target_entity == logical_name`) and lookups within a
+/// dependency cycle are omitted; build_deferred_lookup_operations sets them afterwards.
 pub fn build_insert_operations(plan: &SyncPlan) -> Vec<Operation> {
     let mut operations = Vec::new();
 
@@ -617,11 +680,15 @@ pub fn build_insert_operations(plan: &SyncPlan) -> Vec<Operation> {
 
         // Build internal lookups map for this entity
         // Maps field_name -> (schema_name, entity_set_name)
+        // Self-references and lookups within a dependency cycle are patched afterwards
         let internal_lookups: HashMap<String, (String, String)> = entity_plan
             .entity_info
             .lookups
             .iter()
             .filter(|l| l.is_internal)
+            .filter(|l| {
+                !plan.is_deferred_lookup(&entity_plan.entity_info.logical_name, &l.target_entity)
+            })
             .filter_map(|l| {
                 entity_set_map.get(&l.target_entity).map(|entity_set| {
                     (
@@ -676,6 +743,90 @@ pub fn build_insert_operations(plan: &SyncPlan) -> Vec<Operation> {
     operations
 }
 
+/// Build update operations that set deferred lookups on newly created records.
+/// Self-references and lookups between entities in a dependency cycle are left
+/// out of the creates (the referenced record may not exist yet) and patched here.
+/// These run as their own queue phase, after all inserts have completed.
+pub fn build_deferred_lookup_operations(plan: &SyncPlan) -> Vec<Operation> {
+    let mut operations = Vec::new();
+
+    let entity_set_map: HashMap<&str, &str> = plan
+        .entity_plans
+        .iter()
+        .map(|p| {
+            (
+                p.entity_info.logical_name.as_str(),
+                p.entity_info.entity_set_name.as_str(),
+            )
+        })
+        .collect();
+
+    for entity_plan in plan.insert_order() {
+        if entity_plan.entity_info.nn_relationship.is_some() {
+            continue;
+        }
+
+        let entity_name = &entity_plan.entity_info.logical_name;
+        let deferred: Vec<_> = entity_plan
+            .entity_info
+            .lookups
+            .iter()
+            .filter(|l| l.is_internal && plan.is_deferred_lookup(entity_name, &l.target_entity))
+            .filter_map(|l| {
+                entity_set_map
+                    .get(l.target_entity.as_str())
+                    .map(|entity_set| (l, *entity_set))
+            })
+            .collect();
+        if deferred.is_empty() {
+            continue;
+        }
+
+        let pk_field = format!("{}id", entity_name);
+        let target_guids: HashSet<&str> = entity_plan
+            .data_preview
+            .target_records
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+
+        for record in &entity_plan.data_preview.origin_records {
+            let Some(guid) = record.get(&pk_field).and_then(|v| v.as_str()) else {
+                continue;
+            };
+
+            // Only records created by this sync
+            if target_guids.contains(guid) {
+                continue;
+            }
+
+            let mut data = serde_json::Map::new();
+            for (lookup, entity_set) in &deferred {
+                let value_key = format!("_{}_value", lookup.field_name);
+                if let Some(target_guid) = record.get(&value_key).and_then(|v| v.as_str())
+                    && !target_guid.is_empty()
+                {
+                    data.insert(
+                        format!("{}@odata.bind", lookup.schema_name),
+                        Value::String(format!("/{}({})", entity_set, target_guid)),
+                    );
+                }
+            }
+
+            if !data.is_empty() {
+                operations.push(Operation::Update {
+                    entity: entity_plan.entity_info.entity_set_name.clone(),
+                    id: guid.to_string(),
+                    data: Value::Object(data),
+                    if_match: None,
+                });
+            }
+        }
+    }
+
+    operations
+}
+
 /// Build deactivate operations for newly created records that were inactive in origin.
 /// These must run after inserts complete, since you can't create a record in inactive state.
 /// Returns operations in insert order (dependencies before dependents).
@@ -737,6 +888,7 @@ pub fn build_post_insert_deactivate_operations(plan: &SyncPlan) -> Vec<Operation
                 entity: entity_set.clone(),
                 id: guid.to_string(),
                 data: state_data,
+                if_match: None,
             });
         }
     }
@@ -748,6 +900,8 @@ pub fn build_post_insert_deactivate_operations(plan: &SyncPlan) -> Vec<Operation
 /// Uses origin data to update target records (also reactivates inactive records).
 /// Returns operations in insert order (dependencies before dependents).
 /// Skips junction entities (handled by build_junction_operations).
+/// With `optimistic_concurrency` set, each update carries the target record's
+/// fetched ETag as If-Match so records edited since the fetch fail with 412.
 pub fn build_update_operations(plan: &SyncPlan) -> Vec<Operation> {
     let mut operations = Vec::new();
 
@@ -772,12 +926,12 @@ pub fn build_update_operations(plan: &SyncPlan) -> Vec<Operation> {
 
         let pk_field = format!("{}id", entity_plan.entity_info.logical_name);
 
-        // Build set of target GUIDs
-        let target_guids: HashSet<String> = entity_plan
+        // Map target GUIDs to the ETag captured when they were fetched
+        let target_etags: HashMap<&str, Option<&str>> = entity_plan
             .data_preview
             .target_records
             .iter()
-            .map(|r| r.id.clone())
+            .map(|r| (r.id.as_str(), r.etag.as_deref()))
             .collect();
 
         // Build internal lookups map for this entity
@@ -821,9 +975,9 @@ pub fn build_update_operations(plan: &SyncPlan) -> Vec<Operation> {
             };
 
             // Skip if doesn't exist in target (will be created instead)
-            if !target_guids.contains(guid) {
+            let Some(etag) = target_etags.get(guid) else {
                 continue;
-            }
+            };
 
             // Clean the record (same as for insert, includes statecode for reactivation)
             let cleaned = clean_record_for_insert(record, &ctx);
@@ -831,6 +985,10 @@ pub fn build_update_operations(plan: &SyncPlan) -> Vec<Operation> {
                 entity: entity_set.clone(),
                 id: guid.to_string(),
                 data: cleaned,
+                // Only overwrite if the target is unchanged since it was fetched
+                if_match: etag
+                    .filter(|_| plan.optimistic_concurrency)
+                    .map(|e| e.to_string()),
             });
         }
     }
@@ -958,19 +1116,6 @@ pub fn build_junction_operations(plan: &SyncPlan) -> Vec<Operation> {
     operations
 }
 
-/// Default batch size for queue operations (Dynamics 365 batch limit)
-pub const DEFAULT_BATCH_SIZE: usize = 50;
-
-/// Chunk operations into batches for queue submission.
-/// Preserves operation order - operations within each batch maintain their relative order.
-pub fn chunk_operations(ops: Vec<Operation>, chunk_size: usize) -> Vec<Vec<Operation>> {
-    if ops.is_empty() {
-        return vec![];
-    }
-
-    ops.chunks(chunk_size).map(|chunk| chunk.to_vec()).collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1008,6 +1153,7 @@ mod tests {
                         }],
                         fields_target_only: vec![],
                         fields_type_mismatch: vec![],
+                        global_option_sets_to_add: vec![],
                     },
                     data_preview: EntityDataPreview {
                         entity_name: "parent".to_string(),
@@ -1020,12 +1166,14 @@ mod tests {
                                 name: Some("Parent 1".to_string()),
                                 junction_parent_id: None,
                                 junction_target_id: None,
+                                etag: None,
                             },
                             TargetRecord {
                                 id: "parent-2".to_string(),
                                 name: Some("Parent 2".to_string()),
                                 junction_parent_id: None,
                                 junction_target_id: None,
+                                etag: None,
                             },
                         ],
                         junction_target_raw: vec![],
@@ -1063,18 +1211,21 @@ mod tests {
                                 name: Some("Child 1".to_string()),
                                 junction_parent_id: None,
                                 junction_target_id: None,
+                                etag: None,
                             },
                             TargetRecord {
                                 id: "child-2".to_string(),
                                 name: Some("Child 2".to_string()),
                                 junction_parent_id: None,
                                 junction_target_id: None,
+                                etag: None,
                             },
                             TargetRecord {
                                 id: "child-3".to_string(),
                                 name: Some("Child 3".to_string()),
                                 junction_parent_id: None,
                                 junction_target_id: None,
+                                etag: None,
                             },
                         ],
                         junction_target_raw: vec![],
@@ -1086,6 +1237,10 @@ mod tests {
             has_schema_changes: true,
             total_delete_count: 5,
             total_insert_count: 30,
+            delete_orphans: false,
+            dependency_cycles: vec![],
+            optimistic_concurrency: false,
+            changeset_size: DEFAULT_CHANGESET_SIZE,
         }
     }
 
@@ -1197,6 +1352,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_delete_orphans_switches_deactivate_to_delete() {
+        let mut sync_plan = make_test_plan();
+        sync_plan.delete_orphans = true;
+
+        let ops = build_deactivate_operations(&sync_plan);
+
+        assert_eq!(ops.len(), 5);
+        for op in &ops {
+            assert!(
+                matches!(op, Operation::Delete { .. }),
+                "Expected Delete for target-only record, got {:?}",
+                op
+            );
+        }
+    }
+
     #[test]
     fn test_build_deactivate_operations_priority_order() {
         let sync_plan = make_test_plan();
@@ -1271,7 +1443,7 @@ mod tests {
 
         let schema_ops = build_schema_operations(&sync_plan, None);
 
-        // Should have 2 operations: 1 CreateAttribute + 1 PublishAllXml
+        // Should have 2 operations: 1 CreateAttribute + 1 PublishXml
         // (parent already has new_field in make_test_plan, plus our new_custom_field)
         // Note: new_field has origin_metadata: None in make_test_plan, so only new_custom_field counts
         assert_eq!(schema_ops.len(), 2);
@@ -1291,10 +1463,117 @@ mod tests {
             _ => panic!("Expected CreateAttribute operation"),
         }
 
-        // Last should be PublishAllXml
+        // Last should be a publish scoped to the changed entity
+        assert!(matches!(
+            schema_ops.last(),
+            Some(Operation::PublishXml { entities }) if entities == &vec!["parent".to_string()]
+        ));
+    }
+
+    fn custom_field(name: &str) -> FieldDiffEntry {
+        FieldDiffEntry {
+            logical_name: name.to_string(),
+            display_name: None,
+            field_type: "String".to_string(),
+            status: FieldSyncStatus::OriginOnly,
+            is_system_field: false,
+            origin_metadata: Some(serde_json::json!({ "LogicalName": name })),
+        }
+    }
+
+    #[test]
+    fn test_build_schema_operations_scopes_publish_to_changed_entities() {
+        let mut sync_plan = make_test_plan();
+        for entity_plan in &mut sync_plan.entity_plans {
+            entity_plan.schema_diff.fields_to_add =
+                vec![custom_field("nrq_a"), custom_field("nrq_b")];
+        }
+
+        let schema_ops = build_schema_operations(&sync_plan, None);
+
+        // 4 CreateAttribute + 1 PublishXml listing each changed entity once
+        assert_eq!(schema_ops.len(), 5);
+        match schema_ops.last() {
+            Some(Operation::PublishXml { entities }) => {
+                assert_eq!(entities, &vec!["parent".to_string(), "child".to_string()]);
+            }
+            other => panic!("Expected PublishXml, got {:?}", other),
+        }
+        assert!(
+            !schema_ops
+                .iter()
+                .any(|op| matches!(op, Operation::PublishAllXml))
+        );
+    }
+
+    #[test]
+    fn test_build_schema_operations_publishes_all_above_threshold() {
+        let mut sync_plan = make_test_plan();
+        let template = sync_plan.entity_plans[0].clone();
+        sync_plan.entity_plans = (0..=SCOPED_PUBLISH_MAX_ENTITIES)
+            .map(|i| {
+                let mut entity_plan = template.clone();
+                entity_plan.entity_info.logical_name = format!("entity{}", i);
+                entity_plan.schema_diff.fields_to_add = vec![custom_field("nrq_a")];
+                entity_plan
+            })
+            .collect();
+
+        let schema_ops = build_schema_operations(&sync_plan, None);
+
         assert!(matches!(schema_ops.last(), Some(Operation::PublishAllXml)));
     }
 
+    #[test]
+    fn test_build_schema_operations_creates_global_option_set_first() {
+        let mut sync_plan = make_test_plan();
+        let option_set = serde_json::json!({
+            "Name": "nrq_status_set",
+            "IsGlobal": true,
+            "OptionSetType": "Picklist"
+        });
+
+        let diff = &mut sync_plan.entity_plans[0].schema_diff;
+        diff.fields_to_add.push(FieldDiffEntry {
+            logical_name: "nrq_status".to_string(),
+            display_name: Some("Status".to_string()),
+            field_type: "OptionSet".to_string(),
+            status: FieldSyncStatus::OriginOnly,
+            is_system_field: false,
+            origin_metadata: Some(serde_json::json!({
+                "@odata.type": "Microsoft.Dynamics.CRM.PicklistAttributeMetadata",
+                "LogicalName": "nrq_status",
+                "SchemaName": "nrq_Status",
+                "GlobalOptionSet": option_set
+            })),
+        });
+        diff.global_option_sets_to_add =
+            crate::tui::apps::sync::logic::detect_missing_global_option_sets(diff, &HashSet::new());
+
+        let schema_ops = build_schema_operations(&sync_plan, None);
+
+        // CreateGlobalOptionSet, CreateAttribute, PublishAllXml
+        // (option sets aren't covered by an entity-scoped publish)
+        assert_eq!(schema_ops.len(), 3);
+        match &schema_ops[0] {
+            Operation::CreateGlobalOptionSet {
+                option_set_data, ..
+            } => assert_eq!(option_set_data["Name"], "nrq_status_set"),
+            other => panic!("Expected CreateGlobalOptionSet, got {:?}", other),
+        }
+        match &schema_ops[1] {
+            Operation::CreateAttribute { attribute_data, .. } => {
+                assert!(attribute_data.get("GlobalOptionSet").is_none());
+                assert_eq!(
+                    attribute_data["GlobalOptionSet@odata.bind"],
+                    "/GlobalOptionSetDefinitions(Name='nrq_status_set')"
+                );
+            }
+            other => panic!("Expected CreateAttribute, got {:?}", other),
+        }
+        assert!(matches!(schema_ops[2], Operation::PublishAllXml));
+    }
+
     #[test]
     fn test_build_schema_operations_empty_when_no_changes() {
         let mut sync_plan = make_test_plan();
@@ -1382,6 +1661,10 @@ mod tests {
             has_schema_changes: false,
             total_delete_count: 0,
             total_insert_count: 5,
+            delete_orphans: false,
+            dependency_cycles: vec![],
+            optimistic_concurrency: false,
+            changeset_size: DEFAULT_CHANGESET_SIZE,
         }
     }
 
@@ -1413,6 +1696,44 @@ mod tests {
         assert_eq!(entity_order[4], "children");
     }
 
+    #[test]
+    fn test_self_reference_is_deferred_to_patch() {
+        let mut sync_plan = make_test_plan_with_records();
+        let parent = &mut sync_plan.entity_plans[0];
+        parent.entity_info.lookups.push(LookupInfo {
+            field_name: "masterparentid".to_string(),
+            schema_name: "MasterParentId".to_string(),
+            target_entity: "parent".to_string(),
+            is_internal: true,
+        });
+        parent.data_preview.origin_records[1] = serde_json::json!({
+            "parentid": "p2",
+            "name": "Parent 2",
+            "_masterparentid_value": "p1"
+        });
+
+        // Create omits the self-reference
+        let insert_ops = build_insert_operations(&sync_plan);
+        let Operation::Create { data, .. } = &insert_ops[1] else {
+            panic!("Expected Create operation");
+        };
+        assert!(data.get("MasterParentId@odata.bind").is_none());
+
+        // And a follow-up update sets it
+        let deferred = build_deferred_lookup_operations(&sync_plan);
+        assert_eq!(deferred.len(), 1);
+        match &deferred[0] {
+            Operation::Update {
+                entity, id, data, ..
+            } => {
+                assert_eq!(entity, "parents");
+                assert_eq!(id, "p2");
+                assert_eq!(data["MasterParentId@odata.bind"], "/parents(p1)");
+            }
+            _ => panic!("Expected Update operation"),
+        }
+    }
+
     #[test]
     fn test_build_insert_operations_skips_junction() {
         let mut sync_plan = make_test_plan_with_records();
@@ -1588,6 +1909,10 @@ mod tests {
             has_schema_changes: false,
             total_delete_count: 0,
             total_insert_count: 7,
+            delete_orphans: false,
+            dependency_cycles: vec![],
+            optimistic_concurrency: false,
+            changeset_size: DEFAULT_CHANGESET_SIZE,
         }
     }
 
@@ -1949,79 +2274,6 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_chunk_operations_preserves_order() {
-        // Create operations with identifiable IDs
-        let ops: Vec<Operation> = (0..7)
-            .map(|i| Operation::Delete {
-                entity: "accounts".to_string(),
-                id: format!("id-{}", i),
-            })
-            .collect();
-
-        let chunks = chunk_operations(ops, 3);
-
-        // Should have 3 chunks: [0,1,2], [3,4,5], [6]
-        assert_eq!(chunks.len(), 3);
-        assert_eq!(chunks[0].len(), 3);
-        assert_eq!(chunks[1].len(), 3);
-        assert_eq!(chunks[2].len(), 1);
-
-        // Verify order is preserved
-        let mut expected_id = 0;
-        for chunk in &chunks {
-            for op in chunk {
-                match op {
-                    Operation::Delete { id, .. } => {
-                        assert_eq!(id, &format!("id-{}", expected_id));
-                        expected_id += 1;
-                    }
-                    _ => panic!("Expected Delete operation"),
-                }
-            }
-        }
-        assert_eq!(expected_id, 7);
-    }
-
-    #[test]
-    fn test_chunk_operations_empty() {
-        let chunks = chunk_operations(vec![], 50);
-        assert!(chunks.is_empty());
-    }
-
-    #[test]
-    fn test_chunk_operations_smaller_than_chunk_size() {
-        let ops: Vec<Operation> = (0..3)
-            .map(|i| Operation::Delete {
-                entity: "accounts".to_string(),
-                id: format!("id-{}", i),
-            })
-            .collect();
-
-        let chunks = chunk_operations(ops, 50);
-
-        // Should have 1 chunk with all 3 operations
-        assert_eq!(chunks.len(), 1);
-        assert_eq!(chunks[0].len(), 3);
-    }
-
-    #[test]
-    fn test_chunk_operations_exact_multiple() {
-        let ops: Vec<Operation> = (0..6)
-            .map(|i| Operation::Delete {
-                entity: "accounts".to_string(),
-                id: format!("id-{}", i),
-            })
-            .collect();
-
-        let chunks = chunk_operations(ops, 3);
-
-        // Should have exactly 2 chunks of 3 each
-        assert_eq!(chunks.len(), 2);
-        assert_eq!(chunks[0].len(), 3);
-        assert_eq!(chunks[1].len(), 3);
-    }
-
     // =========================================================================
     // Tests for Create/Update/Deactivate GUID-based comparison logic
     // =========================================================================
@@ -2064,18 +2316,21 @@ mod tests {
                             name: Some("Parent 1".to_string()),
                             junction_parent_id: None,
                             junction_target_id: None,
+                            etag: None,
                         },
                         TargetRecord {
                             id: "p2".to_string(),
                             name: Some("Parent 2".to_string()),
                             junction_parent_id: None,
                             junction_target_id: None,
+                            etag: None,
                         },
                         TargetRecord {
                             id: "p4".to_string(),
                             name: Some("Parent 4 ToDeactivate".to_string()),
                             junction_parent_id: None,
                             junction_target_id: None,
+                            etag: None,
                         },
                     ],
                     junction_target_raw: vec![],
@@ -2086,9 +2341,41 @@ mod tests {
             has_schema_changes: false,
             total_delete_count: 1,
             total_insert_count: 1,
+            delete_orphans: false,
+            dependency_cycles: vec![],
+            optimistic_concurrency: false,
+            changeset_size: DEFAULT_CHANGESET_SIZE,
         }
     }
 
+    #[test]
+    fn test_update_operations_carry_if_match_when_enabled() {
+        let mut sync_plan = make_test_plan_with_overlap();
+        sync_plan.entity_plans[0].data_preview.target_records[0].etag =
+            Some("W/\"1001\"".to_string());
+
+        // Off by default: updates overwrite unconditionally
+        let update_ops = build_update_operations(&sync_plan);
+        assert!(
+            update_ops
+                .iter()
+                .all(|op| matches!(op, Operation::Update { if_match: None, .. }))
+        );
+
+        sync_plan.optimistic_concurrency = true;
+        let update_ops = build_update_operations(&sync_plan);
+        let if_match: Vec<(&str, Option<&str>)> = update_ops
+            .iter()
+            .map(|op| match op {
+                Operation::Update { id, if_match, .. } => (id.as_str(), if_match.as_deref()),
+                _ => panic!("Expected Update operation"),
+            })
+            .collect();
+
+        // p1 has an ETag, p2 was fetched without one
+        assert_eq!(if_match, vec![("p1", Some("W/\"1001\"")), ("p2", None)]);
+    }
+
     #[test]
     fn test_build_insert_operations_origin_only() {
         let sync_plan = make_test_plan_with_overlap();
@@ -2152,7 +2439,9 @@ mod tests {
         assert_eq!(deactivate_ops.len(), 1);
 
         match &deactivate_ops[0] {
-            Operation::Update { entity, id, data } => {
+            Operation::Update {
+                entity, id, data, ..
+            } => {
                 assert_eq!(entity, "parents");
                 assert_eq!(id, "p4");
                 assert_eq!(data["statecode"], 1);
@@ -58,10 +58,7 @@ pub fn build_pre_execution_report(plan: &SyncPlan) -> SyncReport {
                 target_type,
             } = &field.status
             {
-                format!(
-                    "Type mismatch: {} (origin) vs {} (target)",
-                    origin_type, target_type
-                )
+                super::schema_diff::type_mismatch_reason(origin_type, target_type)
             } else {
                 "Type mismatch".to_string()
             };
@@ -377,6 +374,7 @@ mod tests {
                         is_system_field: false,
                         origin_metadata: None,
                     }],
+                    global_option_sets_to_add: vec![],
                 },
                 data_preview: EntityDataPreview {
                     entity_name: "account".to_string(),
@@ -397,6 +395,10 @@ mod tests {
             has_schema_changes: true,
             total_delete_count: 50,
             total_insert_count: 100,
+            delete_orphans: false,
+            dependency_cycles: vec![],
+            optimistic_concurrency: false,
+            changeset_size: DEFAULT_CHANGESET_SIZE,
         }
     }
 
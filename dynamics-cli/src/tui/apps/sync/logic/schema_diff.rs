@@ -4,14 +4,15 @@
 //! - Compare field schemas between origin and target entities
 //! - Categorize fields as matching, origin-only, target-only, or type-mismatch
 //! - Filter out system fields that should be skipped
+//! - Detect global option sets that new fields depend on
 //! - Generate a complete schema diff report
 
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::api::metadata::{EntityMetadata, FieldMetadata, FieldType};
 use crate::tui::apps::sync::types::{
-    EntitySchemaDiff, FieldDiffEntry, FieldSyncStatus, is_system_field,
+    EntitySchemaDiff, FieldDiffEntry, FieldSyncStatus, GlobalOptionSetEntry, is_system_field,
 };
 
 /// Compare two entity schemas and produce a diff
@@ -142,6 +143,119 @@ pub fn format_field_type(field_type: &FieldType) -> String {
     }
 }
 
+/// Whether values of a mismatched field can be converted automatically
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoercionVerdict {
+    /// Every origin value converts without loss (e.g. Integer -> Decimal)
+    Safe,
+    /// Conversion works but may lose precision, length or meaning
+    Lossy,
+    /// No automatic conversion; values need manual mapping
+    Unsafe,
+}
+
+impl CoercionVerdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Safe => "safe",
+            Self::Lossy => "lossy",
+            Self::Unsafe => "unsafe",
+        }
+    }
+}
+
+/// Coercion verdict plus a short suggestion for the user
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoercionAdvice {
+    pub verdict: CoercionVerdict,
+    pub suggestion: &'static str,
+}
+
+/// Classify the conversion from an origin field type to a mismatched target type
+///
+/// Types are the display names produced by [`format_field_type`], which is
+/// what `FieldSyncStatus::TypeMismatch` stores.
+pub fn advise_coercion(origin_type: &str, target_type: &str) -> CoercionAdvice {
+    use CoercionVerdict::*;
+
+    let (verdict, suggestion) = match (origin_type, target_type) {
+        ("Integer", "Decimal" | "Money") | ("Decimal", "Money") | ("Money", "Decimal") => {
+            (Safe, "numeric widening, values copy as-is")
+        }
+        ("String", "Memo") => (Safe, "text widening, values copy as-is"),
+        ("Boolean", "Integer") => (Safe, "booleans map to 0/1"),
+        ("OptionSet", "Integer") => (Safe, "option values copy as integers"),
+        (
+            "Integer" | "Decimal" | "Money" | "Boolean" | "DateTime" | "UniqueIdentifier",
+            "String" | "Memo",
+        ) => (Safe, "values are written as text"),
+        ("Memo", "String") => (Lossy, "long text may exceed the target max length"),
+        ("Decimal" | "Money", "Integer") => (Lossy, "fractional parts are truncated"),
+        ("OptionSet", "String" | "Memo") => (Lossy, "option values are written, labels are lost"),
+        ("Integer", "OptionSet") => (Lossy, "values must exist in the target option set"),
+        ("String" | "Memo", _) => (Unsafe, "text must be parsed; map values manually"),
+        ("Lookup", _) | (_, "Lookup") => (Unsafe, "references cannot be converted"),
+        _ => (Unsafe, "no automatic conversion; fix the target schema"),
+    };
+
+    CoercionAdvice {
+        verdict,
+        suggestion,
+    }
+}
+
+/// Review reason for a type-mismatched field, including the coercion verdict
+pub fn type_mismatch_reason(origin_type: &str, target_type: &str) -> String {
+    let advice = advise_coercion(origin_type, target_type);
+    format!(
+        "Type mismatch: {} vs {} ({}: {})",
+        origin_type,
+        target_type,
+        advice.verdict.label(),
+        advice.suggestion
+    )
+}
+
+/// Get the global option set a field's raw metadata references, if any
+///
+/// Expects the expanded `GlobalOptionSet` navigation property that
+/// `fetch_attribute_global_option_sets` merges into raw attribute metadata.
+pub fn referenced_global_option_set(attribute: &Value) -> Option<&Value> {
+    let option_set = attribute.get("GlobalOptionSet")?;
+    if option_set["IsGlobal"].as_bool() == Some(true) && option_set["Name"].is_string() {
+        Some(option_set)
+    } else {
+        None
+    }
+}
+
+/// Find global option sets referenced by fields to add that don't exist in target
+///
+/// Each option set is reported once, in field order.
+pub fn detect_missing_global_option_sets(
+    diff: &EntitySchemaDiff,
+    target_option_sets: &HashSet<String>,
+) -> Vec<GlobalOptionSetEntry> {
+    let mut seen = HashSet::new();
+
+    diff.fields_to_add
+        .iter()
+        .filter(|f| !f.is_system_field)
+        .filter_map(|f| f.origin_metadata.as_ref())
+        .filter_map(referenced_global_option_set)
+        .filter_map(|option_set| {
+            let name = option_set["Name"].as_str()?;
+            if target_option_sets.contains(name) || !seen.insert(name.to_string()) {
+                return None;
+            }
+            Some(GlobalOptionSetEntry {
+                name: name.to_string(),
+                origin_metadata: option_set.clone(),
+            })
+        })
+        .collect()
+}
+
 /// Filter fields to only include non-system fields that can be synced
 pub fn filter_syncable_fields(fields: &[FieldMetadata]) -> Vec<&FieldMetadata> {
     fields
@@ -341,4 +455,53 @@ mod tests {
         assert!(targets.contains(&"account".to_string()));
         assert!(targets.contains(&"contact".to_string()));
     }
+
+    #[test]
+    fn test_advise_coercion_widening_is_safe() {
+        let advice = advise_coercion("Integer", "Decimal");
+        assert_eq!(advice.verdict, CoercionVerdict::Safe);
+
+        let reason = type_mismatch_reason("Integer", "Decimal");
+        assert!(reason.starts_with("Type mismatch: Integer vs Decimal (safe:"));
+    }
+
+    #[test]
+    fn test_advise_coercion_string_to_int_is_unsafe() {
+        let advice = advise_coercion("String", "Integer");
+        assert_eq!(advice.verdict, CoercionVerdict::Unsafe);
+        assert_eq!(
+            advise_coercion("Decimal", "Integer").verdict,
+            CoercionVerdict::Lossy
+        );
+    }
+
+    #[test]
+    fn test_detect_missing_global_option_sets() {
+        let picklist = |name: &str, option_set: &str| FieldDiffEntry {
+            logical_name: name.to_string(),
+            display_name: None,
+            field_type: "OptionSet".to_string(),
+            status: FieldSyncStatus::OriginOnly,
+            is_system_field: false,
+            origin_metadata: Some(serde_json::json!({
+                "LogicalName": name,
+                "GlobalOptionSet": { "Name": option_set, "IsGlobal": true }
+            })),
+        };
+        let diff = EntitySchemaDiff {
+            entity_name: "nrq_fund".to_string(),
+            fields_to_add: vec![
+                picklist("nrq_status", "nrq_status_set"),
+                picklist("nrq_other_status", "nrq_status_set"),
+                picklist("nrq_region", "nrq_region_set"),
+            ],
+            ..Default::default()
+        };
+        let target: HashSet<String> = ["nrq_region_set".to_string()].into_iter().collect();
+
+        let missing = detect_missing_global_option_sets(&diff, &target);
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].name, "nrq_status_set");
+    }
 }
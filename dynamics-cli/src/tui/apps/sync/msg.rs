@@ -110,6 +110,8 @@ pub enum Msg {
     // === Step 5: Confirm ===
     /// Toggle confirmation checkbox
     ToggleConfirm,
+    /// Toggle deleting (instead of deactivating) target-only records
+    ToggleDeleteOrphans,
     /// Start execution (send to queue)
     Execute,
     /// Export report to Excel
@@ -180,6 +182,7 @@ impl std::fmt::Debug for Msg {
             Self::DiffToggleSection(s) => write!(f, "DiffToggleSection({})", s),
             Self::DiffSetViewportHeight(h) => write!(f, "DiffSetViewportHeight({})", h),
             Self::ToggleConfirm => write!(f, "ToggleConfirm"),
+            Self::ToggleDeleteOrphans => write!(f, "ToggleDeleteOrphans"),
             Self::Execute => write!(f, "Execute"),
             Self::ExportReport => write!(f, "ExportReport"),
             Self::ReportExported(r) => write!(f, "ReportExported({:?})", r.is_ok()),
@@ -387,8 +387,9 @@ pub enum ExecutionPhase {
     Deactivating, // Regular entities, target-only records
     AddingFields,
     Publishing,
-    Updating,  // Regular entities, records in both
-    Inserting, // Regular entities, origin-only records
+    Updating,        // Regular entities, records in both
+    Inserting,       // Regular entities, origin-only records
+    PatchingLookups, // Deferred lookups, once all records exist
     InsertingJunctions,
     Complete,
     Failed,
@@ -404,6 +405,7 @@ impl ExecutionPhase {
             Self::Publishing => "Publishing customizations...",
             Self::Updating => "Updating records...",
             Self::Inserting => "Creating records...",
+            Self::PatchingLookups => "Setting deferred lookups...",
             Self::InsertingJunctions => "Creating associations...",
             Self::Complete => "Complete",
             Self::Failed => "Failed",
@@ -455,6 +457,7 @@ pub struct ConfirmState {
     pub schema_batch_ids: Vec<String>,
     pub update_batch_ids: Vec<String>,
     pub insert_batch_ids: Vec<String>,
+    pub lookup_patch_batch_ids: Vec<String>,
     pub junction_batch_ids: Vec<String>,
 
     /// Failed operation details (if any)
@@ -118,9 +118,10 @@ fn render_summary(state: &mut State, theme: &Theme) -> Element<Msg> {
     };
 
     let summary = build_operation_summary(plan);
+    let delete_orphans = plan.delete_orphans;
 
     // Build summary items
-    let summary_panel = render_operation_summary(&summary, theme);
+    let summary_panel = render_operation_summary(&summary, delete_orphans, theme);
     let warnings_panel = render_warnings(&summary, theme);
     let confirmation_panel = render_confirmation(state, theme);
 
@@ -134,7 +135,11 @@ fn render_summary(state: &mut State, theme: &Theme) -> Element<Msg> {
 }
 
 /// Render operation summary panel
-fn render_operation_summary(summary: &OperationSummary, theme: &Theme) -> Element<Msg> {
+fn render_operation_summary(
+    summary: &OperationSummary,
+    delete_orphans: bool,
+    theme: &Theme,
+) -> Element<Msg> {
     use_constraints!();
 
     let mut lines: Vec<Element<Msg>> = vec![];
@@ -163,10 +168,15 @@ fn render_operation_summary(summary: &OperationSummary, theme: &Theme) -> Elemen
             .iter()
             .map(|(_, c)| c)
             .sum();
+        let (label, color) = if delete_orphans {
+            ("Target-only records to DELETE", theme.accent_error)
+        } else {
+            ("Records to DEACTIVATE", theme.accent_warning)
+        };
         lines.push(
             Element::styled_text(Line::from(Span::styled(
-                format!("Records to DEACTIVATE: {}", total_deactivates),
-                Style::default().fg(theme.accent_warning).bold(),
+                format!("{}: {}", label, total_deactivates),
+                Style::default().fg(color).bold(),
             )))
             .build(),
         );
@@ -317,11 +327,32 @@ fn render_confirmation(state: &State, theme: &Theme) -> Element<Msg> {
         "[ ]"
     };
 
+    let delete_orphans = state
+        .sync_plan
+        .as_ref()
+        .is_some_and(|plan| plan.delete_orphans);
+
     let confirm_text = format!(
-        "{} I understand this will sync records (deactivate/update/create)",
-        checkbox
+        "{} I understand this will sync records ({}/update/create)",
+        checkbox,
+        if delete_orphans {
+            "delete"
+        } else {
+            "deactivate"
+        }
     );
 
+    let orphans_text = if delete_orphans {
+        "[X] Delete target-only records instead of deactivating ('o')"
+    } else {
+        "[ ] Delete target-only records instead of deactivating ('o')"
+    };
+    let orphans_style = if delete_orphans {
+        Style::default().fg(theme.accent_error)
+    } else {
+        Style::default().fg(theme.text_secondary)
+    };
+
     let confirm_style = if state.confirm.confirmed {
         Style::default().fg(theme.accent_success)
     } else {
@@ -340,7 +371,7 @@ fn render_confirmation(state: &State, theme: &Theme) -> Element<Msg> {
 
     let content = Element::column(vec![
         Element::styled_text(Line::from(Span::styled(confirm_text, confirm_style))).build(),
-        spacer!(),
+        Element::styled_text(Line::from(Span::styled(orphans_text, orphans_style))).build(),
         export_hint,
     ])
     .build();
@@ -381,6 +412,12 @@ fn render_execution_progress(state: &State, theme: &Theme) -> Element<Msg> {
         render_phase_indicator("Adding fields", phase, ExecutionPhase::AddingFields, theme),
         render_phase_indicator("Updating records", phase, ExecutionPhase::Updating, theme),
         render_phase_indicator("Creating records", phase, ExecutionPhase::Inserting, theme),
+        render_phase_indicator(
+            "Setting deferred lookups",
+            phase,
+            ExecutionPhase::PatchingLookups,
+            theme,
+        ),
         render_phase_indicator(
             "Creating associations",
             phase,
@@ -418,9 +455,10 @@ fn render_phase_indicator(
             ExecutionPhase::Publishing => 4,
             ExecutionPhase::Updating => 5,
             ExecutionPhase::Inserting => 6,
-            ExecutionPhase::InsertingJunctions => 7,
-            ExecutionPhase::Complete => 8,
-            ExecutionPhase::Failed => 8,
+            ExecutionPhase::PatchingLookups => 7,
+            ExecutionPhase::InsertingJunctions => 8,
+            ExecutionPhase::Complete => 9,
+            ExecutionPhase::Failed => 9,
         }
     };
 
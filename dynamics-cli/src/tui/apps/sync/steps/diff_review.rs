@@ -24,6 +24,8 @@ struct EntityPlanItem {
     display_name: Option<String>,
     category: DependencyCategory,
     has_changes: bool,
+    /// Part of a dependency cycle that needs manual review
+    in_cycle: bool,
 }
 
 impl ListItem for EntityPlanItem {
@@ -40,16 +42,20 @@ impl ListItem for EntityPlanItem {
         let category_symbol = self.category.symbol();
         let name = self.display_name.as_ref().unwrap_or(&self.logical_name);
         let change_indicator = if self.has_changes { " ●" } else { " ✓" };
+        let cycle_indicator = if self.in_cycle { " ⟳" } else { "" };
 
         let text = format!(
-            "{}. {} {}{}",
+            "{}. {} {}{}{}",
             self.index + 1,
             category_symbol,
             name,
-            change_indicator
+            change_indicator,
+            cycle_indicator
         );
 
-        let style = if self.has_changes {
+        let style = if self.in_cycle {
+            Style::default().fg(theme.accent_error)
+        } else if self.has_changes {
             Style::default().fg(theme.accent_warning)
         } else {
             Style::default().fg(theme.text_primary)
@@ -102,7 +108,13 @@ impl ListItem for FieldDiffItem {
             target_type,
         } = &self.status
         {
-            format!(" ({} → {})", origin_type, target_type)
+            let advice = crate::tui::apps::sync::logic::advise_coercion(origin_type, target_type);
+            format!(
+                " ({} → {}, {})",
+                origin_type,
+                target_type,
+                advice.verdict.label()
+            )
         } else {
             String::new()
         };
@@ -186,7 +198,7 @@ fn render_diff_content(state: &mut State, theme: &Theme) -> Element<Msg> {
     use_constraints!();
 
     // Clone data we need from state.sync_plan to avoid borrow issues
-    let (entity_items, entity_count, selected_plan) = {
+    let (entity_items, entity_count, selected_plan, selected_cycle) = {
         let Some(ref plan) = state.sync_plan else {
             return Element::panel(Element::text("No sync plan available"))
                 .title("Error")
@@ -203,6 +215,7 @@ fn render_diff_content(state: &mut State, theme: &Theme) -> Element<Msg> {
                 display_name: p.entity_info.display_name.clone(),
                 category: p.entity_info.category,
                 has_changes: p.schema_diff.has_changes(),
+                in_cycle: plan.cycle_for(&p.entity_info.logical_name).is_some(),
             })
             .collect();
 
@@ -211,8 +224,12 @@ fn render_diff_content(state: &mut State, theme: &Theme) -> Element<Msg> {
         // Get selected plan if any
         let selected_idx = state.diff_review.entity_list.selected();
         let selected = selected_idx.and_then(|idx| plan.entity_plans.get(idx).cloned());
+        let cycle = selected
+            .as_ref()
+            .and_then(|p| plan.cycle_for(&p.entity_info.logical_name))
+            .map(|c| c.to_vec());
 
-        (items, count, selected)
+        (items, count, selected, cycle)
     };
 
     let entity_list = Element::list(
@@ -235,7 +252,7 @@ fn render_diff_content(state: &mut State, theme: &Theme) -> Element<Msg> {
         match state.diff_review.active_tab {
             DiffTab::Schema => render_schema_tab(state, &selected, theme),
             DiffTab::Data => render_data_tab(state, &selected, theme),
-            DiffTab::Lookups => render_lookups_tab(&selected, theme),
+            DiffTab::Lookups => render_lookups_tab(&selected, selected_cycle.as_deref(), theme),
         }
     } else {
         Element::panel(Element::text("Select an entity to view details"))
@@ -532,7 +549,11 @@ fn render_data_tab(state: &mut State, plan: &EntitySyncPlan, theme: &Theme) -> E
 }
 
 /// Render the lookups tab content
-fn render_lookups_tab(plan: &EntitySyncPlan, theme: &Theme) -> Element<Msg> {
+fn render_lookups_tab(
+    plan: &EntitySyncPlan,
+    cycle: Option<&[String]>,
+    theme: &Theme,
+) -> Element<Msg> {
     use_constraints!();
 
     let name = plan
@@ -543,6 +564,25 @@ fn render_lookups_tab(plan: &EntitySyncPlan, theme: &Theme) -> Element<Msg> {
 
     let mut lines: Vec<Element<Msg>> = vec![];
 
+    // === DEPENDENCY CYCLE ===
+    if let Some(cycle) = cycle {
+        lines.push(
+            Element::styled_text(Line::from(Span::styled(
+                format!("⟳ Circular dependency: {}", cycle.join(" ↔ ")),
+                Style::default().fg(theme.accent_error).bold(),
+            )))
+            .build(),
+        );
+        lines.push(
+            Element::styled_text(Line::from(Span::styled(
+                "  Ordered by fallback; lookups within the cycle are set after create. Review manually.".to_string(),
+                Style::default().fg(theme.text_secondary),
+            )))
+            .build(),
+        );
+        lines.push(Element::text(""));
+    }
+
     // === OUTGOING LOOKUPS ===
     lines.push(
         Element::styled_text(Line::from(Span::styled(
@@ -562,7 +602,14 @@ fn render_lookups_tab(plan: &EntitySyncPlan, theme: &Theme) -> Element<Msg> {
 
     if !internal.is_empty() {
         for lookup in &internal {
-            let text = format!("  ✓ {} → {}", lookup.field_name, lookup.target_entity);
+            let deferred = lookup.target_entity == plan.entity_info.logical_name
+                || cycle.is_some_and(|c| c.contains(&lookup.target_entity));
+            let text = format!(
+                "  ✓ {} → {}{}",
+                lookup.field_name,
+                lookup.target_entity,
+                if deferred { " (set after create)" } else { "" }
+            );
             lines.push(
                 Element::styled_text(Line::from(Span::styled(
                     text,
@@ -706,7 +753,21 @@ fn render_step_footer(state: &State, theme: &Theme) -> Element<Msg> {
             plan.entity_plans.len(),
             schema_changes
         );
-        Element::text(text)
+        if plan.dependency_cycles.is_empty() {
+            Element::text(text)
+        } else {
+            Element::styled_text(Line::from(vec![
+                Span::raw(text),
+                Span::styled(
+                    format!(
+                        " | ⟳ {} dependency cycle(s) need review",
+                        plan.dependency_cycles.len()
+                    ),
+                    Style::default().fg(theme.accent_error),
+                ),
+            ]))
+            .build()
+        }
     } else {
         Element::text("")
     };
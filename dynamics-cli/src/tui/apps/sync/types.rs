@@ -175,12 +175,25 @@ pub struct EntitySchemaDiff {
     pub fields_target_only: Vec<FieldDiffEntry>,
     /// Fields with type mismatches
     pub fields_type_mismatch: Vec<FieldDiffEntry>,
+    /// Global option sets referenced by fields to add but missing in target
+    #[serde(default)]
+    pub global_option_sets_to_add: Vec<GlobalOptionSetEntry>,
+}
+
+/// A global option set that must exist in target before dependent fields
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalOptionSetEntry {
+    /// Option set name (e.g., "nrq_status")
+    pub name: String,
+    /// Full option set metadata from origin (for CreateGlobalOptionSet operation)
+    pub origin_metadata: Value,
 }
 
 impl EntitySchemaDiff {
     /// Check if there are any changes needed
     pub fn has_changes(&self) -> bool {
         !self.fields_to_add.is_empty()
+            || !self.global_option_sets_to_add.is_empty()
             || !self.fields_target_only.is_empty()
             || !self.fields_type_mismatch.is_empty()
     }
@@ -209,6 +222,9 @@ pub struct TargetRecord {
     /// For junction entities: FK value to target entity (Entity2)
     /// Used for DisassociateRef operations
     pub junction_target_id: Option<String>,
+    /// `@odata.etag` of the record when it was fetched
+    #[serde(default)]
+    pub etag: Option<String>,
 }
 
 /// Data preview for an entity
@@ -256,7 +272,7 @@ pub struct EntitySyncPlan {
 }
 
 /// Overall sync plan for all selected entities
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyncPlan {
     /// Origin environment name
     pub origin_env: String,
@@ -272,6 +288,46 @@ pub struct SyncPlan {
     pub total_delete_count: usize,
     /// Total records to insert
     pub total_insert_count: usize,
+    /// Delete target-only records of regular entities instead of deactivating them
+    #[serde(default)]
+    pub delete_orphans: bool,
+    /// Groups of entities with circular lookups between them
+    ///
+    /// These were ordered by a deterministic fallback and are flagged for
+    /// manual review; lookups inside a group are patched after insert.
+    #[serde(default)]
+    pub dependency_cycles: Vec<Vec<String>>,
+    /// Send updates with If-Match on the fetched ETag instead of overwriting unconditionally
+    #[serde(default)]
+    pub optimistic_concurrency: bool,
+    /// Maximum number of operations per queued changeset
+    #[serde(default = "default_changeset_size")]
+    pub changeset_size: usize,
+}
+
+/// Default number of operations per queued changeset
+pub const DEFAULT_CHANGESET_SIZE: usize = 50;
+
+fn default_changeset_size() -> usize {
+    DEFAULT_CHANGESET_SIZE
+}
+
+impl Default for SyncPlan {
+    fn default() -> Self {
+        Self {
+            origin_env: String::new(),
+            target_env: String::new(),
+            entity_plans: Vec::new(),
+            detected_junctions: Vec::new(),
+            has_schema_changes: false,
+            total_delete_count: 0,
+            total_insert_count: 0,
+            delete_orphans: false,
+            dependency_cycles: Vec::new(),
+            optimistic_concurrency: false,
+            changeset_size: DEFAULT_CHANGESET_SIZE,
+        }
+    }
 }
 
 impl SyncPlan {
@@ -286,6 +342,27 @@ impl SyncPlan {
         plans
     }
 
+    /// Whether a lookup from `entity` to `target` must be set after the records exist
+    ///
+    /// Self-references and lookups between entities in the same dependency
+    /// cycle can't be satisfied by insert ordering, so creates omit them and
+    /// a follow-up update patches the FK.
+    pub fn is_deferred_lookup(&self, entity: &str, target: &str) -> bool {
+        entity == target
+            || self
+                .dependency_cycles
+                .iter()
+                .any(|cycle| cycle.iter().any(|e| e == entity) && cycle.iter().any(|e| e == target))
+    }
+
+    /// Get the dependency cycle an entity belongs to, if any
+    pub fn cycle_for(&self, entity: &str) -> Option<&[String]> {
+        self.dependency_cycles
+            .iter()
+            .find(|cycle| cycle.iter().any(|e| e == entity))
+            .map(|cycle| cycle.as_slice())
+    }
+
     /// Get entities in insert order
     pub fn insert_order(&self) -> Vec<&EntitySyncPlan> {
         let mut plans: Vec<_> = self.entity_plans.iter().collect();
@@ -2,13 +2,16 @@ use crate::config::repository::transfer::{get_transfer_config, save_transfer_con
 use crate::transfer::{ResolverFallback, TransferConfig};
 use crate::tui::element::FocusId;
 use crate::tui::resource::Resource;
-use crate::tui::widgets::{ListState, ScrollableState, TextInputField, TreeState};
+use crate::tui::widgets::{
+    FileBrowserAction, FileBrowserEvent, ListState, ScrollableState, TextInputField, TreeState,
+};
 use crate::tui::{App, AppId, Command, LayeredView, Subscription};
 
 use super::super::preview::PreviewParams;
+use super::column_map::import_column_map;
 use super::state::{
-    DeleteTarget, EditorParams, EntityMappingForm, FieldMappingForm, Msg, ResolverForm, State,
-    TransformType,
+    ColumnMapData, DeleteTarget, EditorParams, EntityMappingForm, FieldMappingForm, Msg,
+    ResolverForm, State, TransformType,
 };
 use super::view;
 use crate::api::{FieldMetadata, FieldType};
@@ -32,6 +35,7 @@ impl App for MappingEditorApp {
             show_entity_modal: false,
             entity_form: EntityMappingForm::default(),
             editing_entity_idx: None,
+            entity_copy_of: None,
             entity_modal_scroll: ScrollableState::new(),
             show_field_modal: false,
             field_form: FieldMappingForm::default(),
@@ -40,6 +44,7 @@ impl App for MappingEditorApp {
             target_fields: Resource::NotAsked,
             current_field_entity_idx: None,
             pending_field_modal: None,
+            pending_field_copy: false,
             related_fields: std::collections::HashMap::new(),
             show_resolver_modal: false,
             resolver_form: ResolverForm::default(),
@@ -60,6 +65,12 @@ impl App for MappingEditorApp {
             quick_fields_target_prefix: TextInputField::default(),
             entity_target_fields_cache: std::collections::HashMap::new(),
             clipboard: None,
+            sample_record: Resource::NotAsked,
+            sample_entity_idx: None,
+            show_import_browser: false,
+            import_browser: super::state::csv_browser(),
+            import_entity_idx: None,
+            import_report: None,
         };
 
         // Load config first (fast, local DB), then load entities with loading screen
@@ -182,6 +193,7 @@ impl App for MappingEditorApp {
             Msg::AddEntity => {
                 state.show_entity_modal = true;
                 state.editing_entity_idx = None;
+                state.entity_copy_of = None;
                 state.entity_form = EntityMappingForm::default();
                 state.entity_form.priority.value = next_priority(state).to_string();
                 state.entity_modal_scroll = ScrollableState::new();
@@ -193,6 +205,7 @@ impl App for MappingEditorApp {
                     if let Some(mapping) = config.entity_mappings.get(idx) {
                         state.show_entity_modal = true;
                         state.editing_entity_idx = Some(idx);
+                        state.entity_copy_of = None;
                         state.entity_form = EntityMappingForm::from_mapping(mapping);
                         state.entity_modal_scroll = ScrollableState::new();
 
@@ -228,20 +241,40 @@ impl App for MappingEditorApp {
                 Command::None
             }
 
+            Msg::DuplicateEntity(idx) => {
+                // Open the original as a new mapping; nothing is added until it's saved
+                // with a source entity of its own
+                let cmd = Self::update(state, Msg::EditEntity(idx));
+                if state.editing_entity_idx == Some(idx) {
+                    state.editing_entity_idx = None;
+                    state.entity_copy_of = Some(idx);
+                    state.entity_form.priority.value = next_priority(state).to_string();
+                }
+                cmd
+            }
+
             Msg::CloseEntityModal => {
                 state.show_entity_modal = false;
                 state.editing_entity_idx = None;
+                state.entity_copy_of = None;
                 Command::set_focus(FocusId::new("mapping-tree"))
             }
 
             Msg::SaveEntity => {
-                if !state.entity_form.is_valid() {
+                if !state.entity_form.is_valid() || state.entity_source_taken() {
                     return Command::None;
                 }
 
                 if let Resource::Success(config) = &mut state.config {
                     let mut new_mapping = state.entity_form.to_mapping();
 
+                    if let Some(original) = state
+                        .entity_copy_of
+                        .and_then(|idx| config.entity_mappings.get(idx))
+                    {
+                        new_mapping.copy_mappings_from(original);
+                    }
+
                     if let Some(idx) = state.editing_entity_idx {
                         // Editing: preserve field mappings and resolvers
                         if let Some(existing) = config.entity_mappings.get(idx) {
@@ -259,6 +292,7 @@ impl App for MappingEditorApp {
 
                 state.show_entity_modal = false;
                 state.editing_entity_idx = None;
+                state.entity_copy_of = None;
 
                 // Auto-save
                 if let Resource::Success(config) = &state.config {
@@ -352,6 +386,11 @@ impl App for MappingEditorApp {
                 Command::None
             }
 
+            Msg::EntityFormCacheTtl(event) => {
+                state.entity_form.cache_ttl.handle_event(event, Some(6));
+                Command::None
+            }
+
             Msg::EntityFormToggleCreates => {
                 state.entity_form.allow_creates = !state.entity_form.allow_creates;
                 Command::None
@@ -482,6 +521,7 @@ impl App for MappingEditorApp {
                     state.field_form = FieldMappingForm::default();
                     return Command::set_focus(FocusId::new("field-target"));
                 }
+                state.pending_field_copy = false;
 
                 // Need to load fields first - extract entity info
                 let entity_info = if let Resource::Success(config) = &state.config {
@@ -537,76 +577,7 @@ impl App for MappingEditorApp {
             }
 
             Msg::EditField(entity_idx, field_idx) => {
-                // Check if fields already loaded for this entity
-                if state.current_field_entity_idx == Some(entity_idx)
-                    && matches!(&state.source_fields, Resource::Success(_))
-                    && matches!(&state.target_fields, Resource::Success(_))
-                {
-                    // Fields already loaded - open modal immediately
-                    if let Resource::Success(config) = &state.config {
-                        if let Some(entity) = config.entity_mappings.get(entity_idx) {
-                            if let Some(mapping) = entity.field_mappings.get(field_idx) {
-                                state.show_field_modal = true;
-                                state.editing_field = Some((entity_idx, field_idx));
-                                state.field_form = FieldMappingForm::from_mapping(mapping);
-                                return Command::set_focus(FocusId::new("field-target"));
-                            }
-                        }
-                    }
-                    return Command::None;
-                }
-
-                // Need to load fields first - extract entity info
-                let entity_info = if let Resource::Success(config) = &state.config {
-                    config.entity_mappings.get(entity_idx).map(|e| {
-                        (
-                            config.source_env.clone(),
-                            config.target_env.clone(),
-                            e.source_entity.clone(),
-                            e.target_entity.clone(),
-                        )
-                    })
-                } else {
-                    None
-                };
-
-                if let Some((source_env, target_env, source_entity, target_entity)) = entity_info {
-                    // Store pending modal open with field index
-                    state.pending_field_modal = Some((entity_idx, Some(field_idx)));
-                    state.current_field_entity_idx = Some(entity_idx);
-                    state.source_fields = Resource::Loading;
-                    state.target_fields = Resource::Loading;
-
-                    // Use loading screen for field metadata fetch
-                    // Use "source"/"target" prefix to ensure unique task names even when same entity/env
-                    return Command::perform_parallel()
-                        .add_task(
-                            format!(
-                                "Loading source fields for {} ({})",
-                                source_entity, source_env
-                            ),
-                            load_entity_fields(source_env.clone(), source_entity),
-                        )
-                        .add_task(
-                            format!(
-                                "Loading target fields for {} ({})",
-                                target_entity, target_env
-                            ),
-                            load_entity_fields(target_env.clone(), target_entity),
-                        )
-                        .with_title("Loading Field Metadata")
-                        .on_complete(AppId::TransferMappingEditor)
-                        .build(|task_idx, result| {
-                            let data = result
-                                .downcast::<Result<Vec<FieldMetadata>, String>>()
-                                .unwrap();
-                            match task_idx {
-                                0 => Msg::SourceFieldsLoaded(*data),
-                                _ => Msg::TargetFieldsLoaded(*data),
-                            }
-                        });
-                }
-                Command::None
+                open_field_modal(state, entity_idx, field_idx, false)
             }
 
             Msg::DeleteField(entity_idx, field_idx) => {
@@ -630,6 +601,12 @@ impl App for MappingEditorApp {
                 Command::None
             }
 
+            Msg::DuplicateField(entity_idx, field_idx) => {
+                // The copy keeps the target field, which the modal flags as already
+                // mapped until it's changed; nothing is added until it's saved
+                open_field_modal(state, entity_idx, field_idx, true)
+            }
+
             Msg::PasteField(entity_idx) => {
                 let Some(field_mapping) = state.clipboard.clone() else {
                     return Command::None;
@@ -1087,6 +1064,78 @@ impl App for MappingEditorApp {
                 Command::None
             }
 
+            // Aggregate transform fields
+            Msg::FieldFormAggregateSource(event) => {
+                state
+                    .field_form
+                    .aggregate_source
+                    .handle_event(event, Some(200));
+                Command::None
+            }
+
+            Msg::FieldFormToggleAggregateFunction => {
+                state.field_form.aggregate_function = state.field_form.aggregate_function.next();
+                Command::None
+            }
+
+            Msg::FieldFormAggregateSeparator(event) => {
+                state
+                    .field_form
+                    .aggregate_separator
+                    .handle_event(event, Some(20));
+                Command::None
+            }
+
+            // Inline sample preview
+            Msg::FieldFormLoadSample => {
+                let Some((entity_idx, _)) = state.editing_field else {
+                    return Command::None;
+                };
+                let Resource::Success(config) = &state.config else {
+                    return Command::None;
+                };
+                let Some(entity) = config.entity_mappings.get(entity_idx) else {
+                    return Command::None;
+                };
+
+                // Expand every lookup the entity's mappings traverse, plus the one being edited
+                let mut expand_tree = crate::transfer::ExpandTree::new();
+                for fm in &entity.field_mappings {
+                    expand_tree.add_transform(&fm.transform);
+                }
+                if let Some(mapping) = state.field_form.to_mapping() {
+                    expand_tree.add_transform(&mapping.transform);
+                }
+                let source_fields = match &state.source_fields {
+                    Resource::Success(fields) => fields.as_slice(),
+                    _ => &[],
+                };
+                let nav_prop_map: std::collections::HashMap<String, String> = source_fields
+                    .iter()
+                    .filter_map(|f| {
+                        f.navigation_property_name
+                            .clone()
+                            .filter(|_| f.related_entity.is_some())
+                            .map(|nav| (f.logical_name.clone(), nav))
+                    })
+                    .collect();
+                let expands = expand_tree.build_expand_clauses(Some(&nav_prop_map), None);
+
+                let source_env = config.source_env.clone();
+                let source_entity = entity.source_entity.clone();
+                state.sample_record = Resource::Loading;
+                state.sample_entity_idx = Some(entity_idx);
+                Command::perform(
+                    load_sample_record(source_env, source_entity, expands),
+                    Msg::SampleRecordLoaded,
+                )
+            }
+
+            Msg::SampleRecordLoaded(result) => {
+                state.sample_record = Resource::from_result(result);
+                Command::None
+            }
+
             // Resolver modal
             Msg::AddResolver(entity_idx) => {
                 if let Resource::Success(config) = &state.config {
@@ -1273,6 +1322,11 @@ impl App for MappingEditorApp {
                 Command::None
             }
 
+            Msg::ResolverFormCycleNormalization => {
+                state.resolver_form.normalization = state.resolver_form.normalization.cycle();
+                Command::None
+            }
+
             Msg::ResolverFormDefaultGuid(event) => {
                 state.resolver_form.default_guid.handle_event(event, None);
                 Command::None
@@ -1575,6 +1629,152 @@ impl App for MappingEditorApp {
                 Command::set_focus(FocusId::new("mapping-tree"))
             }
 
+            // CSV column map import
+            Msg::ImportColumnMap(entity_idx) => {
+                state.import_entity_idx = Some(entity_idx);
+                state.show_import_browser = true;
+                state
+                    .import_browser
+                    .select_first_matching(|e| e.name.to_lowercase().ends_with(".csv"));
+                Command::set_focus(FocusId::new("import-file-browser"))
+            }
+
+            Msg::CloseImportBrowser => {
+                state.show_import_browser = false;
+                state.import_entity_idx = None;
+                Command::set_focus(FocusId::new("mapping-tree"))
+            }
+
+            Msg::ImportBrowserNavigate(key) => {
+                use crossterm::event::KeyCode;
+
+                match key {
+                    KeyCode::Up => state.import_browser.navigate_up(),
+                    KeyCode::Down => state.import_browser.navigate_down(),
+                    KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End => {
+                        state.import_browser.handle_navigation_key(key);
+                    }
+                    KeyCode::Enter => {
+                        match state
+                            .import_browser
+                            .handle_event(FileBrowserEvent::Activate)
+                        {
+                            Some(FileBrowserAction::FileSelected(path)) => {
+                                return Command::perform(
+                                    async move { path },
+                                    Msg::ImportFileSelected,
+                                );
+                            }
+                            Some(FileBrowserAction::DirectoryEntered(path)) => {
+                                return Command::perform(
+                                    async move { path },
+                                    Msg::ImportDirectoryEntered,
+                                );
+                            }
+                            _ => {}
+                        }
+                    }
+                    KeyCode::Backspace => {
+                        let _ = state.import_browser.go_to_parent();
+                    }
+                    _ => {}
+                }
+                Command::None
+            }
+
+            Msg::ImportDirectoryEntered(_path) => {
+                state
+                    .import_browser
+                    .select_first_matching(|e| e.name.to_lowercase().ends_with(".csv"));
+                Command::None
+            }
+
+            Msg::ImportBrowserViewport(height) => {
+                let item_count = state.import_browser.entries().len();
+                let list_state = state.import_browser.list_state_mut();
+                list_state.set_viewport_height(height);
+                list_state.update_scroll(height, item_count);
+                Command::None
+            }
+
+            Msg::ImportFileSelected(path) => {
+                state.show_import_browser = false;
+                let entity_info = match (&state.config, state.import_entity_idx) {
+                    (Resource::Success(config), Some(idx)) => {
+                        config.entity_mappings.get(idx).map(|e| {
+                            (
+                                config.source_env.clone(),
+                                config.target_env.clone(),
+                                e.source_entity.clone(),
+                                e.target_entity.clone(),
+                            )
+                        })
+                    }
+                    _ => None,
+                };
+                let Some((source_env, target_env, source_entity, target_entity)) = entity_info
+                else {
+                    return Command::set_focus(FocusId::new("mapping-tree"));
+                };
+
+                Command::batch(vec![
+                    Command::perform(
+                        load_column_map(path, source_env, target_env, source_entity, target_entity),
+                        Msg::ColumnMapLoaded,
+                    ),
+                    Command::set_focus(FocusId::new("mapping-tree")),
+                ])
+            }
+
+            Msg::ColumnMapLoaded(result) => {
+                let Some(entity_idx) = state.import_entity_idx.take() else {
+                    return Command::None;
+                };
+                let (pairs, source_fields, target_fields) = match result {
+                    Ok(data) => data,
+                    Err(err) => {
+                        state.import_report =
+                            Some((format!("Column map import failed: {}", err), Vec::new()));
+                        return Command::None;
+                    }
+                };
+                let Resource::Success(config) = &mut state.config else {
+                    return Command::None;
+                };
+                let Some(entity) = config.entity_mappings.get_mut(entity_idx) else {
+                    return Command::None;
+                };
+
+                let import = import_column_map(entity, &pairs, &source_fields, &target_fields);
+                log::info!(
+                    "Imported {} field mappings into '{}' ({} rows skipped)",
+                    import.added,
+                    entity.target_entity,
+                    import.skipped.len()
+                );
+                if !import.skipped.is_empty() {
+                    state.import_report = Some((
+                        format!(
+                            "Imported {} field mappings. {} rows were skipped:",
+                            import.added,
+                            import.skipped.len()
+                        ),
+                        import.skipped,
+                    ));
+                }
+                if import.added == 0 {
+                    return Command::None;
+                }
+
+                state.tree_state.invalidate_cache();
+                Command::perform(save_config(config.clone()), Msg::SaveCompleted)
+            }
+
+            Msg::DismissImportReport => {
+                state.import_report = None;
+                Command::set_focus(FocusId::new("mapping-tree"))
+            }
+
             // Navigation
             Msg::Back => Command::navigate_to(AppId::TransferConfigList),
 
@@ -1585,6 +1785,7 @@ impl App for MappingEditorApp {
                         config_name: state.config_name.clone(),
                         source_env: config.source_env.clone(),
                         target_env: config.target_env.clone(),
+                        warm_cache_only: false,
                     };
                     Command::start_app(AppId::TransferPreview, params)
                 } else {
@@ -1670,6 +1871,89 @@ async fn load_entities_for_env(env_name: String) -> Result<Vec<String>, String>
     Ok(entities)
 }
 
+/// Open the field modal for a field mapping, loading field metadata first if needed
+///
+/// With `as_copy` the modal starts from the field's values but saves a new mapping.
+fn open_field_modal(
+    state: &mut State,
+    entity_idx: usize,
+    field_idx: usize,
+    as_copy: bool,
+) -> Command<Msg> {
+    // Check if fields already loaded for this entity
+    if state.current_field_entity_idx == Some(entity_idx)
+        && matches!(&state.source_fields, Resource::Success(_))
+        && matches!(&state.target_fields, Resource::Success(_))
+    {
+        // Fields already loaded - open modal immediately
+        if let Resource::Success(config) = &state.config {
+            if let Some(entity) = config.entity_mappings.get(entity_idx) {
+                if let Some(mapping) = entity.field_mappings.get(field_idx) {
+                    state.show_field_modal = true;
+                    state.editing_field =
+                        Some((entity_idx, if as_copy { usize::MAX } else { field_idx }));
+                    state.field_form = FieldMappingForm::from_mapping(mapping);
+                    return Command::set_focus(FocusId::new("field-target"));
+                }
+            }
+        }
+        return Command::None;
+    }
+
+    // Need to load fields first - extract entity info
+    let entity_info = if let Resource::Success(config) = &state.config {
+        config.entity_mappings.get(entity_idx).map(|e| {
+            (
+                config.source_env.clone(),
+                config.target_env.clone(),
+                e.source_entity.clone(),
+                e.target_entity.clone(),
+            )
+        })
+    } else {
+        None
+    };
+
+    if let Some((source_env, target_env, source_entity, target_entity)) = entity_info {
+        // Store pending modal open with field index
+        state.pending_field_modal = Some((entity_idx, Some(field_idx)));
+        state.pending_field_copy = as_copy;
+        state.current_field_entity_idx = Some(entity_idx);
+        state.source_fields = Resource::Loading;
+        state.target_fields = Resource::Loading;
+
+        // Use loading screen for field metadata fetch
+        // Use "source"/"target" prefix to ensure unique task names even when same entity/env
+        return Command::perform_parallel()
+            .add_task(
+                format!(
+                    "Loading source fields for {} ({})",
+                    source_entity, source_env
+                ),
+                load_entity_fields(source_env.clone(), source_entity),
+            )
+            .add_task(
+                format!(
+                    "Loading target fields for {} ({})",
+                    target_entity, target_env
+                ),
+                load_entity_fields(target_env.clone(), target_entity),
+            )
+            .with_title("Loading Field Metadata")
+            .on_complete(AppId::TransferMappingEditor)
+            .build(|task_idx, result| {
+                let data = result
+                    .downcast::<Result<Vec<FieldMetadata>, String>>()
+                    .unwrap();
+                match task_idx {
+                    0 => Msg::SourceFieldsLoaded(*data),
+                    _ => Msg::TargetFieldsLoaded(*data),
+                }
+            });
+    }
+    Command::None
+}
+
 /// Try to open the field modal if fields are loaded and there's a pending open
 fn try_open_pending_field_modal(state: &mut State) -> Command<Msg> {
     // Check if both fields are loaded
@@ -1709,8 +1993,10 @@ fn try_open_pending_field_modal(state: &mut State) -> Command<Msg> {
                 if let Resource::Success(config) = &state.config {
                     if let Some(entity) = config.entity_mappings.get(entity_idx) {
                         if let Some(mapping) = entity.field_mappings.get(field_idx) {
+                            let as_copy = std::mem::take(&mut state.pending_field_copy);
                             state.show_field_modal = true;
-                            state.editing_field = Some((entity_idx, field_idx));
+                            state.editing_field =
+                                Some((entity_idx, if as_copy { usize::MAX } else { field_idx }));
                             state.field_form = FieldMappingForm::from_mapping(mapping);
                             return Command::set_focus(FocusId::new("field-target"));
                         }
@@ -1785,6 +2071,61 @@ async fn load_entity_fields(
     Ok(fields)
 }
 
+/// Fetch a single source record to evaluate field mappings against
+async fn load_sample_record(
+    env_name: String,
+    entity_name: String,
+    expands: Vec<String>,
+) -> Result<serde_json::Value, String> {
+    use crate::api::pluralization::pluralize_entity_name;
+    use crate::api::query::QueryBuilder;
+
+    let manager = crate::client_manager();
+    let client = manager
+        .get_client(&env_name)
+        .await
+        .map_err(|e| format!("Failed to get client for {}: {}", env_name, e))?;
+
+    let mut builder = QueryBuilder::new(&pluralize_entity_name(&entity_name)).top(1);
+    if !expands.is_empty() {
+        let expand_refs: Vec<&str> = expands.iter().map(|s| s.as_str()).collect();
+        builder = builder.expand(&expand_refs);
+    }
+
+    let result = client
+        .execute_query(&builder.build())
+        .await
+        .map_err(|e| format!("Query failed for {}: {}", entity_name, e))?;
+    if result.is_error() {
+        return Err(result.error.unwrap_or_else(|| "Unknown error".to_string()));
+    }
+
+    result
+        .data
+        .and_then(|d| d.value.into_iter().next())
+        .ok_or_else(|| format!("No {} records in {}", entity_name, env_name))
+}
+
+/// Read a CSV column map and the entity's field metadata on both sides
+async fn load_column_map(
+    path: std::path::PathBuf,
+    source_env: String,
+    target_env: String,
+    source_entity: String,
+    target_entity: String,
+) -> Result<ColumnMapData, String> {
+    let bytes = tokio::fs::read(&path)
+        .await
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+    let content = crate::csv_parser::decode_csv_bytes(&bytes)?;
+    let pairs = crate::csv_parser::parse_csv_column_map(&content)?;
+
+    let source_fields = load_entity_fields(source_env, source_entity).await?;
+    let target_fields = load_entity_fields(target_env, target_entity).await?;
+
+    Ok((pairs, source_fields, target_fields))
+}
+
 /// Check if a field is a virtual/computed field (like accountidname)
 /// These fields are not queryable and should be excluded from source field selection
 fn is_virtual_field(field: &FieldMetadata) -> bool {
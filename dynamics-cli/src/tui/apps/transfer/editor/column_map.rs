@@ -0,0 +1,96 @@
+//! Field mapping import from a CSV column map
+//!
+//! Turns source → target field pairs into Copy mappings, checking both sides
+//! against the loaded entity metadata.
+
+use crate::api::FieldMetadata;
+use crate::transfer::{EntityMapping, FieldMapping};
+
+/// Outcome of importing a column map into an entity mapping
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnMapImport {
+    /// Number of field mappings created
+    pub added: usize,
+    /// One line per skipped row, with the reason
+    pub skipped: Vec<String>,
+}
+
+/// Add a Copy mapping for every pair whose fields exist in the metadata
+///
+/// Rows naming an unknown field, or a target that is already mapped, are
+/// skipped and reported.
+pub fn import_column_map(
+    entity: &mut EntityMapping,
+    pairs: &[(String, String)],
+    source_fields: &[FieldMetadata],
+    target_fields: &[FieldMetadata],
+) -> ColumnMapImport {
+    let find = |fields: &[FieldMetadata], name: &str| {
+        fields
+            .iter()
+            .find(|f| f.logical_name.eq_ignore_ascii_case(name))
+            .map(|f| f.logical_name.clone())
+    };
+
+    let mut result = ColumnMapImport::default();
+    for (source, target) in pairs {
+        let Some(source_field) = find(source_fields, source) else {
+            result.skipped.push(format!(
+                "{} → {}: unknown source field '{}'",
+                source, target, source
+            ));
+            continue;
+        };
+        let Some(target_field) = find(target_fields, target) else {
+            result.skipped.push(format!(
+                "{} → {}: unknown target field '{}'",
+                source, target, target
+            ));
+            continue;
+        };
+        if entity.find_field_mapping(&target_field).is_some() {
+            result.skipped.push(format!(
+                "{} → {}: '{}' is already mapped",
+                source, target, target_field
+            ));
+            continue;
+        }
+
+        entity.add_field_mapping(FieldMapping::copy_from(target_field, source_field));
+        result.added += 1;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_fields_are_skipped_and_reported() {
+        let csv = "source_field,target_field\ncgk_name,nrq_name\ncgk_legacy,nrq_legacy\n";
+        let pairs = crate::csv_parser::parse_csv_column_map(csv).unwrap();
+
+        let mut entity = EntityMapping::new("cgk_project", "nrq_project", 1);
+        let result = import_column_map(
+            &mut entity,
+            &pairs,
+            &[FieldMetadata::string("cgk_name")],
+            &[
+                FieldMetadata::string("nrq_name"),
+                FieldMetadata::string("nrq_legacy"),
+            ],
+        );
+
+        assert_eq!(result.added, 1);
+        assert_eq!(
+            result.skipped,
+            vec!["cgk_legacy → nrq_legacy: unknown source field 'cgk_legacy'"]
+        );
+        assert_eq!(
+            entity.field_mappings,
+            vec![FieldMapping::copy_from("nrq_name", "cgk_name")]
+        );
+    }
+}
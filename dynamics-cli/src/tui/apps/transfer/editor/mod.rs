@@ -1,4 +1,5 @@
 mod app;
+mod column_map;
 mod state;
 mod tree;
 mod validation;
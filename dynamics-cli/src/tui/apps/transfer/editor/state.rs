@@ -2,13 +2,15 @@ use std::collections::HashMap;
 
 use crate::api::FieldMetadata;
 use crate::transfer::{
-    Condition, EntityMapping, FieldMapping, FieldPath, OperationFilter, Replacement, Resolver,
-    ResolverFallback, SourceFilter, TransferConfig, Transform,
+    AggregateFunction, Condition, EntityMapping, FieldMapping, FieldPath, MatchNormalization,
+    OperationFilter, Replacement, Resolver, ResolverFallback, SourceFilter, TransferConfig,
+    Transform,
 };
 use crate::tui::resource::Resource;
 use crate::tui::widgets::events::{AutocompleteEvent, ListEvent, TextInputEvent, TreeEvent};
 use crate::tui::widgets::{
-    AutocompleteField, ListState, ScrollableState, TextInputField, TreeState,
+    AutocompleteField, FileBrowserEntry, FileBrowserState, ListState, ScrollableState,
+    TextInputField, TreeState,
 };
 
 /// Parameters to initialize the editor
@@ -46,6 +48,8 @@ pub struct State {
     pub show_entity_modal: bool,
     pub entity_form: EntityMappingForm,
     pub editing_entity_idx: Option<usize>,
+    /// Entity mapping the open modal duplicates; its field mappings and resolvers are copied on save
+    pub entity_copy_of: Option<usize>,
     pub entity_modal_scroll: ScrollableState,
 
     // Field mapping modal
@@ -60,6 +64,8 @@ pub struct State {
 
     // Pending field modal open (entity_idx, field_idx) - None field_idx means "add new"
     pub pending_field_modal: Option<(usize, Option<usize>)>,
+    /// Open the pending field modal as a new copy of the field instead of editing it
+    pub pending_field_copy: bool,
 
     // Related entity fields cache - keyed by lookup field name (e.g., "parentaccountid")
     pub related_fields: HashMap<String, Resource<Vec<FieldMetadata>>>,
@@ -98,6 +104,17 @@ pub struct State {
 
     // Clipboard for copy/paste of field mappings
     pub clipboard: Option<FieldMapping>,
+
+    // Sample source record for the field modal's inline preview
+    pub sample_record: Resource<serde_json::Value>,
+    pub sample_entity_idx: Option<usize>,
+
+    // CSV column map import
+    pub show_import_browser: bool,
+    pub import_browser: FileBrowserState,
+    pub import_entity_idx: Option<usize>,
+    /// (summary, skipped rows) shown after an import that skipped rows or failed
+    pub import_report: Option<(String, Vec<String>)>,
 }
 
 impl Default for State {
@@ -111,6 +128,7 @@ impl Default for State {
             show_entity_modal: false,
             entity_form: EntityMappingForm::default(),
             editing_entity_idx: None,
+            entity_copy_of: None,
             entity_modal_scroll: ScrollableState::new(),
             show_field_modal: false,
             field_form: FieldMappingForm::default(),
@@ -119,6 +137,7 @@ impl Default for State {
             target_fields: Resource::NotAsked,
             current_field_entity_idx: None,
             pending_field_modal: None,
+            pending_field_copy: false,
             related_fields: HashMap::new(),
             show_resolver_modal: false,
             resolver_form: ResolverForm::default(),
@@ -139,11 +158,47 @@ impl Default for State {
             quick_fields_target_prefix: TextInputField::default(),
             entity_target_fields_cache: HashMap::new(),
             clipboard: None,
+            sample_record: Resource::NotAsked,
+            sample_entity_idx: None,
+            show_import_browser: false,
+            import_browser: csv_browser(),
+            import_entity_idx: None,
+            import_report: None,
         }
     }
 }
 
+/// File browser for picking a CSV column map, starting in the working directory
+pub fn csv_browser() -> FileBrowserState {
+    let current_dir = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("/"));
+    let mut browser = FileBrowserState::new(current_dir);
+    browser.set_filter(is_csv_or_dir);
+    browser
+}
+
+/// Filter to show only CSV files and directories
+fn is_csv_or_dir(entry: &FileBrowserEntry) -> bool {
+    entry.is_dir || entry.name.to_lowercase().ends_with(".csv")
+}
+
 impl State {
+    /// Whether the entity modal's source entity is already used by another mapping
+    ///
+    /// Source entities are unique per config.
+    pub fn entity_source_taken(&self) -> bool {
+        let Resource::Success(config) = &self.config else {
+            return false;
+        };
+        let source = self.entity_form.source_entity.value.trim();
+        config
+            .entity_mappings
+            .iter()
+            .enumerate()
+            .any(|(idx, mapping)| {
+                Some(idx) != self.editing_entity_idx && mapping.source_entity == source
+            })
+    }
+
     /// Compute fields available for quick-add
     /// Returns fields that exist in both source and target (matching by base name after stripping prefixes),
     /// excluding already-mapped fields and system fields
@@ -238,6 +293,8 @@ pub struct EntityMappingForm {
     pub source_entity: AutocompleteField,
     pub target_entity: AutocompleteField,
     pub priority: TextInputField,
+    /// Cache TTL override in minutes (empty = global default)
+    pub cache_ttl: TextInputField,
     /// Operation filter - which operations to allow
     pub allow_creates: bool,
     pub allow_updates: bool,
@@ -261,6 +318,7 @@ impl Default for EntityMappingForm {
             source_entity: AutocompleteField::default(),
             target_entity: AutocompleteField::default(),
             priority: TextInputField::default(),
+            cache_ttl: TextInputField::default(),
             allow_creates: true,
             allow_updates: true,
             allow_deletes: false,
@@ -282,6 +340,8 @@ impl EntityMappingForm {
         !self.source_entity.value.trim().is_empty()
             && !self.target_entity.value.trim().is_empty()
             && self.priority.value.trim().parse::<u32>().is_ok()
+            && (self.cache_ttl.value.trim().is_empty()
+                || self.cache_ttl.value.trim().parse::<u32>().is_ok())
     }
 
     pub fn from_mapping(mapping: &EntityMapping) -> Self {
@@ -289,6 +349,10 @@ impl EntityMappingForm {
         form.source_entity.value = mapping.source_entity.clone();
         form.target_entity.value = mapping.target_entity.clone();
         form.priority.value = mapping.priority.to_string();
+        form.cache_ttl.value = mapping
+            .cache_ttl_minutes
+            .map(|minutes| minutes.to_string())
+            .unwrap_or_default();
         form.allow_creates = mapping.operation_filter.creates;
         form.allow_updates = mapping.operation_filter.updates;
         form.allow_deletes = mapping.operation_filter.deletes;
@@ -358,6 +422,7 @@ impl EntityMappingForm {
             },
             source_filter,
             target_filter,
+            cache_ttl_minutes: self.cache_ttl.value.trim().parse().ok(),
             resolvers: vec![],
             field_mappings: vec![],
         }
@@ -412,6 +477,7 @@ pub struct ResolverForm {
     pub focused_row: usize,
     pub fallback: ResolverFallback,
     pub default_guid: TextInputField, // For Default fallback
+    pub normalization: MatchNormalization,
 }
 
 impl Default for ResolverForm {
@@ -423,6 +489,7 @@ impl Default for ResolverForm {
             focused_row: 0,
             fallback: ResolverFallback::default(),
             default_guid: TextInputField::default(),
+            normalization: MatchNormalization::default(),
         }
     }
 }
@@ -473,6 +540,7 @@ impl ResolverForm {
         if let Some(guid) = resolver.fallback.default_guid() {
             form.default_guid.value = guid.to_string();
         }
+        form.normalization = resolver.normalization;
         form
     }
 
@@ -515,6 +583,7 @@ impl ResolverForm {
             match_fields,
             fallback,
         )
+        .with_normalization(self.normalization)
     }
 
     /// Add a new empty match field row
@@ -574,6 +643,11 @@ pub struct FieldMappingForm {
     // Replace transform fields
     pub replace_source: AutocompleteField,
     pub replace_entries: Vec<ReplaceEntry>,
+
+    // Aggregate transform fields
+    pub aggregate_source: TextInputField,
+    pub aggregate_function: AggregateFunction,
+    pub aggregate_separator: TextInputField,
 }
 
 #[derive(Clone, Default)]
@@ -598,6 +672,7 @@ pub enum TransformType {
     ValueMap,
     Format,
     Replace,
+    Aggregate,
 }
 
 impl TransformType {
@@ -608,7 +683,8 @@ impl TransformType {
             TransformType::Conditional => TransformType::ValueMap,
             TransformType::ValueMap => TransformType::Format,
             TransformType::Format => TransformType::Replace,
-            TransformType::Replace => TransformType::Copy,
+            TransformType::Replace => TransformType::Aggregate,
+            TransformType::Aggregate => TransformType::Copy,
         }
     }
 
@@ -620,6 +696,7 @@ impl TransformType {
             TransformType::ValueMap => "Value Map",
             TransformType::Format => "Format",
             TransformType::Replace => "Replace",
+            TransformType::Aggregate => "Aggregate",
         }
     }
 }
@@ -760,6 +837,7 @@ impl FieldMappingForm {
                         .iter()
                         .all(|e| !e.pattern.value.trim().is_empty())
             }
+            TransformType::Aggregate => !self.aggregate_source.value.trim().is_empty(),
         };
         target_valid && transform_valid
     }
@@ -941,6 +1019,18 @@ impl FieldMappingForm {
                     }
                 }
             }
+
+            TransformType::Aggregate => {
+                let source_path = self.aggregate_source.value.trim();
+                if source_path.is_empty() {
+                    validation.source_error = Some("Collection path is required".into());
+                    return validation;
+                }
+
+                if let Err(e) = FieldPath::parse(source_path) {
+                    validation.source_error = Some(format!("Invalid path: {}", e));
+                }
+            }
         }
 
         validation
@@ -1081,14 +1171,8 @@ impl FieldMappingForm {
                 form.value_map_entries = mappings
                     .iter()
                     .map(|(src, tgt)| ValueMapEntry {
-                        source_value: TextInputField {
-                            value: src.to_string(),
-                            ..Default::default()
-                        },
-                        target_value: TextInputField {
-                            value: tgt.to_string(),
-                            ..Default::default()
-                        },
+                        source_value: TextInputField::with_value(src.to_string()),
+                        target_value: TextInputField::with_value(tgt.to_string()),
                     })
                     .collect();
             }
@@ -1114,18 +1198,22 @@ impl FieldMappingForm {
                 form.replace_entries = replacements
                     .iter()
                     .map(|r| ReplaceEntry {
-                        pattern: TextInputField {
-                            value: r.pattern.clone(),
-                            ..Default::default()
-                        },
-                        replacement: TextInputField {
-                            value: r.replacement.clone(),
-                            ..Default::default()
-                        },
+                        pattern: TextInputField::with_value(r.pattern.clone()),
+                        replacement: TextInputField::with_value(r.replacement.clone()),
                         is_regex: r.is_regex,
                     })
                     .collect();
             }
+            Transform::Aggregate {
+                source_path,
+                function,
+                separator,
+            } => {
+                form.transform_type = TransformType::Aggregate;
+                form.aggregate_source.value = source_path.to_string();
+                form.aggregate_function = *function;
+                form.aggregate_separator.value = separator.clone().unwrap_or_default();
+            }
         }
         form
     }
@@ -1229,6 +1317,15 @@ impl FieldMappingForm {
                     replacements,
                 }
             }
+            TransformType::Aggregate => {
+                let source_path = FieldPath::parse(self.aggregate_source.value.trim()).ok()?;
+                let separator = &self.aggregate_separator.value;
+                Transform::Aggregate {
+                    source_path,
+                    function: self.aggregate_function,
+                    separator: (!separator.is_empty()).then(|| separator.clone()),
+                }
+            }
         };
 
         Some(FieldMapping {
@@ -1238,6 +1335,27 @@ impl FieldMappingForm {
         })
     }
 
+    /// Evaluate the mapping being edited against a sample source record
+    ///
+    /// Resolvers need the target-side lookup data from a full preview, so a
+    /// Copy with a resolver shows the raw source value instead.
+    pub fn evaluate_sample(
+        &self,
+        record: &serde_json::Value,
+    ) -> Result<crate::transfer::Value, String> {
+        let mapping = self
+            .to_mapping()
+            .ok_or_else(|| "Mapping is incomplete".to_string())?;
+        let transform = match mapping.transform {
+            Transform::Copy { source_path, .. } => Transform::Copy {
+                source_path,
+                resolver: None,
+            },
+            other => other,
+        };
+        crate::transfer::transform::apply_transform(&transform, record, None)
+    }
+
     pub fn add_value_map_entry(&mut self) {
         self.value_map_entries.push(ValueMapEntry::default());
         self.value_map_selected = Some(self.value_map_entries.len() - 1);
@@ -1284,10 +1402,7 @@ impl FieldMappingForm {
                         .unwrap_or_else(|| opt.value.to_string());
 
                     ValueMapEntry {
-                        source_value: TextInputField {
-                            value: opt.value.to_string(), // Just the integer value
-                            ..Default::default()
-                        },
+                        source_value: TextInputField::with_value(opt.value.to_string()), // Just the integer value
                         target_value: TextInputField::default(), // Leave blank for user
                     }
                 })
@@ -1357,11 +1472,13 @@ pub enum Msg {
     AddEntity,
     EditEntity(usize),
     DeleteEntity(usize),
+    DuplicateEntity(usize),
     CloseEntityModal,
     SaveEntity,
     EntityFormSource(AutocompleteEvent),
     EntityFormTarget(AutocompleteEvent),
     EntityFormPriority(TextInputEvent),
+    EntityFormCacheTtl(TextInputEvent),
     EntityFormToggleCreates,
     EntityFormToggleUpdates,
     EntityFormToggleDeletes,
@@ -1387,6 +1504,7 @@ pub enum Msg {
     AddField(usize),         // entity_idx
     EditField(usize, usize), // entity_idx, field_idx
     DeleteField(usize, usize),
+    DuplicateField(usize, usize),
     CopyField(usize, usize), // entity_idx, field_idx - copy to clipboard
     PasteField(usize),       // entity_idx - paste from clipboard
     CloseFieldModal,
@@ -1433,6 +1551,15 @@ pub enum Msg {
     FieldFormReplaceReplacement(usize, TextInputEvent),
     FieldFormToggleReplaceRegex(usize),
 
+    // Aggregate transform fields
+    FieldFormAggregateSource(TextInputEvent),
+    FieldFormToggleAggregateFunction,
+    FieldFormAggregateSeparator(TextInputEvent),
+
+    // Inline sample preview
+    FieldFormLoadSample,
+    SampleRecordLoaded(Result<serde_json::Value, String>),
+
     // Resolver modal actions
     /// AddResolver(entity_idx)
     AddResolver(usize),
@@ -1445,6 +1572,7 @@ pub enum Msg {
     ResolverFormName(TextInputEvent),
     ResolverFormSourceEntity(AutocompleteEvent),
     ResolverFormCycleFallback,
+    ResolverFormCycleNormalization,
     ResolverFormDefaultGuid(TextInputEvent),
     ResolverMatchFieldsLoaded(Result<Vec<FieldMetadata>, String>),
     /// Source fields for resolver source_path autocomplete
@@ -1481,7 +1609,73 @@ pub enum Msg {
     QuickFieldsTargetPrefix(TextInputEvent),
     SaveQuickFields,
 
+    // CSV column map import
+    /// ImportColumnMap(entity_idx)
+    ImportColumnMap(usize),
+    CloseImportBrowser,
+    ImportBrowserNavigate(crossterm::event::KeyCode),
+    ImportFileSelected(std::path::PathBuf),
+    ImportDirectoryEntered(std::path::PathBuf),
+    ImportBrowserViewport(usize),
+    /// Parsed pairs with the entity's source and target field metadata
+    ColumnMapLoaded(Result<ColumnMapData, String>),
+    DismissImportReport,
+
     // Navigation
     Back,
     Preview,
 }
+
+/// (source → target pairs, source fields, target fields)
+pub type ColumnMapData = (
+    Vec<(String, String)>,
+    Vec<FieldMetadata>,
+    Vec<FieldMetadata>,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transfer::Value;
+    use serde_json::json;
+
+    fn form(transform_type: TransformType) -> FieldMappingForm {
+        let mut form = FieldMappingForm::default();
+        form.target_field.value = "description".to_string();
+        form.transform_type = transform_type;
+        form
+    }
+
+    #[test]
+    fn test_evaluate_sample_applies_the_edited_transform() {
+        let record = json!({
+            "firstname": "Ada",
+            "lastname": "Lovelace",
+            "_parentcustomerid_value": "3f2504e0-4f89-11d3-9a0c-0305e82c3301",
+            "parentcustomerid": { "name": "Analytical Engines" }
+        });
+
+        let mut format = form(TransformType::Format);
+        format.format_template.value =
+            "${lastname}, ${firstname} (${parentcustomerid.name})".to_string();
+        assert_eq!(
+            format.evaluate_sample(&record),
+            Ok(Value::String(
+                "Lovelace, Ada (Analytical Engines)".to_string()
+            ))
+        );
+
+        // A resolver can't run without target data, so the raw source value is shown
+        let mut copy = form(TransformType::Copy);
+        copy.source_path.value = "firstname".to_string();
+        copy.resolver_name = Some("contact_by_email".to_string());
+        assert_eq!(
+            copy.evaluate_sample(&record),
+            Ok(Value::String("Ada".to_string()))
+        );
+
+        let mut incomplete = form(TransformType::Format);
+        incomplete.format_template.value = "${".to_string();
+        assert!(incomplete.evaluate_sample(&record).is_err());
+    }
+}
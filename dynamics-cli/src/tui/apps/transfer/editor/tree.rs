@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::api::{FieldMetadata, FieldType};
 use crate::transfer::{EntityMapping, FieldMapping, Resolver};
@@ -9,6 +9,7 @@ use ratatui::{
 };
 
 use super::state::Msg;
+use super::validation::validate_unique_targets;
 
 /// Tree item for the mapping editor
 #[derive(Clone)]
@@ -50,7 +51,11 @@ impl TreeItem for MappingTreeItem {
                     )));
                 }
 
-                // Then add field mappings
+                // Then add field mappings, flagging ones that share a target field
+                let duplicates: HashSet<usize> = validate_unique_targets(&node.field_mappings)
+                    .into_iter()
+                    .map(|(idx, _)| idx)
+                    .collect();
                 for (idx, fm) in node.field_mappings.iter().enumerate() {
                     // Look up field type from cache
                     let field_type = node.field_types.get(&fm.target_field).cloned();
@@ -59,6 +64,7 @@ impl TreeItem for MappingTreeItem {
                         field_idx: idx,
                         mapping: fm.clone(),
                         field_type,
+                        duplicate_target: duplicates.contains(&idx),
                     }));
                 }
 
@@ -305,6 +311,8 @@ pub struct FieldNode {
     pub field_idx: usize,
     pub mapping: FieldMapping,
     pub field_type: Option<FieldType>,
+    /// Another mapping in the same entity writes this target field too
+    pub duplicate_target: bool,
 }
 
 impl FieldNode {
@@ -346,9 +354,14 @@ impl FieldNode {
         }
 
         // Target field
+        let target_color = if self.duplicate_target {
+            theme.accent_error
+        } else {
+            theme.text_primary
+        };
         spans.push(Span::styled(
             self.mapping.target_field.clone(),
-            Style::default().fg(theme.text_primary),
+            Style::default().fg(target_color),
         ));
 
         // Field type (if available)
@@ -365,6 +378,13 @@ impl FieldNode {
             Style::default().fg(theme.text_secondary),
         ));
 
+        if self.duplicate_target {
+            spans.push(Span::styled(
+                " ⚠ duplicate target",
+                Style::default().fg(theme.accent_warning),
+            ));
+        }
+
         let mut builder = Element::styled_text(Line::from(spans));
 
         if is_selected {
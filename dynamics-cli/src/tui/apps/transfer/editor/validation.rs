@@ -3,7 +3,10 @@
 //! Validates that field mappings are type-compatible and that constant values
 //! can be parsed into the target field's type.
 
+use std::collections::HashMap;
+
 use crate::api::metadata::FieldType;
+use crate::transfer::FieldMapping;
 
 /// Validation result for a field mapping
 #[derive(Clone, Debug, PartialEq)]
@@ -275,6 +278,34 @@ pub fn validate_optionset_copy(
     }
 }
 
+/// Flag field mappings that write to the same target field
+///
+/// Only the last mapping's value would survive, silently. Returns the index
+/// of every mapping involved, paired with the error to show for it.
+pub fn validate_unique_targets(field_mappings: &[FieldMapping]) -> Vec<(usize, ValidationResult)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for mapping in field_mappings {
+        *counts.entry(mapping.target_field.as_str()).or_default() += 1;
+    }
+
+    field_mappings
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, mapping)| {
+            let count = counts[mapping.target_field.as_str()];
+            (count > 1).then(|| {
+                (
+                    idx,
+                    ValidationResult::Error(format!(
+                        "'{}' is mapped {} times - only the last mapping takes effect",
+                        mapping.target_field, count
+                    )),
+                )
+            })
+        })
+        .collect()
+}
+
 /// Parse a datetime string - accepts various formats
 fn parse_datetime(s: &str) -> Option<()> {
     // ISO 8601 formats
@@ -379,4 +410,26 @@ mod tests {
         assert!(validate_copy_types(&FieldType::DateTime, &FieldType::Boolean).is_error());
         assert!(validate_copy_types(&FieldType::Boolean, &FieldType::Lookup).is_error());
     }
+
+    #[test]
+    fn test_duplicate_target_fields_are_errors() {
+        let mappings = vec![
+            FieldMapping::copy_from("fullname", "name"),
+            FieldMapping::copy("emailaddress1"),
+            FieldMapping::copy_from("fullname", "lastname"),
+        ];
+
+        let results = validate_unique_targets(&mappings);
+        assert_eq!(
+            results.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+            vec![0, 2]
+        );
+        assert!(results.iter().all(|(_, result)| result.is_error()));
+        assert_eq!(
+            results[0].1.message(),
+            Some("'fullname' is mapped 2 times - only the last mapping takes effect")
+        );
+
+        assert!(validate_unique_targets(&mappings[..2]).is_empty());
+    }
 }
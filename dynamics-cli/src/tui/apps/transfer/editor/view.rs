@@ -3,9 +3,9 @@ use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 
 use crate::api::FieldMetadata;
-use crate::transfer::ResolverFallback;
+use crate::transfer::{AggregateFunction, ResolverFallback};
 use crate::tui::element::{ColumnBuilder, FocusId, RowBuilder};
-use crate::tui::modals::ConfirmationModal;
+use crate::tui::modals::{ConfirmationModal, WarningModal};
 use crate::tui::resource::Resource;
 use crate::tui::widgets::events::TreeEvent;
 use crate::tui::widgets::events::{ListEvent, TextInputEvent};
@@ -47,6 +47,7 @@ pub fn render(state: &mut State, theme: &Theme) -> LayeredView<Msg> {
 
     // Entity modal
     if state.show_entity_modal {
+        let source_taken = state.entity_source_taken();
         let source_entities = match &state.source_entities {
             Resource::Success(e) => e.as_slice(),
             _ => &[],
@@ -67,6 +68,7 @@ pub fn render(state: &mut State, theme: &Theme) -> LayeredView<Msg> {
             render_entity_modal(
                 &mut state.entity_form,
                 state.editing_entity_idx.is_some(),
+                source_taken,
                 source_entities,
                 target_entities,
                 source_fields_for_filter,
@@ -111,6 +113,29 @@ pub fn render(state: &mut State, theme: &Theme) -> LayeredView<Msg> {
                 .unwrap_or_default(),
             _ => vec![],
         };
+        // Target fields already written by the entity's other mappings
+        let other_targets: Vec<&str> = match (&state.config, state.editing_field) {
+            (Resource::Success(config), Some((entity_idx, field_idx))) => config
+                .entity_mappings
+                .get(entity_idx)
+                .map(|em| {
+                    em.field_mappings
+                        .iter()
+                        .enumerate()
+                        .filter(|(idx, _)| *idx != field_idx)
+                        .map(|(_, fm)| fm.target_field.as_str())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            _ => vec![],
+        };
+        // Only show a sample fetched for the entity being edited
+        let sample = match state.editing_field {
+            Some((entity_idx, _)) if state.sample_entity_idx == Some(entity_idx) => {
+                &state.sample_record
+            }
+            _ => &Resource::NotAsked,
+        };
 
         view = view.with_app_modal(
             render_field_modal(
@@ -123,6 +148,8 @@ pub fn render(state: &mut State, theme: &Theme) -> LayeredView<Msg> {
                 target_fields,
                 fields_loading,
                 &resolvers,
+                &other_targets,
+                sample,
                 theme,
             ),
             Alignment::Center,
@@ -175,6 +202,35 @@ pub fn render(state: &mut State, theme: &Theme) -> LayeredView<Msg> {
         );
     }
 
+    // CSV column map file browser
+    if state.show_import_browser {
+        let browser = Element::file_browser("import-file-browser", &state.import_browser, theme)
+            .on_file_selected(Msg::ImportFileSelected)
+            .on_directory_entered(Msg::ImportDirectoryEntered)
+            .on_navigate(Msg::ImportBrowserNavigate)
+            .on_render(Msg::ImportBrowserViewport)
+            .build();
+        let modal = Element::panel(browser)
+            .title(format!(
+                "Import Column Map (source,target CSV) - {}",
+                state.import_browser.current_path().display()
+            ))
+            .width(70)
+            .height(20)
+            .build();
+        view = view.with_app_modal(modal, Alignment::Center);
+    }
+
+    // Column map import report
+    if let Some((summary, skipped)) = &state.import_report {
+        let modal = WarningModal::new("Column Map Import")
+            .message(summary.clone())
+            .items(skipped.clone())
+            .on_close(Msg::DismissImportReport)
+            .build();
+        view = view.with_app_modal(modal, Alignment::Center);
+    }
+
     // Delete confirmation
     if state.show_delete_confirm {
         let message = match &state.delete_target {
@@ -274,6 +330,7 @@ fn render_editor(
 fn render_entity_modal(
     form: &mut super::state::EntityMappingForm,
     is_edit: bool,
+    source_taken: bool,
     source_entities: &[String],
     target_entities: &[String],
     source_fields: &[FieldMetadata],
@@ -297,7 +354,16 @@ fn render_entity_modal(
     .placeholder("Type to search source entities...")
     .on_event(Msg::EntityFormSource)
     .build();
-    let source_panel = Element::panel(source_input).title("Source Entity").build();
+    // Source entities are unique per config (e.g. a duplicated mapping needs a new one)
+    let source_title = if source_taken {
+        format!(
+            "Source Entity - '{}' is already mapped",
+            form.source_entity.value.trim()
+        )
+    } else {
+        "Source Entity".to_string()
+    };
+    let source_panel = Element::panel(source_input).title(source_title).build();
 
     // Target entity autocomplete
     let target_input = Element::autocomplete(
@@ -324,6 +390,19 @@ fn render_entity_modal(
         .title("Priority (lower = first)")
         .build();
 
+    // Cache TTL override input
+    let cache_ttl_input = Element::text_input(
+        FocusId::new("entity-cache-ttl"),
+        &form.cache_ttl.value,
+        &mut form.cache_ttl.state,
+    )
+    .placeholder("Global default")
+    .on_event(Msg::EntityFormCacheTtl)
+    .build();
+    let cache_ttl_panel = Element::panel(cache_ttl_input)
+        .title("Cache TTL (minutes, blank = default)")
+        .build();
+
     // Operation filter toggles
     let creates_label = if form.allow_creates {
         "[x] Creates"
@@ -532,7 +611,7 @@ fn render_entity_modal(
         .on_press(Msg::CloseEntityModal)
         .build();
 
-    let save_btn = if form.is_valid() {
+    let save_btn = if form.is_valid() && !source_taken {
         Element::button(FocusId::new("entity-save"), "Save")
             .on_press(Msg::SaveEntity)
             .build()
@@ -572,6 +651,7 @@ fn render_entity_modal(
         .add(source_panel, LayoutConstraint::Length(3))
         .add(target_panel, LayoutConstraint::Length(3))
         .add(priority_panel, LayoutConstraint::Length(3))
+        .add(cache_ttl_panel, LayoutConstraint::Length(3))
         .add(op_filter_panel, LayoutConstraint::Length(5))
         .add(
             filter_panel,
@@ -616,6 +696,8 @@ fn render_field_modal(
     target_fields: &[FieldMetadata],
     fields_loading: bool,
     resolvers: &[(&str, &str, &str)], // (name, source_entity, match_field)
+    other_targets: &[&str],
+    sample: &Resource<serde_json::Value>,
     theme: &Theme,
 ) -> Element<Msg> {
     use super::state::{ConditionType, FallbackType};
@@ -1245,10 +1327,61 @@ fn render_field_modal(
             let entries_height = (entries_len.min(4) * 4) as u16;
             (content, (base_height + entries_height).min(40))
         }
+        TransformType::Aggregate => {
+            // Collection path input
+            let source_input = Element::text_input(
+                FocusId::new("aggregate-source"),
+                &form.aggregate_source.value,
+                &mut form.aggregate_source.state,
+            )
+            .placeholder("e.g., lines.amount or lines.*.productid.name")
+            .on_event(Msg::FieldFormAggregateSource)
+            .build();
+            let source_panel = Element::panel(source_input)
+                .title("Collection Path")
+                .build();
+
+            // Function indicator
+            let function_label = format!("{} (Ctrl+F to cycle)", form.aggregate_function);
+            let function_indicator = Element::styled_text(Line::from(vec![
+                Span::styled("Function: ", Style::default().fg(theme.text_tertiary)),
+                Span::styled(function_label, Style::default().fg(theme.accent_secondary)),
+            ]))
+            .build();
+
+            let mut col = ColumnBuilder::new()
+                .add(source_panel, LayoutConstraint::Length(3))
+                .add(function_indicator, LayoutConstraint::Length(1));
+
+            // Separator only applies to concat
+            let is_concat = form.aggregate_function == AggregateFunction::Concat;
+            if is_concat {
+                let separator_input = Element::text_input(
+                    FocusId::new("aggregate-separator"),
+                    &form.aggregate_separator.value,
+                    &mut form.aggregate_separator.state,
+                )
+                .placeholder(", ")
+                .on_event(Msg::FieldFormAggregateSeparator)
+                .build();
+                col = col.add(
+                    Element::panel(separator_input).title("Separator").build(),
+                    LayoutConstraint::Length(3),
+                );
+            }
+
+            let content = col.spacing(1).build();
+            (content, if is_concat { 20 } else { 16 })
+        }
     };
 
     // Run validation
-    let validation = form.validate(target_fields, source_fields);
+    let mut validation = form.validate(target_fields, source_fields);
+    // The config can hold only one mapping per target field
+    let target = form.target_field.value.trim();
+    if validation.target_error.is_none() && other_targets.contains(&target) {
+        validation.target_error = Some(format!("'{}' is already mapped", target));
+    }
     let can_save = form.is_valid() && !validation.has_errors();
 
     // Validation message (show error or warning)
@@ -1291,10 +1424,37 @@ fn render_field_modal(
         .add(save_btn, LayoutConstraint::Length(12))
         .build();
 
+    // Inline sample preview: the edited mapping evaluated against one source record
+    let sample_line = match sample {
+        Resource::NotAsked => Line::from(Span::styled(
+            "Ctrl+E to test with a sample source record",
+            Style::default().fg(theme.text_tertiary),
+        )),
+        Resource::Loading => Line::from(Span::styled(
+            "Fetching sample record...",
+            Style::default().fg(theme.text_tertiary),
+        )),
+        Resource::Failure(err) => Line::from(Span::styled(
+            err.clone(),
+            Style::default().fg(theme.accent_error),
+        )),
+        Resource::Success(record) => match form.evaluate_sample(record) {
+            Ok(value) => Line::from(vec![
+                Span::styled("→ ", Style::default().fg(theme.text_tertiary)),
+                Span::styled(value.to_string(), Style::default().fg(theme.accent_success)),
+            ]),
+            Err(err) => Line::from(Span::styled(err, Style::default().fg(theme.accent_error))),
+        },
+    };
+    let sample_panel = Element::panel(Element::styled_text(sample_line).build())
+        .title("Sample")
+        .build();
+
     let mut form_builder = ColumnBuilder::new()
         .add(target_panel, LayoutConstraint::Length(3))
         .add(type_indicator, LayoutConstraint::Length(1))
-        .add(transform_content, LayoutConstraint::Fill(1));
+        .add(transform_content, LayoutConstraint::Fill(1))
+        .add(sample_panel, LayoutConstraint::Length(3));
 
     if let Some(msg) = validation_msg {
         form_builder = form_builder.add(msg, LayoutConstraint::Length(1));
@@ -1307,9 +1467,9 @@ fn render_field_modal(
 
     // Adjust height for validation message
     let final_height = if validation.has_errors() || validation.has_warnings() {
-        modal_height + 2
+        modal_height + 6
     } else {
-        modal_height
+        modal_height + 4
     };
 
     Element::panel(Element::container(form_content).padding(1).build())
@@ -1680,6 +1840,13 @@ fn render_resolver_modal(
         .on_press(Msg::ResolverFormCycleFallback)
         .build();
 
+    // Normalization button (cycle between common matching presets)
+    let normalization_label = format!("Matching: {} (click to cycle)", form.normalization.label());
+    let normalization_btn =
+        Element::button(FocusId::new("resolver-normalization"), &normalization_label)
+            .on_press(Msg::ResolverFormCycleNormalization)
+            .build();
+
     // Default GUID input (optional - when filled, uses Default fallback)
     let default_guid_input = Element::text_input(
         FocusId::new("resolver-default-guid"),
@@ -1722,7 +1889,7 @@ fn render_resolver_modal(
         .build();
 
     // Calculate height based on number of match field rows
-    let base_height: u16 = 28; // Base modal height for single field
+    let base_height: u16 = 31; // Base modal height for single field
     let extra_rows = if rows_len > 1 {
         (rows_len - 1).min(3) * 4
     } else {
@@ -1742,6 +1909,7 @@ fn render_resolver_modal(
             }),
         )
         .add(fallback_btn, LayoutConstraint::Length(3))
+        .add(normalization_btn, LayoutConstraint::Length(3))
         .add(default_guid_panel, LayoutConstraint::Length(3))
         .add(help_text, LayoutConstraint::Length(2))
         .add(button_row, LayoutConstraint::Length(3))
@@ -1972,7 +2140,25 @@ fn render_quick_fields_modal(
 pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
     let mut subs = vec![];
 
-    if state.show_delete_confirm {
+    if state.import_report.is_some() {
+        subs.push(Subscription::keyboard(
+            KeyCode::Esc,
+            "Close",
+            Msg::DismissImportReport,
+        ));
+        subs.push(Subscription::keyboard(
+            KeyCode::Enter,
+            "Close",
+            Msg::DismissImportReport,
+        ));
+    } else if state.show_import_browser {
+        // File browser handles its own navigation via on_navigate
+        subs.push(Subscription::keyboard(
+            KeyCode::Esc,
+            "Close",
+            Msg::CloseImportBrowser,
+        ));
+    } else if state.show_delete_confirm {
         subs.push(Subscription::keyboard(
             KeyCode::Enter,
             "Confirm",
@@ -2010,6 +2196,11 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
             "Cycle transform type",
             Msg::FieldFormToggleType,
         ));
+        subs.push(Subscription::ctrl_key(
+            KeyCode::Char('e'),
+            "Test with sample",
+            Msg::FieldFormLoadSample,
+        ));
 
         // Transform-specific shortcuts
         match state.field_form.transform_type {
@@ -2053,6 +2244,13 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
                     Msg::FieldFormAddReplace,
                 ));
             }
+            TransformType::Aggregate => {
+                subs.push(Subscription::ctrl_key(
+                    KeyCode::Char('f'),
+                    "Cycle function",
+                    Msg::FieldFormToggleAggregateFunction,
+                ));
+            }
             _ => {}
         }
     } else if state.show_resolver_modal {
@@ -2136,6 +2334,11 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
                             "Delete entity",
                             Msg::DeleteEntity(idx),
                         ));
+                        subs.push(Subscription::keyboard(
+                            KeyCode::Char('D'),
+                            "Duplicate entity",
+                            Msg::DuplicateEntity(idx),
+                        ));
                         subs.push(Subscription::keyboard(
                             KeyCode::Char('f'),
                             "Add field",
@@ -2151,6 +2354,11 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
                             "Quick add fields",
                             Msg::OpenQuickFields,
                         ));
+                        subs.push(Subscription::keyboard(
+                            KeyCode::Char('i'),
+                            "Import column map",
+                            Msg::ImportColumnMap(idx),
+                        ));
                         // Paste from clipboard
                         if state.clipboard.is_some() {
                             subs.push(Subscription::keyboard(
@@ -2180,6 +2388,11 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
                                 "Delete field",
                                 Msg::DeleteField(entity_idx, field_idx),
                             ));
+                            subs.push(Subscription::keyboard(
+                                KeyCode::Char('D'),
+                                "Duplicate field",
+                                Msg::DuplicateField(entity_idx, field_idx),
+                            ));
                             // Also allow adding to the parent entity
                             subs.push(Subscription::keyboard(
                                 KeyCode::Char('f'),
@@ -178,6 +178,26 @@ impl App for TransferConfigListApp {
                 Command::None
             }
 
+            Msg::WarmCacheSelected => {
+                if let Resource::Success(configs) = &state.configs {
+                    if let Some(idx) = state.list_state.selected() {
+                        if let Some(config) = configs.get(idx) {
+                            use crate::tui::apps::transfer::PreviewParams;
+                            return Command::start_app(
+                                AppId::TransferPreview,
+                                PreviewParams {
+                                    config_name: config.name.clone(),
+                                    source_env: config.source_env.clone(),
+                                    target_env: config.target_env.clone(),
+                                    warm_cache_only: true,
+                                },
+                            );
+                        }
+                    }
+                }
+                Command::None
+            }
+
             Msg::DeleteSelected => {
                 if let Resource::Success(configs) = &state.configs {
                     if let Some(idx) = state.list_state.selected() {
@@ -101,6 +101,7 @@ pub enum Msg {
     CreateNew,
     EditSelected,
     DeleteSelected,
+    WarmCacheSelected,
     ConfirmDelete,
     CancelDelete,
     Refresh,
@@ -596,6 +596,11 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
             "Refresh list",
             Msg::Refresh,
         ));
+        subs.push(Subscription::keyboard(
+            KeyCode::Char('w'),
+            "Warm data cache",
+            Msg::WarmCacheSelected,
+        ));
 
         // Multi-select shortcuts
         subs.push(Subscription::keyboard(
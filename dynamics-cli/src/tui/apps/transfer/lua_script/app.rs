@@ -1,18 +1,27 @@
 //! LuaScriptApp - TUI app for managing Lua transform scripts
 
+use std::collections::{BTreeSet, HashMap};
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use crossterm::event::KeyCode;
 
 use crate::config::repository::transfer::{get_transfer_config, save_transfer_config};
 use crate::transfer::TransferConfig;
-use crate::transfer::lua::{ValidationResult, validate_script};
+use crate::transfer::lua::{
+    Declaration, DryRunSummary, ExecutionContext, ExecutionUpdate, OperationType, TransformInfo,
+    ValidationResult, execute_transform_async, validate_script,
+};
 use crate::tui::element::FocusId;
 use crate::tui::resource::Resource;
 use crate::tui::widgets::{FileBrowserAction, FileBrowserEvent};
 use crate::tui::{App, AppId, Command, LayeredView, Subscription};
 
-use super::state::{LuaScriptParams, Msg, State, StatusMessage};
+use super::state::{
+    DryRunReport, LuaScriptParams, Msg, State, StatusMessage, reset_dry_run_progress,
+    set_dry_run_progress, set_dry_run_status,
+};
 use super::view;
 
 pub struct LuaScriptApp;
@@ -230,6 +239,86 @@ impl App for LuaScriptApp {
                 Command::None
             }
 
+            Msg::DryRun => {
+                let declaration = match &state.validation {
+                    Resource::Success(validation) if validation.is_valid => {
+                        validation.declaration.clone().unwrap_or_default()
+                    }
+                    _ => {
+                        state.status_message = Some(StatusMessage::error(
+                            "Cannot dry run: validate the script first",
+                        ));
+                        return Command::None;
+                    }
+                };
+
+                if let Resource::Success(config) = &state.config {
+                    if let Some(script) = config.lua_script.clone() {
+                        state.dry_run = Resource::Loading;
+                        state.status_message = Some(StatusMessage::info("Running dry run..."));
+                        reset_dry_run_progress();
+                        let cancel_flag = Arc::new(AtomicBool::new(false));
+                        state.dry_run_cancel = Some(cancel_flag.clone());
+                        let info = TransformInfo {
+                            config_name: config.name.clone(),
+                            source_env: config.source_env.clone(),
+                            target_env: config.target_env.clone(),
+                        };
+                        return Command::perform(
+                            run_dry_run(script, declaration, info, cancel_flag),
+                            Msg::DryRunComplete,
+                        );
+                    }
+                }
+                Command::None
+            }
+
+            Msg::DryRunComplete(result) => {
+                let cancelled = state
+                    .dry_run_cancel
+                    .take()
+                    .is_some_and(|flag| flag.load(Ordering::Relaxed));
+                if cancelled {
+                    state.dry_run = Resource::NotAsked;
+                    state.status_message = Some(StatusMessage::info("Dry run cancelled"));
+                    return Command::None;
+                }
+                match result {
+                    Ok(report) => {
+                        state.status_message =
+                            Some(if report.summary.validation_errors.is_empty() {
+                                StatusMessage::info(format!(
+                                    "Dry run: {} operations",
+                                    report.summary.total
+                                ))
+                            } else {
+                                StatusMessage::error(format!(
+                                    "Dry run: {} operations, {} invalid",
+                                    report.summary.total,
+                                    report.summary.validation_errors.len()
+                                ))
+                            });
+                        state.dry_run = Resource::Success(report);
+                    }
+                    Err(e) => {
+                        state.status_message =
+                            Some(StatusMessage::error(format!("Dry run failed: {}", e)));
+                        state.dry_run = Resource::Failure(e);
+                    }
+                }
+                Command::None
+            }
+
+            Msg::CancelDryRun => {
+                if let Some(flag) = &state.dry_run_cancel {
+                    flag.store(true, Ordering::Relaxed);
+                    state.status_message = Some(StatusMessage::info("Cancelling dry run..."));
+                }
+                Command::None
+            }
+
+            Msg::DryRunTick => Command::None,
+
             Msg::StartPreview => {
                 // Check if valid first
                 if let Resource::Success(validation) = &state.validation {
@@ -254,6 +343,7 @@ impl App for LuaScriptApp {
                             config_name: config.name.clone(),
                             source_env: config.source_env.clone(),
                             target_env: config.target_env.clone(),
+                            warm_cache_only: false,
                         },
                     );
                 }
@@ -304,8 +394,112 @@ async fn load_script_file(path: PathBuf) -> Result<String, String> {
     .map_err(|e| format!("Task failed: {}", e))?
 }
 
+/// Run the transform against cached entity data without fetching or queueing.
+/// Declared entities with no cache entry are passed to the script as empty lists.
+/// Emitted operations are checked against cached target metadata when every
+/// targeted entity has a cache entry.
+/// `lib.status` / `lib.progress` calls are streamed into the dry-run progress
+/// shown while the script runs; setting `cancel_flag` aborts the script.
+async fn run_dry_run(
+    script: String,
+    declaration: Declaration,
+    info: TransformInfo,
+    cancel_flag: Arc<AtomicBool>,
+) -> Result<DryRunReport, String> {
+    // Accept older cache entries than the preview does - counts only need representative data
+    const DRY_RUN_CACHE_MAX_AGE_HOURS: i64 = 24;
+
+    let config = crate::global_config();
+    let source_env = info.source_env.clone();
+    let target_env = info.target_env.clone();
+    let mut uncached = Vec::new();
+    let mut source_data = serde_json::Map::new();
+    let mut target_data = serde_json::Map::new();
+
+    for (env, entities, data, side) in [
+        (&source_env, &declaration.source, &mut source_data, "source"),
+        (&target_env, &declaration.target, &mut target_data, "target"),
+    ] {
+        for entity in entities.keys() {
+            let records = config
+                .get_entity_data_cache(env, entity, DRY_RUN_CACHE_MAX_AGE_HOURS)
+                .await
+                .map_err(|e| e.to_string())?;
+            if records.is_none() {
+                uncached.push(format!("{} ({})", entity, side));
+            }
+            data.insert(
+                entity.clone(),
+                serde_json::Value::Array(records.unwrap_or_default()),
+            );
+        }
+    }
+
+    let source_json = serde_json::Value::Object(source_data);
+    let target_json = serde_json::Value::Object(target_data);
+    let (update_tx, mut update_rx) = tokio::sync::mpsc::channel::<ExecutionUpdate>(100);
+    let ctx = ExecutionContext::new(update_tx, cancel_flag)
+        .with_info(info)
+        .with_min_log_level(crate::global_runtime_config().lua_log_level)
+        .with_module_dir(crate::global_runtime_config().lua_module_dir.clone());
+    let forward_task = tokio::spawn(async move {
+        while let Some(update) = update_rx.recv().await {
+            match update {
+                ExecutionUpdate::Progress {
+                    current,
+                    total,
+                    label,
+                } => set_dry_run_progress(current, total, label),
+                ExecutionUpdate::Status(msg) => set_dry_run_status(msg),
+                _ => {}
+            }
+        }
+    });
+
+    let result = execute_transform_async(script, source_json, target_json, ctx)
+        .await
+        .map_err(|e| format!("{:#}", e));
+    let _ = forward_task.await;
+    let result = result?;
+
+    // Schema check only runs when metadata for every targeted entity is cached,
+    // otherwise uncached entities would be reported as unknown
+    let mut metadata = HashMap::new();
+    let mut metadata_complete = true;
+    let targeted: BTreeSet<&String> = result
+        .operations
+        .iter()
+        .filter(|op| !matches!(op.operation, OperationType::Skip | OperationType::Error))
+        .map(|op| &op.entity)
+        .collect();
+    for entity in targeted {
+        match config
+            .get_entity_metadata_cache(&target_env, entity, DRY_RUN_CACHE_MAX_AGE_HOURS)
+            .await
+        {
+            Ok(Some(entity_metadata)) => {
+                metadata.insert(entity.clone(), entity_metadata);
+            }
+            _ => {
+                metadata_complete = false;
+                uncached.push(format!("{} (target metadata)", entity));
+            }
+        }
+    }
+    uncached.sort();
+
+    let summary = DryRunSummary::from_operations(
+        &result.operations,
+        result.logs,
+        metadata_complete.then_some(&metadata),
+    );
+
+    Ok(DryRunReport { summary, uncached })
+}
+
 async fn validate_script_async(script: String) -> Result<ValidationResult, String> {
-    tokio::task::spawn_blocking(move || Ok(validate_script(&script)))
+    let module_dir = crate::global_runtime_config().lua_module_dir.clone();
+    tokio::task::spawn_blocking(move || Ok(validate_script(&script, module_dir.as_deref())))
         .await
         .map_err(|e| format!("Validation task failed: {}", e))?
 }
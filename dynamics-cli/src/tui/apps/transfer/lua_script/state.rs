@@ -1,11 +1,14 @@
 //! State and messages for LuaScriptApp
 
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, RwLock};
 
 use crossterm::event::KeyCode;
+use once_cell::sync::Lazy;
 
 use crate::transfer::TransferConfig;
-use crate::transfer::lua::ValidationResult;
+use crate::transfer::lua::{DryRunSummary, ValidationResult};
 use crate::tui::resource::Resource;
 use crate::tui::widgets::FileBrowserState;
 
@@ -31,6 +34,10 @@ pub struct State {
     pub config: Resource<TransferConfig>,
     /// Validation result from last validation
     pub validation: Resource<ValidationResult>,
+    /// Result of the last dry run
+    pub dry_run: Resource<DryRunReport>,
+    /// Set to abort the running dry run
+    pub dry_run_cancel: Option<Arc<AtomicBool>>,
 
     // File browser
     pub show_file_browser: bool,
@@ -51,6 +58,8 @@ impl Default for State {
             config_name: String::new(),
             config: Resource::NotAsked,
             validation: Resource::NotAsked,
+            dry_run: Resource::NotAsked,
+            dry_run_cancel: None,
             show_file_browser: false,
             file_browser,
             status_message: None,
@@ -63,6 +72,55 @@ fn is_lua_or_dir(entry: &crate::tui::widgets::FileBrowserEntry) -> bool {
     entry.is_dir || entry.name.to_lowercase().ends_with(".lua")
 }
 
+/// Dry-run summary plus the declared entities that had no cached data
+#[derive(Clone, Debug)]
+pub struct DryRunReport {
+    pub summary: DryRunSummary,
+    pub uncached: Vec<String>,
+}
+
+/// Global progress of the running dry run (lets the async task update the UI)
+static DRY_RUN_PROGRESS: Lazy<RwLock<DryRunProgress>> =
+    Lazy::new(|| RwLock::new(DryRunProgress::default()));
+
+/// Latest `lib.status` / `lib.progress` values reported by the script
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DryRunProgress {
+    /// Last message from `lib.status`
+    pub status: Option<String>,
+    /// Last `(current, total, label)` from `lib.progress`
+    pub progress: Option<(usize, usize, Option<String>)>,
+}
+
+/// Clear progress before starting a new dry run
+pub fn reset_dry_run_progress() {
+    if let Ok(mut progress) = DRY_RUN_PROGRESS.write() {
+        *progress = DryRunProgress::default();
+    }
+}
+
+/// Record a status message from the script
+pub fn set_dry_run_status(status: String) {
+    if let Ok(mut progress) = DRY_RUN_PROGRESS.write() {
+        progress.status = Some(status);
+    }
+}
+
+/// Record a progress update from the script
+pub fn set_dry_run_progress(current: usize, total: usize, label: Option<String>) {
+    if let Ok(mut progress) = DRY_RUN_PROGRESS.write() {
+        progress.progress = Some((current, total, label));
+    }
+}
+
+/// Get a snapshot of the current dry-run progress
+pub fn get_dry_run_progress() -> DryRunProgress {
+    DRY_RUN_PROGRESS
+        .read()
+        .map(|p| p.clone())
+        .unwrap_or_default()
+}
+
 /// Status message displayed in the UI
 #[derive(Clone, Debug)]
 pub struct StatusMessage {
@@ -108,6 +166,13 @@ pub enum Msg {
     Validate,
     ValidationComplete(Result<ValidationResult, String>),
 
+    // Dry run
+    DryRun,
+    DryRunComplete(Result<DryRunReport, String>),
+    CancelDryRun,
+    /// Re-render while the dry run is in progress
+    DryRunTick,
+
     // Preview
     StartPreview,
 
@@ -1,5 +1,7 @@
 //! View rendering for LuaScriptApp
 
+use std::time::Duration;
+
 use crossterm::event::KeyCode;
 use ratatui::style::Style;
 use ratatui::text::{Line, Span};
@@ -8,7 +10,7 @@ use crate::tui::element::{ColumnBuilder, FocusId, LayoutConstraint, RowBuilder};
 use crate::tui::resource::Resource;
 use crate::tui::{Element, LayeredView, Subscription};
 
-use super::state::{Msg, State};
+use super::state::{Msg, State, get_dry_run_progress};
 
 pub fn render(state: &mut State, theme: &crate::tui::Theme) -> LayeredView<Msg> {
     let content = match &state.config {
@@ -282,6 +284,74 @@ fn render_script_view(
         }
     }
 
+    // Dry run results
+    match &state.dry_run {
+        Resource::Success(report) => {
+            rows.push((Element::text(""), LayoutConstraint::Length(1)));
+            rows.push((
+                Element::styled_text(Line::from(vec![Span::styled(
+                    format!("Dry run ({} operations):", report.summary.total),
+                    Style::default().fg(theme.text_secondary),
+                )]))
+                .build(),
+                LayoutConstraint::Length(1),
+            ));
+            for (op_type, count) in &report.summary.counts {
+                rows.push((
+                    Element::styled_text(Line::from(vec![Span::styled(
+                        format!("  - {}: {}", op_type.label(), count),
+                        Style::default().fg(theme.text_primary),
+                    )]))
+                    .build(),
+                    LayoutConstraint::Length(1),
+                ));
+            }
+            if !report.uncached.is_empty() {
+                rows.push((
+                    Element::styled_text(Line::from(vec![Span::styled(
+                        format!("  No cached data for: {}", report.uncached.join(", ")),
+                        Style::default().fg(theme.accent_warning),
+                    )]))
+                    .build(),
+                    LayoutConstraint::Length(1),
+                ));
+            }
+            for error in &report.summary.validation_errors {
+                rows.push((
+                    Element::styled_text(Line::from(vec![Span::styled(
+                        format!("  - {}", error),
+                        Style::default().fg(theme.accent_error),
+                    )]))
+                    .build(),
+                    LayoutConstraint::Length(1),
+                ));
+            }
+        }
+        Resource::Loading => {
+            let progress = get_dry_run_progress();
+            rows.push((Element::text(""), LayoutConstraint::Length(1)));
+            rows.push((
+                Element::styled_text(Line::from(vec![Span::styled(
+                    match &progress.status {
+                        Some(status) => format!("Dry run in progress: {}", status),
+                        None => "Dry run in progress...".to_string(),
+                    },
+                    Style::default().fg(theme.accent_warning),
+                )]))
+                .build(),
+                LayoutConstraint::Length(1),
+            ));
+            if let Some((current, total, label)) = progress.progress {
+                let mut bar = Element::progress_bar(current, total);
+                if let Some(label) = label {
+                    bar = bar.label(label);
+                }
+                rows.push((bar.build(), LayoutConstraint::Length(1)));
+            }
+        }
+        Resource::NotAsked | Resource::Failure(_) => {}
+    }
+
     // Status message
     if let Some(status) = &state.status_message {
         rows.push((Element::text(""), LayoutConstraint::Length(1)));
@@ -314,6 +384,13 @@ fn render_script_view(
     let validate_btn = Element::button(FocusId::new("lua-validate"), "Validate")
         .on_press(Msg::Validate)
         .build();
+    let dry_run_btn = if is_valid {
+        Element::button(FocusId::new("lua-dry-run"), "Dry Run")
+            .on_press(Msg::DryRun)
+            .build()
+    } else {
+        Element::button(FocusId::new("lua-dry-run"), "Dry Run").build() // Disabled
+    };
     let preview_btn = if is_valid {
         Element::button(FocusId::new("lua-preview"), "Preview")
             .on_press(Msg::StartPreview)
@@ -329,6 +406,8 @@ fn render_script_view(
         .add(Element::text(""), LayoutConstraint::Fill(1))
         .add(validate_btn, LayoutConstraint::Length(12))
         .add(Element::text(""), LayoutConstraint::Length(1))
+        .add(dry_run_btn, LayoutConstraint::Length(11))
+        .add(Element::text(""), LayoutConstraint::Length(1))
         .add(preview_btn, LayoutConstraint::Length(12))
         .build();
 
@@ -383,6 +462,11 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
             "Validate",
             Msg::Validate,
         ));
+        subs.push(Subscription::keyboard(
+            KeyCode::Char('d'),
+            "Dry run",
+            Msg::DryRun,
+        ));
         subs.push(Subscription::keyboard(
             KeyCode::Char('p'),
             "Preview",
@@ -390,5 +474,18 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
         ));
     }
 
+    // Poll the script's progress while the dry run is running
+    if matches!(state.dry_run, Resource::Loading) {
+        subs.push(Subscription::keyboard(
+            KeyCode::Char('x'),
+            "Cancel dry run",
+            Msg::CancelDryRun,
+        ));
+        subs.push(Subscription::timer(
+            Duration::from_millis(100),
+            Msg::DryRunTick,
+        ));
+    }
+
     subs
 }
@@ -6,6 +6,8 @@ use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 
 use crate::api::metadata::FieldMetadata;
+use crate::api::query::QueryResult;
+use crate::config::repository::fetch_checkpoints::FetchCheckpoint;
 use crate::config::repository::transfer::get_transfer_config;
 use crate::transfer::{
     ExpandTree, LookupBindingContext, RecordAction, ResolvedTransfer, TransferConfig, TransferMode,
@@ -17,6 +19,9 @@ use crate::tui::{App, AppId, Command, LayeredView, Subscription};
 use super::state::{Msg, PreviewParams, RecordDetailState, RecordFilter, State};
 use super::view;
 
+/// Most entity fetch chains run at once, so large configs don't flood the rate limiter
+const MAX_CONCURRENT_FETCHES: usize = 4;
+
 /// Transfer Preview App - shows resolved records before execution
 pub struct TransferPreviewApp;
 
@@ -32,6 +37,7 @@ impl App for TransferPreviewApp {
             config_name: params.config_name.clone(),
             source_env: params.source_env.clone(),
             target_env: params.target_env.clone(),
+            warm_cache_only: params.warm_cache_only,
             resolved: Resource::Loading,
             ..Default::default()
         };
@@ -52,6 +58,13 @@ impl App for TransferPreviewApp {
 
                         // Branch based on mode
                         if config.mode == TransferMode::Lua {
+                            if state.warm_cache_only {
+                                state.resolved = Resource::Failure(
+                                    "Cache warming is only available for declarative configs"
+                                        .to_string(),
+                                );
+                                return Command::None;
+                            }
                             return handle_lua_mode_config(state, config);
                         }
 
@@ -96,15 +109,21 @@ impl App for TransferPreviewApp {
                         for entity in source_entities {
                             let env = config.source_env.clone();
                             let e = entity.clone();
-                            builder = builder
-                                .add_task(format!("Source: {}", e), fetch_source_metadata(env, e));
+                            let cache_ttl = config.cache_ttl_override(&e);
+                            builder = builder.add_task(
+                                format!("Source: {}", e),
+                                fetch_source_metadata(env, e, cache_ttl),
+                            );
                         }
 
                         for entity in target_entities {
                             let env = config.target_env.clone();
                             let e = entity.clone();
-                            builder = builder
-                                .add_task(format!("Target: {}", e), fetch_target_metadata(env, e));
+                            let cache_ttl = config.cache_ttl_override(&e);
+                            builder = builder.add_task(
+                                format!("Target: {}", e),
+                                fetch_target_metadata(env, e, cache_ttl),
+                            );
                         }
 
                         builder.on_complete(AppId::TransferPreview).build(
@@ -262,8 +281,18 @@ impl App for TransferPreviewApp {
             // Data loading - Step 2: Fetch records (after both source and target metadata are loaded)
             Msg::FetchRecords => {
                 if let Some(ref config) = state.config {
+                    // Warming always refetches so the cache is fresh for the next preview
+                    let force_refresh = state.warm_cache_only;
+                    let title = if force_refresh {
+                        "Warming Cache"
+                    } else {
+                        "Fetching Records"
+                    };
+
                     // Build parallel fetch tasks for loading screen
-                    let mut builder = Command::perform_parallel().with_title("Fetching Records");
+                    let mut builder = Command::perform_parallel()
+                        .with_title(title)
+                        .max_concurrency(MAX_CONCURRENT_FETCHES);
 
                     let num_entities = config.entity_mappings.len();
 
@@ -394,6 +423,7 @@ impl App for TransferPreviewApp {
                             log::info!("[{}] Expands: {:?}", entity, expands);
                         }
 
+                        let cache_ttl = config.cache_ttl_override(&entity);
                         builder = builder.add_task_with_progress(
                             format!("Source: {}", entity),
                             move |progress| {
@@ -404,9 +434,10 @@ impl App for TransferPreviewApp {
                                     source_fields,
                                     expands,
                                     Some(progress),
-                                    false,
+                                    force_refresh,
+                                    cache_ttl,
                                 )
-                            }, // use cache
+                            },
                         );
                     }
 
@@ -428,6 +459,13 @@ impl App for TransferPreviewApp {
                             .cloned()
                             .unwrap_or_else(|| format!("{}id", entity));
                         target_fields.push(pk_field);
+                        // Resolvers looking up this entity match against its target data
+                        target_fields.extend(
+                            config
+                                .resolver_match_fields(&entity)
+                                .into_iter()
+                                .map(String::from),
+                        );
 
                         // Replace lookup fields with _fieldname_value (from target metadata)
                         if let Some(fields) = state.target_metadata.get(&mapping.target_entity) {
@@ -462,6 +500,7 @@ impl App for TransferPreviewApp {
                         // Target fetch doesn't need expands - we compare final values
                         let no_expands: Vec<String> = vec![];
 
+                        let cache_ttl = config.cache_ttl_override(&entity);
                         builder = builder.add_task_with_progress(
                             format!("Target: {}", entity),
                             move |progress| {
@@ -472,9 +511,10 @@ impl App for TransferPreviewApp {
                                     target_fields,
                                     no_expands,
                                     Some(progress),
-                                    false,
+                                    force_refresh,
+                                    cache_ttl,
                                 )
-                            }, // use cache
+                            },
                         );
                     }
 
@@ -559,6 +599,7 @@ impl App for TransferPreviewApp {
                         let no_expands: Vec<String> = vec![];
                         let entity_clone = entity.clone();
 
+                        let cache_ttl = config.cache_ttl_override(&entity);
                         builder = builder.add_task_with_progress(
                             format!("Resolver: {}", entity),
                             move |progress| {
@@ -569,7 +610,8 @@ impl App for TransferPreviewApp {
                                     resolver_fields,
                                     no_expands,
                                     Some(progress),
-                                    false,
+                                    force_refresh,
+                                    cache_ttl,
                                 )
                             },
                         );
@@ -595,6 +637,23 @@ impl App for TransferPreviewApp {
             Msg::FetchResult(result) => {
                 match result {
                     Ok((entity_name, is_source, records)) => {
+                        if state.warm_cache_only {
+                            // Already written to the cache by the fetch - nothing to keep
+                            state.warmed_entities += 1;
+                            state.warmed_records += records.len();
+                            state.pending_fetches = state.pending_fetches.saturating_sub(1);
+                            if state.pending_fetches == 0 {
+                                log::info!(
+                                    "Cache warmed for '{}': {} entities, {} records",
+                                    state.config_name,
+                                    state.warmed_entities,
+                                    state.warmed_records
+                                );
+                                state.resolved = Resource::NotAsked;
+                            }
+                            return Command::None;
+                        }
+
                         if is_source {
                             state.source_data.insert(entity_name, records);
                         } else {
@@ -645,9 +704,10 @@ impl App for TransferPreviewApp {
                         for entity in missing_target_metadata {
                             let env = target_env.clone();
                             let e = entity.clone();
+                            let cache_ttl = config.cache_ttl_override(&e);
                             builder = builder.add_task(
                                 format!("Metadata: {}", e),
-                                fetch_entity_metadata(env, e),
+                                fetch_entity_metadata(env, e, cache_ttl),
                             );
                         }
 
@@ -708,9 +768,10 @@ impl App for TransferPreviewApp {
                         for entity in missing_lookup_targets {
                             let env = target_env.clone();
                             let e = entity.clone();
+                            let cache_ttl = config.cache_ttl_override(&e);
                             builder = builder.add_task(
                                 format!("Metadata: {}", e),
-                                fetch_entity_metadata(env, e),
+                                fetch_entity_metadata(env, e, cache_ttl),
                             );
                         }
 
@@ -768,6 +829,7 @@ impl App for TransferPreviewApp {
                         &state.source_data,
                         &state.target_data,
                         &primary_keys,
+                        crate::global_runtime_config().lua_module_dir.as_deref(),
                     );
 
                     // Build lookup context for each entity (only for mapped fields)
@@ -798,31 +860,34 @@ impl App for TransferPreviewApp {
                                 .cloned()
                                 .collect();
 
-                            match LookupBindingContext::from_field_metadata(
+                            // Unbindable lookups only reject the records that write them
+                            let ctx = LookupBindingContext::from_field_metadata_partial(
                                 &fields_to_use,
                                 &state.entity_set_map,
-                            ) {
-                                Ok(ctx) => {
-                                    log::info!(
-                                        "[{}] Built lookup context with {} lookup fields (from {} mapped fields)",
-                                        entity.entity_name,
-                                        ctx.lookups.len(),
-                                        fields_to_use.len()
-                                    );
-                                    entity.set_lookup_context(ctx);
-                                }
-                                Err(e) => {
-                                    log::error!(
-                                        "[{}] Failed to build lookup context: {}",
-                                        entity.entity_name,
-                                        e
-                                    );
-                                    state.resolved = Resource::Failure(format!(
-                                        "Failed to build lookup context for {}: {}",
-                                        entity.entity_name, e
-                                    ));
-                                    return Command::None;
-                                }
+                            );
+                            log::info!(
+                                "[{}] Built lookup context with {} lookup fields (from {} mapped fields)",
+                                entity.entity_name,
+                                ctx.lookups.len(),
+                                fields_to_use.len()
+                            );
+                            for (field, e) in &ctx.unbound {
+                                log::warn!(
+                                    "[{}] Cannot bind lookup '{}': {}",
+                                    entity.entity_name,
+                                    field,
+                                    e
+                                );
+                            }
+                            entity.set_lookup_context(ctx);
+
+                            let marked = entity.mark_unbound_lookup_errors();
+                            if marked > 0 {
+                                log::warn!(
+                                    "[{}] Marked {} records as errors due to unbindable lookups",
+                                    entity.entity_name,
+                                    marked
+                                );
                             }
                         }
                     }
@@ -863,6 +928,7 @@ impl App for TransferPreviewApp {
                     state.horizontal_scroll = 0;
 
                     state.resolved = Resource::Success(resolved);
+                    return load_saved_edits(state.config_name.clone(), Msg::SavedEditsReloaded);
                 }
                 Command::None
             }
@@ -939,6 +1005,9 @@ impl App for TransferPreviewApp {
                     }
                 }
                 state.horizontal_scroll = 0;
+                if matches!(state.resolved, Resource::Success(_)) {
+                    return load_saved_edits(state.config_name.clone(), Msg::SavedEditsReloaded);
+                }
                 Command::None
             }
 
@@ -1021,6 +1090,14 @@ impl App for TransferPreviewApp {
 
                     let source_data = state.source_data.clone();
                     let target_data = state.target_data.clone();
+                    let info = crate::transfer::lua::TransformInfo {
+                        config_name: config.name.clone(),
+                        source_env: config.source_env.clone(),
+                        target_env: config.target_env.clone(),
+                    };
+                    let cancel_flag =
+                        std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+                    state.lua_transform_cancel = Some(cancel_flag.clone());
 
                     // Use parallel command to show loading screen with progress
                     return Command::perform_parallel()
@@ -1030,10 +1107,14 @@ impl App for TransferPreviewApp {
                                 script,
                                 source_data,
                                 target_data,
+                                info,
+                                cancel_flag,
                                 progress,
                             )
                         })
                         .on_complete(AppId::TransferPreview)
+                        .on_cancel(AppId::TransferPreview)
+                        .cancellable(true)
                         .build(|_idx, result| {
                             let data = result
                                 .downcast::<Result<
@@ -1052,17 +1133,24 @@ impl App for TransferPreviewApp {
 
             // Lua mode - transform complete, build resolved transfer
             Msg::LuaTransformComplete(result) => {
+                state.lua_transform_cancel = None;
                 match result {
                     Ok((operations, logs)) => {
                         // Log all captured messages
                         for log_msg in &logs {
                             match log_msg {
+                                crate::transfer::lua::LogMessage::Debug(msg) => {
+                                    log::debug!("[Lua DEBUG] {}", msg);
+                                }
                                 crate::transfer::lua::LogMessage::Info(msg) => {
                                     log::debug!("[Lua] {}", msg);
                                 }
                                 crate::transfer::lua::LogMessage::Warn(msg) => {
                                     log::debug!("[Lua WARN] {}", msg);
                                 }
+                                crate::transfer::lua::LogMessage::Error(msg) => {
+                                    log::debug!("[Lua ERROR] {}", msg);
+                                }
                             }
                         }
                         log::info!(
@@ -1155,31 +1243,30 @@ impl App for TransferPreviewApp {
                 Command::None
             }
 
+            Msg::CancelLuaTransform => {
+                if let Some(flag) = &state.lua_transform_cancel {
+                    log::info!("[Lua] Cancelling transform");
+                    flag.store(true, std::sync::atomic::Ordering::Relaxed);
+                }
+                Command::None
+            }
+
             // Navigation within table
             Msg::ListEvent(event) => {
                 // Count filtered records for proper navigation bounds
                 let item_count = if let Resource::Success(resolved) = &state.resolved {
-                    let query = state.search_field.value().to_lowercase();
                     resolved
                         .entities
                         .get(state.current_entity_idx)
                         .map(|e| {
                             // Count only records matching current filter and search
-                            e.records
-                                .iter()
-                                .filter(|r| state.filter.matches(r.action))
-                                .filter(|r| {
-                                    if query.is_empty() {
-                                        return true;
-                                    }
-                                    if r.source_id.to_string().to_lowercase().contains(&query) {
-                                        return true;
-                                    }
-                                    r.fields
-                                        .values()
-                                        .any(|v| format!("{:?}", v).to_lowercase().contains(&query))
-                                })
-                                .count()
+                            visible_source_ids(
+                                e,
+                                state.filter,
+                                state.search_field.value(),
+                                &state.sort_key,
+                            )
+                            .len()
                         })
                         .unwrap_or(0)
                 } else {
@@ -1279,6 +1366,28 @@ impl App for TransferPreviewApp {
                 Command::None
             }
 
+            // Sorting
+            Msg::SetSort(sort_key) => {
+                state.sort_key = sort_key;
+                state.list_state = crate::tui::widgets::ListState::with_selection();
+                Command::None
+            }
+
+            Msg::CycleSort => {
+                // Offer the first visible column as the field to sort by
+                let field = if let Resource::Success(resolved) = &state.resolved {
+                    resolved
+                        .entities
+                        .get(state.current_entity_idx)
+                        .and_then(|e| e.field_names.get(state.horizontal_scroll).cloned())
+                } else {
+                    None
+                };
+                state.sort_key = state.sort_key.next(field.as_deref());
+                state.list_state = crate::tui::widgets::ListState::with_selection();
+                Command::None
+            }
+
             Msg::SearchChanged(event) => {
                 state.search_field.handle_event(event, None);
                 // Reset list selection when search changes
@@ -1292,41 +1401,14 @@ impl App for TransferPreviewApp {
                 if let Some(idx) = state.list_state.selected() {
                     if let Resource::Success(ref mut resolved) = state.resolved {
                         if let Some(entity) = resolved.entities.get_mut(state.current_entity_idx) {
-                            // Get filtered record indices
-                            let filter = state.filter;
-                            let query = state.search_field.value().to_lowercase();
-
-                            let mut match_idx = 0;
-                            let mut target_source_id = None;
-                            for record in &entity.records {
-                                if !filter.matches(record.action) {
-                                    continue;
-                                }
-                                let matches_search = if query.is_empty() {
-                                    true
-                                } else if record
-                                    .source_id
-                                    .to_string()
-                                    .to_lowercase()
-                                    .contains(&query)
-                                {
-                                    true
-                                } else {
-                                    record
-                                        .fields
-                                        .values()
-                                        .any(|v| format!("{:?}", v).to_lowercase().contains(&query))
-                                };
-                                if !matches_search {
-                                    continue;
-                                }
-
-                                if match_idx == idx {
-                                    target_source_id = Some(record.source_id);
-                                    break;
-                                }
-                                match_idx += 1;
-                            }
+                            // Find the selected record in filtered + sorted order
+                            let target_source_id = find_visible_source_id(
+                                entity,
+                                state.filter,
+                                state.search_field.value(),
+                                &state.sort_key,
+                                idx,
+                            );
 
                             // Toggle skip on found record
                             if let Some(source_id) = target_source_id {
@@ -1353,32 +1435,46 @@ impl App for TransferPreviewApp {
                 if let Some(idx) = state.list_state.selected() {
                     if let Resource::Success(resolved) = &state.resolved {
                         if let Some(entity) = resolved.entities.get(state.current_entity_idx) {
-                            // Get filtered records to find the actual record
-                            let filtered: Vec<_> = entity
-                                .records
-                                .iter()
-                                .filter(|r| state.filter.matches(r.action))
-                                .filter(|r| {
-                                    let query = state.search_field.value().to_lowercase();
-                                    if query.is_empty() {
-                                        return true;
-                                    }
-                                    if r.source_id.to_string().to_lowercase().contains(&query) {
-                                        return true;
-                                    }
-                                    r.fields
-                                        .values()
-                                        .any(|v| format!("{:?}", v).to_lowercase().contains(&query))
-                                })
-                                .collect();
-
-                            if let Some(record) = filtered.get(idx) {
-                                state.record_detail_state = Some(RecordDetailState::new(
-                                    idx,
-                                    record.action,
-                                    &entity.field_names,
-                                    &record.fields,
-                                ));
+                            // Find the selected record in filtered + sorted order
+                            let record = find_visible_source_id(
+                                entity,
+                                state.filter,
+                                state.search_field.value(),
+                                &state.sort_key,
+                                idx,
+                            )
+                            .and_then(|id| entity.records.iter().find(|r| r.source_id == id));
+
+                            if let Some(record) = record {
+                                // Show old vs new values for updates when the target was fetched
+                                let target_diff = state
+                                    .target_data
+                                    .get(&entity.entity_name)
+                                    .filter(|_| record.action == RecordAction::Update)
+                                    .and_then(|targets| {
+                                        super::state::find_target_record(
+                                            targets,
+                                            &entity.primary_key_field,
+                                            &record.source_id,
+                                        )
+                                    })
+                                    .map(|target| {
+                                        super::state::compute_field_diff(
+                                            &entity.field_names,
+                                            &record.fields,
+                                            target,
+                                        )
+                                    });
+                                state.record_detail_state = Some(
+                                    RecordDetailState::new(
+                                        idx,
+                                        record.source_id,
+                                        record.action,
+                                        &entity.field_names,
+                                        &record.fields,
+                                    )
+                                    .with_target_diff(target_diff),
+                                );
                                 state.active_modal =
                                     Some(super::state::PreviewModal::RecordDetails {
                                         record_idx: idx,
@@ -1394,27 +1490,19 @@ impl App for TransferPreviewApp {
                 if let Some(idx) = state.list_state.selected() {
                     if let Resource::Success(resolved) = &state.resolved {
                         if let Some(entity) = resolved.entities.get(state.current_entity_idx) {
-                            let filtered: Vec<_> = entity
-                                .records
-                                .iter()
-                                .filter(|r| state.filter.matches(r.action))
-                                .filter(|r| {
-                                    let query = state.search_field.value().to_lowercase();
-                                    if query.is_empty() {
-                                        return true;
-                                    }
-                                    if r.source_id.to_string().to_lowercase().contains(&query) {
-                                        return true;
-                                    }
-                                    r.fields
-                                        .values()
-                                        .any(|v| format!("{:?}", v).to_lowercase().contains(&query))
-                                })
-                                .collect();
+                            let record = find_visible_source_id(
+                                entity,
+                                state.filter,
+                                state.search_field.value(),
+                                &state.sort_key,
+                                idx,
+                            )
+                            .and_then(|id| entity.records.iter().find(|r| r.source_id == id));
 
-                            if let Some(record) = filtered.get(idx) {
+                            if let Some(record) = record {
                                 let mut detail_state = RecordDetailState::new(
                                     idx,
+                                    record.source_id,
                                     record.action,
                                     &entity.field_names,
                                     &record.fields,
@@ -1566,72 +1654,32 @@ impl App for TransferPreviewApp {
                 if let Some(ref detail) = state.record_detail_state {
                     if let Resource::Success(ref mut resolved) = state.resolved {
                         if let Some(entity) = resolved.entities.get_mut(state.current_entity_idx) {
-                            // Find the actual record by filtering the same way
-                            let filter = state.filter;
-                            let query = state.search_field.value().to_lowercase();
-                            let record_idx = detail.record_idx;
-
-                            // First pass: find the record's source_id
-                            let mut match_idx = 0;
-                            let mut target_source_id = None;
-                            for record in &entity.records {
-                                if !filter.matches(record.action) {
-                                    continue;
-                                }
-                                let matches_search = if query.is_empty() {
-                                    true
-                                } else if record
-                                    .source_id
-                                    .to_string()
-                                    .to_lowercase()
-                                    .contains(&query)
-                                {
-                                    true
-                                } else {
-                                    record
-                                        .fields
-                                        .values()
-                                        .any(|v| format!("{:?}", v).to_lowercase().contains(&query))
-                                };
-                                if !matches_search {
-                                    continue;
-                                }
-
-                                if match_idx == record_idx {
-                                    target_source_id = Some(record.source_id);
-                                    break;
-                                }
-                                match_idx += 1;
-                            }
-
-                            // Second pass: apply changes to the found record
-                            if let Some(source_id) = target_source_id {
-                                // Find and update the record
-                                if let Some(record) =
-                                    entity.records.iter_mut().find(|r| r.source_id == source_id)
-                                {
-                                    // Apply action change
-                                    if detail.current_action != detail.original_action {
-                                        record.action = detail.current_action;
-                                        if detail.current_action != RecordAction::Error {
-                                            record.error = None;
-                                        }
+                            // Apply changes to the record the modal was opened for
+                            let source_id = detail.source_id;
+                            if let Some(record) =
+                                entity.records.iter_mut().find(|r| r.source_id == source_id)
+                            {
+                                // Apply action change
+                                if detail.current_action != detail.original_action {
+                                    record.action = detail.current_action;
+                                    if detail.current_action != RecordAction::Error {
+                                        record.error = None;
                                     }
+                                }
 
-                                    // Apply field changes
-                                    for field_state in &detail.fields {
-                                        if field_state.is_dirty {
-                                            let new_value = field_state.parse_value();
-                                            record
-                                                .fields
-                                                .insert(field_state.field_name.clone(), new_value);
-                                        }
+                                // Apply field changes
+                                for field_state in &detail.fields {
+                                    if field_state.is_dirty {
+                                        let new_value = field_state.parse_value();
+                                        record
+                                            .fields
+                                            .insert(field_state.field_name.clone(), new_value);
                                     }
                                 }
-
-                                // Mark as dirty in entity
-                                entity.mark_dirty(source_id);
                             }
+
+                            // Mark as dirty in entity
+                            entity.mark_dirty(source_id);
                         }
                     }
                 }
@@ -1641,6 +1689,56 @@ impl App for TransferPreviewApp {
                 Command::None
             }
 
+            Msg::CopyDetailField => {
+                if let (Some(detail), Resource::Success(resolved)) =
+                    (state.record_detail_state.as_mut(), &state.resolved)
+                {
+                    let selected = detail.fields_list_state.selected().unwrap_or(0);
+                    if let Some(field) = detail.fields.get(selected) {
+                        // Unsaved edits are copied as typed; otherwise the record's own value
+                        let stored = resolved
+                            .entities
+                            .get(state.current_entity_idx)
+                            .and_then(|entity| {
+                                entity
+                                    .records
+                                    .iter()
+                                    .find(|r| r.source_id == detail.source_id)
+                            })
+                            .and_then(|record| record.fields.get(&field.field_name))
+                            .filter(|_| !field.is_dirty)
+                            .map(super::state::format_value_for_edit);
+                        let text = stored.unwrap_or_else(|| field.input.value().to_string());
+                        let field_name = field.field_name.clone();
+                        let result = crate::tui::clipboard::copy_text(&text);
+                        detail.copy_status = Some(copy_status(result, &field_name));
+                    }
+                }
+                Command::None
+            }
+
+            Msg::CopyRecordAsJson => {
+                let json = match (&state.record_detail_state, &state.resolved) {
+                    (Some(detail), Resource::Success(resolved)) => resolved
+                        .entities
+                        .get(state.current_entity_idx)
+                        .and_then(|entity| {
+                            entity
+                                .records
+                                .iter()
+                                .find(|r| r.source_id == detail.source_id)
+                                .map(|r| super::state::record_to_json(r, &entity.field_names))
+                        }),
+                    _ => None,
+                };
+
+                if let (Some(json), Some(detail)) = (json, state.record_detail_state.as_mut()) {
+                    let result = crate::tui::clipboard::copy_text(&json);
+                    detail.copy_status = Some(copy_status(result, "record JSON"));
+                }
+                Command::None
+            }
+
             Msg::CancelRecordEdits => {
                 // Just close without saving
                 if let Some(ref mut detail) = state.record_detail_state {
@@ -1662,23 +1760,13 @@ impl App for TransferPreviewApp {
                 if let Resource::Success(resolved) = &state.resolved {
                     if let Some(entity) = resolved.entities.get(state.current_entity_idx) {
                         // Count filtered records for item_count
-                        let query = state.search_field.value().to_lowercase();
-                        let item_count = entity
-                            .records
-                            .iter()
-                            .filter(|r| state.filter.matches(r.action))
-                            .filter(|r| {
-                                if query.is_empty() {
-                                    return true;
-                                }
-                                if r.source_id.to_string().to_lowercase().contains(&query) {
-                                    return true;
-                                }
-                                r.fields
-                                    .values()
-                                    .any(|v| format!("{:?}", v).to_lowercase().contains(&query))
-                            })
-                            .count();
+                        let item_count = visible_source_ids(
+                            entity,
+                            state.filter,
+                            state.search_field.value(),
+                            &state.sort_key,
+                        )
+                        .len();
                         state
                             .list_state
                             .handle_event(event, item_count, state.viewport_height);
@@ -1692,6 +1780,9 @@ impl App for TransferPreviewApp {
                 // Reset to defaults when opening
                 state.bulk_action_scope = super::state::BulkActionScope::Filtered;
                 state.bulk_action_selection = super::state::BulkAction::MarkSkip;
+                state.bulk_set_field = crate::tui::widgets::TextInputField::new();
+                state.bulk_set_value = crate::tui::widgets::TextInputField::new();
+                state.bulk_set_error = None;
                 state.active_modal = Some(super::state::PreviewModal::BulkActions);
                 Command::None
             }
@@ -1706,130 +1797,87 @@ impl App for TransferPreviewApp {
                 Command::None
             }
 
+            Msg::BulkSetFieldChanged(event) => {
+                state.bulk_set_field.handle_event(event, None);
+                state.bulk_set_error = None;
+                Command::None
+            }
+
+            Msg::BulkSetValueChanged(event) => {
+                state.bulk_set_value.handle_event(event, None);
+                state.bulk_set_error = None;
+                Command::None
+            }
+
             Msg::ConfirmBulkAction => {
+                // SetField takes its field and value from the modal inputs
+                let action = match state.bulk_action_selection {
+                    super::state::BulkAction::SetField { .. } => {
+                        let field = state.bulk_set_field.value().trim().to_string();
+                        if field.is_empty() {
+                            return Command::None;
+                        }
+                        super::state::BulkAction::SetField {
+                            field,
+                            value: state.bulk_set_value.value().to_string(),
+                        }
+                    }
+                    ref other => other.clone(),
+                };
+
                 // Apply bulk action to records based on scope
                 if let Resource::Success(ref mut resolved) = state.resolved {
                     if let Some(entity) = resolved.entities.get_mut(state.current_entity_idx) {
-                        let filter = state.filter;
-                        let query = state.search_field.value().to_lowercase();
-
-                        // Get indices to apply action to based on scope
-                        let indices_to_apply: Vec<usize> = match state.bulk_action_scope {
-                            super::state::BulkActionScope::All => {
-                                (0..entity.records.len()).collect()
-                            }
-                            super::state::BulkActionScope::Filtered => entity
-                                .records
-                                .iter()
-                                .enumerate()
-                                .filter(|(_, r)| filter.matches(r.action))
-                                .filter(|(_, r)| {
-                                    if query.is_empty() {
-                                        return true;
-                                    }
-                                    if r.source_id.to_string().to_lowercase().contains(&query) {
-                                        return true;
-                                    }
-                                    r.fields
-                                        .values()
-                                        .any(|v| format!("{:?}", v).to_lowercase().contains(&query))
-                                })
-                                .map(|(i, _)| i)
-                                .collect(),
-                            super::state::BulkActionScope::Selected => {
-                                // Convert filtered indices to actual record indices
-                                let multi_selected = state.list_state.all_selected();
-                                let mut actual_indices = Vec::new();
-                                let mut filtered_idx = 0;
-                                for (actual_idx, record) in entity.records.iter().enumerate() {
-                                    if !filter.matches(record.action) {
-                                        continue;
-                                    }
-                                    let matches_search = if query.is_empty() {
-                                        true
-                                    } else if record
-                                        .source_id
-                                        .to_string()
-                                        .to_lowercase()
-                                        .contains(&query)
-                                    {
-                                        true
-                                    } else {
-                                        record.fields.values().any(|v| {
-                                            format!("{:?}", v).to_lowercase().contains(&query)
-                                        })
-                                    };
-                                    if !matches_search {
-                                        continue;
-                                    }
-                                    if multi_selected.contains(&filtered_idx) {
-                                        actual_indices.push(actual_idx);
-                                    }
-                                    filtered_idx += 1;
-                                }
-                                actual_indices
-                            }
-                        };
-
-                        // Collect source IDs to mark dirty after mutations
-                        let mut dirty_ids = Vec::new();
-
-                        // Apply action to selected records
-                        for idx in indices_to_apply {
-                            if let Some(record) = entity.records.get_mut(idx) {
-                                match state.bulk_action_selection {
-                                    super::state::BulkAction::MarkSkip => {
-                                        record.action = RecordAction::Skip;
-                                    }
-                                    super::state::BulkAction::UnmarkSkip => {
-                                        if record.action == RecordAction::Skip {
-                                            record.action = RecordAction::NoChange;
-                                        }
-                                    }
-                                    super::state::BulkAction::ResetToOriginal => {
-                                        // Reset to NoChange (would need original_action tracking for full reset)
-                                        record.action = RecordAction::NoChange;
-                                    }
-                                }
-                                dirty_ids.push(record.source_id);
-                            }
+                        let query = state.search_field.value();
+
+                        // Convert filtered + sorted indices to source IDs for the Selected scope
+                        let mut selected_ids = std::collections::HashSet::new();
+                        if state.bulk_action_scope == super::state::BulkActionScope::Selected {
+                            let visible =
+                                visible_source_ids(entity, state.filter, query, &state.sort_key);
+                            selected_ids.extend(
+                                state
+                                    .list_state
+                                    .all_selected()
+                                    .iter()
+                                    .filter_map(|i| visible.get(*i))
+                                    .copied(),
+                            );
                         }
 
-                        // Mark all affected records as dirty
-                        for source_id in dirty_ids {
-                            entity.mark_dirty(source_id);
+                        let indices = super::state::bulk_scope_indices(
+                            entity,
+                            state.bulk_action_scope,
+                            state.filter,
+                            query,
+                            &selected_ids,
+                        );
+                        let target_fields = state
+                            .target_metadata
+                            .get(&entity.entity_name)
+                            .map(Vec::as_slice)
+                            .unwrap_or_default();
+                        if let Err(e) = super::state::apply_bulk_action(
+                            entity,
+                            &indices,
+                            &action,
+                            target_fields,
+                        ) {
+                            state.bulk_set_error = Some(e);
+                            return Command::None;
                         }
                     }
                 }
+                state.bulk_set_error = None;
                 state.active_modal = None;
                 state.list_state.clear_multi_selection();
                 Command::None
             }
 
             // Excel export
-            Msg::ExportExcel => {
-                // Initialize export modal with current entity name as default filename
-                if let Resource::Success(resolved) = &state.resolved {
-                    if let Some(entity) = resolved.entities.get(state.current_entity_idx) {
-                        // Set default filename based on entity name
-                        let default_filename = format!("{}_resolved.xlsx", entity.entity_name);
-                        state.export_filename.set_value(default_filename);
-
-                        // Set filter to show directories only (for navigation)
-                        // But also show .xlsx files so user can see existing exports
-                        state.export_file_browser.set_filter(|entry| {
-                            entry.is_dir || entry.name.to_lowercase().ends_with(".xlsx")
-                        });
+            Msg::ExportExcel => open_export_modal(state, false),
 
-                        // Refresh to apply filter
-                        let _ = state.export_file_browser.refresh();
-
-                        state.active_modal = Some(super::state::PreviewModal::ExportExcel);
-                        return Command::set_focus(crate::tui::FocusId::new("export-file-browser"));
-                    }
-                }
-                Command::None
-            }
+            Msg::ExportErrors => open_export_modal(state, true),
 
             Msg::ExportFileNavigate(key) => {
                 use crate::tui::widgets::{FileBrowserAction, FileBrowserEvent};
@@ -1910,6 +1958,12 @@ impl App for TransferPreviewApp {
 
                         state.active_modal = None;
 
+                        if state.export_errors_only {
+                            return Command::perform(
+                                async move { export_errors_to_excel(entity_clone, path_str).await },
+                                Msg::ExportCompleted,
+                            );
+                        }
                         return Command::perform(
                             async move { export_entity_to_excel(entity_clone, path_str).await },
                             Msg::ExportCompleted,
@@ -2082,6 +2136,93 @@ impl App for TransferPreviewApp {
                 Command::None
             }
 
+            Msg::SaveEdits => {
+                let Resource::Success(ref resolved) = state.resolved else {
+                    return Command::None;
+                };
+                let edits = resolved.dirty_edits();
+                let config_name = state.config_name.clone();
+                Command::perform(
+                    async move {
+                        let pool = &crate::global_config().pool;
+                        crate::config::repository::preview_edits::save_preview_edits(
+                            pool,
+                            &config_name,
+                            &edits,
+                        )
+                        .await
+                        .map(|_| edits.len())
+                        .map_err(|e| e.to_string())
+                    },
+                    Msg::EditsSaved,
+                )
+            }
+
+            Msg::EditsSaved(result) => {
+                state.edits_status = Some(match result {
+                    Ok(count) => (format!("Saved {} edited records", count), false),
+                    Err(e) => {
+                        log::error!("Failed to save edits: {}", e);
+                        (format!("Save failed: {}", e), true)
+                    }
+                });
+                Command::None
+            }
+
+            Msg::LoadEdits => {
+                if !matches!(state.resolved, Resource::Success(_)) {
+                    return Command::None;
+                }
+                load_saved_edits(state.config_name.clone(), Msg::EditsLoaded)
+            }
+
+            Msg::EditsLoaded(result) => {
+                state.edits_status = Some(match result {
+                    Ok(edits) => {
+                        if let Resource::Success(ref mut resolved) = state.resolved {
+                            let applied = resolved.apply_edits(&edits);
+                            if let Some(entity) = resolved.entities.get(state.current_entity_idx) {
+                                state.column_widths = super::state::calculate_column_widths(entity);
+                            }
+                            (
+                                format!("Applied {} of {} saved edits", applied, edits.len()),
+                                false,
+                            )
+                        } else {
+                            ("No records loaded".to_string(), true)
+                        }
+                    }
+                    Err(e) => {
+                        log::error!("Failed to load edits: {}", e);
+                        (format!("Load failed: {}", e), true)
+                    }
+                });
+                Command::None
+            }
+
+            Msg::SavedEditsReloaded(result) => {
+                match result {
+                    Ok(edits) => {
+                        if let Resource::Success(ref mut resolved) = state.resolved {
+                            let applied = resolved.apply_saved_edits(&edits);
+                            if applied > 0 {
+                                log::info!("Re-applied {} saved edits", applied);
+                                if let Some(entity) =
+                                    resolved.entities.get(state.current_entity_idx)
+                                {
+                                    state.column_widths =
+                                        super::state::calculate_column_widths(entity);
+                                }
+                                state.edits_status =
+                                    Some((format!("Re-applied {} saved edits", applied), false));
+                            }
+                        }
+                    }
+                    Err(e) => log::warn!("Failed to reload saved edits: {}", e),
+                }
+                Command::None
+            }
+
             // Refresh - re-fetch data and re-run transforms
             Msg::Refresh => {
                 // Only refresh if we have a config and are not already loading
@@ -2103,7 +2244,9 @@ impl App for TransferPreviewApp {
                 state.target_data.clear();
 
                 // Build parallel fetch tasks (same as ConfigLoaded but uses existing config)
-                let mut builder = Command::perform_parallel().with_title("Refreshing Records");
+                let mut builder = Command::perform_parallel()
+                    .with_title("Refreshing Records")
+                    .max_concurrency(MAX_CONCURRENT_FETCHES);
 
                 let num_entities = config.entity_mappings.len();
 
@@ -2219,6 +2362,7 @@ impl App for TransferPreviewApp {
                     let expands = expand_tree
                         .build_expand_clauses(nav_prop_map.as_ref(), all_lookup_fields.as_ref());
 
+                    let cache_ttl = config.cache_ttl_override(&entity);
                     builder = builder.add_task_with_progress(
                         format!("Source: {}", entity),
                         move |progress| {
@@ -2230,6 +2374,7 @@ impl App for TransferPreviewApp {
                                 expands,
                                 Some(progress),
                                 true,
+                                cache_ttl,
                             )
                         }, // force refresh
                     );
@@ -2252,6 +2397,13 @@ impl App for TransferPreviewApp {
                         .cloned()
                         .unwrap_or_else(|| format!("{}id", entity));
                     target_fields.push(pk_field);
+                    // Resolvers looking up this entity match against its target data
+                    target_fields.extend(
+                        config
+                            .resolver_match_fields(&entity)
+                            .into_iter()
+                            .map(String::from),
+                    );
 
                     // Replace lookup fields with _fieldname_value (from target metadata)
                     if let Some(fields) = state.target_metadata.get(&mapping.target_entity) {
@@ -2279,6 +2431,7 @@ impl App for TransferPreviewApp {
 
                     let no_expands: Vec<String> = vec![];
 
+                    let cache_ttl = config.cache_ttl_override(&entity);
                     builder = builder.add_task_with_progress(
                         format!("Target: {}", entity),
                         move |progress| {
@@ -2290,6 +2443,7 @@ impl App for TransferPreviewApp {
                                 no_expands,
                                 Some(progress),
                                 true,
+                                cache_ttl,
                             )
                         }, // force refresh
                     );
@@ -2356,6 +2510,7 @@ impl App for TransferPreviewApp {
                     let no_expands: Vec<String> = vec![];
                     let entity_clone = entity.clone();
 
+                    let cache_ttl = config.cache_ttl_override(&entity);
                     builder = builder.add_task_with_progress(
                         format!("Resolver: {}", entity),
                         move |progress| {
@@ -2367,6 +2522,7 @@ impl App for TransferPreviewApp {
                                 no_expands,
                                 Some(progress),
                                 true,
+                                cache_ttl,
                             )
                         }, // force refresh
                     );
@@ -2392,7 +2548,13 @@ impl App for TransferPreviewApp {
             }
 
             // Navigation
-            Msg::Back => Command::navigate_to(AppId::TransferMappingEditor),
+            Msg::Back => {
+                if state.warm_cache_only {
+                    Command::navigate_to(AppId::TransferConfigList)
+                } else {
+                    Command::navigate_to(AppId::TransferMappingEditor)
+                }
+            }
 
             // Send to Queue
             Msg::OpenSendToQueue => {
@@ -2491,24 +2653,13 @@ impl App for TransferPreviewApp {
                 }
 
                 let entity = &resolved.entities[state.current_entity_idx];
-                let filtered_count = entity
-                    .records
-                    .iter()
-                    .filter(|r| state.filter.matches(r.action))
-                    .filter(|r| {
-                        let query = state.search_field.value().to_lowercase();
-                        if query.is_empty() {
-                            return true;
-                        }
-                        // Search in source_id and field values
-                        if r.source_id.to_string().to_lowercase().contains(&query) {
-                            return true;
-                        }
-                        r.fields
-                            .values()
-                            .any(|v| format!("{:?}", v).to_lowercase().contains(&query))
-                    })
-                    .count();
+                let filtered_count = visible_source_ids(
+                    entity,
+                    state.filter,
+                    state.search_field.value(),
+                    &state.sort_key,
+                )
+                .len();
 
                 Some(Line::from(vec![
                     Span::styled(
@@ -2604,6 +2755,14 @@ impl App for TransferPreviewApp {
                         format!(" ({})", filtered_count),
                         Style::default().fg(theme.text_secondary),
                     ),
+                    if state.sort_key != super::state::SortKey::SourceOrder {
+                        Span::styled(
+                            format!(" | Sort: {}", state.sort_key.display_name()),
+                            Style::default().fg(theme.text_secondary),
+                        )
+                    } else {
+                        Span::raw("")
+                    },
                     // Show selection count if multi-selection is active
                     if state.list_state.has_multi_selection() {
                         Span::styled(
@@ -2613,6 +2772,18 @@ impl App for TransferPreviewApp {
                     } else {
                         Span::raw("")
                     },
+                    // Result of the last edit-set save/load
+                    match &state.edits_status {
+                        Some((message, is_error)) => Span::styled(
+                            format!(" | {}", message),
+                            Style::default().fg(if *is_error {
+                                theme.accent_error
+                            } else {
+                                theme.accent_success
+                            }),
+                        ),
+                        None => Span::raw(""),
+                    },
                 ]))
             }
         }
@@ -2646,9 +2817,125 @@ async fn load_config(config_name: String) -> Result<TransferConfig, String> {
         .ok_or_else(|| format!("Config '{}' not found", config_name))
 }
 
-/// Fetch all records for an entity from an environment
-/// Returns (entity_name, is_source, records)
-///
+/// Weight of the newest page when smoothing fetch throughput
+const THROUGHPUT_SMOOTHING: f64 = 0.3;
+
+/// Pages that must complete before an ETA is shown
+const MIN_PAGES_FOR_ETA: usize = 2;
+
+/// Exponentially weighted moving average of page throughput (records per second)
+///
+/// Recent pages dominate the estimate, so a slow first page (auth, cold caches)
+/// stops skewing the ETA once a few pages have arrived.
+#[derive(Debug, Default)]
+struct ThroughputEstimator {
+    records_per_sec: Option<f64>,
+    pages: usize,
+}
+
+impl ThroughputEstimator {
+    /// Fold one completed page into the average
+    fn record_page(&mut self, records: usize, elapsed: std::time::Duration) {
+        self.pages += 1;
+        let secs = elapsed.as_secs_f64();
+        if secs <= 0.0 {
+            return;
+        }
+        let sample = records as f64 / secs;
+        self.records_per_sec = Some(match self.records_per_sec {
+            Some(avg) => THROUGHPUT_SMOOTHING * sample + (1.0 - THROUGHPUT_SMOOTHING) * avg,
+            None => sample,
+        });
+    }
+
+    /// Estimated seconds to fetch the remaining records, once enough pages have completed
+    fn eta_secs(&self, remaining: u64) -> Option<u64> {
+        if self.pages < MIN_PAGES_FOR_ETA {
+            return None;
+        }
+        self.records_per_sec
+            .filter(|rate| *rate > 0.0)
+            .map(|rate| (remaining as f64 / rate) as u64)
+    }
+}
+
+/// Where a record fetch starts
+#[derive(Debug, PartialEq)]
+enum FetchStart {
+    /// Run the query from the first page
+    Fresh,
+    /// Continue after the last page of an interrupted fetch
+    Resume(FetchCheckpoint),
+}
+
+/// Resume from a checkpoint only when it was taken for the same query within the TTL
+///
+/// A forced refresh always starts over, like it bypasses the entity data cache.
+fn resume_point(
+    checkpoint: Option<FetchCheckpoint>,
+    query_key: &str,
+    force_refresh: bool,
+    max_age_minutes: i64,
+) -> FetchStart {
+    if force_refresh {
+        return FetchStart::Fresh;
+    }
+    match checkpoint {
+        Some(checkpoint)
+            if checkpoint.query == query_key
+                && chrono::Utc::now()
+                    .signed_duration_since(checkpoint.updated_at)
+                    .num_minutes()
+                    < max_age_minutes =>
+        {
+            FetchStart::Resume(checkpoint)
+        }
+        _ => FetchStart::Fresh,
+    }
+}
+
+/// Fetch the first page to process
+///
+/// When resuming, requests the page after the checkpoint via `next_page` and
+/// returns the checkpoint alongside it. Otherwise, or when the stored link no
+/// longer works, runs the query from the start via `first_page`.
+async fn open_fetch<N, NF, F, FF>(
+    start: FetchStart,
+    entity_name: &str,
+    next_page: N,
+    first_page: F,
+) -> anyhow::Result<(QueryResult, Option<FetchCheckpoint>)>
+where
+    N: FnOnce(String) -> NF,
+    NF: std::future::Future<Output = anyhow::Result<QueryResult>>,
+    F: FnOnce() -> FF,
+    FF: std::future::Future<Output = anyhow::Result<QueryResult>>,
+{
+    if let FetchStart::Resume(checkpoint) = start {
+        log::info!(
+            "[{}] Resuming fetch after page {} ({} records)",
+            entity_name,
+            checkpoint.pages,
+            checkpoint.fetched_count
+        );
+        match next_page(checkpoint.next_link.clone()).await {
+            Ok(result) if result.fetch_error().is_none() => {
+                return Ok((result, Some(checkpoint)));
+            }
+            Ok(_) | Err(_) => {
+                log::warn!(
+                    "[{}] Checkpointed page link no longer works, starting over",
+                    entity_name
+                );
+            }
+        }
+    }
+    Ok((first_page().await?, None))
+}
+
+/// Fetch all records for an entity from an environment
+/// Returns (entity_name, is_source, records)
+///
 /// If `force_refresh` is false, checks SQLite cache first (1 hour TTL).
 /// Always saves fetched data to cache after API call.
 async fn fetch_entity_records(
@@ -2658,23 +2945,58 @@ async fn fetch_entity_records(
     fields: Vec<String>,  // Fields to select (for performance)
     expands: Vec<String>, // Expand clauses for lookup traversals
     progress: Option<crate::tui::command::ProgressSender>,
-    force_refresh: bool, // If true, bypass cache and fetch fresh
+    force_refresh: bool,    // If true, bypass cache and fetch fresh
+    cache_ttl: Option<u32>, // Per-entity TTL override in minutes
 ) -> Result<(String, bool, Vec<serde_json::Value>), String> {
-    use crate::api::pluralization::pluralize_entity_name;
-    use crate::api::query::QueryBuilder;
-
     let config = crate::global_config();
+    let records = load_entity_records(
+        config,
+        &env_name,
+        &entity_name,
+        force_refresh,
+        cache_ttl,
+        progress.as_ref(),
+        || {
+            fetch_records_from_api(
+                &env_name,
+                &entity_name,
+                fields,
+                expands,
+                progress.as_ref(),
+                force_refresh,
+                cache_ttl,
+            )
+        },
+    )
+    .await?;
 
-    // Check cache first (1 hour TTL) unless force_refresh
+    Ok((entity_name, is_source, records))
+}
+
+/// Serve an entity's records from the cache, or fetch them and cache the result
+///
+/// A forced refresh (as used by cache warming) skips the lookup but still
+/// writes the freshly fetched records back.
+async fn load_entity_records<F, FF>(
+    config: &crate::config::Config,
+    env_name: &str,
+    entity_name: &str,
+    force_refresh: bool,
+    cache_ttl: Option<u32>,
+    progress: Option<&crate::tui::command::ProgressSender>,
+    fetch: F,
+) -> Result<Vec<serde_json::Value>, String>
+where
+    F: FnOnce() -> FF,
+    FF: std::future::Future<Output = Result<Vec<serde_json::Value>, String>>,
+{
+    // Check cache first (per-entity TTL, falling back to the global default) unless force_refresh
     if !force_refresh {
         if let Some(ref tx) = progress {
             let _ = tx.send("Checking cache...".to_string());
         }
 
-        match config
-            .get_entity_data_cache(&env_name, &entity_name, 1)
-            .await
-        {
+        match cached_entity_records(config, env_name, entity_name, cache_ttl).await {
             Ok(Some(cached_data)) => {
                 log::info!(
                     "✅ Using cached data for {} from {} ({} records)",
@@ -2685,7 +3007,7 @@ async fn fetch_entity_records(
                 if let Some(ref tx) = progress {
                     let _ = tx.send(format!("{} (cached)", cached_data.len()));
                 }
-                return Ok((entity_name, is_source, cached_data));
+                return Ok(cached_data);
             }
             Ok(None) => {
                 log::info!("[{}] No valid cache, fetching from API", entity_name);
@@ -2702,6 +3024,36 @@ async fn fetch_entity_records(
         log::info!("[{}] Force refresh - bypassing cache", entity_name);
     }
 
+    let records = fetch().await?;
+
+    // Save to cache for future use
+    if let Err(e) = config
+        .set_entity_data_cache(env_name, entity_name, &records)
+        .await
+    {
+        log::warn!("[{}] Failed to cache data: {}", entity_name, e);
+    } else {
+        log::info!("[{}] Cached {} records", entity_name, records.len());
+    }
+
+    Ok(records)
+}
+
+/// Page through an entity's records from the API, resuming from a fetch checkpoint when possible
+async fn fetch_records_from_api(
+    env_name: &str,
+    entity_name: &str,
+    fields: Vec<String>,
+    expands: Vec<String>,
+    progress: Option<&crate::tui::command::ProgressSender>,
+    force_refresh: bool,
+    cache_ttl: Option<u32>,
+) -> Result<Vec<serde_json::Value>, String> {
+    use crate::api::pluralization::pluralize_entity_name;
+    use crate::api::query::QueryBuilder;
+
+    let config = crate::global_config();
+
     let manager = crate::client_manager();
     let client = manager
         .get_client(&env_name)
@@ -2711,28 +3063,20 @@ async fn fetch_entity_records(
     let entity_set = pluralize_entity_name(&entity_name);
 
     // First: get real count via FetchXML aggregate (OData $count caps at 5000)
-    let count_fetchxml = format!(
-        r#"<fetch aggregate="true"><entity name="{}"><attribute name="{}id" aggregate="count" alias="total"/></entity></fetch>"#,
-        entity_name, entity_name
-    );
-
-    let total_count: Option<u64> =
-        match client.execute_fetchxml(&entity_name, &count_fetchxml).await {
-            Ok(result) => result
-                .get("value")
-                .and_then(|v| v.as_array())
-                .and_then(|arr| arr.first())
-                .and_then(|obj| obj.get("total"))
-                .and_then(|t| t.as_u64()),
-            Err(e) => {
-                log::warn!(
-                    "[{}] Count query failed, progress will show records only: {}",
-                    entity_name,
-                    e
-                );
-                None
-            }
-        };
+    let total_count: Option<u64> = match QueryBuilder::new(&entity_set)
+        .execute_count(&client, &entity_name)
+        .await
+    {
+        Ok(total) => Some(total),
+        Err(e) => {
+            log::warn!(
+                "[{}] Count query failed, progress will show records only: {}",
+                entity_name,
+                e
+            );
+            None
+        }
+    };
     log::info!("[{}] Total count: {:?}", entity_name, total_count);
 
     // Report initial progress
@@ -2772,16 +3116,58 @@ async fn fetch_entity_records(
     let query = builder.build();
     log::info!("[{}] Executing query: {:?}", entity_name, query);
 
-    let mut result = client
-        .execute_query(&query)
-        .await
-        .map_err(|e| format!("Query failed for {}: {}", entity_name, e))?;
+    // Resume an interrupted fetch of the same query from its last checkpointed page
+    let query_key = query.to_url("");
+    let checkpoint = match config.get_fetch_checkpoint(&env_name, &entity_name).await {
+        Ok(checkpoint) => checkpoint,
+        Err(e) => {
+            log::warn!("[{}] Failed to load fetch checkpoint: {}", entity_name, e);
+            None
+        }
+    };
+
+    let checkpoint_ttl = config.transfer_cache_ttl_minutes(cache_ttl).await;
+    let start = resume_point(checkpoint, &query_key, force_refresh, checkpoint_ttl);
+
+    let mut throughput = ThroughputEstimator::default();
+    let mut page_start = std::time::Instant::now();
+    let client_ref = &client;
+    let (mut result, resumed) = open_fetch(
+        start,
+        &entity_name,
+        |next_link| async move {
+            client_ref
+                .execute_next_page(&next_link, Some(PAGE_SIZE))
+                .await
+        },
+        || async {
+            // Starting over: drop any stale or mismatched checkpoint first
+            if let Err(e) = config
+                .delete_fetch_checkpoint(&env_name, &entity_name)
+                .await
+            {
+                log::warn!("[{}] Failed to clear fetch checkpoint: {}", entity_name, e);
+            }
+            client.execute_query(&query).await
+        },
+    )
+    .await
+    .map_err(|e| format!("Query failed for {}: {}", entity_name, e))?;
+    if let Some(checkpoint) = resumed {
+        page = checkpoint.pages;
+        all_records = checkpoint.records;
+    }
 
     // Check for API errors (e.g., invalid field names return 400)
-    if result.is_error() {
-        let error_msg = result.error.as_deref().unwrap_or("Unknown error");
-        log::error!("[{}] API error: {}", entity_name, error_msg);
-        return Err(format!("[{}] API error: {}", entity_name, error_msg));
+    if let Some(error) = result.fetch_error() {
+        log::error!("[{}] API error: {}", entity_name, error);
+        if error.is_auth() {
+            return Err(format!(
+                "[{}] {}. Sign in to '{}' again from the environment settings and retry.",
+                entity_name, error, env_name
+            ));
+        }
+        return Err(format!("[{}] API error: {}", entity_name, error));
     }
 
     let initial_count = result.data.as_ref().map(|d| d.value.len()).unwrap_or(0);
@@ -2807,44 +3193,56 @@ async fn fetch_entity_records(
 
     loop {
         page += 1;
-        let page_start = std::time::Instant::now();
+        let page_elapsed = page_start.elapsed();
 
         let page_records = result.data.as_ref().map(|d| d.value.len()).unwrap_or(0);
+        throughput.record_page(page_records, page_elapsed);
 
         log::info!(
             "[{}] ✅ Page {} fetched in {}ms ({} records)",
             entity_name,
             page,
-            page_start.elapsed().as_millis(),
+            page_elapsed.as_millis(),
             page_records
         );
 
         if let Some(ref data) = result.data {
             all_records.extend(data.value.clone());
+
+            // Checkpoint the page so an interrupted fetch can resume after it
+            if let Some(next_link) = result.next_link() {
+                if let Err(e) = config
+                    .save_fetch_checkpoint_page(
+                        &env_name,
+                        &entity_name,
+                        &query_key,
+                        page,
+                        &data.value,
+                        next_link,
+                        all_records.len(),
+                    )
+                    .await
+                {
+                    log::warn!("[{}] Failed to save fetch checkpoint: {}", entity_name, e);
+                }
+            }
         }
 
         // Report progress with ETA
         let progress_msg = match total_count {
             Some(total) => {
                 let fetched = all_records.len() as u64;
-                let elapsed = fetch_start.elapsed();
-
-                // Calculate ETA based on records per second
-                let eta_str = if fetched > 0 && fetched < total {
-                    let records_per_sec = fetched as f64 / elapsed.as_secs_f64();
-                    if records_per_sec > 0.0 {
-                        let remaining = total - fetched;
-                        let eta_secs = (remaining as f64 / records_per_sec) as u64;
+
+                // ETA from smoothed recent page throughput, once it has settled
+                let eta_str = match throughput.eta_secs(total.saturating_sub(fetched)) {
+                    Some(eta_secs) if fetched < total => {
                         if eta_secs >= 60 {
                             format!(" (~{}m {}s left)", eta_secs / 60, eta_secs % 60)
                         } else {
                             format!(" (~{}s left)", eta_secs)
                         }
-                    } else {
-                        String::new()
                     }
-                } else {
-                    String::new()
+                    _ => String::new(),
                 };
                 format!("{}/{}{}", fetched, total, eta_str)
             }
@@ -2860,7 +3258,7 @@ async fn fetch_entity_records(
         }
 
         // Fetch next page
-        let next_start = std::time::Instant::now();
+        page_start = std::time::Instant::now();
         result = result
             .next_page(&client, Some(PAGE_SIZE))
             .await
@@ -2870,10 +3268,17 @@ async fn fetch_entity_records(
         log::debug!(
             "[{}] Next page request took {}ms",
             entity_name,
-            next_start.elapsed().as_millis()
+            page_start.elapsed().as_millis()
         );
     }
 
+    if let Err(e) = config
+        .delete_fetch_checkpoint(&env_name, &entity_name)
+        .await
+    {
+        log::warn!("[{}] Failed to clear fetch checkpoint: {}", entity_name, e);
+    }
+
     let total_time = fetch_start.elapsed();
     log::info!(
         "✅ Fetched {} records for {} from {} in {}ms",
@@ -2883,17 +3288,49 @@ async fn fetch_entity_records(
         total_time.as_millis()
     );
 
-    // Save to cache for future use
-    if let Err(e) = config
-        .set_entity_data_cache(&env_name, &entity_name, &all_records)
+    Ok(all_records)
+}
+
+/// Look up cached records for an entity, honouring the per-entity TTL override
+async fn cached_entity_records(
+    config: &crate::config::Config,
+    env_name: &str,
+    entity_name: &str,
+    cache_ttl: Option<u32>,
+) -> anyhow::Result<Option<Vec<serde_json::Value>>> {
+    let ttl = config.transfer_cache_ttl_minutes(cache_ttl).await;
+    config
+        .get_entity_data_cache_minutes(env_name, entity_name, ttl)
         .await
-    {
-        log::warn!("[{}] Failed to cache data: {}", entity_name, e);
-    } else {
-        log::info!("[{}] Cached {} records", entity_name, all_records.len());
-    }
+}
 
-    Ok((entity_name, is_source, all_records))
+/// Open the export modal for the current entity
+///
+/// When `errors_only` is set, only `RecordAction::Error` records are written.
+fn open_export_modal(state: &mut State, errors_only: bool) -> Command<Msg> {
+    // Initialize export modal with current entity name as default filename
+    if let Resource::Success(resolved) = &state.resolved {
+        if let Some(entity) = resolved.entities.get(state.current_entity_idx) {
+            // Set default filename based on entity name
+            let suffix = if errors_only { "errors" } else { "resolved" };
+            let default_filename = format!("{}_{}.xlsx", entity.entity_name, suffix);
+            state.export_filename.set_value(default_filename);
+            state.export_errors_only = errors_only;
+
+            // Set filter to show directories only (for navigation)
+            // But also show .xlsx files so user can see existing exports
+            state
+                .export_file_browser
+                .set_filter(|entry| entry.is_dir || entry.name.to_lowercase().ends_with(".xlsx"));
+
+            // Refresh to apply filter
+            let _ = state.export_file_browser.refresh();
+
+            state.active_modal = Some(super::state::PreviewModal::ExportExcel);
+            return Command::set_focus(crate::tui::FocusId::new("export-file-browser"));
+        }
+    }
+    Command::None
 }
 
 /// Export a ResolvedEntity to an Excel file
@@ -2912,6 +3349,26 @@ async fn export_entity_to_excel(
     result.map_err(|e| format!("Export failed: {}", e))
 }
 
+/// Export only the error records of a ResolvedEntity to an Excel file
+async fn export_errors_to_excel(
+    entity: crate::transfer::ResolvedEntity,
+    path: String,
+) -> Result<String, String> {
+    use crate::transfer::excel::resolved::write_errors_excel;
+
+    // The write function is synchronous, so we wrap it in spawn_blocking
+    let result = tokio::task::spawn_blocking(move || {
+        write_errors_excel(&entity, &path).map(|count| {
+            log::info!("Exported {} error records", count);
+            path
+        })
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))?;
+
+    result.map_err(|e| format!("Export failed: {}", e))
+}
+
 /// Preview an import by reading the Excel file and detecting conflicts
 async fn preview_import(
     entity: crate::transfer::ResolvedEntity,
@@ -2969,6 +3426,43 @@ async fn apply_import(
     result
 }
 
+/// Source IDs of an entity's records in table order (filter, search, then sort)
+fn visible_source_ids(
+    entity: &crate::transfer::ResolvedEntity,
+    filter: RecordFilter,
+    search_query: &str,
+    sort_key: &super::state::SortKey,
+) -> Vec<uuid::Uuid> {
+    view::get_filtered_records(entity, filter, search_query, sort_key)
+        .iter()
+        .map(|r| r.source_id)
+        .collect()
+}
+
+/// Status line for a copy-to-clipboard attempt (message, is_error)
+fn copy_status(result: Result<(), String>, what: &str) -> (String, bool) {
+    match result {
+        Ok(()) => (format!("Copied {} to clipboard", what), false),
+        Err(e) => {
+            log::warn!("Clipboard unavailable: {}", e);
+            (format!("Clipboard unavailable: {}", e), true)
+        }
+    }
+}
+
+/// Find the source ID of the record shown at `idx` in the table
+fn find_visible_source_id(
+    entity: &crate::transfer::ResolvedEntity,
+    filter: RecordFilter,
+    search_query: &str,
+    sort_key: &super::state::SortKey,
+    idx: usize,
+) -> Option<uuid::Uuid> {
+    visible_source_ids(entity, filter, search_query, sort_key)
+        .get(idx)
+        .copied()
+}
+
 /// Build queue items from resolved transfer
 fn build_queue_items_from_resolved(
     resolved: &ResolvedTransfer,
@@ -3054,7 +3548,11 @@ fn check_and_fetch_related_metadata(state: &mut State) -> Option<Command<Msg>> {
     for entity in needed_entities {
         let env = source_env.clone();
         let e = entity.clone();
-        builder = builder.add_task(format!("Related: {}", e), fetch_source_metadata(env, e));
+        let cache_ttl = config.cache_ttl_override(&e);
+        builder = builder.add_task(
+            format!("Related: {}", e),
+            fetch_source_metadata(env, e, cache_ttl),
+        );
     }
 
     Some(builder
@@ -3073,6 +3571,7 @@ fn check_and_fetch_related_metadata(state: &mut State) -> Option<Command<Msg>> {
 async fn fetch_source_metadata(
     env_name: String,
     entity_name: String,
+    cache_ttl: Option<u32>,
 ) -> Result<(String, Vec<crate::api::metadata::FieldMetadata>, String), String> {
     log::debug!(
         "[{}] fetch_source_metadata START for env={}",
@@ -3082,9 +3581,10 @@ async fn fetch_source_metadata(
 
     let config = crate::global_config();
 
-    // Check cache first (1 hour TTL)
+    // Check cache first (per-entity TTL, falling back to the global default)
+    let ttl = config.transfer_cache_ttl_minutes(cache_ttl).await;
     match config
-        .get_entity_metadata_cache(&env_name, &entity_name, 1)
+        .get_entity_metadata_cache_minutes(&env_name, &entity_name, ttl)
         .await
     {
         Ok(Some(cached)) if cached.primary_id_attribute.is_some() => {
@@ -3115,46 +3615,22 @@ async fn fetch_source_metadata(
         }
     }
 
-    // Fetch from API
-    let manager = crate::client_manager();
-    let client = manager
-        .get_client(&env_name)
-        .await
-        .map_err(|e| format!("Failed to get client for {}: {}", env_name, e))?;
-
-    // Fetch field metadata
-    let fields = client
-        .fetch_entity_fields_alt(&entity_name)
-        .await
-        .map_err(|e| format!("Failed to fetch field metadata for {}: {}", entity_name, e))?;
-
-    // Fetch entity metadata info (includes primary_id_attribute)
-    let entity_info = client
-        .fetch_entity_metadata_info(&entity_name)
-        .await
-        .map_err(|e| format!("Failed to fetch entity info for {}: {}", entity_name, e))?;
+    // Fetch from API (shared with any concurrent fetch for the same entity)
+    let metadata = crate::client_manager()
+        .fetch_entity_metadata_shared(&env_name, &entity_name)
+        .await?;
+    let primary_id = metadata
+        .primary_id_attribute
+        .unwrap_or_else(|| format!("{}id", entity_name));
 
     log::info!(
         "[{}] Fetched {} source fields, primary_id={}",
         entity_name,
-        fields.len(),
-        entity_info.primary_id_attribute
+        metadata.fields.len(),
+        primary_id
     );
 
-    // Save to cache with primary_id_attribute
-    let metadata = crate::api::metadata::EntityMetadata {
-        fields: fields.clone(),
-        primary_id_attribute: Some(entity_info.primary_id_attribute.clone()),
-        ..Default::default()
-    };
-    if let Err(e) = config
-        .set_entity_metadata_cache(&env_name, &entity_name, &metadata)
-        .await
-    {
-        log::warn!("[{}] Failed to cache source metadata: {}", entity_name, e);
-    }
-
-    Ok((entity_name, fields, entity_info.primary_id_attribute))
+    Ok((entity_name, metadata.fields, primary_id))
 }
 
 /// Fetch target entity field metadata for lookup field detection
@@ -3163,6 +3639,7 @@ async fn fetch_source_metadata(
 async fn fetch_target_metadata(
     env_name: String,
     entity_name: String,
+    cache_ttl: Option<u32>,
 ) -> Result<
     (
         String,
@@ -3180,9 +3657,10 @@ async fn fetch_target_metadata(
 
     let config = crate::global_config();
 
-    // Check cache first (1 hour TTL) - only use if entity_set_name is present
+    // Check cache first (per-entity TTL, falling back to the global default) - only use if entity_set_name is present
+    let ttl = config.transfer_cache_ttl_minutes(cache_ttl).await;
     match config
-        .get_entity_metadata_cache(&env_name, &entity_name, 1)
+        .get_entity_metadata_cache_minutes(&env_name, &entity_name, ttl)
         .await
     {
         Ok(Some(cached)) if cached.entity_set_name.is_some() => {
@@ -3214,58 +3692,24 @@ async fn fetch_target_metadata(
         }
     }
 
-    // Fetch from API
-    let manager = crate::client_manager();
-    let client = manager
-        .get_client(&env_name)
-        .await
-        .map_err(|e| format!("Failed to get client for {}: {}", env_name, e))?;
-
-    // Fetch field metadata
-    let fields = client
-        .fetch_entity_fields_alt(&entity_name)
-        .await
-        .map_err(|e| {
-            format!(
-                "Failed to fetch target field metadata for {}: {}",
-                entity_name, e
-            )
-        })?;
-
-    // Fetch entity metadata info (includes entity_set_name)
-    let entity_info = client
-        .fetch_entity_metadata_info(&entity_name)
-        .await
-        .map_err(|e| format!("Failed to fetch entity info for {}: {}", entity_name, e))?;
+    // Fetch from API (shared with any concurrent fetch for the same entity)
+    let metadata = crate::client_manager()
+        .fetch_entity_metadata_shared(&env_name, &entity_name)
+        .await?;
+    let entity_set = metadata.entity_set_name.unwrap_or_default();
+    let primary_id = metadata
+        .primary_id_attribute
+        .unwrap_or_else(|| format!("{}id", entity_name));
 
     log::info!(
         "[{}] Fetched {} target fields, entity_set={}, primary_id={}",
         entity_name,
-        fields.len(),
-        entity_info.entity_set_name,
-        entity_info.primary_id_attribute
+        metadata.fields.len(),
+        entity_set,
+        primary_id
     );
 
-    // Save to cache with entity_set_name and primary_id_attribute
-    let metadata = crate::api::metadata::EntityMetadata {
-        fields: fields.clone(),
-        entity_set_name: Some(entity_info.entity_set_name.clone()),
-        primary_id_attribute: Some(entity_info.primary_id_attribute.clone()),
-        ..Default::default()
-    };
-    if let Err(e) = config
-        .set_entity_metadata_cache(&env_name, &entity_name, &metadata)
-        .await
-    {
-        log::warn!("[{}] Failed to cache target metadata: {}", entity_name, e);
-    }
-
-    Ok((
-        entity_name,
-        fields,
-        entity_info.entity_set_name,
-        entity_info.primary_id_attribute,
-    ))
+    Ok((entity_name, metadata.fields, entity_set, primary_id))
 }
 
 /// Fetch entity metadata (fields + entity set name) for lookup binding
@@ -3274,6 +3718,7 @@ async fn fetch_target_metadata(
 async fn fetch_entity_metadata(
     env_name: String,
     entity_name: String,
+    cache_ttl: Option<u32>,
 ) -> Result<
     (
         String,
@@ -3292,10 +3737,11 @@ async fn fetch_entity_metadata(
     let config = crate::global_config();
     log::debug!("[{}] Got global_config", entity_name);
 
-    // Check cache first (1 hour TTL)
+    // Check cache first (per-entity TTL, falling back to the global default)
+    let ttl = config.transfer_cache_ttl_minutes(cache_ttl).await;
     log::debug!("[{}] Checking cache...", entity_name);
     match config
-        .get_entity_metadata_cache(&env_name, &entity_name, 1)
+        .get_entity_metadata_cache_minutes(&env_name, &entity_name, ttl)
         .await
     {
         Ok(Some(cached)) if cached.entity_set_name.is_some() => {
@@ -3324,53 +3770,24 @@ async fn fetch_entity_metadata(
         }
     }
 
-    // Fetch from API
-    let manager = crate::client_manager();
-    let client = manager
-        .get_client(&env_name)
-        .await
-        .map_err(|e| format!("Failed to get client for {}: {}", env_name, e))?;
-
-    // Fetch field metadata (includes schema_name for @odata.bind)
-    let fields = client
-        .fetch_entity_fields_alt(&entity_name)
-        .await
-        .map_err(|e| format!("Failed to fetch field metadata for {}: {}", entity_name, e))?;
-
-    // Fetch entity metadata info (includes entity_set_name and primary_id_attribute)
-    let entity_info = client
-        .fetch_entity_metadata_info(&entity_name)
-        .await
-        .map_err(|e| format!("Failed to fetch entity info for {}: {}", entity_name, e))?;
+    // Fetch from API (shared with any concurrent fetch for the same entity)
+    let metadata = crate::client_manager()
+        .fetch_entity_metadata_shared(&env_name, &entity_name)
+        .await?;
+    let entity_set = metadata.entity_set_name.unwrap_or_default();
+    let primary_id = metadata
+        .primary_id_attribute
+        .unwrap_or_else(|| format!("{}id", entity_name));
 
     log::info!(
         "[{}] Fetched {} fields, entity_set={}, primary_id={}",
         entity_name,
-        fields.len(),
-        entity_info.entity_set_name,
-        entity_info.primary_id_attribute
+        metadata.fields.len(),
+        entity_set,
+        primary_id
     );
 
-    // Save to cache
-    let metadata = crate::api::metadata::EntityMetadata {
-        fields: fields.clone(),
-        entity_set_name: Some(entity_info.entity_set_name.clone()),
-        primary_id_attribute: Some(entity_info.primary_id_attribute.clone()),
-        ..Default::default()
-    };
-    if let Err(e) = config
-        .set_entity_metadata_cache(&env_name, &entity_name, &metadata)
-        .await
-    {
-        log::warn!("[{}] Failed to cache metadata: {}", entity_name, e);
-    }
-
-    Ok((
-        entity_name,
-        fields,
-        entity_info.entity_set_name,
-        entity_info.primary_id_attribute,
-    ))
+    Ok((entity_name, metadata.fields, entity_set, primary_id))
 }
 
 /// Handle Lua mode config - first fetch metadata, then fetch data
@@ -3386,7 +3803,8 @@ fn handle_lua_mode_config(state: &mut State, config: TransferConfig) -> Command<
     };
 
     // Run declare() to get what data we need
-    let declaration = match run_declare(&script) {
+    let module_dir = crate::global_runtime_config().lua_module_dir.clone();
+    let declaration = match run_declare(&script, module_dir.as_deref()) {
         Ok(d) => d,
         Err(e) => {
             state.resolved = Resource::Failure(format!("Failed to run declare(): {}", e));
@@ -3446,10 +3864,11 @@ fn handle_lua_mode_config(state: &mut State, config: TransferConfig) -> Command<
     for entity_name in metadata_entities {
         let env = target_env.clone();
         let entity = entity_name.clone();
+        let cache_ttl = config.cache_ttl_override(&entity);
 
         builder = builder.add_task(
             format!("Metadata: {}", entity),
-            fetch_target_metadata(env, entity),
+            fetch_target_metadata(env, entity, cache_ttl),
         );
     }
 
@@ -3512,6 +3931,7 @@ fn build_lua_data_fetch_commands(state: &mut State) -> Command<Msg> {
         let top = entity_decl.top;
         let primary_id = state.primary_id_map.get(entity_name).cloned();
 
+        let cache_ttl = config.cache_ttl_override(&entity);
         builder = builder.add_task_with_progress(format!("Source: {}", entity), move |progress| {
             fetch_lua_data(
                 env,
@@ -3523,6 +3943,7 @@ fn build_lua_data_fetch_commands(state: &mut State) -> Command<Msg> {
                 true,
                 Some(progress),
                 primary_id,
+                cache_ttl,
             )
         });
     }
@@ -3541,6 +3962,7 @@ fn build_lua_data_fetch_commands(state: &mut State) -> Command<Msg> {
         let top = entity_decl.top;
         let primary_id = state.primary_id_map.get(entity_name).cloned();
 
+        let cache_ttl = config.cache_ttl_override(&entity);
         builder = builder.add_task_with_progress(format!("Target: {}", entity), move |progress| {
             fetch_lua_data(
                 env,
@@ -3552,6 +3974,7 @@ fn build_lua_data_fetch_commands(state: &mut State) -> Command<Msg> {
                 false,
                 Some(progress),
                 primary_id,
+                cache_ttl,
             )
         });
     }
@@ -3577,19 +4000,21 @@ async fn fetch_lua_data(
     is_source: bool,
     progress: Option<crate::tui::command::ProgressSender>,
     primary_id: Option<String>,
+    cache_ttl: Option<u32>,
 ) -> Result<(String, bool, Vec<serde_json::Value>), String> {
     use crate::api::pluralization::pluralize_entity_name;
     use crate::api::query::QueryBuilder;
 
     let config = crate::global_config();
 
-    // Check cache first (1 hour TTL)
+    // Check cache first (per-entity TTL, falling back to the global default)
+    let ttl = config.transfer_cache_ttl_minutes(cache_ttl).await;
     if let Some(ref tx) = progress {
         let _ = tx.send("Checking cache...".to_string());
     }
 
     match config
-        .get_entity_data_cache(&env_name, &entity_name, 1)
+        .get_entity_data_cache_minutes(&env_name, &entity_name, ttl)
         .await
     {
         Ok(Some(cached_data)) => {
@@ -3778,6 +4203,7 @@ async fn run_lua_transform(config_name: String) -> Result<ResolvedTransfer, Stri
         &source_data,
         &target_data,
         &primary_keys,
+        crate::global_runtime_config().lua_module_dir.as_deref(),
     ))
 }
 
@@ -3793,7 +4219,13 @@ fn run_lua_transform_with_data(
         primary_keys.insert(entity_name.clone(), format!("{}id", entity_name));
     }
 
-    TransformEngine::transform_all(config, source_data, target_data, &primary_keys)
+    TransformEngine::transform_all(
+        config,
+        source_data,
+        target_data,
+        &primary_keys,
+        crate::global_runtime_config().lua_module_dir.as_deref(),
+    )
 }
 
 /// Run the Lua transform with progress streaming to the loading screen
@@ -3804,6 +4236,8 @@ async fn run_lua_transform_with_progress(
     script: String,
     source_data: HashMap<String, Vec<serde_json::Value>>,
     target_data: HashMap<String, Vec<serde_json::Value>>,
+    info: crate::transfer::lua::TransformInfo,
+    cancel_flag: std::sync::Arc<std::sync::atomic::AtomicBool>,
     progress: crate::tui::command::ProgressSender,
 ) -> Result<
     (
@@ -3813,16 +4247,16 @@ async fn run_lua_transform_with_progress(
     String,
 > {
     use crate::transfer::lua::{ExecutionContext, ExecutionUpdate, execute_transform_async};
-    use std::sync::Arc;
-    use std::sync::atomic::AtomicBool;
 
     // Send initial status
     let _ = progress.send("Starting Lua transform...".to_string());
 
     // Create execution context with channel for updates
     let (update_tx, mut update_rx) = tokio::sync::mpsc::channel::<ExecutionUpdate>(100);
-    let cancel_flag = Arc::new(AtomicBool::new(false));
-    let ctx = ExecutionContext::new(update_tx, cancel_flag);
+    let ctx = ExecutionContext::new(update_tx, cancel_flag)
+        .with_info(info)
+        .with_min_log_level(crate::global_runtime_config().lua_log_level)
+        .with_module_dir(crate::global_runtime_config().lua_module_dir.clone());
 
     // Convert data to JSON
     let source_json = serde_json::to_value(&source_data).unwrap_or_default();
@@ -3872,6 +4306,22 @@ async fn run_lua_transform_with_progress(
     }
 }
 
+/// Load the saved edit-set for a config
+fn load_saved_edits(
+    config_name: String,
+    to_msg: fn(Result<Vec<crate::transfer::RecordEdit>, String>) -> Msg,
+) -> Command<Msg> {
+    Command::perform(
+        async move {
+            let pool = &crate::global_config().pool;
+            crate::config::repository::preview_edits::load_preview_edits(pool, &config_name)
+                .await
+                .map_err(|e| e.to_string())
+        },
+        to_msg,
+    )
+}
+
 /// Dirty records preserve their user-edited action and field values.
 fn merge_dirty_records(new_resolved: &mut ResolvedTransfer, old_resolved: &ResolvedTransfer) {
     for (new_entity, old_entity) in new_resolved
@@ -3936,3 +4386,286 @@ fn merge_dirty_records(new_resolved: &mut ResolvedTransfer, old_resolved: &Resol
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_warmed_entity_is_cache_hit() {
+        let config = crate::config::Config::new_test().await.unwrap();
+        crate::config::db::seed_environments(&config.pool, &["dev"])
+            .await
+            .unwrap();
+
+        assert!(
+            cached_entity_records(&config, "dev", "account", None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // An earlier fetch left records behind that warming must replace
+        let stale = vec![serde_json::json!({ "accountid": "0" })];
+        config
+            .set_entity_data_cache("dev", "account", &stale)
+            .await
+            .unwrap();
+
+        // A warm fetch is a forced refresh: it skips the cache, fetches and writes the result back
+        let records = vec![
+            serde_json::json!({ "accountid": "1" }),
+            serde_json::json!({ "accountid": "2" }),
+        ];
+        let fetched = load_entity_records(&config, "dev", "account", true, None, None, || async {
+            Ok(records.clone())
+        })
+        .await
+        .unwrap();
+        assert_eq!(fetched, records);
+        assert_eq!(
+            cached_entity_records(&config, "dev", "account", None)
+                .await
+                .unwrap(),
+            Some(records.clone())
+        );
+
+        let mut state = State {
+            warm_cache_only: true,
+            pending_fetches: 1,
+            resolved: Resource::Loading,
+            ..Default::default()
+        };
+        TransferPreviewApp::update(
+            &mut state,
+            Msg::FetchResult(Ok(("account".to_string(), true, records.clone()))),
+        );
+
+        // Warming keeps nothing in memory and never reaches the transform
+        assert!(state.source_data.is_empty());
+        assert_eq!(state.warmed_entities, 1);
+        assert_eq!(state.warmed_records, 2);
+        assert!(matches!(state.resolved, Resource::NotAsked));
+
+        // The next non-force fetch is served from the cache without hitting the API
+        let cached = load_entity_records(&config, "dev", "account", false, None, None, || async {
+            panic!("a warmed entity must not be fetched again")
+        })
+        .await
+        .unwrap();
+        assert_eq!(cached, records);
+    }
+
+    #[test]
+    fn test_throughput_ewma_over_page_timings() {
+        use std::time::Duration;
+
+        let mut throughput = ThroughputEstimator::default();
+
+        // Slow first page (100 rec/s): no ETA yet
+        throughput.record_page(500, Duration::from_secs(5));
+        assert_eq!(throughput.eta_secs(1000), None);
+
+        // Faster pages (500 rec/s) pull the average up gradually
+        throughput.record_page(500, Duration::from_secs(1));
+        let rate = throughput.records_per_sec.unwrap();
+        assert!((rate - 220.0).abs() < 1e-9);
+        assert_eq!(throughput.eta_secs(1100), Some(5));
+
+        throughput.record_page(500, Duration::from_secs(1));
+        let rate = throughput.records_per_sec.unwrap();
+        assert!((rate - 304.0).abs() < 1e-9);
+        assert_eq!(throughput.eta_secs(912), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_resume_uses_stored_next_link() {
+        let config = crate::config::Config::new_test().await.unwrap();
+        crate::config::db::seed_environments(&config.pool, &["dev"])
+            .await
+            .unwrap();
+
+        let query_key = "/api/data/v9.2/accounts?$select=name&$top=500";
+        let next_link = "https://dev.crm.dynamics.com/api/data/v9.2/accounts?$skiptoken=page3";
+        let page_one = vec![
+            serde_json::json!({"name": "A"}),
+            serde_json::json!({"name": "B"}),
+        ];
+        let page_two = vec![serde_json::json!({"name": "C"})];
+        config
+            .save_fetch_checkpoint_page("dev", "account", query_key, 1, &page_one, "link2", 2)
+            .await
+            .unwrap();
+        config
+            .save_fetch_checkpoint_page("dev", "account", query_key, 2, &page_two, next_link, 3)
+            .await
+            .unwrap();
+
+        let checkpoint = config.get_fetch_checkpoint("dev", "account").await.unwrap();
+        let start = resume_point(checkpoint.clone(), query_key, false, 60);
+
+        // Resuming requests the page after the checkpoint via its stored link
+        let requested = std::sync::Mutex::new(Vec::new());
+        let page_three = QueryResult::success(
+            crate::api::query::QueryResponse {
+                value: vec![serde_json::json!({"name": "D"})],
+                count: None,
+                next_link: None,
+            },
+            200,
+            Default::default(),
+        );
+        let (result, resumed) = open_fetch(
+            start,
+            "account",
+            |link| {
+                requested.lock().unwrap().push(link);
+                async { Ok(page_three) }
+            },
+            || async { panic!("a resumed fetch must not rerun the query") },
+        )
+        .await
+        .unwrap();
+        assert_eq!(*requested.lock().unwrap(), vec![next_link.to_string()]);
+        assert_eq!(result.records().unwrap().len(), 1);
+        let resumed = resumed.expect("expected the fetch to resume");
+        assert_eq!(resumed.pages, 2);
+        assert_eq!(resumed.fetched_count, 3);
+        assert_eq!(resumed.records.len(), 3);
+
+        // A different query (e.g. new fields), a forced refresh or an expired checkpoint starts over
+        assert_eq!(
+            resume_point(
+                checkpoint.clone(),
+                "/api/data/v9.2/accounts?$top=500",
+                false,
+                60
+            ),
+            FetchStart::Fresh
+        );
+        assert_eq!(
+            resume_point(checkpoint.clone(), query_key, true, 60),
+            FetchStart::Fresh
+        );
+        let start = resume_point(checkpoint, query_key, false, 0);
+        assert_eq!(start, FetchStart::Fresh);
+        let (_, resumed) = open_fetch(
+            start,
+            "account",
+            |_| async { panic!("a fresh fetch must not follow the stored link") },
+            || async {
+                Ok(QueryResult::success(
+                    crate::api::query::QueryResponse {
+                        value: Vec::new(),
+                        count: None,
+                        next_link: None,
+                    },
+                    200,
+                    Default::default(),
+                ))
+            },
+        )
+        .await
+        .unwrap();
+        assert!(resumed.is_none());
+
+        // Completion clears the checkpoint
+        config
+            .delete_fetch_checkpoint("dev", "account")
+            .await
+            .unwrap();
+        assert!(
+            config
+                .get_fetch_checkpoint("dev", "account")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    /// Two records whose names both contain "string" in Debug form
+    /// (`String("Alpha")`), but only one of which shows it in the table
+    fn search_state() -> (State, uuid::Uuid, uuid::Uuid) {
+        use crate::transfer::{ResolvedEntity, ResolvedRecord, Value};
+
+        let record = |name: &str| {
+            let fields = HashMap::from([("name".to_string(), Value::String(name.to_string()))]);
+            ResolvedRecord::create(uuid::Uuid::new_v4(), fields)
+        };
+        let (alpha, stringer) = (record("Alpha"), record("Stringer"));
+        let ids = (alpha.source_id, stringer.source_id);
+
+        let mut entity = ResolvedEntity::new("account", 1, "accountid");
+        entity.field_names = vec!["name".to_string()];
+        entity.records = vec![alpha, stringer];
+        let mut resolved = ResolvedTransfer::new("test", "dev", "prod");
+        resolved.add_entity(entity);
+
+        let mut state = State {
+            resolved: Resource::Success(resolved),
+            search_field: crate::tui::widgets::TextInputField::with_value("string"),
+            ..Default::default()
+        };
+        state.list_state.select(Some(0));
+        (state, ids.0, ids.1)
+    }
+
+    #[test]
+    fn test_edited_record_is_the_one_shown_in_the_table() {
+        use crate::transfer::Value;
+
+        let (mut state, alpha_id, stringer_id) = search_state();
+        let _ = TransferPreviewApp::update(&mut state, Msg::EditRecord);
+
+        // The search only shows "Stringer", so row 0 opens it
+        let detail = state.record_detail_state.as_mut().unwrap();
+        assert_eq!(detail.fields[0].input.value(), "Stringer");
+        detail.fields[0].input.set_value("Renamed".to_string());
+        detail.fields[0].update_dirty();
+
+        let _ = TransferPreviewApp::update(&mut state, Msg::SaveRecordEdits);
+        let Resource::Success(resolved) = &state.resolved else {
+            panic!("resolved transfer expected");
+        };
+        let name_of = |id: uuid::Uuid| {
+            resolved.entities[0]
+                .records
+                .iter()
+                .find(|r| r.source_id == id)
+                .and_then(|r| r.fields.get("name").cloned())
+        };
+        assert_eq!(name_of(stringer_id), Some(Value::String("Renamed".into())));
+        assert_eq!(name_of(alpha_id), Some(Value::String("Alpha".into())));
+    }
+
+    #[test]
+    fn test_open_modal_keeps_its_record_when_the_table_changes() {
+        let (mut state, alpha_id, stringer_id) = search_state();
+        let _ = TransferPreviewApp::update(&mut state, Msg::ViewDetails);
+        assert_eq!(
+            state.record_detail_state.as_ref().map(|d| d.source_id),
+            Some(stringer_id)
+        );
+
+        // Clearing the search puts "Alpha" at the modal's row index
+        state.search_field = crate::tui::widgets::TextInputField::new();
+        let _ = TransferPreviewApp::update(
+            &mut state,
+            Msg::RecordDetailActionChanged(RecordAction::Skip),
+        );
+        let _ = TransferPreviewApp::update(&mut state, Msg::SaveRecordEdits);
+
+        let Resource::Success(resolved) = &state.resolved else {
+            panic!("resolved transfer expected");
+        };
+        let action_of = |id: uuid::Uuid| {
+            resolved.entities[0]
+                .records
+                .iter()
+                .find(|r| r.source_id == id)
+                .map(|r| r.action)
+        };
+        assert_eq!(action_of(stringer_id), Some(RecordAction::Skip));
+        assert_eq!(action_of(alpha_id), Some(RecordAction::Create));
+    }
+}
@@ -49,6 +49,20 @@ pub fn render(state: &State, theme: &Theme) -> Element<Msg> {
 
     let action_options = render_action_options(state, theme);
 
+    // Field/value inputs, only shown for SetField
+    let is_set_field = matches!(state.bulk_action_selection, BulkAction::SetField { .. });
+    let set_field_inputs = render_set_field_inputs(state);
+    let set_field_error = Element::styled_text(Line::from(vec![Span::styled(
+        state.bulk_set_error.clone().unwrap_or_default(),
+        Style::default().fg(theme.accent_error),
+    )]))
+    .build();
+    let error_height = if is_set_field && state.bulk_set_error.is_some() {
+        1
+    } else {
+        0
+    };
+
     // Buttons
     let buttons = render_buttons(theme);
 
@@ -63,7 +77,12 @@ pub fn render(state: &State, theme: &Theme) -> Element<Msg> {
         )
         .add(Element::text(""), LayoutConstraint::Length(1))
         .add(action_header, LayoutConstraint::Length(1))
-        .add(action_options, LayoutConstraint::Length(3))
+        .add(action_options, LayoutConstraint::Length(4))
+        .add(
+            set_field_inputs,
+            LayoutConstraint::Length(if is_set_field { 3 } else { 0 }),
+        )
+        .add(set_field_error, LayoutConstraint::Length(error_height))
         .add(Element::text(""), LayoutConstraint::Fill(1))
         .add(buttons, LayoutConstraint::Length(3))
         .build();
@@ -71,7 +90,7 @@ pub fn render(state: &State, theme: &Theme) -> Element<Msg> {
     Element::panel(content)
         .title("Bulk Actions")
         .width(60)
-        .height(18)
+        .height(if is_set_field { 22 + error_height } else { 19 })
         .build()
 }
 
@@ -81,23 +100,13 @@ fn calculate_counts(state: &State) -> (usize, usize, usize) {
         if let crate::tui::resource::Resource::Success(resolved) = &state.resolved {
             if let Some(entity) = resolved.entities.get(state.current_entity_idx) {
                 let all = entity.records.len();
-                let query = state.search_field.value().to_lowercase();
-                let filtered = entity
-                    .records
-                    .iter()
-                    .filter(|r| state.filter.matches(r.action))
-                    .filter(|r| {
-                        if query.is_empty() {
-                            return true;
-                        }
-                        if r.source_id.to_string().to_lowercase().contains(&query) {
-                            return true;
-                        }
-                        r.fields
-                            .values()
-                            .any(|v| format!("{:?}", v).to_lowercase().contains(&query))
-                    })
-                    .count();
+                let filtered = super::super::view::get_filtered_records(
+                    entity,
+                    state.filter,
+                    state.search_field.value(),
+                    &state.sort_key,
+                )
+                .len();
                 (all, filtered)
             } else {
                 (0, 0)
@@ -216,10 +225,11 @@ fn render_action_options(state: &State, theme: &Theme) -> Element<Msg> {
         ('a', BulkAction::MarkSkip),
         ('b', BulkAction::UnmarkSkip),
         ('c', BulkAction::ResetToOriginal),
+        ('d', BulkAction::set_field()),
     ];
 
     for (key, action) in actions {
-        let is_selected = state.bulk_action_selection == action;
+        let is_selected = state.bulk_action_selection.same_kind(&action);
         let radio = if is_selected { "(*)" } else { "( )" };
 
         builder = builder.add(
@@ -253,6 +263,38 @@ fn render_action_options(state: &State, theme: &Theme) -> Element<Msg> {
     builder.build()
 }
 
+/// Render the field name and value inputs for the SetField action
+fn render_set_field_inputs(state: &State) -> Element<Msg> {
+    let field_input = Element::text_input(
+        FocusId::new("bulk-set-field"),
+        state.bulk_set_field.value(),
+        &state.bulk_set_field.state,
+    )
+    .on_event(Msg::BulkSetFieldChanged)
+    .placeholder("field name")
+    .build();
+
+    let value_input = Element::text_input(
+        FocusId::new("bulk-set-value"),
+        state.bulk_set_value.value(),
+        &state.bulk_set_value.state,
+    )
+    .on_event(Msg::BulkSetValueChanged)
+    .placeholder("value (empty = null)")
+    .build();
+
+    RowBuilder::new()
+        .add(
+            Element::panel(field_input).title("Field").build(),
+            LayoutConstraint::Fill(1),
+        )
+        .add(
+            Element::panel(value_input).title("Value").build(),
+            LayoutConstraint::Fill(1),
+        )
+        .build()
+}
+
 /// Render Apply and Cancel buttons
 fn render_buttons(theme: &Theme) -> Element<Msg> {
     let apply_btn = Element::button(FocusId::new("apply-btn"), "[Enter] Apply")
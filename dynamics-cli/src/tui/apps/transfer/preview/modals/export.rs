@@ -96,7 +96,11 @@ pub fn render(state: &State, theme: &Theme) -> Element<Msg> {
         .build();
 
     Element::panel(content)
-        .title("Export to Excel")
+        .title(if state.export_errors_only {
+            "Export Errors to Excel"
+        } else {
+            "Export to Excel"
+        })
         .width(80)
         .height(30)
         .build()
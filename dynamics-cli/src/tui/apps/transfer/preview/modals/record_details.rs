@@ -8,7 +8,7 @@ use crate::tui::element::{ColumnBuilder, FocusId, RowBuilder};
 use crate::tui::widgets::{ListItem, ListState, TextInputEvent};
 use crate::tui::{Element, LayoutConstraint, Theme};
 
-use super::super::state::{FieldEditState, Msg, RecordDetailState};
+use super::super::state::{FieldDiff, FieldEditState, Msg, RecordDetailState};
 use super::super::view::sanitize_for_display;
 
 /// Render the record details/edit modal
@@ -64,9 +64,20 @@ fn render_view_mode(
     ]))
     .build();
 
-    // Fields section
-    let fields_content = render_fields_view(state, lookup_context, theme);
-    let fields_panel = Element::panel(fields_content).title("Fields").build();
+    // Fields section - updates with a fetched target show old vs new values
+    let fields_panel = if let Some(diff) = &state.target_diff {
+        let changed = diff.iter().filter(|d| d.changed).count();
+        Element::panel(render_fields_diff(diff, state, theme))
+            .title(format!(
+                "Changes ({} of {} fields) - target → source",
+                changed,
+                diff.len()
+            ))
+            .build()
+    } else {
+        let fields_content = render_fields_view(state, lookup_context, theme);
+        Element::panel(fields_content).title("Fields").build()
+    };
 
     // Error panel (if present) - scrollable list of error lines
     // Errors may be separated by newlines or "; " (semicolon + space)
@@ -100,6 +111,27 @@ fn render_view_mode(
     // Buttons row
     let buttons = render_view_buttons(theme);
 
+    // Copy feedback (or hint when nothing has been copied yet)
+    let copy_row = match &state.copy_status {
+        Some((message, is_error)) => {
+            let color = if *is_error {
+                theme.accent_error
+            } else {
+                theme.accent_success
+            };
+            Element::styled_text(Line::from(Span::styled(
+                message.clone(),
+                Style::default().fg(color),
+            )))
+            .build()
+        }
+        None => Element::styled_text(Line::from(Span::styled(
+            "[y] Copy field value  [Y] Copy record as JSON",
+            Style::default().fg(theme.text_tertiary),
+        )))
+        .build(),
+    };
+
     // Build layout - give errors panel some height if present
     let error_height = if record.error.is_some() {
         LayoutConstraint::Length(8)
@@ -113,7 +145,7 @@ fn render_view_mode(
         .add(Element::text(""), LayoutConstraint::Length(1))
         .add(fields_panel, LayoutConstraint::Fill(1))
         .add(error_element, error_height)
-        .add(Element::text(""), LayoutConstraint::Length(1))
+        .add(copy_row, LayoutConstraint::Length(1))
         .add(buttons, LayoutConstraint::Length(3))
         .build()
 }
@@ -338,6 +370,89 @@ fn render_fields_view(
     .build()
 }
 
+/// A field row comparing the target value with the value that will be written
+#[derive(Clone)]
+pub struct FieldDiffItem(pub FieldDiff);
+
+impl ListItem for FieldDiffItem {
+    type Msg = Msg;
+
+    fn to_element(
+        &self,
+        is_selected: bool,
+        _is_multi_selected: bool,
+        _is_hovered: bool,
+    ) -> Element<Self::Msg> {
+        let theme = &crate::global_runtime_config().theme;
+        let diff = &self.0;
+
+        let display = |value: &str| {
+            if value.is_empty() {
+                "(null)".to_string()
+            } else {
+                truncate_str(&sanitize_for_display(value), 34)
+            }
+        };
+
+        // Changed fields stand out, unchanged ones are dimmed
+        let (name_style, old_style, new_style) = if diff.changed {
+            (
+                Style::default().fg(theme.accent_warning),
+                Style::default()
+                    .fg(theme.text_secondary)
+                    .add_modifier(Modifier::CROSSED_OUT),
+                Style::default().fg(theme.accent_success),
+            )
+        } else {
+            let dim = Style::default().fg(theme.text_tertiary);
+            (dim, dim, dim)
+        };
+
+        let mut builder = Element::styled_text(Line::from(vec![
+            Span::styled(
+                format!("{:<25}", truncate_str(&diff.field_name, 25)),
+                name_style,
+            ),
+            Span::raw(" │ "),
+            Span::styled(format!("{:<34}", display(&diff.target_display)), old_style),
+            Span::styled(
+                if diff.changed { " → " } else { "   " },
+                Style::default().fg(theme.text_tertiary),
+            ),
+            Span::styled(display(&diff.source_display), new_style),
+        ]));
+
+        if is_selected {
+            builder = builder.background(Style::default().bg(theme.bg_surface));
+        }
+
+        builder.build()
+    }
+}
+
+/// Render the old-vs-new field comparison for an update record
+fn render_fields_diff(
+    diff: &[FieldDiff],
+    state: &RecordDetailState,
+    theme: &Theme,
+) -> Element<Msg> {
+    let items: Vec<FieldDiffItem> = diff.iter().cloned().map(FieldDiffItem).collect();
+
+    if items.is_empty() {
+        return Element::text("No fields");
+    }
+
+    Element::list(
+        FocusId::new("detail-fields-list"),
+        &items,
+        &state.fields_list_state,
+        theme,
+    )
+    .on_navigate(Msg::DetailFieldsListNavigate)
+    .on_render(Msg::DetailFieldsSetViewportHeight)
+    .build()
+}
+
 /// Render fields in edit mode
 /// - Navigate with Up/Down arrows
 /// - Press Enter to edit the focused field
@@ -1,12 +1,14 @@
 //! State and messages for the Transfer Preview app
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 use crossterm::event::KeyCode;
 
-use crate::api::metadata::FieldMetadata;
-use crate::transfer::{LookupBindingContext, RecordAction, ResolvedTransfer, Value};
+use crate::api::metadata::{FieldMetadata, FieldType};
+use crate::transfer::{
+    LookupBindingContext, RecordAction, ResolvedEntity, ResolvedRecord, ResolvedTransfer, Value,
+};
 use crate::tui::resource::Resource;
 use crate::tui::widgets::{FileBrowserState, ListState, TextInputEvent, TextInputField};
 
@@ -31,6 +33,8 @@ pub struct PreviewParams {
     pub source_env: String,
     /// Target environment name
     pub target_env: String,
+    /// Only fetch records into the entity data cache, without resolving or rendering them
+    pub warm_cache_only: bool,
 }
 
 /// State for editing a single field in the record details modal
@@ -80,7 +84,7 @@ impl FieldEditState {
 }
 
 /// Format a Value for editing in a text input
-fn format_value_for_edit(value: &Value) -> String {
+pub(super) fn format_value_for_edit(value: &Value) -> String {
     match value {
         Value::Null => String::new(),
         Value::String(s) => s.clone(),
@@ -94,6 +98,99 @@ fn format_value_for_edit(value: &Value) -> String {
     }
 }
 
+/// Serialize a record as pretty-printed JSON for copying out of the TUI
+///
+/// Fields listed in `field_names` are included (missing ones as null), so the
+/// output matches what the details modal shows.
+pub fn record_to_json(record: &ResolvedRecord, field_names: &[String]) -> String {
+    let fields: serde_json::Map<String, serde_json::Value> = field_names
+        .iter()
+        .map(|name| {
+            let value = match record.fields.get(name) {
+                None => serde_json::Value::Null,
+                // Unresolved dynamic values can't go through to_json
+                Some(Value::Dynamic(dv)) => serde_json::Value::String(format!("{:?}", dv)),
+                Some(value) => value.to_json(),
+            };
+            (name.clone(), value)
+        })
+        .collect();
+
+    let mut json = serde_json::json!({
+        "source_id": record.source_id.to_string(),
+        "action": record.action.to_string(),
+        "fields": fields,
+    });
+    if let Some(error) = &record.error {
+        json["error"] = serde_json::Value::String(error.clone());
+    }
+
+    serde_json::to_string_pretty(&json).unwrap_or_default()
+}
+
+/// One row of the old-vs-new comparison shown for update records
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    pub field_name: String,
+    /// Current value in the target (what will be overwritten)
+    pub target_display: String,
+    /// Resolved value from the source (what will be written)
+    pub source_display: String,
+    pub changed: bool,
+}
+
+/// Find the fetched target record with the given primary key
+pub fn find_target_record<'a>(
+    target_records: &'a [serde_json::Value],
+    primary_key_field: &str,
+    id: &uuid::Uuid,
+) -> Option<&'a serde_json::Value> {
+    let id = id.to_string();
+    target_records.iter().find(|t| {
+        t.get(primary_key_field)
+            .and_then(|v| v.as_str())
+            .is_some_and(|pk| pk.eq_ignore_ascii_case(&id))
+    })
+}
+
+/// Compare a resolved record against its target record field by field
+///
+/// Uses the same equality rules as the transform engine, so the fields marked
+/// changed are exactly the ones a partial update would send.
+pub fn compute_field_diff(
+    field_names: &[String],
+    source: &HashMap<String, Value>,
+    target: &serde_json::Value,
+) -> Vec<FieldDiff> {
+    let changed =
+        crate::transfer::TransformEngine::identify_changed_fields(source, target, field_names);
+
+    field_names
+        .iter()
+        .map(|name| {
+            let target_value = target
+                .get(name)
+                .or_else(|| target.get(format!("_{}_value", name)));
+            let target_display = match target_value {
+                None | Some(serde_json::Value::Null) => String::new(),
+                Some(serde_json::Value::String(s)) => s.clone(),
+                Some(other) => other.to_string(),
+            };
+            let source_display = source
+                .get(name)
+                .map(format_value_for_edit)
+                .unwrap_or_default();
+
+            FieldDiff {
+                field_name: name.clone(),
+                target_display,
+                source_display,
+                changed: changed.contains(name),
+            }
+        })
+        .collect()
+}
+
 /// Parse a string back into a Value, using original type as hint
 fn parse_value_from_string(s: &str, original: &Value) -> Value {
     let trimmed = s.trim();
@@ -142,6 +239,11 @@ fn parse_value_from_string(s: &str, original: &Value) -> Value {
 pub struct RecordDetailState {
     /// Index into the filtered records list
     pub record_idx: usize,
+    /// Source ID of the record the modal shows
+    ///
+    /// Actions on the open modal use this rather than `record_idx`, which
+    /// points at a different record once the table's filter or sort changes.
+    pub source_id: uuid::Uuid,
     /// Whether we're in edit mode (true) or view mode (false)
     pub editing: bool,
     /// Whether we're actively editing the focused field's value
@@ -158,12 +260,17 @@ pub struct RecordDetailState {
     pub fields_list_state: crate::tui::widgets::ListState,
     /// List state for errors scrolling
     pub errors_list_state: crate::tui::widgets::ListState,
+    /// Result of the last copy-to-clipboard action (message, is_error)
+    pub copy_status: Option<(String, bool)>,
+    /// Old (target) vs new (source) values, for update records whose target was fetched
+    pub target_diff: Option<Vec<FieldDiff>>,
 }
 
 impl RecordDetailState {
     /// Create a new record detail state
     pub fn new(
         record_idx: usize,
+        source_id: uuid::Uuid,
         action: RecordAction,
         field_names: &[String],
         field_values: &std::collections::HashMap<String, Value>,
@@ -178,6 +285,7 @@ impl RecordDetailState {
 
         Self {
             record_idx,
+            source_id,
             editing: false,
             editing_field: false,
             original_action: action,
@@ -186,9 +294,17 @@ impl RecordDetailState {
             focused_field_idx: 0,
             fields_list_state: crate::tui::widgets::ListState::with_selection(),
             errors_list_state: crate::tui::widgets::ListState::with_selection(),
+            copy_status: None,
+            target_diff: None,
         }
     }
 
+    /// Attach the field diff against the target record
+    pub fn with_target_diff(mut self, diff: Option<Vec<FieldDiff>>) -> Self {
+        self.target_diff = diff;
+        self
+    }
+
     /// Check if any changes have been made
     pub fn has_changes(&self) -> bool {
         self.current_action != self.original_action || self.fields.iter().any(|f| f.is_dirty)
@@ -234,6 +350,12 @@ pub struct State {
     pub config: Option<crate::transfer::TransferConfig>,
     /// Number of pending fetch tasks
     pub pending_fetches: usize,
+    /// Warm-cache mode: fetched records are cached but not kept or transformed
+    pub warm_cache_only: bool,
+    /// Entities written to the cache so far in warm-cache mode
+    pub warmed_entities: usize,
+    /// Records written to the cache so far in warm-cache mode
+    pub warmed_records: usize,
     /// Number of pending metadata fetch tasks
     pub pending_metadata_fetches: usize,
     /// Number of pending source metadata fetch tasks
@@ -248,6 +370,8 @@ pub struct State {
     pub pending_lua_metadata_fetches: usize,
     /// Cached Lua declaration for data fetching phase
     pub lua_declaration: Option<crate::transfer::lua::Declaration>,
+    /// Cancel flag for the running Lua transform, set from the loading screen
+    pub lua_transform_cancel: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
     /// Whether we're currently refreshing (vs initial load)
     pub is_refreshing: bool,
     /// Accumulated source records by entity name (kept for refresh comparison)
@@ -268,6 +392,8 @@ pub struct State {
     pub current_entity_idx: usize,
     /// Filter for record actions
     pub filter: RecordFilter,
+    /// Sort order for the record table (applied after filter and search)
+    pub sort_key: SortKey,
     /// Search input field
     pub search_field: TextInputField,
     /// List state for record table
@@ -288,14 +414,24 @@ pub struct State {
     pub bulk_action_scope: BulkActionScope,
     /// Bulk action modal - selected action
     pub bulk_action_selection: BulkAction,
+    /// Bulk action modal - field name for SetField
+    pub bulk_set_field: TextInputField,
+    /// Bulk action modal - value for SetField
+    pub bulk_set_value: TextInputField,
+    /// Bulk action modal - why the last SetField was rejected
+    pub bulk_set_error: Option<String>,
     /// Export modal - file browser for directory selection
     pub export_file_browser: FileBrowserState,
     /// Export modal - filename input
     pub export_filename: TextInputField,
+    /// Export modal - only write error records (for triage)
+    pub export_errors_only: bool,
     /// Import modal - file browser for file selection
     pub import_file_browser: FileBrowserState,
     /// Import confirmation - pending edits to apply
     pub pending_import: Option<PendingImport>,
+    /// Result of the last save/load of the edit-set (message, is_error)
+    pub edits_status: Option<(String, bool)>,
 }
 
 impl Default for State {
@@ -306,6 +442,9 @@ impl Default for State {
             target_env: String::new(),
             config: None,
             pending_fetches: 0,
+            warm_cache_only: false,
+            warmed_entities: 0,
+            warmed_records: 0,
             pending_metadata_fetches: 0,
             pending_source_metadata_fetches: 0,
             pending_target_metadata_fetches: 0,
@@ -313,6 +452,7 @@ impl Default for State {
             pending_lua_fetches: 0,
             pending_lua_metadata_fetches: 0,
             lua_declaration: None,
+            lua_transform_cancel: None,
             is_refreshing: false,
             source_data: std::collections::HashMap::new(),
             target_data: std::collections::HashMap::new(),
@@ -323,6 +463,7 @@ impl Default for State {
             resolved: Resource::NotAsked,
             current_entity_idx: 0,
             filter: RecordFilter::All,
+            sort_key: SortKey::SourceOrder,
             search_field: TextInputField::new(),
             list_state: ListState::with_selection(),
             horizontal_scroll: 0,
@@ -333,10 +474,15 @@ impl Default for State {
             record_detail_state: None,
             bulk_action_scope: BulkActionScope::default(),
             bulk_action_selection: BulkAction::default(),
+            bulk_set_field: TextInputField::new(),
+            bulk_set_value: TextInputField::new(),
+            bulk_set_error: None,
             export_file_browser: FileBrowserState::new(get_default_export_dir()),
             export_filename: TextInputField::new(),
+            export_errors_only: false,
             import_file_browser: FileBrowserState::new(get_default_export_dir()),
             pending_import: None,
+            edits_status: None,
         }
     }
 }
@@ -437,6 +583,101 @@ impl RecordFilter {
     }
 }
 
+/// Sort order for records in the table
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SortKey {
+    /// Keep the order records came out of the transform
+    #[default]
+    SourceOrder,
+    /// Group by action (creates first, errors last)
+    Action,
+    /// Sort by source record ID
+    SourceId,
+    /// Sort by a field value (logical name)
+    Field(String),
+}
+
+impl SortKey {
+    /// Get display name for the sort key
+    pub fn display_name(&self) -> String {
+        match self {
+            SortKey::SourceOrder => "Source order".to_string(),
+            SortKey::Action => "Action".to_string(),
+            SortKey::SourceId => "Source ID".to_string(),
+            SortKey::Field(name) => name.clone(),
+        }
+    }
+
+    /// Cycle to next sort key
+    ///
+    /// `field` is the field offered after SourceId (typically the first visible column).
+    /// When there is no field to sort by, cycling skips straight back to source order.
+    pub fn next(&self, field: Option<&str>) -> Self {
+        match self {
+            SortKey::SourceOrder => SortKey::Action,
+            SortKey::Action => SortKey::SourceId,
+            SortKey::SourceId => match field {
+                Some(name) => SortKey::Field(name.to_string()),
+                None => SortKey::SourceOrder,
+            },
+            SortKey::Field(_) => SortKey::SourceOrder,
+        }
+    }
+}
+
+/// Rank of an action when sorting by action (follows the filter cycle order)
+fn action_sort_rank(action: RecordAction) -> u8 {
+    match action {
+        RecordAction::Create => 0,
+        RecordAction::Update => 1,
+        RecordAction::Delete => 2,
+        RecordAction::Deactivate => 3,
+        RecordAction::NoChange => 4,
+        RecordAction::TargetOnly => 5,
+        RecordAction::Skip => 6,
+        RecordAction::Error => 7,
+    }
+}
+
+/// Compare two field values for sorting (nulls sort last)
+fn compare_field_values(a: Option<&Value>, b: Option<&Value>) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    let a = a.filter(|v| !matches!(v, Value::Null));
+    let b = b.filter(|v| !matches!(v, Value::Null));
+
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(Value::Int(x)), Some(Value::Int(y))) => x.cmp(y),
+        (Some(Value::Float(x)), Some(Value::Float(y))) => {
+            x.partial_cmp(y).unwrap_or(Ordering::Equal)
+        }
+        (Some(Value::OptionSet(x)), Some(Value::OptionSet(y))) => x.cmp(y),
+        (Some(Value::DateTime(x)), Some(Value::DateTime(y))) => x.cmp(y),
+        (Some(x), Some(y)) => format_value_for_edit(x)
+            .to_lowercase()
+            .cmp(&format_value_for_edit(y).to_lowercase()),
+    }
+}
+
+/// Sort filtered records according to the sort key
+///
+/// The sort is stable, so records that compare equal keep their source order.
+/// Every place that maps a list index back to a record must call this after
+/// filtering so the index math matches what the table shows.
+pub fn sort_records(records: &mut [&ResolvedRecord], sort_key: &SortKey) {
+    match sort_key {
+        SortKey::SourceOrder => {}
+        SortKey::Action => records.sort_by_key(|r| action_sort_rank(r.action)),
+        SortKey::SourceId => records.sort_by_key(|r| r.source_id),
+        SortKey::Field(name) => {
+            records.sort_by(|a, b| compare_field_values(a.fields.get(name), b.fields.get(name)))
+        }
+    }
+}
+
 /// Modal types for the preview app
 #[derive(Debug, Clone)]
 pub enum PreviewModal {
@@ -488,6 +729,7 @@ pub enum Msg {
             String,
         >,
     ), // (operations, logs)
+    CancelLuaTransform, // ESC on the transform's loading screen
 
     // Navigation
     ListEvent(crate::tui::widgets::ListEvent),
@@ -502,6 +744,8 @@ pub enum Msg {
     // Filtering & search
     SetFilter(RecordFilter),
     CycleFilter,
+    SetSort(SortKey),
+    CycleSort,
     SearchChanged(crate::tui::widgets::TextInputEvent),
 
     // Record actions
@@ -524,6 +768,8 @@ pub enum Msg {
     CancelFieldEdit,                   // Esc while editing a field
     SaveRecordEdits,
     CancelRecordEdits,
+    CopyDetailField,  // Copy the selected field value to the clipboard
+    CopyRecordAsJson, // Copy the whole record as JSON to the clipboard
 
     // Multi-selection
     ListMultiSelect(crate::tui::widgets::ListEvent),
@@ -532,10 +778,13 @@ pub enum Msg {
     OpenBulkActions,
     SetBulkActionScope(BulkActionScope),
     SetBulkAction(BulkAction),
+    BulkSetFieldChanged(TextInputEvent),
+    BulkSetValueChanged(TextInputEvent),
     ConfirmBulkAction,
 
     // Excel export
     ExportExcel,
+    ExportErrors,
     ExportFileNavigate(KeyCode),
     ExportFilenameChanged(TextInputEvent),
     ExportSetViewportHeight(usize),
@@ -555,6 +804,13 @@ pub enum Msg {
     // Refresh
     Refresh,
 
+    // Saved edit-set
+    SaveEdits,
+    EditsSaved(Result<usize, String>),
+    LoadEdits,
+    EditsLoaded(Result<Vec<crate::transfer::RecordEdit>, String>),
+    SavedEditsReloaded(Result<Vec<crate::transfer::RecordEdit>, String>),
+
     // Modal
     CloseModal,
 
@@ -567,12 +823,17 @@ pub enum Msg {
 }
 
 /// Bulk action types
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum BulkAction {
     #[default]
     MarkSkip,
     UnmarkSkip,
     ResetToOriginal,
+    /// Set a field to the same value on every record in scope
+    SetField {
+        field: String,
+        value: String,
+    },
 }
 
 impl BulkAction {
@@ -581,17 +842,32 @@ impl BulkAction {
             BulkAction::MarkSkip => "Mark as Skip",
             BulkAction::UnmarkSkip => "Unmark Skip (restore)",
             BulkAction::ResetToOriginal => "Reset to Original",
+            BulkAction::SetField { .. } => "Set Field Value",
         }
     }
 
-    /// Get all variants for iteration
-    pub fn all_variants() -> &'static [BulkAction] {
-        &[
+    /// Get all variants for iteration (SetField with an empty field and value)
+    pub fn all_variants() -> Vec<BulkAction> {
+        vec![
             BulkAction::MarkSkip,
             BulkAction::UnmarkSkip,
             BulkAction::ResetToOriginal,
+            BulkAction::set_field(),
         ]
     }
+
+    /// SetField placeholder selected in the modal before field and value are typed
+    pub fn set_field() -> Self {
+        BulkAction::SetField {
+            field: String::new(),
+            value: String::new(),
+        }
+    }
+
+    /// Whether this is the same kind of action, ignoring SetField's field and value
+    pub fn same_kind(&self, other: &BulkAction) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
 }
 
 /// Scope for bulk actions
@@ -613,6 +889,156 @@ impl BulkActionScope {
     }
 }
 
+/// Indices of the records in `entity` that a bulk action with `scope` applies to
+///
+/// `selected_ids` holds the source IDs of the multi-selected table rows and is
+/// only consulted for the Selected scope. The Filtered scope is exactly the
+/// rows the table shows.
+pub fn bulk_scope_indices(
+    entity: &ResolvedEntity,
+    scope: BulkActionScope,
+    filter: RecordFilter,
+    search_query: &str,
+    selected_ids: &HashSet<uuid::Uuid>,
+) -> Vec<usize> {
+    let visible_ids: HashSet<uuid::Uuid> = match scope {
+        BulkActionScope::Filtered => {
+            super::view::get_filtered_records(entity, filter, search_query, &SortKey::SourceOrder)
+                .iter()
+                .map(|r| r.source_id)
+                .collect()
+        }
+        _ => HashSet::new(),
+    };
+    entity
+        .records
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| match scope {
+            BulkActionScope::All => true,
+            BulkActionScope::Filtered => visible_ids.contains(&r.source_id),
+            BulkActionScope::Selected => selected_ids.contains(&r.source_id),
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Parse a bulk-set value into the type of the target field
+///
+/// Unlike a cell edit there is no original value to take the type from, so it
+/// comes from the target metadata. Values that don't fit the type are rejected.
+fn parse_value_for_field_type(s: &str, field_type: &FieldType) -> Result<Value, String> {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        return Ok(Value::Null);
+    }
+    let invalid = |kind: &str| format!("'{}' is not a valid {}", trimmed, kind);
+
+    match field_type {
+        FieldType::String | FieldType::Memo | FieldType::Other(_) => {
+            Ok(Value::String(trimmed.to_string()))
+        }
+        FieldType::Integer => trimmed
+            .parse::<i64>()
+            .map(Value::Int)
+            .map_err(|_| invalid("integer")),
+        FieldType::Decimal | FieldType::Money => trimmed
+            .parse::<f64>()
+            .map(Value::Float)
+            .map_err(|_| invalid("decimal")),
+        FieldType::Boolean => match trimmed.to_lowercase().as_str() {
+            "true" | "1" | "yes" => Ok(Value::Bool(true)),
+            "false" | "0" | "no" => Ok(Value::Bool(false)),
+            _ => Err(invalid("boolean (use true/false)")),
+        },
+        FieldType::OptionSet => trimmed
+            .parse::<i32>()
+            .map(Value::OptionSet)
+            .map_err(|_| invalid("option value")),
+        FieldType::MultiSelectOptionSet => {
+            if trimmed.split(',').all(|v| v.trim().parse::<i32>().is_ok()) {
+                Ok(Value::String(trimmed.to_string()))
+            } else {
+                Err(invalid("comma-separated list of option values"))
+            }
+        }
+        FieldType::DateTime => chrono::DateTime::parse_from_rfc3339(trimmed)
+            .map(|dt| dt.with_timezone(&chrono::Utc))
+            .or_else(|_| {
+                chrono::NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+                    .map(|d| d.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())
+            })
+            .map(Value::DateTime)
+            .map_err(|_| invalid("date/time (use ISO 8601)")),
+        FieldType::UniqueIdentifier | FieldType::Lookup => uuid::Uuid::parse_str(trimmed)
+            .map(Value::Guid)
+            .map_err(|_| invalid("GUID")),
+    }
+}
+
+/// Apply a bulk action to the records at `indices`, marking each one dirty
+///
+/// SetField only accepts fields present in `target_fields`, and the value is
+/// parsed into that field's type. Nothing is changed when either check fails.
+pub fn apply_bulk_action(
+    entity: &mut ResolvedEntity,
+    indices: &[usize],
+    action: &BulkAction,
+    target_fields: &[FieldMetadata],
+) -> Result<(), String> {
+    let set_value = match action {
+        BulkAction::SetField { field, value } => {
+            let metadata = target_fields
+                .iter()
+                .find(|f| &f.logical_name == field)
+                .ok_or_else(|| {
+                    format!("Field '{}' does not exist on {}", field, entity.entity_name)
+                })?;
+            let parsed = parse_value_for_field_type(value, &metadata.field_type)?;
+            if !entity.field_names.contains(field) {
+                entity.field_names.push(field.clone());
+            }
+            Some(parsed)
+        }
+        _ => None,
+    };
+
+    let mut dirty_ids = Vec::new();
+    for &idx in indices {
+        let Some(record) = entity.records.get_mut(idx) else {
+            continue;
+        };
+        match action {
+            BulkAction::MarkSkip => {
+                record.action = RecordAction::Skip;
+            }
+            BulkAction::UnmarkSkip => {
+                if record.action == RecordAction::Skip {
+                    record.action = RecordAction::NoChange;
+                }
+            }
+            BulkAction::ResetToOriginal => {
+                // Reset to NoChange (would need original_action tracking for full reset)
+                record.action = RecordAction::NoChange;
+            }
+            BulkAction::SetField { field, .. } => {
+                let value = set_value.clone().unwrap_or(Value::Null);
+                record.fields.insert(field.clone(), value);
+                // Partial updates only send changed fields, so include this one
+                if let Some(changed) = record.changed_fields.as_mut() {
+                    changed.insert(field.clone());
+                }
+            }
+        }
+        dirty_ids.push(record.source_id);
+    }
+
+    for source_id in dirty_ids {
+        entity.mark_dirty(source_id);
+    }
+    Ok(())
+}
+
 /// Column width configuration
 pub const MIN_COLUMN_WIDTH: usize = 8;
 pub const MAX_COLUMN_WIDTH: usize = 50; // Needs to fit lookup format: →entity(uuid) ~47 chars
@@ -736,3 +1162,222 @@ fn format_value_for_width(value: &crate::transfer::Value) -> String {
         Value::Dynamic(dv) => format!("{:?}", dv),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_sort_by_action_groups_creates_before_updates() {
+        let records = vec![
+            ResolvedRecord::update(Uuid::new_v4(), HashMap::new()),
+            ResolvedRecord::create(Uuid::new_v4(), HashMap::new()),
+            ResolvedRecord::update(Uuid::new_v4(), HashMap::new()),
+            ResolvedRecord::create(Uuid::new_v4(), HashMap::new()),
+        ];
+        let mut sorted: Vec<&ResolvedRecord> = records.iter().collect();
+
+        sort_records(&mut sorted, &SortKey::Action);
+
+        let actions: Vec<RecordAction> = sorted.iter().map(|r| r.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                RecordAction::Create,
+                RecordAction::Create,
+                RecordAction::Update,
+                RecordAction::Update,
+            ]
+        );
+        // Stable: ties keep source order
+        assert_eq!(sorted[0].source_id, records[1].source_id);
+        assert_eq!(sorted[1].source_id, records[3].source_id);
+    }
+
+    #[test]
+    fn test_sort_by_field_puts_nulls_last() {
+        let record = |n: Option<i64>| {
+            let mut fields = HashMap::new();
+            if let Some(n) = n {
+                fields.insert("rank".to_string(), Value::Int(n));
+            }
+            ResolvedRecord::create(Uuid::new_v4(), fields)
+        };
+        let records = vec![record(Some(10)), record(None), record(Some(2))];
+        let mut sorted: Vec<&ResolvedRecord> = records.iter().collect();
+
+        sort_records(&mut sorted, &SortKey::Field("rank".to_string()));
+
+        assert_eq!(sorted[0].fields.get("rank"), Some(&Value::Int(2)));
+        assert_eq!(sorted[1].fields.get("rank"), Some(&Value::Int(10)));
+        assert_eq!(sorted[2].fields.get("rank"), None);
+    }
+
+    #[test]
+    fn test_field_diff_marks_exactly_the_changed_fields() {
+        let field_names: Vec<String> = ["name", "revenue", "statuscode", "parentid", "notes"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let parent = Uuid::parse_str("11111111-2222-3333-4444-555555555555").unwrap();
+
+        let source = HashMap::from([
+            ("name".to_string(), Value::String("Contoso Ltd".to_string())),
+            ("revenue".to_string(), Value::Int(500)),
+            ("statuscode".to_string(), Value::OptionSet(1)),
+            ("parentid".to_string(), Value::Guid(parent)),
+            ("notes".to_string(), Value::Null),
+        ]);
+        let target = serde_json::json!({
+            "name": "Contoso",
+            "revenue": 500,
+            "statuscode": 2,
+            "_parentid_value": "11111111-2222-3333-4444-555555555555",
+            "notes": null
+        });
+
+        let diff = compute_field_diff(&field_names, &source, &target);
+
+        let changed: Vec<&str> = diff
+            .iter()
+            .filter(|d| d.changed)
+            .map(|d| d.field_name.as_str())
+            .collect();
+        assert_eq!(changed, vec!["name", "statuscode"]);
+
+        assert_eq!(diff[0].target_display, "Contoso");
+        assert_eq!(diff[0].source_display, "Contoso Ltd");
+        assert_eq!(diff[2].target_display, "2");
+        assert_eq!(diff[2].source_display, "1");
+    }
+
+    #[test]
+    fn test_record_to_json_is_valid_json() {
+        let id = Uuid::new_v4();
+        let account = Uuid::new_v4();
+        let mut fields = HashMap::new();
+        fields.insert(
+            "name".to_string(),
+            Value::String("Say \"hi\"\n".to_string()),
+        );
+        fields.insert("parentaccountid".to_string(), Value::Guid(account));
+        fields.insert("rank".to_string(), Value::Int(3));
+        let mut record = ResolvedRecord::create(id, fields);
+        record.error = Some("lookup failed".to_string());
+
+        let names = vec![
+            "name".to_string(),
+            "parentaccountid".to_string(),
+            "rank".to_string(),
+            "missing".to_string(),
+        ];
+        let json: serde_json::Value =
+            serde_json::from_str(&record_to_json(&record, &names)).expect("valid JSON");
+
+        assert_eq!(json["source_id"], id.to_string());
+        assert_eq!(json["fields"]["name"], "Say \"hi\"\n");
+        assert_eq!(json["fields"]["parentaccountid"], account.to_string());
+        assert_eq!(json["fields"]["rank"], 3);
+        assert!(json["fields"]["missing"].is_null());
+        assert_eq!(json["error"], "lookup failed");
+    }
+
+    #[test]
+    fn test_bulk_set_field_applies_to_exactly_the_scoped_records() {
+        let record = |action: RecordAction, name: &str| {
+            let mut fields = HashMap::new();
+            fields.insert("name".to_string(), Value::String(name.to_string()));
+            let mut record = ResolvedRecord::create(Uuid::new_v4(), fields);
+            record.action = action;
+            record
+        };
+        let mut entity = ResolvedEntity::new("account", 1, "accountid");
+        entity.field_names = vec!["name".to_string()];
+        entity.records = vec![
+            record(RecordAction::Create, "Contoso"),
+            record(RecordAction::Update, "Fabrikam"),
+            record(RecordAction::Create, "Contoso Ltd"),
+            record(RecordAction::Skip, "Contoso Skip"),
+        ];
+        let action = BulkAction::SetField {
+            field: "nrq_migrated".to_string(),
+            value: "true".to_string(),
+        };
+        let target_fields = vec![FieldMetadata {
+            logical_name: "nrq_migrated".to_string(),
+            schema_name: None,
+            display_name: None,
+            field_type: FieldType::Boolean,
+            is_required: false,
+            is_primary_key: false,
+            max_length: None,
+            related_entity: None,
+            navigation_property_name: None,
+            option_values: vec![],
+        }];
+
+        // Filtered: Create records whose values match "contoso"
+        let indices = bulk_scope_indices(
+            &entity,
+            BulkActionScope::Filtered,
+            RecordFilter::Create,
+            "contoso",
+            &HashSet::new(),
+        );
+        assert_eq!(indices, vec![0, 2]);
+
+        // Only displayed values count: every name's Debug form is `String("...")`
+        let by_debug_form = bulk_scope_indices(
+            &entity,
+            BulkActionScope::Filtered,
+            RecordFilter::All,
+            "string",
+            &HashSet::new(),
+        );
+        assert!(by_debug_form.is_empty());
+
+        // Fields missing from the target and values of the wrong type are rejected
+        let unknown = BulkAction::SetField {
+            field: "nrq_typo".to_string(),
+            value: "true".to_string(),
+        };
+        assert!(apply_bulk_action(&mut entity, &indices, &unknown, &target_fields).is_err());
+        let mistyped = BulkAction::SetField {
+            field: "nrq_migrated".to_string(),
+            value: "maybe".to_string(),
+        };
+        assert!(apply_bulk_action(&mut entity, &indices, &mistyped, &target_fields).is_err());
+        assert!(entity.dirty_record_ids.is_empty());
+        assert_eq!(entity.field_names, vec!["name".to_string()]);
+
+        apply_bulk_action(&mut entity, &indices, &action, &target_fields).unwrap();
+
+        let stamped: Vec<bool> = entity
+            .records
+            .iter()
+            .map(|r| r.fields.contains_key("nrq_migrated"))
+            .collect();
+        assert_eq!(stamped, vec![true, false, true, false]);
+        assert_eq!(
+            entity.records[0].fields.get("nrq_migrated"),
+            Some(&Value::Bool(true))
+        );
+        assert!(entity.is_dirty(entity.records[0].source_id));
+        assert!(entity.is_dirty(entity.records[2].source_id));
+        assert_eq!(entity.dirty_record_ids.len(), 2);
+        assert!(entity.field_names.contains(&"nrq_migrated".to_string()));
+
+        // Selected: only the multi-selected record
+        let selected: HashSet<Uuid> = [entity.records[3].source_id].into_iter().collect();
+        let indices = bulk_scope_indices(
+            &entity,
+            BulkActionScope::Selected,
+            RecordFilter::All,
+            "",
+            &selected,
+        );
+        assert_eq!(indices, vec![3]);
+    }
+}
@@ -14,10 +14,16 @@ use crate::tui::widgets::{ListEvent, ListItem, TextInputEvent};
 use crate::tui::{Alignment, Element, LayeredView, LayoutConstraint, Subscription, Theme};
 
 use super::modals;
-use super::state::{BulkAction, BulkActionScope, Msg, PreviewModal, RecordFilter, State};
+use super::state::{
+    BulkAction, BulkActionScope, Msg, PreviewModal, RecordFilter, SortKey, State, sort_records,
+};
 
 /// Render the preview app view
 pub fn render(state: &mut State, theme: &Theme) -> LayeredView<Msg> {
+    if state.warm_cache_only {
+        return LayeredView::new(render_warm_cache(state, theme));
+    }
+
     let content = match &state.resolved {
         Resource::NotAsked => Element::text("No data loaded. Press Back to return to editor."),
         Resource::Loading => Element::text("Loading resolved records..."),
@@ -41,26 +47,13 @@ pub fn render(state: &mut State, theme: &Theme) -> LayeredView<Msg> {
                     (&state.record_detail_state, &state.resolved)
                 {
                     if let Some(entity) = resolved.entities.get(state.current_entity_idx) {
-                        // Get the actual record from filtered list
-                        let filtered: Vec<_> = entity
+                        // The record the modal was opened for
+                        let record = entity
                             .records
                             .iter()
-                            .filter(|r| state.filter.matches(r.action))
-                            .filter(|r| {
-                                let query = state.search_field.value().to_lowercase();
-                                if query.is_empty() {
-                                    return true;
-                                }
-                                if r.source_id.to_string().to_lowercase().contains(&query) {
-                                    return true;
-                                }
-                                r.fields
-                                    .values()
-                                    .any(|v| format_value(v).to_lowercase().contains(&query))
-                            })
-                            .collect();
-
-                        if let Some(record) = filtered.get(*record_idx) {
+                            .find(|r| r.source_id == detail_state.source_id);
+
+                        if let Some(record) = record {
                             modals::record_details::render(
                                 detail_state,
                                 record,
@@ -137,14 +130,15 @@ fn render_preview(state: &State, resolved: &ResolvedTransfer, theme: &Theme) ->
         .build()
 }
 
-/// Get records filtered by the current filter and search query
-fn get_filtered_records<'a>(
+/// Get records filtered by the current filter and search query, in sort order
+pub(super) fn get_filtered_records<'a>(
     entity: &'a ResolvedEntity,
     filter: RecordFilter,
     search_query: &str,
+    sort_key: &SortKey,
 ) -> Vec<&'a ResolvedRecord> {
     let query = search_query.to_lowercase();
-    entity
+    let mut records: Vec<&ResolvedRecord> = entity
         .records
         .iter()
         .filter(|r| filter.matches(r.action))
@@ -161,7 +155,9 @@ fn get_filtered_records<'a>(
                 .values()
                 .any(|v| format_value(v).to_lowercase().contains(&query))
         })
-        .collect()
+        .collect();
+    sort_records(&mut records, sort_key);
+    records
 }
 
 /// Render table header row
@@ -191,9 +187,14 @@ fn render_table_header(state: &State, entity: &ResolvedEntity, theme: &Theme) ->
     }
 
     header_parts.push(Span::styled("    ", header_style)); // Space for checkbox [✓] or [ ]
-    header_parts.push(Span::styled(format!("{:<10}", "Action"), header_style));
+    let action_header = sort_header_label("Action", state.sort_key == SortKey::Action);
+    header_parts.push(Span::styled(format!("{:<10}", action_header), header_style));
     header_parts.push(Span::raw(" │ "));
-    header_parts.push(Span::styled(format!("{:<36}", "Source ID"), header_style));
+    let source_id_header = sort_header_label("Source ID", state.sort_key == SortKey::SourceId);
+    header_parts.push(Span::styled(
+        format!("{:<36}", source_id_header),
+        header_style,
+    ));
 
     // Get visible column range
     let visible_range = state.visible_column_range(entity.field_names.len());
@@ -207,9 +208,11 @@ fn render_table_header(state: &State, entity: &ResolvedEntity, theme: &Theme) ->
     for i in visible_range.clone() {
         let field = &entity.field_names[i];
         let width = state.column_widths.get(i).copied().unwrap_or(15);
+        let is_sorted = matches!(&state.sort_key, SortKey::Field(name) if name == field);
+        let label = sort_header_label(field, is_sorted);
         header_parts.push(Span::raw(" │ "));
         header_parts.push(Span::styled(
-            format!("{:<width$}", truncate_str(field, width), width = width),
+            format!("{:<width$}", truncate_str(&label, width), width = width),
             header_style,
         ));
     }
@@ -227,9 +230,23 @@ fn render_table_header(state: &State, entity: &ResolvedEntity, theme: &Theme) ->
         .build()
 }
 
+/// Header label with a sort indicator when the column is the active sort
+fn sort_header_label(name: &str, is_sorted: bool) -> String {
+    if is_sorted {
+        format!("{} ▲", name)
+    } else {
+        name.to_string()
+    }
+}
+
 /// Render the record table as a list with virtual scrolling
 fn render_record_table(state: &State, entity: &ResolvedEntity, theme: &Theme) -> Element<Msg> {
-    let filtered_records = get_filtered_records(entity, state.filter, state.search_field.value());
+    let filtered_records = get_filtered_records(
+        entity,
+        state.filter,
+        state.search_field.value(),
+        &state.sort_key,
+    );
     let total_count = filtered_records.len();
 
     if total_count == 0 {
@@ -570,6 +587,34 @@ fn truncate_str(s: &str, max_len: usize) -> String {
 
 // Placeholder modal renderers - will be implemented in later chunks
 
+/// Summary shown in warm-cache mode instead of the record table
+fn render_warm_cache(state: &State, theme: &Theme) -> Element<Msg> {
+    let line = match &state.resolved {
+        Resource::Loading => Line::from(Span::styled(
+            format!("Warming cache for '{}'...", state.config_name),
+            Style::default().fg(theme.text_secondary),
+        )),
+        Resource::Failure(err) => Line::from(vec![
+            Span::styled("Error: ", Style::default().fg(theme.accent_error)),
+            Span::styled(err.clone(), Style::default().fg(theme.text_primary)),
+        ]),
+        _ => Line::from(vec![
+            Span::styled("✓ ", Style::default().fg(theme.accent_success)),
+            Span::styled(
+                format!(
+                    "Cached {} records across {} fetches for '{}'. Press Esc to return.",
+                    state.warmed_records, state.warmed_entities, state.config_name
+                ),
+                Style::default().fg(theme.text_primary),
+            ),
+        ]),
+    };
+
+    Element::panel(Element::styled_text(line).build())
+        .title("Warm Cache")
+        .build()
+}
+
 fn render_record_details_placeholder(record_idx: usize, theme: &Theme) -> Element<Msg> {
     let content = Element::text(&format!(
         "Record Details (index: {})\n\nPress Esc to close.",
@@ -610,6 +655,23 @@ fn render_bulk_actions_placeholder(theme: &Theme) -> Element<Msg> {
 pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
     let mut subs = vec![];
 
+    // Cancel requests from the Lua transform's loading screen
+    if state.lua_transform_cancel.is_some() {
+        subs.push(Subscription::subscribe(
+            format!("loading:cancel:{:?}", crate::tui::AppId::TransferPreview),
+            |_| Some(Msg::CancelLuaTransform),
+        ));
+    }
+
+    if state.warm_cache_only {
+        subs.push(Subscription::keyboard(
+            KeyCode::Esc,
+            "Back to configs",
+            Msg::Back,
+        ));
+        return subs;
+    }
+
     // Record details modal subscriptions
     if let Some(ref detail) = state.record_detail_state {
         if detail.editing {
@@ -692,6 +754,16 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
                 "Edit",
                 Msg::ToggleEditMode,
             ));
+            subs.push(Subscription::keyboard(
+                KeyCode::Char('y'),
+                "Copy field value",
+                Msg::CopyDetailField,
+            ));
+            subs.push(Subscription::keyboard(
+                KeyCode::Char('Y'),
+                "Copy record as JSON",
+                Msg::CopyRecordAsJson,
+            ));
         }
         return subs;
     }
@@ -728,7 +800,7 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
             ));
         }
 
-        // Action selection (a/b/c)
+        // Action selection (a/b/c/d)
         subs.push(Subscription::keyboard(
             KeyCode::Char('a'),
             "Mark Skip",
@@ -744,6 +816,11 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
             "Reset to Original",
             Msg::SetBulkAction(BulkAction::ResetToOriginal),
         ));
+        subs.push(Subscription::keyboard(
+            KeyCode::Char('d'),
+            "Set Field Value",
+            Msg::SetBulkAction(BulkAction::set_field()),
+        ));
 
         return subs;
     }
@@ -879,6 +956,13 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
         Msg::CycleFilter,
     ));
 
+    // Sorting
+    subs.push(Subscription::keyboard(
+        KeyCode::Char('o'),
+        "Cycle sort",
+        Msg::CycleSort,
+    ));
+
     // Horizontal scrolling (columns)
     subs.push(Subscription::keyboard(
         KeyCode::Left,
@@ -949,12 +1033,29 @@ pub fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
         Msg::Refresh,
     ));
 
+    // Saved edit-set
+    subs.push(Subscription::keyboard(
+        KeyCode::Char('w'),
+        "Save edits",
+        Msg::SaveEdits,
+    ));
+    subs.push(Subscription::keyboard(
+        KeyCode::Char('l'),
+        "Load edits",
+        Msg::LoadEdits,
+    ));
+
     // Excel
     subs.push(Subscription::keyboard(
         KeyCode::Char('x'),
         "Export Excel",
         Msg::ExportExcel,
     ));
+    subs.push(Subscription::keyboard(
+        KeyCode::Char('X'),
+        "Export errors",
+        Msg::ExportErrors,
+    ));
     subs.push(Subscription::keyboard(
         KeyCode::Char('i'),
         "Import Excel",
@@ -0,0 +1,29 @@
+//! System clipboard access
+
+use std::sync::Mutex;
+
+/// Clipboard handle kept alive for the whole session
+///
+/// On X11/Wayland the copying process owns the clipboard contents, so dropping
+/// the handle straight after `set_text` can lose them.
+static CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
+
+/// Copy text to the system clipboard
+///
+/// Fails when no clipboard is available (e.g. headless sessions or SSH
+/// without a display); callers should report the error rather than abort.
+pub fn copy_text(text: &str) -> Result<(), String> {
+    let mut guard = CLIPBOARD
+        .lock()
+        .map_err(|_| "Clipboard lock poisoned".to_string())?;
+
+    if guard.is_none() {
+        *guard = Some(arboard::Clipboard::new().map_err(|e| e.to_string())?);
+    }
+
+    guard
+        .as_mut()
+        .expect("clipboard initialized above")
+        .set_text(text.to_string())
+        .map_err(|e| e.to_string())
+}
@@ -97,6 +97,8 @@ pub struct ParallelConfig {
     pub on_complete: Option<AppId>,
     pub caller: Option<AppId>,
     pub cancellable: bool,
+    /// Maximum number of tasks running at once (None = all at once)
+    pub max_concurrency: Option<usize>,
 }
 
 impl Default for ParallelConfig {
@@ -106,6 +108,7 @@ impl Default for ParallelConfig {
             on_complete: None,
             caller: None,
             cancellable: false,
+            max_concurrency: None,
         }
     }
 }
@@ -305,6 +308,12 @@ impl<Msg: Send + 'static> ParallelBuilder<Msg> {
         self
     }
 
+    /// Limit how many tasks run at once; the rest start in order as slots free up
+    pub fn max_concurrency(mut self, max: usize) -> Self {
+        self.config.max_concurrency = Some(max.max(1));
+        self
+    }
+
     /// Build the command with a message mapper that converts task results to messages
     /// The mapper receives (task_index, result) and should downcast the result to the expected type
     pub fn build(
@@ -319,8 +328,70 @@ impl<Msg: Send + 'static> ParallelBuilder<Msg> {
     }
 }
 
+/// Gate parallel tasks so that at most `max` of them run at once
+///
+/// Each task waits for a slot before its future starts; slots are handed out
+/// in task order. Without a limit the tasks are returned unchanged.
+pub fn limit_concurrency(tasks: Vec<ParallelTask>, max: Option<usize>) -> Vec<ParallelTask> {
+    let Some(max) = max else {
+        return tasks;
+    };
+    let slots = std::sync::Arc::new(tokio::sync::Semaphore::new(max.max(1)));
+
+    tasks
+        .into_iter()
+        .map(|task| {
+            let slots = slots.clone();
+            let future = task.future;
+            ParallelTask {
+                description: task.description,
+                future: Box::pin(async move {
+                    let _slot = slots.acquire_owned().await;
+                    future.await
+                }),
+                progress_rx: task.progress_rx,
+            }
+        })
+        .collect()
+}
+
 impl<Msg> Default for Command<Msg> {
     fn default() -> Self {
         Command::None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_concurrency_cap_limits_in_flight_tasks() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let mut builder = Command::<()>::perform_parallel().max_concurrency(2);
+        for i in 0..6 {
+            let in_flight = in_flight.clone();
+            let peak = peak.clone();
+            builder = builder.add_task(format!("task {}", i), async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                i
+            });
+        }
+
+        let Command::PerformParallel { tasks, config, .. } = builder.build(|_, _| ()) else {
+            panic!("expected a parallel command");
+        };
+        let gated = limit_concurrency(tasks, config.max_concurrency);
+        let results = futures::future::join_all(gated.into_iter().map(|t| t.future)).await;
+
+        assert_eq!(results.len(), 6);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+}
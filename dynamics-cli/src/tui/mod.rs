@@ -1,5 +1,6 @@
 pub mod app;
 pub mod apps;
+pub mod clipboard;
 pub mod color;
 pub mod command;
 pub mod element;
@@ -0,0 +1,243 @@
+//! Command palette modal for fuzzy-jumping to apps and global actions
+
+use crate::tui::element::{ColumnBuilder, LayoutConstraint, RowBuilder};
+use crate::tui::{Element, Theme};
+use crate::{col, spacer, use_constraints};
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+use std::time::Instant;
+
+/// One searchable palette entry
+#[derive(Clone, Debug)]
+pub struct PaletteEntry<T> {
+    /// Text matched against the query (an app title or action name)
+    pub label: String,
+    /// What activating the entry does
+    pub target: T,
+    /// When the entry was last used, if ever
+    pub last_used: Option<Instant>,
+    /// How many times the entry has been used
+    pub use_count: u32,
+}
+
+/// Filter and order palette entries for a query
+///
+/// Entries that don't fuzzy-match the query are dropped. Matches are ordered
+/// by score, then by recency and frequency; an empty query keeps every entry
+/// and orders by recency and frequency alone.
+pub fn rank_entries<T: Clone>(entries: &[PaletteEntry<T>], query: &str) -> Vec<PaletteEntry<T>> {
+    let query = query.trim();
+    let matcher = SkimMatcherV2::default().ignore_case();
+
+    let mut scored: Vec<(i64, &PaletteEntry<T>)> = entries
+        .iter()
+        .filter_map(|entry| {
+            if query.is_empty() {
+                Some((0, entry))
+            } else {
+                matcher
+                    .fuzzy_match(&entry.label, query)
+                    .map(|score| (score, entry))
+            }
+        })
+        .collect();
+
+    scored.sort_by(|(score_a, a), (score_b, b)| {
+        score_b
+            .cmp(score_a)
+            .then_with(|| b.last_used.cmp(&a.last_used))
+            .then_with(|| b.use_count.cmp(&a.use_count))
+            .then_with(|| a.label.cmp(&b.label))
+    });
+
+    scored.into_iter().map(|(_, entry)| entry.clone()).collect()
+}
+
+/// Builder for the command palette modal
+///
+/// The palette is keyboard-driven: the owner feeds it the current query,
+/// the ranked entries and the selected index on every render.
+///
+/// # Example
+/// ```rust
+/// let modal = CommandPaletteModal::new(&query, &ranked)
+///     .selected(selected)
+///     .build();
+/// ```
+pub struct CommandPaletteModal<'a, T> {
+    query: &'a str,
+    entries: &'a [PaletteEntry<T>],
+    selected: usize,
+    visible_rows: usize,
+    width: Option<u16>,
+    height: Option<u16>,
+}
+
+impl<'a, T> CommandPaletteModal<'a, T> {
+    /// Create a palette showing ranked entries for a query
+    pub fn new(query: &'a str, entries: &'a [PaletteEntry<T>]) -> Self {
+        Self {
+            query,
+            entries,
+            selected: 0,
+            visible_rows: 12,
+            width: Some(60),
+            height: Some(22),
+        }
+    }
+
+    /// Set the highlighted entry
+    pub fn selected(mut self, index: usize) -> Self {
+        self.selected = index;
+        self
+    }
+
+    /// Set how many entries are shown at once
+    pub fn visible_rows(mut self, rows: usize) -> Self {
+        self.visible_rows = rows.max(1);
+        self
+    }
+
+    /// Set modal width
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set modal height
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    fn entry_line(entry: &PaletteEntry<T>, is_selected: bool, theme: &Theme) -> Line<'static> {
+        let marker = if is_selected { "▶ " } else { "  " };
+        let label_style = if is_selected {
+            Style::default().fg(theme.accent_primary).bold()
+        } else {
+            Style::default().fg(theme.text_primary)
+        };
+
+        let mut spans = vec![
+            Span::styled(marker, Style::default().fg(theme.accent_primary)),
+            Span::styled(entry.label.clone(), label_style),
+        ];
+        if entry.last_used.is_some() {
+            spans.push(Span::styled(
+                "  (recent)",
+                Style::default().fg(theme.text_tertiary),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// Build the modal Element
+    pub fn build<Msg: Clone + 'static>(self) -> Element<Msg> {
+        use_constraints!();
+        let theme = &crate::global_runtime_config().theme;
+
+        let query_line = Element::styled_text(Line::from(vec![
+            Span::styled("> ", Style::default().fg(theme.accent_tertiary).bold()),
+            Span::styled(
+                format!("{}▏", self.query),
+                Style::default().fg(theme.text_primary),
+            ),
+        ]))
+        .build();
+
+        // Keep the selected entry inside the visible window
+        let first = self
+            .selected
+            .saturating_sub(self.visible_rows.saturating_sub(1));
+        let lines: Vec<Element<Msg>> = if self.entries.is_empty() {
+            vec![
+                Element::styled_text(Line::from(Span::styled(
+                    "  No matching apps or actions",
+                    Style::default().fg(theme.text_tertiary),
+                )))
+                .build(),
+            ]
+        } else {
+            self.entries
+                .iter()
+                .enumerate()
+                .skip(first)
+                .take(self.visible_rows)
+                .map(|(i, entry)| {
+                    Element::styled_text(Self::entry_line(entry, i == self.selected, theme)).build()
+                })
+                .collect()
+        };
+        let entries_panel = Element::panel(Element::column(lines).spacing(0).build())
+            .title(format!("Apps & Actions ({})", self.entries.len()))
+            .build();
+
+        let help_text = Element::styled_text(Line::from(vec![
+            Span::styled("↑/↓", Style::default().fg(theme.accent_tertiary).bold()),
+            Span::styled(" select  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Enter", Style::default().fg(theme.accent_tertiary).bold()),
+            Span::styled(" open  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.accent_tertiary).bold()),
+            Span::styled(" close", Style::default().fg(theme.text_secondary)),
+        ]))
+        .build();
+
+        let modal_body = col![
+            Element::styled_text(
+                Line::from(vec![
+                    Span::styled("Command Palette", Style::default().fg(theme.accent_tertiary).bold())
+                ])
+            ).build() => Length(1),
+            spacer!() => Length(1),
+            query_line => Length(1),
+            spacer!() => Length(1),
+            entries_panel => Fill(1),
+            spacer!() => Length(1),
+            help_text => Length(1),
+        ];
+
+        Element::panel(Element::container(modal_body).padding(2).build())
+            .width(self.width.unwrap_or(60))
+            .height(self.height.unwrap_or(22))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn entry(label: &str, last_used: Option<Instant>, use_count: u32) -> PaletteEntry<String> {
+        PaletteEntry {
+            label: label.to_string(),
+            target: label.to_string(),
+            last_used,
+            use_count,
+        }
+    }
+
+    #[test]
+    fn test_title_substring_ranks_app_first() {
+        let now = Instant::now();
+        let entries = vec![
+            entry("Transfer Configs", Some(now), 9),
+            entry("Mapping Editor", Some(now - Duration::from_secs(5)), 4),
+            entry("Transfer Preview", None, 0),
+            entry("Entity Sync", None, 0),
+            entry("Settings", None, 0),
+        ];
+
+        let ranked = rank_entries(&entries, "preview");
+        assert_eq!(ranked[0].label, "Transfer Preview");
+        assert!(ranked.iter().all(|e| e.label != "Entity Sync"));
+
+        // An empty query lists everything, most recently used first
+        let ranked = rank_entries(&entries, "");
+        assert_eq!(ranked.len(), entries.len());
+        assert_eq!(ranked[0].label, "Transfer Configs");
+        assert_eq!(ranked[1].label, "Mapping Editor");
+    }
+}
@@ -0,0 +1,223 @@
+//! Export columns modal for choosing which field attributes an export writes
+
+use crate::tui::element::{ColumnBuilder, LayoutConstraint, RowBuilder};
+use crate::tui::widgets::{ListItem, ListState};
+use crate::tui::{Element, FocusId, Theme};
+use crate::{button_row, col, spacer, use_constraints};
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+
+/// Column item for display in the list
+#[derive(Clone)]
+pub struct ExportColumnItem<Msg> {
+    pub label: String,
+    pub selected: bool,
+    pub on_toggle: Msg,
+}
+
+impl<Msg: Clone> ListItem for ExportColumnItem<Msg> {
+    type Msg = Msg;
+
+    fn to_element(
+        &self,
+        is_selected: bool,
+        _is_multi_selected: bool,
+        _is_hovered: bool,
+    ) -> Element<Self::Msg> {
+        let theme = &crate::global_runtime_config().theme;
+
+        let (marker, marker_color) = if self.selected {
+            ("[x] ", theme.accent_success)
+        } else {
+            ("[ ] ", theme.text_tertiary)
+        };
+        let mut builder = Element::styled_text(Line::from(vec![
+            Span::styled(marker, Style::default().fg(marker_color)),
+            Span::styled(self.label.clone(), Style::default().fg(theme.text_primary)),
+        ]));
+
+        if is_selected {
+            builder = builder.background(Style::default().bg(theme.bg_surface));
+        }
+
+        builder.build()
+    }
+}
+
+/// Builder for the export column selection modal
+///
+/// # Example
+/// ```rust
+/// let modal = ExportColumnsModal::new()
+///     .columns(column_items)
+///     .list_state(list_state)
+///     .on_list_navigate(Msg::ExportColumnsListNavigate)
+///     .on_list_select(Msg::ExportColumnsListSelect)
+///     .on_list_activate(Msg::ToggleExportColumn)
+///     .on_export(Msg::ConfirmExportToExcel)
+///     .on_cancel(Msg::CloseExportColumnsModal)
+///     .build();
+/// ```
+pub struct ExportColumnsModal<Msg> {
+    columns: Vec<ExportColumnItem<Msg>>,
+    list_state: ListState,
+    on_list_navigate: Option<fn(crossterm::event::KeyCode) -> Msg>,
+    on_list_select: Option<fn(usize) -> Msg>,
+    on_list_activate: Option<fn(usize) -> Msg>,
+    on_export: Option<Msg>,
+    on_cancel: Option<Msg>,
+    width: Option<u16>,
+    height: Option<u16>,
+}
+
+impl<Msg: Clone> ExportColumnsModal<Msg> {
+    /// Create a new export columns modal
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            list_state: ListState::new(),
+            on_list_navigate: None,
+            on_list_select: None,
+            on_list_activate: None,
+            on_export: None,
+            on_cancel: None,
+            width: Some(60),
+            height: Some(22),
+        }
+    }
+
+    /// Set the list of columns
+    pub fn columns(mut self, columns: Vec<ExportColumnItem<Msg>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the list state
+    pub fn list_state(mut self, state: ListState) -> Self {
+        self.list_state = state;
+        self
+    }
+
+    /// Set list navigation handler
+    pub fn on_list_navigate(mut self, handler: fn(crossterm::event::KeyCode) -> Msg) -> Self {
+        self.on_list_navigate = Some(handler);
+        self
+    }
+
+    /// Set list select handler
+    pub fn on_list_select(mut self, handler: fn(usize) -> Msg) -> Self {
+        self.on_list_select = Some(handler);
+        self
+    }
+
+    /// Set list activate handler (Enter toggles a column)
+    pub fn on_list_activate(mut self, handler: fn(usize) -> Msg) -> Self {
+        self.on_list_activate = Some(handler);
+        self
+    }
+
+    /// Set the message sent when Export is clicked
+    pub fn on_export(mut self, msg: Msg) -> Self {
+        self.on_export = Some(msg);
+        self
+    }
+
+    /// Set the message sent when Cancel is clicked
+    pub fn on_cancel(mut self, msg: Msg) -> Self {
+        self.on_cancel = Some(msg);
+        self
+    }
+
+    /// Set modal width
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set modal height
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Build the modal Element
+    pub fn build(self) -> Element<Msg> {
+        use_constraints!();
+        let theme = &crate::global_runtime_config().theme;
+
+        let list_handler = self
+            .on_list_navigate
+            .expect("ExportColumnsModal requires on_list_navigate");
+        let select_handler = self
+            .on_list_select
+            .expect("ExportColumnsModal requires on_list_select");
+        let mut columns_list = Element::list(
+            FocusId::new("export-columns-list"),
+            &self.columns,
+            &self.list_state,
+            theme,
+        )
+        .on_select(select_handler)
+        .on_navigate(list_handler);
+        if let Some(activate_handler) = self.on_list_activate {
+            columns_list = columns_list.on_activate(activate_handler);
+        }
+        let columns_list = columns_list.build();
+
+        let columns_panel = Element::panel(columns_list)
+            .title("Field Sheet Columns")
+            .build();
+
+        let buttons = button_row![
+            (
+                "export-columns-export",
+                "Export",
+                self.on_export
+                    .clone()
+                    .expect("ExportColumnsModal requires on_export")
+            ),
+            (
+                "export-columns-cancel",
+                "Cancel",
+                self.on_cancel
+                    .clone()
+                    .expect("ExportColumnsModal requires on_cancel")
+            ),
+        ];
+
+        let help_text = Element::styled_text(Line::from(vec![
+            Span::styled("Press ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Space", Style::default().fg(theme.accent_tertiary).bold()),
+            Span::styled(
+                " to toggle a column. The selection is saved for this comparison.",
+                Style::default().fg(theme.text_secondary),
+            ),
+        ]))
+        .build();
+
+        let modal_body = col![
+            Element::styled_text(
+                Line::from(vec![
+                    Span::styled("Export to Excel", Style::default().fg(theme.accent_tertiary).bold())
+                ])
+            ).build() => Length(1),
+            spacer!() => Length(1),
+            help_text => Length(2),
+            spacer!() => Length(1),
+            columns_panel => Fill(1),
+            spacer!() => Length(1),
+            buttons => Length(3),
+        ];
+
+        Element::panel(Element::container(modal_body).padding(2).build())
+            .width(self.width.unwrap_or(60))
+            .height(self.height.unwrap_or(22))
+            .build()
+    }
+}
+
+impl<Msg: Clone> Default for ExportColumnsModal<Msg> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
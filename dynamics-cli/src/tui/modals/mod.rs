@@ -1,19 +1,25 @@
 pub mod app_overview;
+pub mod command_palette;
 pub mod confirmation;
 pub mod error;
 pub mod examples;
+pub mod export_columns;
 pub mod help;
 pub mod manual_mappings;
 pub mod negative_matches;
 pub mod prefix_mappings;
+pub mod search_presets;
 pub mod warning;
 
 pub use app_overview::AppOverviewModal;
+pub use command_palette::{CommandPaletteModal, PaletteEntry};
 pub use confirmation::ConfirmationModal;
 pub use error::ErrorModal;
 pub use examples::{ExamplePairItem, ExamplesModal};
+pub use export_columns::{ExportColumnItem, ExportColumnsModal};
 pub use help::HelpModal;
 pub use manual_mappings::{ManualMappingItem, ManualMappingsModal};
 pub use negative_matches::{NegativeMatchItem, NegativeMatchesModal};
 pub use prefix_mappings::{PrefixMappingItem, PrefixMappingsModal};
+pub use search_presets::{SearchPresetItem, SearchPresetsModal};
 pub use warning::WarningModal;
@@ -47,6 +47,8 @@ impl<Msg: Clone> ListItem for NegativeMatchItem<Msg> {
 ///     .matches(negative_match_items)
 ///     .list_state(list_state)
 ///     .on_delete(Msg::DeleteNegativeMatch)
+///     .on_import(Msg::OpenNegativeMatchesImport)
+///     .on_export(Msg::ExportNegativeMatches)
 ///     .on_close(Msg::CloseNegativeMatchesModal)
 ///     .build();
 /// ```
@@ -56,6 +58,8 @@ pub struct NegativeMatchesModal<Msg> {
     on_list_navigate: Option<fn(crossterm::event::KeyCode) -> Msg>,
     on_list_select: Option<fn(usize) -> Msg>,
     on_delete: Option<Msg>,
+    on_import: Option<Msg>,
+    on_export: Option<Msg>,
     on_close: Option<Msg>,
     width: Option<u16>,
     height: Option<u16>,
@@ -70,6 +74,8 @@ impl<Msg: Clone> NegativeMatchesModal<Msg> {
             on_list_navigate: None,
             on_list_select: None,
             on_delete: None,
+            on_import: None,
+            on_export: None,
             on_close: None,
             width: Some(70),
             height: Some(25),
@@ -106,6 +112,18 @@ impl<Msg: Clone> NegativeMatchesModal<Msg> {
         self
     }
 
+    /// Set the message sent when Import is clicked
+    pub fn on_import(mut self, msg: Msg) -> Self {
+        self.on_import = Some(msg);
+        self
+    }
+
+    /// Set the message sent when Export is clicked
+    pub fn on_export(mut self, msg: Msg) -> Self {
+        self.on_export = Some(msg);
+        self
+    }
+
     /// Set the message sent when Close is clicked
     pub fn on_close(mut self, msg: Msg) -> Self {
         self.on_close = Some(msg);
@@ -159,6 +177,20 @@ impl<Msg: Clone> NegativeMatchesModal<Msg> {
                     .clone()
                     .expect("NegativeMatchesModal requires on_delete")
             ),
+            (
+                "negative-import",
+                "Import",
+                self.on_import
+                    .clone()
+                    .expect("NegativeMatchesModal requires on_import")
+            ),
+            (
+                "negative-export",
+                "Export",
+                self.on_export
+                    .clone()
+                    .expect("NegativeMatchesModal requires on_export")
+            ),
             (
                 "negative-close",
                 "Close",
@@ -177,7 +209,14 @@ impl<Msg: Clone> NegativeMatchesModal<Msg> {
             Span::styled("Press ", Style::default().fg(theme.text_secondary)),
             Span::styled("d", Style::default().fg(theme.accent_tertiary).bold()),
             Span::styled(
-                " on a prefix-matched field to add it.",
+                " on a prefix-matched field to add it, ",
+                Style::default().fg(theme.text_secondary),
+            ),
+            Span::styled("i", Style::default().fg(theme.accent_tertiary).bold()),
+            Span::styled("/", Style::default().fg(theme.text_secondary)),
+            Span::styled("e", Style::default().fg(theme.accent_tertiary).bold()),
+            Span::styled(
+                " to import/export a file.",
                 Style::default().fg(theme.text_secondary),
             ),
         ]))
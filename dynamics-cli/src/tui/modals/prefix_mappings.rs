@@ -188,7 +188,7 @@ impl<Msg: Clone> PrefixMappingsModal<Msg> {
             &self.source_input_state.value,
             &self.source_input_state.state,
         )
-        .placeholder("e.g., cr123_")
+        .placeholder("e.g., cr123_, cr123_*_c or re:cr\\d+_")
         .on_event(source_handler)
         .build();
 
@@ -200,7 +200,7 @@ impl<Msg: Clone> PrefixMappingsModal<Msg> {
             &self.target_input_state.value,
             &self.target_input_state.state,
         )
-        .placeholder("e.g., new_")
+        .placeholder("e.g., new_, new_* or new_$1")
         .on_event(target_handler)
         .build();
 
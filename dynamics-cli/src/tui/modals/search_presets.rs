@@ -0,0 +1,281 @@
+//! Search presets modal for saving and restoring comparison search views
+
+use crate::tui::element::{ColumnBuilder, LayoutConstraint, RowBuilder};
+use crate::tui::widgets::{ListItem, ListState, TextInputField};
+use crate::tui::{Element, FocusId, Theme};
+use crate::{button_row, col, spacer, use_constraints};
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+
+/// Search preset for display in the list
+#[derive(Clone)]
+pub struct SearchPresetItem<Msg> {
+    pub name: String,
+    pub query: String,
+    pub match_mode: String,
+    pub hide_mode: String,
+    pub(crate) _phantom: std::marker::PhantomData<Msg>,
+}
+
+impl<Msg: Clone> ListItem for SearchPresetItem<Msg> {
+    type Msg = Msg;
+
+    fn to_element(
+        &self,
+        is_selected: bool,
+        _is_multi_selected: bool,
+        _is_hovered: bool,
+    ) -> Element<Self::Msg> {
+        let theme = &crate::global_runtime_config().theme;
+        let query = if self.query.is_empty() {
+            "(no query)".to_string()
+        } else {
+            format!("\"{}\"", self.query)
+        };
+
+        let mut builder = Element::styled_text(Line::from(vec![
+            Span::styled(self.name.clone(), Style::default().fg(theme.text_primary)),
+            Span::styled(
+                format!("  {} · {} · {}", query, self.match_mode, self.hide_mode),
+                Style::default().fg(theme.text_tertiary),
+            ),
+        ]));
+
+        if is_selected {
+            builder = builder.background(Style::default().bg(theme.bg_surface));
+        }
+
+        builder.build()
+    }
+}
+
+/// Builder for search presets management modal
+///
+/// # Example
+/// ```rust
+/// let modal = SearchPresetsModal::new()
+///     .presets(preset_items)
+///     .name_input_state(name_field)
+///     .list_state(list_state)
+///     .on_save(Msg::SaveSearchPreset)
+///     .on_load(Msg::LoadSearchPreset)
+///     .on_delete(Msg::DeleteSearchPreset)
+///     .on_close(Msg::CloseSearchPresetsModal)
+///     .build();
+/// ```
+pub struct SearchPresetsModal<Msg> {
+    presets: Vec<SearchPresetItem<Msg>>,
+    name_input_state: TextInputField,
+    list_state: ListState,
+    on_name_input_event: Option<fn(crate::tui::widgets::TextInputEvent) -> Msg>,
+    on_list_navigate: Option<fn(crossterm::event::KeyCode) -> Msg>,
+    on_list_select: Option<fn(usize) -> Msg>,
+    on_list_activate: Option<fn(usize) -> Msg>,
+    on_save: Option<Msg>,
+    on_load: Option<Msg>,
+    on_delete: Option<Msg>,
+    on_close: Option<Msg>,
+    width: Option<u16>,
+    height: Option<u16>,
+}
+
+impl<Msg: Clone> SearchPresetsModal<Msg> {
+    /// Create a new search presets modal
+    pub fn new() -> Self {
+        Self {
+            presets: Vec::new(),
+            name_input_state: TextInputField::new(),
+            list_state: ListState::new(),
+            on_name_input_event: None,
+            on_list_navigate: None,
+            on_list_select: None,
+            on_list_activate: None,
+            on_save: None,
+            on_load: None,
+            on_delete: None,
+            on_close: None,
+            width: Some(80),
+            height: Some(25),
+        }
+    }
+
+    /// Set the list of presets
+    pub fn presets(mut self, presets: Vec<SearchPresetItem<Msg>>) -> Self {
+        self.presets = presets;
+        self
+    }
+
+    /// Set the preset name input state
+    pub fn name_input_state(mut self, state: TextInputField) -> Self {
+        self.name_input_state = state;
+        self
+    }
+
+    /// Set the list state
+    pub fn list_state(mut self, state: ListState) -> Self {
+        self.list_state = state;
+        self
+    }
+
+    /// Set name input event handler
+    pub fn on_name_input_event(
+        mut self,
+        handler: fn(crate::tui::widgets::TextInputEvent) -> Msg,
+    ) -> Self {
+        self.on_name_input_event = Some(handler);
+        self
+    }
+
+    /// Set list navigation handler
+    pub fn on_list_navigate(mut self, handler: fn(crossterm::event::KeyCode) -> Msg) -> Self {
+        self.on_list_navigate = Some(handler);
+        self
+    }
+
+    /// Set list select handler
+    pub fn on_list_select(mut self, handler: fn(usize) -> Msg) -> Self {
+        self.on_list_select = Some(handler);
+        self
+    }
+
+    /// Set list activate handler (Enter on a preset)
+    pub fn on_list_activate(mut self, handler: fn(usize) -> Msg) -> Self {
+        self.on_list_activate = Some(handler);
+        self
+    }
+
+    /// Set the message sent when Save is clicked
+    pub fn on_save(mut self, msg: Msg) -> Self {
+        self.on_save = Some(msg);
+        self
+    }
+
+    /// Set the message sent when Load is clicked
+    pub fn on_load(mut self, msg: Msg) -> Self {
+        self.on_load = Some(msg);
+        self
+    }
+
+    /// Set the message sent when Delete is clicked
+    pub fn on_delete(mut self, msg: Msg) -> Self {
+        self.on_delete = Some(msg);
+        self
+    }
+
+    /// Set the message sent when Close is clicked
+    pub fn on_close(mut self, msg: Msg) -> Self {
+        self.on_close = Some(msg);
+        self
+    }
+
+    /// Set modal width
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set modal height
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Build the modal Element
+    pub fn build(self) -> Element<Msg> {
+        use_constraints!();
+        let theme = &crate::global_runtime_config().theme;
+
+        let name_handler = self
+            .on_name_input_event
+            .expect("SearchPresetsModal requires on_name_input_event");
+        let name_input = Element::text_input(
+            FocusId::new("search-preset-name-input"),
+            &self.name_input_state.value,
+            &self.name_input_state.state,
+        )
+        .placeholder("e.g., Unmatched custom fields")
+        .on_event(name_handler)
+        .build();
+
+        let list_handler = self
+            .on_list_navigate
+            .expect("SearchPresetsModal requires on_list_navigate");
+        let select_handler = self
+            .on_list_select
+            .expect("SearchPresetsModal requires on_list_select");
+        let mut presets_list = Element::list(
+            FocusId::new("search-presets-list"),
+            &self.presets,
+            &self.list_state,
+            theme,
+        )
+        .on_select(select_handler)
+        .on_navigate(list_handler);
+        if let Some(activate_handler) = self.on_list_activate {
+            presets_list = presets_list.on_activate(activate_handler);
+        }
+        let presets_list = presets_list.build();
+
+        let name_panel = Element::panel(name_input)
+            .title("Save Current Search As")
+            .build();
+
+        let presets_panel = Element::panel(presets_list).title("Saved Presets").build();
+
+        let buttons = button_row![
+            (
+                "search-preset-save",
+                "Save",
+                self.on_save
+                    .clone()
+                    .expect("SearchPresetsModal requires on_save")
+            ),
+            (
+                "search-preset-load",
+                "Load",
+                self.on_load
+                    .clone()
+                    .expect("SearchPresetsModal requires on_load")
+            ),
+            (
+                "search-preset-delete",
+                "Delete",
+                self.on_delete
+                    .clone()
+                    .expect("SearchPresetsModal requires on_delete")
+            ),
+            (
+                "search-preset-close",
+                "Close",
+                self.on_close
+                    .clone()
+                    .expect("SearchPresetsModal requires on_close")
+            ),
+        ];
+
+        let modal_body = col![
+            Element::styled_text(
+                Line::from(vec![
+                    Span::styled("Search Presets", Style::default().fg(theme.accent_tertiary).bold())
+                ])
+            ).build() => Length(1),
+            spacer!() => Length(1),
+            name_panel => Length(3),
+            spacer!() => Length(1),
+            presets_panel => Fill(1),
+            spacer!() => Length(1),
+            buttons => Length(3),
+        ];
+
+        Element::panel(Element::container(modal_body).padding(2).build())
+            .width(self.width.unwrap_or(80))
+            .height(self.height.unwrap_or(25))
+            .build()
+    }
+}
+
+impl<Msg: Clone> Default for SearchPresetsModal<Msg> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
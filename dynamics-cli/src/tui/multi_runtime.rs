@@ -8,7 +8,8 @@ use std::collections::HashMap;
 use std::time::Instant;
 
 use crate::tui::element::{ColumnBuilder, FocusId, RowBuilder};
-use crate::tui::modals::AppOverviewModal;
+use crate::tui::modals::command_palette::rank_entries;
+use crate::tui::modals::{AppOverviewModal, CommandPaletteModal, PaletteEntry};
 use crate::tui::runtime::AppFactory;
 use crate::tui::widgets::ScrollableState;
 use crate::tui::{
@@ -65,6 +66,15 @@ enum GlobalMsg {
     CloseAppOverview,
 }
 
+/// What a command palette entry does when activated
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PaletteTarget {
+    App(AppId),
+    Help,
+    AppOverview,
+    Quit,
+}
+
 /// Manages multiple app runtimes and handles navigation between them
 pub struct MultiAppRuntime {
     /// App factories for lazy creation
@@ -87,6 +97,14 @@ pub struct MultiAppRuntime {
     help_scroll_state: ScrollableState,
     quit_modal: ModalState<()>,
     app_overview_modal: ModalState<()>,
+    command_palette_modal: ModalState<()>,
+    /// Current command palette query
+    palette_query: String,
+    /// Index of the highlighted command palette entry
+    palette_selected: usize,
+
+    /// How many times each app has been switched to (for frequency ordering)
+    app_use_counts: HashMap<AppId, u32>,
 
     // Global focus system
     global_interaction_registry: crate::tui::InteractionRegistry<GlobalMsg>,
@@ -213,6 +231,10 @@ impl MultiAppRuntime {
             help_scroll_state: ScrollableState::new(),
             quit_modal: ModalState::Closed,
             app_overview_modal: ModalState::Closed,
+            command_palette_modal: ModalState::Closed,
+            palette_query: String::new(),
+            palette_selected: 0,
+            app_use_counts: HashMap::new(),
             global_interaction_registry: crate::tui::InteractionRegistry::new(),
             global_focus_registry: crate::tui::renderer::FocusRegistry::new(),
             global_focused_id: None,
@@ -227,9 +249,7 @@ impl MultiAppRuntime {
         runtime
             .ensure_app_exists(AppId::AppLauncher, Box::new(()))
             .ok();
-        runtime
-            .last_active_time
-            .insert(AppId::AppLauncher, Instant::now());
+        runtime.mark_active(AppId::AppLauncher);
 
         // Eagerly create the OperationQueue so it can receive pub/sub messages from any app
         runtime
@@ -484,6 +504,11 @@ impl MultiAppRuntime {
             return Ok(true); // Consume all other keys (except Tab, handled above)
         }
 
+        // Priority 3.6: Command palette takes all keys while open
+        if self.command_palette_modal.is_open() {
+            return self.handle_palette_key(key_event);
+        }
+
         // Priority 3.75: Check if app is capturing raw input (e.g., keybind capture mode)
         // If so, skip global keybind handling and delegate directly to the app
         let runtime = self
@@ -627,9 +652,7 @@ impl MultiAppRuntime {
                     .insert(AppId::AppLauncher, AppLifecycle::Running);
             }
 
-            self.active_app = AppId::AppLauncher;
-            self.last_active_time
-                .insert(AppId::AppLauncher, Instant::now());
+            self.mark_active(AppId::AppLauncher);
             log::info!("✅ AppLauncher is now active");
             return Ok(true);
         }
@@ -642,6 +665,16 @@ impl MultiAppRuntime {
             return Ok(true);
         }
 
+        // Priority 7: Configurable command palette keybind
+        let palette_key = config.get_keybind("global.command_palette");
+        if palette_key.matches(&key_event) {
+            self.command_palette_modal.open_empty();
+            self.palette_query.clear();
+            self.palette_selected = 0;
+            self.global_focused_id = None;
+            return Ok(true);
+        }
+
         // When help menu is open, intercept keys for help control
         if self.help_modal.is_open() {
             match key_event.code {
@@ -826,6 +859,11 @@ impl MultiAppRuntime {
             self.render_app_overview(frame, full_area);
         }
 
+        // If command palette is open, overlay it on top
+        if self.command_palette_modal.is_open() {
+            self.render_command_palette(frame, full_area);
+        }
+
         // If quit confirmation is open, overlay it on top (highest priority)
         if self.quit_modal.is_open() {
             self.render_quit_confirm(frame, full_area);
@@ -940,10 +978,12 @@ impl MultiAppRuntime {
         let config = crate::global_runtime_config();
         let help_key = config.get_keybind("global.help");
         let launcher_key = config.get_keybind("global.app_launcher");
+        let palette_key = config.get_keybind("global.command_palette");
 
         let global_bindings = vec![
             (help_key, "Toggle help menu"),
             (launcher_key, "Go to app launcher"),
+            (palette_key, "Open command palette"),
             (KeyBinding::new(KeyCode::Esc), "Close help menu"),
         ];
 
@@ -1373,6 +1413,156 @@ impl MultiAppRuntime {
             .save_layer_focus(self.global_focused_id.clone());
     }
 
+    /// Build the command palette entries: reachable apps plus global actions
+    ///
+    /// Apps that need init params are only listed while they are alive, since
+    /// they can't be started from the palette without a caller.
+    fn palette_entries(&self) -> Vec<PaletteEntry<PaletteTarget>> {
+        let mut entries: Vec<PaletteEntry<PaletteTarget>> = self
+            .factories
+            .iter()
+            .filter(|(id, factory)| {
+                let alive = matches!(
+                    self.lifecycles.get(id),
+                    Some(AppLifecycle::Running) | Some(AppLifecycle::Background)
+                );
+                **id != self.active_app && (alive || !factory.takes_params())
+            })
+            .map(|(id, factory)| PaletteEntry {
+                label: factory.title().to_string(),
+                target: PaletteTarget::App(*id),
+                last_used: self.last_active_time.get(id).copied(),
+                use_count: self.app_use_counts.get(id).copied().unwrap_or(0),
+            })
+            .collect();
+
+        for (label, target) in [
+            ("Help: Keyboard Shortcuts", PaletteTarget::Help),
+            ("App Overview", PaletteTarget::AppOverview),
+            ("Quit", PaletteTarget::Quit),
+        ] {
+            entries.push(PaletteEntry {
+                label: label.to_string(),
+                target,
+                last_used: None,
+                use_count: 0,
+            });
+        }
+
+        entries
+    }
+
+    /// Handle a key while the command palette is open
+    fn handle_palette_key(&mut self, key_event: KeyEvent) -> Result<bool> {
+        match key_event.code {
+            KeyCode::Esc => {
+                self.command_palette_modal.close();
+            }
+            KeyCode::Up => {
+                self.palette_selected = self.palette_selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                let count = rank_entries(&self.palette_entries(), &self.palette_query).len();
+                if self.palette_selected + 1 < count {
+                    self.palette_selected += 1;
+                }
+            }
+            KeyCode::Enter => {
+                let ranked = rank_entries(&self.palette_entries(), &self.palette_query);
+                if let Some(entry) = ranked.get(self.palette_selected) {
+                    self.command_palette_modal.close();
+                    self.activate_palette_target(entry.target);
+                }
+            }
+            KeyCode::Backspace => {
+                self.palette_query.pop();
+                self.palette_selected = 0;
+            }
+            KeyCode::Char(c)
+                if !key_event
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.palette_query.push(c);
+                self.palette_selected = 0;
+            }
+            _ => {}
+        }
+        Ok(true) // Consume all keys while the palette is open
+    }
+
+    /// Run the action behind a command palette entry
+    fn activate_palette_target(&mut self, target: PaletteTarget) {
+        match target {
+            PaletteTarget::App(app_id) => {
+                log::info!(
+                    "🔎 Command palette navigation: {:?} -> {:?}",
+                    self.active_app,
+                    app_id
+                );
+                self.switch_to_app(app_id);
+            }
+            PaletteTarget::Help => {
+                self.help_modal.open_empty();
+                self.help_scroll_state = ScrollableState::new();
+                self.global_focused_id = Some(FocusId::new("help-scroll"));
+            }
+            PaletteTarget::AppOverview => {
+                self.app_overview_modal.open_empty();
+                self.global_focused_id = Some(FocusId::new("app-overview-close"));
+            }
+            PaletteTarget::Quit => self.request_quit(),
+        }
+    }
+
+    fn render_command_palette(&mut self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let theme = &crate::global_runtime_config().theme;
+        use ratatui::style::Style;
+        use ratatui::widgets::{Clear, Paragraph};
+
+        // Render dim overlay
+        let dim_overlay = Paragraph::new("").style(Style::default().bg(theme.bg_surface));
+        frame.render_widget(dim_overlay, area);
+
+        let ranked = rank_entries(&self.palette_entries(), &self.palette_query);
+        self.palette_selected = self.palette_selected.min(ranked.len().saturating_sub(1));
+
+        let modal_width = 60.min(area.width);
+        let modal_height = 22.min(area.height.saturating_sub(4));
+        // Title, query, panel borders, help line, padding and spacers
+        let visible_rows = (modal_height as usize).saturating_sub(14);
+        let modal: Element<GlobalMsg> = CommandPaletteModal::new(&self.palette_query, &ranked)
+            .selected(self.palette_selected)
+            .visible_rows(visible_rows)
+            .width(modal_width)
+            .height(modal_height)
+            .build();
+
+        let modal_area = ratatui::layout::Rect {
+            x: area.x + (area.width.saturating_sub(modal_width)) / 2,
+            y: area.y + (area.height.saturating_sub(modal_height)) / 2,
+            width: modal_width,
+            height: modal_height,
+        };
+        frame.render_widget(Clear, modal_area);
+
+        // The palette has no focusables; it is driven by handle_palette_key
+        use crate::tui::Renderer;
+        use crate::tui::renderer::DropdownRegistry;
+        let mut dropdown_registry: DropdownRegistry<GlobalMsg> = DropdownRegistry::new();
+        self.global_interaction_registry = crate::tui::InteractionRegistry::new();
+        self.global_focus_registry = crate::tui::renderer::FocusRegistry::new();
+        Renderer::render(
+            frame,
+            &mut self.global_interaction_registry,
+            &mut self.global_focus_registry,
+            &mut dropdown_registry,
+            None,
+            &modal,
+            modal_area,
+        );
+    }
+
     /// Get apps ordered by recency (most recent first), filtered to Running/Background apps only
     pub fn get_recent_apps(&self) -> Vec<AppId> {
         let mut apps: Vec<(AppId, Instant)> = self
@@ -1654,8 +1844,7 @@ impl MultiAppRuntime {
             // Create target app with provided params
             self.ensure_app_exists(target, params)?;
 
-            self.active_app = target;
-            self.last_active_time.insert(target, Instant::now());
+            self.mark_active(target);
             return Ok(true); // Navigation happened
         }
 
@@ -1666,75 +1855,89 @@ impl MultiAppRuntime {
                 target,
                 nav_source_app
             );
-            // Handle current app based on quit and suspend policies
-            if let Some(current_runtime) = self.runtimes.get(&self.active_app) {
-                let quit_policy = self
-                    .factories
-                    .get(&self.active_app)
-                    .map(|f| f.quit_policy())
-                    .unwrap_or(crate::tui::QuitPolicy::Sleep);
-
-                match quit_policy {
-                    crate::tui::QuitPolicy::Sleep | crate::tui::QuitPolicy::QuitOnIdle(_) => {
-                        // Check suspend policy
-                        let suspend_policy = self
-                            .factories
-                            .get(&self.active_app)
-                            .map(|f| f.suspend_policy())
-                            .unwrap_or(crate::tui::SuspendPolicy::Suspend);
+            self.switch_to_app(target);
+            log::info!("✅ Navigation complete - now active: {:?}", target);
+            Ok(true) // Navigation happened
+        } else {
+            log::debug!("  No navigation requests found");
+            Ok(false) // No navigation
+        }
+    }
 
-                        match suspend_policy {
-                            crate::tui::SuspendPolicy::Suspend => {
-                                // Keep app in background and call on_suspend
-                                self.lifecycles
-                                    .insert(self.active_app, AppLifecycle::Background);
-                                if let Some(runtime) = self.runtimes.get_mut(&self.active_app) {
-                                    runtime.on_suspend().ok();
-                                }
-                            }
-                            crate::tui::SuspendPolicy::AlwaysActive => {
-                                // Keep app in background but don't call on_suspend
-                                self.lifecycles
-                                    .insert(self.active_app, AppLifecycle::Background);
+    /// Switch to an app, suspending the current one per its policies
+    ///
+    /// This is the path taken by `Command::navigate_to`; the target is
+    /// created with default params if it isn't running yet.
+    fn switch_to_app(&mut self, target: AppId) {
+        // Handle current app based on quit and suspend policies
+        if self.runtimes.contains_key(&self.active_app) {
+            let quit_policy = self
+                .factories
+                .get(&self.active_app)
+                .map(|f| f.quit_policy())
+                .unwrap_or(crate::tui::QuitPolicy::Sleep);
+
+            match quit_policy {
+                crate::tui::QuitPolicy::Sleep | crate::tui::QuitPolicy::QuitOnIdle(_) => {
+                    // Check suspend policy
+                    let suspend_policy = self
+                        .factories
+                        .get(&self.active_app)
+                        .map(|f| f.suspend_policy())
+                        .unwrap_or(crate::tui::SuspendPolicy::Suspend);
+
+                    match suspend_policy {
+                        crate::tui::SuspendPolicy::Suspend => {
+                            // Keep app in background and call on_suspend
+                            self.lifecycles
+                                .insert(self.active_app, AppLifecycle::Background);
+                            if let Some(runtime) = self.runtimes.get_mut(&self.active_app) {
+                                runtime.on_suspend().ok();
                             }
-                            crate::tui::SuspendPolicy::QuitOnSuspend => {
-                                // Destroy app instead of suspending
-                                if let Some(mut runtime) = self.runtimes.remove(&self.active_app) {
-                                    runtime.on_destroy().ok();
-                                }
-                                self.lifecycles.insert(self.active_app, AppLifecycle::Dead);
+                        }
+                        crate::tui::SuspendPolicy::AlwaysActive => {
+                            // Keep app in background but don't call on_suspend
+                            self.lifecycles
+                                .insert(self.active_app, AppLifecycle::Background);
+                        }
+                        crate::tui::SuspendPolicy::QuitOnSuspend => {
+                            // Destroy app instead of suspending
+                            if let Some(mut runtime) = self.runtimes.remove(&self.active_app) {
+                                runtime.on_destroy().ok();
                             }
+                            self.lifecycles.insert(self.active_app, AppLifecycle::Dead);
                         }
                     }
-                    crate::tui::QuitPolicy::QuitOnExit => {
-                        // Remove app immediately
-                        if let Some(mut runtime) = self.runtimes.remove(&self.active_app) {
-                            runtime.on_destroy().ok();
-                        }
-                        self.lifecycles.insert(self.active_app, AppLifecycle::Dead);
+                }
+                crate::tui::QuitPolicy::QuitOnExit => {
+                    // Remove app immediately
+                    if let Some(mut runtime) = self.runtimes.remove(&self.active_app) {
+                        runtime.on_destroy().ok();
                     }
+                    self.lifecycles.insert(self.active_app, AppLifecycle::Dead);
                 }
             }
+        }
 
-            // Ensure target app exists (create with default params if needed)
-            self.ensure_app_exists(target, Box::new(())).ok();
+        // Ensure target app exists (create with default params if needed)
+        self.ensure_app_exists(target, Box::new(())).ok();
 
-            // Resume target app if it was backgrounded
-            if matches!(self.lifecycles.get(&target), Some(AppLifecycle::Background)) {
-                if let Some(runtime) = self.runtimes.get_mut(&target) {
-                    runtime.on_resume().ok();
-                }
-                self.lifecycles.insert(target, AppLifecycle::Running);
+        // Resume target app if it was backgrounded
+        if matches!(self.lifecycles.get(&target), Some(AppLifecycle::Background)) {
+            if let Some(runtime) = self.runtimes.get_mut(&target) {
+                runtime.on_resume().ok();
             }
-
-            self.active_app = target;
-            self.last_active_time.insert(target, Instant::now());
-            log::info!("✅ Navigation complete - now active: {:?}", target);
-            Ok(true) // Navigation happened
-        } else {
-            log::debug!("  No navigation requests found");
-            Ok(false) // No navigation
+            self.lifecycles.insert(target, AppLifecycle::Running);
         }
+
+        self.mark_active(target);
+    }
+
+    /// Make an app the active one and record it for recency/frequency ordering
+    fn mark_active(&mut self, app_id: AppId) {
+        self.active_app = app_id;
+        self.last_active_time.insert(app_id, Instant::now());
+        *self.app_use_counts.entry(app_id).or_insert(0) += 1;
     }
 
     /// Broadcast events globally to all apps
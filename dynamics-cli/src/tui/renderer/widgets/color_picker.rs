@@ -2,7 +2,9 @@ use crate::tui::color::color_to_hex;
 use crate::tui::command::DispatchTarget;
 use crate::tui::element::FocusId;
 use crate::tui::renderer::{FocusRegistry, FocusableInfo, InteractionRegistry};
-use crate::tui::widgets::{Channel, ColorPickerEvent, ColorPickerMode, ColorPickerState};
+use crate::tui::widgets::{
+    Channel, ColorPickerEvent, ColorPickerMode, ColorPickerState, NAMED_PALETTE, PALETTE_COLUMNS,
+};
 use crate::tui::{Element, Theme};
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -19,8 +21,13 @@ pub fn color_picker_on_key<Msg: Clone + Send + 'static>(
     state: &ColorPickerState,
 ) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
     let current_color = state.color();
+    let hex_editing = state.is_hex_editing();
 
     Box::new(move |key_event| match key_event.code {
+        // Enter in the hex field applies the typed value first; the next Enter submits
+        KeyCode::Enter if hex_editing => {
+            DispatchTarget::AppMsg(on_event(ColorPickerEvent::Changed(KeyCode::Enter)))
+        }
         KeyCode::Enter => {
             // Submit with current color
             DispatchTarget::AppMsg(on_event(ColorPickerEvent::Submitted(current_color)))
@@ -86,7 +93,10 @@ pub fn render_color_picker<Msg: Clone + Send + 'static>(
 
     // Preview box with current color
     let preview_text = format!("  Preview: ████████  {}", color_to_hex(state.color()));
-    let mode_text = format!("  Mode: {:?} (M to toggle)", mode);
+    let mode_text = match state.palette_name() {
+        Some(name) => format!("  Mode: {:?} (M to toggle)  Named: {}", mode, name),
+        None => format!("  Mode: {:?} (M to toggle)", mode),
+    };
 
     let preview_para = Paragraph::new(vec![
         Line::from(preview_text).fg(state.color()),
@@ -166,6 +176,36 @@ pub fn render_color_picker<Msg: Clone + Send + 'static>(
                 theme,
             );
         }
+        ColorPickerMode::Palette => {
+            let palette_focused = focused_channel != Channel::Hex && is_focused;
+            for (row, chunk) in chunks[2..4].iter().enumerate() {
+                let mut spans = vec![Span::raw("  ")];
+                for index in row * PALETTE_COLUMNS..(row + 1) * PALETTE_COLUMNS {
+                    let (r, g, b) = NAMED_PALETTE[index].1;
+                    let swatch = if index == state.palette_index() {
+                        "[██]"
+                    } else {
+                        " ██ "
+                    };
+                    spans.push(Span::styled(
+                        swatch,
+                        Style::default().fg(ratatui::style::Color::Rgb(r, g, b)),
+                    ));
+                }
+                frame.render_widget(Paragraph::new(Line::from(spans)), *chunk);
+            }
+
+            let name_style = if palette_focused {
+                Style::default().fg(theme.accent_primary).bold()
+            } else {
+                Style::default().fg(theme.text_secondary)
+            };
+            let name_text = format!("  Palette: {}", NAMED_PALETTE[state.palette_index()].0);
+            frame.render_widget(
+                Paragraph::new(Line::from(name_text)).style(name_style),
+                chunks[4],
+            );
+        }
     }
 
     // Hex input
@@ -187,7 +227,11 @@ pub fn render_color_picker<Msg: Clone + Send + 'static>(
 
     // Help text at bottom (if there's room)
     if area.height > 9 {
-        let help = "  ←/→: Adjust  Tab: Next  M: Mode  Enter: Confirm";
+        let help = if mode == ColorPickerMode::Palette {
+            "  Arrows: Pick  Tab: Hex  M: Mode  Enter: Confirm"
+        } else {
+            "  ←/→: Adjust  Tab: Next  M: Mode  Enter: Confirm"
+        };
         let help_para = Paragraph::new(help).style(Style::default().fg(theme.text_tertiary));
 
         if let Some(last_chunk) = chunks.last() {
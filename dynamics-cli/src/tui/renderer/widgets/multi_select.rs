@@ -6,7 +6,7 @@ use crate::tui::renderer::{
 };
 use crate::tui::widgets::MultiSelectEvent;
 use crate::tui::{Element, Theme};
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     Frame,
     layout::Rect,
@@ -21,6 +21,13 @@ pub fn multi_select_on_key_event<Msg: Clone + Send + 'static>(
     on_event: fn(MultiSelectEvent) -> Msg,
 ) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
     Box::new(move |key_event| {
+        // Ctrl+A toggles every item matching the current filter
+        if key_event.code == KeyCode::Char('a')
+            && key_event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            return DispatchTarget::AppMsg(on_event(MultiSelectEvent::SelectAllFiltered));
+        }
+
         if is_open {
             // Dropdown open: Up/Down/Enter/Esc go to navigate, others to input
             match key_event.code {
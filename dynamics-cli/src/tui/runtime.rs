@@ -28,6 +28,12 @@ pub trait AppFactory: Send {
 
     /// Get the suspend policy for this app type
     fn suspend_policy(&self) -> SuspendPolicy;
+
+    /// Get the title of this app type
+    fn title(&self) -> &'static str;
+
+    /// Whether this app needs init params (and so can't be started without a caller)
+    fn takes_params(&self) -> bool;
 }
 
 /// Trait for runtime operations, allowing type-erased storage of different Runtime<A> types
@@ -881,7 +887,8 @@ impl<A: App> Runtime<A> {
                     config: config.clone(),
                 });
 
-                // Spawn all tasks as separate futures
+                // Spawn all tasks as separate futures, gated by the concurrency cap
+                let tasks = crate::tui::command::limit_concurrency(tasks, config.max_concurrency);
                 for (idx, task) in tasks.into_iter().enumerate() {
                     let results_ref = results.clone();
                     let task_name = task.description.clone();
@@ -1228,4 +1235,12 @@ where
     fn suspend_policy(&self) -> SuspendPolicy {
         A::suspend_policy()
     }
+
+    fn title(&self) -> &'static str {
+        A::title()
+    }
+
+    fn takes_params(&self) -> bool {
+        std::any::TypeId::of::<A::InitParams>() != std::any::TypeId::of::<()>()
+    }
 }
@@ -1,9 +1,12 @@
 use super::{FocusMode, Theme, ThemeVariant};
 use crate::config::options::Options;
+use crate::services::matching::NameSimilarity;
+use crate::transfer::lua::LogLevel;
 use crate::tui::KeyBinding;
 use crate::tui::color::hex_to_color;
 use ratatui::style::Color;
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Runtime configuration for TUI behavior and appearance
 ///
@@ -23,6 +26,15 @@ pub struct RuntimeConfig {
 
     /// Tab key debouncing duration in milliseconds
     pub tab_debouncing_ms: u64,
+
+    /// How the entity comparison scores leftover names for similarity matches
+    pub name_similarity: NameSimilarity,
+
+    /// Lowest Lua script log level kept from transform runs
+    pub lua_log_level: LogLevel,
+
+    /// Directory Lua scripts can `require` shared modules from (None disables require)
+    pub lua_module_dir: Option<PathBuf>,
 }
 
 impl Default for RuntimeConfig {
@@ -34,6 +46,9 @@ impl Default for RuntimeConfig {
             focus_mode: FocusMode::default(),
             keybinds: HashMap::new(),
             tab_debouncing_ms: 150,
+            name_similarity: NameSimilarity::default(),
+            lua_log_level: LogLevel::default(),
+            lua_module_dir: None,
         }
     }
 }
@@ -47,6 +62,9 @@ impl RuntimeConfig {
             focus_mode,
             keybinds,
             tab_debouncing_ms: default.tab_debouncing_ms,
+            name_similarity: default.name_similarity,
+            lua_log_level: default.lua_log_level,
+            lua_module_dir: default.lua_module_dir,
         }
     }
 
@@ -58,6 +76,9 @@ impl RuntimeConfig {
             focus_mode: FocusMode::default(),
             keybinds: default.keybinds,
             tab_debouncing_ms: default.tab_debouncing_ms,
+            name_similarity: default.name_similarity,
+            lua_log_level: default.lua_log_level,
+            lua_module_dir: default.lua_module_dir,
         }
     }
 
@@ -69,6 +90,9 @@ impl RuntimeConfig {
             focus_mode: mode,
             keybinds: default.keybinds,
             tab_debouncing_ms: default.tab_debouncing_ms,
+            name_similarity: default.name_similarity,
+            lua_log_level: default.lua_log_level,
+            lua_module_dir: default.lua_module_dir,
         }
     }
 
@@ -151,6 +175,16 @@ impl RuntimeConfig {
                 Theme::mocha()
             });
 
+        // Load the directory Lua scripts can require shared modules from
+        let lua_module_dir = config
+            .options
+            .get_string("transfer.lua_module_dir")
+            .await
+            .ok()
+            .map(|dir| dir.trim().to_string())
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+
         // Load tab debouncing from options (defaults to 150ms if not found)
         let tab_debouncing_ms = config
             .options
@@ -158,6 +192,22 @@ impl RuntimeConfig {
             .await
             .unwrap_or_else(|_| 150);
 
+        // Load name similarity strategy for entity comparison matching
+        let name_similarity = config
+            .options
+            .get_string("migration.name_similarity")
+            .await
+            .map(|value| NameSimilarity::from_option(&value))
+            .unwrap_or_default();
+
+        // Load the Lua log level applied to transform runs
+        let lua_log_level = config
+            .options
+            .get_string("transfer.lua_log_level")
+            .await
+            .map(|value| LogLevel::from_option(&value))
+            .unwrap_or_default();
+
         // Load keybinds from options database (now app-scoped)
         let mut keybinds = HashMap::new();
         let apps = keybinds::list_apps(&config.options.registry());
@@ -210,6 +260,9 @@ impl RuntimeConfig {
             focus_mode,
             keybinds,
             tab_debouncing_ms,
+            name_similarity,
+            lua_log_level,
+            lua_module_dir,
         })
     }
 }
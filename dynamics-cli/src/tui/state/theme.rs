@@ -1,6 +1,8 @@
 // NEW Theme struct definition (21 colors - Option 1)
 // Replace contents of dynamics-cli/src/tui/state/theme.rs with this
 
+use crate::tui::color::{color_to_hex, hex_to_color};
+use anyhow::Context;
 use ratatui::style::{Color, Style};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -15,7 +17,7 @@ impl Default for ThemeVariant {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Theme {
     // Accent colors (8)
     pub accent_primary: Color, // Focus, selection, primary highlight (was: lavender)
@@ -177,6 +179,79 @@ impl Theme {
             ("palette_4", self.palette_4),
         ]
     }
+
+    /// Set a color field by name, returning false for an unknown name
+    pub fn set_color(&mut self, name: &str, color: Color) -> bool {
+        let slot = match name {
+            "accent_primary" => &mut self.accent_primary,
+            "accent_secondary" => &mut self.accent_secondary,
+            "accent_tertiary" => &mut self.accent_tertiary,
+            "accent_error" => &mut self.accent_error,
+            "accent_warning" => &mut self.accent_warning,
+            "accent_success" => &mut self.accent_success,
+            "accent_info" => &mut self.accent_info,
+            "accent_muted" => &mut self.accent_muted,
+            "text_primary" => &mut self.text_primary,
+            "text_secondary" => &mut self.text_secondary,
+            "text_tertiary" => &mut self.text_tertiary,
+            "border_primary" => &mut self.border_primary,
+            "border_secondary" => &mut self.border_secondary,
+            "border_tertiary" => &mut self.border_tertiary,
+            "bg_base" => &mut self.bg_base,
+            "bg_surface" => &mut self.bg_surface,
+            "bg_elevated" => &mut self.bg_elevated,
+            "palette_1" => &mut self.palette_1,
+            "palette_2" => &mut self.palette_2,
+            "palette_3" => &mut self.palette_3,
+            "palette_4" => &mut self.palette_4,
+            _ => return false,
+        };
+        *slot = color;
+        true
+    }
+
+    /// Serialize to a JSON object mapping each color name to "#rrggbb"
+    pub fn to_json(&self) -> serde_json::Value {
+        let colors = self
+            .colors()
+            .into_iter()
+            .map(|(name, color)| {
+                (
+                    name.to_string(),
+                    serde_json::Value::String(color_to_hex(color)),
+                )
+            })
+            .collect();
+        serde_json::Value::Object(colors)
+    }
+
+    /// Parse a theme from a JSON object of color name → "#rrggbb"
+    ///
+    /// Every color in `COLOR_NAMES` must be present with a valid hex value;
+    /// unknown keys are rejected so typos don't go unnoticed.
+    pub fn from_json(value: &serde_json::Value) -> anyhow::Result<Self> {
+        let object = value
+            .as_object()
+            .context("Theme JSON must be an object of color name to hex value")?;
+
+        if let Some(unknown) = object.keys().find(|k| !COLOR_NAMES.contains(&k.as_str())) {
+            anyhow::bail!("Unknown theme color '{}'", unknown);
+        }
+
+        let mut theme = Self::default();
+        for name in COLOR_NAMES {
+            let hex = object
+                .get(*name)
+                .with_context(|| format!("Missing theme color '{}'", name))?
+                .as_str()
+                .with_context(|| format!("Theme color '{}' must be a hex string", name))?;
+            let color = hex_to_color(hex)
+                .with_context(|| format!("Invalid value '{}' for theme color '{}'", hex, name))?;
+            theme.set_color(name, color);
+        }
+
+        Ok(theme)
+    }
 }
 
 impl Default for Theme {
@@ -209,3 +284,35 @@ pub const COLOR_NAMES: &[&str] = &[
     "palette_3",
     "palette_4",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_json_round_trip() {
+        // Give every field a distinct color so a swapped field would be caught
+        let mut theme = Theme::mocha();
+        for (i, name) in COLOR_NAMES.iter().enumerate() {
+            assert!(theme.set_color(name, Color::Rgb(i as u8, 0x80, 0xff - i as u8)));
+        }
+
+        let json = theme.to_json();
+        assert_eq!(json.as_object().unwrap().len(), COLOR_NAMES.len());
+        assert_eq!(json["palette_4"], "#1480eb");
+
+        let text = serde_json::to_string_pretty(&json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(Theme::from_json(&parsed).unwrap(), theme);
+
+        // Invalid, missing and unknown colors are rejected
+        let mut bad = json.clone();
+        bad["bg_base"] = serde_json::Value::String("#12345".to_string());
+        assert!(Theme::from_json(&bad).is_err());
+        bad.as_object_mut().unwrap().remove("bg_base");
+        assert!(Theme::from_json(&bad).is_err());
+        let mut extra = json;
+        extra["bg_overlay"] = serde_json::Value::String("#000000".to_string());
+        assert!(Theme::from_json(&extra).is_err());
+    }
+}
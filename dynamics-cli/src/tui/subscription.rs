@@ -161,8 +161,9 @@ impl std::str::FromStr for KeyBinding {
             }
         }
 
-        // Parse the key itself
-        let code = match *key_str {
+        // Parse the key itself (special key names are case-insensitive so the
+        // output of `display()` parses back)
+        let code = match key_str.to_lowercase().as_str() {
             // Special keys
             "space" => KeyCode::Char(' '),
             "enter" | "return" => KeyCode::Enter,
@@ -179,14 +180,18 @@ impl std::str::FromStr for KeyBinding {
             "down" => KeyCode::Down,
             "left" => KeyCode::Left,
             "right" => KeyCode::Right,
+            "↑" => KeyCode::Up,
+            "↓" => KeyCode::Down,
+            "←" => KeyCode::Left,
+            "→" => KeyCode::Right,
             // F keys
-            s if s.starts_with('f') || s.starts_with('F') => {
+            s if s.len() > 1 && s.starts_with('f') => {
                 let num = s[1..].parse::<u8>().context("Invalid F-key number")?;
                 KeyCode::F(num)
             }
-            // Single character
-            s if s.len() == 1 => {
-                let c = s.chars().next().unwrap();
+            // Single character (keeps its case)
+            _ if key_str.chars().count() == 1 => {
+                let c = key_str.chars().next().unwrap();
                 KeyCode::Char(c)
             }
             _ => anyhow::bail!("Unknown key: {}", key_str),
@@ -284,3 +289,24 @@ impl<Msg> Subscription<Msg> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keybinding_display_round_trips() {
+        use std::str::FromStr;
+
+        for key in [
+            KeyBinding::ctrl(KeyCode::Char('n')),
+            KeyBinding::new(KeyCode::Char('N')),
+            KeyBinding::new(KeyCode::Enter),
+            KeyBinding::alt(KeyCode::Up),
+            KeyBinding::new(KeyCode::F(5)),
+            KeyBinding::new(KeyCode::Char(' ')),
+        ] {
+            assert_eq!(KeyBinding::from_str(&key.to_string()).unwrap(), key);
+        }
+    }
+}
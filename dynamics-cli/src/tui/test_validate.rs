@@ -21,10 +21,7 @@ mod tests {
         source.set_value(Some("test".to_string()));
 
         let form = TestForm {
-            name: TextInputField {
-                value: String::new(),
-                state: Default::default(),
-            },
+            name: TextInputField::with_value(String::new()),
             source,
         };
 
@@ -36,10 +33,7 @@ mod tests {
     #[test]
     fn test_validate_missing_source() {
         let form = TestForm {
-            name: TextInputField {
-                value: "test".to_string(),
-                state: Default::default(),
-            },
+            name: TextInputField::with_value("test".to_string()),
             source: SelectField::default(), // None by default
         };
 
@@ -54,10 +48,7 @@ mod tests {
         source.set_value(Some("source".to_string()));
 
         let form = TestForm {
-            name: TextInputField {
-                value: "test".to_string(),
-                state: Default::default(),
-            },
+            name: TextInputField::with_value("test".to_string()),
             source,
         };
 
@@ -1,6 +1,41 @@
 use crate::tui::widgets::TextInputState;
 use crossterm::event::KeyCode;
 
+/// Match and rank options against the input, best first
+///
+/// Uses subsequence scoring, so `acnt` matches `account`. Exact-prefix matches
+/// always come before other fuzzy matches; within each group options are
+/// ordered by score, then by length, then alphabetically.
+pub fn rank_options(input: &str, options: &[String]) -> Vec<(String, i64)> {
+    use fuzzy_matcher::FuzzyMatcher;
+    use fuzzy_matcher::skim::SkimMatcherV2;
+
+    let input_lower = input.to_lowercase();
+    let matcher = SkimMatcherV2::default();
+
+    let mut scored: Vec<(bool, String, i64)> = options
+        .iter()
+        .filter_map(|opt| {
+            let is_prefix = opt.to_lowercase().starts_with(&input_lower);
+            matcher
+                .fuzzy_match(opt, input)
+                .map(|score| (is_prefix, opt.clone(), score))
+        })
+        .collect();
+
+    scored.sort_by(|a, b| {
+        b.0.cmp(&a.0)
+            .then(b.2.cmp(&a.2))
+            .then(a.1.len().cmp(&b.1.len()))
+            .then(a.1.cmp(&b.1))
+    });
+
+    scored
+        .into_iter()
+        .map(|(_, opt, score)| (opt, score))
+        .collect()
+}
+
 /// Manages state for Autocomplete input widgets
 /// Combines text input with fuzzy-matched dropdown suggestions
 #[derive(Debug, Clone)]
@@ -78,12 +113,9 @@ impl AutocompleteState {
             .collect()
     }
 
-    /// Update filtered options using fuzzy matching
+    /// Update filtered options, ranking prefix matches ahead of other fuzzy matches
     /// Automatically opens/closes dropdown based on results
     pub fn update_filtered_options(&mut self, input: &str, all_options: &[String]) {
-        use fuzzy_matcher::FuzzyMatcher;
-        use fuzzy_matcher::skim::SkimMatcherV2;
-
         self.total_option_count = all_options.len();
 
         if input.is_empty() {
@@ -94,22 +126,11 @@ impl AutocompleteState {
             return;
         }
 
-        // Fuzzy match and score all options
-        let matcher = SkimMatcherV2::default();
-        let mut scored: Vec<(String, i64)> = all_options
-            .iter()
-            .filter_map(|opt| {
-                matcher
-                    .fuzzy_match(opt, input)
-                    .map(|score| (opt.clone(), score))
-            })
-            .collect();
-
-        // Sort by score descending (higher score = better match)
-        scored.sort_by(|a, b| b.1.cmp(&a.1));
-
         // Take top 100 results (with scrolling, we can handle larger lists)
-        self.filtered_options = scored.into_iter().take(100).collect();
+        self.filtered_options = rank_options(input, all_options)
+            .into_iter()
+            .take(100)
+            .collect();
 
         // Auto-open dropdown if we have results
         if !self.filtered_options.is_empty() {
@@ -224,3 +245,37 @@ impl AutocompleteState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> Vec<String> {
+        [
+            "contact",
+            "account",
+            "accountleads",
+            "opportunity",
+            "nrq_account",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+    }
+
+    fn names(ranked: Vec<(String, i64)>) -> Vec<String> {
+        ranked.into_iter().map(|(opt, _)| opt).collect()
+    }
+
+    #[test]
+    fn test_fuzzy_ranks_prefix_matches_first() {
+        let ranked = names(rank_options("acc", &options()));
+        assert_eq!(ranked, vec!["account", "accountleads", "nrq_account"]);
+
+        // Subsequence match with no prefix hit
+        let ranked = names(rank_options("acnt", &options()));
+        assert_eq!(ranked[0], "account");
+        assert!(ranked.contains(&"nrq_account".to_string()));
+        assert!(!ranked.contains(&"opportunity".to_string()));
+    }
+}
@@ -1,6 +1,6 @@
 //! Color picker widget state and logic
 
-use crate::tui::color::{HSL, hsl_to_rgb, rgb_to_hsl};
+use crate::tui::color::{HSL, hex_to_color, hsl_to_rgb, rgb_to_hsl};
 use crossterm::event::KeyCode;
 use ratatui::style::Color;
 
@@ -11,18 +11,44 @@ pub enum ColorPickerMode {
     HSL,
     /// RGB mode: Red, Green, Blue
     RGB,
+    /// Named palette: the 16 ANSI colors
+    Palette,
 }
 
 impl ColorPickerMode {
-    /// Toggle between HSL and RGB
+    /// Cycle HSL → RGB → Palette
     pub fn toggle(&self) -> Self {
         match self {
             Self::HSL => Self::RGB,
-            Self::RGB => Self::HSL,
+            Self::RGB => Self::Palette,
+            Self::Palette => Self::HSL,
         }
     }
 }
 
+/// The 16 ANSI colors with their standard (xterm) RGB values
+pub const NAMED_PALETTE: [(&str, (u8, u8, u8)); 16] = [
+    ("Black", (0x00, 0x00, 0x00)),
+    ("Red", (0x80, 0x00, 0x00)),
+    ("Green", (0x00, 0x80, 0x00)),
+    ("Yellow", (0x80, 0x80, 0x00)),
+    ("Blue", (0x00, 0x00, 0x80)),
+    ("Magenta", (0x80, 0x00, 0x80)),
+    ("Cyan", (0x00, 0x80, 0x80)),
+    ("Gray", (0xc0, 0xc0, 0xc0)),
+    ("Dark Gray", (0x80, 0x80, 0x80)),
+    ("Light Red", (0xff, 0x00, 0x00)),
+    ("Light Green", (0x00, 0xff, 0x00)),
+    ("Light Yellow", (0xff, 0xff, 0x00)),
+    ("Light Blue", (0x00, 0x00, 0xff)),
+    ("Light Magenta", (0xff, 0x00, 0xff)),
+    ("Light Cyan", (0x00, 0xff, 0xff)),
+    ("White", (0xff, 0xff, 0xff)),
+];
+
+/// Palette swatches per row when rendered as a grid
+pub const PALETTE_COLUMNS: usize = 8;
+
 /// Which channel/input field is currently focused
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Channel {
@@ -75,6 +101,9 @@ pub struct ColorPickerState {
 
     /// Whether hex input is currently being edited
     hex_editing: bool,
+
+    /// Highlighted entry in the named palette
+    palette_index: usize,
 }
 
 impl ColorPickerState {
@@ -86,6 +115,10 @@ impl ColorPickerState {
         };
 
         let hsl = rgb_to_hsl(r, g, b);
+        let palette_index = NAMED_PALETTE
+            .iter()
+            .position(|(_, rgb)| *rgb == (r, g, b))
+            .unwrap_or(0);
 
         Self {
             mode,
@@ -93,6 +126,7 @@ impl ColorPickerState {
             focused_channel: Channel::Primary,
             hex_input: format!("{:02x}{:02x}{:02x}", r, g, b),
             hex_editing: false,
+            palette_index,
         }
     }
 
@@ -137,16 +171,63 @@ impl ColorPickerState {
         self.hex_editing
     }
 
+    /// Highlighted entry in the named palette
+    pub fn palette_index(&self) -> usize {
+        self.palette_index
+    }
+
+    /// Name of the palette color matching the current color exactly, if any
+    pub fn palette_name(&self) -> Option<&'static str> {
+        let rgb = self.rgb();
+        NAMED_PALETTE
+            .iter()
+            .find(|(_, palette_rgb)| *palette_rgb == rgb)
+            .map(|(name, _)| *name)
+    }
+
     /// Toggle display mode
     pub fn toggle_mode(&mut self) {
         self.mode = self.mode.toggle();
     }
 
+    /// Set the color from a hex string ("#1a2b3c" or "1a2b3c")
+    ///
+    /// An invalid string is rejected and leaves the color unchanged.
+    pub fn set_hex(&mut self, hex: &str) -> Result<(), String> {
+        match hex_to_color(hex.trim()).map_err(|e| e.to_string())? {
+            Color::Rgb(r, g, b) => {
+                self.hsl = rgb_to_hsl(r, g, b);
+                self.hex_input = format!("{:02x}{:02x}{:02x}", r, g, b);
+                Ok(())
+            }
+            _ => Err(format!("Invalid hex color: {}", hex)),
+        }
+    }
+
+    /// Pick a color from the named palette by index
+    pub fn select_palette(&mut self, index: usize) {
+        let index = index.min(NAMED_PALETTE.len() - 1);
+        let (r, g, b) = NAMED_PALETTE[index].1;
+        self.palette_index = index;
+        self.hsl = rgb_to_hsl(r, g, b);
+    }
+
     /// Handle keyboard input
     ///
     /// Returns true if the value changed
     pub fn handle_key(&mut self, key: KeyCode) -> bool {
         match key {
+            // The palette has a single field, so Tab just flips between it and hex
+            KeyCode::Tab | KeyCode::BackTab if self.mode == ColorPickerMode::Palette => {
+                if self.focused_channel == Channel::Hex {
+                    self.focused_channel = Channel::Primary;
+                    self.finish_hex_edit();
+                } else {
+                    self.focused_channel = Channel::Hex;
+                    self.start_hex_edit();
+                }
+                false
+            }
             KeyCode::Tab => {
                 self.focused_channel = self.focused_channel.next();
                 if self.focused_channel == Channel::Hex {
@@ -170,6 +251,19 @@ impl ColorPickerState {
                 false
             }
             _ if self.hex_editing => self.handle_hex_input(key),
+            KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down
+                if self.mode == ColorPickerMode::Palette =>
+            {
+                let last = NAMED_PALETTE.len() - 1;
+                let index = match key {
+                    KeyCode::Left => self.palette_index.saturating_sub(1),
+                    KeyCode::Right => (self.palette_index + 1).min(last),
+                    KeyCode::Up => self.palette_index.saturating_sub(PALETTE_COLUMNS),
+                    _ => (self.palette_index + PALETTE_COLUMNS).min(last),
+                };
+                self.select_palette(index);
+                true
+            }
             KeyCode::Up | KeyCode::Right => {
                 self.adjust_channel(1);
                 true
@@ -185,6 +279,7 @@ impl ColorPickerState {
     /// Adjust the currently focused channel by delta
     fn adjust_channel(&mut self, delta: i32) {
         match self.mode {
+            ColorPickerMode::Palette => {}
             ColorPickerMode::HSL => match self.focused_channel {
                 Channel::Primary => {
                     let new_h = (self.hsl.h + delta as f32).rem_euclid(360.0);
@@ -228,15 +323,10 @@ impl ColorPickerState {
     fn finish_hex_edit(&mut self) {
         self.hex_editing = false;
 
-        // Try to parse hex input
-        if self.hex_input.len() == 6 {
-            if let (Ok(r), Ok(g), Ok(b)) = (
-                u8::from_str_radix(&self.hex_input[0..2], 16),
-                u8::from_str_radix(&self.hex_input[2..4], 16),
-                u8::from_str_radix(&self.hex_input[4..6], 16),
-            ) {
-                self.hsl = rgb_to_hsl(r, g, b);
-            }
+        // Apply the typed hex if valid; otherwise keep the current color
+        let input = self.hex_input.clone();
+        if self.set_hex(&input).is_err() {
+            log::debug!("Ignoring invalid hex color input '{}'", input);
         }
     }
 
@@ -249,6 +339,8 @@ impl ColorPickerState {
                 self.hex_input.push(c.to_ascii_lowercase());
                 true
             }
+            // A leading '#' is accepted (and dropped) so "#1a2b3c" can be typed as-is
+            KeyCode::Char('#') if self.hex_input.is_empty() => false,
             KeyCode::Backspace if !self.hex_input.is_empty() => {
                 self.hex_input.pop();
                 true
@@ -283,6 +375,9 @@ mod tests {
         state.toggle_mode();
         assert_eq!(state.mode(), ColorPickerMode::RGB);
 
+        state.toggle_mode();
+        assert_eq!(state.mode(), ColorPickerMode::Palette);
+
         state.toggle_mode();
         assert_eq!(state.mode(), ColorPickerMode::HSL);
     }
@@ -306,6 +401,52 @@ mod tests {
         assert_eq!(state.focused_channel(), Channel::Primary);
     }
 
+    #[test]
+    fn test_hex_input_sets_color() {
+        let mut state = ColorPickerState::from_color(Color::Rgb(255, 0, 0), ColorPickerMode::HSL);
+
+        assert!(state.set_hex("#1a2b3c").is_ok());
+        assert_eq!(state.color(), Color::Rgb(0x1a, 0x2b, 0x3c));
+
+        // Invalid input is rejected and the color is kept
+        assert!(state.set_hex("#12345g").is_err());
+        assert!(state.set_hex("#1a2b").is_err());
+        assert_eq!(state.color(), Color::Rgb(0x1a, 0x2b, 0x3c));
+
+        // Typing into the hex field: Tab to it, clear it, type with '#', Enter
+        for _ in 0..3 {
+            state.handle_key(KeyCode::Tab);
+        }
+        assert!(state.is_hex_editing());
+        for _ in 0..6 {
+            state.handle_key(KeyCode::Backspace);
+        }
+        for c in "#A0B1C2".chars() {
+            state.handle_key(KeyCode::Char(c));
+        }
+        assert_eq!(state.hex_input, "a0b1c2");
+        state.handle_key(KeyCode::Enter);
+        assert_eq!(state.color(), Color::Rgb(0xa0, 0xb1, 0xc2));
+    }
+
+    #[test]
+    fn test_palette_selection_syncs_color() {
+        let mut state = ColorPickerState::from_color(Color::Rgb(1, 2, 3), ColorPickerMode::HSL);
+        state.toggle_mode();
+        state.toggle_mode();
+        assert_eq!(state.mode(), ColorPickerMode::Palette);
+
+        // Down moves one grid row: Black → Dark Gray
+        state.handle_key(KeyCode::Down);
+        assert_eq!(state.color(), Color::Rgb(0x80, 0x80, 0x80));
+        assert_eq!(state.palette_name(), Some("Dark Gray"));
+
+        // Back in HSL mode the picked palette color is kept
+        state.toggle_mode();
+        assert_eq!(state.mode(), ColorPickerMode::HSL);
+        assert_eq!(state.hex(), "808080");
+    }
+
     #[test]
     fn test_adjust_hsl() {
         let mut state = ColorPickerState::from_color(
@@ -104,6 +104,8 @@ pub enum MultiSelectEvent {
     Remove(String),
     /// Clear all selected items
     Clear,
+    /// Select every item matching the filter, or deselect them if all are selected (Ctrl+A)
+    SelectAllFiltered,
     /// Item clicked in dropdown
     Select(String),
 }
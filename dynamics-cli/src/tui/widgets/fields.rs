@@ -1,6 +1,7 @@
 use super::events::{AutocompleteEvent, MultiSelectEvent, SelectEvent, TextInputEvent};
-use super::{AutocompleteState, MultiSelectState, SelectState, TextInputState};
+use super::{AutocompleteState, InputMask, MultiSelectState, SelectState, TextInputState};
 use crate::tui::command::Command;
+use crossterm::event::KeyCode;
 
 /// Field that combines value + state for Autocomplete widget
 #[derive(Clone, Default)]
@@ -70,10 +71,20 @@ impl AutocompleteField {
 }
 
 /// Field that combines value + state for TextInput widget
+///
+/// An optional [`InputMask`] rejects characters that don't fit the pattern as
+/// they are typed, and an optional validator produces an error hint for the
+/// current value (see [`TextInputField::error`]).
 #[derive(Clone, Default)]
 pub struct TextInputField {
     pub value: String,
     pub state: TextInputState,
+    /// Restricts which characters can be typed
+    pub mask: Option<InputMask>,
+    /// Checks the value after every change
+    pub validator: Option<fn(&str) -> Result<(), String>>,
+    /// Last validation error (kept in sync by `handle_event`/`set_value`)
+    error: Option<String>,
 }
 
 impl TextInputField {
@@ -81,6 +92,28 @@ impl TextInputField {
         Self::default()
     }
 
+    /// Create a field pre-filled with `value`
+    pub fn with_value(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Restrict typed input to a mask pattern
+    pub fn with_mask(mut self, mask: InputMask) -> Self {
+        self.mask = Some(mask);
+        self.validate();
+        self
+    }
+
+    /// Validate the value after every change; the error is exposed via `error()`
+    pub fn with_validator(mut self, validator: fn(&str) -> Result<(), String>) -> Self {
+        self.validator = Some(validator);
+        self.validate();
+        self
+    }
+
     /// Handle text input event and return command (usually None unless Submit)
     /// Returns Some(value) on Submit, None otherwise
     pub fn handle_event(
@@ -90,8 +123,9 @@ impl TextInputField {
     ) -> Option<String> {
         match event {
             TextInputEvent::Changed(key) => {
-                if let Some(new_value) = self.state.handle_key(key, &self.value, max_length) {
+                if let Some(new_value) = self.apply_key(key, max_length) {
                     self.value = new_value;
+                    self.validate();
                 }
                 None
             }
@@ -99,6 +133,53 @@ impl TextInputField {
         }
     }
 
+    /// Apply a key, honouring the mask; rejected characters leave the state untouched
+    fn apply_key(&mut self, key: KeyCode, max_length: Option<usize>) -> Option<String> {
+        let (Some(mask), KeyCode::Char(c)) = (self.mask, key) else {
+            return self.state.handle_key(key, &self.value, max_length);
+        };
+
+        let mut state = self.state.clone();
+        let mut value = self.value.clone();
+
+        // Insert any literal the mask expects here (e.g. GUID dashes) unless it was typed
+        while let Some(literal) = mask.literal_at(state.cursor_pos()) {
+            if literal == c {
+                break;
+            }
+            value = state.handle_key(KeyCode::Char(literal), &value, max_length)?;
+        }
+
+        let value = state.handle_key(key, &value, max_length)?;
+        if !mask.accepts(&value) {
+            return None;
+        }
+        self.state = state;
+        Some(value)
+    }
+
+    /// Re-run the mask and validator against the current value
+    fn validate(&mut self) {
+        self.error = if self.value.is_empty() {
+            None
+        } else if let Some(mask) = self.mask.filter(|m| !m.is_complete(&self.value)) {
+            Some(format!("Expected format: {}", mask.pattern()))
+        } else {
+            self.validator
+                .and_then(|validate| validate(&self.value).err())
+        };
+    }
+
+    /// Validation error for the current value, if any
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Whether the current value passes the mask and validator
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
     /// Get current value
     pub fn value(&self) -> &str {
         &self.value
@@ -109,6 +190,7 @@ impl TextInputField {
     pub fn set_value(&mut self, value: String) {
         self.value = value;
         self.state.set_cursor_to_end(&self.value);
+        self.validate();
     }
 }
 
@@ -276,6 +358,10 @@ impl MultiSelectField {
                 self.state.clear_all();
                 self.selected_items.clear();
             }
+            MultiSelectEvent::SelectAllFiltered => {
+                self.state.select_all_filtered(options);
+                self.selected_items = self.state.selected_items().to_vec();
+            }
             MultiSelectEvent::Select(item) => {
                 self.state.toggle_item(&item);
                 self.selected_items = self.state.selected_items().to_vec();
@@ -309,3 +395,44 @@ impl MultiSelectField {
         !self.selected_items.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_str(field: &mut TextInputField, text: &str) {
+        for c in text.chars() {
+            field.handle_event(TextInputEvent::Changed(KeyCode::Char(c)), None);
+        }
+    }
+
+    #[test]
+    fn test_guid_mask_rejects_non_hex_input() {
+        let mut field = TextInputField::new().with_mask(InputMask::GUID);
+        type_str(&mut field, "12zg3x");
+
+        assert_eq!(field.value(), "123");
+        assert_eq!(field.state.cursor_pos(), 3);
+        assert!(!field.is_valid());
+    }
+
+    #[test]
+    fn test_guid_mask_accepts_valid_guid() {
+        let guid = "3f2504e0-4f89-11d3-9a0c-0305e82c3301";
+
+        let mut typed = TextInputField::new().with_mask(InputMask::GUID);
+        type_str(&mut typed, guid);
+        assert_eq!(typed.value(), guid);
+        assert!(typed.is_valid());
+
+        // Dashes are inserted automatically when only hex digits are typed
+        let mut digits_only = TextInputField::new().with_mask(InputMask::GUID);
+        type_str(&mut digits_only, &guid.replace('-', ""));
+        assert_eq!(digits_only.value(), guid);
+        assert!(digits_only.is_valid());
+
+        // Nothing can be typed past the end of the mask
+        type_str(&mut digits_only, "a");
+        assert_eq!(digits_only.value(), guid);
+    }
+}
@@ -11,7 +11,9 @@ pub mod text_input;
 pub mod tree;
 
 pub use autocomplete::AutocompleteState;
-pub use color_picker::{Channel, ColorPickerMode, ColorPickerState};
+pub use color_picker::{
+    Channel, ColorPickerMode, ColorPickerState, NAMED_PALETTE, PALETTE_COLUMNS,
+};
 pub use events::{
     AutocompleteEvent, ColorPickerEvent, FileBrowserEvent, ListEvent, MultiSelectEvent,
     SelectEvent, TextInputEvent, TreeEvent,
@@ -22,5 +24,5 @@ pub use list::{ListItem, ListState};
 pub use multi_select::MultiSelectState;
 pub use scrollable::ScrollableState;
 pub use select::SelectState;
-pub use text_input::TextInputState;
+pub use text_input::{InputMask, TextInputState};
 pub use tree::{FlatTableNode, TableTreeItem, TreeItem, TreeState};
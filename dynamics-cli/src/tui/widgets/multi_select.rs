@@ -1,4 +1,5 @@
 use crate::tui::widgets::TextInputState;
+use crate::tui::widgets::autocomplete::rank_options;
 use crossterm::event::KeyCode;
 
 /// Manages state for multi-select input widgets
@@ -25,6 +26,20 @@ pub struct MultiSelectState {
 
     /// Total count of available options (for validation)
     total_option_count: usize,
+
+    /// Options matching the last non-empty input, reused while the input grows
+    match_cache: Option<MatchCache>,
+}
+
+/// Indices of the options that matched a filter input
+///
+/// A fuzzy match for a longer input is also a match for any of its prefixes,
+/// so typing another character only needs to re-score these candidates.
+#[derive(Debug, Clone)]
+struct MatchCache {
+    input: String,
+    option_count: usize,
+    indices: Vec<usize>,
 }
 
 impl Default for MultiSelectState {
@@ -44,6 +59,7 @@ impl MultiSelectState {
             highlight_index: 0,
             filtered_options: Vec::new(),
             total_option_count: 0,
+            match_cache: None,
         }
     }
 
@@ -97,13 +113,67 @@ impl MultiSelectState {
         self.input_state = TextInputState::new();
     }
 
+    /// Fuzzy match the candidate options, best first and in list order on ties
+    fn score_indices(
+        input: &str,
+        all_options: &[String],
+        candidates: Vec<usize>,
+    ) -> Vec<(usize, i64)> {
+        use fuzzy_matcher::FuzzyMatcher;
+        use fuzzy_matcher::skim::SkimMatcherV2;
+
+        let matcher = SkimMatcherV2::default();
+        let mut scored: Vec<(usize, i64)> = candidates
+            .into_iter()
+            .filter_map(|i| {
+                matcher
+                    .fuzzy_match(&all_options[i], input)
+                    .map(|score| (i, score))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        scored
+    }
+
+    /// All options matching the current filter input, selected or not
+    pub fn matching_options(&self, all_options: &[String]) -> Vec<String> {
+        if self.value.is_empty() {
+            return all_options.to_vec();
+        }
+
+        rank_options(&self.value, all_options)
+            .into_iter()
+            .map(|(opt, _)| opt)
+            .collect()
+    }
+
+    /// Select every option matching the current filter
+    ///
+    /// If all of them are already selected, they are deselected instead, so
+    /// the same action toggles the filtered set. Items outside the filter are
+    /// left untouched.
+    pub fn select_all_filtered(&mut self, all_options: &[String]) {
+        let matching = self.matching_options(all_options);
+        if matching.is_empty() {
+            return;
+        }
+
+        if matching.iter().all(|opt| self.selected_items.contains(opt)) {
+            self.selected_items.retain(|item| !matching.contains(item));
+        } else {
+            for opt in matching {
+                self.add_item(opt);
+            }
+        }
+
+        let value = self.value.clone();
+        self.update_filtered_options(&value, all_options);
+    }
+
     /// Update filtered options using fuzzy matching
     /// Automatically opens/closes dropdown based on results
     /// Excludes already selected items from the dropdown
     pub fn update_filtered_options(&mut self, input: &str, all_options: &[String]) {
-        use fuzzy_matcher::FuzzyMatcher;
-        use fuzzy_matcher::skim::SkimMatcherV2;
-
         self.total_option_count = all_options.len();
 
         if input.is_empty() {
@@ -114,28 +184,36 @@ impl MultiSelectState {
                 .map(|opt| (opt.clone(), 0))
                 .take(100)
                 .collect();
+            self.match_cache = None;
             self.is_open = !self.filtered_options.is_empty();
             self.highlight_index = 0;
             return;
         }
 
-        // Fuzzy match
-        let matcher = SkimMatcherV2::default();
-        let mut scored: Vec<(String, i64)> = all_options
+        // Narrow the previous matches when the input only grew
+        let candidates = match self.match_cache.take() {
+            Some(cache)
+                if cache.option_count == all_options.len() && input.starts_with(&cache.input) =>
+            {
+                cache.indices
+            }
+            _ => (0..all_options.len()).collect(),
+        };
+        let scored = Self::score_indices(input, all_options, candidates);
+
+        // Fuzzy match, excluding already selected items - take top 100
+        self.filtered_options = scored
             .iter()
-            .filter(|opt| !self.selected_items.contains(opt))
-            .filter_map(|opt| {
-                matcher
-                    .fuzzy_match(opt, input)
-                    .map(|score| (opt.clone(), score))
-            })
+            .map(|&(i, score)| (&all_options[i], score))
+            .filter(|(opt, _)| !self.selected_items.contains(opt))
+            .take(100)
+            .map(|(opt, score)| (opt.clone(), score))
             .collect();
-
-        // Sort by score descending
-        scored.sort_by(|a, b| b.1.cmp(&a.1));
-
-        // Take top 100
-        self.filtered_options = scored.into_iter().take(100).collect();
+        self.match_cache = Some(MatchCache {
+            input: input.to_string(),
+            option_count: all_options.len(),
+            indices: scored.into_iter().map(|(i, _)| i).collect(),
+        });
 
         // Auto-open/close dropdown
         self.is_open = !self.filtered_options.is_empty();
@@ -215,3 +293,78 @@ impl MultiSelectState {
         self.selected_items = items;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> Vec<String> {
+        ["account", "contact", "nrq_account", "opportunity", "lead"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_select_all_filtered_only_selects_matches() {
+        let options = options();
+        let mut state = MultiSelectState::new();
+        state.add_item("lead".to_string());
+
+        state.set_value("account".to_string());
+        state.update_filtered_options("account", &options);
+        state.select_all_filtered(&options);
+
+        let mut selected = state.selected_items().to_vec();
+        selected.sort();
+        assert_eq!(selected, vec!["account", "lead", "nrq_account"]);
+
+        // Applying it again deselects the filtered items only
+        state.select_all_filtered(&options);
+        assert_eq!(state.selected_items(), ["lead".to_string()]);
+    }
+
+    #[test]
+    fn test_matching_options_rank_prefix_matches_first() {
+        let mut state = MultiSelectState::new();
+        state.set_value("acc".to_string());
+        assert_eq!(
+            state.matching_options(&options()),
+            vec!["account", "nrq_account"]
+        );
+
+        // No filter matches every option in list order
+        state.set_value(String::new());
+        assert_eq!(state.matching_options(&options()), options());
+    }
+
+    #[test]
+    fn test_incremental_filter_on_large_list_stays_ordered() {
+        let options: Vec<String> = (0..3000).map(|i| format!("nrq_entity_{:04}", i)).collect();
+        let mut state = MultiSelectState::new();
+
+        let start = std::time::Instant::now();
+        let mut input = String::new();
+        for c in "nrq_entity_12".chars() {
+            input.push(c);
+            state.set_value(input.clone());
+            state.update_filtered_options(&input, &options);
+        }
+        assert!(start.elapsed() < std::time::Duration::from_secs(2));
+
+        // Narrowing from cached matches gives the same result as a full filter
+        let mut fresh = MultiSelectState::new();
+        fresh.update_filtered_options(&input, &options);
+        assert_eq!(state.filtered_options(), fresh.filtered_options());
+
+        // Equal scores keep list order, so the contiguous matches come first in sequence
+        let expected: Vec<String> = (1200..1300).map(|i| format!("nrq_entity_{}", i)).collect();
+        assert_eq!(state.filtered_options(), expected);
+
+        // Deleting a character falls back to filtering the full list
+        input.pop();
+        state.update_filtered_options(&input, &options);
+        fresh.update_filtered_options(&input, &options);
+        assert_eq!(state.filtered_options(), fresh.filtered_options());
+    }
+}
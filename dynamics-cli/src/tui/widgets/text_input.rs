@@ -192,3 +192,66 @@ impl TextInputState {
  *
  * ============================================================================
  */
+
+/// Character pattern restricting what can be typed into a text input
+///
+/// Each pattern character is a slot: `h` hex digit, `9` decimal digit,
+/// `a` letter, `*` anything; any other character is a literal that must
+/// appear as-is and is inserted automatically while typing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputMask {
+    pattern: &'static str,
+}
+
+impl InputMask {
+    /// GUID in 8-4-4-4-12 form
+    pub const GUID: InputMask = InputMask::new("hhhhhhhh-hhhh-hhhh-hhhh-hhhhhhhhhhhh");
+
+    /// ISO date (YYYY-MM-DD)
+    pub const DATE: InputMask = InputMask::new("9999-99-99");
+
+    pub const fn new(pattern: &'static str) -> Self {
+        Self { pattern }
+    }
+
+    /// The raw pattern string
+    pub fn pattern(&self) -> &'static str {
+        self.pattern
+    }
+
+    fn slot_accepts(slot: char, c: char) -> bool {
+        match slot {
+            'h' => c.is_ascii_hexdigit(),
+            '9' => c.is_ascii_digit(),
+            'a' => c.is_alphabetic(),
+            '*' => true,
+            literal => c == literal,
+        }
+    }
+
+    fn is_literal(slot: char) -> bool {
+        !matches!(slot, 'h' | '9' | 'a' | '*')
+    }
+
+    /// Whether `value` is a valid (possibly incomplete) prefix of the mask
+    pub fn accepts(&self, value: &str) -> bool {
+        value.chars().count() <= self.pattern.chars().count()
+            && value
+                .chars()
+                .zip(self.pattern.chars())
+                .all(|(c, slot)| Self::slot_accepts(slot, c))
+    }
+
+    /// Whether `value` fills the whole mask
+    pub fn is_complete(&self, value: &str) -> bool {
+        value.chars().count() == self.pattern.chars().count() && self.accepts(value)
+    }
+
+    /// Literal expected at `pos`, if that slot is a literal
+    pub fn literal_at(&self, pos: usize) -> Option<char> {
+        self.pattern
+            .chars()
+            .nth(pos)
+            .filter(|slot| Self::is_literal(*slot))
+    }
+}
@@ -192,6 +192,72 @@ impl TreeState {
         }
     }
 
+    /// Collapse every node, moving the selection up to its root ancestor
+    pub fn collapse_all(&mut self) {
+        if let Some(mut id) = self.selected.clone() {
+            while let Some(parent) = self.node_parents.get(&id) {
+                id = parent.clone();
+            }
+            self.selected = Some(id);
+        }
+        self.expanded.clear();
+        self.multi_selected.clear();
+        self.anchor_selection = None;
+        self.cache_valid = false;
+    }
+
+    /// Expand every node that has children
+    pub fn expand_all<T: TreeItem>(&mut self, root_items: &[T]) {
+        fn walk<T: TreeItem>(items: &[T], expanded: &mut HashSet<String>) {
+            for item in items {
+                if item.has_children() {
+                    expanded.insert(item.id());
+                    walk(&item.children(), expanded);
+                }
+            }
+        }
+
+        walk(root_items, &mut self.expanded);
+        self.cache_valid = false;
+    }
+
+    /// Expand the ancestors of every node matching `is_match` so all matches are visible
+    ///
+    /// Matching nodes themselves are left as they are. Returns the number of matches.
+    pub fn expand_to_match<T: TreeItem>(
+        &mut self,
+        root_items: &[T],
+        is_match: impl Fn(&T) -> bool,
+    ) -> usize {
+        fn walk<T: TreeItem>(
+            items: &[T],
+            is_match: &impl Fn(&T) -> bool,
+            expanded: &mut HashSet<String>,
+        ) -> (usize, bool) {
+            let mut count = 0;
+            let mut any = false;
+            for item in items {
+                if is_match(item) {
+                    count += 1;
+                    any = true;
+                }
+                if item.has_children() {
+                    let (child_count, child_any) = walk(&item.children(), is_match, expanded);
+                    if child_any {
+                        expanded.insert(item.id());
+                        count += child_count;
+                        any = true;
+                    }
+                }
+            }
+            (count, any)
+        }
+
+        let (count, _) = walk(root_items, &is_match, &mut self.expanded);
+        self.cache_valid = false;
+        count
+    }
+
     /// Get parent of a node (O(1) with cache)
     pub fn parent_of(&self, node_id: &str) -> Option<&str> {
         self.node_parents.get(node_id).map(|s| s.as_str())
@@ -714,3 +780,87 @@ fn flatten_table_recursive<T: TableTreeItem>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Node {
+        id: &'static str,
+        children: Vec<Node>,
+    }
+
+    fn node(id: &'static str, children: Vec<Node>) -> Node {
+        Node { id, children }
+    }
+
+    impl TreeItem for Node {
+        type Msg = ();
+
+        fn id(&self) -> String {
+            self.id.to_string()
+        }
+
+        fn has_children(&self) -> bool {
+            !self.children.is_empty()
+        }
+
+        fn children(&self) -> Vec<Self> {
+            self.children.clone()
+        }
+
+        fn to_element(&self, _: usize, _: bool, _: bool, _: bool) -> Element<()> {
+            Element::text(self.id)
+        }
+    }
+
+    fn tree() -> Vec<Node> {
+        vec![
+            node(
+                "fields",
+                vec![
+                    node(
+                        "contact",
+                        vec![node("firstname", vec![]), node("lastname", vec![])],
+                    ),
+                    node("account", vec![node("name", vec![])]),
+                ],
+            ),
+            node("views", vec![node("active_contacts", vec![])]),
+        ]
+    }
+
+    fn expanded(state: &TreeState) -> Vec<String> {
+        let mut ids: Vec<String> = state.expanded.iter().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_expand_to_match_opens_only_ancestors_of_matches() {
+        let mut state = TreeState::new();
+        let count = state.expand_to_match(&tree(), |n| n.id == "lastname");
+
+        assert_eq!(count, 1);
+        assert_eq!(expanded(&state), vec!["contact", "fields"]);
+    }
+
+    #[test]
+    fn test_expand_all_and_collapse_all() {
+        let items = tree();
+        let mut state = TreeState::new();
+
+        state.expand_all(&items);
+        assert_eq!(
+            expanded(&state),
+            vec!["account", "contact", "fields", "views"]
+        );
+
+        state.rebuild_metadata(&items);
+        state.select(Some("firstname".to_string()));
+        state.collapse_all();
+        assert!(expanded(&state).is_empty());
+        assert_eq!(state.selected(), Some("fields"));
+    }
+}